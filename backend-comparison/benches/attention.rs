@@ -0,0 +1,87 @@
+use backend_comparison::persistence::save;
+use burn::module::ForwardFlops;
+use burn::nn::attention::{MhaInput, MultiHeadAttention, MultiHeadAttentionConfig};
+use burn::tensor::{backend::Backend, Distribution, Shape, Tensor};
+use burn_common::benchmark::{run_benchmark, Benchmark};
+
+/// Benchmark the forward pass of multi-head self-attention.
+struct AttentionBenchmark<B: Backend> {
+    shape: Shape<3>,
+    n_heads: usize,
+    mha: MultiHeadAttention<B>,
+    device: B::Device,
+}
+
+impl<B: Backend> AttentionBenchmark<B> {
+    fn new(shape: Shape<3>, n_heads: usize, device: B::Device) -> Self {
+        let mha = MultiHeadAttentionConfig::new(shape.dims[2], n_heads).init(&device);
+
+        Self {
+            shape,
+            n_heads,
+            mha,
+            device,
+        }
+    }
+}
+
+impl<B: Backend> Benchmark for AttentionBenchmark<B> {
+    type Args = MhaInput<B>;
+
+    fn name(&self) -> String {
+        "attention".into()
+    }
+
+    fn options(&self) -> Option<String> {
+        // The estimated number of operations of a forward pass gives the throughput of the
+        // backend from the measured durations.
+        let input = self.prepare();
+        let gflops = self.mha.forward_flops(&input) as f64 / 1e9;
+
+        Some(format!("n_heads={}, gflops={:.2}", self.n_heads, gflops))
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![self.shape.dims.into()]
+    }
+
+    fn num_samples(&self) -> usize {
+        10
+    }
+
+    fn execute(&self, input: Self::Args) {
+        self.mha.forward(input);
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let x = Tensor::random(self.shape.clone(), Distribution::Default, &self.device);
+
+        MhaInput::self_attn(x)
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device)
+    }
+}
+
+#[allow(dead_code)]
+fn bench<B: Backend>(device: &B::Device) {
+    let d_model = 512;
+    let n_heads = 8;
+    let mut results = Vec::new();
+
+    for batch_size in [8, 32] {
+        for seq_length in [128, 512, 2048] {
+            let shape = [batch_size, seq_length, d_model].into();
+            let benchmark = AttentionBenchmark::<B>::new(shape, n_heads, device.clone());
+
+            results.push(run_benchmark(benchmark));
+        }
+    }
+
+    save::<B>(results, device).unwrap();
+}
+
+fn main() {
+    backend_comparison::bench_on_backend!();
+}
@@ -0,0 +1,116 @@
+use backend_comparison::persistence::save;
+use burn::tensor::{
+    backend::Backend, module::conv2d, ops::ConvOptions, Distribution, Shape, Tensor,
+};
+use burn_common::benchmark::{run_benchmark, Benchmark};
+use derive_new::new;
+
+#[derive(Debug)]
+enum Conv2dKind {
+    Kernel3x3,
+    Kernel7x7,
+    Depthwise,
+}
+
+/// Benchmark a 2D convolution, with a bias, on a batch of images.
+#[derive(new)]
+struct Conv2dBenchmark<B: Backend> {
+    input_shape: Shape<4>,
+    weight_shape: Shape<4>,
+    options: ConvOptions<2>,
+    kind: Conv2dKind,
+    device: B::Device,
+}
+
+impl<B: Backend> Benchmark for Conv2dBenchmark<B> {
+    type Args = (Tensor<B, 4>, Tensor<B, 4>, Tensor<B, 1>);
+
+    fn name(&self) -> String {
+        "conv2d".into()
+    }
+
+    fn options(&self) -> Option<String> {
+        Some(format!("{:?}", self.kind))
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![self.input_shape.dims.into(), self.weight_shape.dims.into()]
+    }
+
+    fn num_samples(&self) -> usize {
+        10
+    }
+
+    fn execute(&self, (x, weight, bias): Self::Args) {
+        conv2d(x, weight, Some(bias), self.options.clone());
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let x = Tensor::random(
+            self.input_shape.clone(),
+            Distribution::Default,
+            &self.device,
+        );
+        let weight = Tensor::random(
+            self.weight_shape.clone(),
+            Distribution::Default,
+            &self.device,
+        );
+        let bias = Tensor::random(
+            [self.weight_shape.dims[0]],
+            Distribution::Default,
+            &self.device,
+        );
+
+        (x, weight, bias)
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device)
+    }
+}
+
+#[allow(dead_code)]
+fn bench<B: Backend>(device: &B::Device) {
+    let batch_size = 16;
+    let channels = 64;
+    let size = 128;
+    let input_shape: Shape<4> = [batch_size, channels, size, size].into();
+
+    let kernel_3x3 = Conv2dBenchmark::<B>::new(
+        input_shape.clone(),
+        [channels, channels, 3, 3].into(),
+        ConvOptions::new([1, 1], [1, 1], [1, 1], 1),
+        Conv2dKind::Kernel3x3,
+        device.clone(),
+    );
+    let kernel_7x7 = Conv2dBenchmark::<B>::new(
+        input_shape.clone(),
+        [channels, channels, 7, 7].into(),
+        ConvOptions::new([1, 1], [3, 3], [1, 1], 1),
+        Conv2dKind::Kernel7x7,
+        device.clone(),
+    );
+    // Each channel is convolved with its own kernel.
+    let depthwise = Conv2dBenchmark::<B>::new(
+        input_shape,
+        [channels, 1, 3, 3].into(),
+        ConvOptions::new([1, 1], [1, 1], [1, 1], channels),
+        Conv2dKind::Depthwise,
+        device.clone(),
+    );
+
+    save::<B>(
+        vec![
+            run_benchmark(kernel_3x3),
+            run_benchmark(kernel_7x7),
+            run_benchmark(depthwise),
+        ],
+        device,
+    )
+    .unwrap();
+}
+
+fn main() {
+    backend_comparison::bench_on_backend!();
+}
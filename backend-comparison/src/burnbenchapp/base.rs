@@ -69,8 +69,12 @@ pub(crate) enum BackendValues {
 
 #[derive(Debug, Clone, PartialEq, Eq, ValueEnum, Display, EnumIter)]
 pub(crate) enum BenchmarkValues {
+    #[strum(to_string = "attention")]
+    Attention,
     #[strum(to_string = "binary")]
     Binary,
+    #[strum(to_string = "conv2d")]
+    Conv2d,
     #[strum(to_string = "custom_gelu")]
     CustomGelu,
     #[strum(to_string = "data")]
@@ -19,9 +19,26 @@ pub type NodeSteps = HashMap<NodeID, StepBoxed>;
 /// Graph data structure.
 ///
 /// The graph contains the [node steps](Step), which can be access by [node id](NodeID).
+///
+/// When two graphs shared with other tensors are merged, the steps of one of them are moved into
+/// the other one, which it then points to. The tensors still referring to the merged graph
+/// therefore keep access to their steps, even when they are used in other operations than the
+/// one that merged the graphs.
 #[derive(Default, Clone, Debug)]
 pub struct Graph {
-    steps: Arc<Mutex<NodeSteps>>,
+    state: Arc<Mutex<GraphState>>,
+}
+
+#[derive(Debug)]
+enum GraphState {
+    Steps(NodeSteps),
+    Merged(Graph),
+}
+
+impl Default for GraphState {
+    fn default() -> Self {
+        Self::Steps(NodeSteps::new())
+    }
 }
 
 impl Graph {
@@ -54,43 +71,205 @@ impl Graph {
         })
     }
 
+    /// Register steps that were taken from the graph back into it.
+    pub fn extend(self, steps: NodeSteps) -> Self {
+        self.execute_mut(|map| map.extend(steps))
+    }
+
     /// Merge two graphs.
-    pub fn merge(self, other: Self) -> Self {
-        if Arc::ptr_eq(&self.steps, &other.steps) {
+    pub fn merge(mut self, mut other: Self) -> Self {
+        if Arc::ptr_eq(&self.state, &other.state) {
             return self;
         }
 
-        self.merge_different(other)
+        // Without other references to the graphs, the steps are moved without locking and
+        // nothing needs to point to the merged graph.
+        if let (Some(state_1), Some(state_2)) = (
+            Arc::get_mut(&mut self.state),
+            Arc::get_mut(&mut other.state),
+        ) {
+            if let (GraphState::Steps(map_1), GraphState::Steps(map_2)) =
+                (state_1.get_mut(), state_2.get_mut())
+            {
+                if map_1.len() >= map_2.len() {
+                    map_1.extend(core::mem::take(map_2));
+                    return self;
+                }
+
+                map_2.extend(core::mem::take(map_1));
+                return other;
+            }
+        }
+
+        loop {
+            let (graph_1, graph_2) = (self.root(), other.root());
+
+            if Arc::ptr_eq(&graph_1.state, &graph_2.state) {
+                return graph_1;
+            }
+
+            if let Some(graph) = graph_1.merge_roots(&graph_2) {
+                return graph;
+            }
+        }
     }
 
     fn execute_mut<F: FnOnce(&mut NodeSteps)>(mut self, func: F) -> Self {
-        match Arc::get_mut(&mut self.steps) {
-            Some(mutex) => {
-                let map = mutex.get_mut();
-                func(map);
-            }
+        let merged = match Arc::get_mut(&mut self.state) {
+            Some(mutex) => Self::apply(mutex.get_mut(), func),
             None => {
                 // Only lock when there are multiple references to the graph.
-                let mut map = self.steps.lock();
-                func(&mut map);
+                let mut state = self.state.lock();
+                Self::apply(&mut state, func)
+            }
+        };
+
+        match merged {
+            Ok(()) => self,
+            Err((graph, func)) => graph.execute_mut(func),
+        }
+    }
+
+    /// Applies the function on the steps, or returns the graph they were merged into.
+    fn apply<F: FnOnce(&mut NodeSteps)>(state: &mut GraphState, func: F) -> Result<(), (Graph, F)> {
+        match state {
+            GraphState::Steps(map) => {
+                func(map);
+                Ok(())
             }
+            GraphState::Merged(graph) => Err((graph.clone(), func)),
+        }
+    }
+
+    /// Moves the steps of the smallest graph into the other one, returning the merged graph, or
+    /// none if one of the graphs was merged into another one by a different thread.
+    fn merge_roots(&self, other: &Self) -> Option<Self> {
+        // The graphs are always locked in the same order to avoid deadlocks.
+        let (first, second) = match Arc::as_ptr(&self.state) < Arc::as_ptr(&other.state) {
+            true => (self, other),
+            false => (other, self),
+        };
+        let mut state_1 = first.state.lock();
+        let mut state_2 = second.state.lock();
+
+        let (map_1, map_2) = match (&mut *state_1, &mut *state_2) {
+            (GraphState::Steps(map_1), GraphState::Steps(map_2)) => (map_1, map_2),
+            _ => return None,
+        };
+
+        let (graph, state_merged) = if map_1.len() >= map_2.len() {
+            map_1.extend(core::mem::take(map_2));
+            (first, &mut *state_2)
+        } else {
+            map_2.extend(core::mem::take(map_1));
+            (second, &mut *state_1)
+        };
+        *state_merged = GraphState::Merged(graph.clone());
+
+        Some(graph.clone())
+    }
+
+    /// Returns the graph containing the steps, following the merged graphs.
+    fn root(&self) -> Self {
+        let next = match &*self.state.lock() {
+            GraphState::Steps(_) => return self.clone(),
+            GraphState::Merged(next) => next.clone(),
         };
+        let root = next.root();
+
+        // A merged graph never holds steps again, so it can point directly to the root, which
+        // keeps the chains of merged graphs short.
+        if !Arc::ptr_eq(&next.state, &root.state) {
+            *self.state.lock() = GraphState::Merged(root.clone());
+        }
+
+        root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{traversal::BreadthFirstSearch, Node, Requirement};
+
+    #[derive(Debug)]
+    struct TestStep {
+        node: NodeRef,
+    }
+
+    impl Step for TestStep {
+        fn step(self: Box<Self>, _grads: &mut Gradients) {}
+
+        fn node(&self) -> NodeRef {
+            self.node.clone()
+        }
+    }
+
+    fn register(graph: Graph, parents: &[&NodeRef]) -> (Graph, NodeRef) {
+        let node: NodeRef = Node::new(
+            parents.iter().map(|node| node.id.clone()).collect(),
+            parents.iter().map(|node| node.order).max().unwrap_or(0) + 1,
+            NodeID::new(),
+            Requirement::Grad,
+        )
+        .into();
+        let step = TestStep { node: node.clone() };
 
-        self
+        (graph.register(&node.id, Box::new(step)), node)
     }
 
-    fn merge_different(self, other: Self) -> Self {
-        let mut map2 = other.steps();
+    #[test]
+    fn steps_of_unused_branches_should_be_dropped_after_backward() {
+        let (leaf, _) = register(Graph::new(), &[]);
 
-        self.execute_mut(|map1| {
-            if map1.len() > map2.len() {
-                map1.extend(map2);
-            } else {
-                let mut map_drain = HashMap::new();
-                std::mem::swap(map1, &mut map_drain);
-                map2.extend(map_drain);
-                std::mem::swap(map1, &mut map2);
+        for _ in 0..100 {
+            let (graph, node) = register(leaf.clone(), &[]);
+            {
+                // A branch merging the graph, which isn't used anymore by the backward pass.
+                let (unused, unused_node) = register(Graph::new(), &[]);
+                register(unused.merge(graph.clone()), &[&node, &unused_node]);
             }
-        })
+
+            let mut num_traversed = 0;
+            BreadthFirstSearch.traverse(node, graph, |_, _| num_traversed += 1);
+            assert_eq!(num_traversed, 1);
+        }
+
+        assert!(leaf.steps().is_empty());
+    }
+
+    #[test]
+    fn steps_still_used_should_be_kept_after_backward() {
+        let (graph, leaf) = register(Graph::new(), &[]);
+        let (graph, node_1) = register(graph, &[&leaf]);
+        let (graph, node_2) = register(graph, &[&leaf]);
+
+        let mut num_traversed = 0;
+        BreadthFirstSearch.traverse(node_1, graph.clone(), |_, _| num_traversed += 1);
+        assert_eq!(num_traversed, 2);
+
+        let mut traversed = Vec::new();
+        BreadthFirstSearch.traverse(node_2.clone(), graph, |node, _| {
+            traversed.push(node.id.clone())
+        });
+        assert_eq!(traversed, vec![node_2.id.clone()]);
+    }
+
+    #[test]
+    fn merged_graphs_should_keep_their_steps() {
+        let (graph_1, node_1) = register(Graph::new(), &[]);
+        let (graph_2, node_2) = register(Graph::new(), &[]);
+        let (graph_3, _) = register(Graph::new(), &[]);
+
+        // The second graph is shared, so it points to the graph its steps were moved into.
+        let _merged = graph_3.merge(graph_2.clone()).merge(graph_1);
+        let (graph_2, node) = register(graph_2, &[&node_2]);
+
+        let mut traversed = Vec::new();
+        BreadthFirstSearch.traverse(node.clone(), graph_2, |node, _| {
+            traversed.push(node.id.clone())
+        });
+        assert_eq!(traversed, vec![node.id.clone(), node_2.id.clone()]);
+        assert!(!traversed.contains(&node_1.id));
     }
 }
@@ -1,6 +1,6 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, sync::Arc};
 
-use super::{Graph, NodeRef, StepBoxed};
+use super::{Graph, NodeRef, NodeSteps, StepBoxed};
 
 /// Breadth for search algorithm.
 pub struct BreadthFirstSearch;
@@ -15,7 +15,7 @@ impl BreadthFirstSearch {
     ) {
         let mut visited = HashSet::with_capacity(root.order);
         let mut parents = Vec::with_capacity(root.order);
-        let mut steps = graph.steps();
+        let mut steps = graph.clone().steps();
         let root_step = steps.remove(&root.id).expect(
             "Root node should have a step registered, did you forget to call \
              `Tensor::register_grad` on the tensor where you need gradients?",
@@ -47,5 +47,33 @@ impl BreadthFirstSearch {
 
             callback(node, step);
         }
+
+        // The steps that weren't traversed are put back for the tensors still using them, but
+        // the ones no tensor can reach anymore are dropped, so a shared graph doesn't grow with
+        // every backward pass.
+        Self::drop_unreachable(&mut steps);
+        graph.extend(steps);
+    }
+
+    /// Drops the steps whose node is only referenced by the step itself, which means that no
+    /// tensor nor any other step uses it.
+    fn drop_unreachable(steps: &mut NodeSteps) {
+        let mut candidates: Vec<_> = steps.keys().cloned().collect();
+
+        while let Some(id) = candidates.pop() {
+            let node = match steps.get(&id) {
+                Some(step) => step.node(),
+                None => continue,
+            };
+
+            // One reference is held by the step and the other one here.
+            if Arc::strong_count(&node) > 2 {
+                continue;
+            }
+
+            steps.remove(&id);
+            // Dropping the step releases its parents, which may now be unreachable as well.
+            candidates.extend(node.parents.iter().cloned());
+        }
     }
 }
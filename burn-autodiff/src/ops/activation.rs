@@ -77,4 +77,26 @@ impl<B: Backend> ActivationOps<Autodiff<B>> for Autodiff<B> {
             OpsKind::UnTracked(prep) => prep.finish(B::sigmoid(tensor.primitive)),
         }
     }
+
+    fn log_sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct LogSigmoid;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for LogSigmoid {
+            type State = B::FloatTensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::log_sigmoid_backward(ops.state, grad)
+                });
+            }
+        }
+
+        match LogSigmoid.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::log_sigmoid(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::log_sigmoid(tensor.primitive)),
+        }
+    }
 }
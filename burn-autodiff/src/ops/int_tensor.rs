@@ -334,6 +334,12 @@ impl<B: Backend> IntTensorOps<Autodiff<B>> for Autodiff<B> {
         B::int_chunk(tensor, chunks, dim)
     }
 
+    fn int_nonzero<const D: usize>(
+        tensor: <Autodiff<B> as Backend>::IntTensorPrimitive<D>,
+    ) -> <Autodiff<B> as Backend>::IntTensorPrimitive<2> {
+        B::int_nonzero(tensor)
+    }
+
     fn int_arange(range: std::ops::Range<i64>, device: &Device<Self>) -> IntTensor<Self, 1> {
         B::int_arange(range, device)
     }
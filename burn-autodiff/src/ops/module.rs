@@ -877,6 +877,224 @@ impl<B: Backend> ModuleOps<Autodiff<B>> for Autodiff<B> {
     ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive<4> {
         panic!("Can't differentiate adaptive avg pool2d backward.");
     }
+
+    fn interpolate(
+        x: AutodiffTensor<B, 4>,
+        output_size: [usize; 2],
+        mode: InterpolateMode,
+    ) -> AutodiffTensor<B, 4> {
+        #[derive(Debug)]
+        struct Interpolate;
+
+        impl<B: Backend> Backward<B, 4, 1> for Interpolate {
+            type State = (B::FloatTensorPrimitive<4>, [usize; 2], InterpolateMode);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+                let (x, output_size, mode) = ops.state;
+
+                if let Some(node) = node_parent {
+                    let grad = B::interpolate_backward(x, grad, output_size, mode);
+                    grads.register::<B, 4>(node, grad);
+                }
+            }
+        }
+
+        match Interpolate.prepare([x.node], [x.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (x.primitive.clone(), output_size, mode),
+                B::interpolate(x.primitive, output_size, mode),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::interpolate(x.primitive, output_size, mode)),
+        }
+    }
+
+    fn rope_embed(
+        x: AutodiffTensor<B, 4>,
+        cos: AutodiffTensor<B, 2>,
+        sin: AutodiffTensor<B, 2>,
+    ) -> AutodiffTensor<B, 4> {
+        #[derive(Debug)]
+        struct RopeEmbed;
+
+        impl<B: Backend> Backward<B, 4, 1> for RopeEmbed {
+            type State = (B::FloatTensorPrimitive<2>, B::FloatTensorPrimitive<2>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (cos, sin) = ops.state;
+
+                // The rotation is an isometry, its transpose is the rotation by the opposite
+                // angle.
+                unary::<B, 4, 4, _>(ops.parents, ops.node, grads, |grad| {
+                    B::rope_embed(grad, cos, B::float_neg(sin))
+                });
+            }
+        }
+
+        match RopeEmbed.prepare([x.node], [x.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (cos.primitive.clone(), sin.primitive.clone()),
+                B::rope_embed(x.primitive, cos.primitive, sin.primitive),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::rope_embed(x.primitive, cos.primitive, sin.primitive))
+            }
+        }
+    }
+
+    fn flash_attention(
+        query: AutodiffTensor<B, 4>,
+        key: AutodiffTensor<B, 4>,
+        value: AutodiffTensor<B, 4>,
+        causal: bool,
+    ) -> AutodiffTensor<B, 4> {
+        #[derive(Debug)]
+        struct FlashAttention;
+
+        impl<B: Backend> Backward<B, 4, 3> for FlashAttention {
+            type State = (
+                B::FloatTensorPrimitive<4>,
+                B::FloatTensorPrimitive<4>,
+                B::FloatTensorPrimitive<4>,
+                bool,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 3>, grads: &mut Gradients) {
+                let [node_query, node_key, node_value] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+
+                let (query, key, value, causal) = ops.state;
+                let backward = B::flash_attention_backward(query, key, value, grad, causal);
+
+                if let Some(node) = node_query {
+                    grads.register::<B, 4>(node, backward.query_grad)
+                }
+                if let Some(node) = node_key {
+                    grads.register::<B, 4>(node, backward.key_grad)
+                }
+                if let Some(node) = node_value {
+                    grads.register::<B, 4>(node, backward.value_grad)
+                }
+            }
+        }
+
+        match FlashAttention
+            .prepare(
+                [query.node, key.node, value.node],
+                [query.graph, key.graph, value.graph],
+            )
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    query.primitive.clone(),
+                    key.primitive.clone(),
+                    value.primitive.clone(),
+                    causal,
+                ),
+                B::flash_attention(query.primitive, key.primitive, value.primitive, causal),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::flash_attention(
+                query.primitive,
+                key.primitive,
+                value.primitive,
+                causal,
+            )),
+        }
+    }
+
+    fn scaled_dot_product_attention(
+        query: AutodiffTensor<B, 4>,
+        key: AutodiffTensor<B, 4>,
+        value: AutodiffTensor<B, 4>,
+        mask: Option<BoolTensor<B, 4>>,
+        dropout_p: f64,
+        causal: bool,
+    ) -> AutodiffTensor<B, 4> {
+        #[derive(Debug)]
+        struct ScaledDotProductAttention;
+
+        impl<B: Backend> Backward<B, 4, 3> for ScaledDotProductAttention {
+            type State = (
+                B::FloatTensorPrimitive<4>,
+                B::FloatTensorPrimitive<4>,
+                B::FloatTensorPrimitive<4>,
+                Option<BoolTensor<B, 4>>,
+                bool,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 3>, grads: &mut Gradients) {
+                let [node_query, node_key, node_value] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+
+                let (query, key, value, mask, causal) = ops.state;
+                let backward =
+                    B::scaled_dot_product_attention_backward(query, key, value, mask, grad, causal);
+
+                if let Some(node) = node_query {
+                    grads.register::<B, 4>(node, backward.query_grad)
+                }
+                if let Some(node) = node_key {
+                    grads.register::<B, 4>(node, backward.key_grad)
+                }
+                if let Some(node) = node_value {
+                    grads.register::<B, 4>(node, backward.value_grad)
+                }
+            }
+        }
+
+        // The dropout mask would have to be kept for the backward pass, the attention is
+        // decomposed into differentiable operations instead.
+        if dropout_p > 0.0 {
+            return attention::scaled_dot_product_attention_from_decomposition::<Self>(
+                query, key, value, mask, dropout_p, causal,
+            );
+        }
+
+        match ScaledDotProductAttention
+            .prepare(
+                [query.node, key.node, value.node],
+                [query.graph, key.graph, value.graph],
+            )
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    query.primitive.clone(),
+                    key.primitive.clone(),
+                    value.primitive.clone(),
+                    mask.clone(),
+                    causal,
+                ),
+                B::scaled_dot_product_attention(
+                    query.primitive,
+                    key.primitive,
+                    value.primitive,
+                    mask,
+                    0.0,
+                    causal,
+                ),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::scaled_dot_product_attention(
+                query.primitive,
+                key.primitive,
+                value.primitive,
+                mask,
+                0.0,
+                causal,
+            )),
+        }
+    }
+
+    fn interpolate_backward(
+        _x: AutodiffTensor<B, 4>,
+        _grad: AutodiffTensor<B, 4>,
+        _output_size: [usize; 2],
+        _mode: InterpolateMode,
+    ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive<4> {
+        panic!("Can't differentiate interpolate backward.");
+    }
 }
 
 #[derive(Debug)]
@@ -532,6 +532,43 @@ impl<B: Backend> FloatTensorOps<Self> for Autodiff<B> {
         }
     }
 
+    fn float_expand<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        shape: Shape<D>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Expand;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Expand {
+            type State = Shape<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let shape_original = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let shape_grad = B::float_shape(&grad);
+                    let mut grad = grad;
+
+                    for i in 0..D {
+                        if shape_original.dims[i] == 1 && shape_grad.dims[i] != 1 {
+                            grad = B::float_sum_dim(grad, i);
+                        }
+                    }
+
+                    grad
+                });
+            }
+        }
+
+        match Expand.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(
+                B::float_shape(&tensor.primitive),
+                B::float_expand(tensor.primitive, shape),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_expand(tensor.primitive, shape)),
+        }
+    }
+
     fn float_gather<const D: usize>(
         dim: usize,
         tensor: FloatTensor<Self, D>,
@@ -579,24 +616,19 @@ impl<B: Backend> FloatTensorOps<Self> for Autodiff<B> {
         struct Scatter;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Scatter {
-            type State = (usize, IntTensor<B, D>, Shape<D>, Shape<D>, B::Device);
+            type State = (usize, IntTensor<B, D>);
 
             fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
-                let (dim, indices, shape_lhs, shape_rhs, device) = ops.state;
-                let [indices_4lhs, indices_4rhs] = duplicate(&ops.parents, Some(indices));
+                let (dim, indices) = ops.state;
 
                 binary::<B, D, D, D, _, _>(
                     ops.parents,
                     ops.node,
                     grads,
-                    |grad| {
-                        let zeros = B::float_zeros(shape_lhs, &device);
-                        B::float_scatter(dim, grad, indices_4lhs.unwrap(), zeros)
-                    },
-                    |grad| {
-                        let zeros = B::float_zeros(shape_rhs, &device);
-                        B::float_scatter(dim, zeros, indices_4rhs.unwrap(), grad)
-                    },
+                    // The values are added to the tensor, so its gradient is left unchanged.
+                    |grad| grad,
+                    // Each value only contributes to the position it was scattered to.
+                    |grad| B::float_gather(dim, grad, indices),
                 );
             }
         }
@@ -606,13 +638,7 @@ impl<B: Backend> FloatTensorOps<Self> for Autodiff<B> {
             .stateful()
         {
             OpsKind::Tracked(prep) => prep.finish(
-                (
-                    dim,
-                    indices.clone(),
-                    B::float_shape(&tensor.primitive),
-                    B::float_shape(&value.primitive),
-                    B::float_device(&value.primitive),
-                ),
+                (dim, indices.clone()),
                 B::float_scatter(dim, tensor.primitive, indices, value.primitive),
             ),
             OpsKind::UnTracked(prep) => prep.finish(B::float_scatter(
@@ -674,24 +700,19 @@ impl<B: Backend> FloatTensorOps<Self> for Autodiff<B> {
         struct IndexSelectDimAssign<const D: usize>;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for IndexSelectDimAssign<D> {
-            type State = (usize, IntTensor<B, 1>, Shape<D>, Shape<D>, B::Device);
+            type State = (usize, IntTensor<B, 1>);
 
             fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
-                let (dim, indices, shape_lhs, shape_rhs, device) = ops.state;
-                let [indices_4lhs, indices_4rhs] = duplicate(&ops.parents, Some(indices));
+                let (dim, indices) = ops.state;
 
                 binary::<B, D, D, D, _, _>(
                     ops.parents,
                     ops.node,
                     grads,
-                    |grad| {
-                        let zeros = B::float_zeros(shape_lhs, &device);
-                        B::float_select_assign(grad, dim, indices_4lhs.unwrap(), zeros)
-                    },
-                    |grad| {
-                        let zeros = B::float_zeros(shape_rhs, &device);
-                        B::float_select_assign(zeros, dim, indices_4rhs.unwrap(), grad)
-                    },
+                    |grad| grad,
+                    // Each value is added to the element at its index, which is where its
+                    // gradient is taken from.
+                    |grad| B::float_select(grad, dim, indices),
                 );
             }
         }
@@ -701,13 +722,7 @@ impl<B: Backend> FloatTensorOps<Self> for Autodiff<B> {
             .stateful()
         {
             OpsKind::Tracked(prep) => prep.finish(
-                (
-                    dim,
-                    indices.clone(),
-                    B::float_shape(&tensor.primitive),
-                    B::float_shape(&value.primitive),
-                    B::float_device(&value.primitive),
-                ),
+                (dim, indices.clone()),
                 B::float_select_assign(tensor.primitive, dim, indices, value.primitive),
             ),
             OpsKind::UnTracked(prep) => prep.finish(B::float_select_assign(
@@ -1422,6 +1437,34 @@ impl<B: Backend> FloatTensorOps<Self> for Autodiff<B> {
         }
     }
 
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Erfinv;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Erfinv {
+            type State = B::FloatTensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // d/dx erfinv(x) = sqrt(pi) / 2 * exp(erfinv(x)^2)
+                    let exponent = B::float_powf_scalar(ops.state, 2.0);
+                    let factor = (std::f64::consts::PI.sqrt() / 2.0).elem();
+                    let value = B::float_mul_scalar(B::float_exp(exponent), factor);
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Erfinv.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_erfinv(tensor.primitive);
+                prep.finish(output.clone(), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_erfinv(tensor.primitive)),
+        }
+    }
+
     fn float_cat<const D: usize>(
         tensors: Vec<FloatTensor<Self, D>>,
         dim: usize,
@@ -1558,6 +1601,519 @@ impl<B: Backend> FloatTensorOps<Self> for Autodiff<B> {
         }
     }
 
+    fn float_cumsum<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct CumSum;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for CumSum {
+            type State = usize;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let dim = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    reverse_cumsum::<B, D>(grad, dim)
+                });
+            }
+        }
+
+        match CumSum.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(dim, B::float_cumsum(tensor.primitive, dim)),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cumsum(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_cumprod<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct CumProd;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for CumProd {
+            type State = (usize, B::FloatTensorPrimitive<D>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (dim, input) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // The gradient of each element is the sum of the following gradients
+                    // weighted by the product of all the other elements that were accumulated,
+                    // which can't be computed by dividing the output by the element when it
+                    // is zero. Zeros are replaced by ones, and only the first zero of each lane
+                    // receives a gradient, since any product including a second zero vanishes.
+                    let zeros = B::float_equal_elem(input.clone(), 0.elem());
+                    let input = B::float_mask_fill(input, zeros.clone(), 1.elem());
+                    let num_zeros = B::float_cumsum(B::bool_into_float(zeros.clone()), dim);
+                    let output = B::float_cumprod(input.clone(), dim);
+                    let grad = B::float_mul(grad, output);
+
+                    let before_zero = B::float_equal_elem(num_zeros.clone(), 0.elem());
+                    let grad_before_zero =
+                        B::float_mul(grad.clone(), B::bool_into_float(before_zero));
+                    let grad_before_zero =
+                        B::float_div(reverse_cumsum::<B, D>(grad_before_zero, dim), input);
+
+                    let first_zero = B::float_equal_elem(num_zeros, 1.elem());
+                    let grad_first_zero =
+                        B::float_mul(grad, B::bool_into_float(first_zero.clone()));
+                    let grad_first_zero = B::float_mask_fill(
+                        reverse_cumsum::<B, D>(grad_first_zero, dim),
+                        B::bool_not(first_zero),
+                        0.elem(),
+                    );
+
+                    B::float_mask_where(grad_before_zero, zeros, grad_first_zero)
+                });
+            }
+        }
+
+        match CumProd.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (dim, tensor.primitive.clone()),
+                B::float_cumprod(tensor.primitive, dim),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cumprod(tensor.primitive, dim)),
+        }
+    }
+
+    fn float_logcumsumexp<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct LogCumSumExp;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for LogCumSumExp {
+            type State = (
+                usize,
+                B::FloatTensorPrimitive<D>,
+                B::FloatTensorPrimitive<D>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (dim, input, output) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // The gradient of each element is the sum of the following gradients
+                    // weighted by the softmax of the element over each of the following
+                    // prefixes, `exp(x_j - y_i)`. The sum is accumulated in log space from the
+                    // end of the dimension, with the positive and negative gradients apart since
+                    // their logarithm isn't defined together.
+                    let weighted = |grad: B::FloatTensorPrimitive<D>| {
+                        let log_grad = B::float_sub(B::float_log(grad), output.clone());
+                        let log_sum = flip::<B, D>(
+                            B::float_logcumsumexp(flip::<B, D>(log_grad, dim), dim),
+                            dim,
+                        );
+
+                        B::float_exp(B::float_add(log_sum, input.clone()))
+                    };
+
+                    let positive = B::float_clamp_min(grad.clone(), 0.elem());
+                    let negative = B::float_clamp_min(B::float_neg(grad), 0.elem());
+
+                    B::float_sub(weighted(positive), weighted(negative))
+                });
+            }
+        }
+
+        let output = B::float_logcumsumexp(tensor.primitive.clone(), dim);
+
+        match LogCumSumExp
+            .prepare([tensor.node], [tensor.graph])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((dim, tensor.primitive, output.clone()), output),
+            OpsKind::UnTracked(prep) => prep.finish(output),
+        }
+    }
+
+    fn float_fft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        #[derive(Debug)]
+        struct Fft;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Fft {
+            type State = (usize, bool);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (dim, inverse) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // The gradient is the conjugate transpose of the Fourier matrix applied to
+                    // the gradient, which is N times the inverse transform, or the forward
+                    // transform divided by N for the inverse transform.
+                    let (real, imag) = unpack::<B, D>(grad, dim);
+                    let size = B::float_shape(&real).dims[dim];
+                    let (real, imag) = B::float_fft(real, imag, dim, !inverse);
+                    let scale = match inverse {
+                        true => 1.0 / size as f32,
+                        false => size as f32,
+                    };
+
+                    B::float_cat(
+                        vec![
+                            B::float_mul_scalar(real, scale.elem()),
+                            B::float_mul_scalar(imag, scale.elem()),
+                        ],
+                        dim,
+                    )
+                });
+            }
+        }
+
+        fn unpack<B: Backend, const D: usize>(
+            tensor: B::FloatTensorPrimitive<D>,
+            dim: usize,
+        ) -> (B::FloatTensorPrimitive<D>, B::FloatTensorPrimitive<D>) {
+            let size = B::float_shape(&tensor).dims[dim] / 2;
+
+            (
+                B::float_narrow(tensor.clone(), dim, 0, size),
+                B::float_narrow(tensor, dim, size, size),
+            )
+        }
+
+        fn forward<B: Backend, const D: usize>(
+            tensor: B::FloatTensorPrimitive<D>,
+            dim: usize,
+            inverse: bool,
+        ) -> B::FloatTensorPrimitive<D> {
+            let (real, imag) = unpack::<B, D>(tensor, dim);
+            let (real, imag) = B::float_fft(real, imag, dim, inverse);
+
+            B::float_cat(vec![real, imag], dim)
+        }
+
+        // The real and imaginary parts are concatenated along the dimension of the transform, so
+        // that both outputs are tracked by a single node.
+        let size = B::float_shape(&real.primitive).dims[dim];
+        let packed = Self::float_cat(vec![real, imag], dim);
+
+        let output = match Fft.prepare([packed.node], [packed.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (dim, inverse),
+                forward::<B, D>(packed.primitive, dim, inverse),
+            ),
+            OpsKind::UnTracked(prep) => {
+                prep.finish(forward::<B, D>(packed.primitive, dim, inverse))
+            }
+        };
+
+        (
+            Self::float_narrow(output.clone(), dim, 0, size),
+            Self::float_narrow(output, dim, size, size),
+        )
+    }
+
+    fn float_svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        full_matrices: bool,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        #[derive(Debug)]
+        struct Svd;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Svd {
+            type State = (
+                B::FloatTensorPrimitive<D>,
+                B::FloatTensorPrimitive<D>,
+                B::FloatTensorPrimitive<D>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (u, s, v) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    svd_backward::<B, D>(grad, u, s, v)
+                });
+            }
+        }
+
+        let dims = B::float_shape(&tensor.primitive).dims;
+        let [m, n] = [dims[D - 2], dims[D - 1]];
+        let k = usize::min(m, n);
+        let (u, s, vh) = B::float_svd(tensor.primitive, full_matrices);
+        let u_thin = B::float_narrow(u.clone(), D - 1, 0, k);
+        let v_thin = B::float_swap_dims(B::float_narrow(vh.clone(), D - 2, 0, k), D - 2, D - 1);
+
+        // The singular vectors and values are concatenated along the rows, `k` columns each, so
+        // that all the outputs are tracked by a single node.
+        let packed = B::float_cat(vec![u_thin.clone(), s.clone(), v_thin.clone()], D - 2);
+        let output = match Svd.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish((u_thin, s, v_thin), packed),
+            OpsKind::UnTracked(prep) => prep.finish(packed),
+        };
+
+        let mut u_output = Self::float_narrow(output.clone(), D - 2, 0, m);
+        let s_output = Self::float_narrow(output.clone(), D - 2, m, 1);
+        let v_output = Self::float_narrow(output, D - 2, m + 1, n);
+        let mut vh_output = Self::float_swap_dims(v_output, D - 2, D - 1);
+
+        // The extra singular vectors of the full matrices are constants, as they don't depend
+        // continuously on the input.
+        if full_matrices && m > k {
+            let extra = AutodiffTensor::new(B::float_narrow(u, D - 1, k, m - k));
+            u_output = Self::float_cat(vec![u_output, extra], D - 1);
+        }
+        if full_matrices && n > k {
+            let extra = AutodiffTensor::new(B::float_narrow(vh, D - 2, k, n - k));
+            vh_output = Self::float_cat(vec![vh_output, extra], D - 2);
+        }
+
+        (u_output, s_output, vh_output)
+    }
+
+    fn float_eigh<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        #[derive(Debug)]
+        struct Eigh;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Eigh {
+            type State = (B::FloatTensorPrimitive<D>, B::FloatTensorPrimitive<D>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (vectors, values) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    eigh_backward::<B, D>(grad, vectors, values)
+                });
+            }
+        }
+
+        let n = B::float_shape(&tensor.primitive).dims[D - 1];
+        let (vectors, values) = B::float_eigh(tensor.primitive);
+
+        // The eigenvectors and eigenvalues are concatenated along the rows so that both outputs
+        // are tracked by a single node.
+        let packed = B::float_cat(vec![vectors.clone(), values.clone()], D - 2);
+        let output = match Eigh.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish((vectors, values), packed),
+            OpsKind::UnTracked(prep) => prep.finish(packed),
+        };
+
+        (
+            Self::float_narrow(output.clone(), D - 2, 0, n),
+            Self::float_narrow(output, D - 2, n, 1),
+        )
+    }
+
+    fn float_solve<const D: usize>(
+        a: FloatTensor<Self, D>,
+        b: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Solve;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for Solve {
+            type State = (
+                B::FloatTensorPrimitive<D>,
+                Option<B::FloatTensorPrimitive<D>>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let [node_a, node_b] = ops.parents;
+                let grad = grads.consume::<B, D>(&ops.node);
+                let (a, x) = ops.state;
+
+                // The gradient of the right-hand sides is also needed by the coefficients, as
+                // `grad_A = -grad_B X^T` from the implicit function theorem.
+                let grad_b = B::float_solve(B::float_transpose(a), grad);
+
+                if let Some(node) = node_a {
+                    let x = B::float_transpose(x.unwrap());
+                    let grad_a = B::float_neg(B::float_matmul(grad_b.clone(), x));
+                    grads.register::<B, D>(node, grad_a)
+                }
+                if let Some(node) = node_b {
+                    grads.register::<B, D>(node, grad_b)
+                }
+            }
+        }
+
+        let a_tracked = a.is_tracked();
+
+        match Solve
+            .prepare([a.node, b.node], [a.graph, b.graph])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let x = B::float_solve(a.primitive.clone(), b.primitive);
+                prep.finish((a.primitive, a_tracked.then(|| x.clone())), x)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_solve(a.primitive, b.primitive)),
+        }
+    }
+
+    fn float_cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Cholesky;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Cholesky {
+            type State = B::FloatTensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let factor = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    cholesky_backward::<B, D>(grad, factor)
+                });
+            }
+        }
+
+        match Cholesky.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let factor = B::float_cholesky(tensor.primitive);
+                prep.finish(factor.clone(), factor)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_cholesky(tensor.primitive)),
+        }
+    }
+
+    fn float_sort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<Self, D> {
+        Self::float_sort_with_indices(tensor, dim, descending).0
+    }
+
+    fn float_roll<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Roll;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Roll {
+            type State = (Vec<i64>, Vec<usize>);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (shifts, dims) = ops.state;
+                // Rolling is a permutation, which is undone by rolling in the opposite direction.
+                let shifts: Vec<_> = shifts.into_iter().map(|shift| -shift).collect();
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_roll(grad, &shifts, &dims)
+                });
+            }
+        }
+
+        match Roll.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => prep.finish(
+                (shifts.to_vec(), dims.to_vec()),
+                B::float_roll(tensor.primitive, shifts, dims),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_roll(tensor.primitive, shifts, dims)),
+        }
+    }
+
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        #[derive(Debug)]
+        struct Sort;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Sort {
+            type State = (usize, IntTensor<B, D>, Shape<D>, B::Device);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (dim, indices, shape, device) = ops.state;
+
+                // Each sorted element receives the gradient of its original position.
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let zeros = B::float_zeros(shape, &device);
+                    B::float_scatter(dim, zeros, indices, grad)
+                });
+            }
+        }
+
+        match Sort.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let device = B::float_device(&tensor.primitive);
+                let (tensor, indices) =
+                    B::float_sort_with_indices(tensor.primitive, dim, descending);
+                let tensor = prep.finish((dim, indices.clone(), shape, device), tensor);
+
+                (tensor, indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, indices) =
+                    B::float_sort_with_indices(tensor.primitive, dim, descending);
+                let tensor = prep.finish(tensor);
+
+                (tensor, indices)
+            }
+        }
+    }
+
+    fn float_argsort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::float_argsort(tensor.primitive, dim, descending)
+    }
+
+    fn float_nonzero<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<B, 2> {
+        B::float_nonzero(tensor.primitive)
+    }
+
+    fn float_topk<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        k: usize,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        #[derive(Debug)]
+        struct TopK;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for TopK {
+            type State = (usize, IntTensor<B, D>, Shape<D>, B::Device);
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                let (dim, indices, shape, device) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let zeros = B::float_zeros(shape, &device);
+                    B::float_scatter(dim, zeros, indices, grad)
+                });
+            }
+        }
+
+        match TopK.prepare([tensor.node], [tensor.graph]).stateful() {
+            OpsKind::Tracked(prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let device = B::float_device(&tensor.primitive);
+                let (tensor, indices) = B::float_topk(tensor.primitive, k, dim);
+                let tensor = prep.finish((dim, indices.clone(), shape, device), tensor);
+
+                (tensor, indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let (tensor, indices) = B::float_topk(tensor.primitive, k, dim);
+                let tensor = prep.finish(tensor);
+
+                (tensor, indices)
+            }
+        }
+    }
+
     fn float_into_int<const D: usize>(
         tensor: FloatTensor<Self, D>,
     ) -> <Autodiff<B> as Backend>::IntTensorPrimitive<D> {
@@ -1674,3 +2230,163 @@ impl<const D: usize> BinaryOpsBroadcast<D> {
         }
     }
 }
+
+/// Computes the gradient of the singular value decomposition `A = U S V^T` from the gradients of
+/// the thin `U`, `S` and `V` packed along the rows.
+///
+/// `dA = U [(skew(U^T dU) / E) S + S (skew(V^T dV) / E) + diag(dS)] V^T
+///     + (I - U U^T) dU S^-1 V^T + U S^-1 dV^T (I - V V^T)`
+///
+/// where `skew(X) = X - X^T` and `E_ij = s_j^2 - s_i^2`.
+fn svd_backward<B: Backend, const D: usize>(
+    grad: B::FloatTensorPrimitive<D>,
+    u: B::FloatTensorPrimitive<D>,
+    s: B::FloatTensorPrimitive<D>,
+    v: B::FloatTensorPrimitive<D>,
+) -> B::FloatTensorPrimitive<D> {
+    let [grad, u, s, v] = [grad, u, s, v].map(Tensor::<B, D>::from_primitive);
+    let [m, n, k] = [u.dims()[D - 2], v.dims()[D - 2], s.dims()[D - 1]];
+    let device = u.device();
+    let transpose = |tensor: Tensor<B, D>| tensor.swap_dims(D - 2, D - 1);
+    let skew = |tensor: Tensor<B, D>| tensor.clone().sub(transpose(tensor));
+
+    let grad_u = grad.clone().narrow(D - 2, 0, m);
+    let grad_s = grad.clone().narrow(D - 2, m, 1);
+    let grad_v = grad.narrow(D - 2, m + 1, n);
+
+    let identity = (0..k * k)
+        .map(|index| if index % (k + 1) == 0 { 1.0 } else { 0.0 })
+        .collect::<Vec<f32>>();
+    let identity =
+        Tensor::<B, 2>::from_data(Data::new(identity, Shape::new([k, k])).convert(), &device)
+            .unsqueeze::<D>();
+
+    // The gaps between the squared singular values are infinite on the diagonal and for repeated
+    // singular values, where the gradient of the singular vectors is undefined, so that the
+    // gradient of the singular values alone stays finite.
+    let squared = s.clone().powf_scalar(2.0);
+    let gap = squared.clone().sub(transpose(squared));
+    let gap = gap.clone().mask_fill(gap.equal_elem(0.0), f32::INFINITY);
+
+    let u_grad_u = transpose(u.clone()).matmul(grad_u.clone());
+    let v_grad_v = transpose(v.clone()).matmul(grad_v.clone());
+    let inner = skew(u_grad_u.clone())
+        .div(gap.clone())
+        .mul(s.clone())
+        .add(transpose(s.clone()).mul(skew(v_grad_v.clone()).div(gap)))
+        .add(identity.mul(grad_s));
+    let mut grad = u.clone().matmul(inner).matmul(transpose(v.clone()));
+
+    // The projections on the orthogonal complements of the singular vectors vanish for square
+    // matrices.
+    if m > k {
+        let grad_u = grad_u.sub(u.clone().matmul(u_grad_u)).div(s.clone());
+        grad = grad.add(grad_u.matmul(transpose(v.clone())));
+    }
+    if n > k {
+        let grad_v = grad_v.sub(v.matmul(v_grad_v)).div(s);
+        grad = grad.add(u.matmul(transpose(grad_v)));
+    }
+
+    grad.into_primitive()
+}
+
+/// Computes the gradient of the symmetric eigendecomposition `A = V diag(L) V^T` from the
+/// gradients of `V` and `L` packed along the rows, following the Daleckiĭ-Kreĭn formula.
+///
+/// `dA = V [diag(dL) + (skew(V^T dV) / 2) / E] V^T`
+///
+/// where `skew(X) = X - X^T` and `E_ij = l_j - l_i`.
+fn eigh_backward<B: Backend, const D: usize>(
+    grad: B::FloatTensorPrimitive<D>,
+    vectors: B::FloatTensorPrimitive<D>,
+    values: B::FloatTensorPrimitive<D>,
+) -> B::FloatTensorPrimitive<D> {
+    let [grad, vectors, values] = [grad, vectors, values].map(Tensor::<B, D>::from_primitive);
+    let n = values.dims()[D - 1];
+    let device = values.device();
+    let transpose = |tensor: Tensor<B, D>| tensor.swap_dims(D - 2, D - 1);
+
+    let grad_vectors = grad.clone().narrow(D - 2, 0, n);
+    let grad_values = grad.narrow(D - 2, n, 1);
+
+    let identity = (0..n * n)
+        .map(|index| if index % (n + 1) == 0 { 1.0 } else { 0.0 })
+        .collect::<Vec<f32>>();
+    let identity =
+        Tensor::<B, 2>::from_data(Data::new(identity, Shape::new([n, n])).convert(), &device)
+            .unsqueeze::<D>();
+
+    // The gaps between the eigenvalues are infinite on the diagonal and for repeated eigenvalues,
+    // where the gradient of the eigenvectors is undefined, so that the gradient of the
+    // eigenvalues alone stays finite.
+    let gap = values.clone().sub(transpose(values));
+    let gap = gap.clone().mask_fill(gap.equal_elem(0.0), f32::INFINITY);
+
+    let projected = transpose(vectors.clone()).matmul(grad_vectors);
+    let skew = projected.clone().sub(transpose(projected)).div_scalar(2.0);
+    let inner = skew.div(gap).add(identity.mul(grad_values));
+
+    vectors
+        .clone()
+        .matmul(inner)
+        .matmul(transpose(vectors))
+        .into_primitive()
+}
+
+/// Computes the gradient of the Cholesky decomposition `A = L L^T`, as derived by Iain Murray in
+/// "Differentiation of the Cholesky decomposition".
+///
+/// `dA = L^-T Φ(L^T dL) L^-1`
+///
+/// where `Φ` takes the lower triangle with half the diagonal, and is then symmetrized since only
+/// the lower triangle of `A` is read.
+fn cholesky_backward<B: Backend, const D: usize>(
+    grad: B::FloatTensorPrimitive<D>,
+    factor: B::FloatTensorPrimitive<D>,
+) -> B::FloatTensorPrimitive<D> {
+    let [grad, factor] = [grad, factor].map(Tensor::<B, D>::from_primitive);
+    let transpose = |tensor: Tensor<B, D>| tensor.swap_dims(D - 2, D - 1);
+
+    // The upper triangle of `L^T dL` only depends on the upper triangle of `dL`, which doesn't
+    // affect the factor.
+    let phi = transpose(factor.clone()).matmul(grad).tril(0);
+    let symmetric = phi.clone().add(transpose(phi.tril(-1))).div_scalar(2.0);
+
+    // `L^-T S L^-1`, with the right division done by transposing the symmetric result.
+    let left = B::float_solve(
+        transpose(factor.clone()).into_primitive(),
+        symmetric.into_primitive(),
+    );
+    let left = transpose(Tensor::from_primitive(left));
+
+    transpose(Tensor::from_primitive(B::float_solve(
+        transpose(factor).into_primitive(),
+        left.into_primitive(),
+    )))
+    .into_primitive()
+}
+
+/// Computes the cumulative sum starting from the last element of the given dimension.
+fn reverse_cumsum<B: Backend, const D: usize>(
+    tensor: B::FloatTensorPrimitive<D>,
+    dim: usize,
+) -> B::FloatTensorPrimitive<D> {
+    let tensor = flip::<B, D>(tensor, dim);
+    let tensor = B::float_cumsum(tensor, dim);
+
+    flip::<B, D>(tensor, dim)
+}
+
+/// Reverses the order of the elements of the given dimension.
+fn flip<B: Backend, const D: usize>(
+    tensor: B::FloatTensorPrimitive<D>,
+    dim: usize,
+) -> B::FloatTensorPrimitive<D> {
+    let size = B::float_shape(&tensor).dims[dim];
+    let device = B::float_device(&tensor);
+    let indices: Vec<i64> = (0..size as i64).rev().collect();
+    let indices = B::int_from_data(Data::new(indices, Shape::new([size])).convert(), &device);
+
+    B::float_select(tensor, dim, indices)
+}
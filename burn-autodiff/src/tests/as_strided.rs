@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(ad_as_strided)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_as_strided() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data([1.0, 10.0], &device).require_grad();
+
+        // The overlapping windows [1, 2], [2, 3] and [3, 4].
+        let windows: TestAutodiffTensor<2> = tensor_1.clone().as_strided([3, 2], [1, 1], 0);
+        let tensor_3 = windows.matmul(tensor_2.clone().reshape([2, 1]));
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        // Each element accumulates the gradient of every window reading it.
+        assert_eq!(grad_1.to_data(), Data::from([[1.0, 11.0], [11.0, 10.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([6.0, 9.0]));
+    }
+}
@@ -0,0 +1,48 @@
+#[burn_tensor_testgen::testgen(ad_cdist)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_cdist_euclidean_with_zero_distance() {
+        let device = Default::default();
+        let x1 =
+            TestAutodiffTensor::<3>::from_data([[[0.0, 0.0], [1.0, 1.0], [2.0, -1.0]]], &device)
+                .require_grad();
+        let x2 =
+            TestAutodiffTensor::<3>::from_data([[[3.0, 4.0], [1.0, 1.0]]], &device).require_grad();
+
+        let grads = x1.clone().cdist(x2.clone(), 2.0).sum().backward();
+
+        let grad_1 = x1.grad(&grads).unwrap();
+        let grad_2 = x2.grad(&grads).unwrap();
+        grad_1.to_data().assert_approx_eq(
+            &Data::from([[[-1.3071, -1.5071], [-0.5547, -0.8321], [0.2511, -1.875]]]),
+            3,
+        );
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[[1.3508, 2.6126], [0.2599, 1.6015]]]), 3);
+    }
+
+    #[test]
+    fn should_diff_cdist_manhattan() {
+        let device = Default::default();
+        let x1 =
+            TestAutodiffTensor::<3>::from_data([[[0.0, 0.0], [1.0, 1.0], [2.0, -1.0]]], &device)
+                .require_grad();
+        let x2 =
+            TestAutodiffTensor::<3>::from_data([[[3.0, 4.0], [1.0, 1.0]]], &device).require_grad();
+
+        let grads = x1.clone().cdist(x2.clone(), 1.0).sum().backward();
+
+        let grad_1 = x1.grad(&grads).unwrap();
+        let grad_2 = x2.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[[-2.0, -2.0], [-1.0, -1.0], [0.0, -2.0]]]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[[3.0, 3.0], [0.0, 2.0]]]), 3);
+    }
+}
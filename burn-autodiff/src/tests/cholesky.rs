@@ -0,0 +1,93 @@
+#[burn_tensor_testgen::testgen(ad_cholesky)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    /// Computes the gradient of the function with central finite differences.
+    fn finite_differences<F>(data: [[f32; 3]; 3], function: F) -> Data<f32, 2>
+    where
+        F: Fn(Tensor<TestBackend, 2>) -> f32,
+    {
+        let epsilon = 1e-2;
+        let mut grad = Vec::new();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let mut forward = data;
+                let mut backward = data;
+                forward[i][j] += epsilon;
+                backward[i][j] -= epsilon;
+
+                let forward = function(TestTensor::from(forward));
+                let backward = function(TestTensor::from(backward));
+                grad.push((forward - backward) / (2.0 * epsilon));
+            }
+        }
+
+        Data::new(grad, [3, 3].into())
+    }
+
+    #[test]
+    fn should_diff_cholesky() {
+        let data = [[3.0, 1.0, 0.5], [0.0, 2.5, -1.0], [0.5, 0.0, 2.0]];
+        let weights = [[1.0, -2.0, 3.0], [0.5, 4.0, -1.0], [2.0, 0.0, 1.5]];
+        let tensor = TestAutodiffTensor::from_floats(data, &Default::default()).require_grad();
+
+        // The input is symmetrized so that all of its elements are used.
+        let factor = tensor.clone().add(tensor.clone().transpose()).cholesky();
+        let grads = factor
+            .mul(TestAutodiffTensor::from_floats(
+                weights,
+                &Default::default(),
+            ))
+            .sum()
+            .backward();
+
+        let expected = finite_differences(data, |tensor| {
+            tensor
+                .clone()
+                .add(tensor.transpose())
+                .cholesky()
+                .mul(TestTensor::from(weights))
+                .sum()
+                .into_scalar()
+        });
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected, 2);
+    }
+
+    #[test]
+    fn should_diff_cholesky_solve() {
+        let data = [[3.0, 1.0, 0.5], [0.0, 2.5, -1.0], [0.5, 0.0, 2.0]];
+        let b = [[1.0], [-2.0], [0.5]];
+        let tensor = TestAutodiffTensor::from_floats(data, &Default::default()).require_grad();
+
+        let x = tensor
+            .clone()
+            .add(tensor.clone().transpose())
+            .cholesky()
+            .cholesky_solve(
+                TestAutodiffTensor::from_floats(b, &Default::default()),
+                false,
+            );
+        let grads = x.sum().backward();
+
+        let expected = finite_differences(data, |tensor| {
+            tensor
+                .clone()
+                .add(tensor.transpose())
+                .cholesky()
+                .cholesky_solve(TestTensor::from(b), false)
+                .sum()
+                .into_scalar()
+        });
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected, 2);
+    }
+}
@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(ad_cosine_similarity)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_pairwise_cosine_similarity() {
+        let device = Default::default();
+        let x1 =
+            TestAutodiffTensor::<2>::from_data([[1.0, 0.0], [3.0, 4.0]], &device).require_grad();
+        let x2 = TestAutodiffTensor::<2>::from_data([[0.0, 2.0]], &device).require_grad();
+
+        let grads = x1
+            .clone()
+            .pairwise_cosine_similarity(x2.clone())
+            .sum()
+            .backward();
+
+        // d/dx (x.y / (|x| |y|)) = y / (|x| |y|) - (x.y) x / (|x|^3 |y|)
+        let grad_1 = x1.grad(&grads).unwrap();
+        let grad_2 = x2.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 1.0], [-0.096, 0.072]]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.8, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_cosine_similarity_matrix_orthogonally_to_the_rows() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(
+            [[1.0, -2.0, 0.5], [0.3, 0.1, 2.0], [2.0, 1.0, -1.0]],
+            &device,
+        )
+        .require_grad();
+        let weights = TestAutodiffTensor::<2>::from_data(
+            [[0.0, 1.0, -2.0], [1.0, 0.0, 3.0], [-2.0, 3.0, 0.0]],
+            &device,
+        );
+
+        let grads = tensor
+            .clone()
+            .cosine_similarity_matrix()
+            .mul(weights)
+            .sum()
+            .backward();
+
+        // The similarities don't change when a row is scaled, so the gradient of each row is
+        // orthogonal to it.
+        let grad = tensor.grad(&grads).unwrap();
+        let products = grad.clone().mul(tensor.inner()).sum_dim(1);
+        products
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0], [0.0], [0.0]]), 4);
+        assert!(grad.abs().sum().into_scalar() > 0.0);
+    }
+}
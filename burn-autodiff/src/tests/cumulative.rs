@@ -0,0 +1,98 @@
+#[burn_tensor_testgen::testgen(ad_cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_cumsum() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device)
+            .require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [1.0, 1.0, 1.0]], &device);
+
+        let tensor_2 = tensor_1.clone().cumsum(1);
+        let grads = tensor_2.mul(weights).backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[6.0, 5.0, 3.0], [3.0, 2.0, 1.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_cumprod() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device)
+            .require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [1.0, 1.0, 1.0]], &device);
+
+        let tensor_2 = tensor_1.clone().cumprod(1);
+        let grads = tensor_2.mul(weights).backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[23.0, 11.0, 6.0], [36.0, 28.0, 20.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_cumprod_with_zeros() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[2.0, 0.0, 3.0, 0.0, 5.0]], &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().cumprod(1);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 8.0, 0.0, 0.0, 0.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_logcumsumexp_like_naive() {
+        let device = Default::default();
+        let data = Data::from([[0.5, -1.0, 2.0], [3.0, 1.5, -2.0]]);
+        let weights = Data::from([[1.0, -2.0, 3.0], [-1.0, 0.5, 2.0]]);
+        let tensor_1 = TestAutodiffTensor::from_data(data.clone(), &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data, &device).require_grad();
+
+        let weights = TestAutodiffTensor::from_data(weights, &device);
+        let grads_1 = tensor_1
+            .clone()
+            .logcumsumexp(1)
+            .mul(weights.clone())
+            .backward();
+        let grads_2 = tensor_2
+            .clone()
+            .exp()
+            .cumsum(1)
+            .log()
+            .mul(weights)
+            .backward();
+
+        let grad_1 = tensor_1.grad(&grads_1).unwrap();
+        let grad_2 = tensor_2.grad(&grads_2).unwrap();
+
+        grad_1.to_data().assert_approx_eq(&grad_2.to_data(), 4);
+    }
+
+    #[test]
+    fn should_diff_logcumsumexp_with_large_values() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1000.0, 1000.0, -1000.0]], &device).require_grad();
+
+        let grads = tensor_1.clone().logcumsumexp(1).backward();
+
+        // d/dx_j sum_i y_i = sum_{i >= j} exp(x_j - y_i)
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 1.0, 0.0]]), 4);
+    }
+}
@@ -0,0 +1,91 @@
+#[burn_tensor_testgen::testgen(ad_eigh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    /// Computes the gradient of the function with central finite differences.
+    fn finite_differences<F>(data: [[f32; 3]; 3], function: F) -> Data<f32, 2>
+    where
+        F: Fn(Tensor<TestBackend, 2>) -> f32,
+    {
+        let epsilon = 1e-2;
+        let mut grad = Vec::new();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let mut forward = data;
+                let mut backward = data;
+                forward[i][j] += epsilon;
+                backward[i][j] -= epsilon;
+
+                let forward = function(TestTensor::from(forward));
+                let backward = function(TestTensor::from(backward));
+                grad.push((forward - backward) / (2.0 * epsilon));
+            }
+        }
+
+        Data::new(grad, [3, 3].into())
+    }
+
+    #[test]
+    fn should_diff_eigenvalues() {
+        let data = [[2.0, 1.0, 0.5], [0.0, -1.0, 3.0], [1.0, 0.5, 1.0]];
+        let tensor = TestAutodiffTensor::from_floats(data, &Default::default()).require_grad();
+        let weights = [2.0, -1.0, 0.5];
+
+        // The input is symmetrized so that all of its elements are used.
+        let (_vectors, values) = tensor.clone().add(tensor.clone().transpose()).eigh::<1>();
+        let grads = values
+            .mul(Tensor::from_floats(weights, &Default::default()))
+            .sum()
+            .backward();
+
+        let expected = finite_differences(data, |tensor| {
+            let (_vectors, values) = tensor.clone().add(tensor.transpose()).eigh::<1>();
+            let values = values.into_data().value;
+            values[0] * weights[0] + values[1] * weights[1] + values[2] * weights[2]
+        });
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected, 2);
+    }
+
+    #[test]
+    fn should_diff_eigenvectors() {
+        let data = [[2.0, 1.0, 0.5], [0.0, -1.0, 3.0], [1.0, 0.5, 1.0]];
+        let tensor = TestAutodiffTensor::from_floats(data, &Default::default()).require_grad();
+        let weights = [[1.0, -2.0, 3.0], [0.5, 4.0, -1.0], [2.0, 0.0, 1.5]];
+
+        // The projection on the first eigenvector doesn't depend on its sign.
+        let projection = |vectors: Tensor<_, 2>| {
+            let vector = vectors.narrow(1, 0, 1);
+            vector.clone().matmul(vector.transpose())
+        };
+        let (vectors, _values) = tensor.clone().add(tensor.clone().transpose()).eigh::<1>();
+        let grads = projection(vectors)
+            .mul(TestAutodiffTensor::from_floats(
+                weights,
+                &Default::default(),
+            ))
+            .sum()
+            .backward();
+
+        let expected = finite_differences(data, |tensor| {
+            let (vectors, _values) = tensor.clone().add(tensor.transpose()).eigh::<1>();
+            let vector = vectors.narrow(1, 0, 1);
+            vector
+                .clone()
+                .matmul(vector.transpose())
+                .mul(TestTensor::from(weights))
+                .sum()
+                .into_scalar()
+        });
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected, 2);
+    }
+}
@@ -0,0 +1,47 @@
+#[burn_tensor_testgen::testgen(ad_einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_diff_einsum_same_as_matmul() {
+        let device = Default::default();
+        let data_1 = Data::from([[1.0, 7.0], [2.0, 3.0]]);
+        let data_2 = Data::from([[4.0, 7.0], [2.0, 3.0]]);
+
+        let tensor_1 = TestAutodiffTensor::from_data(data_1.clone(), &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2.clone(), &device).require_grad();
+        let tensor_3 = TestAutodiffTensor::from_data(data_1, &device).require_grad();
+        let tensor_4 = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+
+        let output = Tensor::einsum::<2>("ij,jk->ik", vec![tensor_1.clone(), tensor_2.clone()]);
+        let expected = tensor_3.clone().matmul(tensor_4.clone());
+        let grads = output.backward();
+        let grads_expected = expected.backward();
+
+        assert_eq!(
+            tensor_1.grad(&grads).unwrap().into_data(),
+            tensor_3.grad(&grads_expected).unwrap().into_data()
+        );
+        assert_eq!(
+            tensor_2.grad(&grads).unwrap().into_data(),
+            tensor_4.grad(&grads_expected).unwrap().into_data()
+        );
+    }
+
+    #[test]
+    fn should_diff_einsum_trace() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let output = Tensor::einsum::<1>("ii->", vec![tensor_1.clone()]);
+        let grads = output.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, 1.0]]), 5);
+    }
+}
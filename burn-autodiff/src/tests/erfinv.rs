@@ -0,0 +1,18 @@
+#[burn_tensor_testgen::testgen(ad_erfinv)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_erfinv() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data([0.0, 0.5, -0.5], &device).require_grad();
+
+        let grads = tensor.clone().erfinv().sum().backward();
+
+        // sqrt(pi) / 2 * exp(erfinv(x)^2)
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([0.886_226_9, 1.112_585_9, 1.112_585_9]), 4);
+    }
+}
@@ -0,0 +1,38 @@
+#[burn_tensor_testgen::testgen(ad_expand)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_expand() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data([[1.0], [2.0]], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device)
+            .require_grad();
+
+        let tensor_3 = tensor_1.clone().expand([2, 3]).mul(tensor_2.clone());
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[6.0], [15.0]]));
+        assert_eq!(
+            grad_2.to_data(),
+            Data::from([[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]])
+        );
+    }
+
+    #[test]
+    fn should_diff_expand_multiple_dims() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data([[[1.0]], [[2.0]]], &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().expand([2, 3, 4]).mul_scalar(2.0);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[[24.0]], [[24.0]]]));
+    }
+}
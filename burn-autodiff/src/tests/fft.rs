@@ -0,0 +1,68 @@
+#[burn_tensor_testgen::testgen(ad_fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_fft() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<1>::from_data([1.0, 2.0, 3.0, 4.0], &device).require_grad();
+        let weights_real = TestAutodiffTensor::from_data([1.0, 2.0, 3.0, 4.0], &device);
+        let weights_imag = TestAutodiffTensor::from_data([0.5, -1.0, 2.0, 1.0], &device);
+
+        let (real, imag) = tensor.clone().fft(None, 0);
+        let loss = (real * weights_real).sum() + (imag * weights_imag).sum();
+        let grads = loss.backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([10.0, 0.0, -2.0, -4.0]), 3);
+    }
+
+    #[test]
+    fn should_diff_fft_complex() {
+        let device = Default::default();
+        let real = TestAutodiffTensor::<2>::from_data([[1.0, -1.0, 3.0]], &device).require_grad();
+        let imag = TestAutodiffTensor::<2>::from_data([[2.0, 0.5, -1.0]], &device).require_grad();
+        let weights_real = TestAutodiffTensor::from_data([[1.0, -2.0, 0.5]], &device);
+        let weights_imag = TestAutodiffTensor::from_data([[2.0, 1.0, -1.0]], &device);
+
+        let (output_real, output_imag) = real.clone().fft_complex(imag.clone(), None, 1);
+        let loss = (output_real * weights_real).sum() + (output_imag * weights_imag).sum();
+        let grads = loss.backward();
+
+        let grad_real = real.grad(&grads).unwrap();
+        let grad_imag = imag.grad(&grads).unwrap();
+        grad_real
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.5, 0.0179, 3.4821]]), 3);
+        grad_imag
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, -0.1651, 4.1651]]), 3);
+    }
+
+    #[test]
+    fn should_diff_ifft() {
+        let device = Default::default();
+        let real =
+            TestAutodiffTensor::<2>::from_data([[1.0], [-1.0], [3.0]], &device).require_grad();
+        let imag =
+            TestAutodiffTensor::<2>::from_data([[2.0], [0.5], [-1.0]], &device).require_grad();
+        let weights_real = TestAutodiffTensor::from_data([[1.0], [-2.0], [0.5]], &device);
+        let weights_imag = TestAutodiffTensor::from_data([[2.0], [1.0], [-1.0]], &device);
+
+        let (output_real, output_imag) = real.clone().ifft(imag.clone(), None, 0);
+        let loss = (output_real * weights_real).sum() + (output_imag * weights_imag).sum();
+        let grads = loss.backward();
+
+        let grad_real = real.grad(&grads).unwrap();
+        let grad_imag = imag.grad(&grads).unwrap();
+        grad_real
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.1667], [1.1607], [0.006]]), 3);
+        grad_imag
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.6667], [1.3884], [-0.055]]), 3);
+    }
+}
@@ -0,0 +1,138 @@
+#[burn_tensor_testgen::testgen(ad_flash_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::flash_attention;
+    use burn_tensor::{activation, Data, Distribution, Tensor};
+
+    #[test]
+    fn should_diff_flash_attention() {
+        let test = FlashAttentionTestCase {
+            batch_size: 2,
+            num_heads: 2,
+            seq_length_1: 5,
+            seq_length_2: 7,
+            head_dim: 4,
+            head_dim_value: 3,
+            causal: false,
+        };
+
+        test.assert_grads();
+    }
+
+    #[test]
+    fn should_diff_flash_attention_causal() {
+        let test = FlashAttentionTestCase {
+            batch_size: 1,
+            num_heads: 3,
+            seq_length_1: 6,
+            seq_length_2: 6,
+            head_dim: 8,
+            head_dim_value: 8,
+            causal: true,
+        };
+
+        test.assert_grads();
+    }
+
+    struct FlashAttentionTestCase {
+        batch_size: usize,
+        num_heads: usize,
+        seq_length_1: usize,
+        seq_length_2: usize,
+        head_dim: usize,
+        head_dim_value: usize,
+        causal: bool,
+    }
+
+    impl FlashAttentionTestCase {
+        /// Compares the gradients with the ones of the attention computed with a softmax.
+        fn assert_grads(self) {
+            let device = Default::default();
+            let shape_query = [
+                self.batch_size,
+                self.num_heads,
+                self.seq_length_1,
+                self.head_dim,
+            ];
+            let shape_key = [
+                self.batch_size,
+                self.num_heads,
+                self.seq_length_2,
+                self.head_dim,
+            ];
+            let shape_value = [
+                self.batch_size,
+                self.num_heads,
+                self.seq_length_2,
+                self.head_dim_value,
+            ];
+            let query =
+                TestAutodiffTensor::<4>::random(shape_query, Distribution::Default, &device);
+            let key = TestAutodiffTensor::<4>::random(shape_key, Distribution::Default, &device);
+            let value =
+                TestAutodiffTensor::<4>::random(shape_value, Distribution::Default, &device);
+            let weights = TestAutodiffTensor::<4>::random(
+                [
+                    self.batch_size,
+                    self.num_heads,
+                    self.seq_length_1,
+                    self.head_dim_value,
+                ],
+                Distribution::Default,
+                &device,
+            );
+
+            let grads = |reference: bool| {
+                let query = query.clone().detach().require_grad();
+                let key = key.clone().detach().require_grad();
+                let value = value.clone().detach().require_grad();
+                let output = match reference {
+                    true => self.reference(query.clone(), key.clone(), value.clone()),
+                    false => {
+                        flash_attention(query.clone(), key.clone(), value.clone(), self.causal)
+                    }
+                };
+                let grads = (output * weights.clone()).sum().backward();
+
+                [
+                    query.grad(&grads).unwrap().into_data(),
+                    key.grad(&grads).unwrap().into_data(),
+                    value.grad(&grads).unwrap().into_data(),
+                ]
+            };
+
+            let [query_grad, key_grad, value_grad] = grads(false);
+            let [query_grad_expected, key_grad_expected, value_grad_expected] = grads(true);
+
+            query_grad.assert_approx_eq(&query_grad_expected, 3);
+            key_grad.assert_approx_eq(&key_grad_expected, 3);
+            value_grad.assert_approx_eq(&value_grad_expected, 3);
+        }
+
+        fn reference(
+            &self,
+            query: TestAutodiffTensor<4>,
+            key: TestAutodiffTensor<4>,
+            value: TestAutodiffTensor<4>,
+        ) -> TestAutodiffTensor<4> {
+            let mut scores = query
+                .matmul(key.transpose())
+                .div_scalar((self.head_dim as f32).sqrt());
+
+            if self.causal {
+                let offset = self.seq_length_2 - self.seq_length_1;
+                let mask: Vec<bool> = (0..self.seq_length_1)
+                    .flat_map(|i| (0..self.seq_length_2).map(move |j| j > i + offset))
+                    .collect();
+                let mask = Tensor::<TestAutodiffBackend, 1, burn_tensor::Bool>::from_data(
+                    Data::from(mask.as_slice()),
+                    &scores.device(),
+                )
+                .reshape([1, 1, self.seq_length_1, self.seq_length_2]);
+                scores = scores.mask_fill(mask, f32::NEG_INFINITY);
+            }
+
+            activation::softmax(scores, 3).matmul(value)
+        }
+    }
+}
@@ -60,4 +60,51 @@ mod tests {
             Data::from([[19., 19., 19.], [64., 64., 64.]])
         );
     }
+
+    #[test]
+    fn test_scatter_grad_values_is_gathered() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::zeros([2, 3], &device).require_grad();
+        let values =
+            TestAutodiffTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]), &device)
+                .require_grad();
+        let weights =
+            TestAutodiffTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]), &device);
+        let indices = Tensor::<TestAutodiffBackend, 2, Int>::from_data(
+            Data::from([[2, 1, 0], [2, 0, 1]]),
+            &device,
+        );
+
+        let tensor_2 = tensor_1.clone().scatter(1, indices, values.clone());
+        let tensor_3 = tensor_2.mul(weights);
+
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = values.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.into_data(), Data::from([[1., 2., 3.], [4., 5., 6.]]));
+        assert_eq!(grad_2.into_data(), Data::from([[3., 2., 1.], [6., 4., 5.]]));
+    }
+
+    #[test]
+    fn test_scatter_grad_with_repeated_indices() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::zeros([1, 3], &device).require_grad();
+        let values =
+            TestAutodiffTensor::from_data(Data::from([[1.0, 2.0, 3.0]]), &device).require_grad();
+        let weights = TestAutodiffTensor::from_data(Data::from([[2.0, 3.0, 4.0]]), &device);
+        let indices =
+            Tensor::<TestAutodiffBackend, 2, Int>::from_data(Data::from([[1, 1, 2]]), &device);
+
+        let tensor_2 = tensor_1.clone().scatter(1, indices, values.clone());
+        let tensor_3 = tensor_2.clone().mul(weights);
+
+        let grads = tensor_3.backward();
+
+        let grad_2 = values.grad(&grads).unwrap();
+
+        assert_eq!(tensor_2.into_data(), Data::from([[0., 3., 3.]]));
+        assert_eq!(grad_2.into_data(), Data::from([[3., 3., 4.]]));
+    }
 }
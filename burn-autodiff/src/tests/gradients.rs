@@ -24,4 +24,23 @@ mod tests {
         assert_ne!(grad_1_new.to_data(), grad_1.into_data());
         assert_eq!(grad_1_new.into_data(), grad_1_updated.into_data());
     }
+
+    #[test]
+    fn should_keep_gradients_when_graph_merged_into_unused_branch() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let tensor_2 = tensor_1.clone().mul_scalar(2.0);
+
+        // The graph of the second tensor is merged into the graph of the zeros, which isn't used
+        // to compute the loss.
+        let _unused = TestAutodiffTensor::zeros([2, 2], &device)
+            .slice_assign([0..1, 0..2], tensor_2.clone().slice([0..1, 0..2]));
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0], [2.0, 2.0]]), 5);
+    }
 }
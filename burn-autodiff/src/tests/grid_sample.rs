@@ -0,0 +1,135 @@
+#[burn_tensor_testgen::testgen(ad_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample;
+    use burn_tensor::ops::{GridSampleMode, GridSamplePaddingMode};
+    use burn_tensor::{Data, Int, Shape, Tensor};
+
+    #[test]
+    fn test_grid_sample_bilinear_zeros_grad() {
+        let (input_grad, grid_grad) = grid_sample_grads(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            false,
+        );
+
+        input_grad.into_data().assert_approx_eq(
+            &Data::from([[[
+                [0.56, 0.0, 0.0, 1.89],
+                [0.0, 0.99, 3.83, 1.53],
+                [0.475, 5.085, 1.17, 1.08],
+            ]]]),
+            3,
+        );
+        grid_grad.unwrap().into_data().assert_approx_eq(
+            &Data::from([[
+                [[0.0, 0.0], [4.0, 12.0], [-20.4, 12.6]],
+                [[-77.6, 7.2], [9.5, -66.75], [12.0, 36.0]],
+            ]]),
+            2,
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_border_align_corners_grad() {
+        let (input_grad, grid_grad) = grid_sample_grads(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Border,
+            true,
+        );
+
+        input_grad.into_data().assert_approx_eq(
+            &Data::from([[[
+                [0.68, 0.12, 0.27, 1.53],
+                [0.17, 2.575, 5.5075, 3.3475],
+                [0.0, 3.805, 1.1725, 1.8225],
+            ]]]),
+            3,
+        );
+        grid_grad.unwrap().into_data().assert_approx_eq(
+            &Data::from([[
+                [[1.5, 4.0], [3.0, 8.0], [4.5, 12.0]],
+                [[0.0, 16.0], [7.5, 20.0], [9.0, 24.0]],
+            ]]),
+            2,
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_reflection_grad() {
+        let (input_grad, grid_grad) = grid_sample_grads(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Reflection,
+            false,
+        );
+
+        input_grad.into_data().assert_approx_eq(
+            &Data::from([[[
+                [1.0, 0.0, 0.0, 2.7],
+                [0.0, 0.99, 3.83, 2.53],
+                [0.5, 5.31, 1.17, 2.97],
+            ]]]),
+            3,
+        );
+        grid_grad.unwrap().into_data().assert_approx_eq(
+            &Data::from([[
+                [[0.0, 0.0], [4.0, 12.0], [0.0, 18.0]],
+                [[0.0, 24.0], [10.0, 0.0], [12.0, 36.0]],
+            ]]),
+            2,
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_nearest_grad() {
+        let (input_grad, grid_grad) =
+            grid_sample_grads(GridSampleMode::Nearest, GridSamplePaddingMode::Zeros, false);
+
+        input_grad.into_data().assert_approx_eq(
+            &Data::from([[[
+                [1.0, 0.0, 0.0, 3.0],
+                [0.0, 2.0, 6.0, 0.0],
+                [0.0, 5.0, 0.0, 0.0],
+            ]]]),
+            3,
+        );
+        // The sampled values are piecewise constant with respect to the grid.
+        assert!(grid_grad.is_none());
+    }
+
+    /// Samples a `3x4` image with values from 0 to 11 at six locations, each output pixel being
+    /// weighted by its position.
+    fn grid_sample_grads(
+        mode: GridSampleMode,
+        padding_mode: GridSamplePaddingMode,
+        align_corners: bool,
+    ) -> (TestTensor<4>, Option<TestTensor<4>>) {
+        let device = Default::default();
+        let input = Tensor::<TestAutodiffBackend, 1, Int>::arange(0..12, &device)
+            .reshape(Shape::new([1, 1, 3, 4]))
+            .float()
+            .require_grad();
+        let grid = TestAutodiffTensor::from_floats(
+            [[
+                [[-0.9, -0.8], [-0.2, 0.3], [0.9, -0.6]],
+                [[1.1, 0.45], [-0.3, 0.7], [0.35, 0.15]],
+            ]],
+            &device,
+        )
+        .require_grad();
+        let weights = Tensor::<TestAutodiffBackend, 1, Int>::arange(1..7, &device)
+            .reshape(Shape::new([1, 1, 2, 3]))
+            .float();
+
+        let output = grid_sample(
+            input.clone(),
+            grid.clone(),
+            mode,
+            padding_mode,
+            align_corners,
+        );
+        let grads = output.mul(weights).sum().backward();
+
+        (input.grad(&grads).unwrap(), grid.grad(&grads))
+    }
+}
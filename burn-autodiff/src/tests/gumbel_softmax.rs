@@ -0,0 +1,34 @@
+#[burn_tensor_testgen::testgen(ad_gumbel_softmax)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data};
+
+    fn assert_gradient_flows(hard: bool) {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data([[0.5, -1.0, 2.0], [1.0, 0.0, -0.5]], &device)
+            .require_grad();
+        let weights = TestAutodiffTensor::from_data([[1.0, 2.0, 3.0], [-1.0, 4.0, 0.5]], &device);
+
+        let output = activation::gumbel_softmax(tensor.clone(), 2.0, hard, 1);
+        let grads = output.mul(weights).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+        let values = grad.to_data().value;
+        assert!(values.iter().all(|value| value.is_finite()));
+        assert!(values.iter().any(|value| value.abs() > 1e-6));
+        // The softmax is invariant to a shift of the logits, so the gradient sums to zero.
+        grad.sum_dim(1)
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0], [0.0]]), 4);
+    }
+
+    #[test]
+    fn should_diff_gumbel_softmax() {
+        assert_gradient_flows(false);
+    }
+
+    #[test]
+    fn should_diff_gumbel_softmax_hard() {
+        assert_gradient_flows(true);
+    }
+}
@@ -0,0 +1,75 @@
+#[burn_tensor_testgen::testgen(ad_index_put)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    fn weights() -> TestAutodiffTensor<2> {
+        TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &Default::default())
+    }
+
+    #[test]
+    fn should_diff_index_put() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::zeros([3, 2], &device).require_grad();
+        let values = TestAutodiffTensor::ones([2, 2], &device).require_grad();
+        let rows = Tensor::<TestAutodiffBackend, 1, Int>::from_ints([2, 0], &device);
+
+        let output = tensor
+            .clone()
+            .index_put(&[Some(rows)], values.clone(), false);
+        let grads = output.mul(weights()).sum().backward();
+
+        // The replaced positions don't contribute to the output.
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [3.0, 4.0], [0.0, 0.0]]), 4);
+        values
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[5.0, 6.0], [1.0, 2.0]]), 4);
+    }
+
+    #[test]
+    fn should_diff_index_put_only_last_value_of_duplicates() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::zeros([3, 2], &device).require_grad();
+        let values = TestAutodiffTensor::ones([3, 2], &device).require_grad();
+        let rows = Tensor::<TestAutodiffBackend, 1, Int>::from_ints([1, 1, 0], &device);
+
+        let output = tensor.index_put(&[Some(rows)], values.clone(), false);
+        let grads = output.mul(weights()).sum().backward();
+
+        values
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [3.0, 4.0], [1.0, 2.0]]), 4);
+    }
+
+    #[test]
+    fn should_diff_index_put_accumulate() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::zeros([3, 2], &device).require_grad();
+        let values = TestAutodiffTensor::ones([3, 2], &device).require_grad();
+        let rows = Tensor::<TestAutodiffBackend, 1, Int>::from_ints([1, 1, 0], &device);
+
+        let output = tensor
+            .clone()
+            .index_put(&[Some(rows)], values.clone(), true);
+        let grads = output.mul(weights()).sum().backward();
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&weights().into_data(), 4);
+        values
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0, 4.0], [3.0, 4.0], [1.0, 2.0]]), 4);
+    }
+}
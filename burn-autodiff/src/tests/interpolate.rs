@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(ad_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate;
+    use burn_tensor::ops::InterpolateMode;
+    use burn_tensor::{Data, Int, Shape, Tensor};
+
+    #[test]
+    fn test_interpolate_nearest_grad() {
+        let x_grad = interpolate_grad(InterpolateMode::Nearest);
+
+        x_grad
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[12., 20., 13.], [21., 25., 14.]]]]), 3);
+    }
+
+    #[test]
+    fn test_interpolate_bilinear_grad() {
+        let x_grad = interpolate_grad(InterpolateMode::Bilinear {
+            align_corners: false,
+        });
+
+        x_grad
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[4.9, 9.9, 12.7], [20.9, 27.9, 28.7]]]]), 3);
+    }
+
+    #[test]
+    fn test_interpolate_bilinear_align_corners_grad() {
+        let x_grad = interpolate_grad(InterpolateMode::Bilinear {
+            align_corners: true,
+        });
+
+        x_grad
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[4.5, 11., 12.], [19.5, 31., 27.]]]]), 3);
+    }
+
+    #[test]
+    fn test_interpolate_bicubic_grad() {
+        let x_grad = interpolate_grad(InterpolateMode::Bicubic);
+
+        x_grad.into_data().assert_approx_eq(
+            &Data::from([[[[3.2639, 8.004, 11.8919], [22.4641, 28.284, 31.0921]]]]),
+            3,
+        );
+    }
+
+    /// Upsamples a `2x3` image to `3x5`, each output pixel being weighted by its position.
+    fn interpolate_grad(mode: InterpolateMode) -> TestTensor<4> {
+        let device = Default::default();
+        let x = TestAutodiffTensor::ones([1, 1, 2, 3], &device).require_grad();
+        let weights = Tensor::<TestAutodiffBackend, 1, Int>::arange(0..15, &device)
+            .reshape(Shape::new([1, 1, 3, 5]))
+            .float();
+
+        let output = interpolate(x.clone(), [3, 5], mode);
+        let grads = output.mul(weights).sum().backward();
+
+        x.grad(&grads).unwrap()
+    }
+}
@@ -0,0 +1,51 @@
+#[burn_tensor_testgen::testgen(ad_kron)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_diff_outer() {
+        let device = Default::default();
+        let lhs = Tensor::<TestAutodiffBackend, 1>::from_floats([1.0, 2.0], &device).require_grad();
+        let rhs =
+            Tensor::<TestAutodiffBackend, 1>::from_floats([3.0, -1.0, 4.0], &device).require_grad();
+
+        let grads = lhs.clone().outer(rhs.clone()).sum().backward();
+
+        let grad_lhs = lhs.grad(&grads).unwrap();
+        let grad_rhs = rhs.grad(&grads).unwrap();
+        grad_lhs
+            .to_data()
+            .assert_approx_eq(&Data::from([6.0, 6.0]), 5);
+        grad_rhs
+            .to_data()
+            .assert_approx_eq(&Data::from([3.0, 3.0, 3.0]), 5);
+    }
+
+    #[test]
+    fn should_diff_kron() {
+        let device = Default::default();
+        let lhs = TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let rhs = TestAutodiffTensor::from_floats([[0.0, 5.0, 1.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::from_floats(
+            [
+                [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+                [-1.0, 0.0, 1.0, 2.0, 0.5, -2.0],
+            ],
+            &device,
+        );
+
+        let grads = lhs.clone().kron(rhs.clone()).mul(weights).sum().backward();
+
+        // Each element of the left tensor is weighted by the right tensor, and each element of
+        // the right tensor by each block of the left tensor.
+        let grad_lhs = lhs.grad(&grads).unwrap();
+        let grad_rhs = rhs.grad(&grads).unwrap();
+        grad_lhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[13.0, 31.0], [1.0, 0.5]]), 5);
+        grad_rhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[14.0, 14.0, 10.0]]), 5);
+    }
+}
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(ad_log_sigmoid)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data};
+
+    #[test]
+    fn should_diff_log_sigmoid() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::from_data([-100.0, -2.0, 0.0, 3.0, 100.0], &device).require_grad();
+
+        let grads = activation::log_sigmoid(tensor.clone()).sum().backward();
+
+        // d/dx log_sigmoid(x) = sigmoid(-x)
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([1.0, 0.8808, 0.5, 0.0474, 0.0]), 4);
+    }
+
+    #[test]
+    fn should_diff_log_sigmoid_like_finite_differences() {
+        let device = Default::default();
+        let data = Data::<f32, 1>::from([-4.0, -0.5, 0.0, 1.5, 6.0]);
+        let tensor = TestAutodiffTensor::from_data(data.clone(), &device).require_grad();
+        let epsilon = 1e-2;
+
+        let grads = activation::log_sigmoid(tensor.clone()).sum().backward();
+
+        let inner = TestAutodiffTensor::from_data(data, &device).inner();
+        let forward = activation::log_sigmoid(inner.clone().add_scalar(epsilon));
+        let backward = activation::log_sigmoid(inner.sub_scalar(epsilon));
+        let expected = forward.sub(backward).div_scalar(2.0 * epsilon);
+
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data().assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_diff_logit_like_finite_differences() {
+        let device = Default::default();
+        let data = Data::<f32, 1>::from([0.05, 0.3, 0.5, 0.8, 0.95]);
+        let tensor = TestAutodiffTensor::from_data(data.clone(), &device).require_grad();
+        let epsilon = 1e-3;
+
+        let grads = tensor.clone().logit(None).sum().backward();
+
+        let inner = TestAutodiffTensor::from_data(data, &device).inner();
+        let forward = inner.clone().add_scalar(epsilon).logit(None);
+        let backward = inner.sub_scalar(epsilon).logit(None);
+        let expected = forward.sub(backward).div_scalar(2.0 * epsilon);
+
+        // d/dx logit(x) = 1 / (x (1 - x))
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([21.0526, 4.7619, 4.0, 6.25, 21.0526]), 2);
+        grad.to_data().assert_approx_eq(&expected.into_data(), 1);
+    }
+}
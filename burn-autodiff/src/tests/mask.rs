@@ -25,6 +25,37 @@ mod tests {
         assert_eq!(grad_2.to_data(), Data::from([[2.0, 1.0], [3.0, 7.0]]));
     }
 
+    #[test]
+    fn should_diff_masked_fill_broadcast_attention_mask() {
+        let device = Default::default();
+        let scores = TestAutodiffTensor::<3>::from_data(
+            [
+                [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+                [[9.0, 8.0, 7.0], [6.0, 5.0, 4.0], [3.0, 2.0, 1.0]],
+            ],
+            &device,
+        )
+        .require_grad();
+        let mask = Tensor::<TestAutodiffBackend, 2, Bool>::from_bool(
+            Data::from([
+                [false, true, true],
+                [false, false, true],
+                [false, false, false],
+            ]),
+            &device,
+        );
+
+        let output = scores.clone().mul_scalar(2.0);
+        let output = output.masked_fill_broadcast(mask, -1.0e4);
+        let grads = output.sum().backward();
+
+        let grad = scores.grad(&grads).unwrap();
+
+        // No gradient flows through the masked positions.
+        let causal = [[2.0, 0.0, 0.0], [2.0, 2.0, 0.0], [2.0, 2.0, 2.0]];
+        assert_eq!(grad.to_data(), Data::from([causal, causal]));
+    }
+
     #[test]
     fn should_diff_mask_where() {
         let device = Default::default();
@@ -5,26 +5,43 @@ mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
 mod add;
 mod aggregation;
+mod as_strided;
 mod avgpool1d;
 mod avgpool2d;
 mod backward;
 mod broadcast;
 mod cat;
+mod cdist;
+mod cholesky;
 mod complex;
 mod conv1d;
 mod conv2d;
 mod conv_transpose1d;
 mod conv_transpose2d;
 mod cos;
+mod cosine_similarity;
 mod cross_entropy;
+mod cumulative;
 mod div;
+mod eigh;
+mod einsum;
 mod erf;
+mod erfinv;
 mod exp;
+mod expand;
+mod fft;
+mod flash_attention;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod grid_sample;
+mod gumbel_softmax;
+mod index_put;
+mod interpolate;
+mod kron;
 mod log;
 mod log1p;
+mod log_sigmoid;
 mod mask;
 mod matmul;
 mod maxmin;
@@ -32,20 +49,35 @@ mod maxpool1d;
 mod maxpool2d;
 mod mul;
 mod multithread;
+mod nan_to_num;
 mod neg;
+mod normalize;
 mod pow;
 mod recip;
 mod relu;
 mod reshape;
+mod roll;
+mod rope_embed;
+mod scaled_dot_product_attention;
+mod segment_reduce;
 mod select;
 mod sigmoid;
 mod sin;
 mod slice;
 mod softmax;
+mod solve;
+mod sort;
 mod sqrt;
 mod sub;
+mod svd;
 mod tanh;
+mod tensordot;
+mod topk;
 mod transpose;
+mod unbind;
+mod unfold;
+mod vmap;
+mod where_cond;
 
 #[macro_export]
 macro_rules! testgen_all {
@@ -60,6 +92,7 @@ macro_rules! testgen_all {
         // Activation
         burn_autodiff::testgen_ad_relu!();
         burn_autodiff::testgen_ad_gelu!();
+        burn_autodiff::testgen_ad_gumbel_softmax!();
 
         // Modules
         burn_autodiff::testgen_ad_conv1d!();
@@ -72,6 +105,10 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_avg_pool2d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+        burn_autodiff::testgen_ad_interpolate!();
+        burn_autodiff::testgen_ad_grid_sample!();
+        burn_autodiff::testgen_ad_flash_attention!();
+        burn_autodiff::testgen_ad_scaled_dot_product_attention!();
         burn_autodiff::testgen_module_backward!();
 
         // Tensor
@@ -79,31 +116,58 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_multithread!();
         burn_autodiff::testgen_ad_add!();
         burn_autodiff::testgen_ad_aggregation!();
+        burn_autodiff::testgen_ad_as_strided!();
         burn_autodiff::testgen_ad_maxmin!();
         burn_autodiff::testgen_ad_cat!();
+        burn_autodiff::testgen_ad_cdist!();
         burn_autodiff::testgen_ad_cos!();
+        burn_autodiff::testgen_ad_cosine_similarity!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_erf!();
+        burn_autodiff::testgen_ad_erfinv!();
         burn_autodiff::testgen_ad_exp!();
+        burn_autodiff::testgen_ad_expand!();
+        burn_autodiff::testgen_ad_fft!();
         burn_autodiff::testgen_ad_slice!();
         burn_autodiff::testgen_ad_gather_scatter!();
         burn_autodiff::testgen_ad_select!();
+        burn_autodiff::testgen_ad_index_put!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
+        burn_autodiff::testgen_ad_log_sigmoid!();
         burn_autodiff::testgen_ad_mask!();
         burn_autodiff::testgen_ad_matmul!();
+        burn_autodiff::testgen_ad_kron!();
         burn_autodiff::testgen_ad_mul!();
         burn_autodiff::testgen_ad_neg!();
+        burn_autodiff::testgen_ad_normalize!();
+        burn_autodiff::testgen_ad_nan_to_num!();
         burn_autodiff::testgen_ad_powf!();
         burn_autodiff::testgen_ad_recip!();
         burn_autodiff::testgen_ad_reshape!();
+        burn_autodiff::testgen_ad_roll!();
+        burn_autodiff::testgen_ad_rope_embed!();
+        burn_autodiff::testgen_ad_segment_reduce!();
         burn_autodiff::testgen_ad_sin!();
         burn_autodiff::testgen_ad_softmax!();
         burn_autodiff::testgen_ad_sqrt!();
         burn_autodiff::testgen_ad_abs!();
         burn_autodiff::testgen_ad_sub!();
+        burn_autodiff::testgen_ad_svd!();
+        burn_autodiff::testgen_ad_eigh!();
+        burn_autodiff::testgen_ad_solve!();
+        burn_autodiff::testgen_ad_cholesky!();
         burn_autodiff::testgen_ad_tanh!();
+        burn_autodiff::testgen_ad_tensordot!();
+        burn_autodiff::testgen_ad_topk!();
+        burn_autodiff::testgen_ad_sort!();
+        burn_autodiff::testgen_ad_cumulative!();
+        burn_autodiff::testgen_ad_einsum!();
+        burn_autodiff::testgen_ad_unbind!();
+        burn_autodiff::testgen_ad_vmap!();
+        burn_autodiff::testgen_ad_unfold!();
+        burn_autodiff::testgen_ad_where_cond!();
         burn_autodiff::testgen_ad_sigmoid!();
         burn_autodiff::testgen_ad_transpose!();
     };
@@ -0,0 +1,23 @@
+#[burn_tensor_testgen::testgen(ad_nan_to_num)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_nan_to_num() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(
+            [f32::NAN, 2.0, f32::INFINITY, -3.0, f32::NEG_INFINITY],
+            &device,
+        )
+        .require_grad();
+
+        let output = tensor.clone().nan_to_num(0.0, Some(1.0), Some(-1.0));
+        let grads = output.mul_scalar(2.0).sum().backward();
+
+        // The gradient is zero where the elements are replaced.
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([0.0, 2.0, 0.0, 2.0, 0.0]), 4);
+    }
+}
@@ -0,0 +1,32 @@
+#[burn_tensor_testgen::testgen(ad_normalize)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_normalize() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data([[3.0, 4.0]], &device).require_grad();
+
+        let grads = tensor.clone().normalize(2.0, 1, 1e-12).sum().backward();
+
+        // 1 / ||x|| - x * sum(x) / ||x||^3
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.032, -0.024]]), 4);
+    }
+
+    #[test]
+    fn should_diff_normalize_below_eps() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::from_data([[0.1, 0.2], [0.0, 0.0]], &device).require_grad();
+
+        let grads = tensor.clone().normalize(2.0, 1, 0.5).sum().backward();
+
+        // The vectors are divided by eps.
+        let grad = tensor.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0], [2.0, 2.0]]), 4);
+    }
+}
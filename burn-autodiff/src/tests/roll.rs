@@ -0,0 +1,39 @@
+#[burn_tensor_testgen::testgen(ad_roll)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_roll() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device)
+            .require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let tensor_2 = tensor_1.clone().roll(&[1], &[1]);
+        let grads = tensor_2.mul(weights).backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 3.0, 1.0], [5.0, 6.0, 4.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_roll_multiple_dims() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device)
+            .require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let tensor_2 = tensor_1.clone().roll(&[1, -1], &[0, 1]);
+        let grads = tensor_2.mul(weights).backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[6.0, 4.0, 5.0], [3.0, 1.0, 2.0]]), 5);
+    }
+}
@@ -0,0 +1,47 @@
+#[burn_tensor_testgen::testgen(ad_rope_embed)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::rope_embed;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_rope_embed() {
+        let device = Default::default();
+        let query = TestAutodiffTensor::<4>::from_data(
+            [[[[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]]],
+            &device,
+        )
+        .require_grad();
+        let key = TestAutodiffTensor::<4>::from_data(
+            [[[[0.5, -1.0, 2.0, 0.0], [1.0, 1.0, 1.0, 1.0]]]],
+            &device,
+        )
+        .require_grad();
+        let weights = TestAutodiffTensor::<4>::from_data(
+            [[[[1.0, -1.0, 2.0, 0.5], [0.5, 2.0, -1.0, 1.0]]]],
+            &device,
+        );
+        let angles = TestAutodiffTensor::<2>::from_data([[0.0, 0.0], [0.5, 1.0]], &device);
+
+        let (query_out, key_out) = rope_embed(
+            query.clone(),
+            key.clone(),
+            angles.clone().cos(),
+            angles.sin(),
+        );
+        let loss = (query_out * weights.clone()).sum() + (key_out * weights.mul_scalar(2.0)).sum();
+        let grads = loss.backward();
+
+        // The gradient is the rotation of the weights by the opposite angles.
+        let query_grad = query.grad(&grads).unwrap();
+        let key_grad = key.grad(&grads).unwrap();
+        query_grad.to_data().assert_approx_eq(
+            &Data::from([[[[1.0, -1.0, 2.0, 0.5], [-0.0406, 1.9221, -1.1173, -1.1426]]]]),
+            3,
+        );
+        key_grad.to_data().assert_approx_eq(
+            &Data::from([[[[2.0, -2.0, 4.0, 1.0], [-0.0812, 3.8442, -2.2346, -2.2852]]]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,82 @@
+#[burn_tensor_testgen::testgen(ad_scaled_dot_product_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::scaled_dot_product_attention;
+    use burn_tensor::{activation, Bool, Data, Distribution, Tensor};
+
+    #[test]
+    fn should_diff_scaled_dot_product_attention_with_mask() {
+        let device = Default::default();
+        let query = TestAutodiffTensor::<4>::random([2, 2, 4, 3], Distribution::Default, &device);
+        let key = TestAutodiffTensor::<4>::random([2, 2, 5, 3], Distribution::Default, &device);
+        let value = TestAutodiffTensor::<4>::random([2, 2, 5, 2], Distribution::Default, &device);
+        let weights = TestAutodiffTensor::<4>::random([2, 2, 4, 2], Distribution::Default, &device);
+        let mask: Vec<bool> = (0..20).map(|i| i % 3 == 2).collect();
+        let mask =
+            Tensor::<TestAutodiffBackend, 1, Bool>::from_data(Data::from(mask.as_slice()), &device)
+                .reshape([1, 1, 4, 5]);
+
+        let grads = |reference: bool| {
+            let query = query.clone().detach().require_grad();
+            let key = key.clone().detach().require_grad();
+            let value = value.clone().detach().require_grad();
+            let output = match reference {
+                true => {
+                    let scores = query
+                        .clone()
+                        .matmul(key.clone().transpose())
+                        .div_scalar(3.0f32.sqrt())
+                        .mask_fill(mask.clone().repeat(0, 2).repeat(1, 2), f32::NEG_INFINITY);
+                    activation::softmax(scores, 3).matmul(value.clone())
+                }
+                false => scaled_dot_product_attention(
+                    query.clone(),
+                    key.clone(),
+                    value.clone(),
+                    Some(mask.clone()),
+                    0.0,
+                    false,
+                ),
+            };
+            let grads = (output * weights.clone()).sum().backward();
+
+            [
+                query.grad(&grads).unwrap().into_data(),
+                key.grad(&grads).unwrap().into_data(),
+                value.grad(&grads).unwrap().into_data(),
+            ]
+        };
+
+        let [query_grad, key_grad, value_grad] = grads(false);
+        let [query_grad_expected, key_grad_expected, value_grad_expected] = grads(true);
+
+        query_grad.assert_approx_eq(&query_grad_expected, 3);
+        key_grad.assert_approx_eq(&key_grad_expected, 3);
+        value_grad.assert_approx_eq(&value_grad_expected, 3);
+    }
+
+    #[test]
+    fn should_diff_scaled_dot_product_attention_with_dropout() {
+        let device = Default::default();
+        let query = TestAutodiffTensor::<4>::random([1, 2, 4, 3], Distribution::Default, &device)
+            .require_grad();
+        let key = TestAutodiffTensor::<4>::random([1, 2, 4, 3], Distribution::Default, &device)
+            .require_grad();
+        let value = TestAutodiffTensor::<4>::random([1, 2, 4, 3], Distribution::Default, &device)
+            .require_grad();
+
+        let output = scaled_dot_product_attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            None,
+            0.5,
+            true,
+        );
+        let grads = output.sum().backward();
+
+        assert_eq!(query.grad(&grads).unwrap().dims(), [1, 2, 4, 3]);
+        assert_eq!(key.grad(&grads).unwrap().dims(), [1, 2, 4, 3]);
+        assert_eq!(value.grad(&grads).unwrap().dims(), [1, 2, 4, 3]);
+    }
+}
@@ -0,0 +1,73 @@
+#[burn_tensor_testgen::testgen(ad_segment_reduce)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, SegmentReduce, Tensor};
+
+    fn data() -> (TestAutodiffTensor<2>, Tensor<TestAutodiffBackend, 1, Int>) {
+        let device = Default::default();
+        let data = TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, -4.0], [5.0, 6.0]], &device)
+            .require_grad();
+        let segment_ids = Tensor::from_ints([0, 1, 0], &device);
+
+        (data, segment_ids)
+    }
+
+    #[test]
+    fn should_diff_segment_reduce_sum() {
+        let (data, segment_ids) = data();
+        let weights = TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &Default::default());
+
+        let output = data
+            .clone()
+            .segment_reduce(segment_ids, 2, SegmentReduce::Sum);
+        let grads = output.mul(weights).sum().backward();
+
+        // The upstream gradient of the segment of each row.
+        let grad = data.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[1.0, 2.0], [3.0, 4.0], [1.0, 2.0]]), 4);
+    }
+
+    #[test]
+    fn should_diff_segment_reduce_mean() {
+        let (data, segment_ids) = data();
+
+        let output = data
+            .clone()
+            .segment_reduce(segment_ids, 3, SegmentReduce::Mean);
+        let grads = output.sum().backward();
+
+        let grad = data.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.5, 0.5], [1.0, 1.0], [0.5, 0.5]]), 4);
+    }
+
+    #[test]
+    fn should_diff_segment_reduce_max() {
+        let (data, segment_ids) = data();
+
+        let output = data
+            .clone()
+            .segment_reduce(segment_ids, 3, SegmentReduce::Max);
+        let grads = output.sum().backward();
+
+        // Only the maximum of each segment receives the gradient.
+        let grad = data.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [1.0, 1.0], [1.0, 1.0]]), 4);
+    }
+
+    #[test]
+    fn should_diff_segment_reduce_min() {
+        let (data, segment_ids) = data();
+
+        let output = data
+            .clone()
+            .segment_reduce(segment_ids, 2, SegmentReduce::Min);
+        let grads = output.sum().backward();
+
+        let grad = data.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0], [1.0, 1.0], [0.0, 0.0]]), 4);
+    }
+}
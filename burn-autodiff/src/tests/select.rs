@@ -54,4 +54,35 @@ mod tests {
             Data::from([[64., 64., 64.], [19., 19., 19.]])
         );
     }
+
+    #[test]
+    fn test_select_assign_grad_values_is_selected() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::zeros([3, 2], &device).require_grad();
+        let values =
+            TestAutodiffTensor::from_data(Data::from([[1.0, 2.0]; 4]), &device).require_grad();
+        let weights = TestAutodiffTensor::from_data(
+            Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]),
+            &device,
+        );
+        let indices =
+            Tensor::<TestAutodiffBackend, 1, Int>::from_data(Data::from([2, 0, 2, 1]), &device);
+
+        let tensor_2 = tensor_1.clone().select_assign(0, indices, values.clone());
+        let tensor_3 = tensor_2.mul(weights);
+
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = values.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad_1.into_data(),
+            Data::from([[1., 2.], [3., 4.], [5., 6.]])
+        );
+        assert_eq!(
+            grad_2.into_data(),
+            Data::from([[5., 6.], [1., 2.], [5., 6.], [3., 4.]])
+        );
+    }
 }
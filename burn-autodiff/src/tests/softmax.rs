@@ -72,4 +72,41 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[0.2534, 0.2862], [0.5286, 2.9317]]), 3);
     }
+
+    #[test]
+    fn test_softmax_grad_large_logits() {
+        let device = Default::default();
+        let tensor_1 =
+            Tensor::<TestAutodiffBackend, 2>::from_data([[1000.0, 1000.0, 1000.0]], &device)
+                .require_grad();
+        let tensor_2 = Tensor::<TestAutodiffBackend, 2>::from_data([[1.0, 2.0, 3.0]], &device);
+
+        let tensor_3 = activation::softmax(tensor_1.clone(), 1).mul(tensor_2);
+
+        let grads = tensor_3.sum().backward();
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let third = 1.0 / 3.0;
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[-third, 0.0, third]]), 4);
+    }
+
+    #[test]
+    fn test_log_softmax_grad_large_logits() {
+        let device = Default::default();
+        let tensor_1 =
+            Tensor::<TestAutodiffBackend, 2>::from_data([[1000.0, 1000.0, 1000.0]], &device)
+                .require_grad();
+        let tensor_2 = Tensor::<TestAutodiffBackend, 2>::from_data([[1.0, 2.0, 3.0]], &device);
+
+        let tensor_3 = tensor_1.clone().log_softmax(1).mul(tensor_2);
+
+        let grads = tensor_3.sum().backward();
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[-1.0, 0.0, 1.0]]), 4);
+    }
 }
@@ -0,0 +1,98 @@
+#[burn_tensor_testgen::testgen(ad_solve)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    /// Computes the gradient of the function with central finite differences.
+    fn finite_differences<const M: usize, const N: usize, F>(
+        data: [[f32; N]; M],
+        function: F,
+    ) -> Data<f32, 2>
+    where
+        F: Fn(Tensor<TestBackend, 2>) -> f32,
+    {
+        let epsilon = 1e-2;
+        let mut grad = Vec::new();
+
+        for i in 0..M {
+            for j in 0..N {
+                let mut forward = data;
+                let mut backward = data;
+                forward[i][j] += epsilon;
+                backward[i][j] -= epsilon;
+
+                let forward = function(TestTensor::from(forward));
+                let backward = function(TestTensor::from(backward));
+                grad.push((forward - backward) / (2.0 * epsilon));
+            }
+        }
+
+        Data::new(grad, [M, N].into())
+    }
+
+    #[test]
+    fn should_diff_solve() {
+        let data_a = [[3.0, 1.0], [1.0, 2.0]];
+        let data_b = [[9.0, 1.0], [8.0, -2.0]];
+        let weights = [[1.0, -2.0], [0.5, 3.0]];
+        let device = Default::default();
+        let a = TestAutodiffTensor::from_floats(data_a, &device).require_grad();
+        let b = TestAutodiffTensor::from_floats(data_b, &device).require_grad();
+
+        let x = a.clone().solve(b.clone());
+        let grads = x
+            .mul(TestAutodiffTensor::from_floats(weights, &device))
+            .sum()
+            .backward();
+
+        let loss = |a: TestTensor<2>, b: TestTensor<2>| {
+            a.solve(b)
+                .mul(TestTensor::from(weights))
+                .sum()
+                .into_scalar()
+        };
+        let expected_a = finite_differences(data_a, |a| loss(a, TestTensor::from(data_b)));
+        let expected_b = finite_differences(data_b, |b| loss(TestTensor::from(data_a), b));
+        a.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected_a, 2);
+        b.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected_b, 2);
+    }
+
+    #[test]
+    fn should_diff_lstsq() {
+        let data_a = [[1.0, 0.5], [1.0, 1.0], [2.0, -1.0]];
+        let data_b = [[1.0], [2.0], [0.5]];
+        let weights = [[2.0], [-1.0]];
+        let device = Default::default();
+        let a = TestAutodiffTensor::from_floats(data_a, &device).require_grad();
+        let b = TestAutodiffTensor::from_floats(data_b, &device).require_grad();
+
+        let x = a.clone().lstsq(b.clone());
+        let grads = x
+            .mul(TestAutodiffTensor::from_floats(weights, &device))
+            .sum()
+            .backward();
+
+        let loss = |a: TestTensor<2>, b: TestTensor<2>| {
+            a.lstsq(b)
+                .mul(TestTensor::from(weights))
+                .sum()
+                .into_scalar()
+        };
+        let expected_a = finite_differences(data_a, |a| loss(a, TestTensor::from(data_b)));
+        let expected_b = finite_differences(data_b, |b| loss(TestTensor::from(data_a), b));
+        a.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected_a, 2);
+        b.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected_b, 2);
+    }
+}
@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(ad_sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_sort() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 7.0, 3.0], [-2.0, -3.0, 4.0]], &device)
+                .require_grad();
+        let tensor_2 = TestAutodiffTensor::from_floats(
+            [[2.0, 1.0, 0.0], [3.0, -1.0, 1.0], [0.5, 4.0, -2.0]],
+            &device,
+        )
+        .require_grad();
+
+        let values = tensor_1.clone().sort(1, false);
+        let tensor_3 = values.matmul(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0, 2.5, 3.0], [3.0, 3.0, 2.5]]), 5);
+        grad_2.to_data().assert_approx_eq(
+            &Data::from([[-2.0, -2.0, -2.0], [1.0, 1.0, 1.0], [11.0, 11.0, 11.0]]),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_diff_sort_with_indices_descending() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_floats([[1.0, 7.0, 3.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0]], &device);
+
+        let (values, _indices) = tensor_1.clone().sort_with_indices(1, true);
+        let grads = values.mul(weights).backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[3.0, 1.0, 2.0]]), 5);
+    }
+}
@@ -0,0 +1,106 @@
+#[burn_tensor_testgen::testgen(ad_svd)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    /// Computes the gradient of the function with central finite differences.
+    fn finite_differences<F>(data: [[f32; 3]; 2], function: F) -> Data<f32, 2>
+    where
+        F: Fn(Tensor<TestBackend, 2>) -> f32,
+    {
+        let epsilon = 1e-2;
+        let mut grad = Vec::new();
+
+        for i in 0..2 {
+            for j in 0..3 {
+                let mut forward = data;
+                let mut backward = data;
+                forward[i][j] += epsilon;
+                backward[i][j] -= epsilon;
+
+                let forward = function(TestTensor::from(forward));
+                let backward = function(TestTensor::from(backward));
+                grad.push((forward - backward) / (2.0 * epsilon));
+            }
+        }
+
+        Data::new(grad, [2, 3].into())
+    }
+
+    #[test]
+    fn should_diff_svd_reconstruction() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::from_floats([[1.0, 2.0], [0.5, -1.0], [3.0, 2.0]], &device)
+                .require_grad();
+        let weights =
+            TestAutodiffTensor::from_floats([[1.0, -2.0], [3.0, 0.5], [4.0, -1.0]], &device);
+
+        let (u, s, vh) = tensor.clone().svd::<1>(false);
+        let reconstruction = u.mul(s.unsqueeze()).matmul(vh);
+        let grads = reconstruction.mul(weights.clone()).sum().backward();
+
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&weights.into_data(), 3);
+    }
+
+    #[test]
+    fn should_diff_singular_values() {
+        let data = [[1.0, 2.0, 0.5], [-1.0, 3.0, 2.0]];
+        let tensor = TestAutodiffTensor::from_floats(data, &Default::default()).require_grad();
+        let weights = [2.0, -1.0];
+
+        let (_u, s, _vh) = tensor.clone().svd::<1>(false);
+        let grads = s
+            .mul(Tensor::from_floats(weights, &Default::default()))
+            .sum()
+            .backward();
+
+        let expected = finite_differences(data, |tensor| {
+            let (_u, s, _vh) = tensor.svd::<1>(false);
+            let s = s.into_data().value;
+            s[0] * weights[0] + s[1] * weights[1]
+        });
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected, 2);
+    }
+
+    #[test]
+    fn should_diff_singular_vectors() {
+        let data = [[1.0, 2.0, 0.5], [-1.0, 3.0, 2.0]];
+        let tensor = TestAutodiffTensor::from_floats(data, &Default::default()).require_grad();
+        let weights = [[1.0, -2.0, 3.0], [0.5, 4.0, -1.0]];
+
+        // The outer product of the first singular vectors doesn't depend on their sign.
+        let projection =
+            |u: Tensor<_, 2>, vh: Tensor<_, 2>| u.narrow(1, 0, 1).matmul(vh.narrow(0, 0, 1));
+        let (u, _s, vh) = tensor.clone().svd::<1>(false);
+        let grads = projection(u, vh)
+            .mul(TestAutodiffTensor::from_floats(
+                weights,
+                &Default::default(),
+            ))
+            .sum()
+            .backward();
+
+        let expected = finite_differences(data, |tensor| {
+            let (u, _s, vh) = tensor.svd::<1>(false);
+            let projection = u.narrow(1, 0, 1).matmul(vh.narrow(0, 0, 1));
+            projection
+                .mul(TestTensor::from(weights))
+                .sum()
+                .into_scalar()
+        });
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&expected, 2);
+    }
+}
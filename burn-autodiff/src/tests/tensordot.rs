@@ -0,0 +1,35 @@
+#[burn_tensor_testgen::testgen(ad_tensordot)]
+mod tests {
+    use super::*;
+    use burn_tensor::Tensor;
+
+    #[test]
+    fn should_diff_tensordot_like_matmul() {
+        let device = Default::default();
+        let data_lhs = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        let data_rhs = [[1.0, -1.0], [0.5, 2.0]];
+        let lhs_1 = TestAutodiffTensor::from_floats(data_lhs, &device).require_grad();
+        let rhs_1 = TestAutodiffTensor::from_floats(data_rhs, &device).require_grad();
+        let lhs_2 = TestAutodiffTensor::from_floats(data_lhs, &device).require_grad();
+        let rhs_2 = TestAutodiffTensor::from_floats(data_rhs, &device).require_grad();
+        let weights =
+            TestAutodiffTensor::from_floats([[1.0, 2.0], [-1.0, 0.5], [3.0, 0.0]], &device);
+
+        let output_1: Tensor<TestAutodiffBackend, 2> =
+            lhs_1.clone().tensordot(rhs_1.clone(), [[0], [0]]);
+        let output_2 = lhs_2.clone().transpose().matmul(rhs_2.clone());
+        let grads_1 = output_1.mul(weights.clone()).sum().backward();
+        let grads_2 = output_2.mul(weights).sum().backward();
+
+        lhs_1
+            .grad(&grads_1)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&lhs_2.grad(&grads_2).unwrap().to_data(), 4);
+        rhs_1
+            .grad(&grads_1)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&rhs_2.grad(&grads_2).unwrap().to_data(), 4);
+    }
+}
@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(ad_topk)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_top_k() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_floats([[1.0, 7.0, 3.0], [-2.0, -3.0, 4.0]], &device)
+                .require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::from_floats([[2.0, 1.0], [3.0, -1.0], [0.5, 4.0]], &device)
+                .require_grad();
+
+        let (values, _indices) = tensor_1.clone().top_k(2, 1);
+        let tensor_3 = values.matmul(tensor_2.clone().transpose());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 5.5, 4.0], [4.0, 0.0, 5.5]]), 5);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[11.0, 1.0], [11.0, 1.0], [11.0, 1.0]]), 5);
+    }
+}
@@ -0,0 +1,42 @@
+#[burn_tensor_testgen::testgen(ad_unbind)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_diff_unbind() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let rows: Vec<Tensor<TestAutodiffBackend, 1>> = tensor_1.clone().unbind(0);
+        let tensor_2 = rows[0]
+            .clone()
+            .mul_scalar(2.0)
+            .add(rows[1].clone().powf_scalar(2.0));
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[2.0, 2.0], [6.0, 8.0]]));
+    }
+
+    #[test]
+    fn should_diff_chunk() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data([[1.0, 2.0, 3.0, 4.0, 5.0]], &device).require_grad();
+
+        let chunks = tensor_1.clone().chunk(3, 1);
+        let tensor_2 = chunks[0]
+            .clone()
+            .sum()
+            .add(chunks[2].clone().mul_scalar(3.0).sum());
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(grad_1.to_data(), Data::from([[1.0, 1.0, 0.0, 0.0, 3.0]]));
+    }
+}
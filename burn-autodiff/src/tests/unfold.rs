@@ -0,0 +1,37 @@
+#[burn_tensor_testgen::testgen(ad_unfold)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_unfold_with_overlapping_windows() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::from_data(Data::from([[1., 2., 3., 4., 5.]]), &device)
+            .require_grad();
+        let weights =
+            TestAutodiffTensor::from_data(Data::from([[[1., 2., 3.], [4., 5., 6.]]]), &device);
+
+        let output = tensor.clone().unfold::<3>(1, 3, 2);
+        let grads = output.mul(weights).sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.into_data()
+            .assert_approx_eq(&Data::from([[1., 2., 7., 5., 6.]]), 3);
+    }
+
+    #[test]
+    fn should_diff_fold() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::from_data(Data::from([[1., 2., 3.], [3., 4., 5.]]), &device)
+                .require_grad();
+        let weights = TestAutodiffTensor::from_data(Data::from([1., 2., 3., 4., 5.]), &device);
+
+        let output = tensor.clone().fold::<1>(0, 5, 2);
+        let grads = output.mul(weights).sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.into_data()
+            .assert_approx_eq(&Data::from([[1., 2., 3.], [3., 4., 5.]]), 3);
+    }
+}
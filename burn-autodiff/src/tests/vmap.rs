@@ -0,0 +1,32 @@
+#[burn_tensor_testgen::testgen(ad_vmap)]
+mod tests {
+    use super::*;
+    use burn_tensor::Tensor;
+
+    #[test]
+    fn should_diff_vmap_like_batched_computation() {
+        let device = Default::default();
+        let data = [[1.0, 2.0, -1.0], [0.5, -3.0, 2.0]];
+        let tensor_1 = TestAutodiffTensor::from_floats(data, &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_floats(data, &device).require_grad();
+        let weights = Tensor::<TestAutodiffBackend, 1>::from_floats([1.0, -2.0, 3.0], &device);
+
+        let output_1 = tensor_1
+            .clone()
+            .vmap(|sample: Tensor<TestAutodiffBackend, 1>| {
+                sample.clone().mul(sample).mul(weights.clone()).cumsum(0)
+            });
+        let output_2 = tensor_2
+            .clone()
+            .powf_scalar(2.0)
+            .mul(weights.clone().unsqueeze())
+            .cumsum(1);
+        let grads_1 = output_1.sum().backward();
+        let grads_2 = output_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads_1).unwrap();
+        let grad_2 = tensor_2.grad(&grads_2).unwrap();
+
+        grad_1.to_data().assert_approx_eq(&grad_2.to_data(), 4);
+    }
+}
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(ad_where_cond)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    #[test]
+    fn should_diff_where_cond() {
+        let device = Default::default();
+        let condition = Tensor::<TestAutodiffBackend, 2, Bool>::from_bool(
+            Data::from([[true, false], [false, true]]),
+            &device,
+        );
+        let x = TestAutodiffTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let y = TestAutodiffTensor::from_floats([[5.0, 6.0], [7.0, 8.0]], &device).require_grad();
+
+        let output = Tensor::where_cond(condition, x.clone() * 2.0, y.clone() * 3.0);
+        let grads = output.sum().backward();
+
+        let grad_x = x.grad(&grads).unwrap();
+        let grad_y = y.grad(&grads).unwrap();
+
+        grad_x
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 0.0], [0.0, 2.0]]), 3);
+        grad_y
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 3.0], [3.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_where_cond_with_broadcasting() {
+        let device = Default::default();
+        let condition = Tensor::<TestAutodiffBackend, 2, Bool>::from_bool(
+            Data::from([[true, false, true], [false, false, true]]),
+            &device,
+        );
+        let x = TestAutodiffTensor::from_floats([[1.0, 2.0, 3.0]], &device).require_grad();
+        let y = TestAutodiffTensor::from_floats([[4.0]], &device).require_grad();
+
+        let output = Tensor::where_cond(condition, x.clone(), y.clone());
+        let grads = output.sum().backward();
+
+        // The gradients are summed over the broadcasted dimensions.
+        let grad_x = x.grad(&grads).unwrap();
+        let grad_y = y.grad(&grads).unwrap();
+
+        grad_x
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0, 2.0]]), 3);
+        grad_y.to_data().assert_approx_eq(&Data::from([[3.0]]), 3);
+    }
+}
@@ -0,0 +1,220 @@
+use burn_tensor::{BasicOps, Bool, Float, Int, Tensor};
+use candle_core::{DType, WithDType};
+
+use crate::{
+    element::{FloatCandleElement, IntCandleElement},
+    Candle, CandleDevice, CandleTensor,
+};
+
+/// A tensor kind of the candle backend, which can be converted from and into a
+/// [candle tensor](candle_core::Tensor).
+pub trait CandleKind<F: FloatCandleElement, I: IntCandleElement>: BasicOps<Candle<F, I>> {
+    /// The data type of the candle tensors of this kind.
+    fn dtype() -> DType;
+
+    /// Wraps a candle tensor, which must already have the data type of the kind.
+    fn from_candle<const D: usize>(tensor: candle_core::Tensor) -> Self::Primitive<D>;
+
+    /// Returns the candle tensor wrapped by the primitive.
+    fn to_candle<const D: usize>(tensor: &Self::Primitive<D>) -> candle_core::Tensor;
+}
+
+impl<F: FloatCandleElement, I: IntCandleElement> CandleKind<F, I> for Float {
+    fn dtype() -> DType {
+        F::DTYPE
+    }
+
+    fn from_candle<const D: usize>(tensor: candle_core::Tensor) -> CandleTensor<F, D> {
+        CandleTensor::new(tensor)
+    }
+
+    fn to_candle<const D: usize>(tensor: &CandleTensor<F, D>) -> candle_core::Tensor {
+        tensor.tensor.clone()
+    }
+}
+
+impl<F: FloatCandleElement, I: IntCandleElement> CandleKind<F, I> for Int {
+    fn dtype() -> DType {
+        I::DTYPE
+    }
+
+    fn from_candle<const D: usize>(tensor: candle_core::Tensor) -> CandleTensor<I, D> {
+        CandleTensor::new(tensor)
+    }
+
+    fn to_candle<const D: usize>(tensor: &CandleTensor<I, D>) -> candle_core::Tensor {
+        tensor.tensor.clone()
+    }
+}
+
+impl<F: FloatCandleElement, I: IntCandleElement> CandleKind<F, I> for Bool {
+    fn dtype() -> DType {
+        DType::U8
+    }
+
+    fn from_candle<const D: usize>(tensor: candle_core::Tensor) -> CandleTensor<u8, D> {
+        CandleTensor::new(tensor)
+    }
+
+    fn to_candle<const D: usize>(tensor: &CandleTensor<u8, D>) -> candle_core::Tensor {
+        tensor.tensor.clone()
+    }
+}
+
+/// Converts a [candle tensor](candle_core::Tensor) into a tensor of the candle backend.
+pub trait ToBurn {
+    /// Converts the candle tensor into a tensor of the candle backend on the given device.
+    ///
+    /// The storage is shared with the candle tensor when it is already on the device and has the
+    /// data type of the tensor kind, otherwise the data is copied.
+    ///
+    /// # Panics
+    ///
+    /// If the rank of the candle tensor isn't `D`.
+    fn to_burn<F, I, const D: usize, K>(&self, device: &CandleDevice) -> Tensor<Candle<F, I>, D, K>
+    where
+        F: FloatCandleElement,
+        I: IntCandleElement,
+        K: CandleKind<F, I>;
+}
+
+impl ToBurn for candle_core::Tensor {
+    fn to_burn<F, I, const D: usize, K>(&self, device: &CandleDevice) -> Tensor<Candle<F, I>, D, K>
+    where
+        F: FloatCandleElement,
+        I: IntCandleElement,
+        K: CandleKind<F, I>,
+    {
+        assert_eq!(
+            self.rank(),
+            D,
+            "Can't convert a candle tensor of shape {:?} into a tensor of rank {D}.",
+            self.dims()
+        );
+
+        // Each conversion into a candle device creates a new one, which candle doesn't consider
+        // to be the same device, so the locations are compared instead to avoid a copy.
+        let tensor = match CandleDevice::from(self.device().clone()) == *device {
+            true => self.clone(),
+            false => self.to_device(&(*device).into()).unwrap(),
+        };
+        let tensor = tensor.to_dtype(K::dtype()).unwrap();
+
+        Tensor::from_primitive(K::from_candle(tensor))
+    }
+}
+
+/// Converts a tensor of the candle backend into a [candle tensor](candle_core::Tensor).
+pub trait ToCandle {
+    /// Returns the candle tensor sharing the storage of the tensor, with the same device, data
+    /// type and shape.
+    fn to_candle(&self) -> candle_core::Tensor;
+}
+
+impl<F, I, const D: usize, K> ToCandle for Tensor<Candle<F, I>, D, K>
+where
+    F: FloatCandleElement,
+    I: IntCandleElement,
+    K: CandleKind<F, I>,
+{
+    fn to_candle(&self) -> candle_core::Tensor {
+        K::to_candle(&self.clone().into_primitive())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_round_trip_f32() {
+        let device = CandleDevice::Cpu;
+        let tensor = Tensor::<Candle<f32, i64>, 2>::from_floats([[1.0, -2.0], [3.5, 4.0]], &device);
+
+        let candle = tensor.to_candle();
+        assert_eq!(candle.dtype(), DType::F32);
+        assert_eq!(candle.dims(), &[2, 2]);
+        let tensor: Tensor<Candle<f32, i64>, 2> = candle.to_burn(&device);
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, -2.0], [3.5, 4.0]]), 5);
+    }
+
+    #[test]
+    fn should_round_trip_f64() {
+        let device = CandleDevice::Cpu;
+        let candle = candle_core::Tensor::new(&[[0.5f64, 1.5, -2.5]], &device.into()).unwrap();
+
+        let tensor: Tensor<Candle<f64, i64>, 2> = candle.to_burn(&device);
+        assert_eq!(tensor.dims(), [1, 3]);
+        let candle = tensor.to_candle();
+
+        assert_eq!(candle.dtype(), DType::F64);
+        assert_eq!(candle.to_vec2::<f64>().unwrap(), vec![vec![0.5, 1.5, -2.5]]);
+    }
+
+    #[test]
+    fn should_round_trip_i64() {
+        let device = CandleDevice::Cpu;
+        let candle = candle_core::Tensor::new(&[3i64, -1, 7], &device.into()).unwrap();
+
+        let tensor: Tensor<Candle<f32, i64>, 1, Int> = candle.to_burn(&device);
+        assert_eq!(tensor.clone().into_data(), Data::from([3, -1, 7]));
+        let candle = tensor.to_candle();
+
+        assert_eq!(candle.dtype(), DType::I64);
+        assert_eq!(candle.to_vec1::<i64>().unwrap(), vec![3, -1, 7]);
+    }
+
+    #[test]
+    fn should_convert_dtype_to_tensor_kind() {
+        let device = CandleDevice::Cpu;
+        let candle = candle_core::Tensor::new(&[1.0f64, 2.0], &device.into()).unwrap();
+
+        let tensor: Tensor<Candle<f32, i64>, 1> = candle.to_burn(&device);
+
+        assert_eq!(tensor.to_candle().dtype(), DType::F32);
+    }
+
+    #[test]
+    fn should_share_storage_on_same_device() {
+        let device = CandleDevice::Cpu;
+        let candle = candle_core::Tensor::new(&[1.0f32, 2.0], &device.into()).unwrap();
+
+        let tensor: Tensor<Candle<f32, i64>, 1> = candle.to_burn(&device);
+
+        // The candle tensor is shared rather than copied, which keeps its identifier.
+        assert_eq!(candle.id(), tensor.to_candle().id());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_on_rank_mismatch() {
+        let device = CandleDevice::Cpu;
+        let candle = candle_core::Tensor::new(&[1.0f32, 2.0], &device.into()).unwrap();
+
+        let _tensor: Tensor<Candle<f32, i64>, 2> = candle.to_burn(&device);
+    }
+
+    #[cfg(feature = "cuda")]
+    #[test]
+    fn should_round_trip_cuda() {
+        let device = CandleDevice::Cuda(0);
+        let candle =
+            candle_core::Tensor::new(&[[1.0f32, 2.0], [3.0, 4.0]], &device.into()).unwrap();
+
+        let tensor: Tensor<Candle<f32, i64>, 2> = candle.to_burn(&device);
+        let round_trip = tensor.to_candle();
+
+        assert_eq!(candle.id(), round_trip.id());
+        assert_eq!(
+            round_trip.to_vec2::<f32>().unwrap(),
+            vec![vec![1.0, 2.0], vec![3.0, 4.0]]
+        );
+
+        let cpu: Tensor<Candle<f32, i64>, 2, Int> = round_trip.to_burn(&CandleDevice::Cpu);
+        assert_eq!(cpu.into_data(), Data::from([[1, 2], [3, 4]]));
+    }
+}
@@ -8,9 +8,11 @@ extern crate derive_new;
 
 mod backend;
 mod element;
+mod interop;
 mod ops;
 mod tensor;
 pub use backend::*;
+pub use interop::*;
 pub use tensor::*;
 
 #[cfg(test)]
@@ -60,6 +60,13 @@ pub fn reshape<E: CandleElement, const D1: usize, const D2: usize>(
     CandleTensor::new(tensor.tensor.reshape(&shape.dims).unwrap())
 }
 
+pub fn expand<E: CandleElement, const D: usize>(
+    tensor: CandleTensor<E, D>,
+    shape: Shape<D>,
+) -> CandleTensor<E, D> {
+    CandleTensor::new(tensor.tensor.broadcast_as(&shape.dims).unwrap())
+}
+
 pub fn device<E: CandleElement, const D: usize>(tensor: &CandleTensor<E, D>) -> CandleDevice {
     tensor.tensor.device().clone().into()
 }
@@ -61,6 +61,13 @@ impl<F: FloatCandleElement, I: IntCandleElement> BoolTensorOps<Self> for Candle<
         super::base::to_device(tensor, device)
     }
 
+    fn bool_expand<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        shape: Shape<D>,
+    ) -> BoolTensor<Self, D> {
+        super::base::expand(tensor, shape)
+    }
+
     fn bool_reshape<const D1: usize, const D2: usize>(
         tensor: BoolTensor<Self, D1>,
         shape: Shape<D2>,
@@ -39,6 +39,13 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
         super::base::to_device(tensor, device)
     }
 
+    fn int_expand<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        shape: Shape<D>,
+    ) -> IntTensor<Self, D> {
+        super::base::expand(tensor, shape)
+    }
+
     fn int_reshape<const D1: usize, const D2: usize>(
         tensor: IntTensor<Self, D1>,
         shape: Shape<D2>,
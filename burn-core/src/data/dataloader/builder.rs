@@ -1,4 +1,7 @@
-use super::{batcher::Batcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy};
+use super::{
+    batcher::Batcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy,
+    PrefetchDataLoader,
+};
 use burn_dataset::Dataset;
 use rand::{rngs::StdRng, SeedableRng};
 use std::sync::Arc;
@@ -6,8 +9,10 @@ use std::sync::Arc;
 /// A builder for data loaders.
 pub struct DataLoaderBuilder<I, O> {
     strategy: Option<Box<dyn BatchStrategy<I>>>,
+    batch_size: usize,
     batcher: Arc<dyn Batcher<I, O>>,
     num_threads: Option<usize>,
+    prefetch_size: Option<usize>,
     shuffle: Option<u64>,
 }
 
@@ -32,7 +37,9 @@ where
         Self {
             batcher: Arc::new(batcher),
             strategy: None,
+            batch_size: 1,
             num_threads: None,
+            prefetch_size: None,
             shuffle: None,
         }
     }
@@ -49,6 +56,7 @@ where
     /// The data loader builder.
     pub fn batch_size(mut self, batch_size: usize) -> Self {
         self.strategy = Some(Box::new(FixBatchStrategy::new(batch_size)));
+        self.batch_size = batch_size;
         self
     }
 
@@ -70,6 +78,10 @@ where
 
     /// Sets the number of workers.
     ///
+    /// The workers are threads spawned once when the data loader is built, which load the
+    /// batches in the background while the previous ones are being used. The batches and their
+    /// order don't depend on the number of workers.
+    ///
     /// # Arguments
     ///
     /// * `num_workers` - The number of workers.
@@ -82,6 +94,23 @@ where
         self
     }
 
+    /// Sets the maximum number of batches loaded ahead by the workers, which defaults to two
+    /// batches per worker.
+    ///
+    /// Has no effect without [workers](Self::num_workers).
+    ///
+    /// # Arguments
+    ///
+    /// * `prefetch_size` - The number of batches.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn prefetch_size(mut self, prefetch_size: usize) -> Self {
+        self.prefetch_size = Some(prefetch_size);
+        self
+    }
+
     /// Builds the data loader.
     ///
     /// # Arguments
@@ -102,12 +131,13 @@ where
             Some(strategy) => strategy,
             None => Box::new(FixBatchStrategy::new(1)),
         };
-        if let Some(num_threads) = self.num_threads {
-            return Arc::new(BatchDataLoader::multi_thread(
-                strategy,
+        if let Some(num_threads) = self.num_threads.filter(|num_threads| *num_threads > 0) {
+            return Arc::new(PrefetchDataLoader::new(
+                self.batch_size,
                 dataset,
                 self.batcher,
                 num_threads,
+                self.prefetch_size.unwrap_or(2 * num_threads),
                 rng,
             ));
         }
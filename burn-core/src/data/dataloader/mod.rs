@@ -2,6 +2,7 @@ mod base;
 mod batch;
 mod builder;
 mod multithread;
+mod prefetch;
 mod strategy;
 
 /// Module for batching items.
@@ -11,4 +12,5 @@ pub use base::*;
 pub use batch::*;
 pub use builder::*;
 pub use multithread::*;
+pub use prefetch::*;
 pub use strategy::*;
@@ -0,0 +1,340 @@
+use super::{batcher::Batcher, DataLoader, DataLoaderIterator, Progress};
+use burn_dataset::{transform::ShuffledDataset, Dataset};
+use rand::{distributions::Standard, rngs::StdRng, Rng};
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+type BatchResult<O> = Result<O, Box<dyn Any + Send>>;
+
+/// A batch to be loaded by a worker.
+struct Job<I, O> {
+    index: usize,
+    dataset: Arc<dyn Dataset<I>>,
+    items: core::ops::Range<usize>,
+    sender: mpsc::SyncSender<(usize, BatchResult<O>)>,
+}
+
+/// A data loader with persistent worker threads prefetching the batches in the background.
+///
+/// The workers are spawned when the data loader is created and are reused by every iteration,
+/// until the data loader is dropped. Each iteration keeps up to `prefetch_size` batches loading
+/// ahead of the one being consumed, and the batches are returned in order.
+///
+/// The dataset is shuffled by the data loader itself, before the items are dispatched to the
+/// workers, so the batches are the same as the ones of a [single-threaded](super::BatchDataLoader)
+/// data loader created with the same seed, regardless of the number of workers.
+pub struct PrefetchDataLoader<I, O> {
+    batch_size: usize,
+    prefetch_size: usize,
+    dataset: Arc<dyn Dataset<I>>,
+    rng: Option<spin::Mutex<StdRng>>,
+    jobs: Option<mpsc::Sender<Job<I, O>>>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl<I, O> PrefetchDataLoader<I, O>
+where
+    I: Send + Sync + Clone + 'static,
+    O: Send + 'static,
+{
+    /// Creates a new prefetch data loader, spawning its workers.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch_size` - The batch size.
+    /// * `dataset` - The dataset.
+    /// * `batcher` - The batcher.
+    /// * `num_workers` - The number of worker threads.
+    /// * `prefetch_size` - The maximum number of batches loaded ahead of the one being consumed.
+    /// * `rng` - The rng determining if the dataset is shuffled each time a dataloader
+    ///   iterator is created.
+    ///
+    /// # Returns
+    ///
+    /// The prefetch data loader.
+    pub fn new(
+        batch_size: usize,
+        dataset: Arc<dyn Dataset<I>>,
+        batcher: Arc<dyn Batcher<I, O>>,
+        num_workers: usize,
+        prefetch_size: usize,
+        rng: Option<StdRng>,
+    ) -> Self {
+        assert!(
+            batch_size > 0,
+            "The batch size should be greater than zero."
+        );
+        assert!(
+            num_workers > 0,
+            "The number of workers should be greater than zero."
+        );
+        assert!(
+            prefetch_size > 0,
+            "The prefetch size should be greater than zero."
+        );
+
+        let (sender, receiver) = mpsc::channel::<Job<I, O>>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..num_workers)
+            .map(|_| {
+                let receiver = receiver.clone();
+                let batcher = batcher.clone();
+
+                thread::spawn(move || loop {
+                    // The lock is released as soon as a job is received, so the other workers can
+                    // pick the next ones while this one is loading its batch.
+                    let job = match receiver.lock() {
+                        Ok(receiver) => receiver.recv(),
+                        Err(_) => return,
+                    };
+                    // The data loader is dropped.
+                    let Ok(job) = job else {
+                        return;
+                    };
+
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                        let items = job
+                            .items
+                            .filter_map(|index| job.dataset.get(index))
+                            .collect();
+                        batcher.batch(items)
+                    }));
+
+                    // The iterator is probably gone, no need to panic.
+                    job.sender.send((job.index, result)).ok();
+                })
+            })
+            .collect();
+
+        Self {
+            batch_size,
+            prefetch_size,
+            dataset,
+            rng: rng.map(spin::Mutex::new),
+            jobs: Some(sender),
+            workers,
+        }
+    }
+}
+
+impl<I, O> DataLoader<O> for PrefetchDataLoader<I, O>
+where
+    I: Send + Sync + Clone + 'static,
+    O: Send + 'static,
+{
+    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        // Same as the batch data loader, the rng is advanced to shuffle the dataset differently
+        // at each iteration.
+        let dataset = match &self.rng {
+            Some(rng) => {
+                let mut rng = rng.lock();
+
+                Arc::new(ShuffledDataset::with_seed(
+                    self.dataset.clone(),
+                    rng.sample(Standard),
+                ))
+            }
+            None => self.dataset.clone(),
+        };
+
+        let (sender, receiver) = mpsc::sync_channel(self.prefetch_size);
+        let mut iterator = PrefetchDataLoaderIterator {
+            jobs: self.jobs.as_ref().unwrap(),
+            dataset,
+            sender,
+            receiver,
+            batch_size: self.batch_size,
+            num_batches_sent: 0,
+            num_batches_received: 0,
+            pending: BTreeMap::new(),
+        };
+
+        for _ in 0..self.prefetch_size {
+            iterator.send_next_job();
+        }
+
+        Box::new(iterator)
+    }
+
+    fn num_items(&self) -> usize {
+        self.dataset.len()
+    }
+}
+
+impl<I, O> Drop for PrefetchDataLoader<I, O> {
+    fn drop(&mut self) {
+        // Closing the channel stops the workers once they are done with their current batch.
+        self.jobs.take();
+
+        for worker in self.workers.drain(..) {
+            worker.join().ok();
+        }
+    }
+}
+
+struct PrefetchDataLoaderIterator<'a, I, O> {
+    jobs: &'a mpsc::Sender<Job<I, O>>,
+    dataset: Arc<dyn Dataset<I>>,
+    sender: mpsc::SyncSender<(usize, BatchResult<O>)>,
+    receiver: mpsc::Receiver<(usize, BatchResult<O>)>,
+    batch_size: usize,
+    num_batches_sent: usize,
+    num_batches_received: usize,
+    pending: BTreeMap<usize, BatchResult<O>>,
+}
+
+impl<'a, I, O> PrefetchDataLoaderIterator<'a, I, O> {
+    fn num_batches(&self) -> usize {
+        self.dataset.len().div_ceil(self.batch_size)
+    }
+
+    fn send_next_job(&mut self) {
+        if self.num_batches_sent == self.num_batches() {
+            return;
+        }
+
+        let index = self.num_batches_sent;
+        let start = index * self.batch_size;
+        let end = usize::min(start + self.batch_size, self.dataset.len());
+
+        self.jobs
+            .send(Job {
+                index,
+                dataset: self.dataset.clone(),
+                items: start..end,
+                sender: self.sender.clone(),
+            })
+            .expect("The workers of the data loader should be running.");
+        self.num_batches_sent += 1;
+    }
+}
+
+impl<'a, I, O> Iterator for PrefetchDataLoaderIterator<'a, I, O> {
+    type Item = O;
+
+    fn next(&mut self) -> Option<O> {
+        if self.num_batches_received == self.num_batches() {
+            return None;
+        }
+
+        // The batches can be completed out of order, they are kept until their turn comes.
+        let result = loop {
+            if let Some(result) = self.pending.remove(&self.num_batches_received) {
+                break result;
+            }
+
+            let (index, result) = self.receiver.recv().unwrap();
+            self.pending.insert(index, result);
+        };
+
+        self.num_batches_received += 1;
+        self.send_next_job();
+
+        match result {
+            Ok(batch) => Some(batch),
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
+}
+
+impl<'a, I, O> DataLoaderIterator<O> for PrefetchDataLoaderIterator<'a, I, O> {
+    fn progress(&self) -> Progress {
+        let items_total = self.dataset.len();
+        let items_processed = usize::min(self.num_batches_received * self.batch_size, items_total);
+
+        Progress::new(items_processed, items_total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::batcher::TestBatcher;
+    use crate::data::dataloader::{BatchDataLoader, FixBatchStrategy};
+    use crate::data::dataset::FakeDataset;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_prefetch_dataloader_matches_single_thread() {
+        let batcher = Arc::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader_single_thread = BatchDataLoader::new(
+            Box::new(FixBatchStrategy::new(5)),
+            dataset.clone(),
+            batcher.clone(),
+            None,
+        );
+        let dataloader_prefetch = PrefetchDataLoader::new(5, dataset, batcher, 4, 2, None);
+
+        let batches_single_thread: Vec<_> = dataloader_single_thread.iter().collect();
+        let batches_prefetch: Vec<_> = dataloader_prefetch.iter().collect();
+
+        assert_eq!(batches_prefetch.len(), 6);
+        assert_eq!(batches_single_thread, batches_prefetch);
+    }
+
+    #[test]
+    fn test_prefetch_dataloader_shuffle_is_independent_of_num_workers() {
+        let batcher = Arc::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader_single_thread = BatchDataLoader::new(
+            Box::new(FixBatchStrategy::new(4)),
+            dataset.clone(),
+            batcher.clone(),
+            Some(StdRng::seed_from_u64(42)),
+        );
+        let dataloaders_prefetch = [1, 3, 8].map(|num_workers| {
+            PrefetchDataLoader::new(
+                4,
+                dataset.clone(),
+                batcher.clone(),
+                num_workers,
+                3,
+                Some(StdRng::seed_from_u64(42)),
+            )
+        });
+
+        // Each iteration is shuffled differently, the same way for every data loader.
+        for _ in 0..2 {
+            let expected: Vec<_> = dataloader_single_thread.iter().collect();
+
+            for dataloader in dataloaders_prefetch.iter() {
+                let batches: Vec<_> = dataloader.iter().collect();
+                assert_eq!(batches, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_prefetch_dataloader_progress() {
+        let batcher = Arc::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(7));
+        let dataloader = PrefetchDataLoader::new(3, dataset, batcher, 2, 1, None);
+
+        let mut iterator = dataloader.iter();
+        let mut progresses = Vec::new();
+        while iterator.next().is_some() {
+            progresses.push(iterator.progress().items_processed);
+        }
+
+        assert_eq!(progresses, vec![3, 6, 7]);
+        assert_eq!(iterator.progress().items_total, 7);
+    }
+
+    #[test]
+    fn test_prefetch_dataloader_iterator_dropped_early() {
+        let batcher = Arc::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(20));
+        let dataloader = PrefetchDataLoader::new(2, dataset, batcher, 2, 4, None);
+
+        let first = dataloader.iter().next();
+        let batches: Vec<_> = dataloader.iter().collect();
+
+        assert_eq!(batches.len(), 10);
+        assert_eq!(first.as_ref(), batches.first());
+    }
+}
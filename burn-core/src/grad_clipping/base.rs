@@ -1,7 +1,10 @@
 use crate as burn;
 
+use crate::module::{AutodiffModule, ModuleVisitor, ParamId};
+use crate::optim::GradientsParams;
 use crate::{config::Config, tensor::Tensor};
-use burn_tensor::backend::Backend;
+use burn_tensor::backend::{AutodiffBackend, Backend};
+use core::marker::PhantomData;
 
 /// Gradient Clipping provides a way to mitigate exploding gradients
 #[derive(Config)]
@@ -11,6 +14,9 @@ pub enum GradientClippingConfig {
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip the gradients by their global norm, computed over all the parameters.
+    GlobalNorm(f32),
 }
 
 impl GradientClippingConfig {
@@ -23,6 +29,7 @@ impl GradientClippingConfig {
         match self {
             GradientClippingConfig::Value(val) => GradientClipping::Value(*val),
             GradientClippingConfig::Norm(val) => GradientClipping::Norm(*val),
+            GradientClippingConfig::GlobalNorm(val) => GradientClipping::GlobalNorm(*val),
         }
     }
 }
@@ -36,11 +43,21 @@ pub enum GradientClipping {
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip the gradients by their global norm, computed over all the parameters.
+    ///
+    /// When the global norm exceeds the threshold, all the gradients are scaled by the same
+    /// factor so that the global norm equals the threshold.
+    GlobalNorm(f32),
 }
 
 impl GradientClipping {
     /// Clip the gradient.
     ///
+    /// The global norm of a single gradient being its norm, [global norm](GradientClipping::GlobalNorm)
+    /// clipping is the same as [norm](GradientClipping::Norm) clipping here, use
+    /// [clip_gradients](Self::clip_gradients) to clip all the gradients of a module together.
+    ///
     /// # Arguments
     ///
     /// * `grad` - The gradient to clip.
@@ -51,10 +68,63 @@ impl GradientClipping {
     pub fn clip_gradient<B: Backend, const D: usize>(&self, grad: Tensor<B, D>) -> Tensor<B, D> {
         match self {
             GradientClipping::Value(threshold) => self.clip_by_value(grad, *threshold),
-            GradientClipping::Norm(max_norm) => self.clip_by_norm(grad, *max_norm),
+            GradientClipping::Norm(max_norm) | GradientClipping::GlobalNorm(max_norm) => {
+                self.clip_by_norm(grad, *max_norm)
+            }
         }
     }
 
+    /// Clip the gradients of all the parameters of the module.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module the gradients belong to.
+    /// * `grads` - The gradients to clip.
+    ///
+    /// # Returns
+    ///
+    /// The clipped gradients.
+    pub fn clip_gradients<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        module: &M,
+        mut grads: GradientsParams,
+    ) -> GradientsParams {
+        match self {
+            GradientClipping::GlobalNorm(max_norm) => {
+                let norm = Self::global_norm::<B, M>(module, &grads);
+
+                if norm > *max_norm {
+                    grads = grads.mul_scalar((max_norm / norm) as f64, module);
+                }
+            }
+            _ => {
+                let mut visitor = GradientsClipper::<M, B::InnerBackend>::new(&mut grads, self);
+                module.visit(&mut visitor);
+            }
+        }
+
+        grads
+    }
+
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    fn global_norm<B: AutodiffBackend, M: AutodiffModule<B>>(
+        _module: &M,
+        _grads: &GradientsParams,
+    ) -> f32 {
+        todo!("Not yet supported on wasm");
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn global_norm<B: AutodiffBackend, M: AutodiffModule<B>>(
+        module: &M,
+        grads: &GradientsParams,
+    ) -> f32 {
+        let mut visitor = GradientsSquaredNorm::<M, B::InnerBackend>::new(grads);
+        module.visit(&mut visitor);
+
+        visitor.sum.sqrt()
+    }
+
     fn clip_by_value<B: Backend, const D: usize>(
         &self,
         grad: Tensor<B, D>,
@@ -105,11 +175,58 @@ impl GradientClipping {
     }
 }
 
+#[derive(new)]
+struct GradientsSquaredNorm<'a, M, B: Backend> {
+    grads: &'a GradientsParams,
+    #[new(default)]
+    sum: f32,
+    #[new(default)]
+    phantom: PhantomData<(M, B)>,
+}
+
+#[derive(new)]
+struct GradientsClipper<'a, M, B: Backend> {
+    grads: &'a mut GradientsParams,
+    clipping: &'a GradientClipping,
+    #[new(default)]
+    phantom: PhantomData<(M, B)>,
+}
+
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+impl<'a, B, M> ModuleVisitor<B> for GradientsSquaredNorm<'a, M, B::InnerBackend>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        use burn_tensor::ElementConversion;
+
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            let squared_norm = grad.powf_scalar(2.0).sum().into_scalar();
+            self.sum += squared_norm.elem::<f32>();
+        }
+    }
+}
+
+impl<'a, B, M> ModuleVisitor<B> for GradientsClipper<'a, M, B::InnerBackend>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+            self.grads
+                .register::<B::InnerBackend, D>(id.clone(), self.clipping.clip_gradient(grad));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tensor::Tensor;
-    use crate::TestBackend;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::{Distribution, Tensor};
+    use crate::{TestAutodiffBackend, TestBackend};
 
     #[test]
     fn test_clip_by_value() {
@@ -146,4 +263,67 @@ mod tests {
             assert!(value <= 0.88);
         }
     }
+
+    #[test]
+    fn test_clip_by_global_norm() {
+        let (layer, grads) = huge_gradients();
+
+        let grads = GradientClipping::GlobalNorm(1.0).clip_gradients(&layer, grads);
+
+        let norm = GradientClipping::global_norm(&layer, &grads);
+        assert!(
+            norm <= 1.0 + 1e-4,
+            "Global norm {} should be at most 1",
+            norm
+        );
+        assert!(
+            norm >= 1.0 - 1e-4,
+            "Global norm {} should be close to 1",
+            norm
+        );
+    }
+
+    #[test]
+    fn test_clip_by_global_norm_keeps_direction() {
+        let (layer, grads) = huge_gradients();
+        let norm = GradientClipping::global_norm(&layer, &grads);
+        let expected = grads
+            .get::<TestBackend, 2>(&layer.weight.id)
+            .unwrap()
+            .div_scalar(norm);
+
+        let grads = GradientClipping::GlobalNorm(1.0).clip_gradients(&layer, grads);
+
+        grads
+            .get::<TestBackend, 2>(&layer.weight.id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    fn test_clip_by_global_norm_below_threshold() {
+        let (layer, grads) = huge_gradients();
+        let norm = GradientClipping::global_norm(&layer, &grads);
+
+        let grads = GradientClipping::GlobalNorm(norm * 2.0).clip_gradients(&layer, grads);
+
+        let norm_clipped = GradientClipping::global_norm(&layer, &grads);
+        assert_eq!(norm, norm_clipped);
+    }
+
+    fn huge_gradients() -> (Linear<TestAutodiffBackend>, GradientsParams) {
+        let device = Default::default();
+        let layer = LinearConfig::new(8, 4).init::<TestAutodiffBackend>(&device);
+        let input = Tensor::<TestAutodiffBackend, 2>::random(
+            [16, 8],
+            Distribution::Uniform(1e4, 1e5),
+            &device,
+        );
+
+        let grads = layer.forward(input).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &layer);
+
+        (layer, grads)
+    }
 }
@@ -0,0 +1,162 @@
+use burn_tensor::backend::Backend;
+use core::f64::consts::PI;
+use libm::cos;
+
+use crate as burn;
+
+use super::LrScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [cosine annealing with warm restarts](CosineAnnealingWarmRestartsLrScheduler)
+/// learning rate scheduler.
+#[derive(Config)]
+pub struct CosineAnnealingWarmRestartsLrSchedulerConfig {
+    /// The maximum learning rate, used at the start of each cycle.
+    eta_max: LearningRate,
+    /// The number of steps of the first cycle.
+    t_0: usize,
+    /// The factor by which the number of steps of a cycle is multiplied after each restart.
+    #[config(default = 1)]
+    t_mult: usize,
+    /// The minimum learning rate, reached at the end of each cycle.
+    #[config(default = 0.0)]
+    eta_min: LearningRate,
+}
+
+/// Cosine annealing with warm restarts learning rate scheduler as described in
+/// [SGDR: Stochastic Gradient Descent with Warm Restarts](https://arxiv.org/abs/1608.03983).
+///
+/// The learning rate decreases from `eta_max` to `eta_min` following a cosine curve during a
+/// cycle, then restarts at `eta_max` for a new cycle `t_mult` times longer.
+#[derive(Clone, Debug)]
+pub struct CosineAnnealingWarmRestartsLrScheduler {
+    eta_max: LearningRate,
+    eta_min: LearningRate,
+    t_mult: usize,
+    t_cur: usize,
+    t_i: usize,
+}
+
+impl CosineAnnealingWarmRestartsLrSchedulerConfig {
+    /// Initialize a new [cosine annealing with warm restarts](CosineAnnealingWarmRestartsLrScheduler)
+    /// learning rate scheduler.
+    ///
+    /// # Panics
+    ///
+    /// If `t_0` or `t_mult` is zero.
+    pub fn init(&self) -> CosineAnnealingWarmRestartsLrScheduler {
+        assert!(
+            self.t_0 > 0,
+            "The number of steps of a cycle must be positive"
+        );
+        assert!(
+            self.t_mult > 0,
+            "The cycle length multiplier must be positive"
+        );
+
+        CosineAnnealingWarmRestartsLrScheduler {
+            eta_max: self.eta_max,
+            eta_min: self.eta_min,
+            t_mult: self.t_mult,
+            t_cur: 0,
+            t_i: self.t_0,
+        }
+    }
+}
+
+impl<B: Backend> LrScheduler<B> for CosineAnnealingWarmRestartsLrScheduler {
+    type Record = (usize, usize);
+
+    fn step(&mut self) -> LearningRate {
+        let progress = self.t_cur as f64 / self.t_i as f64;
+        let lr = self.eta_min + (self.eta_max - self.eta_min) * (1.0 + cos(PI * progress)) / 2.0;
+
+        self.t_cur += 1;
+        if self.t_cur >= self.t_i {
+            self.t_cur = 0;
+            self.t_i *= self.t_mult;
+        }
+
+        lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        (self.t_cur, self.t_i)
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        (self.t_cur, self.t_i) = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TestBackend;
+
+    use super::*;
+
+    const ETA_MAX: LearningRate = 1.0;
+    const ETA_MIN: LearningRate = 0.1;
+
+    fn scheduler(t_0: usize, t_mult: usize) -> CosineAnnealingWarmRestartsLrScheduler {
+        CosineAnnealingWarmRestartsLrSchedulerConfig::new(ETA_MAX, t_0)
+            .with_t_mult(t_mult)
+            .with_eta_min(ETA_MIN)
+            .init()
+    }
+
+    fn steps(scheduler: &mut CosineAnnealingWarmRestartsLrScheduler, n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|_| LrScheduler::<TestBackend>::step(scheduler))
+            .collect()
+    }
+
+    fn assert_approx_eq(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-10,
+            "Expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_lr_mid_cycle() {
+        let lrs = steps(&mut scheduler(10, 1), 10);
+
+        assert_approx_eq(lrs[0], ETA_MAX);
+        assert_approx_eq(lrs[5], (ETA_MAX + ETA_MIN) / 2.0);
+        assert!(lrs.windows(2).all(|w| w[1] < w[0]));
+    }
+
+    #[test]
+    fn test_lr_restarts_at_cycle_boundary() {
+        let lrs = steps(&mut scheduler(10, 1), 21);
+
+        assert!(lrs[9] < ETA_MIN + 0.1);
+        assert_approx_eq(lrs[10], ETA_MAX);
+        assert_approx_eq(lrs[15], (ETA_MAX + ETA_MIN) / 2.0);
+        assert_approx_eq(lrs[20], ETA_MAX);
+    }
+
+    #[test]
+    fn test_cycle_length_multiplied_after_restart() {
+        let lrs = steps(&mut scheduler(4, 2), 15);
+
+        assert_approx_eq(lrs[4], ETA_MAX);
+        // The second cycle lasts 8 steps, the middle being reached after 4 of them.
+        assert_approx_eq(lrs[8], (ETA_MAX + ETA_MIN) / 2.0);
+        assert!(lrs[11] > ETA_MIN);
+        assert_approx_eq(lrs[12], ETA_MAX);
+    }
+
+    #[test]
+    fn test_load_record_resumes_schedule() {
+        let mut scheduler_1 = scheduler(4, 2);
+        steps(&mut scheduler_1, 6);
+        let record = LrScheduler::<TestBackend>::to_record(&scheduler_1);
+
+        let mut scheduler_2 = LrScheduler::<TestBackend>::load_record(scheduler(4, 2), record);
+
+        assert_eq!(steps(&mut scheduler_1, 10), steps(&mut scheduler_2, 10));
+    }
+}
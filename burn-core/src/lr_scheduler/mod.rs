@@ -4,6 +4,12 @@ pub mod constant;
 /// Noam Learning rate schedule
 pub mod noam;
 
+/// Cosine annealing with warm restarts learning rate schedule
+pub mod cosine;
+
+/// Polynomial decay learning rate schedule with linear warmup
+pub mod polynomial;
+
 mod base;
 
 pub use base::*;
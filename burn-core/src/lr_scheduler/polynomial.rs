@@ -0,0 +1,163 @@
+use burn_tensor::backend::Backend;
+use libm::pow;
+
+use crate as burn;
+
+use super::LrScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [polynomial decay](PolynomialDecayLrScheduler) learning rate
+/// scheduler.
+#[derive(Config)]
+pub struct PolynomialDecayLrSchedulerConfig {
+    /// The learning rate reached at the end of the warmup, from which the decay starts.
+    start_lr: LearningRate,
+    /// The number of steps of the decay.
+    decay_steps: usize,
+    /// The learning rate reached at the end of the decay, and kept afterward.
+    #[config(default = 0.0)]
+    end_lr: LearningRate,
+    /// The number of steps of the linear warmup.
+    #[config(default = 0)]
+    warmup_steps: usize,
+    /// The power of the polynomial decay, a power of one being a linear decay.
+    #[config(default = 1.0)]
+    power: f64,
+}
+
+/// Polynomial decay learning rate scheduler with a linear warmup.
+///
+/// The learning rate increases linearly from zero to `start_lr` during `warmup_steps`, then
+/// decreases to `end_lr` during `decay_steps` following
+/// `(start_lr - end_lr) * (1 - t / decay_steps) ^ power + end_lr`.
+#[derive(Clone, Debug)]
+pub struct PolynomialDecayLrScheduler {
+    start_lr: LearningRate,
+    end_lr: LearningRate,
+    warmup_steps: usize,
+    decay_steps: usize,
+    power: f64,
+    step: usize,
+}
+
+impl PolynomialDecayLrSchedulerConfig {
+    /// Initialize a new [polynomial decay](PolynomialDecayLrScheduler) learning rate scheduler.
+    ///
+    /// # Panics
+    ///
+    /// If `decay_steps` is zero.
+    pub fn init(&self) -> PolynomialDecayLrScheduler {
+        assert!(
+            self.decay_steps > 0,
+            "The number of decay steps must be positive"
+        );
+
+        PolynomialDecayLrScheduler {
+            start_lr: self.start_lr,
+            end_lr: self.end_lr,
+            warmup_steps: self.warmup_steps,
+            decay_steps: self.decay_steps,
+            power: self.power,
+            step: 0,
+        }
+    }
+}
+
+impl PolynomialDecayLrScheduler {
+    fn lr(&self) -> LearningRate {
+        if self.step < self.warmup_steps {
+            return self.start_lr * self.step as f64 / self.warmup_steps as f64;
+        }
+
+        let decay_step = usize::min(self.step - self.warmup_steps, self.decay_steps);
+        let remaining = 1.0 - decay_step as f64 / self.decay_steps as f64;
+
+        (self.start_lr - self.end_lr) * pow(remaining, self.power) + self.end_lr
+    }
+}
+
+impl<B: Backend> LrScheduler<B> for PolynomialDecayLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let lr = self.lr();
+        self.step += 1;
+
+        lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TestBackend;
+
+    use super::*;
+
+    fn lrs(config: PolynomialDecayLrSchedulerConfig, n: usize) -> Vec<f64> {
+        let mut scheduler = config.init();
+
+        (0..n)
+            .map(|_| LrScheduler::<TestBackend>::step(&mut scheduler))
+            .collect()
+    }
+
+    fn assert_approx_eq(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-10,
+            "Expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_warmup_then_decay() {
+        let config = PolynomialDecayLrSchedulerConfig::new(1.0, 8)
+            .with_end_lr(0.2)
+            .with_warmup_steps(4)
+            .with_power(2.0);
+
+        let lrs = lrs(config, 16);
+
+        assert_approx_eq(lrs[0], 0.0);
+        assert_approx_eq(lrs[2], 0.5);
+        assert_approx_eq(lrs[4], 1.0);
+        // Half way through the decay: 0.8 * 0.5^2 + 0.2.
+        assert_approx_eq(lrs[8], 0.4);
+        assert_approx_eq(lrs[12], 0.2);
+        assert_approx_eq(lrs[15], 0.2);
+    }
+
+    #[test]
+    fn test_linear_decay_without_warmup() {
+        let config = PolynomialDecayLrSchedulerConfig::new(1.0, 4);
+
+        let lrs = lrs(config, 6);
+
+        assert_eq!(lrs, vec![1.0, 0.75, 0.5, 0.25, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_load_record_resumes_schedule() {
+        let config = PolynomialDecayLrSchedulerConfig::new(1.0, 8).with_warmup_steps(4);
+        let mut scheduler_1 = config.init();
+        for _ in 0..6 {
+            LrScheduler::<TestBackend>::step(&mut scheduler_1);
+        }
+        let record = LrScheduler::<TestBackend>::to_record(&scheduler_1);
+
+        let mut scheduler_2 = LrScheduler::<TestBackend>::load_record(config.init(), record);
+
+        assert_eq!(
+            LrScheduler::<TestBackend>::step(&mut scheduler_1),
+            LrScheduler::<TestBackend>::step(&mut scheduler_2)
+        );
+    }
+}
@@ -201,6 +201,48 @@ pub trait Module<B: Backend>: Clone + Send + Sync + core::fmt::Debug {
 
         Ok(self.load_record(record))
     }
+
+    #[cfg(feature = "safetensors")]
+    /// Save the module to a file in the [safetensors](crate::record::SafetensorsFileRecorder)
+    /// format, with the full precision settings.
+    ///
+    /// ## Notes
+    ///
+    /// The parameters are stored under the dot-separated paths of their fields, such as
+    /// `layers.0.weight`, like the state dicts of PyTorch.
+    fn save_to_safetensors<PB>(self, file_path: PB) -> Result<(), crate::record::RecorderError>
+    where
+        PB: Into<std::path::PathBuf>,
+    {
+        let recorder =
+            crate::record::SafetensorsFileRecorder::<crate::record::FullPrecisionSettings>::new();
+
+        self.save_file(file_path, &recorder)
+    }
+
+    #[cfg(feature = "safetensors")]
+    /// Load the module from a file in the [safetensors](crate::record::SafetensorsFileRecorder)
+    /// format.
+    ///
+    /// ## Notes
+    ///
+    /// The parameters are loaded from the dot-separated paths of their fields, such as
+    /// `layers.0.weight`, and the float weights stored as `f32`, `f16` or `bf16` are converted to
+    /// the float elements of the backend. The parameters missing from the file keep their
+    /// default values.
+    fn load_from_safetensors<PB>(
+        self,
+        file_path: PB,
+        device: &B::Device,
+    ) -> Result<Self, crate::record::RecorderError>
+    where
+        PB: Into<std::path::PathBuf>,
+    {
+        let recorder =
+            crate::record::SafetensorsFileRecorder::<crate::record::FullPrecisionSettings>::new();
+
+        self.load_file(file_path, &recorder, device)
+    }
 }
 
 /// Module visitor trait.
@@ -1,5 +1,7 @@
 mod base;
 mod param;
+mod summary;
 
 pub use base::*;
 pub use param::*;
+pub use summary::*;
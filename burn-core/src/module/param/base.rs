@@ -14,6 +14,13 @@ impl<T> core::fmt::Display for Param<T> {
     }
 }
 
+impl<T> Param<T> {
+    /// Gets the parameter id.
+    pub fn id(&self) -> &ParamId {
+        &self.id
+    }
+}
+
 impl<T: Clone> Param<T> {
     /// Gets the parameter value.
     ///
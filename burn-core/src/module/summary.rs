@@ -0,0 +1,210 @@
+use super::{Module, ModuleVisitor, ParamId};
+use crate::tensor::{backend::Backend, Tensor};
+use alloc::string::ToString;
+use core::fmt::Display;
+use core::marker::PhantomData;
+
+/// Estimation of the number of floating point operations (FLOPs) performed by the forward pass
+/// of a module.
+///
+/// Only the matrix products and the additions of biases are counted, a multiply-accumulate
+/// being two operations. Element wise operations such as activations and normalizations are
+/// ignored, since their cost is usually negligible in comparison.
+///
+/// The trait is implemented for the common layers. It can be implemented for a model by adding
+/// the estimations of its layers with the inputs they receive.
+pub trait ForwardFlops<I> {
+    /// Estimate the number of floating point operations of the forward pass with the given
+    /// input.
+    fn forward_flops(&self, input: &I) -> u64;
+}
+
+/// Summary of the size and the cost of a model.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_core::module::ModelSummary;
+/// use burn_core::nn::LinearConfig;
+/// use burn_core::tensor::{backend::Backend, Tensor};
+///
+/// fn summary<B: Backend>(device: &B::Device) {
+///     let model = LinearConfig::new(4, 3).init::<B>(device);
+///     let input = Tensor::<B, 2>::zeros([2, 4], device);
+///
+///     let summary = ModelSummary::new(&model, &input);
+///     println!("{summary}");
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelSummary {
+    total_params: usize,
+    trainable_params: usize,
+    estimated_flops: u64,
+}
+
+impl ModelSummary {
+    /// Create the summary of the given model, the FLOPs being estimated for the forward pass of
+    /// the example input.
+    pub fn new<B, M, I>(model: &M, example_input: &I) -> Self
+    where
+        B: Backend,
+        M: Module<B> + ForwardFlops<I>,
+    {
+        let mut visitor = TrainableParamsCounter::<B>::new();
+        model.visit(&mut visitor);
+
+        Self {
+            total_params: model.num_params(),
+            trainable_params: visitor.num_params,
+            estimated_flops: model.forward_flops(example_input),
+        }
+    }
+
+    /// The number of elements of all the tensors of the model, including the running states.
+    pub fn total_params(&self) -> usize {
+        self.total_params
+    }
+
+    /// The number of elements of the tensors requiring gradients, which are only tracked with an
+    /// [autodiff backend](crate::tensor::backend::AutodiffBackend).
+    pub fn trainable_params(&self) -> usize {
+        self.trainable_params
+    }
+
+    /// The estimated number of floating point operations of the forward pass.
+    pub fn estimated_flops(&self) -> u64 {
+        self.estimated_flops
+    }
+}
+
+impl Display for ModelSummary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let rows = [
+            ("Total params", self.total_params as u64),
+            ("Trainable params", self.trainable_params as u64),
+            (
+                "Non-trainable params",
+                (self.total_params - self.trainable_params) as u64,
+            ),
+            ("Estimated FLOPs", self.estimated_flops),
+        ];
+        let width_name = rows.iter().map(|(name, _)| name.len()).max().unwrap();
+        let width_value = rows
+            .iter()
+            .map(|(_, value)| value.to_string().len())
+            .fold("Value".len(), usize::max);
+
+        writeln!(
+            f,
+            "| {:<width_name$} | {:>width_value$} |",
+            "Metric", "Value"
+        )?;
+        writeln!(
+            f,
+            "|{}|{}|",
+            "-".repeat(width_name + 2),
+            "-".repeat(width_value + 2)
+        )?;
+        for (name, value) in rows {
+            writeln!(f, "| {name:<width_name$} | {value:>width_value$} |")?;
+        }
+
+        Ok(())
+    }
+}
+
+struct TrainableParamsCounter<B: Backend> {
+    num_params: usize,
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend> TrainableParamsCounter<B> {
+    fn new() -> Self {
+        Self {
+            num_params: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for TrainableParamsCounter<B> {
+    fn visit_float<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        if tensor.is_require_grad() {
+            self.num_params += tensor.shape().num_elements();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::nn::{BatchNorm, BatchNormConfig, Linear, LinearConfig};
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        linear: Linear<B>,
+        norm: BatchNorm<B, 0>,
+    }
+
+    impl<B: Backend> ForwardFlops<Tensor<B, 2>> for Model<B> {
+        fn forward_flops(&self, input: &Tensor<B, 2>) -> u64 {
+            self.linear.forward_flops(input)
+        }
+    }
+
+    fn model<B: Backend>(device: &B::Device) -> Model<B> {
+        Model {
+            linear: LinearConfig::new(4, 3).init(device),
+            norm: BatchNormConfig::new(3).init(device),
+        }
+    }
+
+    #[test]
+    fn summary_counts_params_and_flops() {
+        let device = Default::default();
+        let model = model::<TestAutodiffBackend>(&device);
+        let input = Tensor::zeros([2, 4], &device);
+
+        let summary = ModelSummary::new(&model, &input);
+
+        // The running mean and variance of the batch norm aren't trainable.
+        assert_eq!(summary.total_params(), 4 * 3 + 3 + 4 * 3);
+        assert_eq!(summary.trainable_params(), 4 * 3 + 3 + 2 * 3);
+        // Two rows with 4 multiply-accumulates and 1 bias addition per output feature.
+        assert_eq!(summary.estimated_flops(), 2 * 3 * (2 * 4 + 1));
+    }
+
+    #[test]
+    fn summary_without_autodiff_has_no_trainable_params() {
+        let device = Default::default();
+        let model = model::<TestBackend>(&device);
+        let input = Tensor::zeros([2, 4], &device);
+
+        let summary = ModelSummary::new(&model, &input);
+
+        assert_eq!(summary.total_params(), 27);
+        assert_eq!(summary.trainable_params(), 0);
+    }
+
+    #[test]
+    fn summary_display_as_table() {
+        let summary = ModelSummary {
+            total_params: 27,
+            trainable_params: 21,
+            estimated_flops: 54,
+        };
+
+        assert_eq!(
+            summary.to_string(),
+            "| Metric               | Value |\n\
+             |----------------------|-------|\n\
+             | Total params         |    27 |\n\
+             | Trainable params     |    21 |\n\
+             | Non-trainable params |     6 |\n\
+             | Estimated FLOPs      |    54 |\n"
+        );
+    }
+}
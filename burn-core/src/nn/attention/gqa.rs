@@ -0,0 +1,543 @@
+use crate as burn;
+
+use crate::nn::attention::{AttentionMechanism, KvCache, MhaInput, MhaOutput};
+use crate::nn::Initializer;
+use crate::{
+    config::Config,
+    module::{ForwardFlops, Module},
+    nn,
+    tensor::{backend::Backend, Bool, Tensor},
+};
+
+/// Configuration to create a [Grouped Query Attention](GroupedQueryAttention) layer.
+#[derive(Config)]
+pub struct GroupedQueryAttentionConfig {
+    /// The size of the query and output linear layers.
+    d_model: usize,
+    /// The number of query heads.
+    num_query_heads: usize,
+    /// The number of key and value heads, each shared by `num_query_heads / num_kv_heads` query
+    /// heads.
+    num_kv_heads: usize,
+    /// The dropout rate. Default: 0.1
+    #[config(default = 0.1)]
+    dropout: f64,
+    /// The minimum value a float can take. Default: -1.0e4
+    /// This is used to mask attention scores before calculating attention weights.
+    /// A value too low might result in NaN.
+    #[config(default = -1.0e4)]
+    min_float: f64,
+    /// Use "quiet softmax" instead of regular softmax.
+    ///
+    /// Reference: <https://www.evanmiller.org/attention-is-off-by-one.html>
+    #[config(default = false)]
+    quiet_softmax: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/libm::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Configuration to create a [Multi Query Attention](MultiQueryAttention) layer, a
+/// [grouped query attention](GroupedQueryAttention) with a single key and value head.
+#[derive(Config)]
+pub struct MultiQueryAttentionConfig {
+    /// The size of the query and output linear layers.
+    d_model: usize,
+    /// The number of query heads.
+    num_query_heads: usize,
+    /// The dropout rate. Default: 0.1
+    #[config(default = 0.1)]
+    dropout: f64,
+    /// The minimum value a float can take. Default: -1.0e4
+    /// This is used to mask attention scores before calculating attention weights.
+    /// A value too low might result in NaN.
+    #[config(default = -1.0e4)]
+    min_float: f64,
+    /// Use "quiet softmax" instead of regular softmax.
+    ///
+    /// Reference: <https://www.evanmiller.org/attention-is-off-by-one.html>
+    #[config(default = false)]
+    quiet_softmax: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/libm::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// The grouped query attention module as described in the paper
+/// [GQA: Training Generalized Multi-Query Transformer Models from Multi-Head Checkpoints](https://arxiv.org/abs/2305.13245).
+///
+/// The query heads are split into groups sharing the same key and value head, which reduces the
+/// size of the keys and values. With one key and value head, it's a
+/// [multi query attention](MultiQueryAttention), and with as many key and value heads as query
+/// heads, it's a [multihead attention](nn::attention::MultiHeadAttention).
+///
+/// # Params
+///
+/// - query: [Linear](nn::Linear) layer with `d_model` input and output features.
+/// - key: [Linear](nn::Linear) layer with `d_model` input features and `num_kv_heads * d_k`
+///   output features.
+/// - value: [Linear](nn::Linear) layer with `d_model` input features and `num_kv_heads * d_k`
+///   output features.
+/// - output: [Linear](nn::Linear) layer with `d_model` input and output features.
+///
+/// Where `d_k` is `d_model / num_query_heads`.
+#[derive(Module, Debug)]
+pub struct GroupedQueryAttention<B: Backend> {
+    query: nn::Linear<B>,
+    key: nn::Linear<B>,
+    value: nn::Linear<B>,
+    output: nn::Linear<B>,
+    dropout: nn::Dropout,
+    num_query_heads: usize,
+    num_kv_heads: usize,
+    d_k: usize,
+    min_float: f64,
+    quiet_softmax: bool,
+}
+
+/// The multi query attention module as described in the paper
+/// [Fast Transformer Decoding: One Write-Head is All You Need](https://arxiv.org/abs/1911.02150),
+/// a [grouped query attention](GroupedQueryAttention) with a single key and value head.
+pub type MultiQueryAttention<B> = GroupedQueryAttention<B>;
+
+impl GroupedQueryAttentionConfig {
+    /// Initialize a new [grouped query attention](GroupedQueryAttention) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> GroupedQueryAttention<B> {
+        self.check();
+
+        let d_k = self.d_model / self.num_query_heads;
+        let linear = |d_output: usize| {
+            nn::LinearConfig::new(self.d_model, d_output)
+                .with_initializer(self.initializer.clone())
+                .init(device)
+        };
+
+        GroupedQueryAttention {
+            query: linear(self.d_model),
+            key: linear(self.num_kv_heads * d_k),
+            value: linear(self.num_kv_heads * d_k),
+            output: linear(self.d_model),
+            dropout: nn::DropoutConfig::new(self.dropout).init(),
+            num_query_heads: self.num_query_heads,
+            num_kv_heads: self.num_kv_heads,
+            d_k,
+            min_float: self.min_float,
+            quiet_softmax: self.quiet_softmax,
+        }
+    }
+
+    /// Initialize a new [grouped query attention](GroupedQueryAttention) module with a
+    /// [record](GroupedQueryAttentionRecord).
+    pub fn init_with<B: Backend>(
+        &self,
+        record: GroupedQueryAttentionRecord<B>,
+    ) -> GroupedQueryAttention<B> {
+        self.check();
+
+        let d_k = self.d_model / self.num_query_heads;
+        let linear = |d_output: usize, record| {
+            nn::LinearConfig::new(self.d_model, d_output).init_with(record)
+        };
+
+        GroupedQueryAttention {
+            query: linear(self.d_model, record.query),
+            key: linear(self.num_kv_heads * d_k, record.key),
+            value: linear(self.num_kv_heads * d_k, record.value),
+            output: linear(self.d_model, record.output),
+            dropout: nn::DropoutConfig::new(self.dropout).init(),
+            num_query_heads: self.num_query_heads,
+            num_kv_heads: self.num_kv_heads,
+            d_k,
+            min_float: self.min_float,
+            quiet_softmax: self.quiet_softmax,
+        }
+    }
+
+    fn check(&self) {
+        assert!(
+            self.num_query_heads > 0 && self.d_model.is_multiple_of(self.num_query_heads),
+            "The model size {} should be divisible by the number of query heads {}.",
+            self.d_model,
+            self.num_query_heads
+        );
+        assert!(
+            self.num_kv_heads > 0 && self.num_query_heads.is_multiple_of(self.num_kv_heads),
+            "The number of query heads {} should be divisible by the number of key and value heads {}.",
+            self.num_query_heads,
+            self.num_kv_heads
+        );
+    }
+}
+
+impl MultiQueryAttentionConfig {
+    /// Initialize a new [multi query attention](MultiQueryAttention) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> MultiQueryAttention<B> {
+        self.grouped().init(device)
+    }
+
+    /// Initialize a new [multi query attention](MultiQueryAttention) module with a
+    /// [record](GroupedQueryAttentionRecord).
+    pub fn init_with<B: Backend>(
+        &self,
+        record: GroupedQueryAttentionRecord<B>,
+    ) -> MultiQueryAttention<B> {
+        self.grouped().init_with(record)
+    }
+
+    fn grouped(&self) -> GroupedQueryAttentionConfig {
+        GroupedQueryAttentionConfig::new(self.d_model, self.num_query_heads, 1)
+            .with_dropout(self.dropout)
+            .with_min_float(self.min_float)
+            .with_quiet_softmax(self.quiet_softmax)
+            .with_initializer(self.initializer.clone())
+    }
+}
+
+impl<B: Backend> GroupedQueryAttention<B> {
+    /// Applies the forward pass on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    ///
+    /// The attention weights of the output have the shape
+    /// `[batch_size, num_query_heads, seq_length_1, seq_length_2]`.
+    pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
+        let query = self.attention_linear(input.query, &self.query, self.num_query_heads);
+        let key = self.attention_linear(input.key, &self.key, self.num_kv_heads);
+        let value = self.attention_linear(input.value, &self.value, self.num_kv_heads);
+
+        self.attention(query, key, value, input.mask_pad, input.mask_attn)
+    }
+
+    /// Applies the forward pass using a [key-value cache](KvCache), with the new tokens only as
+    /// input.
+    ///
+    /// The keys and values of the new tokens are appended to the cache, created with
+    /// `num_kv_heads` heads of size `d_model / num_query_heads`, and the queries attend to all
+    /// the tokens of the cache.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length, d_model]`
+    /// - key: `[batch_size, seq_length, d_model]`
+    /// - value: `[batch_size, seq_length, d_model]`
+    /// - mask_pad: `[batch_size, cached_seq_length]`
+    /// - mask_attn: `[batch_size, seq_length, cached_seq_length]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward_kv_cache(&self, input: MhaInput<B>, cache: &mut KvCache<B>) -> MhaOutput<B> {
+        let query = self.attention_linear(input.query, &self.query, self.num_query_heads);
+        let key = self.attention_linear(input.key, &self.key, self.num_kv_heads);
+        let value = self.attention_linear(input.value, &self.value, self.num_kv_heads);
+        let (key, value) = cache.forward(key, value);
+
+        self.attention(query, key, value, input.mask_pad, input.mask_attn)
+    }
+
+    fn attention(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+    ) -> MhaOutput<B> {
+        let [batch_size, _, seq_length_1, _] = query.dims();
+        let [_, _, seq_length_2, _] = key.dims();
+        let group_size = self.num_query_heads / self.num_kv_heads;
+
+        // The query heads of a group are stacked along the sequence, so that each key and value
+        // head is used by all of them without being repeated.
+        let query = query.reshape([
+            batch_size,
+            self.num_kv_heads,
+            group_size * seq_length_1,
+            self.d_k,
+        ]);
+
+        let attn_scores = self.attn_scores(query, key).reshape([
+            batch_size,
+            self.num_query_heads,
+            seq_length_1,
+            seq_length_2,
+        ]);
+        let weights = self.attn_weights(attn_scores, mask_pad, mask_attn);
+
+        let context = weights
+            .clone()
+            .reshape([
+                batch_size,
+                self.num_kv_heads,
+                group_size * seq_length_1,
+                seq_length_2,
+            ])
+            .matmul(value)
+            .reshape([batch_size, self.num_query_heads, seq_length_1, self.d_k])
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, self.num_query_heads * self.d_k]);
+        let context = self.output.forward(context);
+
+        MhaOutput { weights, context }
+    }
+
+    fn attention_linear(
+        &self,
+        x: Tensor<B, 3>,
+        linear: &nn::Linear<B>,
+        n_heads: usize,
+    ) -> Tensor<B, 4> {
+        let [batch_size, seq_length, _d_model] = x.dims();
+        linear
+            .forward(x)
+            .reshape([batch_size, seq_length, n_heads, self.d_k])
+            .swap_dims(1, 2)
+    }
+}
+
+impl<B: Backend> AttentionMechanism<B> for GroupedQueryAttention<B> {
+    fn head_dim(&self) -> usize {
+        self.d_k
+    }
+
+    fn dropout(&self) -> &nn::Dropout {
+        &self.dropout
+    }
+
+    fn min_float(&self) -> f64 {
+        self.min_float
+    }
+
+    fn quiet_softmax(&self) -> bool {
+        self.quiet_softmax
+    }
+}
+
+impl<B: Backend> ForwardFlops<MhaInput<B>> for GroupedQueryAttention<B> {
+    fn forward_flops(&self, input: &MhaInput<B>) -> u64 {
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
+        let [_, seq_length_2, _] = input.key.dims();
+
+        let projections = self.query.forward_flops(&input.query)
+            + self.key.forward_flops(&input.key)
+            + self.value.forward_flops(&input.value)
+            + self.output.forward_flops(&input.query);
+        // Same as the multihead attention, each query head attends to the keys of its group.
+        let attention = 2 * 2 * batch_size * seq_length_1 * seq_length_2 * d_model;
+
+        projections + attention as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::attention::{generate_autoregressive_mask, MultiHeadAttentionConfig};
+    use crate::{TestAutodiffBackend, TestBackend};
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use burn::tensor::{Distribution, Shape};
+
+    #[test]
+    fn test_gqa_shapes() {
+        let [batch_size, seq_length_1, seq_length_2, d_model] = [3, 5, 7, 32];
+        let [num_query_heads, num_kv_heads] = [8, 2];
+        let device = Default::default();
+        let gqa = GroupedQueryAttentionConfig::new(d_model, num_query_heads, num_kv_heads)
+            .init::<TestBackend>(&device);
+        let key = Tensor::random(
+            [batch_size, seq_length_2, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let input = MhaInput::new(
+            Tensor::random(
+                [batch_size, seq_length_1, d_model],
+                Distribution::Default,
+                &device,
+            ),
+            key.clone(),
+            key,
+        );
+
+        let output = gqa.forward(input);
+
+        assert_eq!(
+            output.context.shape(),
+            Shape::new([batch_size, seq_length_1, d_model]),
+        );
+        assert_eq!(
+            output.weights.shape(),
+            Shape::new([batch_size, num_query_heads, seq_length_1, seq_length_2]),
+        );
+        assert_eq!(gqa.key.weight.shape(), Shape::new([d_model, 2 * 4]));
+    }
+
+    #[test]
+    fn test_mqa_shapes() {
+        let [batch_size, seq_length, d_model, num_query_heads] = [2, 6, 16, 4];
+        let device = Default::default();
+        let mqa =
+            MultiQueryAttentionConfig::new(d_model, num_query_heads).init::<TestBackend>(&device);
+        let input = MhaInput::self_attn(Tensor::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        ));
+
+        let output = mqa.forward(input);
+
+        assert_eq!(mqa.num_kv_heads, 1);
+        assert_eq!(
+            output.context.shape(),
+            Shape::new([batch_size, seq_length, d_model]),
+        );
+        assert_eq!(
+            output.weights.shape(),
+            Shape::new([batch_size, num_query_heads, seq_length, seq_length]),
+        );
+    }
+
+    #[test]
+    fn test_gqa_with_as_many_kv_heads_as_query_heads_matches_mha() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 12, 3];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+        let record = mha.clone().into_record();
+        let linear = |record| nn::LinearConfig::new(d_model, d_model).init_with(record);
+        let mut gqa = GroupedQueryAttentionConfig::new(d_model, n_heads, n_heads)
+            .init::<TestBackend>(&device);
+        gqa.query = linear(record.query);
+        gqa.key = linear(record.key);
+        gqa.value = linear(record.value);
+        gqa.output = linear(record.output);
+
+        let tensor = Tensor::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &device);
+        let input = MhaInput::self_attn(tensor).mask_attn(mask_attn);
+
+        let expected = mha.forward(input.clone());
+        let actual = gqa.forward(input);
+
+        expected
+            .context
+            .into_data()
+            .assert_approx_eq(&actual.context.into_data(), 3);
+        expected
+            .weights
+            .into_data()
+            .assert_approx_eq(&actual.weights.into_data(), 3);
+    }
+
+    #[test]
+    fn test_gqa_gradients_flow_through_all_head_groups() {
+        let [batch_size, seq_length, d_model] = [2, 4, 24];
+        let [num_query_heads, num_kv_heads] = [6, 3];
+        let d_k = d_model / num_query_heads;
+        let device = Default::default();
+        let gqa = GroupedQueryAttentionConfig::new(d_model, num_query_heads, num_kv_heads)
+            .with_dropout(0.0)
+            .init::<TestAutodiffBackend>(&device);
+        let input = MhaInput::self_attn(Tensor::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        ));
+
+        let output = gqa.forward(input);
+        let grads = output.context.powf_scalar(2.0).sum().backward();
+
+        // The output features of the projections are split into heads.
+        let head_grads = |grad: Tensor<TestBackend, 2>, n_heads: usize| {
+            (0..n_heads)
+                .map(|head| {
+                    grad.clone()
+                        .slice([0..d_model, head * d_k..(head + 1) * d_k])
+                        .abs()
+                        .sum()
+                        .into_scalar()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let query_grad = gqa.query.weight.grad(&grads).unwrap();
+        let key_grad = gqa.key.weight.grad(&grads).unwrap();
+        let value_grad = gqa.value.weight.grad(&grads).unwrap();
+
+        for grad in head_grads(query_grad, num_query_heads)
+            .into_iter()
+            .chain(head_grads(key_grad, num_kv_heads))
+            .chain(head_grads(value_grad, num_kv_heads))
+        {
+            assert!(grad > 0.0, "Every head should receive a gradient");
+        }
+    }
+
+    #[test]
+    fn test_gqa_kv_cache_should_have_same_output_as_autoregressive_mask() {
+        let [batch_size, seq_length, d_model] = [2, 6, 16];
+        let [num_query_heads, num_kv_heads] = [4, 2];
+        let device = Default::default();
+        let gqa = GroupedQueryAttentionConfig::new(d_model, num_query_heads, num_kv_heads)
+            .init::<TestBackend>(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &device);
+        let input = MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn.clone());
+
+        let output_1 = gqa.forward(input);
+        let mut cache = KvCache::new(batch_size, seq_length, num_kv_heads, 4, &device);
+
+        // The prompt is processed at once, then the next tokens one at a time.
+        let prompt = tensor.clone().slice([0..batch_size, 0..3, 0..d_model]);
+        let input =
+            MhaInput::self_attn(prompt).mask_attn(mask_attn.slice([0..batch_size, 0..3, 0..3]));
+        let mut output_2 = vec![gqa.forward_kv_cache(input, &mut cache).context];
+
+        for i in 3..seq_length {
+            let token = tensor.clone().slice([0..batch_size, i..i + 1, 0..d_model]);
+            let input = MhaInput::self_attn(token);
+            output_2.push(gqa.forward_kv_cache(input, &mut cache).context);
+        }
+
+        let output_2 = Tensor::cat(output_2, 1);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gqa_should_panic_when_query_heads_not_divisible_by_kv_heads() {
+        GroupedQueryAttentionConfig::new(24, 6, 4).init::<TestBackend>(&Default::default());
+    }
+
+    #[test]
+    fn forward_flops() {
+        let device = Default::default();
+        let gqa = GroupedQueryAttentionConfig::new(8, 4, 2).init::<TestBackend>(&device);
+        let query = Tensor::zeros([2, 3, 8], &device);
+        let key = Tensor::zeros([2, 5, 8], &device);
+        let input = MhaInput::new(query, key.clone(), key);
+
+        let projections = 2 * (3 + 3) * 8 * (2 * 8 + 1) + 2 * 2 * 5 * 4 * (2 * 8 + 1);
+        let attention = 2 * 2 * 2 * 3 * 5 * 8;
+        assert_eq!(gqa.forward_flops(&input), projections + attention);
+    }
+}
@@ -0,0 +1,205 @@
+use crate::tensor::{backend::Backend, Tensor};
+
+/// Cache of the keys and values of the previous tokens for autoregressive inference with the
+/// [multihead attention](super::MultiHeadAttention) and
+/// [grouped query attention](super::GroupedQueryAttention) layers.
+///
+/// The keys and values are stored in tensors allocated for `max_seq_len` tokens, and each call
+/// appends the keys and values of the new tokens to the ones of the previous tokens. The cache
+/// isn't a module, so it's never part of the parameters of the attention layers.
+pub struct KvCache<B: Backend> {
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    batch_size: Option<usize>,
+    seq_length: usize,
+}
+
+impl<B: Backend> KvCache<B> {
+    /// Creates a new empty cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_batch_size` - The maximum batch size.
+    /// * `max_seq_len` - The maximum number of tokens kept in the cache.
+    /// * `num_heads` - The number of key and value heads.
+    /// * `head_dim` - The size of each head.
+    /// * `device` - The device where the keys and values are stored.
+    pub fn new(
+        max_batch_size: usize,
+        max_seq_len: usize,
+        num_heads: usize,
+        head_dim: usize,
+        device: &B::Device,
+    ) -> Self {
+        let shape = [max_batch_size, num_heads, max_seq_len, head_dim];
+
+        Self {
+            key: Tensor::zeros(shape, device),
+            value: Tensor::zeros(shape, device),
+            batch_size: None,
+            seq_length: 0,
+        }
+    }
+
+    /// Appends the keys and values of the new tokens to the cache.
+    ///
+    /// # Shapes
+    ///
+    /// - key: `[batch_size, num_heads, seq_length, head_dim]`
+    /// - value: `[batch_size, num_heads, seq_length, head_dim]`
+    ///
+    /// # Returns
+    ///
+    /// The keys and values of all the tokens in the cache, of shape
+    /// `[batch_size, num_heads, cached_seq_length, head_dim]`.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes don't match the cache, if the batch size isn't the one of the previous
+    /// tokens, or if the cache would hold more than `max_seq_len` tokens.
+    pub fn forward(
+        &mut self,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+    ) -> (Tensor<B, 4>, Tensor<B, 4>) {
+        let [max_batch_size, num_heads, max_seq_len, head_dim] = self.key.dims();
+        let [batch_size, key_heads, seq_length, key_head_dim] = key.dims();
+
+        assert_eq!(
+            key.dims(),
+            value.dims(),
+            "The keys and values should have the same shape."
+        );
+        assert!(
+            key_heads == num_heads && key_head_dim == head_dim,
+            "The keys and values should have {num_heads} heads of size {head_dim}, got {key_heads} heads of size {key_head_dim}."
+        );
+        assert!(
+            batch_size <= max_batch_size,
+            "The batch size {batch_size} is greater than the maximum batch size {max_batch_size}."
+        );
+        assert_eq!(
+            *self.batch_size.get_or_insert(batch_size),
+            batch_size,
+            "The batch size should be the same as the one of the previous tokens."
+        );
+
+        let start = self.seq_length;
+        let end = start + seq_length;
+        assert!(
+            end <= max_seq_len,
+            "The cache can't hold more than {max_seq_len} tokens, got {end}."
+        );
+
+        let ranges = [0..batch_size, 0..num_heads, start..end, 0..head_dim];
+        self.key = self.key.clone().slice_assign(ranges.clone(), key);
+        self.value = self.value.clone().slice_assign(ranges, value);
+        self.seq_length = end;
+
+        let ranges = [0..batch_size, 0..num_heads, 0..end, 0..head_dim];
+        (
+            self.key.clone().slice(ranges.clone()),
+            self.value.clone().slice(ranges),
+        )
+    }
+
+    /// The number of tokens in the cache.
+    pub fn len(&self) -> usize {
+        self.seq_length
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.seq_length == 0
+    }
+
+    /// The maximum number of tokens the cache can hold.
+    pub fn max_seq_len(&self) -> usize {
+        self.key.dims()[2]
+    }
+
+    /// Empties the cache, keeping its memory for the next sequence.
+    pub fn reset(&mut self) {
+        self.batch_size = None;
+        self.seq_length = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use alloc::vec;
+    use burn_tensor::{Data, Distribution};
+
+    #[test]
+    fn kv_cache_should_append_tokens() {
+        let device = Default::default();
+        let mut cache = KvCache::<TestBackend>::new(4, 8, 2, 3, &device);
+        let key_1 = Tensor::random([2, 2, 3, 3], Distribution::Default, &device);
+        let value_1 = Tensor::random([2, 2, 3, 3], Distribution::Default, &device);
+        let key_2 = Tensor::random([2, 2, 1, 3], Distribution::Default, &device);
+        let value_2 = Tensor::random([2, 2, 1, 3], Distribution::Default, &device);
+
+        cache.forward(key_1.clone(), value_1.clone());
+        let (key, value) = cache.forward(key_2.clone(), value_2.clone());
+
+        assert_eq!(cache.len(), 4);
+        key.into_data()
+            .assert_approx_eq(&Tensor::cat(vec![key_1, key_2], 2).into_data(), 3);
+        value
+            .into_data()
+            .assert_approx_eq(&Tensor::cat(vec![value_1, value_2], 2).into_data(), 3);
+    }
+
+    #[test]
+    fn kv_cache_reset_should_start_a_new_sequence() {
+        let device = Default::default();
+        let mut cache = KvCache::<TestBackend>::new(1, 2, 1, 1, &device);
+
+        cache.forward(
+            Tensor::ones([1, 1, 2, 1], &device),
+            Tensor::ones([1, 1, 2, 1], &device),
+        );
+        cache.reset();
+        let (key, _) = cache.forward(
+            Tensor::zeros([1, 1, 1, 1], &device),
+            Tensor::zeros([1, 1, 1, 1], &device),
+        );
+
+        assert!(!cache.is_empty());
+        assert_eq!(cache.len(), 1);
+        key.into_data()
+            .assert_approx_eq(&Data::from([[[[0.0]]]]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn kv_cache_should_panic_when_full() {
+        let device = Default::default();
+        let mut cache = KvCache::<TestBackend>::new(1, 2, 1, 1, &device);
+
+        for _ in 0..3 {
+            cache.forward(
+                Tensor::ones([1, 1, 1, 1], &device),
+                Tensor::ones([1, 1, 1, 1], &device),
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn kv_cache_should_panic_when_batch_size_changes() {
+        let device = Default::default();
+        let mut cache = KvCache::<TestBackend>::new(2, 4, 1, 1, &device);
+
+        cache.forward(
+            Tensor::ones([2, 1, 1, 1], &device),
+            Tensor::ones([2, 1, 1, 1], &device),
+        );
+        cache.forward(
+            Tensor::ones([1, 1, 1, 1], &device),
+            Tensor::ones([1, 1, 1, 1], &device),
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    nn,
+    tensor::{activation, backend::Backend, Bool, Tensor},
+};
+use libm::sqrtf;
+
+/// Scaled dot-product attention shared by the attention modules, computing the attention
+/// weights from the queries and keys once they are projected and split into heads.
+pub trait AttentionMechanism<B: Backend> {
+    /// The size of each head.
+    fn head_dim(&self) -> usize;
+    /// The dropout applied to the attention scores.
+    fn dropout(&self) -> &nn::Dropout;
+    /// The value given to the masked attention scores.
+    fn min_float(&self) -> f64;
+    /// Whether the "quiet softmax" is used instead of the regular softmax.
+    fn quiet_softmax(&self) -> bool;
+
+    /// Computes the scaled attention scores.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, n_heads, seq_length_1, head_dim]`
+    /// - key: `[batch_size, n_heads, seq_length_2, head_dim]`
+    /// - output: `[batch_size, n_heads, seq_length_1, seq_length_2]`
+    fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
+        let attn_scores = query
+            .matmul(key.transpose())
+            .div_scalar(sqrtf(self.head_dim() as f32));
+
+        self.dropout().forward(attn_scores)
+    }
+
+    /// Computes the attention weights from the attention scores, applying the masks.
+    ///
+    /// # Shapes
+    ///
+    /// - attn_scores: `[batch_size, n_heads, seq_length_1, seq_length_2]`
+    /// - mask_pad: `[batch_size, seq_length_2]`
+    /// - mask_attn: `[batch_size, seq_length_1, seq_length_2]`
+    /// - output: `[batch_size, n_heads, seq_length_1, seq_length_2]`
+    fn attn_weights(
+        &self,
+        mut attn_scores: Tensor<B, 4>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+    ) -> Tensor<B, 4> {
+        if let Some(mask_pad) = mask_pad {
+            let [batch_size, seq_length] = mask_pad.dims();
+
+            attn_scores = attn_scores.mask_fill(
+                mask_pad.reshape([batch_size, 1, 1, seq_length]),
+                self.min_float(),
+            );
+        }
+
+        if let Some(mask_attn) = mask_attn {
+            let [batch_size, seq_length_1, seq_length_2] = mask_attn.dims();
+
+            attn_scores = attn_scores.mask_fill(
+                mask_attn.reshape([batch_size, 1, seq_length_1, seq_length_2]),
+                self.min_float(),
+            );
+        }
+
+        if self.quiet_softmax() {
+            activation::quiet_softmax(attn_scores, 3)
+        } else {
+            activation::softmax(attn_scores, 3)
+        }
+    }
+}
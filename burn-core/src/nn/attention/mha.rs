@@ -1,14 +1,14 @@
 use crate as burn;
 
+use crate::nn::attention::{AttentionMechanism, KvCache};
 use crate::nn::cache::TensorCache;
 use crate::nn::Initializer;
 use crate::{
     config::Config,
-    module::Module,
+    module::{ForwardFlops, Module},
     nn,
-    tensor::{activation, backend::Backend, Bool, Tensor},
+    tensor::{backend::Backend, module, Bool, Tensor},
 };
-use libm::sqrtf;
 
 /// Configuration to create a [Multi Head Attention](MultiHeadAttention) layer.
 #[derive(Config)]
@@ -33,6 +33,12 @@ pub struct MultiHeadAttentionConfig {
     /// Reference: <https://www.evanmiller.org/attention-is-off-by-one.html>
     #[config(default = false)]
     quiet_softmax: bool,
+    /// Use the [flash attention](crate::tensor::module::flash_attention) in the forward pass when
+    /// there is no mask, no active dropout and the regular softmax. Default: false
+    ///
+    /// The flash attention doesn't compute the attention weights, see [MhaOutput::weights].
+    #[config(default = false)]
+    flash_attention: bool,
     /// The type of function used to initialize neural network parameters
     #[config(
         default = "Initializer::KaimingUniform{gain:1.0/libm::sqrt(3.0), fan_out_only:false}"
@@ -60,16 +66,17 @@ pub struct MultiHeadAttention<B: Backend> {
     d_k: usize,
     min_float: f64,
     quiet_softmax: bool,
+    flash_attention: bool,
 }
 
 /// [Multihead attention](MultiHeadAttention) forward pass input argument.
 #[derive(Debug, Clone)]
 pub struct MhaInput<B: Backend> {
-    query: Tensor<B, 3>,
-    key: Tensor<B, 3>,
-    value: Tensor<B, 3>,
-    mask_pad: Option<Tensor<B, 2, Bool>>,
-    mask_attn: Option<Tensor<B, 3, Bool>>,
+    pub(crate) query: Tensor<B, 3>,
+    pub(crate) key: Tensor<B, 3>,
+    pub(crate) value: Tensor<B, 3>,
+    pub(crate) mask_pad: Option<Tensor<B, 2, Bool>>,
+    pub(crate) mask_attn: Option<Tensor<B, 3, Bool>>,
 }
 
 impl MultiHeadAttentionConfig {
@@ -92,6 +99,7 @@ impl MultiHeadAttentionConfig {
             d_k: self.d_model / self.n_heads,
             min_float: self.min_float,
             quiet_softmax: self.quiet_softmax,
+            flash_attention: self.flash_attention,
         }
     }
 
@@ -116,6 +124,7 @@ impl MultiHeadAttentionConfig {
             d_k: self.d_model / self.n_heads,
             min_float: self.min_float,
             quiet_softmax: self.quiet_softmax,
+            flash_attention: self.flash_attention,
         }
     }
 }
@@ -161,6 +170,11 @@ impl<B: Backend> MhaInput<B> {
 #[derive(Debug, Clone)]
 pub struct MhaOutput<B: Backend> {
     /// The attention weights [batch_size, n_heads, seq_length_1, seq_length_2].
+    ///
+    /// When the module is [configured](MultiHeadAttentionConfig) to use the flash attention and
+    /// the forward pass uses it, the weights aren't computed and this tensor is empty, with the
+    /// shape [batch_size, n_heads, seq_length_1, 0]. The forward passes using a cache always compute
+    /// the weights.
     pub weights: Tensor<B, 4>,
     /// The context tensor [batch_size, seq_length_1, d_model].
     pub context: Tensor<B, 3>,
@@ -175,7 +189,15 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - key: `[batch_size, seq_length_2, d_model]`
     /// - value: `[batch_size, seq_length_2, d_model]`
     /// - output: `[batch_size, seq_length_1, d_model]`
+    ///
+    /// When the module is configured to use the
+    /// [flash attention](crate::tensor::module::flash_attention), it is used if there is no mask,
+    /// no active dropout and the regular softmax, and the weights of the output are empty.
     pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
+        if self.flash_attention && self.supports_flash_attention(&input) {
+            return self.forward_flash_attention(input);
+        }
+
         let [batch_size, seq_length_1, d_model] = input.query.dims();
 
         let query = self.attention_linear(input.query, &self.query);
@@ -228,43 +250,65 @@ impl<B: Backend> MultiHeadAttention<B> {
         MhaOutput { weights, context }
     }
 
-    fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
-        let attn_scores = query
-            .matmul(key.transpose())
-            .div_scalar(sqrtf(self.d_k as f32));
+    /// Applies the forward pass using a [key-value cache](KvCache), with the new tokens only as
+    /// input.
+    ///
+    /// The keys and values of the new tokens are appended to the cache, created with `n_heads`
+    /// heads of size `d_model / n_heads`, and the queries attend to all the tokens of the cache.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length, d_model]`
+    /// - key: `[batch_size, seq_length, d_model]`
+    /// - value: `[batch_size, seq_length, d_model]`
+    /// - mask_pad: `[batch_size, cached_seq_length]`
+    /// - mask_attn: `[batch_size, seq_length, cached_seq_length]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward_kv_cache(&self, input: MhaInput<B>, cache: &mut KvCache<B>) -> MhaOutput<B> {
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
 
-        self.dropout.forward(attn_scores)
+        let query = self.attention_linear(input.query, &self.query);
+        let key = self.attention_linear(input.key, &self.key);
+        let value = self.attention_linear(input.value, &self.value);
+        let (key, value) = cache.forward(key, value);
+
+        let attn_scores = self.attn_scores(query, key);
+        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+
+        let context = weights.clone().matmul(value);
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, d_model]);
+        let context = self.output.forward(context);
+
+        MhaOutput { weights, context }
     }
 
-    fn attn_weights(
-        &self,
-        mut attn_scores: Tensor<B, 4>,
-        mask_pad: Option<Tensor<B, 2, Bool>>,
-        mask_attn: Option<Tensor<B, 3, Bool>>,
-    ) -> Tensor<B, 4> {
-        if let Some(mask_pad) = mask_pad {
-            let [batch_size, seq_length] = mask_pad.dims();
-
-            attn_scores = attn_scores.mask_fill(
-                mask_pad.reshape([batch_size, 1, 1, seq_length]),
-                self.min_float,
-            );
-        }
+    fn supports_flash_attention(&self, input: &MhaInput<B>) -> bool {
+        input.mask_pad.is_none()
+            && input.mask_attn.is_none()
+            && !self.quiet_softmax
+            && !self.dropout.is_active::<B>()
+    }
 
-        if let Some(mask_attn) = mask_attn {
-            let [batch_size, seq_length_1, seq_length_2] = mask_attn.dims();
+    /// Applies the forward pass with the flash attention, without computing the attention
+    /// weights.
+    fn forward_flash_attention(&self, input: MhaInput<B>) -> MhaOutput<B> {
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
 
-            attn_scores = attn_scores.mask_fill(
-                mask_attn.reshape([batch_size, 1, seq_length_1, seq_length_2]),
-                self.min_float,
-            );
-        }
+        let query = self.attention_linear(input.query, &self.query);
+        let key = self.attention_linear(input.key, &self.key);
+        let value = self.attention_linear(input.value, &self.value);
+        let device = query.device();
 
-        if self.quiet_softmax {
-            activation::quiet_softmax(attn_scores, 3)
-        } else {
-            activation::softmax(attn_scores, 3)
-        }
+        let context = module::flash_attention(query, key, value, false);
+        let context = context
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length_1, d_model]);
+        let context = self.output.forward(context);
+        let weights = Tensor::empty([batch_size, self.n_heads, seq_length_1, 0], &device);
+
+        MhaOutput { weights, context }
     }
 
     fn attention_linear(&self, x: Tensor<B, 3>, linear: &nn::Linear<B>) -> Tensor<B, 4> {
@@ -276,6 +320,24 @@ impl<B: Backend> MultiHeadAttention<B> {
     }
 }
 
+impl<B: Backend> AttentionMechanism<B> for MultiHeadAttention<B> {
+    fn head_dim(&self) -> usize {
+        self.d_k
+    }
+
+    fn dropout(&self) -> &nn::Dropout {
+        &self.dropout
+    }
+
+    fn min_float(&self) -> f64 {
+        self.min_float
+    }
+
+    fn quiet_softmax(&self) -> bool {
+        self.quiet_softmax
+    }
+}
+
 /// Cache for the [Multi Head Attention](MultiHeadAttention) layer.
 ///
 /// To be used during inference when decoding tokens.
@@ -329,6 +391,23 @@ impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
     }
 }
 
+impl<B: Backend> ForwardFlops<MhaInput<B>> for MultiHeadAttention<B> {
+    fn forward_flops(&self, input: &MhaInput<B>) -> u64 {
+        let [batch_size, seq_length_1, d_model] = input.query.dims();
+        let [_, seq_length_2, _] = input.key.dims();
+
+        let projections = self.query.forward_flops(&input.query)
+            + self.key.forward_flops(&input.key)
+            + self.value.forward_flops(&input.value)
+            + self.output.forward_flops(&input.query);
+        // The attention scores and the context are both products of [seq_length_1, d_k] and
+        // [d_k, seq_length_2] matrices for each head.
+        let attention = 2 * 2 * batch_size * seq_length_1 * seq_length_2 * d_model;
+
+        projections + attention as u64
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -490,4 +569,90 @@ mod tests {
             .into_data()
             .assert_approx_eq(&output_2.into_data(), 3);
     }
+
+    #[test]
+    fn test_kv_cache_should_have_same_output_as_autoregressive_mask() {
+        let [batch_size, seq_length, d_model, n_heads] = [3, 5, 12, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let input = MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn);
+
+        let output_1 = mha.forward(input);
+        let mut output_2 = Vec::new();
+        let mut cache = KvCache::new(batch_size, seq_length, n_heads, d_model / n_heads, &device);
+
+        for i in 0..seq_length {
+            let token = tensor.clone().slice([0..batch_size, i..i + 1, 0..d_model]);
+            let input = MhaInput::self_attn(token);
+            output_2.push(mha.forward_kv_cache(input, &mut cache).context);
+        }
+
+        let output_2 = Tensor::cat(output_2, 1);
+
+        assert_eq!(cache.len(), seq_length);
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_flash_attention_should_match_forward() {
+        let [batch_size, seq_length_1, seq_length_2, d_model, n_heads] = [2, 5, 7, 16, 4];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_flash_attention(true)
+            .init::<TestBackend>(&device);
+        let query = Tensor::random(
+            [batch_size, seq_length_1, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let key = Tensor::random(
+            [batch_size, seq_length_2, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let input = MhaInput::new(query, key.clone(), key);
+        // An empty mask doesn't change the output, but disables the flash attention.
+        let mask_attn =
+            Tensor::<TestBackend, 3, Int>::zeros([batch_size, seq_length_1, seq_length_2], &device)
+                .equal_elem(1);
+
+        let output_1 = mha.forward(input.clone().mask_attn(mask_attn));
+        let output_2 = mha.forward(input);
+
+        assert_eq!(
+            output_1.weights.shape(),
+            Shape::new([batch_size, n_heads, seq_length_1, seq_length_2])
+        );
+        assert_eq!(
+            output_2.weights.shape(),
+            Shape::new([batch_size, n_heads, seq_length_1, 0])
+        );
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.context.into_data(), 3);
+    }
+
+    #[test]
+    fn forward_flops() {
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(8, 2).init::<TestBackend>(&device);
+        let query = Tensor::zeros([2, 3, 8], &device);
+        let key = Tensor::zeros([2, 5, 8], &device);
+        let input = MhaInput::new(query, key.clone(), key);
+
+        let projections = 2 * (3 + 5 + 5 + 3) * 8 * (2 * 8 + 1);
+        let attention = 2 * 2 * 2 * 3 * 5 * 8;
+        assert_eq!(mha.forward_flops(&input), projections + attention);
+    }
 }
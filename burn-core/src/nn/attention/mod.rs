@@ -1,5 +1,11 @@
+mod gqa;
+mod kv_cache;
 mod mask;
+mod mechanism;
 mod mha;
 
+pub use gqa::*;
+pub use kv_cache::*;
 pub use mask::*;
+pub use mechanism::*;
 pub use mha::*;
@@ -1,6 +1,7 @@
 use crate as burn;
 
 use crate::config::Config;
+use crate::module::ForwardFlops;
 use crate::module::Module;
 use crate::module::Param;
 use crate::nn::Initializer;
@@ -8,6 +9,7 @@ use crate::nn::PaddingConfig2d;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 use burn_tensor::module::conv2d;
+use burn_tensor::ops::conv::calculate_conv_output_size;
 use burn_tensor::ops::ConvOptions;
 use libm::sqrt;
 
@@ -136,10 +138,36 @@ impl<B: Backend> Conv2d<B> {
     }
 }
 
+impl<B: Backend> ForwardFlops<Tensor<B, 4>> for Conv2d<B> {
+    fn forward_flops(&self, input: &Tensor<B, 4>) -> u64 {
+        let [batch_size, _channels_in, height_in, width_in] = input.dims();
+        let [channels_out, channels_per_group, kernel_height, kernel_width] = self.weight.dims();
+        let padding =
+            self.padding
+                .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
+        let output_size = |i: usize, size_in: usize| {
+            calculate_conv_output_size(
+                self.kernel_size[i],
+                self.stride[i],
+                padding[i],
+                self.dilation[i],
+                size_in,
+            )
+        };
+
+        let num_outputs =
+            batch_size * channels_out * output_size(0, height_in) * output_size(1, width_in);
+        let kernel_size = channels_per_group * kernel_height * kernel_width;
+        let bias = self.bias.is_some() as u64;
+
+        num_outputs as u64 * (2 * kernel_size as u64 + bias)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::TestBackend;
+    use crate::{TestAutodiffBackend, TestBackend};
     use burn_tensor::Data;
 
     #[test]
@@ -200,4 +228,63 @@ mod tests {
 
         assert_eq!(config.initializer, init);
     }
+
+    #[test]
+    fn depthwise_conv_output_shape() {
+        let device = Default::default();
+        let config = Conv2dConfig::new([4, 4], [3, 3])
+            .with_groups(4)
+            .with_padding(PaddingConfig2d::Same);
+        let conv = config.init::<TestBackend>(&device);
+
+        let output = conv.forward(Tensor::ones([2, 4, 8, 8], &device));
+
+        assert_eq!(conv.weight.dims(), [4, 1, 3, 3]);
+        assert_eq!(output.dims(), [2, 4, 8, 8]);
+    }
+
+    #[test]
+    fn grouped_conv_gradients_flow_to_all_groups() {
+        let device = Default::default();
+        let config = Conv2dConfig::new([4, 6], [3, 3]).with_groups(2);
+        let conv = config.init::<TestAutodiffBackend>(&device);
+
+        let output = conv.forward(Tensor::ones([1, 4, 5, 5], &device));
+        let grads = output.backward();
+        let weight_grad = conv.weight.grad(&grads).unwrap();
+
+        assert_eq!(output.dims(), [1, 6, 3, 3]);
+        assert_eq!(weight_grad.dims(), [6, 2, 3, 3]);
+        // Every input channel is ones, so each weight receives the number of output positions.
+        let expected = Tensor::<TestBackend, 4>::full([6, 2, 3, 3], 9.0, &device);
+        weight_grad
+            .to_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic = "Both channels must be divisible by the number of groups."]
+    fn channels_out_not_divisible_by_groups() {
+        let device = Default::default();
+        let config = Conv2dConfig::new([4, 6], [3, 3]).with_groups(4);
+
+        let _ = config.init::<TestBackend>(&device);
+    }
+
+    #[test]
+    fn forward_flops() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([4, 6], [3, 3])
+            .with_stride([2, 2])
+            .with_groups(2)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::zeros([2, 4, 9, 7], &device);
+
+        // The output has a shape of [2, 6, 4, 3], each value being computed from 2 input
+        // channels with a 3x3 kernel and a bias.
+        assert_eq!(
+            conv.forward_flops(&input),
+            2 * 6 * 4 * 3 * (2 * 2 * 3 * 3 + 1)
+        );
+    }
 }
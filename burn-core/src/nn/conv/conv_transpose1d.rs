@@ -44,6 +44,9 @@ pub struct ConvTranspose1dConfig {
 
 /// Applies a 1D transposed convolution over input tensors.
 ///
+/// The forward pass is the gradient of a [1D convolution](crate::nn::conv::Conv1d) with respect
+/// to its input, the weight having the same shape for both layers.
+///
 /// # Params
 ///
 /// - weight: Tensor of shape `[channels_in, channels_out / groups, kernel_size]`
@@ -140,8 +143,10 @@ impl<B: Backend> ConvTranspose1d<B> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::TestBackend;
-    use burn_tensor::Data;
+    use crate::nn::conv::Conv1dConfig;
+    use crate::nn::PaddingConfig1d;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::{Data, Distribution};
 
     #[test]
     fn initializer_default() {
@@ -167,4 +172,66 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
     }
+
+    #[test]
+    fn forward_is_gradient_of_conv1d() {
+        let device = Default::default();
+        let conv = Conv1dConfig::new(2, 3, 3)
+            .with_stride(2)
+            .with_padding(PaddingConfig1d::Explicit(1))
+            .with_bias(false)
+            .init::<TestAutodiffBackend>(&device);
+        let mut conv_transpose = ConvTranspose1dConfig::new([3, 2], 3)
+            .with_stride(2)
+            .with_padding(1)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        conv_transpose.weight = Param::from(conv.weight.val().inner());
+
+        let input =
+            Tensor::<TestAutodiffBackend, 3>::random([2, 2, 7], Distribution::Default, &device)
+                .require_grad();
+        let output_grad =
+            Tensor::<TestBackend, 3>::random([2, 3, 4], Distribution::Default, &device);
+
+        let output = conv.forward(input.clone());
+        let grads = (output * Tensor::from_inner(output_grad.clone()))
+            .sum()
+            .backward();
+
+        let expected = input.grad(&grads).unwrap();
+        let actual = conv_transpose.forward(output_grad);
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
+    #[test]
+    fn forward_is_left_inverse_of_conv1d_with_orthogonal_weight() {
+        let device = Default::default();
+        // A kernel centered on a rotation of the channels, with stride 1.
+        let weight = Tensor::<TestBackend, 3>::from_floats(
+            [
+                [[0.0, 0.6, 0.0], [0.0, -0.8, 0.0]],
+                [[0.0, 0.8, 0.0], [0.0, 0.6, 0.0]],
+            ],
+            &device,
+        );
+        let mut conv = Conv1dConfig::new(2, 2, 3)
+            .with_padding(PaddingConfig1d::Explicit(1))
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        let mut conv_transpose = ConvTranspose1dConfig::new([2, 2], 3)
+            .with_padding(1)
+            .with_bias(false)
+            .init::<TestBackend>(&device);
+        conv.weight = Param::from(weight.clone());
+        conv_transpose.weight = Param::from(weight);
+
+        let input = Tensor::<TestBackend, 3>::random([3, 2, 8], Distribution::Default, &device);
+
+        let output = conv_transpose.forward(conv.forward(input.clone()));
+
+        output.into_data().assert_approx_eq(&input.into_data(), 3);
+    }
 }
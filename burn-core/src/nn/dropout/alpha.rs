@@ -0,0 +1,101 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Tensor};
+
+/// The value to which the SELU activation saturates for large negative inputs, `-scale * alpha`.
+const SELU_SATURATION: f64 = -1.758_099_340_847_376_6;
+
+/// Configuration to create an [AlphaDropout](AlphaDropout) layer.
+#[derive(Config, Debug)]
+pub struct AlphaDropoutConfig {
+    /// The probability of randomly dropping some elements of the input tensor during training.
+    pub prob: f64,
+}
+
+/// Set at random some elements of the input tensor to the saturation value of the SELU
+/// activation during training, keeping the mean and variance of the input.
+///
+/// This is the dropout of self-normalizing networks, as describe in the paper
+/// [Self-Normalizing Neural Networks](https://arxiv.org/abs/1706.02515).
+#[derive(Module, Clone, Debug)]
+pub struct AlphaDropout {
+    prob: f64,
+}
+
+impl AlphaDropoutConfig {
+    /// Initialize a new [alpha dropout](AlphaDropout) module.
+    pub fn init(&self) -> AlphaDropout {
+        AlphaDropout { prob: self.prob }
+    }
+}
+
+impl AlphaDropout {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let prob_keep = 1.0 - self.prob;
+        // The affine transformation restoring a zero mean and a unit variance.
+        let a = (prob_keep * (1.0 + self.prob * SELU_SATURATION.powi(2))).powf(-0.5);
+        let b = -a * SELU_SATURATION * self.prob;
+
+        let keep = input.random_like(Distribution::Bernoulli(prob_keep));
+        let dropped = keep.clone().neg().add_scalar(1.0);
+        let x = input * keep + dropped * SELU_SATURATION;
+
+        x * a + b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Shape;
+
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_should_keep_mean_and_variance() {
+        let device = Default::default();
+        let tensor = Tensor::<TestAutodiffBackend, 2>::random(
+            Shape::new([200, 200]),
+            Distribution::Normal(0.0, 1.0),
+            &device,
+        );
+        let dropout = AlphaDropoutConfig::new(0.2).init();
+
+        let output = dropout.forward(tensor.clone());
+
+        assert_ne!(tensor.to_data(), output.to_data());
+        let (var, mean) = output.reshape([40_000]).var_mean_bias(0);
+        let mean = mean.into_scalar();
+        let var = var.into_scalar();
+        assert!(mean.abs() < 0.05, "mean {mean}");
+        assert!((var - 1.0).abs() < 0.05, "variance {var}");
+    }
+
+    #[test]
+    fn without_ad_backend_should_not_change_input() {
+        let tensor = Tensor::<TestBackend, 2>::ones(Shape::new([100, 100]), &Default::default());
+        let dropout = AlphaDropoutConfig::new(0.5).init();
+
+        let output = dropout.forward(tensor.clone());
+
+        assert_eq!(tensor.to_data(), output.to_data());
+    }
+}
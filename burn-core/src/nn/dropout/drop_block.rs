@@ -0,0 +1,147 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::module::max_pool2d;
+use crate::tensor::{Distribution, Tensor};
+
+/// Configuration to create a [DropBlock](DropBlock) layer.
+#[derive(Config, Debug)]
+pub struct DropBlockConfig {
+    /// The probability of an element of the input to be set to zero during training.
+    pub prob: f64,
+    /// The size of the square blocks set to zero.
+    #[config(default = 7)]
+    pub block_size: usize,
+}
+
+/// Set at random some square blocks of the feature maps to zero during training.
+///
+/// Dropping contiguous regions removes the semantic information that neighboring activations
+/// would otherwise still carry, as describe in the paper
+/// [DropBlock: A regularization method for convolutional networks](https://arxiv.org/abs/1810.12890).
+///
+/// The input is also scaled during training by the number of elements over the number of kept
+/// elements.
+#[derive(Module, Clone, Debug)]
+pub struct DropBlock {
+    prob: f64,
+    block_size: usize,
+}
+
+impl DropBlockConfig {
+    /// Initialize a new [drop block](DropBlock) module.
+    pub fn init(&self) -> DropBlock {
+        assert!(self.block_size > 0, "The block size must be at least 1");
+
+        DropBlock {
+            prob: self.prob,
+            block_size: self.block_size,
+        }
+    }
+}
+
+impl DropBlock {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height, width]`
+    /// - output: `[batch_size, channels, height, width]`
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let [batch_size, channels, height, width] = input.dims();
+        let block_size = self.block_size.min(height).min(width);
+
+        // The probability of each element to be the center of a block, so that about `prob` of
+        // the elements are dropped once the blocks are expanded.
+        let num_centers = (height - block_size + 1) * (width - block_size + 1);
+        let gamma = self.prob / (block_size * block_size) as f64 * (height * width) as f64
+            / num_centers as f64;
+        let centers = Tensor::<B, 4>::random(
+            [batch_size, channels, height, width],
+            Distribution::Bernoulli(gamma.min(1.0)),
+            &input.device(),
+        );
+
+        let padding = block_size / 2;
+        let blocks = max_pool2d(
+            centers,
+            [block_size, block_size],
+            [1, 1],
+            [padding, padding],
+            [1, 1],
+        )
+        // An even block size yields one more row and column, the last ones being removed.
+        .slice([0..batch_size, 0..channels, 0..height, 0..width]);
+        let mask = blocks.neg().add_scalar(1.0);
+
+        let num_elements = (batch_size * channels * height * width) as f64;
+        let num_kept = mask.clone().sum().clamp_min(1.0).reshape([1, 1, 1, 1]);
+
+        input.mul(mask).div(num_kept).mul_scalar(num_elements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Shape;
+
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_should_drop_blocks() {
+        let tensor =
+            Tensor::<TestAutodiffBackend, 4>::ones(Shape::new([2, 3, 16, 16]), &Default::default());
+        let drop_block = DropBlockConfig::new(0.2).with_block_size(3).init();
+
+        let output = drop_block.forward(tensor.clone());
+
+        assert_eq!(output.dims(), [2, 3, 16, 16]);
+        let values = output.into_data().value;
+        let num_dropped = values.iter().filter(|value| **value == 0.0).count();
+        assert!(num_dropped > 0);
+        // The kept values are scaled so that the mean is preserved.
+        let sum = values.iter().sum::<f32>();
+        assert!((sum - values.len() as f32).abs() < 1e-2 * values.len() as f32);
+        // Each dropped element belongs to a dropped block of at least 2x2 elements, even when
+        // clipped by the borders, so it has a dropped neighbor on its row and on its column.
+        let dropped =
+            |map: usize, row: usize, col: usize| values[(map * 16 + row) * 16 + col] == 0.0;
+        for map in 0..6 {
+            for row in 0..16 {
+                for col in 0..16 {
+                    if !dropped(map, row, col) {
+                        continue;
+                    }
+                    let row_neighbor = (col > 0 && dropped(map, row, col - 1))
+                        || (col < 15 && dropped(map, row, col + 1));
+                    let col_neighbor = (row > 0 && dropped(map, row - 1, col))
+                        || (row < 15 && dropped(map, row + 1, col));
+                    assert!(row_neighbor && col_neighbor);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn without_ad_backend_should_not_change_input() {
+        let tensor =
+            Tensor::<TestBackend, 4>::ones(Shape::new([2, 3, 16, 16]), &Default::default());
+        let drop_block = DropBlockConfig::new(0.5).init();
+
+        let output = drop_block.forward(tensor.clone());
+
+        assert_eq!(tensor.to_data(), output.to_data());
+    }
+}
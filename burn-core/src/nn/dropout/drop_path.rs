@@ -0,0 +1,119 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Tensor};
+
+/// Configuration to create a [DropPath](DropPath) layer.
+#[derive(Config, Debug)]
+pub struct DropPathConfig {
+    /// The probability of dropping the whole path of each sample during training.
+    pub prob: f64,
+    /// If the kept samples are scaled to `1 / (1 - prob)` during training. Default: `false`
+    #[config(default = false)]
+    pub scale_by_keep: bool,
+}
+
+/// Set at random the whole input of some samples to zero during training, also known as
+/// stochastic depth.
+///
+/// It is applied on the residual branches, so that some samples skip the branch, as describe in
+/// the paper [Deep Networks with Stochastic Depth](https://arxiv.org/abs/1603.09382).
+///
+/// A single draw is made for each sample of the batch, the first dimension of the input, and all
+/// its features are kept or set to zero together.
+#[derive(Module, Clone, Debug)]
+pub struct DropPath {
+    prob: f64,
+    scale_by_keep: bool,
+}
+
+impl DropPathConfig {
+    /// Initialize a new [drop path](DropPath) module.
+    pub fn init(&self) -> DropPath {
+        DropPath {
+            prob: self.prob,
+            scale_by_keep: self.scale_by_keep,
+        }
+    }
+}
+
+impl DropPath {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, ...]`
+    /// - output: `[batch_size, ...]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let prob_keep = 1.0 - self.prob;
+        let mut shape = [1; D];
+        shape[0] = input.dims()[0];
+        let random = Tensor::random(shape, Distribution::Bernoulli(prob_keep), &input.device());
+        let x = input * random;
+
+        match self.scale_by_keep && prob_keep > 0.0 {
+            true => x * (1.0 / prob_keep),
+            false => x,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Shape;
+
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_should_drop_whole_samples() {
+        let tensor =
+            Tensor::<TestAutodiffBackend, 3>::ones(Shape::new([64, 4, 8]), &Default::default());
+        let drop_path = DropPathConfig::new(0.5).with_scale_by_keep(true).init();
+
+        let output = drop_path.forward(tensor.clone());
+
+        let values = output.into_data().value;
+        for sample in values.chunks(4 * 8) {
+            let first = sample[0];
+            assert!(first == 0.0 || first == 2.0);
+            assert!(sample.iter().all(|value| *value == first));
+        }
+        assert!(values.contains(&0.0));
+        assert!(values.contains(&2.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_should_not_scale_by_default() {
+        let tensor =
+            Tensor::<TestAutodiffBackend, 2>::ones(Shape::new([64, 4]), &Default::default());
+        let drop_path = DropPathConfig::new(0.5).init();
+
+        let output = drop_path.forward(tensor);
+
+        let values = output.into_data().value;
+        assert!(values.iter().all(|value| *value == 0.0 || *value == 1.0));
+    }
+
+    #[test]
+    fn without_ad_backend_should_not_change_input() {
+        let tensor = Tensor::<TestBackend, 2>::ones(Shape::new([100, 100]), &Default::default());
+        let drop_path = DropPathConfig::new(0.5).init();
+
+        let output = drop_path.forward(tensor.clone());
+
+        assert_eq!(tensor.to_data(), output.to_data());
+    }
+}
@@ -0,0 +1,9 @@
+mod alpha;
+mod base;
+mod drop_block;
+mod drop_path;
+
+pub use alpha::*;
+pub use base::*;
+pub use drop_block::*;
+pub use drop_path::*;
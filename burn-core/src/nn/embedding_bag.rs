@@ -0,0 +1,384 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+
+use super::Initializer;
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Bool, Data, Int, Shape, Tensor};
+
+/// The reduction applied to the embeddings of each bag of an [EmbeddingBag](EmbeddingBag).
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum EmbeddingBagMode {
+    /// Sums the embeddings of the bag.
+    Sum,
+    /// Averages the embeddings of the bag.
+    Mean,
+    /// Takes the maximum of each feature over the embeddings of the bag.
+    Max,
+}
+
+/// Configuration to create an [EmbeddingBag](EmbeddingBag) layer.
+#[derive(Config)]
+pub struct EmbeddingBagConfig {
+    /// The number of embedding vectors.
+    pub n_embedding: usize,
+    /// The size of each vector.
+    pub d_model: usize,
+    /// The reduction applied to the embeddings of each bag.
+    #[config(default = "EmbeddingBagMode::Mean")]
+    pub mode: EmbeddingBagMode,
+    /// If the gradients of the weight should be sparse, only containing the rows of the looked up
+    /// embeddings. The gradients are currently always dense, so this is only a hint.
+    #[config(default = false)]
+    pub sparse: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// Lookup table reducing the embeddings of bags of indices, such as bags of words, without
+/// creating the embeddings of each bag separately.
+///
+/// # Params
+///
+/// - weight: Matrix of shape `[n_embedding, d_model]` initialized from a normal distribution:
+///   `N(0, 1)`
+#[derive(Module, Debug)]
+pub struct EmbeddingBag<B: Backend> {
+    /// The learnable weights of the module of shape [n_embedding, d_model] initialized
+    /// from a normal distribution `N(0, 1)`.
+    pub weight: Param<Tensor<B, 2>>,
+    mode: EmbeddingBagMode,
+    sparse: bool,
+}
+
+impl EmbeddingBagConfig {
+    /// Initialize a new [embedding bag](EmbeddingBag) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> EmbeddingBag<B> {
+        let weight = self
+            .initializer
+            .init([self.n_embedding, self.d_model], device)
+            .require_grad();
+
+        EmbeddingBag {
+            weight: Param::from(weight),
+            mode: self.mode.clone(),
+            sparse: self.sparse,
+        }
+    }
+
+    /// Initialize a new [embedding bag](EmbeddingBag) module with a [record](EmbeddingBagRecord).
+    pub fn init_with<B: Backend>(&self, record: EmbeddingBagRecord<B>) -> EmbeddingBag<B> {
+        EmbeddingBag {
+            weight: record.weight,
+            mode: self.mode.clone(),
+            sparse: self.sparse,
+        }
+    }
+}
+
+impl<B: Backend> EmbeddingBag<B> {
+    /// If the gradients of the weight should be sparse.
+    pub fn sparse(&self) -> bool {
+        self.sparse
+    }
+
+    /// Applies the forward pass on the bags of indices.
+    ///
+    /// The indices of all the bags are concatenated, and each offset is the position of the first
+    /// index of a bag, so the bag `i` contains the indices from `offsets[i]` to `offsets[i + 1]`,
+    /// or to the end for the last bag. Empty bags produce zeros.
+    ///
+    /// # Shapes
+    ///
+    /// - indices: `[num_indices]`
+    /// - offsets: `[batch_size]`
+    /// - output: `[batch_size, d_model]`
+    ///
+    /// # Panics
+    ///
+    /// If the first offset isn't zero, or if the offsets are decreasing or greater than the
+    /// number of indices.
+    pub fn forward(&self, indices: Tensor<B, 1, Int>, offsets: Tensor<B, 1, Int>) -> Tensor<B, 2> {
+        let [num_indices] = indices.dims();
+        let bags = bag_ranges(offsets.into_data().convert::<i64>().value, num_indices);
+        let [_, d_model] = self.weight.dims();
+        let device = indices.device();
+
+        match self.mode {
+            EmbeddingBagMode::Sum => self.sum(indices, &bags, d_model, &device),
+            EmbeddingBagMode::Mean => {
+                let counts = bags
+                    .iter()
+                    .map(|&(start, end)| usize::max(end - start, 1) as f32)
+                    .collect();
+                let counts = Tensor::from_data(
+                    Data::new(counts, Shape::new([bags.len(), 1])).convert(),
+                    &device,
+                );
+
+                self.sum(indices, &bags, d_model, &device) / counts
+            }
+            EmbeddingBagMode::Max => self.max(indices, &bags, d_model, &device),
+        }
+    }
+
+    /// Adds the embedding of each index to the row of its bag.
+    fn sum(
+        &self,
+        indices: Tensor<B, 1, Int>,
+        bags: &[(usize, usize)],
+        d_model: usize,
+        device: &B::Device,
+    ) -> Tensor<B, 2> {
+        let bag_indices = bags
+            .iter()
+            .enumerate()
+            .flat_map(|(bag, &(start, end))| (start..end).map(move |_| bag as i64))
+            .collect::<Vec<_>>();
+        let output = Tensor::zeros([bags.len(), d_model], device);
+
+        if bag_indices.is_empty() {
+            return output;
+        }
+
+        let embeddings = self.weight.val().select(0, indices);
+        output.select_assign(0, int_tensor(bag_indices, device), embeddings)
+    }
+
+    /// Pads the bags to the size of the largest one, with the padding masked out of the maximum.
+    fn max(
+        &self,
+        indices: Tensor<B, 1, Int>,
+        bags: &[(usize, usize)],
+        d_model: usize,
+        device: &B::Device,
+    ) -> Tensor<B, 2> {
+        let batch_size = bags.len();
+        let max_size = bags.iter().map(|(start, end)| end - start).max();
+        let max_size = match max_size {
+            Some(size) if size > 0 => size,
+            _ => return Tensor::zeros([batch_size, d_model], device),
+        };
+
+        // The padding repeats the first index of the bag, or any index for empty bags.
+        let mut positions = Vec::with_capacity(batch_size * max_size);
+        let mut padding = Vec::with_capacity(batch_size * max_size);
+        for &(start, end) in bags.iter() {
+            for position in start..start + max_size {
+                positions.push(match position < end {
+                    true => position as i64,
+                    false => usize::min(start, indices.dims()[0] - 1) as i64,
+                });
+                padding.push(position >= end);
+            }
+        }
+        let empty = bags.iter().map(|(start, end)| start == end).collect();
+
+        // The maximum is taken over the last dimension, which all the backends can scatter the
+        // gradients of.
+        let embeddings = self
+            .weight
+            .val()
+            .select(0, indices.select(0, int_tensor(positions, device)))
+            .reshape([batch_size, max_size, d_model])
+            .swap_dims(1, 2);
+        let padding = Tensor::<B, 3, Bool>::from_bool(
+            Data::new(padding, Shape::new([batch_size, 1, max_size])),
+            device,
+        )
+        .repeat(1, d_model);
+        let empty =
+            Tensor::<B, 2, Bool>::from_bool(Data::new(empty, Shape::new([batch_size, 1])), device)
+                .repeat(1, d_model);
+
+        embeddings
+            .mask_fill(padding, f32::NEG_INFINITY)
+            .max_dim(2)
+            .reshape([batch_size, d_model])
+            .mask_fill(empty, 0.0)
+    }
+}
+
+/// The range of positions of the indices of each bag.
+fn bag_ranges(offsets: Vec<i64>, num_indices: usize) -> Vec<(usize, usize)> {
+    assert!(
+        offsets.first().is_none_or(|&offset| offset == 0),
+        "The first offset should be zero, got {:?}.",
+        offsets
+    );
+    assert!(
+        offsets.windows(2).all(|pair| pair[0] <= pair[1])
+            && offsets.iter().all(|&offset| offset as usize <= num_indices),
+        "The offsets should be non-decreasing and at most the number of indices \
+         ({num_indices}), got {:?}.",
+        offsets
+    );
+
+    offsets
+        .iter()
+        .enumerate()
+        .map(|(bag, &start)| {
+            let end = offsets.get(bag + 1).map_or(num_indices as i64, |&end| end);
+            (start as usize, end as usize)
+        })
+        .collect()
+}
+
+fn int_tensor<B: Backend>(values: Vec<i64>, device: &B::Device) -> Tensor<B, 1, Int> {
+    let shape = Shape::new([values.len()]);
+
+    Tensor::from_data(Data::new(values, shape).convert(), device)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{EmbeddingConfig, EmbeddingRecord};
+    use crate::TestBackend;
+
+    fn embedding_bag<B: Backend>(mode: EmbeddingBagMode) -> EmbeddingBag<B> {
+        let device = Default::default();
+        let mut embedding_bag = EmbeddingBagConfig::new(4, 2)
+            .with_mode(mode)
+            .init::<B>(&device);
+        embedding_bag.weight = Param::from(Tensor::from_floats(
+            [[1.0, -1.0], [2.0, 0.5], [-3.0, 4.0], [0.0, 2.0]],
+            &device,
+        ));
+
+        embedding_bag
+    }
+
+    fn ints<B: Backend, const N: usize>(values: [i32; N]) -> Tensor<B, 1, Int> {
+        Tensor::from_data(Data::from(values).convert(), &Default::default())
+    }
+
+    #[test]
+    fn test_sum_mode() {
+        let embedding_bag = embedding_bag::<TestBackend>(EmbeddingBagMode::Sum);
+
+        let output = embedding_bag.forward(ints([0, 2, 1, 1, 3]), ints([0, 2]));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[-2.0, 3.0], [4.0, 3.0]]), 5);
+    }
+
+    #[test]
+    fn test_mean_mode() {
+        let embedding_bag = embedding_bag::<TestBackend>(EmbeddingBagMode::Mean);
+
+        let output = embedding_bag.forward(ints([0, 2, 1, 1, 3]), ints([0, 2]));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[-1.0, 1.5], [4.0 / 3.0, 1.0]]), 5);
+    }
+
+    #[test]
+    fn test_max_mode() {
+        let embedding_bag = embedding_bag::<TestBackend>(EmbeddingBagMode::Max);
+
+        let output = embedding_bag.forward(ints([0, 2, 1, 3, 2]), ints([0, 2, 4]));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 4.0], [2.0, 2.0], [-3.0, 4.0]]), 5);
+    }
+
+    #[test]
+    fn test_empty_bags_are_zeros() {
+        for mode in [
+            EmbeddingBagMode::Sum,
+            EmbeddingBagMode::Mean,
+            EmbeddingBagMode::Max,
+        ] {
+            let embedding_bag = embedding_bag::<TestBackend>(mode);
+
+            let output = embedding_bag.forward(ints([1, 2]), ints([0, 0, 2]));
+
+            output
+                .select(0, ints([0, 2]))
+                .into_data()
+                .assert_approx_eq(&Data::from([[0.0, 0.0], [0.0, 0.0]]), 5);
+        }
+    }
+
+    #[test]
+    fn test_matches_embedding() {
+        let device = Default::default();
+        let embedding_bag = EmbeddingBagConfig::new(10, 3)
+            .with_mode(EmbeddingBagMode::Mean)
+            .init::<TestBackend>(&device);
+        let embedding = EmbeddingConfig::new(10, 3).init_with(EmbeddingRecord {
+            weight: embedding_bag.weight.clone(),
+        });
+        let indices = Tensor::<TestBackend, 2, Int>::from_data(
+            Data::<i32, 2>::from([[1, 4, 7], [9, 0, 4]]).convert(),
+            &device,
+        );
+
+        let output = embedding_bag.forward(indices.clone().reshape([6]), ints([0, 3]));
+
+        let expected = embedding.forward(indices).mean_dim(1).reshape([2, 3]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_gradients() {
+        use crate::TestAutodiffBackend;
+
+        let grad = |mode: EmbeddingBagMode| {
+            let embedding_bag = embedding_bag::<TestAutodiffBackend>(mode);
+            let grads = embedding_bag
+                .forward(ints([0, 2, 1, 1, 3]), ints([0, 2]))
+                .sum()
+                .backward();
+
+            embedding_bag.weight.grad(&grads).unwrap()
+        };
+
+        grad(EmbeddingBagMode::Sum).into_data().assert_approx_eq(
+            &Data::from([[1.0, 1.0], [2.0, 2.0], [1.0, 1.0], [1.0, 1.0]]),
+            5,
+        );
+        grad(EmbeddingBagMode::Mean).into_data().assert_approx_eq(
+            &Data::from([
+                [0.5, 0.5],
+                [2.0 / 3.0, 2.0 / 3.0],
+                [0.5, 0.5],
+                [1.0 / 3.0, 1.0 / 3.0],
+            ]),
+            5,
+        );
+        // The maximum of the second bag is the embedding 1 for the first feature and the
+        // embedding 3 for the second one.
+        grad(EmbeddingBagMode::Max).into_data().assert_approx_eq(
+            &Data::from([[1.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.0, 1.0]]),
+            5,
+        );
+    }
+
+    #[test]
+    #[should_panic = "The first offset should be zero"]
+    fn test_should_panic_with_invalid_first_offset() {
+        let embedding_bag = embedding_bag::<TestBackend>(EmbeddingBagMode::Sum);
+
+        embedding_bag.forward(ints([0, 1, 2]), ints([1, 2]));
+    }
+
+    #[test]
+    #[should_panic = "The offsets should be non-decreasing"]
+    fn test_should_panic_with_decreasing_offsets() {
+        let embedding_bag = embedding_bag::<TestBackend>(EmbeddingBagMode::Sum);
+
+        embedding_bag.forward(ints([0, 1, 2]), ints([0, 2, 1]));
+    }
+}
@@ -0,0 +1,188 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::activation;
+use crate::tensor::{backend::Backend, Tensor};
+use libm::sqrt;
+
+use super::{Initializer, Linear, LinearConfig};
+
+/// The activation applied to the gate of a [gated linear unit](Glu).
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum GluActivation {
+    /// The sigmoid, for the original GLU.
+    Sigmoid,
+    /// The swish, also known as SiLU, for SwiGLU.
+    Swish,
+    /// The GELU, for GeGLU.
+    Gelu,
+}
+
+/// Configuration to create a [gated linear unit](Glu) layer.
+#[derive(Config, Debug)]
+pub struct GluConfig {
+    /// The size of the input features.
+    pub d_model: usize,
+    /// The size of the output features.
+    pub d_ff: usize,
+    /// The activation applied to the gate. Default: `GluActivation::Sigmoid`
+    #[config(default = "GluActivation::Sigmoid")]
+    pub activation: GluActivation,
+    /// If a bias should be applied during the projections.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::KaimingUniform{gain:1.0/sqrt(3.0), fan_out_only:false}")]
+    pub initializer: Initializer,
+}
+
+/// Applies a gated linear unit to the input tensor, as describe in the paper
+/// [GLU Variants Improve Transformer](https://arxiv.org/abs/2002.05202):
+///
+/// `O = act(IW + b) ⊙ (IV + c)`
+///
+/// The gate and value projections are fused into a single [linear](Linear) layer, of weight
+/// `[W, V]` of shape `[d_model, 2 * d_ff]`, the first half of the output features being the gate.
+#[derive(Module, Debug)]
+pub struct Glu<B: Backend> {
+    /// The fused gate and value projections.
+    pub linear: Linear<B>,
+    activation: GluActivation,
+    d_ff: usize,
+}
+
+impl GluConfig {
+    /// Initialize a new [gated linear unit](Glu) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> Glu<B> {
+        Glu {
+            linear: self.linear().init(device),
+            activation: self.activation.clone(),
+            d_ff: self.d_ff,
+        }
+    }
+
+    /// Initialize a new [gated linear unit](Glu) module with a [record](GluRecord).
+    pub fn init_with<B: Backend>(&self, record: GluRecord<B>) -> Glu<B> {
+        Glu {
+            linear: self.linear().init_with(record.linear),
+            activation: self.activation.clone(),
+            d_ff: self.d_ff,
+        }
+    }
+
+    fn linear(&self) -> LinearConfig {
+        LinearConfig::new(self.d_model, 2 * self.d_ff)
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+    }
+}
+
+impl<B: Backend> Glu<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_model]`
+    /// - output: `[..., any, d_ff]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let projected = self.linear.forward(input);
+        let gate = projected.clone().narrow(D - 1, 0, self.d_ff);
+        let value = projected.narrow(D - 1, self.d_ff, self.d_ff);
+
+        let gate = match self.activation {
+            GluActivation::Sigmoid => activation::sigmoid(gate),
+            GluActivation::Swish => activation::silu(gate),
+            GluActivation::Gelu => activation::gelu(gate),
+        };
+
+        gate.mul(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Param;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[cfg(feature = "std")]
+    use crate::TestAutodiffBackend;
+
+    fn glu<B: Backend>(activation: GluActivation, device: &B::Device) -> Glu<B> {
+        let mut glu = GluConfig::new(2, 2)
+            .with_activation(activation)
+            .init::<B>(device);
+        glu.linear.weight = Param::from(Tensor::from_floats(
+            [[1.0, 0.0, 1.0, 2.0], [0.0, -1.0, 0.5, 0.0]],
+            device,
+        ));
+        glu.linear.bias = Some(Param::from(Tensor::from_floats(
+            [0.0, 0.5, 0.0, -1.0],
+            device,
+        )));
+        glu
+    }
+
+    #[test]
+    fn glu_forward_variants() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0]], &device);
+        // The gate is [1.0, -1.5] and the value [2.0, 1.0].
+
+        let output = glu::<TestBackend>(GluActivation::Sigmoid, &device).forward(input.clone());
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.4621, 0.1824]]), 3);
+
+        let output = glu::<TestBackend>(GluActivation::Swish, &device).forward(input.clone());
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.4621, -0.2736]]), 3);
+
+        let output = glu::<TestBackend>(GluActivation::Gelu, &device).forward(input);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.6827, -0.1002]]), 3);
+    }
+
+    #[test]
+    fn glu_output_shape() {
+        let device = Default::default();
+        let glu = GluConfig::new(8, 16)
+            .with_activation(GluActivation::Swish)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::ones([2, 5, 8], &device);
+
+        assert_eq!(glu.linear.weight.dims(), [8, 32]);
+        assert_eq!(glu.forward(input).dims(), [2, 5, 16]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn glu_backward() {
+        let device = Default::default();
+        let glu = glu::<TestAutodiffBackend>(GluActivation::Sigmoid, &device);
+        let input =
+            Tensor::<TestAutodiffBackend, 2>::from_floats([[1.0, 2.0]], &device).require_grad();
+
+        let output = glu.forward(input.clone());
+        let grads = output.sum().backward();
+
+        let weight_grad = glu.linear.weight.grad(&grads).unwrap();
+        let input_grad = input.grad(&grads).unwrap();
+
+        // The gate gradient is value * sigmoid'(gate), the value gradient is sigmoid(gate).
+        weight_grad.to_data().assert_approx_eq(
+            &Data::from([
+                [0.3932, 0.1491, 0.7311, 0.1824],
+                [0.7864, 0.2983, 1.4621, 0.3649],
+            ]),
+            3,
+        );
+        input_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.4891, 0.2164]]), 3);
+    }
+}
@@ -1,6 +1,7 @@
 use crate as burn;
 
 use crate::config::Config;
+use crate::module::ForwardFlops;
 use crate::module::Module;
 use crate::module::Param;
 use crate::tensor::{backend::Backend, Tensor};
@@ -86,6 +87,16 @@ impl<B: Backend> Linear<B> {
     }
 }
 
+impl<B: Backend, const D: usize> ForwardFlops<Tensor<B, D>> for Linear<B> {
+    fn forward_flops(&self, input: &Tensor<B, D>) -> u64 {
+        let [d_input, d_output] = self.weight.dims();
+        let num_rows = (input.shape().num_elements() / d_input) as u64;
+        let bias = self.bias.is_some() as u64;
+
+        num_rows * d_output as u64 * (2 * d_input as u64 + bias)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,4 +172,14 @@ mod tests {
 
         assert_eq!(result.into_data(), expected_result.into_data());
     }
+
+    #[test]
+    fn forward_flops() {
+        let device = Default::default();
+        let linear = LinearConfig::new(5, 3).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::zeros([2, 4, 5], &device);
+
+        // Each of the 8 rows has 3 outputs of 5 multiply-accumulates and a bias addition.
+        assert_eq!(linear.forward_flops(&input), 8 * 3 * (2 * 5 + 1));
+    }
 }
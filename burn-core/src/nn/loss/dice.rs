@@ -0,0 +1,188 @@
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Compute the dice loss between the predicted probabilities and the targets of a segmentation.
+///
+/// The dice coefficient `(2 * intersection + smooth) / (sum_pred + sum_target + smooth)` is
+/// computed over the spatial dimensions of each sample and class, and the loss is one minus its
+/// mean over the batch and the classes.
+///
+/// # Shapes
+///
+/// - pred: `[batch_size, num_classes, height, width]`
+/// - target: `[batch_size, num_classes, height, width]`
+pub fn dice_loss<B: Backend>(
+    pred: Tensor<B, 4>,
+    target: Tensor<B, 4>,
+    smooth: f64,
+) -> Tensor<B, 1> {
+    assertions(&pred, &target);
+    let [batch_size, num_classes, _, _] = pred.dims();
+
+    let spatial_sum = |tensor: Tensor<B, 4>| {
+        tensor
+            .sum_dim(3)
+            .sum_dim(2)
+            .reshape([batch_size, num_classes])
+    };
+    let intersection = spatial_sum(pred.clone() * target.clone());
+    let total = spatial_sum(pred) + spatial_sum(target);
+
+    let dice = (intersection * 2 + smooth) / (total + smooth);
+
+    dice.mean().neg() + 1
+}
+
+/// Compute the generalized dice loss between the predicted probabilities and the targets of a
+/// segmentation.
+///
+/// Unlike the [dice loss](dice_loss), the intersections and the sums are accumulated over the
+/// whole batch, with each class weighted by the inverse of its frequency in the target, so that
+/// small structures contribute as much as large ones. The frequency is clamped to one to keep
+/// the weight of classes absent from the batch finite.
+///
+/// # Shapes
+///
+/// - pred: `[batch_size, num_classes, height, width]`
+/// - target: `[batch_size, num_classes, height, width]`
+pub fn generalized_dice_loss<B: Backend>(
+    pred: Tensor<B, 4>,
+    target: Tensor<B, 4>,
+    smooth: f64,
+) -> Tensor<B, 1> {
+    assertions(&pred, &target);
+    let [_, num_classes, _, _] = pred.dims();
+
+    let class_sum = |tensor: Tensor<B, 4>| {
+        tensor
+            .sum_dim(3)
+            .sum_dim(2)
+            .sum_dim(0)
+            .reshape([num_classes])
+    };
+    let intersection = class_sum(pred.clone() * target.clone());
+    let sum_target = class_sum(target);
+    let total = class_sum(pred) + sum_target.clone();
+
+    let weights = sum_target.clamp_min(1.0).recip();
+    let numerator = (weights.clone() * intersection).sum() * 2 + smooth;
+    let denominator = (weights * total).sum() + smooth;
+
+    (numerator / denominator).neg() + 1
+}
+
+fn assertions<B: Backend>(pred: &Tensor<B, 4>, target: &Tensor<B, 4>) {
+    assert!(
+        pred.dims() == target.dims(),
+        "Shape of targets ({:?}) should correspond to shape of predictions ({:?}).",
+        target.dims(),
+        pred.dims()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Data;
+
+    fn binary_segmentation() -> (Tensor<TestBackend, 4>, Tensor<TestBackend, 4>) {
+        let device = Default::default();
+        let pred = Tensor::from_floats([[[[0.8, 0.2]], [[0.2, 0.8]]]], &device);
+        let target = Tensor::from_floats([[[[1.0, 0.0]], [[0.0, 1.0]]]], &device);
+
+        (pred, target)
+    }
+
+    fn imbalanced_segmentation() -> (Tensor<TestBackend, 4>, Tensor<TestBackend, 4>) {
+        let device = Default::default();
+        let pred = Tensor::from_floats([[[[0.9, 0.8, 0.7, 0.4]], [[0.1, 0.2, 0.3, 0.6]]]], &device);
+        let target =
+            Tensor::from_floats([[[[1.0, 1.0, 1.0, 0.0]], [[0.0, 0.0, 0.0, 1.0]]]], &device);
+
+        (pred, target)
+    }
+
+    #[test]
+    fn test_dice_loss() {
+        let (pred, target) = binary_segmentation();
+
+        // Dice of each class: 1.6 / 2.0
+        let loss = dice_loss(pred.clone(), target.clone(), 0.0);
+        loss.into_data().assert_approx_eq(&Data::from([0.2]), 5);
+
+        // Dice of each class: 2.6 / 3.0
+        let loss = dice_loss(pred, target, 1.0);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([1.0 - 2.6 / 3.0]), 5);
+    }
+
+    #[test]
+    fn test_dice_loss_perfect_prediction() {
+        let (_, target) = binary_segmentation();
+
+        let loss = dice_loss(target.clone(), target, 1.0);
+
+        loss.into_data().assert_approx_eq(&Data::from([0.0]), 5);
+    }
+
+    #[test]
+    fn test_dice_loss_imbalanced_classes() {
+        let (pred, target) = imbalanced_segmentation();
+
+        // Dice of each class: 4.8 / 5.8 and 1.2 / 2.2
+        let loss = dice_loss(pred, target, 0.0);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([1.0 - (4.8 / 5.8 + 1.2 / 2.2) / 2.0]), 5);
+    }
+
+    #[test]
+    fn test_generalized_dice_loss_imbalanced_classes() {
+        let (pred, target) = imbalanced_segmentation();
+
+        // Weights: 1 / 3 and 1
+        let loss = generalized_dice_loss(pred, target, 0.0);
+
+        let numerator = 2.0 * (2.4 / 3.0 + 0.6);
+        let denominator = 5.8 / 3.0 + 2.2;
+        loss.into_data()
+            .assert_approx_eq(&Data::from([1.0 - numerator / denominator]), 5);
+    }
+
+    #[test]
+    fn test_dice_loss_gradients() {
+        let device = Default::default();
+        let pred =
+            Tensor::<TestAutodiffBackend, 4>::from_floats([[[[0.8, 0.2]], [[0.2, 0.8]]]], &device)
+                .require_grad();
+        let target =
+            Tensor::<TestAutodiffBackend, 4>::from_floats([[[[1.0, 0.0]], [[0.0, 1.0]]]], &device);
+
+        let grads = dice_loss(pred.clone(), target, 0.0).backward();
+
+        // -(2 * target * (sum_pred + sum_target) - 2 * intersection) / (sum_pred + sum_target)^2
+        // averaged over the two classes.
+        pred.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[-0.3, 0.2]], [[0.2, -0.3]]]]), 5);
+    }
+
+    #[test]
+    fn test_generalized_dice_loss_gradients() {
+        let device = Default::default();
+        let pred =
+            Tensor::<TestAutodiffBackend, 4>::from_floats([[[[0.8, 0.2]], [[0.2, 0.8]]]], &device)
+                .require_grad();
+        let target =
+            Tensor::<TestAutodiffBackend, 4>::from_floats([[[[1.0, 0.0]], [[0.0, 1.0]]]], &device);
+
+        let grads = generalized_dice_loss(pred.clone(), target, 0.0).backward();
+
+        // Both classes have the same frequency, so the loss is 1 - 2 * 1.6 / 4.
+        pred.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[-0.3, 0.2]], [[0.2, -0.3]]]]), 5);
+    }
+}
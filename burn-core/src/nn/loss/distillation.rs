@@ -0,0 +1,288 @@
+use crate as burn;
+
+use crate::nn::loss::{cross_entropy_loss, reduction::Reduction};
+use crate::{config::Config, module::Module};
+use burn_tensor::activation::{log_softmax, softmax};
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+/// Configuration to create a [knowledge distillation loss](KDLoss).
+#[derive(Config, Debug)]
+pub struct KDLossConfig {
+    /// The weight of the distillation term, the cross entropy with the hard labels being weighted
+    /// by `1 - alpha`.
+    #[config(default = 0.5)]
+    pub alpha: f64,
+    /// The temperature used to soften the probabilities of the student and the teacher.
+    #[config(default = 1.0)]
+    pub temperature: f64,
+    /// If the distillation term is scaled by `T^2`, so that its gradients keep the same magnitude
+    /// when the temperature changes. Default: `false`
+    #[config(default = false)]
+    pub scale_by_temperature: bool,
+}
+
+impl KDLossConfig {
+    /// Initialize a new [knowledge distillation loss](KDLoss).
+    pub fn init(&self) -> KDLoss {
+        assert!(
+            (0.0..=1.0).contains(&self.alpha),
+            "Alpha of the knowledge distillation loss should be in interval [0, 1]. Got {}",
+            self.alpha
+        );
+        assert!(
+            self.temperature > 0.0,
+            "Temperature of the knowledge distillation loss should be positive. Got {}",
+            self.temperature
+        );
+
+        KDLoss {
+            alpha: self.alpha,
+            temperature: self.temperature,
+            scale_by_temperature: self.scale_by_temperature,
+        }
+    }
+}
+
+/// The knowledge distillation loss, training a student model to match the soft predictions of a
+/// teacher model as described in [Distilling the Knowledge in a Neural Network](https://arxiv.org/abs/1503.02531).
+///
+/// `loss = (1 - alpha) * CE(student, targets) + alpha * KL(p_teacher || p_student)`,
+/// where `p = softmax(logits / T)`. The distillation term can also be scaled by `T^2`, as in the
+/// paper, with [scale_by_temperature](KDLossConfig::scale_by_temperature).
+#[derive(Module, Clone, Debug)]
+pub struct KDLoss {
+    alpha: f64,
+    temperature: f64,
+    scale_by_temperature: bool,
+}
+
+impl KDLoss {
+    /// Compute the loss from the logits of the student and the teacher, and the hard targets.
+    ///
+    /// The teacher logits are detached, so the gradients only flow to the student.
+    ///
+    /// # Shapes
+    ///
+    /// - student: `[batch_size, num_classes]`
+    /// - teacher: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend>(
+        &self,
+        student: Tensor<B, 2>,
+        teacher: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        assert!(
+            student.dims() == teacher.dims(),
+            "Shape of the teacher logits ({:?}) should be the same as the student logits ({:?}).",
+            teacher.dims(),
+            student.dims()
+        );
+
+        let distillation = self.distillation(student.clone(), teacher.detach());
+
+        if self.alpha == 1.0 {
+            return distillation;
+        }
+
+        let cross_entropy = cross_entropy_loss(student, targets, None, None, Reduction::Mean);
+
+        if self.alpha == 0.0 {
+            return cross_entropy;
+        }
+
+        cross_entropy * (1.0 - self.alpha) + distillation * self.alpha
+    }
+
+    /// The KL divergence between the softened teacher and student distributions, averaged over
+    /// the batch and scaled by `T^2` when enabled.
+    fn distillation<B: Backend>(
+        &self,
+        student: Tensor<B, 2>,
+        teacher: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = student.dims();
+        let student = log_softmax(student / self.temperature, 1);
+        let teacher = teacher / self.temperature;
+        let teacher_log_probs = log_softmax(teacher.clone(), 1);
+        let teacher_probs = softmax(teacher, 1);
+
+        let divergence = (teacher_probs * (teacher_log_probs - student)).sum();
+
+        let scale = match self.scale_by_temperature {
+            true => self.temperature.powi(2),
+            false => 1.0,
+        };
+
+        divergence * (scale / batch_size as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Data;
+
+    fn logits<B: Backend>(data: [[f32; 3]; 2]) -> Tensor<B, 2> {
+        Tensor::from_floats(data, &Default::default())
+    }
+
+    fn student<B: Backend>() -> Tensor<B, 2> {
+        logits([[1.0, -0.5, 2.0], [0.3, 0.8, -1.2]])
+    }
+
+    fn teacher<B: Backend>() -> Tensor<B, 2> {
+        logits([[2.5, -1.0, 0.5], [-0.4, 1.7, 0.2]])
+    }
+
+    fn targets<B: Backend>() -> Tensor<B, 1, Int> {
+        Tensor::from_data(Data::from([0, 1]).convert(), &Default::default())
+    }
+
+    /// KL(p || q) averaged over the batch, computed on the host.
+    fn kl_divergence(p: [[f32; 3]; 2], q: [[f32; 3]; 2], temperature: f32) -> f32 {
+        let softmax = |row: [f32; 3]| {
+            let exp = row.map(|x| (x / temperature).exp());
+            let sum: f32 = exp.iter().sum();
+            exp.map(|x| x / sum)
+        };
+
+        let divergence: f32 = p
+            .into_iter()
+            .zip(q)
+            .map(|(p, q)| {
+                let (p, q) = (softmax(p), softmax(q));
+                (0..3).map(|i| p[i] * (p[i] / q[i]).ln()).sum::<f32>()
+            })
+            .sum();
+
+        divergence / 2.0
+    }
+
+    #[test]
+    fn test_kd_loss_without_alpha_is_cross_entropy() {
+        let loss = KDLossConfig::new()
+            .with_alpha(0.0)
+            .with_temperature(3.0)
+            .init()
+            .forward(student::<TestBackend>(), teacher(), targets());
+
+        let expected = cross_entropy_loss(
+            student::<TestBackend>(),
+            targets(),
+            None,
+            None,
+            Reduction::Mean,
+        );
+        loss.into_data().assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn test_kd_loss_with_alpha_one_is_kl_divergence() {
+        let student_data = [[1.0, -0.5, 2.0], [0.3, 0.8, -1.2]];
+        let teacher_data = [[2.5, -1.0, 0.5], [-0.4, 1.7, 0.2]];
+
+        let loss = KDLossConfig::new().with_alpha(1.0).init().forward(
+            student::<TestBackend>(),
+            teacher(),
+            targets(),
+        );
+        let expected = kl_divergence(teacher_data, student_data, 1.0);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([expected]), 5);
+
+        let loss = KDLossConfig::new()
+            .with_alpha(1.0)
+            .with_temperature(2.0)
+            .init()
+            .forward(student::<TestBackend>(), teacher(), targets());
+        let expected = kl_divergence(teacher_data, student_data, 2.0);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([expected]), 5);
+    }
+
+    #[test]
+    fn test_kd_loss_scaled_by_temperature() {
+        let student_data = [[1.0, -0.5, 2.0], [0.3, 0.8, -1.2]];
+        let teacher_data = [[2.5, -1.0, 0.5], [-0.4, 1.7, 0.2]];
+
+        let loss = KDLossConfig::new()
+            .with_alpha(1.0)
+            .with_temperature(2.0)
+            .with_scale_by_temperature(true)
+            .init()
+            .forward(student::<TestBackend>(), teacher(), targets());
+
+        let expected = kl_divergence(teacher_data, student_data, 2.0) * 4.0;
+        loss.into_data()
+            .assert_approx_eq(&Data::from([expected]), 5);
+    }
+
+    #[test]
+    fn test_kd_loss_combines_both_terms() {
+        let config = KDLossConfig::new().with_alpha(0.3).with_temperature(2.0);
+        let cross_entropy = config.clone().with_alpha(0.0).init().forward(
+            student::<TestBackend>(),
+            teacher(),
+            targets(),
+        );
+        let distillation = config.clone().with_alpha(1.0).init().forward(
+            student::<TestBackend>(),
+            teacher(),
+            targets(),
+        );
+
+        let loss = config
+            .init()
+            .forward(student::<TestBackend>(), teacher(), targets());
+
+        let expected = cross_entropy * 0.7 + distillation * 0.3;
+        loss.into_data().assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn test_kd_loss_is_zero_when_matching_the_teacher() {
+        let loss = KDLossConfig::new()
+            .with_alpha(1.0)
+            .with_temperature(4.0)
+            .init()
+            .forward(teacher::<TestBackend>(), teacher(), targets());
+
+        loss.into_data().assert_approx_eq(&Data::from([0.0]), 5);
+    }
+
+    #[test]
+    fn test_kd_loss_gradients_flow_through_both_terms() {
+        let loss = |alpha: f64| {
+            let student = student::<TestAutodiffBackend>().require_grad();
+            let teacher = teacher::<TestAutodiffBackend>().require_grad();
+            let grads = KDLossConfig::new()
+                .with_alpha(alpha)
+                .with_temperature(2.0)
+                .init()
+                .forward(student.clone(), teacher.clone(), targets())
+                .backward();
+
+            assert!(teacher.grad(&grads).is_none());
+            student.grad(&grads).unwrap()
+        };
+
+        let grad_cross_entropy = loss(0.0);
+        let grad_distillation = loss(1.0);
+        let grad = loss(0.5);
+
+        let expected = (grad_cross_entropy + grad_distillation) * 0.5;
+        grad.into_data().assert_approx_eq(&expected.into_data(), 5);
+        // Both terms have non zero gradients.
+        assert!(loss(1.0).abs().sum().into_scalar() > 0.0);
+        assert!(loss(0.0).abs().sum().into_scalar() > 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kd_loss_should_panic_with_invalid_alpha() {
+        KDLossConfig::new().with_alpha(1.5).init();
+    }
+}
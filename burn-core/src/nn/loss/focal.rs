@@ -0,0 +1,175 @@
+use crate::nn::loss::reduction::Reduction;
+
+use burn_tensor::activation::{log_sigmoid, log_softmax};
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+/// Compute the focal loss from the logits and the targets of a multiclass classification.
+///
+/// The [focal loss](https://arxiv.org/abs/1708.02002) scales the cross entropy of each sample by
+/// `alpha * (1 - p_t)^gamma`, where `p_t` is the predicted probability of the target class, so
+/// that well classified examples contribute less to the loss. With `alpha = 1` and `gamma = 0`,
+/// it is the same as the cross entropy loss.
+///
+/// # Shapes
+///
+/// - input: `[batch_size, num_classes]`
+/// - target: `[batch_size]`
+pub fn focal_loss<B: Backend>(
+    input: Tensor<B, 2>,
+    target: Tensor<B, 1, Int>,
+    alpha: f64,
+    gamma: f64,
+    reduction: Reduction,
+) -> Tensor<B, 1> {
+    let [batch_size, _] = input.dims();
+    let [target_size] = target.dims();
+    assert!(
+        batch_size == target_size,
+        "Shape of target ({}) should correspond to outer shape of input ({}).",
+        target_size,
+        batch_size
+    );
+
+    let log_pt = log_softmax(input, 1)
+        .gather(1, target.reshape([batch_size, 1]))
+        .reshape([batch_size]);
+
+    reduce(modulate(log_pt, alpha, gamma), reduction)
+}
+
+/// Compute the focal loss from the logits and the targets of a binary classification.
+///
+/// The `alpha` factor weights the positive class and `1 - alpha` the negative one, and the loss
+/// of each sample is modulated by `(1 - p_t)^gamma` like the [multiclass variant](focal_loss).
+///
+/// # Shapes
+///
+/// - input: `[batch_size]`
+/// - target: `[batch_size]`, with values of either 0 or 1
+pub fn binary_focal_loss<B: Backend>(
+    input: Tensor<B, 1>,
+    target: Tensor<B, 1, Int>,
+    alpha: f64,
+    gamma: f64,
+    reduction: Reduction,
+) -> Tensor<B, 1> {
+    let [batch_size] = input.dims();
+    let [target_size] = target.dims();
+    assert!(
+        batch_size == target_size,
+        "Shape of target ({}) should correspond to shape of input ({}).",
+        target_size,
+        batch_size
+    );
+
+    let target = target.float();
+    // log(p_t) is log(sigmoid(x)) for the positive class and log(1 - sigmoid(x)) = log(sigmoid(-x))
+    // for the negative one.
+    let log_pt = log_sigmoid(input * (target.clone() * 2 - 1));
+    let alpha_t = target.clone() * alpha + (target.neg() + 1) * (1.0 - alpha);
+
+    reduce(modulate(log_pt, 1.0, gamma) * alpha_t, reduction)
+}
+
+fn modulate<B: Backend>(log_pt: Tensor<B, 1>, alpha: f64, gamma: f64) -> Tensor<B, 1> {
+    let loss = log_pt.clone().neg() * alpha;
+
+    if gamma == 0.0 {
+        return loss;
+    }
+
+    loss * (log_pt.exp().neg() + 1).powf_scalar(gamma)
+}
+
+fn reduce<B: Backend>(loss: Tensor<B, 1>, reduction: Reduction) -> Tensor<B, 1> {
+    match reduction {
+        Reduction::Mean | Reduction::Auto => loss.mean(),
+        Reduction::Sum => loss.sum(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::cross_entropy_loss;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::{Data, Distribution};
+
+    #[test]
+    fn test_focal_loss_without_gamma_is_cross_entropy() {
+        let device = Default::default();
+        let input =
+            Tensor::<TestBackend, 2>::random([4, 5], Distribution::Normal(0., 1.0), &device);
+        let target = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 0, 4, 1]), &device);
+
+        let loss_1 = focal_loss(input.clone(), target.clone(), 1.0, 0.0, Reduction::Mean);
+        let loss_2 = cross_entropy_loss(input.clone(), target.clone(), None, None, Reduction::Mean);
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 5);
+
+        let loss_1 = focal_loss(input.clone(), target.clone(), 1.0, 0.0, Reduction::Sum);
+        let loss_2 = cross_entropy_loss(input, target, None, None, Reduction::Sum);
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 5);
+    }
+
+    #[test]
+    fn test_focal_loss_suppresses_well_classified_examples() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 2>::from_floats(
+            [[4.0, -2.0, -1.0], [-3.0, 5.0, 0.0], [-1.0, 0.0, 6.0]],
+            &device,
+        );
+        let target = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0, 1, 2]), &device);
+
+        let cross_entropy =
+            cross_entropy_loss(input.clone(), target.clone(), None, None, Reduction::Sum)
+                .into_scalar();
+        let focal = focal_loss(input, target, 1.0, 5.0, Reduction::Sum).into_scalar();
+
+        assert!(focal > 0.0);
+        assert!(focal < cross_entropy * 1e-6);
+    }
+
+    #[test]
+    fn test_focal_loss_gradients_match_cross_entropy_without_gamma() {
+        let device = Default::default();
+        let data = Data::from([[0.2, -1.0, 0.5], [1.5, 0.3, -0.4]]);
+        let target = Tensor::<TestAutodiffBackend, 1, Int>::from_data(Data::from([1, 0]), &device);
+
+        let input_1 =
+            Tensor::<TestAutodiffBackend, 2>::from_data(data.clone(), &device).require_grad();
+        let input_2 = Tensor::<TestAutodiffBackend, 2>::from_data(data, &device).require_grad();
+
+        let grads_1 =
+            focal_loss(input_1.clone(), target.clone(), 1.0, 0.0, Reduction::Mean).backward();
+        let grads_2 =
+            cross_entropy_loss(input_2.clone(), target, None, None, Reduction::Mean).backward();
+
+        input_1
+            .grad(&grads_1)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&input_2.grad(&grads_2).unwrap().into_data(), 5);
+    }
+
+    #[test]
+    fn test_binary_focal_loss() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 1>::from_floats([0.5, -1.0, 2.0], &device);
+        let target = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 0, 0]), &device);
+
+        // p_t: sigmoid(0.5), 1 - sigmoid(-1.0), 1 - sigmoid(2.0)
+        let pt = [0.62245935, 0.7310586, 0.11920292];
+        let alpha_t = [0.25, 0.75, 0.75];
+        let expected = (0..3)
+            .map(|i| -alpha_t[i] * (1.0 - pt[i]) * (1.0 - pt[i]) * f32::ln(pt[i]))
+            .collect::<Vec<_>>();
+
+        let loss = binary_focal_loss(input.clone(), target.clone(), 0.25, 2.0, Reduction::Sum);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([expected.iter().sum::<f32>()]), 5);
+
+        let loss = binary_focal_loss(input, target, 0.25, 2.0, Reduction::Mean);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([expected.iter().sum::<f32>() / 3.0]), 5);
+    }
+}
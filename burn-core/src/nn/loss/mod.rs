@@ -1,9 +1,21 @@
 mod binary_cross_entropy;
 mod cross_entropy;
+mod dice;
+mod distillation;
+mod focal;
 mod mse;
+mod nll;
+mod nt_xent;
 mod reduction;
+mod triplet;
 
 pub use binary_cross_entropy::*;
 pub use cross_entropy::*;
+pub use dice::*;
+pub use distillation::*;
+pub use focal::*;
 pub use mse::*;
+pub use nll::*;
+pub use nt_xent::*;
 pub use reduction::*;
+pub use triplet::*;
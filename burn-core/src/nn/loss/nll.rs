@@ -0,0 +1,200 @@
+use crate::nn::loss::reduction::Reduction;
+
+use burn_tensor::activation::log_softmax;
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+/// Compute the negative log likelihood loss from the log probabilities and the targets.
+///
+/// The loss of each sample is scaled by the weight of its target class when `weight` is given,
+/// and samples whose target is `ignore_index` don't contribute to the loss. With the mean
+/// reduction, the loss is divided by the sum of the weights of the samples that aren't ignored.
+///
+/// # Shapes
+///
+/// - log_probs: `[batch_size, num_classes]`
+/// - targets: `[batch_size]`
+/// - weight: `[num_classes]`
+pub fn nll_loss<B: Backend>(
+    log_probs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+    weight: Option<Tensor<B, 1>>,
+    ignore_index: Option<i64>,
+    reduction: Reduction,
+) -> Tensor<B, 1> {
+    let [batch_size, num_classes] = log_probs.dims();
+    let [targets_size] = targets.dims();
+    assert!(
+        batch_size == targets_size,
+        "Shape of targets ({}) should correspond to outer shape of log probabilities ({}).",
+        targets_size,
+        batch_size
+    );
+
+    // Ignored targets are replaced by a valid class so they can be gathered, their weight is
+    // then set to zero.
+    let (targets, mask) = match ignore_index {
+        Some(index) => {
+            let mask = targets.clone().equal_elem(index);
+            (targets.mask_fill(mask.clone(), 0), Some(mask))
+        }
+        None => (targets, None),
+    };
+
+    let weights = match weight {
+        Some(weight) => {
+            let [weight_size] = weight.dims();
+            assert!(
+                weight_size == num_classes,
+                "Shape of weight ({}) should correspond to the number of classes ({}).",
+                weight_size,
+                num_classes
+            );
+            weight.gather(0, targets.clone())
+        }
+        None => Tensor::ones([batch_size], &log_probs.device()),
+    };
+    let weights = match mask {
+        Some(mask) => weights.mask_fill(mask, 0),
+        None => weights,
+    };
+
+    let tensor = log_probs
+        .gather(1, targets.reshape([batch_size, 1]))
+        .reshape([batch_size])
+        .neg()
+        * weights.clone();
+
+    match reduction {
+        Reduction::Mean | Reduction::Auto => tensor.sum() / weights.sum(),
+        Reduction::Sum => tensor.sum(),
+    }
+}
+
+/// Compute the cross entropy loss from the logits and the targets.
+///
+/// This is the [negative log likelihood loss](nll_loss) of the log softmax of the logits, with
+/// the same class weights, ignored index and reduction.
+///
+/// # Shapes
+///
+/// - logits: `[batch_size, num_classes]`
+/// - targets: `[batch_size]`
+/// - weight: `[num_classes]`
+pub fn cross_entropy_loss<B: Backend>(
+    logits: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+    weight: Option<Tensor<B, 1>>,
+    ignore_index: Option<i64>,
+    reduction: Reduction,
+) -> Tensor<B, 1> {
+    nll_loss(
+        log_softmax(logits, 1),
+        targets,
+        weight,
+        ignore_index,
+        reduction,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::CrossEntropyLossConfig;
+    use crate::TestBackend;
+    use burn_tensor::{Data, Distribution};
+
+    fn log_probs() -> Tensor<TestBackend, 2> {
+        Tensor::from_data(
+            Data::from([[-0.5, -1.0, -2.0], [-1.5, -0.2, -3.0], [-0.7, -1.2, -0.9]]),
+            &Default::default(),
+        )
+    }
+
+    fn targets(values: [i64; 3]) -> Tensor<TestBackend, 1, Int> {
+        Tensor::from_data(Data::from(values).convert(), &Default::default())
+    }
+
+    fn weight() -> Option<Tensor<TestBackend, 1>> {
+        Some(Tensor::from_floats([1.0, 2.0, 3.0], &Default::default()))
+    }
+
+    fn assert_loss(
+        weight: Option<Tensor<TestBackend, 1>>,
+        ignore_index: Option<i64>,
+        targets: [i64; 3],
+        expected_mean: f32,
+        expected_sum: f32,
+    ) {
+        let mean = nll_loss(
+            log_probs(),
+            self::targets(targets),
+            weight.clone(),
+            ignore_index,
+            Reduction::Mean,
+        );
+        let sum = nll_loss(
+            log_probs(),
+            self::targets(targets),
+            weight,
+            ignore_index,
+            Reduction::Sum,
+        );
+
+        mean.into_data()
+            .assert_approx_eq(&Data::from([expected_mean]), 5);
+        sum.into_data()
+            .assert_approx_eq(&Data::from([expected_sum]), 5);
+    }
+
+    #[test]
+    fn test_nll_loss() {
+        // Losses: 0.5, 3.0, 1.2
+        assert_loss(None, None, [0, 2, 1], 4.7 / 3.0, 4.7);
+    }
+
+    #[test]
+    fn test_nll_loss_with_weight() {
+        // Losses: 0.5 * 1, 3.0 * 3, 1.2 * 2
+        assert_loss(weight(), None, [0, 2, 1], 11.9 / 6.0, 11.9);
+    }
+
+    #[test]
+    fn test_nll_loss_with_ignore_index() {
+        // Losses: 0.5, ignored, 1.2
+        assert_loss(None, Some(2), [0, 2, 1], 1.7 / 2.0, 1.7);
+    }
+
+    #[test]
+    fn test_nll_loss_with_weight_and_ignore_index() {
+        // Losses: 0.5 * 1, ignored, 1.2 * 2
+        assert_loss(weight(), Some(2), [0, 2, 1], 2.9 / 3.0, 2.9);
+    }
+
+    #[test]
+    fn test_nll_loss_with_negative_ignore_index() {
+        assert_loss(weight(), Some(-100), [0, -100, 1], 2.9 / 3.0, 2.9);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_matches_module() {
+        let device = Default::default();
+        let logits =
+            Tensor::<TestBackend, 2>::random([4, 5], Distribution::Normal(0., 1.0), &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 0, 4, 1]), &device);
+        let weights = vec![1.0, 2., 3., 4., 5.];
+
+        let loss_1 = cross_entropy_loss(
+            logits.clone(),
+            targets.clone(),
+            Some(Tensor::from_floats(weights.as_slice(), &device)),
+            None,
+            Reduction::Mean,
+        );
+        let loss_2 = CrossEntropyLossConfig::new()
+            .with_weights(Some(weights))
+            .init(&device)
+            .forward(logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::nn::loss::{cross_entropy_loss, reduction::Reduction};
+
+use alloc::vec;
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+/// Compute the normalized temperature-scaled cross entropy loss (NT-Xent) of a batch of
+/// embeddings of two augmented views, as described in
+/// [A Simple Framework for Contrastive Learning of Visual Representations](https://arxiv.org/abs/2002.05709).
+///
+/// The batch contains the embeddings of the first view followed by the embeddings of the second
+/// view, so the rows `i` and `i + N` are a positive pair, and every other row of the batch is a
+/// negative for both of them. Each row is classified among the other `2N - 1` rows with the
+/// [cosine similarities](Tensor::cosine_similarity_matrix) divided by the temperature as logits,
+/// and the loss is averaged over the rows of both views, so it is symmetric.
+///
+/// # Shapes
+///
+/// - embeddings: `[2 * batch_size, num_features]`
+/// - output: `[1]`
+pub fn nt_xent_loss<B: Backend>(embeddings: Tensor<B, 2>, temperature: f64) -> Tensor<B, 1> {
+    let [num_embeddings, _] = embeddings.dims();
+    assert!(
+        num_embeddings > 0 && num_embeddings.is_multiple_of(2),
+        "The number of embeddings should be even and non zero, got {}.",
+        num_embeddings
+    );
+    assert!(
+        temperature > 0.0,
+        "The temperature should be positive, got {}.",
+        temperature
+    );
+
+    let device = embeddings.device();
+    let batch_size = num_embeddings / 2;
+
+    // A row can't be its own positive nor negative.
+    let indices = Tensor::<B, 1, Int>::arange(0..num_embeddings as i64, &device);
+    let diagonal = indices
+        .clone()
+        .reshape([num_embeddings, 1])
+        .repeat(1, num_embeddings)
+        .equal(
+            indices
+                .reshape([1, num_embeddings])
+                .repeat(0, num_embeddings),
+        );
+    let logits = embeddings
+        .cosine_similarity_matrix()
+        .div_scalar(temperature)
+        .mask_fill(diagonal, f32::NEG_INFINITY);
+
+    // The positive of row `i` is the row `(i + N) mod 2N`.
+    let targets = Tensor::cat(
+        vec![
+            Tensor::<B, 1, Int>::arange(batch_size as i64..num_embeddings as i64, &device),
+            Tensor::<B, 1, Int>::arange(0..batch_size as i64, &device),
+        ],
+        0,
+    );
+
+    cross_entropy_loss(logits, targets, None, None, Reduction::Mean)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Data;
+
+    fn embeddings<B: Backend>(data: [[f32; 2]; 4]) -> Tensor<B, 2> {
+        Tensor::from_floats(data, &Default::default())
+    }
+
+    #[test]
+    fn test_nt_xent_loss() {
+        let embeddings =
+            embeddings::<TestBackend>([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [0.0, 2.0]]);
+
+        let loss = nt_xent_loss(embeddings, 0.5);
+
+        // The similarities divided by the temperature, without the diagonal, with the positive
+        // first: row 0: [s2, s1, s3] = [sqrt(2), 0, 0], row 1: [2, 0, sqrt(2)],
+        // row 2: [sqrt(2), sqrt(2), sqrt(2)] and row 3: [2, 0, sqrt(2)].
+        let sqrt_2 = core::f32::consts::SQRT_2;
+        let cross_entropy = |logits: [f32; 3]| {
+            let sum: f32 = logits.iter().map(|logit| logit.exp()).sum();
+            sum.ln() - logits[0]
+        };
+        let expected = (cross_entropy([sqrt_2, 0.0, 0.0])
+            + cross_entropy([2.0, 0.0, sqrt_2])
+            + cross_entropy([sqrt_2, sqrt_2, sqrt_2])
+            + cross_entropy([2.0, 0.0, sqrt_2]))
+            / 4.0;
+        loss.into_data()
+            .assert_approx_eq(&Data::from([expected]), 4);
+    }
+
+    #[test]
+    fn test_nt_xent_loss_is_lower_with_similar_positives() {
+        let similar = embeddings::<TestBackend>([[1.0, 0.1], [-0.2, 1.0], [0.9, 0.0], [-0.1, 1.1]]);
+        let dissimilar =
+            embeddings::<TestBackend>([[1.0, 0.1], [-0.2, 1.0], [-0.1, 1.1], [0.9, 0.0]]);
+
+        let loss_similar = nt_xent_loss(similar, 0.1).into_scalar();
+        let loss_dissimilar = nt_xent_loss(dissimilar, 0.1).into_scalar();
+
+        assert!(loss_similar < loss_dissimilar);
+        assert!(loss_similar < 0.01);
+    }
+
+    #[test]
+    fn test_nt_xent_loss_is_symmetric_in_the_views() {
+        let data = [[1.0, 0.3], [-0.5, 2.0], [0.2, 0.7], [1.5, -1.0]];
+        let swapped = [data[2], data[3], data[0], data[1]];
+
+        let loss = nt_xent_loss(embeddings::<TestBackend>(data), 0.2);
+        let loss_swapped = nt_xent_loss(embeddings::<TestBackend>(swapped), 0.2);
+
+        loss.into_data()
+            .assert_approx_eq(&loss_swapped.into_data(), 5);
+    }
+
+    #[test]
+    fn test_nt_xent_loss_gradients() {
+        let embeddings =
+            embeddings::<TestAutodiffBackend>([[1.0, 0.3], [-0.5, 2.0], [0.2, 0.7], [1.5, -1.0]])
+                .require_grad();
+
+        let loss = nt_xent_loss(embeddings.clone(), 0.5);
+        let grads = loss.backward();
+        let grad = embeddings.grad(&grads).unwrap();
+
+        // The loss is invariant to the scale of the embeddings, so the gradient of each
+        // embedding is orthogonal to it.
+        let products = grad.clone().mul(embeddings.inner()).sum_dim(1);
+        products
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0], [0.0], [0.0], [0.0]]), 4);
+        assert!(grad.abs().sum().into_scalar() > 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nt_xent_loss_should_panic_with_odd_batch() {
+        let embeddings = Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]],
+            &Default::default(),
+        );
+
+        nt_xent_loss(embeddings, 0.5);
+    }
+}
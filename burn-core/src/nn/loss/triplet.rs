@@ -0,0 +1,224 @@
+use crate::nn::loss::reduction::Reduction;
+
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Compute the triplet margin loss from the anchors, the positive and the negative samples.
+///
+/// The loss of each triplet is `max(d(anchor, positive) - d(anchor, negative) + margin, 0)`, where
+/// `d(x, y) = ||x - y + eps||_p`. With `swap`, the distance to the negative sample is the smallest
+/// of `d(anchor, negative)` and `d(positive, negative)`, as described in
+/// [Learning local feature descriptors with triplets and shallow convolutional neural networks](http://www.bmva.org/bmvc/2016/papers/paper119/index.html).
+///
+/// # Shapes
+///
+/// - anchor: `[batch_size, num_features]`
+/// - positive: `[batch_size, num_features]`
+/// - negative: `[batch_size, num_features]`
+#[allow(clippy::too_many_arguments)]
+pub fn triplet_margin_loss<B: Backend>(
+    anchor: Tensor<B, 2>,
+    positive: Tensor<B, 2>,
+    negative: Tensor<B, 2>,
+    margin: f64,
+    p: f64,
+    eps: f64,
+    swap: bool,
+    reduction: Reduction,
+) -> Tensor<B, 1> {
+    assert!(
+        anchor.dims() == positive.dims() && anchor.dims() == negative.dims(),
+        "Shapes of anchor ({:?}), positive ({:?}) and negative ({:?}) should be the same.",
+        anchor.dims(),
+        positive.dims(),
+        negative.dims()
+    );
+    assert!(p > 0.0, "The norm degree should be positive, got {}", p);
+
+    let distance_positive = pairwise_distance(anchor.clone(), positive.clone(), p, eps);
+    let distance_negative = pairwise_distance(anchor, negative.clone(), p, eps);
+    let distance_negative = match swap {
+        true => {
+            let distance_swap = pairwise_distance(positive, negative, p, eps);
+            let mask = distance_swap.clone().lower(distance_negative.clone());
+            distance_negative.mask_where(mask, distance_swap)
+        }
+        false => distance_negative,
+    };
+
+    let tensor = (distance_positive - distance_negative + margin).clamp_min(0.0);
+
+    match reduction {
+        Reduction::Mean | Reduction::Auto => tensor.mean(),
+        Reduction::Sum => tensor.sum(),
+    }
+}
+
+/// The `p`-norm distance between each row of `x1` and `x2`.
+fn pairwise_distance<B: Backend>(
+    x1: Tensor<B, 2>,
+    x2: Tensor<B, 2>,
+    p: f64,
+    eps: f64,
+) -> Tensor<B, 1> {
+    let [batch_size, _] = x1.dims();
+    let differences = x1 - x2 + eps;
+    // The absolute value is computed with the signs of the differences, so the gradient of a
+    // zero difference is zero instead of undefined.
+    let signs =
+        differences.clone().greater_elem(0.0).float() - differences.clone().lower_elem(0.0).float();
+    let differences = differences * signs;
+
+    let distances = if p == 1.0 {
+        differences.sum_dim(1)
+    } else if p == f64::INFINITY {
+        differences.max_dim(1)
+    } else {
+        differences.powf_scalar(p).sum_dim(1).powf_scalar(1.0 / p)
+    };
+
+    distances.reshape([batch_size])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Data;
+
+    fn tensor(data: [[f32; 2]; 2]) -> Tensor<TestBackend, 2> {
+        Tensor::from_floats(data, &Default::default())
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_equidistant_is_zero() {
+        let anchor = tensor([[0.0, 0.0], [1.0, 1.0]]);
+        let positive = tensor([[3.0, 4.0], [1.0, 2.0]]);
+        let negative = tensor([[-4.0, 3.0], [0.0, 1.0]]);
+
+        let loss = triplet_margin_loss(
+            anchor,
+            positive,
+            negative,
+            0.0,
+            2.0,
+            0.0,
+            false,
+            Reduction::Sum,
+        );
+
+        loss.into_data().assert_approx_eq(&Data::from([0.0]), 5);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss() {
+        let anchor = tensor([[0.0, 0.0], [1.0, 1.0]]);
+        let positive = tensor([[3.0, 4.0], [1.0, 2.0]]);
+        let negative = tensor([[0.0, 1.0], [4.0, 5.0]]);
+
+        // Distances to the positives: 5, 1 and to the negatives: 1, 5
+        let loss = triplet_margin_loss(
+            anchor.clone(),
+            positive.clone(),
+            negative.clone(),
+            1.0,
+            2.0,
+            0.0,
+            false,
+            Reduction::Sum,
+        );
+        loss.into_data().assert_approx_eq(&Data::from([5.0]), 5);
+
+        let loss = triplet_margin_loss(
+            anchor,
+            positive,
+            negative,
+            1.0,
+            2.0,
+            0.0,
+            false,
+            Reduction::Mean,
+        );
+        loss.into_data().assert_approx_eq(&Data::from([2.5]), 5);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_l1() {
+        let anchor = tensor([[0.0, 0.0], [1.0, 1.0]]);
+        let positive = tensor([[3.0, 4.0], [1.0, 2.0]]);
+        let negative = tensor([[0.0, 1.0], [4.0, 5.0]]);
+
+        // Distances to the positives: 7, 1 and to the negatives: 1, 7
+        let loss = triplet_margin_loss(
+            anchor,
+            positive,
+            negative,
+            1.0,
+            1.0,
+            0.0,
+            false,
+            Reduction::Sum,
+        );
+
+        loss.into_data().assert_approx_eq(&Data::from([7.0]), 5);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_swap() {
+        let anchor = tensor([[0.0, 0.0], [0.0, 0.0]]);
+        let positive = tensor([[1.0, 0.0], [1.0, 0.0]]);
+        let negative = tensor([[2.0, 0.0], [-2.0, 0.0]]);
+
+        // The first negative is closer to the positive (1) than to the anchor (2).
+        let loss = triplet_margin_loss(
+            anchor.clone(),
+            positive.clone(),
+            negative.clone(),
+            1.0,
+            2.0,
+            0.0,
+            false,
+            Reduction::Sum,
+        );
+        loss.into_data().assert_approx_eq(&Data::from([0.0]), 5);
+
+        let loss = triplet_margin_loss(
+            anchor,
+            positive,
+            negative,
+            1.0,
+            2.0,
+            0.0,
+            true,
+            Reduction::Sum,
+        );
+        loss.into_data().assert_approx_eq(&Data::from([1.0]), 5);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_gradients() {
+        let device = Default::default();
+        let anchor =
+            Tensor::<TestAutodiffBackend, 2>::from_floats([[0.0, 0.0]], &device).require_grad();
+        let positive = Tensor::<TestAutodiffBackend, 2>::from_floats([[3.0, 4.0]], &device);
+        let negative = Tensor::<TestAutodiffBackend, 2>::from_floats([[0.0, 1.0]], &device);
+
+        let grads = triplet_margin_loss(
+            anchor.clone(),
+            positive,
+            negative,
+            1.0,
+            2.0,
+            0.0,
+            false,
+            Reduction::Mean,
+        )
+        .backward();
+
+        // (anchor - positive) / 5 - (anchor - negative) / 1
+        anchor
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([[-0.6, 0.2]]), 5);
+    }
+}
@@ -0,0 +1,390 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, Param};
+use crate::nn::conv::{Conv1d, Conv1dConfig};
+use crate::nn::{Initializer, Linear, LinearConfig, PaddingConfig1d};
+use crate::tensor::activation::silu;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Tensor};
+use alloc::vec::Vec;
+use libm::sqrt;
+
+/// Configuration to create a [Mamba](Mamba) layer.
+#[derive(Config, Debug)]
+pub struct MambaConfig {
+    /// The size of the input and output features.
+    pub d_model: usize,
+    /// The size of the state of the selective state space model. Default: 16
+    #[config(default = 16)]
+    pub d_state: usize,
+    /// The kernel size of the causal convolution. Default: 4
+    #[config(default = 4)]
+    pub d_conv: usize,
+    /// The expansion factor of the inner features, `d_inner = expand * d_model`. Default: 2
+    #[config(default = 2)]
+    pub expand: usize,
+    /// The rank of the projection computing the time steps. Default: `ceil(d_model / 16)`
+    #[config(default = "None")]
+    pub dt_rank: Option<usize>,
+    /// The number of time steps scanned in parallel, the chunks being scanned sequentially.
+    /// The memory of the scan grows with the square of the chunk size. Default: 64
+    #[config(default = 64)]
+    pub chunk_size: usize,
+    /// The type of function used to initialize the projections and the convolution.
+    #[config(default = "Initializer::KaimingUniform{gain:1.0/sqrt(3.0), fan_out_only:false}")]
+    pub initializer: Initializer,
+}
+
+/// The Mamba layer of the paper
+/// [Mamba: Linear-Time Sequence Modeling with Selective State Spaces](https://arxiv.org/abs/2312.00752).
+///
+/// The input is expanded, passed through a causal depthwise convolution and a selective state
+/// space model, whose parameters `Δ`, `B` and `C` are projections of the input:
+///
+/// `h_t = exp(Δ_t A) h_(t-1) + Δ_t B_t x_t`
+///
+/// `y_t = C_t h_t + D x_t`
+///
+/// before being gated and projected back to `d_model`. The time is linear in the sequence length.
+#[derive(Module, Debug)]
+pub struct Mamba<B: Backend> {
+    in_proj: Linear<B>,
+    conv1d: Conv1d<B>,
+    x_proj: Linear<B>,
+    dt_proj: Linear<B>,
+    out_proj: Linear<B>,
+    /// The logarithm of `-A`, of shape `[d_inner, d_state]`.
+    a_log: Param<Tensor<B, 2>>,
+    /// The skip connection `D`, of shape `[d_inner]`.
+    d: Param<Tensor<B, 1>>,
+    d_inner: usize,
+    d_state: usize,
+    dt_rank: usize,
+    chunk_size: usize,
+}
+
+impl MambaConfig {
+    /// Initialize a new [Mamba](Mamba) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> Mamba<B> {
+        let d_inner = self.d_inner();
+        let mut dt_proj = self.dt_proj().init(device);
+
+        // The time steps are initialized between 0.001 and 0.1, the bias being their inverse
+        // softplus.
+        let dt = Tensor::<B, 1>::random(
+            [d_inner],
+            Distribution::Uniform(0.001f64.ln(), 0.1f64.ln()),
+            device,
+        )
+        .exp();
+        let bias = dt.clone() + dt.neg().exp().neg().log1p();
+        dt_proj.bias = Some(Param::from(bias));
+
+        let a = Tensor::<B, 1, _>::arange(1..self.d_state as i64 + 1, device)
+            .float()
+            .reshape([1, self.d_state])
+            .repeat(0, d_inner);
+
+        Mamba {
+            in_proj: self.in_proj().init(device),
+            conv1d: self.conv1d().init(device),
+            x_proj: self.x_proj().init(device),
+            dt_proj,
+            out_proj: self.out_proj().init(device),
+            a_log: Param::from(a.log()),
+            d: Param::from(Tensor::ones([d_inner], device)),
+            d_inner,
+            d_state: self.d_state,
+            dt_rank: self.dt_rank(),
+            chunk_size: self.chunk_size,
+        }
+    }
+
+    /// Initialize a new [Mamba](Mamba) module with a [record](MambaRecord).
+    pub fn init_with<B: Backend>(&self, record: MambaRecord<B>) -> Mamba<B> {
+        Mamba {
+            in_proj: self.in_proj().init_with(record.in_proj),
+            conv1d: self.conv1d().init_with(record.conv1d),
+            x_proj: self.x_proj().init_with(record.x_proj),
+            dt_proj: self.dt_proj().init_with(record.dt_proj),
+            out_proj: self.out_proj().init_with(record.out_proj),
+            a_log: record.a_log,
+            d: record.d,
+            d_inner: self.d_inner(),
+            d_state: self.d_state,
+            dt_rank: self.dt_rank(),
+            chunk_size: self.chunk_size,
+        }
+    }
+
+    fn d_inner(&self) -> usize {
+        self.expand * self.d_model
+    }
+
+    fn dt_rank(&self) -> usize {
+        self.dt_rank.unwrap_or(self.d_model.div_ceil(16))
+    }
+
+    fn in_proj(&self) -> LinearConfig {
+        LinearConfig::new(self.d_model, 2 * self.d_inner())
+            .with_bias(false)
+            .with_initializer(self.initializer.clone())
+    }
+
+    fn conv1d(&self) -> Conv1dConfig {
+        Conv1dConfig::new(self.d_inner(), self.d_inner(), self.d_conv)
+            .with_groups(self.d_inner())
+            .with_padding(PaddingConfig1d::Explicit(self.d_conv - 1))
+            .with_initializer(self.initializer.clone())
+    }
+
+    fn x_proj(&self) -> LinearConfig {
+        LinearConfig::new(self.d_inner(), self.dt_rank() + 2 * self.d_state)
+            .with_bias(false)
+            .with_initializer(self.initializer.clone())
+    }
+
+    fn dt_proj(&self) -> LinearConfig {
+        LinearConfig::new(self.dt_rank(), self.d_inner()).with_initializer(self.initializer.clone())
+    }
+
+    fn out_proj(&self) -> LinearConfig {
+        LinearConfig::new(self.d_inner(), self.d_model)
+            .with_bias(false)
+            .with_initializer(self.initializer.clone())
+    }
+}
+
+impl<B: Backend> Mamba<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, d_model]`
+    /// - output: `[batch_size, seq_length, d_model]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [_, seq_length, _] = input.dims();
+
+        let xz = self.in_proj.forward(input);
+        let x = xz.clone().narrow(2, 0, self.d_inner);
+        let z = xz.narrow(2, self.d_inner, self.d_inner);
+
+        // The convolution is made causal by keeping the first outputs of the padded input.
+        let x = self
+            .conv1d
+            .forward(x.swap_dims(1, 2))
+            .narrow(2, 0, seq_length)
+            .swap_dims(1, 2);
+        let x = silu(x);
+
+        let params = self.x_proj.forward(x.clone());
+        let delta = params.clone().narrow(2, 0, self.dt_rank);
+        let b = params.clone().narrow(2, self.dt_rank, self.d_state);
+        let c = params.narrow(2, self.dt_rank + self.d_state, self.d_state);
+        let delta = softplus(self.dt_proj.forward(delta));
+        let a = self.a_log.val().exp().neg();
+
+        let y = selective_scan(x.clone(), delta, a, b, c, self.chunk_size);
+        let y = y + x.mul(self.d.val().unsqueeze());
+        let y = y.mul(silu(z));
+
+        self.out_proj.forward(y)
+    }
+}
+
+/// The softplus `log(1 + exp(x))`, without overflow for large inputs.
+fn softplus<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.clone().clamp_min(0.0) + tensor.abs().neg().exp().log1p()
+}
+
+/// Scans the selective state space model over the sequence.
+///
+/// Within a chunk, the state of each time step is computed in parallel from the cumulative sum
+/// `S` of `Δ A`, as `h_t = sum_(s <= t) exp(S_t - S_s) Δ_s B_s x_s + exp(S_t) h_0`, where `h_0` is
+/// the last state of the previous chunk. Since `Δ A` is never positive, every exponential is at
+/// most one.
+///
+/// # Shapes
+///
+/// - x: `[batch_size, seq_length, d_inner]`
+/// - delta: `[batch_size, seq_length, d_inner]`
+/// - a: `[d_inner, d_state]`
+/// - b: `[batch_size, seq_length, d_state]`
+/// - c: `[batch_size, seq_length, d_state]`
+/// - output: `[batch_size, seq_length, d_inner]`
+fn selective_scan<B: Backend>(
+    x: Tensor<B, 3>,
+    delta: Tensor<B, 3>,
+    a: Tensor<B, 2>,
+    b: Tensor<B, 3>,
+    c: Tensor<B, 3>,
+    chunk_size: usize,
+) -> Tensor<B, 3> {
+    let [batch_size, seq_length, d_inner] = x.dims();
+    let [_, d_state] = a.dims();
+    let device = x.device();
+
+    let delta_a = delta.clone().unsqueeze_dim::<4>(3).mul(a.unsqueeze::<4>());
+    let inputs = delta
+        .mul(x)
+        .unsqueeze_dim::<4>(3)
+        .mul(b.unsqueeze_dim::<4>(2));
+
+    let mut state = Tensor::<B, 4>::zeros([batch_size, 1, d_inner, d_state], &device);
+    let mut outputs = Vec::with_capacity(seq_length.div_ceil(chunk_size));
+
+    for start in (0..seq_length).step_by(chunk_size) {
+        let length = chunk_size.min(seq_length - start);
+        let cumsum = delta_a.clone().narrow(1, start, length).cumsum(1);
+        let inputs = inputs.clone().narrow(1, start, length);
+        let c = c.clone().narrow(1, start, length);
+
+        // The decay from each step `s` to each later step `t`, of shape
+        // `[batch_size, t, s, d_inner, d_state]`, the earlier steps being masked.
+        let causal = Tensor::<B, 2>::ones([length, length], &device)
+            .tril(0)
+            .reshape([1, length, length, 1, 1]);
+        let decay = cumsum
+            .clone()
+            .unsqueeze_dim::<5>(2)
+            .sub(cumsum.clone().unsqueeze_dim::<5>(1))
+            .clamp_max(0.0)
+            .exp()
+            .mul(causal);
+
+        let states = decay
+            .mul(inputs.unsqueeze_dim::<5>(1))
+            .sum_dim(2)
+            .reshape([batch_size, length, d_inner, d_state])
+            .add(cumsum.exp().mul(state));
+        state = states.clone().narrow(1, length - 1, 1);
+
+        let output = states
+            .mul(c.unsqueeze_dim::<4>(2))
+            .sum_dim(3)
+            .reshape([batch_size, length, d_inner]);
+        outputs.push(output);
+    }
+
+    Tensor::cat(outputs, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[cfg(feature = "std")]
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn mamba_output_shape() {
+        let device = Default::default();
+        let mamba = MambaConfig::new(8)
+            .with_d_state(4)
+            .with_chunk_size(3)
+            .init::<TestBackend>(&device);
+        let input = Tensor::random([2, 7, 8], Distribution::Default, &device);
+
+        let output = mamba.forward(input);
+
+        assert_eq!(output.dims(), [2, 7, 8]);
+    }
+
+    #[test]
+    fn selective_scan_should_match_the_recurrence() {
+        let device = Default::default();
+        let [batch_size, seq_length, d_inner, d_state] = [2, 7, 3, 4];
+        let x = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_inner],
+            Distribution::Default,
+            &device,
+        );
+        let delta = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_inner],
+            Distribution::Uniform(0.0, 2.0),
+            &device,
+        );
+        let a = Tensor::<TestBackend, 2>::random(
+            [d_inner, d_state],
+            Distribution::Uniform(-3.0, -0.1),
+            &device,
+        );
+        let b = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_state],
+            Distribution::Default,
+            &device,
+        );
+        let c = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_state],
+            Distribution::Default,
+            &device,
+        );
+
+        let output = selective_scan(x.clone(), delta.clone(), a.clone(), b.clone(), c.clone(), 3);
+
+        let mut state = Tensor::<TestBackend, 3>::zeros([batch_size, d_inner, d_state], &device);
+        let mut expected = Vec::new();
+        for t in 0..seq_length {
+            let step = |tensor: &Tensor<TestBackend, 3>| {
+                let [_, _, size] = tensor.dims();
+                tensor
+                    .clone()
+                    .narrow(1, t, 1)
+                    .reshape([batch_size, size, 1])
+            };
+            let delta_t = step(&delta);
+            let decay = delta_t.clone().mul(a.clone().unsqueeze()).exp();
+            let input = delta_t.mul(step(&x)).mul(step(&b).swap_dims(1, 2));
+            state = decay.mul(state).add(input);
+            let output = state
+                .clone()
+                .mul(step(&c).swap_dims(1, 2))
+                .sum_dim(2)
+                .reshape([batch_size, 1, d_inner]);
+            expected.push(output);
+        }
+        let expected = Tensor::cat(expected, 1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mamba_gradients_should_be_finite() {
+        let device = Default::default();
+        let mamba = MambaConfig::new(4)
+            .with_d_state(8)
+            .with_chunk_size(4)
+            .init::<TestAutodiffBackend>(&device);
+        let input = Tensor::<TestAutodiffBackend, 3>::random(
+            [2, 10, 4],
+            Distribution::Normal(0.0, 3.0),
+            &device,
+        )
+        .require_grad();
+
+        let output = mamba.forward(input.clone());
+        let grads = output.sum().backward();
+
+        let input_grad = input.grad(&grads).unwrap();
+        let a_log_grad = mamba.a_log.grad(&grads).unwrap();
+        let d_grad = mamba.d.grad(&grads).unwrap();
+        let dt_grad = mamba.dt_proj.weight.grad(&grads).unwrap();
+
+        assert_eq!(input_grad.dims(), [2, 10, 4]);
+        assert_eq!(a_log_grad.dims(), [8, 8]);
+        for grad in [
+            input_grad.into_data().value,
+            a_log_grad.into_data().value,
+            d_grad.into_data().value,
+            dt_grad.into_data().value,
+        ] {
+            assert!(grad.iter().all(|value| value.is_finite()));
+            assert!(grad.iter().any(|value| *value != 0.0));
+        }
+    }
+}
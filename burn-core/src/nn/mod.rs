@@ -18,24 +18,32 @@ pub mod transformer;
 
 mod dropout;
 mod embedding;
+mod embedding_bag;
 mod gelu;
+mod glu;
 mod initializer;
 mod linear;
+mod mamba;
 mod norm;
 mod padding;
 mod pos_encoding;
 mod relu;
 mod rnn;
+mod rotary_embedding;
 mod unfold;
 
 pub use dropout::*;
 pub use embedding::*;
+pub use embedding_bag::*;
 pub use gelu::*;
+pub use glu::*;
 pub use initializer::*;
 pub use linear::*;
+pub use mamba::*;
 pub use norm::*;
 pub use padding::*;
 pub use pos_encoding::*;
 pub use relu::*;
 pub use rnn::*;
+pub use rotary_embedding::*;
 pub use unfold::*;
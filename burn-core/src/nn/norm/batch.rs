@@ -74,6 +74,13 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
     ///
     /// - input: `[batch_size, channels, ...]`
     /// - output: `[batch_size, channels, ...]`
+    ///
+    /// # Panics
+    ///
+    /// * Panics when training if the input has a single value per channel, since the variance
+    ///   would be zero.
+    ///
+    /// The running statistics aren't updated when training with a batch size of 1.
     pub fn forward<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
         // Should be move to a compilation error when const generic support that kind of
         // validation. https://github.com/rust-lang/rust/issues/76560
@@ -119,6 +126,16 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
             flatten_size *= dim;
         }
 
+        // The variance of a single value is zero, so the normalized output wouldn't depend on
+        // the input anymore.
+        if flatten_size == 1 {
+            panic!(
+                "BatchNorm{}D expects more than one value per channel when training, received \
+                 tensor of shape {:?}",
+                D, dims
+            );
+        }
+
         let mean = input
             .clone()
             .swap_dims(0, 1)
@@ -135,26 +152,34 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
             .mean_dim(1)
             .reshape(shape_unsqueeze);
 
-        let running_mean = self.running_mean.value_sync().to_device(&device);
-        let running_var = self.running_var.value_sync().to_device(&device);
+        // The statistics of a single sample are poor estimates of the statistics of the
+        // dataset, so the running statistics are only updated with larger batches.
+        if batch_size > 1 {
+            self.update_running_stats(mean.clone(), var.clone(), &device);
+        }
 
-        let running_mean = running_mean.mul_scalar(1.0 - self.momentum).add(
-            mean.clone()
-                .detach()
-                .mul_scalar(self.momentum)
-                .reshape([channels]),
-        );
-        let running_var = running_var.mul_scalar(1.0 - self.momentum).add(
-            var.clone()
-                .detach()
-                .mul_scalar(self.momentum)
-                .reshape([channels]),
-        );
+        self.forward_shared(input, mean, var)
+    }
+
+    fn update_running_stats<const DI: usize>(
+        &self,
+        mean: Tensor<B, DI>,
+        var: Tensor<B, DI>,
+        device: &B::Device,
+    ) {
+        let channels = mean.dims()[1];
+        let running_mean = self.running_mean.value_sync().to_device(device);
+        let running_var = self.running_var.value_sync().to_device(device);
+
+        let running_mean = running_mean
+            .mul_scalar(1.0 - self.momentum)
+            .add(mean.detach().mul_scalar(self.momentum).reshape([channels]));
+        let running_var = running_var
+            .mul_scalar(1.0 - self.momentum)
+            .add(var.detach().mul_scalar(self.momentum).reshape([channels]));
 
         self.running_mean.update(running_mean.detach());
         self.running_var.update(running_var.detach());
-
-        self.forward_shared(input, mean, var)
     }
 
     fn forward_shared<const DI: usize>(
@@ -227,6 +252,34 @@ mod tests_1d {
         );
     }
 
+    #[test]
+    #[should_panic = "BatchNorm1D expects more than one value per channel when training"]
+    fn batch_norm_forward_train_single_value_per_channel() {
+        let device = Default::default();
+        let module = BatchNormConfig::new(3).init::<TestAutodiffBackend, 1>(&device);
+
+        module.forward(Tensor::from_floats(
+            [[[0.9601], [0.6272], [0.9378]]],
+            &device,
+        ));
+    }
+
+    #[test]
+    fn batch_norm_forward_inference_single_value_per_channel() {
+        let device = Default::default();
+        let module = BatchNormConfig::new(3).init::<TestAutodiffBackend, 1>(&device);
+        let module = module.valid();
+
+        let output = module.forward(Tensor::from_floats(
+            [[[0.9601], [0.6272], [0.9378]]],
+            &device,
+        ));
+
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.9601], [0.6272], [0.9378]]]), 3);
+    }
+
     fn input_tensor<B: Backend>(device: &B::Device) -> Tensor<B, 3> {
         Tensor::<B, 3>::from_floats(
             [
@@ -384,6 +437,38 @@ mod tests_2d {
         );
     }
 
+    #[test]
+    fn batch_norm_batch_size_one_keeps_running_stats() {
+        let device = Default::default();
+        let module = BatchNormConfig::new(3).init::<TestAutodiffBackend, 2>(&device);
+        let input = input_tensor(&device).narrow(0, 0, 1).require_grad();
+
+        let output = module.forward(input.clone());
+        let grads = output.backward();
+
+        module
+            .running_mean
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0, 0.0]), 3);
+        module
+            .running_var
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 1.0, 1.0]), 3);
+        module
+            .beta
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([4., 4., 4.]), 3);
+        input
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::zeros([1, 3, 2, 2]), 3);
+    }
+
     fn input_tensor<B: Backend>(device: &B::Device) -> Tensor<B, 4> {
         Tensor::<B, 4>::from_floats(
             [
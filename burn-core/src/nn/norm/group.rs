@@ -7,20 +7,20 @@ use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 
 /// Configuration to create a [GroupNorm](GroupNorm) layer.
-#[derive(Config)]
+#[derive(Config, Debug)]
 pub struct GroupNormConfig {
     /// The number of groups to separate the channels into
-    num_groups: usize,
+    pub num_groups: usize,
     /// The number of channels expected in the input
-    num_channels: usize,
+    pub num_channels: usize,
     /// A value required for numerical stability. Default: 1e-5
     #[config(default = 1e-5)]
-    epsilon: f64,
+    pub epsilon: f64,
     /// A boolean value that when set to `true`, this module has learnable
     /// per-channel affine parameters initialized to ones (for weights)
     /// and zeros (for biases). Default: `true`
     #[config(default = true)]
-    affine: bool,
+    pub affine: bool,
 }
 
 /// Applies Group Normalization over a mini-batch of inputs.
@@ -85,17 +85,14 @@ impl<B: Backend> GroupNorm<B> {
     /// - input: `[..., any, d_model]`
     /// - output: `[..., any, d_model]`
     pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
-        let shape = input.shape();
-        if shape.num_elements() <= 2 {
+        if D <= 2 {
             panic!(
                 "input rank for GroupNorm should be at least 3, but got {}",
-                shape.num_elements()
+                D
             );
         }
 
-        let batch_size = shape.dims[0];
-        let num_channels = shape.dims[1];
-
+        let num_channels = input.dims()[1];
         if num_channels != self.num_channels {
             panic!(
                 "expected {} channels but got {}",
@@ -103,34 +100,74 @@ impl<B: Backend> GroupNorm<B> {
             );
         }
 
-        let hidden_size =
-            shape.dims[2..].iter().product::<usize>() * num_channels / self.num_groups;
-        let input = input.reshape([batch_size, self.num_groups, hidden_size]);
+        group_norm(
+            input,
+            self.gamma.as_ref().map(|gamma| gamma.val()),
+            self.beta.as_ref().map(|beta| beta.val()),
+            self.num_groups,
+            self.epsilon,
+        )
+    }
+}
+
+/// Normalizes the input over the channels of each group and the remaining dimensions, for each
+/// sample independently, before applying the per-channel affine transformation when `gamma` and
+/// `beta` are given.
+///
+/// # Shapes
+///
+/// - input: `[batch_size, num_channels, *]`
+/// - gamma: `[num_channels]`
+/// - beta: `[num_channels]`
+/// - output: `[batch_size, num_channels, *]`
+pub(crate) fn group_norm<B: Backend, const D: usize>(
+    input: Tensor<B, D>,
+    gamma: Option<Tensor<B, 1>>,
+    beta: Option<Tensor<B, 1>>,
+    num_groups: usize,
+    epsilon: f64,
+) -> Tensor<B, D> {
+    let shape = input.shape();
+    let batch_size = shape.dims[0];
+    let num_channels = shape.dims[1];
 
-        let mean = input.clone().sum_dim(2) / hidden_size as f64;
-        let input = input.sub(mean);
+    let hidden_size = shape.dims[2..].iter().product::<usize>() * num_channels / num_groups;
+    let input = input.reshape([batch_size, num_groups, hidden_size]);
 
-        let var = input.clone().powf_scalar(2.).sum_dim(2) / hidden_size as f64;
-        let input_normalized = input.div(var.sqrt().add_scalar(self.epsilon));
+    let mean = reduce_mean(input.clone());
+    let input = input.sub(mean);
+    let var = reduce_var(input.clone());
+    let input_normalized = input.div(var.sqrt().add_scalar(epsilon)).reshape(shape);
 
-        if self.affine {
-            let mut affine_shape = [1; D];
-            affine_shape[1] = num_channels;
+    let mut affine_shape = [1; D];
+    affine_shape[1] = num_channels;
 
-            input_normalized
-                .reshape(shape)
-                .mul(self.gamma.clone().unwrap().val().reshape(affine_shape))
-                .add(self.beta.clone().unwrap().val().reshape(affine_shape))
-        } else {
-            input_normalized.reshape(shape)
-        }
+    let input_normalized = match gamma {
+        Some(gamma) => input_normalized.mul(gamma.reshape(affine_shape)),
+        None => input_normalized,
+    };
+    match beta {
+        Some(beta) => input_normalized.add(beta.reshape(affine_shape)),
+        None => input_normalized,
     }
 }
 
+/// The mean over the last dimension.
+fn reduce_mean<B: Backend>(input: Tensor<B, 3>) -> Tensor<B, 3> {
+    let [_, _, hidden_size] = input.dims();
+    input.sum_dim(2) / hidden_size as f64
+}
+
+/// The biased variance over the last dimension, of an input already centered.
+fn reduce_var<B: Backend>(centered: Tensor<B, 3>) -> Tensor<B, 3> {
+    let [_, _, hidden_size] = centered.dims();
+    centered.powf_scalar(2.).sum_dim(2) / hidden_size as f64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::TestBackend;
+    use crate::{TestAutodiffBackend, TestBackend};
     use burn_tensor::Data;
 
     #[test]
@@ -259,4 +296,36 @@ mod tests {
             3,
         );
     }
+
+    #[test]
+    fn group_norm_backward() {
+        let device = Default::default();
+        let module = GroupNormConfig::new(2, 4).init::<TestAutodiffBackend>(&device);
+        let input = Tensor::<TestAutodiffBackend, 3>::from_floats(
+            [
+                [[0.1, 0.8], [0.4, 0.3], [0.9, 0.2], [0.6, 0.7]],
+                [[0.5, 0.2], [0.3, 0.9], [0.1, 0.4], [0.8, 0.6]],
+            ],
+            &device,
+        )
+        .require_grad();
+
+        let output = module.forward(input.clone());
+        let grads = output.sum().backward();
+
+        let input_grad = input.grad(&grads).unwrap();
+        let gamma_grad = module.gamma.unwrap().grad(&grads).unwrap();
+        let beta_grad = module.beta.unwrap().grad(&grads).unwrap();
+
+        // The normalized values of each group sum to zero, whatever the input.
+        input_grad
+            .to_data()
+            .assert_approx_eq(&Data::zeros([2, 4, 2]), 3);
+        let gamma_grad = gamma_grad.to_data().value;
+        assert!((gamma_grad[0] + gamma_grad[1]).abs() < 1e-3);
+        assert!((gamma_grad[2] + gamma_grad[3]).abs() < 1e-3);
+        beta_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([4.0, 4.0, 4.0, 4.0]), 3);
+    }
 }
@@ -0,0 +1,196 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::nn::norm::group_norm;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [InstanceNorm](InstanceNorm) layer.
+#[derive(Config, Debug)]
+pub struct InstanceNormConfig {
+    /// The number of channels expected in the input
+    pub num_features: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+    /// A boolean value that when set to `true`, this module has learnable
+    /// per-channel affine parameters initialized to ones (for weights)
+    /// and zeros (for biases). Default: `true`
+    #[config(default = true)]
+    pub affine: bool,
+}
+
+/// Applies Instance Normalization over a mini-batch of inputs.
+///
+/// `Y = instancenorm(X) * γ + β`
+///
+/// Each channel of each sample is normalized over its spatial dimensions, without running
+/// statistics, which is a [group norm](crate::nn::GroupNorm) with one group per channel.
+#[derive(Module, Debug)]
+pub struct InstanceNorm<B: Backend> {
+    num_features: usize,
+    gamma: Option<Param<Tensor<B, 1>>>,
+    beta: Option<Param<Tensor<B, 1>>>,
+    epsilon: f64,
+    affine: bool,
+}
+
+impl InstanceNormConfig {
+    /// Initialize a new [instance norm](InstanceNorm) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> InstanceNorm<B> {
+        let (gamma, beta) = if self.affine {
+            let gamma = Tensor::ones([self.num_features], device).into();
+            let beta = Tensor::zeros([self.num_features], device).into();
+
+            (Some(gamma), Some(beta))
+        } else {
+            (None, None)
+        };
+
+        InstanceNorm {
+            num_features: self.num_features,
+            gamma,
+            beta,
+            epsilon: self.epsilon,
+            affine: self.affine,
+        }
+    }
+
+    /// Initialize a new [instance norm](InstanceNorm) module with a [record](InstanceNormRecord).
+    pub fn init_with<B: Backend>(&self, record: InstanceNormRecord<B>) -> InstanceNorm<B> {
+        InstanceNorm {
+            num_features: self.num_features,
+            gamma: record.gamma,
+            beta: record.beta,
+            epsilon: self.epsilon,
+            affine: self.affine,
+        }
+    }
+}
+
+impl<B: Backend> InstanceNorm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, num_features, *]`
+    /// - output: `[batch_size, num_features, *]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if D <= 2 {
+            panic!(
+                "input rank for InstanceNorm should be at least 3, but got {}",
+                D
+            );
+        }
+
+        let num_features = input.dims()[1];
+        if num_features != self.num_features {
+            panic!(
+                "expected {} channels but got {}",
+                self.num_features, num_features
+            );
+        }
+
+        group_norm(
+            input,
+            self.gamma.as_ref().map(|gamma| gamma.val()),
+            self.beta.as_ref().map(|beta| beta.val()),
+            self.num_features,
+            self.epsilon,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Data;
+
+    #[test]
+    fn instance_norm_forward_affine_false() {
+        let device = Default::default();
+        let module = InstanceNormConfig::new(2)
+            .with_affine(false)
+            .init::<TestBackend>(&device);
+
+        assert!(module.gamma.is_none());
+        assert!(module.beta.is_none());
+
+        let input = Tensor::<TestBackend, 4>::from_floats(
+            [
+                [[[1.0, 2.0], [3.0, 4.0]], [[-1.0, 1.0], [-1.0, 1.0]]],
+                [[[0.0, 0.0], [0.0, 8.0]], [[5.0, 5.0], [6.0, 6.0]]],
+            ],
+            &device,
+        );
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [
+                    [[-1.3416, -0.4472], [0.4472, 1.3416]],
+                    [[-1.0, 1.0], [-1.0, 1.0]],
+                ],
+                [
+                    [[-0.5774, -0.5774], [-0.5774, 1.7320]],
+                    [[-1.0, -1.0], [1.0, 1.0]],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn instance_norm_forward_affine_true() {
+        let device = Default::default();
+        let mut module = InstanceNormConfig::new(2).init::<TestBackend>(&device);
+        module.gamma = Some(Param::from(Tensor::from_floats([2.0, 0.5], &device)));
+        module.beta = Some(Param::from(Tensor::from_floats([1.0, -1.0], &device)));
+
+        let input = Tensor::<TestBackend, 3>::from_floats(
+            [[[1.0, 3.0], [10.0, 20.0]], [[4.0, 2.0], [-5.0, 5.0]]],
+            &device,
+        );
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([[[-1.0, 3.0], [-1.5, -0.5]], [[3.0, -1.0], [-1.5, -0.5]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn instance_norm_backward() {
+        let device = Default::default();
+        let module = InstanceNormConfig::new(2).init::<TestAutodiffBackend>(&device);
+        let input = Tensor::<TestAutodiffBackend, 3>::from_floats(
+            [
+                [[0.1, 0.8, 0.4], [0.3, 0.9, 0.2]],
+                [[0.6, 0.7, 0.5], [0.2, 0.3, 0.9]],
+            ],
+            &device,
+        )
+        .require_grad();
+
+        let output = module.forward(input.clone());
+        let grads = output.sum().backward();
+
+        let input_grad = input.grad(&grads).unwrap();
+        let gamma_grad = module.gamma.unwrap().grad(&grads).unwrap();
+        let beta_grad = module.beta.unwrap().grad(&grads).unwrap();
+
+        // The normalized values of each instance sum to zero, whatever the input.
+        input_grad
+            .to_data()
+            .assert_approx_eq(&Data::zeros([2, 2, 3]), 3);
+        gamma_grad.to_data().assert_approx_eq(&Data::zeros([2]), 3);
+        beta_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([6.0, 6.0]), 3);
+    }
+}
@@ -5,15 +5,23 @@ use crate::module::Module;
 use crate::module::Param;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
+use alloc::{vec, vec::Vec};
 
 /// Configuration to create a [LayerNorm](LayerNorm) layer.
 #[derive(Config)]
 pub struct LayerNormConfig {
-    /// The size of the input features.
+    /// The size of the input features, which is the number of elements of the normalized shape.
     pub d_model: usize,
     /// A value required for numerical stability. Default: 1e-5
     #[config(default = 1e-5)]
     pub epsilon: f64,
+    /// The shape of the normalized dimensions. Default: `[d_model]`
+    #[config(default = "None")]
+    pub normalized_shape: Option<Vec<usize>>,
+    /// The normalized dimensions, which must be contiguous, negative values being counted from
+    /// the last dimension. Default: the last dimensions.
+    #[config(default = "None")]
+    pub axes: Option<Vec<i64>>,
 }
 
 /// Applies Layer Normalization over an input tensor as described in the paper [Layer Normalization](https://arxiv.org/abs/1607.06450).
@@ -24,29 +32,86 @@ pub struct LayerNorm<B: Backend> {
     gamma: Param<Tensor<B, 1>>,
     beta: Param<Tensor<B, 1>>,
     epsilon: f64,
+    first_axis: i64,
+    num_axes: usize,
 }
 
 impl LayerNormConfig {
+    /// Create the config of a layer norm over the given dimensions of the input, similar to
+    /// PyTorch.
+    pub fn from_shape(normalized_shape: &[usize], axes: &[i64]) -> Self {
+        Self::new(normalized_shape.iter().product())
+            .with_normalized_shape(Some(normalized_shape.to_vec()))
+            .with_axes(Some(axes.to_vec()))
+    }
+
     /// Initialize a new [layer norm](LayerNorm) module.
     pub fn init<B: Backend>(&self, device: &B::Device) -> LayerNorm<B> {
         let gamma = Tensor::ones([self.d_model], device);
         let beta = Tensor::zeros([self.d_model], device);
+        let (first_axis, num_axes) = self.axes();
 
         LayerNorm {
             gamma: Param::from(gamma),
             beta: Param::from(beta),
             epsilon: self.epsilon,
+            first_axis,
+            num_axes,
         }
     }
 
     /// Initialize a new [layer norm](LayerNorm) module with a [record](LayerNormRecord).
     pub fn init_with<B: Backend>(&self, record: LayerNormRecord<B>) -> LayerNorm<B> {
+        let (first_axis, num_axes) = self.axes();
+
         LayerNorm {
             gamma: record.gamma,
             beta: record.beta,
             epsilon: self.epsilon,
+            first_axis,
+            num_axes,
         }
     }
+
+    /// Returns the first normalized axis and the number of normalized axes.
+    fn axes(&self) -> (i64, usize) {
+        let normalized_shape = match &self.normalized_shape {
+            Some(normalized_shape) => normalized_shape.clone(),
+            None => vec![self.d_model],
+        };
+        let num_elements: usize = normalized_shape.iter().product();
+
+        assert!(
+            num_elements == self.d_model,
+            "The normalized shape {:?} should have d_model ({}) elements",
+            normalized_shape,
+            self.d_model
+        );
+
+        let axes = match &self.axes {
+            Some(axes) => axes.clone(),
+            None => return (-(normalized_shape.len() as i64), normalized_shape.len()),
+        };
+
+        assert!(
+            axes.len() == normalized_shape.len(),
+            "The axes {:?} should have the same length as the normalized shape {:?}",
+            axes,
+            normalized_shape
+        );
+        assert!(
+            axes.iter().all(|axis| *axis < 0) || axes.iter().all(|axis| *axis >= 0),
+            "The axes {:?} should be either all negative or all positive",
+            axes
+        );
+        assert!(
+            axes.windows(2).all(|pair| pair[1] == pair[0] + 1),
+            "The axes {:?} should be contiguous and in increasing order",
+            axes
+        );
+
+        (axes[0], axes.len())
+    }
 }
 
 impl<B: Backend> LayerNorm<B> {
@@ -56,14 +121,54 @@ impl<B: Backend> LayerNorm<B> {
     ///
     /// - input: `[..., any, d_model]`
     /// - output: `[..., any, d_model]`
+    ///
+    /// When the layer normalizes other dimensions, the input can have any shape as long as the
+    /// normalized dimensions have `d_model` elements.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the normalized dimensions are out of bounds or don't have `d_model` elements.
     pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
-        let (var, mean) = input.clone().var_mean_bias(D - 1);
+        let dims = input.dims();
+        let first_axis = match self.first_axis < 0 {
+            true => D as i64 + self.first_axis,
+            false => self.first_axis,
+        };
+
+        assert!(
+            first_axis >= 0 && first_axis as usize + self.num_axes <= D,
+            "LayerNorm can't normalize {} dimensions from dimension {} of a {}D tensor",
+            self.num_axes,
+            self.first_axis,
+            D
+        );
+
+        // The input is seen as [before, normalized, after], so that the normalized dimensions
+        // don't have to be the last ones.
+        let first_axis = first_axis as usize;
+        let end_axis = first_axis + self.num_axes;
+        let size_before: usize = dims[..first_axis].iter().product();
+        let size_normalized: usize = dims[first_axis..end_axis].iter().product();
+        let size_after: usize = dims[end_axis..].iter().product();
+        let [d_model] = self.gamma.dims();
+
+        assert!(
+            size_normalized == d_model,
+            "LayerNorm expects the normalized dimensions {:?} to have {} elements",
+            &dims[first_axis..end_axis],
+            d_model
+        );
+
+        let input = input.reshape([size_before, size_normalized, size_after]);
+        let (var, mean) = input.clone().var_mean_bias(1);
 
         let input_normalized = input.sub(mean).div(var.sqrt().add_scalar(self.epsilon));
 
+        let shape = [1, size_normalized, 1];
         input_normalized
-            .mul(self.gamma.val().unsqueeze())
-            .add(self.beta.val().unsqueeze())
+            .mul(self.gamma.val().reshape(shape))
+            .add(self.beta.val().reshape(shape))
+            .reshape(dims)
     }
 }
 
@@ -99,6 +204,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn layer_norm_forward_inner_axis() {
+        let device = Default::default();
+        let module = LayerNormConfig::from_shape(&[3], &[1]).init::<TestBackend>(&device);
+
+        let output = module.forward(input_tensor(&device));
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [[-1.3363, -1.2247], [1.0690, 0.0], [0.2673, 1.2247]],
+                [[-1.1625, 1.3363], [-0.1162, -0.2673], [1.2787, -1.0690]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn layer_norm_forward_trailing_axes() {
+        let device = Default::default();
+        let module = LayerNormConfig::from_shape(&[3, 2], &[-2, -1]).init::<TestBackend>(&device);
+
+        let output = module.forward(input_tensor(&device));
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [[-1.2499, -0.8087], [0.0735, 0.5146], [-0.3676, 1.8380]],
+                [[-1.4104, 1.0664], [-0.1720, -0.1720], [1.4792, -0.7912]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic = "should be contiguous"]
+    fn layer_norm_non_contiguous_axes() {
+        let device = Default::default();
+        LayerNormConfig::from_shape(&[2, 2], &[0, 2]).init::<TestBackend>(&device);
+    }
+
+    #[test]
+    #[should_panic = "LayerNorm expects the normalized dimensions [2] to have 3 elements"]
+    fn layer_norm_wrong_normalized_size() {
+        let device = Default::default();
+        let module = LayerNormConfig::from_shape(&[3], &[-1]).init::<TestBackend>(&device);
+
+        module.forward(input_tensor(&device));
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn layer_norm_backward() {
@@ -138,4 +291,14 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(tensor_2_grad.shape()), 3);
     }
+
+    fn input_tensor<B: Backend>(device: &B::Device) -> Tensor<B, 3> {
+        Tensor::from_floats(
+            [
+                [[0.0, 1.0], [3.0, 4.0], [2.0, 7.0]],
+                [[-1.0, 5.0], [2.0, 2.0], [6.0, 0.5]],
+            ],
+            device,
+        )
+    }
 }
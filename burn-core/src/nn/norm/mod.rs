@@ -1,7 +1,17 @@
 mod batch;
 mod group;
+mod instance;
 mod layer;
+mod rms;
+mod spectral;
+mod weight;
+mod weight_module;
 
 pub use batch::*;
 pub use group::*;
+pub use instance::*;
 pub use layer::*;
+pub use rms::*;
+pub use spectral::*;
+pub use weight::*;
+pub use weight_module::*;
@@ -0,0 +1,157 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [RMSNorm](RMSNorm) layer.
+#[derive(Config, Debug)]
+pub struct RMSNormConfig {
+    /// The size of the input features.
+    pub d_model: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+}
+
+/// Applies Root Mean Square Layer Normalization over an input tensor as described in the paper
+/// [Root Mean Square Layer Normalization](https://arxiv.org/abs/1910.07467).
+///
+/// `Y = X / sqrt(mean(X^2) + eps) * γ`
+///
+/// Unlike the [layer norm](crate::nn::LayerNorm), the input isn't centered and there is no bias.
+#[derive(Module, Debug)]
+pub struct RMSNorm<B: Backend> {
+    gamma: Param<Tensor<B, 1>>,
+    epsilon: f64,
+}
+
+impl RMSNormConfig {
+    /// Initialize a new [RMS norm](RMSNorm) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> RMSNorm<B> {
+        let gamma = Tensor::ones([self.d_model], device);
+
+        RMSNorm {
+            gamma: Param::from(gamma),
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Initialize a new [RMS norm](RMSNorm) module with a [record](RMSNormRecord).
+    pub fn init_with<B: Backend>(&self, record: RMSNormRecord<B>) -> RMSNorm<B> {
+        RMSNorm {
+            gamma: record.gamma,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend> RMSNorm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_model]`
+    /// - output: `[..., any, d_model]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let rms = input
+            .clone()
+            .powf_scalar(2.0)
+            .mean_dim(D - 1)
+            .add_scalar(self.epsilon)
+            .sqrt();
+
+        input.div(rms).mul(self.gamma.val().unsqueeze())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[test]
+    fn rms_norm_forward() {
+        let device = Default::default();
+        let mut module = RMSNormConfig::new(3).init::<TestBackend>(&device);
+        module.gamma = Param::from(Tensor::from_floats([1.0, 2.0, 0.5], &device));
+        let input =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 3.0], [-1.0, 0.0, 1.0]], &device);
+
+        let output = module.forward(input.clone());
+
+        // Reference implementation.
+        let rms = input
+            .clone()
+            .powf_scalar(2.0)
+            .sum_dim(1)
+            .div_scalar(3.0)
+            .add_scalar(1e-5)
+            .sqrt();
+        let expected = input
+            .div(rms)
+            .mul(Tensor::from_floats([[1.0, 2.0, 0.5]], &device));
+
+        output.to_data().assert_approx_eq(&expected.into_data(), 4);
+        output.to_data().assert_approx_eq(
+            &Data::from([[0.4629, 1.8516, 0.6944], [-1.2247, 0.0, 0.6124]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn rms_norm_forward_3d() {
+        let device = Default::default();
+        let module = RMSNormConfig::new(2).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::from_floats(
+            [[[3.0, 4.0], [1.0, -3.0]], [[1.0, 1.0], [-6.0, 8.0]]],
+            &device,
+        );
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [[0.8485, 1.1314], [0.4472, -1.3416]],
+                [[1.0, 1.0], [-0.8485, 1.1314]],
+            ]),
+            3,
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn rms_norm_backward() {
+        let device = Default::default();
+        let module = RMSNormConfig::new(3).init::<TestAutodiffBackend>(&device);
+        let input = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[1.0, 2.0, 3.0], [-1.0, 0.0, 1.0]],
+            &device,
+        )
+        .require_grad();
+
+        let output = module.forward(input.clone());
+        let grads = output.sum().backward();
+
+        let input_grad = input.grad(&grads).unwrap();
+        let gamma_grad = module.gamma.grad(&grads).unwrap();
+
+        // d/dγ_j = sum_i x_ij / rms_i
+        gamma_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([-0.7618, 0.9258, 2.6135]), 3);
+        // d/dx_ij = (1 - x_ij * sum_k x_ik / (n * rms_i^2)) / rms_i
+        input_grad.to_data().assert_approx_eq(
+            &Data::from([[0.2645, 0.0661, -0.1323], [1.2247, 1.2247, 1.2247]]),
+            3,
+        );
+    }
+}
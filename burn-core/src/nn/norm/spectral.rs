@@ -0,0 +1,259 @@
+use crate as burn;
+
+use super::WeightModule;
+use crate::config::Config;
+use crate::module::{Module, RunningState};
+use crate::nn::conv::{Conv1d, Conv2d};
+use crate::nn::Linear;
+use crate::tensor::{backend::Backend, Distribution, Tensor};
+
+/// Configuration to create a [SpectralNorm](SpectralNorm) wrapper.
+#[derive(Config, Debug)]
+pub struct SpectralNormConfig {
+    /// The number of power iterations run on each forward pass when training.
+    #[config(default = 1)]
+    pub n_power_iterations: usize,
+    /// A value required for numerical stability.
+    #[config(default = 1e-12)]
+    pub epsilon: f64,
+}
+
+/// Applies spectral normalization to the weight of a module, as described in the paper
+/// [Spectral Normalization for Generative Adversarial Networks](https://arxiv.org/abs/1802.05957).
+///
+/// The weight is divided by its largest singular value, which is estimated by power iteration.
+/// The left singular vector is kept between the forward passes, and refined by
+/// `n_power_iterations` steps on each forward pass when training.
+#[derive(Module, Debug)]
+pub struct SpectralNorm<B: Backend, M> {
+    /// The wrapped module, with its weight before normalization.
+    pub module: M,
+    u: RunningState<Tensor<B, 1>>,
+    n_power_iterations: usize,
+    epsilon: f64,
+}
+
+impl SpectralNormConfig {
+    /// Wrap the module with [spectral normalization](SpectralNorm).
+    pub fn init<B: Backend, M: WeightModule<B>>(&self, module: M) -> SpectralNorm<B, M> {
+        let weight = module.weight_matrix();
+        let [num_outputs, _] = weight.dims();
+        let u = Tensor::random(
+            [num_outputs],
+            Distribution::Normal(0.0, 1.0),
+            &weight.device(),
+        )
+        .normalize(2.0, 0, self.epsilon);
+
+        SpectralNorm {
+            module,
+            u: RunningState::new(u),
+            n_power_iterations: self.n_power_iterations,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend, M: WeightModule<B>> SpectralNorm<B, M> {
+    /// The wrapped module with its weight divided by its spectral norm.
+    ///
+    /// When training, the estimation of the singular vectors is refined first.
+    pub fn normalized(&self) -> M {
+        let iterations = match B::ad_enabled() {
+            true => self.n_power_iterations,
+            false => 0,
+        };
+        let weight = self.normalized_weight(iterations);
+
+        self.module.clone().with_weight_matrix(weight)
+    }
+
+    fn normalized_weight(&self, iterations: usize) -> Tensor<B, 2> {
+        let weight = self.module.weight_matrix();
+        let device = weight.device();
+        let matrix = weight.clone().detach();
+        let mut u = self.u.value_sync().to_device(&device);
+
+        for _ in 0..iterations {
+            let v = Self::multiply(matrix.clone().transpose(), u).normalize(2.0, 0, self.epsilon);
+            u = Self::multiply(matrix.clone(), v).normalize(2.0, 0, self.epsilon);
+        }
+        if iterations > 0 {
+            self.u.update(u.clone());
+        }
+
+        let v = Self::multiply(matrix.transpose(), u.clone()).normalize(2.0, 0, self.epsilon);
+        let sigma = Self::multiply(weight.clone(), v).mul(u).sum();
+
+        weight.div(sigma.unsqueeze())
+    }
+
+    fn multiply(matrix: Tensor<B, 2>, vector: Tensor<B, 1>) -> Tensor<B, 1> {
+        matrix.matmul(vector.unsqueeze_dim(1)).squeeze(1)
+    }
+}
+
+/// Remove the [spectral normalization](SpectralNorm) of a module, its weight being replaced by
+/// the normalized weight.
+pub fn remove_spectral_norm<B: Backend, M: WeightModule<B>>(module: SpectralNorm<B, M>) -> M {
+    let require_grad = module.module.weight_matrix().is_require_grad();
+    let weight = module
+        .normalized_weight(0)
+        .detach()
+        .set_require_grad(require_grad);
+
+    module.module.with_weight_matrix(weight)
+}
+
+impl<B: Backend> SpectralNorm<B, Linear<B>> {
+    /// Applies the forward pass of the [linear](Linear) module with the normalized weight.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        self.normalized().forward(input)
+    }
+}
+
+impl<B: Backend> SpectralNorm<B, Conv1d<B>> {
+    /// Applies the forward pass of the [conv1d](Conv1d) module with the normalized weight.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, length_in]`
+    /// - output: `[batch_size, channels_out, length_out]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        self.normalized().forward(input)
+    }
+}
+
+impl<B: Backend> SpectralNorm<B, Conv2d<B>> {
+    /// Applies the forward pass of the [conv2d](Conv2d) module with the normalized weight.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        self.normalized().forward(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::nn::LinearConfig;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Data;
+
+    /// The largest singular value of the matrix, estimated with many power iterations.
+    fn operator_norm(matrix: Tensor<TestBackend, 2>) -> f32 {
+        let [_, num_inputs] = matrix.dims();
+        let mut v =
+            Tensor::<TestBackend, 1>::ones([num_inputs], &matrix.device()).normalize(2.0, 0, 1e-12);
+
+        for _ in 0..200 {
+            let u = matrix.clone().matmul(v.unsqueeze_dim(1)).squeeze::<1>(1);
+            v = matrix
+                .clone()
+                .transpose()
+                .matmul(u.unsqueeze_dim(1))
+                .squeeze(1)
+                .normalize(2.0, 0, 1e-12);
+        }
+
+        let u = matrix.matmul(v.unsqueeze_dim(1)).squeeze::<1>(1);
+        u.powf_scalar(2.0).sum().sqrt().into_scalar()
+    }
+
+    #[test]
+    fn test_spectral_norm_linear_operator_norm() {
+        let device = Default::default();
+        let linear = LinearConfig::new(6, 4).init::<TestAutodiffBackend>(&device);
+        let linear = SpectralNormConfig::new().init(linear);
+
+        for _ in 0..50 {
+            linear.normalized();
+        }
+
+        let norm = operator_norm(linear.normalized().weight_matrix().inner());
+        assert!(
+            norm <= 1.0 + 1e-3,
+            "Operator norm {} should be at most 1",
+            norm
+        );
+        assert!(
+            norm >= 1.0 - 1e-3,
+            "Operator norm {} should be close to 1",
+            norm
+        );
+    }
+
+    #[test]
+    fn test_spectral_norm_known_singular_value() {
+        let device = Default::default();
+        let linear = LinearConfig::new(2, 2)
+            .with_bias(false)
+            .init::<TestAutodiffBackend>(&device);
+        let weight = Tensor::from_floats([[3.0, 0.0], [0.0, 1.0]], &device);
+        let linear = SpectralNormConfig::new()
+            .with_n_power_iterations(10)
+            .init(linear.with_weight_matrix(weight));
+
+        let output = linear.forward(Tensor::from_floats([[1.0, 1.0]], &device));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0 / 3.0]]), 3);
+    }
+
+    #[test]
+    fn test_spectral_norm_conv2d_operator_norm() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([2, 3], [3, 3]).init::<TestAutodiffBackend>(&device);
+        let conv = SpectralNormConfig::new()
+            .with_n_power_iterations(50)
+            .init(conv);
+
+        let output = conv.forward(Tensor::random([1, 2, 5, 5], Distribution::Default, &device));
+
+        assert_eq!(output.dims(), [1, 3, 3, 3]);
+        let norm = operator_norm(conv.normalized().weight_matrix().inner());
+        assert!(
+            norm <= 1.0 + 1e-3,
+            "Operator norm {} should be at most 1",
+            norm
+        );
+    }
+
+    #[test]
+    fn test_spectral_norm_gradients_reach_the_wrapped_weight() {
+        let device = Default::default();
+        let linear = LinearConfig::new(3, 2).init::<TestAutodiffBackend>(&device);
+        let linear = SpectralNormConfig::new().init(linear);
+
+        let output = linear.forward(Tensor::random([4, 3], Distribution::Default, &device));
+        let grads = output.sum().backward();
+
+        assert!(linear.module.weight.grad(&grads).is_some());
+    }
+
+    #[test]
+    fn test_remove_spectral_norm() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 3).init::<TestBackend>(&device);
+        let linear = SpectralNormConfig::new().init(linear);
+        let input = Tensor::<TestBackend, 2>::random([2, 4], Distribution::Default, &device);
+
+        let expected = linear.forward(input.clone());
+        let linear = remove_spectral_norm(linear);
+
+        linear
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+}
@@ -0,0 +1,224 @@
+use crate as burn;
+
+use super::WeightModule;
+use crate::config::Config;
+use crate::module::{Module, Param};
+use crate::nn::conv::{Conv1d, Conv2d};
+use crate::nn::Linear;
+use crate::tensor::{backend::Backend, Tensor};
+
+/// Configuration to create a [WeightNorm](WeightNorm) wrapper.
+#[derive(Config, Debug)]
+pub struct WeightNormConfig {
+    /// A value required for numerical stability.
+    #[config(default = 1e-12)]
+    pub epsilon: f64,
+}
+
+/// Applies weight normalization to the weight of a module, as described in the paper
+/// [Weight Normalization: A Simple Reparameterization to Accelerate Training of Deep Neural Networks](https://arxiv.org/abs/1602.07868).
+///
+/// The weight of each output is reparameterized as `w = g * v / ||v||`, where the magnitude `g`
+/// and the direction `v` are trained separately. The direction is the weight of the wrapped
+/// module, so the records store `g` and `v` instead of `w`.
+#[derive(Module, Debug)]
+pub struct WeightNorm<B: Backend, M> {
+    /// The wrapped module, with the direction `v` as its weight.
+    pub module: M,
+    /// The magnitude of the weight of each output, of shape `[num_outputs]`.
+    pub g: Param<Tensor<B, 1>>,
+    epsilon: f64,
+}
+
+impl WeightNormConfig {
+    /// Wrap the module with [weight normalization](WeightNorm), the magnitude being initialized
+    /// so that the weight is unchanged.
+    pub fn init<B: Backend, M: WeightModule<B>>(&self, module: M) -> WeightNorm<B, M> {
+        let g = module
+            .weight_matrix()
+            .detach()
+            .powf_scalar(2.0)
+            .sum_dim(1)
+            .sqrt()
+            .squeeze(1);
+
+        WeightNorm {
+            module,
+            g: Param::from(g),
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend, M: WeightModule<B>> WeightNorm<B, M> {
+    /// The wrapped module with the weight computed from its magnitude and direction.
+    pub fn normalized(&self) -> M {
+        self.module.clone().with_weight_matrix(self.weight())
+    }
+
+    fn weight(&self) -> Tensor<B, 2> {
+        let direction = self.module.weight_matrix().normalize(2.0, 1, self.epsilon);
+
+        direction.mul(self.g.val().unsqueeze_dim(1))
+    }
+}
+
+/// Remove the [weight normalization](WeightNorm) of a module, its weight being replaced by the
+/// weight computed from the magnitude and the direction.
+pub fn remove_weight_norm<B: Backend, M: WeightModule<B>>(module: WeightNorm<B, M>) -> M {
+    let require_grad = module.module.weight_matrix().is_require_grad();
+    let weight = module.weight().detach().set_require_grad(require_grad);
+
+    module.module.with_weight_matrix(weight)
+}
+
+impl<B: Backend> WeightNorm<B, Linear<B>> {
+    /// Applies the forward pass of the [linear](Linear) module with the normalized weight.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        self.normalized().forward(input)
+    }
+}
+
+impl<B: Backend> WeightNorm<B, Conv1d<B>> {
+    /// Applies the forward pass of the [conv1d](Conv1d) module with the normalized weight.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, length_in]`
+    /// - output: `[batch_size, channels_out, length_out]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        self.normalized().forward(input)
+    }
+}
+
+impl<B: Backend> WeightNorm<B, Conv2d<B>> {
+    /// Applies the forward pass of the [conv2d](Conv2d) module with the normalized weight.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        self.normalized().forward(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::conv::Conv1dConfig;
+    use crate::nn::LinearConfig;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::{Data, Distribution};
+
+    #[test]
+    fn test_weight_norm_linear_keeps_the_output() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 3).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 2>::random([2, 4], Distribution::Default, &device);
+
+        let expected = linear.forward(input.clone());
+        let linear = WeightNormConfig::new().init(linear);
+
+        linear
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn test_weight_norm_conv1d_keeps_the_output() {
+        let device = Default::default();
+        let conv = Conv1dConfig::new(2, 3, 3).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 2, 6], Distribution::Default, &device);
+
+        let expected = conv.forward(input.clone());
+        let conv = WeightNormConfig::new().init(conv);
+
+        conv.forward(input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn test_weight_norm_magnitude() {
+        let device = Default::default();
+        let linear = LinearConfig::new(2, 2)
+            .with_bias(false)
+            .init::<TestBackend>(&device)
+            .with_weight_matrix(Tensor::from_floats([[3.0, 4.0], [0.0, 2.0]], &device));
+        let mut linear = WeightNormConfig::new().init(linear);
+
+        linear
+            .g
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([5.0, 2.0]), 5);
+
+        linear.g = linear.g.map(|_| Tensor::from_floats([10.0, 1.0], &device));
+        let output = linear.forward(Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0]], &device));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[14.0, 1.0]]), 5);
+    }
+
+    #[test]
+    fn test_weight_norm_record_stores_magnitude_and_direction() {
+        let device = Default::default();
+        let linear = LinearConfig::new(2, 2)
+            .with_bias(false)
+            .init::<TestBackend>(&device)
+            .with_weight_matrix(Tensor::from_floats([[3.0, 4.0], [0.0, 2.0]], &device));
+        let linear = WeightNormConfig::new().init(linear);
+
+        let record = linear.into_record();
+
+        record
+            .g
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([5.0, 2.0]), 5);
+        record
+            .module
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0, 0.0], [4.0, 2.0]]), 5);
+    }
+
+    #[test]
+    fn test_weight_norm_gradients() {
+        let device = Default::default();
+        let linear = LinearConfig::new(3, 2).init::<TestAutodiffBackend>(&device);
+        let linear = WeightNormConfig::new().init(linear);
+
+        let output = linear.forward(Tensor::random([4, 3], Distribution::Default, &device));
+        let grads = output.sum().backward();
+
+        assert!(linear.g.grad(&grads).is_some());
+        assert!(linear.module.weight.grad(&grads).is_some());
+    }
+
+    #[test]
+    fn test_remove_weight_norm() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 3).init::<TestBackend>(&device);
+        let mut linear = WeightNormConfig::new().init(linear);
+        linear.g = linear.g.map(|g| g * 2);
+        let input = Tensor::<TestBackend, 2>::random([2, 4], Distribution::Default, &device);
+
+        let expected = linear.forward(input.clone());
+        let linear = remove_weight_norm(linear);
+
+        linear
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::module::Module;
+use crate::nn::conv::{Conv1d, Conv2d};
+use crate::nn::Linear;
+use crate::tensor::{backend::Backend, Tensor};
+
+/// A module with a weight that can be reparameterized, such as by
+/// [spectral normalization](crate::nn::SpectralNorm) or [weight normalization](crate::nn::WeightNorm).
+///
+/// The weight is seen as a matrix of shape `[num_outputs, num_inputs]`, all the dimensions
+/// except the one of the outputs being flattened.
+pub trait WeightModule<B: Backend>: Module<B> {
+    /// The weight of the module as a matrix of shape `[num_outputs, num_inputs]`.
+    fn weight_matrix(&self) -> Tensor<B, 2>;
+
+    /// Replace the weight of the module with the given matrix of shape
+    /// `[num_outputs, num_inputs]`, keeping the parameter id.
+    fn with_weight_matrix(self, weight: Tensor<B, 2>) -> Self;
+}
+
+impl<B: Backend> WeightModule<B> for Linear<B> {
+    fn weight_matrix(&self) -> Tensor<B, 2> {
+        self.weight.val().transpose()
+    }
+
+    fn with_weight_matrix(mut self, weight: Tensor<B, 2>) -> Self {
+        self.weight = self.weight.map(|_| weight.transpose());
+        self
+    }
+}
+
+impl<B: Backend> WeightModule<B> for Conv1d<B> {
+    fn weight_matrix(&self) -> Tensor<B, 2> {
+        let [channels_out, channels_in, kernel_size] = self.weight.dims();
+
+        self.weight
+            .val()
+            .reshape([channels_out, channels_in * kernel_size])
+    }
+
+    fn with_weight_matrix(mut self, weight: Tensor<B, 2>) -> Self {
+        let shape = self.weight.shape();
+        self.weight = self.weight.map(|_| weight.reshape(shape));
+        self
+    }
+}
+
+impl<B: Backend> WeightModule<B> for Conv2d<B> {
+    fn weight_matrix(&self) -> Tensor<B, 2> {
+        let [channels_out, channels_in, kernel_size_1, kernel_size_2] = self.weight.dims();
+
+        self.weight
+            .val()
+            .reshape([channels_out, channels_in * kernel_size_1 * kernel_size_2])
+    }
+
+    fn with_weight_matrix(mut self, weight: Tensor<B, 2>) -> Self {
+        let shape = self.weight.shape();
+        self.weight = self.weight.map(|_| weight.reshape(shape));
+        self
+    }
+}
@@ -3,6 +3,7 @@ use alloc::vec::Vec;
 use crate as burn;
 use crate::config::Config;
 use crate::module::Module;
+use crate::nn::{Dropout, DropoutConfig, Embedding, EmbeddingConfig, Initializer};
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 use burn_tensor::Data;
@@ -93,6 +94,156 @@ impl<B: Backend> PositionalEncoding<B> {
     }
 }
 
+/// Configuration to create a [SinusoidalPositionalEncoding](SinusoidalPositionalEncoding) layer.
+#[derive(Config, Debug)]
+pub struct SinusoidalPositionalEncodingConfig {
+    /// The size of each vector.
+    pub d_model: usize,
+    /// The maximum sequence length.
+    #[config(default = "5_000")]
+    pub max_len: usize,
+    /// The dropout probability applied after adding the encodings.
+    #[config(default = 0.1)]
+    pub dropout: f64,
+    /// Max time scale to use.
+    #[config(default = "10_000")]
+    pub max_timescale: usize,
+}
+
+/// Adds fixed sinusoidal encodings of the positions to the input, followed by a dropout.
+///
+/// The encoding of the position `p` is `sin(p * w_k)` at index `2k` and `cos(p * w_k)` at index
+/// `2k + 1`, with the frequencies `w_k = max_timescale^(-2k / d_model)`, as introduced in
+/// [Attention is all you need](https://arxiv.org/abs/1706.03762). They are computed once, when
+/// the module is initialized, for all the positions up to `max_len`.
+#[derive(Module, Debug)]
+pub struct SinusoidalPositionalEncoding<B: Backend> {
+    sinusoids: Tensor<B, 3>,
+    dropout: Dropout,
+}
+
+impl SinusoidalPositionalEncodingConfig {
+    /// Initialize a new [SinusoidalPositionalEncoding](SinusoidalPositionalEncoding) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> SinusoidalPositionalEncoding<B> {
+        let sinusoids =
+            generate_sinusoids::<B>(self.max_len, self.d_model, self.max_timescale, device)
+                .unsqueeze::<3>();
+
+        SinusoidalPositionalEncoding {
+            sinusoids,
+            dropout: DropoutConfig::new(self.dropout).init(),
+        }
+    }
+}
+
+impl<B: Backend> SinusoidalPositionalEncoding<B> {
+    /// Applies the forward pass on the input tensor by adding the sinusoids to the input.
+    ///
+    /// # Shapes
+    ///
+    /// * input: `[batch_size, seq_length, d_model]`
+    /// * output: `[batch_size, seq_length, d_model]`
+    ///
+    /// # Panics
+    ///
+    /// If the sequence is longer than `max_len`, or if the size of the input vectors isn't
+    /// `d_model`.
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [_, max_len, d_model] = self.sinusoids.dims();
+        let [_, seq_length, _] = input.dims();
+        check_input_shape(input.dims(), max_len, d_model);
+
+        let sinusoids = self
+            .sinusoids
+            .clone()
+            .slice([0..1, 0..seq_length, 0..d_model]);
+
+        self.dropout.forward(input.add(sinusoids))
+    }
+}
+
+/// Configuration to create a [LearnablePositionalEncoding](LearnablePositionalEncoding) layer.
+#[derive(Config, Debug)]
+pub struct LearnablePositionalEncodingConfig {
+    /// The size of each vector.
+    pub d_model: usize,
+    /// The maximum sequence length.
+    #[config(default = "512")]
+    pub max_len: usize,
+    /// The dropout probability applied after adding the encodings.
+    #[config(default = 0.1)]
+    pub dropout: f64,
+    /// The type of function used to initialize the encodings.
+    #[config(default = "Initializer::Normal{mean:0.0, std:0.02}")]
+    pub initializer: Initializer,
+}
+
+/// Adds learned encodings of the positions to the input, followed by a dropout.
+///
+/// The encodings are the vectors of an [embedding](Embedding) of shape `[max_len, d_model]`,
+/// indexed by the positions, as in [BERT](https://arxiv.org/abs/1810.04805).
+#[derive(Module, Debug)]
+pub struct LearnablePositionalEncoding<B: Backend> {
+    /// The embedding of the positions.
+    pub embedding: Embedding<B>,
+    dropout: Dropout,
+}
+
+impl LearnablePositionalEncodingConfig {
+    /// Initialize a new [LearnablePositionalEncoding](LearnablePositionalEncoding) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> LearnablePositionalEncoding<B> {
+        let embedding = EmbeddingConfig::new(self.max_len, self.d_model)
+            .with_initializer(self.initializer.clone())
+            .init(device);
+
+        LearnablePositionalEncoding {
+            embedding,
+            dropout: DropoutConfig::new(self.dropout).init(),
+        }
+    }
+}
+
+impl<B: Backend> LearnablePositionalEncoding<B> {
+    /// Applies the forward pass on the input tensor by adding the encodings of the positions to
+    /// the input.
+    ///
+    /// # Shapes
+    ///
+    /// * input: `[batch_size, seq_length, d_model]`
+    /// * output: `[batch_size, seq_length, d_model]`
+    ///
+    /// # Panics
+    ///
+    /// If the sequence is longer than `max_len`, or if the size of the input vectors isn't
+    /// `d_model`.
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [max_len, d_model] = self.embedding.weight.dims();
+        let [_, seq_length, _] = input.dims();
+        check_input_shape(input.dims(), max_len, d_model);
+
+        let positions =
+            Tensor::arange(0..seq_length as i64, &input.device()).reshape([1, seq_length]);
+        let encodings = self.embedding.forward(positions);
+
+        self.dropout.forward(input.add(encodings))
+    }
+}
+
+fn check_input_shape(dims: [usize; 3], max_len: usize, d_model: usize) {
+    let [_, seq_length, d_model_input] = dims;
+
+    assert!(
+        seq_length <= max_len,
+        "The sequence length ({seq_length}) should be at most the maximum length of the \
+         positional encoding ({max_len})."
+    );
+    assert!(
+        d_model_input == d_model,
+        "The size of the input vectors ({d_model_input}) should be the d_model of the \
+         positional encoding ({d_model})."
+    );
+}
+
 /// Returns sinusoids for positional embedding introduced in
 /// [Attention is all you need](https://arxiv.org/abs/1706.03762).
 ///
@@ -158,6 +309,7 @@ mod tests {
 
     use super::*;
     use crate::TestBackend;
+    use burn_tensor::Distribution;
 
     #[test]
     fn test_module() {
@@ -242,4 +394,120 @@ mod tests {
         let input = Tensor::zeros([1, 6_000, d_model], &device);
         let _output = pe.forward(input);
     }
+    #[test]
+    fn sinusoidal_encodings_should_follow_the_frequencies() {
+        let [d_model, length] = [8, 16];
+        let device = Default::default();
+        let pe = SinusoidalPositionalEncodingConfig::new(d_model)
+            .with_max_len(32)
+            .with_dropout(0.0)
+            .init::<TestBackend>(&device);
+
+        let output = pe.forward(Tensor::zeros([1, length, d_model], &device));
+
+        let mut expected = Vec::new();
+        for position in 0..length {
+            for k in 0..d_model / 2 {
+                let frequency = 10_000f32.powf(-2.0 * k as f32 / d_model as f32);
+                let angle = position as f32 * frequency;
+                expected.push(angle.sin());
+                expected.push(angle.cos());
+            }
+        }
+        output
+            .into_data()
+            .assert_approx_eq(&Data::new(expected, [1, length, d_model].into()), 4);
+    }
+
+    #[test]
+    fn sinusoidal_encoding_should_add_to_the_input() {
+        let device = Default::default();
+        let pe = SinusoidalPositionalEncodingConfig::new(6)
+            .with_dropout(0.0)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 6], Distribution::Default, &device);
+
+        let output = pe.forward(input.clone());
+
+        let sinusoids = generate_sinusoids::<TestBackend>(3, 6, 10_000, &device).unsqueeze::<3>();
+        output
+            .into_data()
+            .assert_approx_eq(&(input + sinusoids).into_data(), 5);
+    }
+
+    #[test]
+    #[should_panic = "should be at most the maximum length"]
+    fn sinusoidal_encoding_should_panic_with_longer_sequences() {
+        let device = Default::default();
+        let pe = SinusoidalPositionalEncodingConfig::new(4)
+            .with_max_len(8)
+            .init::<TestBackend>(&device);
+
+        let _output = pe.forward(Tensor::zeros([1, 9, 4], &device));
+    }
+
+    #[test]
+    fn learnable_encoding_should_add_the_embedding_of_the_positions() {
+        let device = Default::default();
+        let pe = LearnablePositionalEncodingConfig::new(4)
+            .with_max_len(10)
+            .with_dropout(0.0)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 7, 4], Distribution::Default, &device);
+
+        let output = pe.forward(input.clone());
+
+        let encodings = pe
+            .embedding
+            .weight
+            .val()
+            .slice([0..7, 0..4])
+            .unsqueeze::<3>();
+        output
+            .into_data()
+            .assert_approx_eq(&(input + encodings).into_data(), 5);
+    }
+
+    #[test]
+    #[should_panic = "should be at most the maximum length"]
+    fn learnable_encoding_should_panic_with_longer_sequences() {
+        let device = Default::default();
+        let pe = LearnablePositionalEncodingConfig::new(4)
+            .with_max_len(8)
+            .init::<TestBackend>(&device);
+
+        let _output = pe.forward(Tensor::zeros([1, 9, 4], &device));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn learnable_encoding_should_update_during_training() {
+        use crate::optim::{GradientsParams, Optimizer, SgdConfig};
+        use crate::TestAutodiffBackend;
+
+        let device = Default::default();
+        let pe = LearnablePositionalEncodingConfig::new(4)
+            .with_max_len(10)
+            .with_dropout(0.0)
+            .init::<TestAutodiffBackend>(&device);
+        let before = pe.embedding.weight.val().into_data();
+        let input =
+            Tensor::<TestAutodiffBackend, 3>::random([2, 6, 4], Distribution::Default, &device);
+
+        let grads = pe.forward(input).powf_scalar(2.0).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &pe);
+        let pe = SgdConfig::new().init().step(0.1, pe, grads);
+
+        // Only the encodings of the positions of the sequence are updated.
+        let after = pe.embedding.weight.val().into_data();
+        let changed = before
+            .value
+            .chunks(4)
+            .zip(after.value.chunks(4))
+            .map(|(before, after)| before != after)
+            .collect::<Vec<_>>();
+        let mut expected = vec![true; 6];
+        expected.extend([false; 4]);
+        assert_eq!(changed, expected);
+    }
 }
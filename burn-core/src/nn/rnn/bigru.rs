@@ -0,0 +1,255 @@
+use crate as burn;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::nn::gru::{Gru, GruConfig};
+use crate::nn::{
+    pack_padded_sequence, pad_packed_sequence, Dropout, DropoutConfig, Initializer, PackedSequence,
+};
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// The configuration for a [bidirectional gru](BiGru) module.
+#[derive(Config, Debug)]
+pub struct BiGruConfig {
+    /// The size of the input features.
+    pub d_input: usize,
+    /// The size of the hidden state of each direction.
+    pub d_hidden: usize,
+    /// If a bias should be applied during the Gru transformations.
+    pub bias: bool,
+    /// The number of stacked bidirectional layers.
+    #[config(default = 1)]
+    pub num_layers: usize,
+    /// The dropout applied to the outputs of each layer except the last one.
+    #[config(default = 0.0)]
+    pub dropout: f64,
+    /// Gru initializer
+    #[config(default = "Initializer::XavierNormal{gain:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// The bidirectional Gru module, which runs a [gru](Gru) over the sequences in both directions
+/// and concatenates their hidden states, so each output has `2 * d_hidden` features: the hidden
+/// state of the forward direction followed by the one of the reverse direction.
+///
+/// When several layers are stacked, each layer takes the outputs of the previous one as input.
+#[derive(Module, Debug)]
+pub struct BiGru<B: Backend> {
+    forward_layers: Vec<Gru<B>>,
+    reverse_layers: Vec<Gru<B>>,
+    dropout: Dropout,
+    d_hidden: usize,
+}
+
+impl BiGruConfig {
+    /// Initialize a new [bidirectional gru](BiGru) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> BiGru<B> {
+        assert!(
+            self.num_layers > 0,
+            "A bidirectional Gru should have at least one layer."
+        );
+
+        let gru = |layer| {
+            let d_input = match layer {
+                0 => self.d_input,
+                _ => 2 * self.d_hidden,
+            };
+
+            GruConfig::new(d_input, self.d_hidden, self.bias)
+                .with_initializer(self.initializer.clone())
+                .init(device)
+        };
+
+        BiGru {
+            forward_layers: (0..self.num_layers).map(gru).collect(),
+            reverse_layers: (0..self.num_layers).map(gru).collect(),
+            dropout: DropoutConfig::new(self.dropout).init(),
+            d_hidden: self.d_hidden,
+        }
+    }
+}
+
+impl<B: Backend> BiGru<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// Parameters:
+    ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
+    ///     state: An optional tensor representing the initial hidden state of each layer and
+    ///            direction, where the index `2 * layer` is the forward direction of the layer
+    ///            and `2 * layer + 1` its reverse direction, with shape
+    ///            [2 * num_layers, batch_size, hidden_size].
+    ///            If none is provided, it is initialized to zeros.
+    ///
+    /// Returns:
+    ///     The hidden states of the last layer for each sequence element, with shape
+    ///     [batch_size, sequence_length, 2 * hidden_size], and the final hidden state of each
+    ///     layer and direction, in the same layout as the initial state.
+    pub fn forward(
+        &self,
+        batched_input: Tensor<B, 3>,
+        state: Option<Tensor<B, 3>>,
+    ) -> (Tensor<B, 3>, Tensor<B, 3>) {
+        let [batch_size, seq_length, _] = batched_input.dims();
+
+        let packed = pack_padded_sequence(batched_input, vec![seq_length; batch_size]);
+        let (output, state) = self.forward_packed(packed, state);
+        let (output, _) = pad_packed_sequence(output, None);
+
+        (output, state)
+    }
+
+    /// Applies the forward pass on a batch of [packed](PackedSequence) variable-length sequences.
+    ///
+    /// The reverse direction starts from the last element of each sequence rather than from the
+    /// end of the padding, so its final state is the one at the first element.
+    ///
+    /// Parameters:
+    ///     packed: The packed sequences, with `input_size` features.
+    ///     state: An optional tensor representing the initial hidden state of each layer and
+    ///            direction, in the layout of [forward](BiGru::forward) and in the order of the
+    ///            original batch.
+    ///
+    /// Returns:
+    ///     The packed hidden states of the last layer, with `2 * hidden_size` features, and the
+    ///     final hidden state of each layer and direction, with shape
+    ///     [2 * num_layers, batch_size, hidden_size].
+    pub fn forward_packed(
+        &self,
+        packed: PackedSequence<B>,
+        state: Option<Tensor<B, 3>>,
+    ) -> (PackedSequence<B>, Tensor<B, 3>) {
+        let batch_size = packed.batch_size();
+        let initial_state = |index: usize| {
+            state.as_ref().map(|state| {
+                state
+                    .clone()
+                    .slice([index..index + 1, 0..batch_size, 0..self.d_hidden])
+                    .squeeze(0)
+            })
+        };
+
+        let mut output = packed;
+        let mut hidden_states = Vec::with_capacity(2 * self.forward_layers.len());
+        for (layer, (forward, reverse)) in self
+            .forward_layers
+            .iter()
+            .zip(&self.reverse_layers)
+            .enumerate()
+        {
+            if layer > 0 {
+                output = output.with_data(self.dropout.forward(output.data.clone()));
+            }
+
+            let (output_forward, hidden_forward) =
+                forward.forward_packed(output.clone(), initial_state(2 * layer));
+            let (output_reverse, hidden_reverse) =
+                reverse.forward_packed(output.reverse(), initial_state(2 * layer + 1));
+
+            output = output.with_data(Tensor::cat(
+                vec![output_forward.data, output_reverse.reverse().data],
+                1,
+            ));
+            hidden_states.extend([hidden_forward, hidden_reverse]);
+        }
+
+        (output, Tensor::stack(hidden_states, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[cfg(feature = "std")]
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn test_forward_shapes() {
+        let device = Default::default();
+        let gru = BiGruConfig::new(3, 4, true)
+            .with_num_layers(3)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 3], Distribution::Default, &device);
+
+        let (output, hidden_state) = gru.forward(input, None);
+
+        assert_eq!(output.dims(), [2, 5, 8]);
+        assert_eq!(hidden_state.dims(), [6, 2, 4]);
+    }
+
+    #[test]
+    fn test_reverse_direction_starts_at_the_last_valid_element() {
+        let device = Default::default();
+        let gru = BiGruConfig::new(3, 4, true).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([3, 4, 3], Distribution::Default, &device);
+        let lengths = vec![2, 4, 1];
+
+        let packed = pack_padded_sequence(input.clone(), lengths.clone());
+        let (output, hidden_state) = gru.forward_packed(packed, None);
+        let (output, _) = pad_packed_sequence(output, None);
+
+        for (i, &length) in lengths.iter().enumerate() {
+            // The first output of the reverse direction at the last valid element only depends on
+            // that element.
+            let last = input.clone().slice([i..i + 1, length - 1..length, 0..3]);
+            let expected = gru.reverse_layers[0].forward(last, None);
+            output
+                .clone()
+                .slice([i..i + 1, length - 1..length, 4..8])
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 5);
+
+            // Its final state is its output at the first element, and the final state of the
+            // forward direction its output at the last valid element.
+            hidden_state
+                .clone()
+                .slice([1..2, i..i + 1, 0..4])
+                .reshape([1, 4])
+                .into_data()
+                .assert_approx_eq(
+                    &output
+                        .clone()
+                        .slice([i..i + 1, 0..1, 4..8])
+                        .reshape([1, 4])
+                        .into_data(),
+                    5,
+                );
+            hidden_state
+                .clone()
+                .slice([0..1, i..i + 1, 0..4])
+                .reshape([1, 4])
+                .into_data()
+                .assert_approx_eq(
+                    &output
+                        .clone()
+                        .slice([i..i + 1, length - 1..length, 0..4])
+                        .reshape([1, 4])
+                        .into_data(),
+                    5,
+                );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_backward_pass() {
+        let device = Default::default();
+        let gru = BiGruConfig::new(3, 4, true)
+            .with_num_layers(2)
+            .init::<TestAutodiffBackend>(&device);
+        let input =
+            Tensor::<TestAutodiffBackend, 3>::random([2, 5, 3], Distribution::Default, &device)
+                .require_grad();
+
+        let (output, _) = gru.forward(input.clone(), None);
+        let grads = output.sum().backward();
+
+        assert!(input.grad(&grads).unwrap().abs().sum().into_scalar() > 0.0);
+    }
+}
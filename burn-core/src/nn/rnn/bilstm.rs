@@ -0,0 +1,298 @@
+use crate as burn;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::nn::{
+    pack_padded_sequence, pad_packed_sequence, Dropout, DropoutConfig, Initializer, Lstm,
+    LstmConfig, PackedSequence,
+};
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// The configuration for a [bidirectional lstm](BiLstm) module.
+#[derive(Config, Debug)]
+pub struct BiLstmConfig {
+    /// The size of the input features.
+    pub d_input: usize,
+    /// The size of the hidden state of each direction.
+    pub d_hidden: usize,
+    /// If a bias should be applied during the Lstm transformations.
+    pub bias: bool,
+    /// The number of stacked bidirectional layers.
+    #[config(default = 1)]
+    pub num_layers: usize,
+    /// The dropout applied to the outputs of each layer except the last one.
+    #[config(default = 0.0)]
+    pub dropout: f64,
+    /// Lstm initializer
+    #[config(default = "Initializer::XavierNormal{gain:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// The bidirectional Lstm module, which runs a [lstm](Lstm) over the sequences in both directions
+/// and concatenates their hidden states, so each output has `2 * d_hidden` features: the hidden
+/// state of the forward direction followed by the one of the reverse direction.
+///
+/// When several layers are stacked, each layer takes the outputs of the previous one as input.
+#[derive(Module, Debug)]
+pub struct BiLstm<B: Backend> {
+    forward_layers: Vec<Lstm<B>>,
+    reverse_layers: Vec<Lstm<B>>,
+    dropout: Dropout,
+    d_hidden: usize,
+}
+
+impl BiLstmConfig {
+    /// Initialize a new [bidirectional lstm](BiLstm) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> BiLstm<B> {
+        assert!(
+            self.num_layers > 0,
+            "A bidirectional Lstm should have at least one layer."
+        );
+
+        let lstm = |layer| {
+            let d_input = match layer {
+                0 => self.d_input,
+                _ => 2 * self.d_hidden,
+            };
+
+            LstmConfig::new(d_input, self.d_hidden, self.bias)
+                .with_initializer(self.initializer.clone())
+                .init(device)
+        };
+
+        BiLstm {
+            forward_layers: (0..self.num_layers).map(lstm).collect(),
+            reverse_layers: (0..self.num_layers).map(lstm).collect(),
+            dropout: DropoutConfig::new(self.dropout).init(),
+            d_hidden: self.d_hidden,
+        }
+    }
+}
+
+impl<B: Backend> BiLstm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// Parameters:
+    ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
+    ///     state: An optional tuple of tensors representing the initial cell state and hidden state
+    ///            of each layer and direction, where the index `2 * layer` is the forward direction
+    ///            of the layer and `2 * layer + 1` its reverse direction.
+    ///            Each state tensor has shape [2 * num_layers, batch_size, hidden_size].
+    ///            If no initial state is provided, these tensors are initialized to zeros.
+    ///
+    /// Returns:
+    ///     The hidden states of the last layer for each sequence element, with shape
+    ///     [batch_size, sequence_length, 2 * hidden_size], and a tuple of the final cell state and
+    ///     hidden state of each layer and direction, in the same layout as the initial state.
+    pub fn forward(
+        &self,
+        batched_input: Tensor<B, 3>,
+        state: Option<(Tensor<B, 3>, Tensor<B, 3>)>,
+    ) -> (Tensor<B, 3>, (Tensor<B, 3>, Tensor<B, 3>)) {
+        let [batch_size, seq_length, _] = batched_input.dims();
+
+        let packed = pack_padded_sequence(batched_input, vec![seq_length; batch_size]);
+        let (output, state) = self.forward_packed(packed, state);
+        let (output, _) = pad_packed_sequence(output, None);
+
+        (output, state)
+    }
+
+    /// Applies the forward pass on a batch of [packed](PackedSequence) variable-length sequences.
+    ///
+    /// The reverse direction starts from the last element of each sequence rather than from the
+    /// end of the padding, so its final states are the ones at the first element.
+    ///
+    /// Parameters:
+    ///     packed: The packed sequences, with `input_size` features.
+    ///     state: An optional tuple of tensors representing the initial cell state and hidden state
+    ///            of each layer and direction, in the layout of [forward](BiLstm::forward) and in
+    ///            the order of the original batch.
+    ///
+    /// Returns:
+    ///     The packed hidden states of the last layer, with `2 * hidden_size` features, and a tuple
+    ///     of the final cell state and hidden state of each layer and direction, with shape
+    ///     [2 * num_layers, batch_size, hidden_size].
+    pub fn forward_packed(
+        &self,
+        packed: PackedSequence<B>,
+        state: Option<(Tensor<B, 3>, Tensor<B, 3>)>,
+    ) -> (PackedSequence<B>, (Tensor<B, 3>, Tensor<B, 3>)) {
+        let batch_size = packed.batch_size();
+        let num_states = 2 * self.forward_layers.len();
+        let initial_state = |index: usize| {
+            state.as_ref().map(|(cell_state, hidden_state)| {
+                let rows = [index..index + 1, 0..batch_size, 0..self.d_hidden];
+                (
+                    cell_state.clone().slice(rows.clone()).squeeze(0),
+                    hidden_state.clone().slice(rows).squeeze(0),
+                )
+            })
+        };
+
+        let mut output = packed;
+        let mut cell_states = Vec::with_capacity(num_states);
+        let mut hidden_states = Vec::with_capacity(num_states);
+        for (layer, (forward, reverse)) in self
+            .forward_layers
+            .iter()
+            .zip(&self.reverse_layers)
+            .enumerate()
+        {
+            if layer > 0 {
+                output = output.with_data(self.dropout.forward(output.data.clone()));
+            }
+
+            let (output_forward, (cell_forward, hidden_forward)) =
+                forward.forward_packed(output.clone(), initial_state(2 * layer));
+            let (output_reverse, (cell_reverse, hidden_reverse)) =
+                reverse.forward_packed(output.reverse(), initial_state(2 * layer + 1));
+
+            output = output.with_data(Tensor::cat(
+                vec![output_forward.data, output_reverse.reverse().data],
+                1,
+            ));
+            cell_states.extend([cell_forward, cell_reverse]);
+            hidden_states.extend([hidden_forward, hidden_reverse]);
+        }
+
+        let state = (
+            Tensor::stack(cell_states, 0),
+            Tensor::stack(hidden_states, 0),
+        );
+
+        (output, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[cfg(feature = "std")]
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn test_forward_shapes() {
+        let device = Default::default();
+        let lstm = BiLstmConfig::new(3, 4, true)
+            .with_num_layers(2)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 3], Distribution::Default, &device);
+
+        let (output, (cell_state, hidden_state)) = lstm.forward(input, None);
+
+        assert_eq!(output.dims(), [2, 5, 8]);
+        assert_eq!(cell_state.dims(), [4, 2, 4]);
+        assert_eq!(hidden_state.dims(), [4, 2, 4]);
+    }
+
+    #[test]
+    fn test_forward_concatenates_both_directions() {
+        let device = Default::default();
+        let lstm = BiLstmConfig::new(3, 4, true).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 3], Distribution::Default, &device);
+        let reversed_input = input
+            .clone()
+            .select(1, Tensor::arange(0..5, &device).neg() + 4);
+
+        let (output, (_, hidden_state)) = lstm.forward(input.clone(), None);
+
+        let (_, forward) = lstm.forward_layers[0].forward(input, None);
+        let (_, reverse) = lstm.reverse_layers[0].forward(reversed_input, None);
+        let reverse = reverse.select(1, Tensor::arange(0..5, &device).neg() + 4);
+        output
+            .clone()
+            .slice([0..2, 0..5, 0..4])
+            .into_data()
+            .assert_approx_eq(&forward.clone().into_data(), 5);
+        output
+            .slice([0..2, 0..5, 4..8])
+            .into_data()
+            .assert_approx_eq(&reverse.clone().into_data(), 5);
+        // The final state of the reverse direction is its output at the first element.
+        hidden_state
+            .slice([1..2, 0..2, 0..4])
+            .reshape([2, 4])
+            .into_data()
+            .assert_approx_eq(
+                &reverse
+                    .slice([0..2, 0..1, 0..4])
+                    .reshape([2, 4])
+                    .into_data(),
+                5,
+            );
+    }
+
+    #[test]
+    fn test_reverse_direction_starts_at_the_last_valid_element() {
+        let device = Default::default();
+        let lstm = BiLstmConfig::new(3, 4, true).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 3], Distribution::Default, &device);
+        let lengths = vec![3, 5];
+
+        let packed = pack_padded_sequence(input.clone(), lengths.clone());
+        let (output, _) = lstm.forward_packed(packed, None);
+        let (output, _) = pad_packed_sequence(output, None);
+
+        for (i, &length) in lengths.iter().enumerate() {
+            // The first output of the reverse direction at the last valid element only depends on
+            // that element.
+            let last = input.clone().slice([i..i + 1, length - 1..length, 0..3]);
+            let (_, expected) = lstm.reverse_layers[0].forward(last, None);
+
+            output
+                .clone()
+                .slice([i..i + 1, length - 1..length, 4..8])
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 5);
+        }
+    }
+
+    #[test]
+    fn test_forward_with_initial_state() {
+        let device = Default::default();
+        let lstm = BiLstmConfig::new(3, 4, false).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 3], Distribution::Default, &device);
+        let state = (
+            Tensor::<TestBackend, 3>::random([2, 2, 4], Distribution::Default, &device),
+            Tensor::<TestBackend, 3>::random([2, 2, 4], Distribution::Default, &device),
+        );
+
+        let (output, _) = lstm.forward(input.clone(), Some(state.clone()));
+
+        let forward_state = (
+            state.0.clone().slice([0..1, 0..2, 0..4]).squeeze(0),
+            state.1.clone().slice([0..1, 0..2, 0..4]).squeeze(0),
+        );
+        let (_, forward) = lstm.forward_layers[0].forward(input, Some(forward_state));
+        output
+            .slice([0..2, 0..5, 0..4])
+            .into_data()
+            .assert_approx_eq(&forward.into_data(), 5);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_backward_pass() {
+        let device = Default::default();
+        let lstm = BiLstmConfig::new(3, 4, true)
+            .with_num_layers(2)
+            .with_dropout(0.1)
+            .init::<TestAutodiffBackend>(&device);
+        let input =
+            Tensor::<TestAutodiffBackend, 3>::random([2, 5, 3], Distribution::Default, &device)
+                .require_grad();
+
+        let (output, _) = lstm.forward(input.clone(), None);
+        let grads = output.sum().backward();
+
+        assert!(input.grad(&grads).unwrap().abs().sum().into_scalar() > 0.0);
+    }
+}
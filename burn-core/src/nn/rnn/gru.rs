@@ -1,10 +1,13 @@
 use crate as burn;
 
+use alloc::vec::Vec;
+
 use crate::config::Config;
 use crate::module::Module;
 use crate::nn::rnn::gate_controller;
 use crate::nn::Initializer;
 use crate::nn::LinearConfig;
+use crate::nn::PackedSequence;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 use burn_tensor::activation;
@@ -12,7 +15,7 @@ use burn_tensor::activation;
 use super::gate_controller::GateController;
 
 /// The configuration for a [gru](Gru) module.
-#[derive(Config)]
+#[derive(Config, Debug)]
 pub struct GruConfig {
     /// The size of the input features.
     pub d_input: usize,
@@ -99,63 +102,112 @@ impl<B: Backend> Gru<B> {
     ///
     /// Parameters:
     ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
-    ///     state: An optional tensor representing an initial cell state with the same dimensions
-    ///            as batched_input. If none is provided, one will be generated.
+    ///     state: An optional tensor representing the initial hidden state, with shape
+    ///            [batch_size, hidden_size]. If none is provided, it is initialized to zeros.
     ///
     /// Returns:
     ///     The resulting state tensor, with shape [batch_size, sequence_length, hidden_size].
     pub fn forward(
         &self,
         batched_input: Tensor<B, 3>,
-        state: Option<Tensor<B, 3>>,
+        state: Option<Tensor<B, 2>>,
     ) -> Tensor<B, 3> {
         let [batch_size, seq_length, _] = batched_input.shape().dims;
+        let device = &batched_input.device();
+        let mut batched_hidden_state =
+            Tensor::zeros([batch_size, seq_length, self.d_hidden], device);
 
-        let mut hidden_state = match state {
+        let mut hidden_t = match state {
             Some(state) => state,
-            None => Tensor::zeros(
-                [batch_size, seq_length, self.d_hidden],
-                &batched_input.device(),
-            ),
+            None => Tensor::zeros([batch_size, self.d_hidden], device),
         };
 
-        for (t, (input_t, hidden_t)) in batched_input
-            .iter_dim(1)
-            .zip(hidden_state.clone().iter_dim(1))
-            .enumerate()
-        {
+        for (t, input_t) in batched_input.iter_dim(1).enumerate() {
             let input_t = input_t.squeeze(1);
-            let hidden_t = hidden_t.squeeze(1);
-            // u(pdate)g(ate) tensors
-            let biased_ug_input_sum = self.gate_product(&input_t, &hidden_t, &self.update_gate);
-            let update_values = activation::sigmoid(biased_ug_input_sum); // Colloquially referred to as z(t)
-
-            // r(eset)g(ate) tensors
-            let biased_rg_input_sum = self.gate_product(&input_t, &hidden_t, &self.reset_gate);
-            let reset_values = activation::sigmoid(biased_rg_input_sum); // Colloquially referred to as r(t)
-            let reset_t = hidden_t.clone().mul(reset_values); // Passed as input to new_gate
-
-            // n(ew)g(ate) tensor
-            let biased_ng_input_sum = self.gate_product(&input_t, &reset_t, &self.new_gate);
-            let candidate_state = biased_ng_input_sum.tanh(); // Colloquially referred to as g(t)
-
-            // calculate linear interpolation between previous hidden state and candidate state:
-            // g(t) * (1 - z(t)) + z(t) * hidden_t
-            let state_vector = candidate_state
-                .clone()
-                .mul(update_values.clone().sub_scalar(1).mul_scalar(-1)) // (1 - z(t)) = -(z(t) - 1)
-                + update_values.clone().mul(hidden_t);
+            hidden_t = self.step(input_t, hidden_t);
+
+            let unsqueezed_hidden_state = hidden_t.clone().unsqueeze_dim(1);
 
-            let current_shape = state_vector.shape().dims;
-            let unsqueezed_shape = [current_shape[0], 1, current_shape[1]];
-            let reshaped_state_vector = state_vector.reshape(unsqueezed_shape);
-            hidden_state = hidden_state.slice_assign(
+            // store the state for this timestep
+            batched_hidden_state = batched_hidden_state.slice_assign(
                 [0..batch_size, t..(t + 1), 0..self.d_hidden],
-                reshaped_state_vector,
+                unsqueezed_hidden_state,
             );
         }
 
-        hidden_state
+        batched_hidden_state
+    }
+
+    /// Applies the forward pass on a batch of [packed](PackedSequence) variable-length sequences,
+    /// computing only the time steps of the sequences that haven't ended.
+    ///
+    /// Parameters:
+    ///     packed: The packed sequences, with `input_size` features.
+    ///     state: An optional tensor representing the initial hidden state of each sequence, in
+    ///            the order of the original batch, with shape [batch_size, hidden_size].
+    ///            If none is provided, it is initialized to zeros.
+    ///
+    /// Returns:
+    ///     The packed hidden states for each time step, and the hidden state at the last time step
+    ///     of each sequence, in the order of the original batch, with shape
+    ///     [batch_size, hidden_size].
+    pub fn forward_packed(
+        &self,
+        packed: PackedSequence<B>,
+        state: Option<Tensor<B, 2>>,
+    ) -> (PackedSequence<B>, Tensor<B, 2>) {
+        let batch_size = packed.batch_size();
+        let [_, d_input] = packed.data.dims();
+        let device = &packed.data.device();
+
+        let mut hidden_state = match state {
+            Some(state) => packed.sort(state),
+            None => Tensor::zeros([batch_size, self.d_hidden], device),
+        };
+
+        let mut offset = 0;
+        let mut outputs = Vec::with_capacity(packed.batch_sizes.len());
+        for &size in packed.batch_sizes.iter() {
+            // The sequences are sorted by length, so the ones that haven't ended come first and
+            // the others keep their last state.
+            let input_t = packed
+                .data
+                .clone()
+                .slice([offset..offset + size, 0..d_input]);
+            let rows = [0..size, 0..self.d_hidden];
+            let hidden_t = self.step(input_t, hidden_state.clone().slice(rows.clone()));
+
+            hidden_state = hidden_state.slice_assign(rows, hidden_t.clone());
+            outputs.push(hidden_t);
+            offset += size;
+        }
+
+        let output = packed.with_data(Tensor::cat(outputs, 0));
+
+        (output, packed.unsort(hidden_state))
+    }
+
+    /// Computes the hidden state of the next time step.
+    fn step(&self, input_t: Tensor<B, 2>, hidden_t: Tensor<B, 2>) -> Tensor<B, 2> {
+        // u(pdate)g(ate) tensors
+        let biased_ug_input_sum = self.gate_product(&input_t, &hidden_t, &self.update_gate);
+        let update_values = activation::sigmoid(biased_ug_input_sum); // Colloquially referred to as z(t)
+
+        // r(eset)g(ate) tensors
+        let biased_rg_input_sum = self.gate_product(&input_t, &hidden_t, &self.reset_gate);
+        let reset_values = activation::sigmoid(biased_rg_input_sum); // Colloquially referred to as r(t)
+        let reset_t = hidden_t.clone().mul(reset_values); // Passed as input to new_gate
+
+        // n(ew)g(ate) tensor
+        let biased_ng_input_sum = self.gate_product(&input_t, &reset_t, &self.new_gate);
+        let candidate_state = biased_ng_input_sum.tanh(); // Colloquially referred to as g(t)
+
+        // calculate linear interpolation between previous hidden state and candidate state:
+        // g(t) * (1 - z(t)) + z(t) * hidden_t
+        candidate_state
+            .clone()
+            .mul(update_values.clone().sub_scalar(1).mul_scalar(-1)) // (1 - z(t)) = -(z(t) - 1)
+            + update_values.clone().mul(hidden_t)
     }
 
     /// Helper function for performing weighted matrix product for a gate and adds
@@ -282,6 +334,23 @@ mod tests {
         output.to_data().assert_approx_eq(&Data::from([[0.034]]), 3);
     }
 
+    #[test]
+    fn test_forward_is_recurrent_over_the_sequence() {
+        let device = Default::default();
+        let gru = GruConfig::new(4, 6, true).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 4], Distribution::Default, &device);
+
+        let hidden_state = gru.forward(input.clone(), None);
+
+        // Resuming from the state after the first step gives the same following states.
+        let initial_state = hidden_state.clone().slice([0..2, 0..1, 0..6]).squeeze(1);
+        let resumed = gru.forward(input.slice([0..2, 1..3, 0..4]), Some(initial_state));
+
+        resumed
+            .to_data()
+            .assert_approx_eq(&hidden_state.slice([0..2, 1..3, 0..6]).to_data(), 3);
+    }
+
     #[test]
     fn test_batched_forward_pass() {
         let device = Default::default();
@@ -293,4 +362,39 @@ mod tests {
 
         assert_eq!(hidden_state.shape().dims, [8, 10, 1024]);
     }
+
+    #[test]
+    fn test_forward_packed_matches_each_sequence() {
+        let device = Default::default();
+        let gru = GruConfig::new(3, 4, true).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([3, 5, 3], Distribution::Default, &device);
+        let lengths = vec![3, 1, 5];
+        let state = Tensor::<TestBackend, 2>::random([3, 4], Distribution::Default, &device);
+
+        let packed = crate::nn::pack_padded_sequence(input.clone(), lengths.clone());
+        let (output, hidden_state) = gru.forward_packed(packed, Some(state.clone()));
+        let (output, _) = crate::nn::pad_packed_sequence(output, None);
+
+        for (i, &length) in lengths.iter().enumerate() {
+            let sequence = input.clone().slice([i..i + 1, 0..length, 0..3]);
+            let hiddens = gru.forward(sequence, Some(state.clone().slice([i..i + 1, 0..4])));
+
+            output
+                .clone()
+                .slice([i..i + 1, 0..length, 0..4])
+                .into_data()
+                .assert_approx_eq(&hiddens.clone().into_data(), 5);
+            hidden_state
+                .clone()
+                .slice([i..i + 1, 0..4])
+                .into_data()
+                .assert_approx_eq(
+                    &hiddens
+                        .slice([0..1, length - 1..length, 0..4])
+                        .reshape([1, 4])
+                        .into_data(),
+                    5,
+                );
+        }
+    }
 }
@@ -1,10 +1,13 @@
 use crate as burn;
 
+use alloc::vec::Vec;
+
 use crate::config::Config;
 use crate::module::Module;
 use crate::nn::rnn::gate_controller;
 use crate::nn::Initializer;
 use crate::nn::LinearConfig;
+use crate::nn::PackedSequence;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 use burn_tensor::activation;
@@ -12,7 +15,7 @@ use burn_tensor::activation;
 use super::gate_controller::GateController;
 
 /// The configuration for a [lstm](Lstm) module.
-#[derive(Config)]
+#[derive(Config, Debug)]
 pub struct LstmConfig {
     /// The size of the input features.
     pub d_input: usize,
@@ -142,24 +145,7 @@ impl<B: Backend> Lstm<B> {
 
         for (t, input_t) in batched_input.iter_dim(1).enumerate() {
             let input_t = input_t.squeeze(1);
-            // f(orget)g(ate) tensors
-            let biased_fg_input_sum = self.gate_product(&input_t, &hidden_state, &self.forget_gate);
-            let forget_values = activation::sigmoid(biased_fg_input_sum); // to multiply with cell state
-
-            // i(nput)g(ate) tensors
-            let biased_ig_input_sum = self.gate_product(&input_t, &hidden_state, &self.input_gate);
-            let add_values = activation::sigmoid(biased_ig_input_sum);
-
-            // o(output)g(ate) tensors
-            let biased_og_input_sum = self.gate_product(&input_t, &hidden_state, &self.output_gate);
-            let output_values = activation::sigmoid(biased_og_input_sum);
-
-            // c(ell)g(ate) tensors
-            let biased_cg_input_sum = self.gate_product(&input_t, &hidden_state, &self.cell_gate);
-            let candidate_cell_values = biased_cg_input_sum.tanh();
-
-            cell_state = forget_values * cell_state.clone() + add_values * candidate_cell_values;
-            hidden_state = output_values * cell_state.clone().tanh();
+            (cell_state, hidden_state) = self.step(input_t, cell_state, hidden_state);
 
             let unsqueezed_shape = [cell_state.shape().dims[0], 1, cell_state.shape().dims[1]];
 
@@ -180,6 +166,96 @@ impl<B: Backend> Lstm<B> {
         (batched_cell_state, batched_hidden_state)
     }
 
+    /// Applies the forward pass on a batch of [packed](PackedSequence) variable-length sequences,
+    /// computing only the time steps of the sequences that haven't ended.
+    ///
+    /// Parameters:
+    ///     packed: The packed sequences, with `input_size` features.
+    ///     state: An optional tuple of tensors representing the initial cell state and hidden state
+    ///            of each sequence, in the order of the original batch.
+    ///            Each state tensor has shape [batch_size, hidden_size].
+    ///            If no initial state is provided, these tensors are initialized to zeros.
+    ///
+    /// Returns:
+    ///     The packed hidden states for each time step, and a tuple of the cell state and hidden
+    ///     state at the last time step of each sequence, in the order of the original batch.
+    ///     Both final state tensors have the shape [batch_size, hidden_size].
+    pub fn forward_packed(
+        &self,
+        packed: PackedSequence<B>,
+        state: Option<(Tensor<B, 2>, Tensor<B, 2>)>,
+    ) -> (PackedSequence<B>, (Tensor<B, 2>, Tensor<B, 2>)) {
+        let batch_size = packed.batch_size();
+        let [_, d_input] = packed.data.dims();
+        let device = &packed.data.device();
+
+        let (mut cell_state, mut hidden_state) = match state {
+            Some((cell_state, hidden_state)) => {
+                (packed.sort(cell_state), packed.sort(hidden_state))
+            }
+            None => (
+                Tensor::zeros([batch_size, self.d_hidden], device),
+                Tensor::zeros([batch_size, self.d_hidden], device),
+            ),
+        };
+
+        let mut offset = 0;
+        let mut outputs = Vec::with_capacity(packed.batch_sizes.len());
+        for &size in packed.batch_sizes.iter() {
+            // The sequences are sorted by length, so the ones that haven't ended come first and
+            // the others keep their last state.
+            let input_t = packed
+                .data
+                .clone()
+                .slice([offset..offset + size, 0..d_input]);
+            let rows = [0..size, 0..self.d_hidden];
+            let (cell_t, hidden_t) = self.step(
+                input_t,
+                cell_state.clone().slice(rows.clone()),
+                hidden_state.clone().slice(rows.clone()),
+            );
+
+            cell_state = cell_state.slice_assign(rows.clone(), cell_t);
+            hidden_state = hidden_state.slice_assign(rows, hidden_t.clone());
+            outputs.push(hidden_t);
+            offset += size;
+        }
+
+        let output = packed.with_data(Tensor::cat(outputs, 0));
+        let state = (packed.unsort(cell_state), packed.unsort(hidden_state));
+
+        (output, state)
+    }
+
+    /// Computes the cell state and hidden state of the next time step.
+    fn step(
+        &self,
+        input_t: Tensor<B, 2>,
+        cell_state: Tensor<B, 2>,
+        hidden_state: Tensor<B, 2>,
+    ) -> (Tensor<B, 2>, Tensor<B, 2>) {
+        // f(orget)g(ate) tensors
+        let biased_fg_input_sum = self.gate_product(&input_t, &hidden_state, &self.forget_gate);
+        let forget_values = activation::sigmoid(biased_fg_input_sum); // to multiply with cell state
+
+        // i(nput)g(ate) tensors
+        let biased_ig_input_sum = self.gate_product(&input_t, &hidden_state, &self.input_gate);
+        let add_values = activation::sigmoid(biased_ig_input_sum);
+
+        // o(output)g(ate) tensors
+        let biased_og_input_sum = self.gate_product(&input_t, &hidden_state, &self.output_gate);
+        let output_values = activation::sigmoid(biased_og_input_sum);
+
+        // c(ell)g(ate) tensors
+        let biased_cg_input_sum = self.gate_product(&input_t, &hidden_state, &self.cell_gate);
+        let candidate_cell_values = biased_cg_input_sum.tanh();
+
+        let cell_state = forget_values * cell_state + add_values * candidate_cell_values;
+        let hidden_state = output_values * cell_state.clone().tanh();
+
+        (cell_state, hidden_state)
+    }
+
     /// Helper function for performing weighted matrix product for a gate and adds
     /// bias, if any.
     ///
@@ -380,4 +456,74 @@ mod tests {
         // Asserts the gradients exist and are non zero
         assert!(*some_gradient.abs().sum().into_data().value.first().unwrap() > 0.);
     }
+
+    #[test]
+    fn test_forward_packed_matches_each_sequence() {
+        let device = Default::default();
+        let lstm = LstmConfig::new(3, 4, true).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::random([3, 5, 3], Distribution::Default, &device);
+        let lengths = vec![2, 5, 3];
+        let state = (
+            Tensor::<TestBackend, 2>::random([3, 4], Distribution::Default, &device),
+            Tensor::<TestBackend, 2>::random([3, 4], Distribution::Default, &device),
+        );
+
+        let packed = crate::nn::pack_padded_sequence(input.clone(), lengths.clone());
+        let (output, (cell_state, hidden_state)) = lstm.forward_packed(packed, Some(state.clone()));
+        let (output, _) = crate::nn::pad_packed_sequence(output, None);
+
+        for (i, &length) in lengths.iter().enumerate() {
+            let sequence = input.clone().slice([i..i + 1, 0..length, 0..3]);
+            let state_i = (
+                state.0.clone().slice([i..i + 1, 0..4]),
+                state.1.clone().slice([i..i + 1, 0..4]),
+            );
+            let (cells, hiddens) = lstm.forward(sequence, Some(state_i));
+
+            output
+                .clone()
+                .slice([i..i + 1, 0..length, 0..4])
+                .into_data()
+                .assert_approx_eq(&hiddens.clone().into_data(), 5);
+            let last = [0..1, length - 1..length, 0..4];
+            cell_state
+                .clone()
+                .slice([i..i + 1, 0..4])
+                .into_data()
+                .assert_approx_eq(&cells.slice(last.clone()).reshape([1, 4]).into_data(), 5);
+            hidden_state
+                .clone()
+                .slice([i..i + 1, 0..4])
+                .into_data()
+                .assert_approx_eq(&hiddens.slice(last).reshape([1, 4]).into_data(), 5);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_packed_backward_pass() {
+        let device = Default::default();
+        let lstm = LstmConfig::new(3, 4, true).init::<TestAutodiffBackend>(&device);
+        let input =
+            Tensor::<TestAutodiffBackend, 3>::random([2, 4, 3], Distribution::Default, &device)
+                .require_grad();
+
+        let packed = crate::nn::pack_padded_sequence(input.clone(), vec![4, 1]);
+        let (output, _) = lstm.forward_packed(packed, None);
+        let (output, _) = crate::nn::pad_packed_sequence(output, None);
+        let grads = output.sum().backward();
+
+        // The padding of the shorter sequence doesn't receive any gradient.
+        let grad = input.grad(&grads).unwrap();
+        let padding = grad
+            .clone()
+            .slice([1..2, 1..4, 0..3])
+            .abs()
+            .sum()
+            .into_scalar();
+        let steps = grad.slice([0..2, 0..1, 0..3]).abs().sum().into_scalar();
+        assert_eq!(padding, 0.0);
+        assert!(steps > 0.0);
+        assert!(lstm.cell_gate.input_transform.weight.grad(&grads).is_some());
+    }
 }
@@ -1,10 +1,21 @@
 mod gate_controller;
 
+/// Bidirectional Gated Recurrent Unit module.
+pub mod bigru;
+
+/// Bidirectional Long Short-Term Memory module.
+pub mod bilstm;
+
 /// Gated Recurrent Unit module.
 pub mod gru;
 
 /// Long Short-Term Memory module.
 pub mod lstm;
 
+mod packed;
+
+pub use bigru::*;
+pub use bilstm::*;
 pub use gate_controller::*;
 pub use lstm::*;
+pub use packed::*;
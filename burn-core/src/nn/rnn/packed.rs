@@ -0,0 +1,361 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::tensor::backend::Backend;
+use crate::tensor::{Data, Int, Shape, Tensor};
+
+/// A batch of variable-length sequences packed time step after time step, so that the recurrent
+/// modules only compute the time steps of the sequences that haven't ended.
+///
+/// The sequences are sorted by decreasing length. The rows of `data` are the time steps of all
+/// the sequences: first the first time step of every sequence, then the second time step of the
+/// sequences of length at least two, and so on.
+///
+/// It is created with [pack_padded_sequence] and converted back into a padded batch with
+/// [pad_packed_sequence].
+#[derive(Clone, Debug)]
+pub struct PackedSequence<B: Backend> {
+    /// The packed time steps, of shape `[sum(batch_sizes), d_features]`.
+    pub data: Tensor<B, 2>,
+    /// The number of sequences at each time step, which is non-increasing.
+    pub batch_sizes: Vec<usize>,
+    /// The index in the original batch of each sorted sequence.
+    pub sorted_indices: Vec<usize>,
+}
+
+impl<B: Backend> PackedSequence<B> {
+    /// The number of sequences in the batch.
+    pub fn batch_size(&self) -> usize {
+        self.sorted_indices.len()
+    }
+
+    /// The lengths of the sequences, in the order of the original batch.
+    pub fn lengths(&self) -> Vec<usize> {
+        let mut lengths = vec![0; self.batch_size()];
+
+        for &batch_size in self.batch_sizes.iter() {
+            for &index in self.sorted_indices[..batch_size].iter() {
+                lengths[index] += 1;
+            }
+        }
+
+        lengths
+    }
+
+    /// Returns a packed sequence with the same layout and new data, such as the outputs of a
+    /// recurrent module for each packed time step.
+    pub fn with_data(&self, data: Tensor<B, 2>) -> Self {
+        Self {
+            data,
+            batch_sizes: self.batch_sizes.clone(),
+            sorted_indices: self.sorted_indices.clone(),
+        }
+    }
+
+    /// Returns the packed sequences with each sequence reversed in time, which is its own
+    /// inverse.
+    pub fn reverse(&self) -> Self {
+        let offsets = self
+            .batch_sizes
+            .iter()
+            .scan(0, |offset, &size| {
+                let start = *offset;
+                *offset += size;
+                Some(start)
+            })
+            .collect::<Vec<_>>();
+        let sorted_lengths = (0..self.batch_size())
+            .map(|i| self.batch_sizes.iter().filter(|&&size| size > i).count())
+            .collect::<Vec<_>>();
+
+        // The time step `t` of the sorted sequence `i` is the row `offsets[t] + i`.
+        let rows = self
+            .batch_sizes
+            .iter()
+            .enumerate()
+            .flat_map(|(t, &size)| {
+                let offsets = &offsets;
+                let sorted_lengths = &sorted_lengths;
+                (0..size).map(move |i| offsets[sorted_lengths[i] - 1 - t] + i)
+            })
+            .collect::<Vec<_>>();
+
+        let device = self.data.device();
+        self.with_data(self.data.clone().select(0, indices(&rows, &device)))
+    }
+
+    /// Reorders the rows of a tensor in the original batch order into the sorted order.
+    pub(crate) fn sort<const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let device = tensor.device();
+        tensor.select(0, indices(&self.sorted_indices, &device))
+    }
+
+    /// Reorders the rows of a tensor in the sorted order back into the original batch order.
+    pub(crate) fn unsort<const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let mut unsorted_indices = vec![0; self.batch_size()];
+        for (position, &index) in self.sorted_indices.iter().enumerate() {
+            unsorted_indices[index] = position;
+        }
+
+        let device = tensor.device();
+        tensor.select(0, indices(&unsorted_indices, &device))
+    }
+}
+
+/// Packs a padded batch of variable-length sequences into a [PackedSequence].
+///
+/// The sequences don't need to be sorted by length: they are sorted when packed, and
+/// [pad_packed_sequence] restores their original order.
+///
+/// # Arguments
+///
+/// * `input` - The padded batch, of shape `[batch_size, seq_length, d_features]`.
+/// * `lengths` - The length of each sequence, between 1 and `seq_length`.
+///
+/// # Panics
+///
+/// If there isn't a length for each sequence, or if a length is zero or greater than the
+/// sequence length of the batch.
+pub fn pack_padded_sequence<B: Backend>(
+    input: Tensor<B, 3>,
+    lengths: Vec<usize>,
+) -> PackedSequence<B> {
+    let [batch_size, seq_length, d_features] = input.dims();
+    assert!(batch_size > 0, "Can't pack an empty batch of sequences.");
+    assert_eq!(
+        lengths.len(),
+        batch_size,
+        "There should be a length for each of the {batch_size} sequences, got {} lengths.",
+        lengths.len()
+    );
+    assert!(
+        lengths
+            .iter()
+            .all(|&length| length > 0 && length <= seq_length),
+        "The lengths should be between 1 and the sequence length ({seq_length}), got {:?}.",
+        lengths
+    );
+
+    // The sort is stable, so sequences of the same length keep their order.
+    let mut sorted_indices = (0..batch_size).collect::<Vec<_>>();
+    sorted_indices.sort_by(|a, b| lengths[*b].cmp(&lengths[*a]));
+
+    let batch_sizes = (0..lengths[sorted_indices[0]])
+        .map(|t| lengths.iter().filter(|&&length| length > t).count())
+        .collect::<Vec<_>>();
+
+    let device = input.device();
+    let sorted = input.select(0, indices(&sorted_indices, &device));
+    let steps = batch_sizes
+        .iter()
+        .enumerate()
+        .map(|(t, &size)| {
+            sorted
+                .clone()
+                .slice([0..size, t..t + 1, 0..d_features])
+                .reshape([size, d_features])
+        })
+        .collect();
+
+    PackedSequence {
+        data: Tensor::cat(steps, 0),
+        batch_sizes,
+        sorted_indices,
+    }
+}
+
+/// Pads a [PackedSequence] back into a batch of sequences, in the original order.
+///
+/// # Arguments
+///
+/// * `packed` - The packed sequences.
+/// * `total_length` - The sequence length of the padded batch, which defaults to the length of
+///   the longest sequence.
+///
+/// # Returns
+///
+/// The padded batch, of shape `[batch_size, total_length, d_features]`, with zeros after the
+/// end of each sequence, and the length of each sequence.
+///
+/// # Panics
+///
+/// If the total length is lower than the length of the longest sequence.
+pub fn pad_packed_sequence<B: Backend>(
+    packed: PackedSequence<B>,
+    total_length: Option<usize>,
+) -> (Tensor<B, 3>, Vec<usize>) {
+    let max_length = packed.batch_sizes.len();
+    let total_length = total_length.unwrap_or(max_length);
+    assert!(
+        total_length >= max_length,
+        "The total length ({total_length}) should be at least the length of the longest \
+         sequence ({max_length})."
+    );
+
+    let batch_size = packed.batch_size();
+    let [_, d_features] = packed.data.dims();
+    let device = packed.data.device();
+
+    let mut offset = 0;
+    let mut steps = Vec::with_capacity(total_length);
+    for &size in packed.batch_sizes.iter() {
+        let step = packed
+            .data
+            .clone()
+            .slice([offset..offset + size, 0..d_features]);
+        let step = match size < batch_size {
+            true => Tensor::cat(
+                vec![
+                    step,
+                    Tensor::zeros([batch_size - size, d_features], &device),
+                ],
+                0,
+            ),
+            false => step,
+        };
+
+        steps.push(step.unsqueeze_dim(1));
+        offset += size;
+    }
+    if total_length > max_length {
+        steps.push(Tensor::zeros(
+            [batch_size, total_length - max_length, d_features],
+            &device,
+        ));
+    }
+
+    let padded = packed.unsort(Tensor::cat(steps, 1));
+
+    (padded, packed.lengths())
+}
+
+fn indices<B: Backend>(indices: &[usize], device: &B::Device) -> Tensor<B, 1, Int> {
+    let values = indices.iter().map(|&index| index as i64).collect();
+
+    Tensor::from_data(
+        Data::new(values, Shape::new([indices.len()])).convert(),
+        device,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    fn input() -> Tensor<TestBackend, 3> {
+        let values = (0..24).map(|value| value as f32).collect::<Vec<_>>();
+
+        Tensor::from_data(
+            Data::new(values, Shape::new([3, 4, 2])),
+            &Default::default(),
+        )
+    }
+
+    #[test]
+    fn should_pack_time_steps_by_decreasing_length() {
+        let packed = pack_padded_sequence(input(), vec![2, 4, 1]);
+
+        assert_eq!(packed.sorted_indices, vec![1, 0, 2]);
+        assert_eq!(packed.batch_sizes, vec![3, 2, 1, 1]);
+        assert_eq!(packed.lengths(), vec![2, 4, 1]);
+        packed.data.into_data().assert_approx_eq(
+            &Data::from([
+                [8.0, 9.0],
+                [0.0, 1.0],
+                [16.0, 17.0],
+                [10.0, 11.0],
+                [2.0, 3.0],
+                [12.0, 13.0],
+                [14.0, 15.0],
+            ]),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_pad_packed_sequences_in_the_original_order() {
+        let packed = pack_padded_sequence(input(), vec![2, 4, 1]);
+
+        let (padded, lengths) = pad_packed_sequence(packed, None);
+
+        assert_eq!(lengths, vec![2, 4, 1]);
+        padded.into_data().assert_approx_eq(
+            &Data::from([
+                [[0.0, 1.0], [2.0, 3.0], [0.0, 0.0], [0.0, 0.0]],
+                [[8.0, 9.0], [10.0, 11.0], [12.0, 13.0], [14.0, 15.0]],
+                [[16.0, 17.0], [0.0, 0.0], [0.0, 0.0], [0.0, 0.0]],
+            ]),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_pad_to_the_total_length() {
+        let packed = pack_padded_sequence(input(), vec![1, 2, 2]);
+
+        let (padded, _) = pad_packed_sequence(packed, Some(5));
+
+        assert_eq!(padded.dims(), [3, 5, 2]);
+        padded
+            .slice([0..3, 2..5, 0..2])
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<TestBackend, 3>::zeros([3, 3, 2], &Default::default()).into_data(),
+                5,
+            );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn should_diff_through_packing_and_padding() {
+        use crate::TestAutodiffBackend;
+
+        let input = Tensor::<TestAutodiffBackend, 3>::from_inner(input()).require_grad();
+        let packed = pack_padded_sequence(input.clone(), vec![2, 4, 1]);
+        let (padded, _) = pad_packed_sequence(packed, None);
+
+        let grads = padded.mul_scalar(2.0).sum().backward();
+
+        // Only the time steps of the sequences receive a gradient.
+        let grad = input.grad(&grads).unwrap();
+        grad.into_data().assert_approx_eq(
+            &Data::from([
+                [[2.0, 2.0], [2.0, 2.0], [0.0, 0.0], [0.0, 0.0]],
+                [[2.0, 2.0], [2.0, 2.0], [2.0, 2.0], [2.0, 2.0]],
+                [[2.0, 2.0], [0.0, 0.0], [0.0, 0.0], [0.0, 0.0]],
+            ]),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_reverse_each_sequence() {
+        let packed = pack_padded_sequence(input(), vec![2, 4, 1]);
+
+        let (padded, _) = pad_packed_sequence(packed.reverse(), None);
+
+        padded.into_data().assert_approx_eq(
+            &Data::from([
+                [[2.0, 3.0], [0.0, 1.0], [0.0, 0.0], [0.0, 0.0]],
+                [[14.0, 15.0], [12.0, 13.0], [10.0, 11.0], [8.0, 9.0]],
+                [[16.0, 17.0], [0.0, 0.0], [0.0, 0.0], [0.0, 0.0]],
+            ]),
+            5,
+        );
+    }
+
+    #[test]
+    #[should_panic = "The lengths should be between 1 and the sequence length"]
+    fn should_panic_with_longer_sequences() {
+        pack_padded_sequence(input(), vec![2, 5, 1]);
+    }
+
+    #[test]
+    #[should_panic = "should be at least the length of the longest sequence"]
+    fn should_panic_with_shorter_total_length() {
+        let packed = pack_padded_sequence(input(), vec![2, 3, 1]);
+
+        pad_packed_sequence(packed, Some(2));
+    }
+}
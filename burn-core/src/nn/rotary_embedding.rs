@@ -0,0 +1,180 @@
+use alloc::vec::Vec;
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use burn_tensor::module::rope_embed;
+use burn_tensor::{Data, Shape};
+
+/// Configuration to create a [RotaryEmbedding](RotaryEmbedding) layer.
+#[derive(Config)]
+pub struct RotaryEmbeddingConfig {
+    /// The size of each attention head.
+    head_dim: usize,
+
+    /// Maximum sequence length to use.
+    max_seq_len: usize,
+
+    /// The base of the frequencies of the rotations.
+    #[config(default = "10_000.0")]
+    theta: f64,
+}
+
+/// Rotary position embedding layer for attention models.
+///
+/// The queries and keys of each head are rotated by an angle proportional to their position,
+/// with a different frequency for each pair of elements, so that the attention scores only
+/// depend on the relative positions of the tokens. It was introduced in
+/// [RoFormer: Enhanced Transformer with Rotary Position Embedding](https://arxiv.org/abs/2104.09864).
+///
+/// The cosines and sines of the angles are computed once for all the positions, up to the
+/// maximum sequence length.
+#[derive(Module, Debug)]
+pub struct RotaryEmbedding<B: Backend> {
+    cos: Tensor<B, 2>,
+    sin: Tensor<B, 2>,
+}
+
+impl RotaryEmbeddingConfig {
+    /// Initialize a new [RotaryEmbedding](RotaryEmbedding) module.
+    ///
+    /// # Panics
+    ///
+    /// If the head size isn't even.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> RotaryEmbedding<B> {
+        assert!(
+            self.head_dim.is_multiple_of(2),
+            "head_dim({}) must be even",
+            self.head_dim
+        );
+
+        let half = self.head_dim / 2;
+        // The pair i rotates with the frequency theta^(-2i / head_dim).
+        let frequencies: Vec<f64> = (0..half)
+            .map(|i| libm::pow(self.theta, -2.0 * i as f64 / self.head_dim as f64))
+            .collect();
+
+        let mut cos = Vec::with_capacity(self.max_seq_len * half);
+        let mut sin = Vec::with_capacity(self.max_seq_len * half);
+
+        for position in 0..self.max_seq_len {
+            for frequency in frequencies.iter() {
+                let angle = position as f64 * frequency;
+                cos.push(libm::cos(angle) as f32);
+                sin.push(libm::sin(angle) as f32);
+            }
+        }
+
+        let shape = Shape::new([self.max_seq_len, half]);
+        let cos = Tensor::from_data(Data::new(cos, shape.clone()).convert(), device);
+        let sin = Tensor::from_data(Data::new(sin, shape).convert(), device);
+
+        RotaryEmbedding { cos, sin }
+    }
+}
+
+impl<B: Backend> RotaryEmbedding<B> {
+    /// Applies the forward pass on the queries and keys, the first token being at the position
+    /// `start`, which is not zero when the previous tokens are cached.
+    ///
+    /// # Shapes
+    ///
+    /// * query: `[batch_size, num_query_heads, seq_length, head_dim]`
+    /// * key: `[batch_size, num_kv_heads, seq_length, head_dim]`
+    /// * output: the queries and keys, with the same shapes
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the last position is greater than the maximum sequence length.
+    /// * Panics if the heads of the input don't have the size of the module.
+    pub fn forward(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        start: usize,
+    ) -> (Tensor<B, 4>, Tensor<B, 4>) {
+        let [_, _, seq_length, _] = query.dims();
+        let [max_seq_len, half] = self.cos.dims();
+
+        assert!(
+            start + seq_length <= max_seq_len,
+            "max_seq_len({}) must be greater or equal than the last position({})",
+            max_seq_len,
+            start + seq_length,
+        );
+
+        let positions = [start..start + seq_length, 0..half];
+        rope_embed(
+            query,
+            key,
+            self.cos.clone().slice(positions.clone()),
+            self.sin.clone().slice(positions),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn test_rotary_embedding_forward() {
+        let device = Default::default();
+        let rope = RotaryEmbeddingConfig::new(4, 8).init::<TestBackend>(&device);
+        let query = Tensor::<TestBackend, 4>::from_floats(
+            [[[
+                [1.0, 2.0, 3.0, 4.0],
+                [1.0, 2.0, 3.0, 4.0],
+                [1.0, 2.0, 3.0, 4.0],
+            ]]],
+            &device,
+        );
+
+        let (output, _) = rope.forward(query.clone(), query, 0);
+
+        // The frequencies are 1 and 0.01.
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [1.0, 2.0, 3.0, 4.0],
+                [-1.9841, 1.9599, 2.4624, 4.0198],
+                [-3.144, 1.9196, -0.3391, 4.0392],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_rotary_embedding_scores_depend_on_relative_positions() {
+        let device = Default::default();
+        let rope = RotaryEmbeddingConfig::new(8, 16).init::<TestBackend>(&device);
+        let query = Tensor::<TestBackend, 4>::random([1, 1, 1, 8], Distribution::Default, &device);
+        let key = Tensor::<TestBackend, 4>::random([1, 1, 1, 8], Distribution::Default, &device);
+
+        let score = |query_position: usize, key_position: usize| {
+            let (query, _) = rope.forward(query.clone(), query.clone(), query_position);
+            let (key, _) = rope.forward(key.clone(), key.clone(), key_position);
+
+            query.matmul(key.transpose()).into_scalar()
+        };
+
+        let expected = score(3, 1);
+        for (query_position, key_position) in [(5, 3), (10, 8), (2, 0)] {
+            let actual = score(query_position, key_position);
+            assert!((actual - expected).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotary_embedding_should_panic_after_max_seq_len() {
+        let device = Default::default();
+        let rope = RotaryEmbeddingConfig::new(4, 2).init::<TestBackend>(&device);
+        let query = Tensor::<TestBackend, 4>::zeros([1, 1, 2, 4], &device);
+
+        rope.forward(query.clone(), query, 1);
+    }
+}
@@ -5,3 +5,144 @@ mod pwff;
 pub use decoder::*;
 pub use encoder::*;
 pub use pwff::*;
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::module::Module;
+    use crate::nn::attention::generate_autoregressive_mask;
+    use crate::nn::loss::CrossEntropyLossConfig;
+    use crate::nn::{
+        Embedding, EmbeddingConfig, LearnablePositionalEncoding, LearnablePositionalEncodingConfig,
+        Linear, LinearConfig,
+    };
+    use crate::optim::{AdamConfig, GradientsParams, Optimizer};
+    use crate::tensor::backend::Backend;
+    use crate::tensor::{Data, Int, Tensor};
+    use crate::TestAutodiffBackend;
+
+    const VOCAB_SIZE: usize = 6;
+    const BOS_TOKEN: i64 = 0;
+    const SEQ_LENGTH: usize = 5;
+    const D_MODEL: usize = 16;
+
+    /// A tiny encoder-decoder transformer copying its input sequence.
+    #[derive(Module, Debug)]
+    struct CopyModel<B: Backend> {
+        embedding: Embedding<B>,
+        positional: LearnablePositionalEncoding<B>,
+        encoder: TransformerEncoder<B>,
+        decoder: TransformerDecoder<B>,
+        output: Linear<B>,
+    }
+
+    impl<B: Backend> CopyModel<B> {
+        fn new(norm_first: bool, device: &B::Device) -> Self {
+            Self {
+                embedding: EmbeddingConfig::new(VOCAB_SIZE, D_MODEL).init(device),
+                positional: LearnablePositionalEncodingConfig::new(D_MODEL)
+                    .with_max_len(SEQ_LENGTH)
+                    .with_dropout(0.0)
+                    .init(device),
+                encoder: TransformerEncoderConfig::new(D_MODEL, 2 * D_MODEL, 2, 1)
+                    .with_dropout(0.0)
+                    .with_norm_first(norm_first)
+                    .init(device),
+                decoder: TransformerDecoderConfig::new(D_MODEL, 2 * D_MODEL, 2, 1)
+                    .with_dropout(0.0)
+                    .with_norm_first(norm_first)
+                    .init(device),
+                output: LinearConfig::new(D_MODEL, VOCAB_SIZE).init(device),
+            }
+        }
+
+        /// Returns the logits of the next tokens, with teacher forcing.
+        fn forward(&self, source: Tensor<B, 2, Int>, target: Tensor<B, 2, Int>) -> Tensor<B, 3> {
+            let [batch_size, seq_length] = target.dims();
+            let device = source.device();
+
+            let source = self.positional.forward(self.embedding.forward(source));
+            let memory = self.encoder.forward(TransformerEncoderInput::new(source));
+
+            // The decoder sees the target shifted by one, starting with the BOS token.
+            let shifted = Tensor::cat(
+                vec![
+                    Tensor::full([batch_size, 1], BOS_TOKEN, &device),
+                    target.slice([0..batch_size, 0..seq_length - 1]),
+                ],
+                1,
+            );
+            let shifted = self.positional.forward(self.embedding.forward(shifted));
+            let mask = generate_autoregressive_mask(batch_size, seq_length, &device);
+            let output = self
+                .decoder
+                .forward(TransformerDecoderInput::new(shifted, memory).target_mask_attn(mask));
+
+            self.output.forward(output)
+        }
+    }
+
+    fn sequences<B: Backend>(device: &B::Device) -> Tensor<B, 2, Int> {
+        let batch_size = 16;
+        let tokens = (0..batch_size * SEQ_LENGTH)
+            .map(|i| ((i * 7 + i / 3) % (VOCAB_SIZE - 1) + 1) as i64)
+            .collect::<Vec<_>>();
+
+        Tensor::from_data(
+            Data::new(tokens, [batch_size, SEQ_LENGTH].into()).convert(),
+            device,
+        )
+    }
+
+    /// Trains the model to copy the sequences, returning the initial and final losses and the
+    /// final accuracy.
+    fn train_copy(norm_first: bool) -> (f32, f32, f32) {
+        let device = Default::default();
+        let mut model = CopyModel::<TestAutodiffBackend>::new(norm_first, &device);
+        let mut optim = AdamConfig::new().init();
+        let loss = CrossEntropyLossConfig::new().init(&device);
+        let sequences = sequences::<TestAutodiffBackend>(&device);
+        let [batch_size, _] = sequences.dims();
+        let num_tokens = batch_size * SEQ_LENGTH;
+        let targets = sequences.clone().reshape([num_tokens]);
+
+        let mut losses = Vec::new();
+        for _ in 0..60 {
+            let logits = model.forward(sequences.clone(), sequences.clone());
+            let loss = loss.forward(logits.reshape([num_tokens, VOCAB_SIZE]), targets.clone());
+            losses.push(loss.clone().into_scalar());
+
+            let grads = GradientsParams::from_grads(loss.backward(), &model);
+            model = optim.step(2e-2, model, grads);
+        }
+
+        let predictions = model
+            .forward(sequences.clone(), sequences)
+            .argmax(2)
+            .reshape([num_tokens]);
+        let accuracy = predictions.equal(targets).int().sum().into_scalar() as f32;
+
+        (
+            losses[0],
+            losses[losses.len() - 1],
+            accuracy / num_tokens as f32,
+        )
+    }
+
+    #[test]
+    fn transformer_should_learn_to_copy_norm_last() {
+        let (initial_loss, loss, accuracy) = train_copy(false);
+
+        assert!(loss < initial_loss / 10.0, "{initial_loss} -> {loss}");
+        assert!(accuracy > 0.95, "Accuracy {accuracy}");
+    }
+
+    #[test]
+    fn transformer_should_learn_to_copy_norm_first() {
+        let (initial_loss, loss, accuracy) = train_copy(true);
+
+        assert!(loss < initial_loss / 10.0, "{initial_loss} -> {loss}");
+        assert!(accuracy > 0.95, "Accuracy {accuracy}");
+    }
+}
@@ -1,10 +1,13 @@
 use crate::{
-    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    self as burn,
+    grad_clipping::GradientClippingConfig,
+    module::{AutodiffModule, ParamId},
+    record::Record,
     LearningRate,
 };
 use std::marker::PhantomData;
 
-use super::{Optimizer, SimpleOptimizer};
+use super::{decay::ParamDecayFilter, Optimizer, SimpleOptimizer};
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
 use crate::tensor::{backend::AutodiffBackend, Tensor};
@@ -30,12 +33,40 @@ pub struct AdamWConfig {
 }
 
 /// AdamW optimizer as described in the paper [Decoupled Weight Decay Regularization, Loshchilov and Hutter, 2019](https://arxiv.org/abs/1711.05101).
+///
+/// The weight decay is applied directly to the parameters instead of being added to the
+/// gradients, optionally only to the parameters selected by a [filter](ParamDecayFilter).
 pub struct AdamW<B: Backend> {
     momentum: AdaptiveMomentumW,
     weight_decay: f32,
+    decay_filter: Option<Box<dyn ParamDecayFilter>>,
     _phantom: PhantomData<B>,
 }
 
+impl<B: Backend> AdamW<B> {
+    fn update<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<AdamWState<B, D>>,
+        decay: bool,
+    ) -> (Tensor<B, D>, Option<AdamWState<B, D>>) {
+        let tensor_updated = match decay {
+            true => tensor.clone() - tensor.mul_scalar(lr).mul_scalar(self.weight_decay),
+            false => tensor,
+        };
+
+        let (raw_delta, momentum_state) = self.momentum.transform(grad, state.map(|s| s.momentum));
+
+        let state = AdamWState {
+            momentum: momentum_state,
+        };
+
+        (tensor_updated - raw_delta.mul_scalar(lr), Some(state))
+    }
+}
+
 /// AdamW state.
 #[derive(Record, Clone, new)]
 pub struct AdamWState<B: Backend, const D: usize> {
@@ -57,15 +88,23 @@ impl<B: Backend> SimpleOptimizer<B> for AdamW<B> {
         // State of the optimizer.
         state: Option<Self::State<D>>,
     ) -> (Tensor<B, D>, Option<Self::State<D>>) {
-        let tensor_updated = tensor.clone() - tensor.mul_scalar(lr).mul_scalar(self.weight_decay);
-
-        let (raw_delta, momentum_state) = self.momentum.transform(grad, state.map(|s| s.momentum));
+        self.update(lr, tensor, grad, state, true)
+    }
 
-        let state = AdamWState {
-            momentum: momentum_state,
+    fn step_param<const D: usize>(
+        &self,
+        id: &ParamId,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let decay = match &self.decay_filter {
+            Some(filter) => filter.should_decay(id, &tensor.dims()),
+            None => true,
         };
 
-        (tensor_updated - raw_delta.mul_scalar(lr), Some(state))
+        self.update(lr, tensor, grad, state, decay)
     }
 
     fn to_device<const D: usize>(
@@ -84,6 +123,28 @@ impl AdamWConfig {
     ///
     /// Returns an optimizer that can be used to optimize a module.
     pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> impl Optimizer<M, B> {
+        self.init_optimizer(None)
+    }
+
+    /// Initialize AdamW optimizer, applying the weight decay only to the parameters selected by
+    /// the given [filter](ParamDecayFilter).
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init_with_decay_filter<B, M, F>(&self, filter: F) -> impl Optimizer<M, B>
+    where
+        B: AutodiffBackend,
+        M: AutodiffModule<B>,
+        F: ParamDecayFilter + 'static,
+    {
+        self.init_optimizer(Some(Box::new(filter)))
+    }
+
+    fn init_optimizer<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        decay_filter: Option<Box<dyn ParamDecayFilter>>,
+    ) -> OptimizerAdaptor<AdamW<B::InnerBackend>, M, B> {
         let optim = AdamW {
             momentum: AdaptiveMomentumW {
                 beta_1: self.beta_1,
@@ -91,6 +152,7 @@ impl AdamWConfig {
                 epsilon: self.epsilon,
             },
             weight_decay: self.weight_decay,
+            decay_filter,
             _phantom: Default::default(),
         };
 
@@ -110,10 +172,10 @@ pub struct AdaptiveMomentumWState<B: Backend, const D: usize> {
     moment_2: Tensor<B, D>,
 }
 
-struct AdaptiveMomentumW {
-    beta_1: f32,
-    beta_2: f32,
-    epsilon: f32,
+pub(crate) struct AdaptiveMomentumW {
+    pub(crate) beta_1: f32,
+    pub(crate) beta_2: f32,
+    pub(crate) epsilon: f32,
 }
 
 impl AdaptiveMomentumW {
@@ -349,6 +411,92 @@ mod tests {
         assert!(!state_updated.weight.to_data().value[0].is_nan());
     }
 
+    #[test]
+    fn test_adamw_decay_filter_excludes_bias() {
+        let config = AdamWConfig::new().with_weight_decay(0.5);
+        let filter = |_id: &ParamId, shape: &[usize]| shape.len() > 1;
+
+        let decayed = train_two_steps(config.init());
+        let not_decayed = train_two_steps(config.clone().with_weight_decay(0.0).init());
+        let filtered = train_two_steps(config.init_with_decay_filter(filter));
+
+        filtered
+            .weight
+            .to_data()
+            .assert_approx_eq(&decayed.weight.to_data(), 5);
+        filtered
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&not_decayed.bias.unwrap().to_data(), 5);
+    }
+
+    #[test]
+    fn test_adamw_decay_filter_excludes_param_ids() {
+        let config = AdamWConfig::new().with_weight_decay(0.5);
+        let excluded = given_default_linear_layer().weight.id().clone();
+        let filter = move |id: &ParamId, _shape: &[usize]| id != &excluded;
+
+        let decayed = train_two_steps(config.init());
+        let not_decayed = train_two_steps(config.clone().with_weight_decay(0.0).init());
+        let filtered = train_two_steps(config.init_with_decay_filter(filter));
+
+        filtered
+            .weight
+            .to_data()
+            .assert_approx_eq(&not_decayed.weight.to_data(), 5);
+        filtered
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&decayed.bias.unwrap().to_data(), 5);
+    }
+
+    fn train_two_steps(
+        mut optimizer: impl Optimizer<nn::Linear<TestAutodiffBackend>, TestAutodiffBackend>,
+    ) -> nn::LinearRecord<TestAutodiffBackend> {
+        let mut linear = given_default_linear_layer();
+        let x = Tensor::from_floats(
+            [
+                [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+                [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+            ],
+            &Default::default(),
+        )
+        .require_grad();
+
+        for _ in 0..2 {
+            let grads = linear.forward(x.clone()).backward();
+            let grads = GradientsParams::from_grads(grads, &linear);
+            linear = optimizer.step(LEARNING_RATE, linear, grads);
+        }
+
+        linear.into_record()
+    }
+
+    /// The parameters keep the same ids, so that they can be referenced by the filters.
+    fn given_default_linear_layer() -> nn::Linear<TestAutodiffBackend> {
+        let device = Default::default();
+        let weight = Tensor::from_floats(
+            [
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ],
+            &device,
+        );
+        let bias = Tensor::from_floats([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130], &device);
+        let record = nn::LinearRecord {
+            weight: Param::new(ParamId::from("weight"), weight),
+            bias: Some(Param::new(ParamId::from("bias"), bias)),
+        };
+
+        nn::LinearConfig::new(6, 6).init_with(record)
+    }
+
     fn given_linear_layer(
         weight: Data<f32, 2>,
         bias: Data<f32, 1>,
@@ -373,6 +521,7 @@ mod tests {
                 epsilon: config.epsilon,
             },
             weight_decay: config.weight_decay,
+            decay_filter: None,
             _phantom: Default::default(),
         }
         .into()
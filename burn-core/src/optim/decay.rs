@@ -1,6 +1,7 @@
 use burn_tensor::backend::Backend;
 
 use crate as burn;
+use crate::module::ParamId;
 use crate::record::Record;
 
 use crate::config::Config;
@@ -13,6 +14,28 @@ pub struct WeightDecayConfig {
     pub penalty: f64,
 }
 
+/// Selects the parameters to which the weight decay is applied.
+///
+/// Parameters such as biases and normalization scales are commonly excluded from the weight
+/// decay. The filter receives the [id](ParamId) of each parameter, which can be obtained from a
+/// module with [Param::id](crate::module::Param::id), and the shape of its tensor.
+///
+/// The trait is implemented for closures, e.g. `|_id: &ParamId, shape: &[usize]| shape.len() > 1`
+/// only decays the matrices and kernels of a model.
+pub trait ParamDecayFilter: Send + Sync {
+    /// Whether the weight decay should be applied to the parameter.
+    fn should_decay(&self, id: &ParamId, shape: &[usize]) -> bool;
+}
+
+impl<F> ParamDecayFilter for F
+where
+    F: Fn(&ParamId, &[usize]) -> bool + Send + Sync,
+{
+    fn should_decay(&self, id: &ParamId, shape: &[usize]) -> bool {
+        self(id, shape)
+    }
+}
+
 /// State of [weight decay](WeightDecay).
 #[derive(Record, Clone, new)]
 pub struct WeightDecayState<B: Backend, const D: usize> {
@@ -0,0 +1,227 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{AutodiffModule, Module, ModuleMapper, ModuleVisitor, ParamId};
+use crate::tensor::backend::{AutodiffBackend, Backend};
+use crate::tensor::container::TensorContainer;
+use crate::tensor::Tensor;
+use core::marker::PhantomData;
+
+/// Configuration to create an [exponential moving average](EmaModel) of a model.
+#[derive(Config)]
+pub struct EmaConfig {
+    /// The decay of the moving average.
+    #[config(default = 0.999)]
+    pub decay: f64,
+    /// If the decay is warmed up, starting near zero so that the first updates follow the model
+    /// closely.
+    #[config(default = false)]
+    pub warmup: bool,
+}
+
+impl EmaConfig {
+    /// Initialize the [exponential moving average](EmaModel) of the given model, starting from its
+    /// current weights.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        model: &M,
+    ) -> EmaModel<B::InnerBackend, M::InnerModule> {
+        assert!(
+            (0.0..=1.0).contains(&self.decay),
+            "The decay must be between 0 and 1, got {}",
+            self.decay
+        );
+
+        EmaModel {
+            module: model.valid(),
+            decay: self.decay,
+            warmup: self.warmup,
+            num_updates: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Exponential moving average of the weights of a model, as used by diffusion models and
+/// self-supervised methods such as MoCo.
+///
+/// A shadow copy of the weights, without autodiff, is updated after each optimizer step with
+/// `shadow = decay * shadow + (1 - decay) * param`. The shadow [module](EmaModel::module) can be
+/// used for inference at any point without modifying the model being trained, and the moving
+/// average is saved independently of that model, being a module itself.
+///
+/// With warm-up, the decay of the `n`-th update is `min(decay, (1 + n) / (10 + n))`.
+#[derive(Module, Debug)]
+pub struct EmaModel<B: Backend, M> {
+    module: M,
+    decay: f64,
+    warmup: bool,
+    num_updates: usize,
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend, M: Module<B>> EmaModel<B, M> {
+    /// Update the moving average with the weights of the trained model.
+    pub fn update<BA, MA>(&mut self, model: &MA)
+    where
+        BA: AutodiffBackend<InnerBackend = B>,
+        MA: AutodiffModule<BA>,
+    {
+        let mut params = TensorContainer::new();
+        let mut collector = ParamsCollector::<BA>::new(&mut params);
+        model.visit(&mut collector);
+
+        let mut mapper = EmaMapper::new(&params, self.current_decay());
+        self.module = self.module.clone().map(&mut mapper);
+        self.num_updates += 1;
+    }
+
+    /// The decay used by the next update.
+    pub fn current_decay(&self) -> f64 {
+        match self.warmup {
+            true => {
+                let num_updates = self.num_updates as f64;
+                f64::min(self.decay, (1.0 + num_updates) / (10.0 + num_updates))
+            }
+            false => self.decay,
+        }
+    }
+
+    /// The moving average of the model.
+    pub fn module(&self) -> &M {
+        &self.module
+    }
+
+    /// Consume the wrapper, returning the moving average of the model.
+    pub fn into_module(self) -> M {
+        self.module
+    }
+}
+
+#[derive(new)]
+struct ParamsCollector<'a, B: AutodiffBackend> {
+    params: &'a mut TensorContainer<ParamId>,
+    #[new(default)]
+    phantom: PhantomData<B>,
+}
+
+#[derive(new)]
+struct EmaMapper<'a> {
+    params: &'a TensorContainer<ParamId>,
+    decay: f64,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for ParamsCollector<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        self.params.register(id.clone(), tensor.clone().inner());
+    }
+}
+
+impl<'a, B: Backend> ModuleMapper<B> for EmaMapper<'a> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.params.get::<B, D>(id) {
+            Some(param) => tensor.mul_scalar(self.decay) + param.mul_scalar(1.0 - self.decay),
+            None => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::TestAutodiffBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_ema_lags_behind_the_model() {
+        let layer = layer_with_weight(0.0);
+        let mut ema = EmaConfig::new().with_decay(0.5).init(&layer);
+
+        let expected = [0.5, 1.25, 2.125, 3.0625, 4.03125];
+        for (step, expected) in expected.iter().enumerate() {
+            let value = (step + 1) as f32;
+            let layer = with_weight(layer.clone(), value);
+
+            ema.update(&layer);
+
+            let weight = ema.module().weight.val().into_data();
+            weight.assert_approx_eq(&Data::from([[*expected; 3]; 2]), 5);
+            assert!(*expected < value);
+        }
+    }
+
+    #[test]
+    fn test_ema_warmup_follows_the_first_updates() {
+        let layer = layer_with_weight(0.0);
+        let mut ema = EmaConfig::new()
+            .with_decay(0.99)
+            .with_warmup(true)
+            .init(&layer);
+
+        assert_eq!(ema.current_decay(), 0.1);
+        ema.update(&with_weight(layer, 1.0));
+
+        ema.module()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.9; 3]; 2]), 5);
+        assert_eq!(ema.current_decay(), 2.0 / 11.0);
+    }
+
+    #[test]
+    fn test_ema_keeps_the_model_unchanged() {
+        let layer = layer_with_weight(0.0);
+        let mut ema = EmaConfig::new().with_decay(0.5).init(&layer);
+        let layer = with_weight(layer, 2.0);
+
+        ema.update(&layer);
+
+        layer
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0; 3]; 2]), 5);
+        ema.module()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0; 3]; 2]), 5);
+    }
+
+    #[test]
+    fn test_ema_record() {
+        let layer = layer_with_weight(0.0);
+        let mut ema = EmaConfig::new().with_decay(0.5).init(&layer);
+        ema.update(&with_weight(layer.clone(), 4.0));
+
+        let record = ema.into_record();
+        let ema = EmaConfig::new()
+            .with_decay(0.5)
+            .init(&layer)
+            .load_record(record);
+
+        ema.module()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0; 3]; 2]), 5);
+    }
+
+    fn layer_with_weight(value: f32) -> Linear<TestAutodiffBackend> {
+        let layer = LinearConfig::new(2, 3).init(&Default::default());
+
+        with_weight(layer, value)
+    }
+
+    fn with_weight(
+        mut layer: Linear<TestAutodiffBackend>,
+        value: f32,
+    ) -> Linear<TestAutodiffBackend> {
+        layer.weight = layer
+            .weight
+            .map(|weight| weight.zeros_like().add_scalar(value));
+        layer
+    }
+}
@@ -6,7 +6,9 @@ use burn_tensor::{
 
 use crate::module::{AutodiffModule, ParamId};
 
-use super::visitor::{GradientsParamsChangeDevice, GradientsParamsConverter};
+use super::visitor::{
+    GradientsParamsChangeDevice, GradientsParamsConverter, GradientsParamsScaler,
+};
 
 /// Data type that contains gradients for parameters.
 #[derive(Default)]
@@ -74,6 +76,18 @@ impl GradientsParams {
         self
     }
 
+    /// Multiply each tensor gradients registered for the given [module](AutodiffModule) by the
+    /// scalar.
+    pub fn mul_scalar<B: AutodiffBackend, M: AutodiffModule<B>>(
+        mut self,
+        scalar: f64,
+        module: &M,
+    ) -> Self {
+        let mut visitor = GradientsParamsScaler::<M, B>::new(scalar, &mut self);
+        module.visit(&mut visitor);
+        self
+    }
+
     /// Extract each tensor gradients for the given [module](AutodiffModule).
     pub fn from_grads<B: AutodiffBackend, M: AutodiffModule<B>>(
         grads: B::Gradients,
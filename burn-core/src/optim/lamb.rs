@@ -0,0 +1,260 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use core::marker::PhantomData;
+
+use super::{
+    adamw::{AdaptiveMomentumW, AdaptiveMomentumWState},
+    Optimizer, SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// LAMB configuration.
+#[derive(Config)]
+pub struct LAMBConfig {
+    /// Parameter for LAMB.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for LAMB.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-6)]
+    epsilon: f32,
+    /// Weight decay config.
+    #[config(default = 0.01)]
+    weight_decay: f32,
+    /// The maximum value of the trust ratio.
+    #[config(default = 10.0)]
+    trust_ratio_clip: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// LAMB optimizer as described in the paper [Large Batch Optimization for Deep Learning: Training BERT in 76 minutes, You et al., 2020](https://arxiv.org/abs/1904.00962).
+///
+/// The update of each parameter is computed as with [AdamW](super::AdamW), then scaled by the
+/// trust ratio `clip(||w|| / ||update||, 0, trust_ratio_clip)`, so that the step taken by each
+/// layer is proportional to the norm of its weights.
+pub struct LAMB<B: Backend> {
+    momentum: AdaptiveMomentumW,
+    weight_decay: f32,
+    trust_ratio_clip: f32,
+    _phantom: PhantomData<B>,
+}
+
+/// LAMB state.
+#[derive(Record, Clone, new)]
+pub struct LAMBState<B: Backend, const D: usize> {
+    momentum: AdaptiveMomentumWState<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for LAMB<B> {
+    type State<const D: usize> = LAMBState<B, D>;
+
+    /// A single optimization step for any tensor that represents the parameters of a model.
+    fn step<const D: usize>(
+        &self,
+        // Learning rate.
+        lr: LearningRate,
+        // Any tensor that represents the parameters of a model.
+        tensor: Tensor<B, D>,
+        // Gradient of the loss w.r.t. the parameters.
+        grad: Tensor<B, D>,
+        // State of the optimizer.
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let (raw_delta, momentum_state) = self.momentum.transform(grad, state.map(|s| s.momentum));
+        let delta = raw_delta + tensor.clone().mul_scalar(self.weight_decay);
+
+        let norm_tensor = tensor.clone().powf_scalar(2.0).sum().sqrt();
+        let norm_delta = delta.clone().powf_scalar(2.0).sum().sqrt();
+
+        // The trust ratio is one when either norm is zero, e.g. for parameters initialized to
+        // zeros.
+        let zero_tensor = norm_tensor.clone().equal_elem(0.0);
+        let zero_delta = norm_delta.clone().equal_elem(0.0);
+        let trust_ratio = norm_tensor
+            .div(norm_delta)
+            .clamp(0.0, self.trust_ratio_clip)
+            .mask_fill(zero_tensor, 1.0)
+            .mask_fill(zero_delta, 1.0);
+
+        let state = LAMBState {
+            momentum: momentum_state,
+        };
+        let delta = delta.mul(trust_ratio.reshape([1; D])).mul_scalar(lr);
+
+        (tensor - delta, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+}
+
+impl LAMBConfig {
+    /// Initialize LAMB optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> impl Optimizer<M, B> {
+        let optim = LAMB {
+            momentum: AdaptiveMomentumW {
+                beta_1: self.beta_1,
+                beta_2: self.beta_2,
+                epsilon: self.epsilon,
+            },
+            weight_decay: self.weight_decay,
+            trust_ratio_clip: self.trust_ratio_clip,
+            _phantom: Default::default(),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+    use tempfile::TempDir;
+
+    const LEARNING_RATE: LearningRate = 0.01;
+    const ASSERT_PRECISION: usize = 4;
+
+    #[test]
+    fn test_lamb_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = create_lamb();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        let temp_dir = TempDir::new().unwrap();
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(optimizer.to_record(), temp_dir.path().join("test_optim"))
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = create_lamb();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_lamb_optimizer_with_numbers() {
+        let mut linear = given_linear_layer(
+            Data::from([[-0.3206, 0.1374], [0.4043, 0.3200], [0.0859, 0.0671]]),
+            Data::from([-0.3905, 0.0884]),
+        );
+        let device = Default::default();
+        let xs = [
+            Tensor::from_floats(
+                [[0.6294, 0.0940, 0.8176], [0.7152, 0.9559, 0.7893]],
+                &device,
+            ),
+            Tensor::from_floats(
+                [[0.8491, 0.2108, 0.8939], [0.3270, 0.0412, 0.5538]],
+                &device,
+            ),
+        ];
+
+        let mut optimizer = LAMBConfig::new().init();
+
+        for x in xs {
+            let grads = linear.forward(x.require_grad()).backward();
+            let grads = GradientsParams::from_grads(grads, &linear);
+            linear = optimizer.step(LEARNING_RATE, linear, grads);
+        }
+
+        let state_updated = linear.into_record();
+        let weights_expected = Data::from([
+            [-0.325869, 0.132107],
+            [0.399453, 0.315158],
+            [0.080605, 0.061806],
+        ]);
+        let bias_expected = Data::from([-0.396164, 0.082709]);
+
+        state_updated
+            .weight
+            .to_data()
+            .assert_approx_eq(&weights_expected, ASSERT_PRECISION);
+        state_updated
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&bias_expected, ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_lamb_optimizer_zero_params_no_nan() {
+        let mut linear = given_linear_layer(Data::zeros([3, 2]), Data::zeros([2]));
+        let x = Tensor::from_floats([[0.6294, 0.0940, 0.8176]], &Default::default());
+
+        let mut optimizer = LAMBConfig::new().init();
+
+        let grads = linear.forward(x.require_grad()).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        // With a trust ratio of one, the first step is the sign of the gradient.
+        state_updated
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.01, -0.01]; 3]), ASSERT_PRECISION);
+    }
+
+    fn given_linear_layer(
+        weight: Data<f32, 2>,
+        bias: Data<f32, 1>,
+    ) -> nn::Linear<TestAutodiffBackend> {
+        let device = Default::default();
+        let [d_input, d_output] = weight.shape.dims;
+        let record = nn::LinearRecord {
+            weight: Param::from(Tensor::from_data(weight, &device)),
+            bias: Some(Param::from(Tensor::from_data(bias, &device))),
+        };
+
+        nn::LinearConfig::new(d_input, d_output).init_with(record)
+    }
+
+    fn create_lamb(
+    ) -> OptimizerAdaptor<LAMB<TestBackend>, nn::Linear<TestAutodiffBackend>, TestAutodiffBackend>
+    {
+        let config = LAMBConfig::new();
+        LAMB {
+            momentum: AdaptiveMomentumW {
+                beta_1: config.beta_1,
+                beta_2: config.beta_2,
+                epsilon: config.epsilon,
+            },
+            weight_decay: config.weight_decay,
+            trust_ratio_clip: config.trust_ratio_clip,
+            _phantom: Default::default(),
+        }
+        .into()
+    }
+}
@@ -8,9 +8,12 @@ mod adagrad;
 mod adam;
 mod adamw;
 mod base;
+mod ema;
 mod grad_accum;
 mod grads;
+mod lamb;
 mod rmsprop;
+mod sam;
 mod sgd;
 mod simple;
 mod visitor;
@@ -19,8 +22,11 @@ pub use adagrad::*;
 pub use adam::*;
 pub use adamw::*;
 pub use base::*;
+pub use ema::*;
 pub use grad_accum::*;
 pub use grads::*;
+pub use lamb::*;
 pub use rmsprop::*;
+pub use sam::*;
 pub use sgd::*;
 pub use simple::*;
@@ -0,0 +1,298 @@
+use crate as burn;
+
+use super::{GradientsParams, Optimizer};
+use crate::config::Config;
+use crate::module::{AutodiffModule, ModuleMapper, ModuleVisitor, ParamId};
+use crate::tensor::backend::AutodiffBackend;
+use crate::tensor::Tensor;
+use crate::LearningRate;
+use core::marker::PhantomData;
+
+/// Configuration to create the [SAM](Sam) optimizer wrapper.
+#[derive(Config)]
+pub struct SamConfig {
+    /// The radius of the neighborhood of the parameters in which the loss is maximized.
+    #[config(default = 0.05)]
+    rho: f64,
+}
+
+impl SamConfig {
+    /// Initialize the [SAM](Sam) optimizer wrapper around the given optimizer.
+    pub fn init<B, M, O>(&self, optim: O) -> Sam<O, M, B>
+    where
+        B: AutodiffBackend,
+        M: AutodiffModule<B>,
+        O: Optimizer<M, B>,
+    {
+        Sam {
+            optim,
+            rho: self.rho,
+            perturbation: None,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Sharpness-Aware Minimization as described in the paper
+/// [Sharpness-Aware Minimization for Efficiently Improving Generalization, Foret et al., 2021](https://arxiv.org/abs/2010.01412).
+///
+/// Each step requires two forward and backward passes on the same batch:
+///
+/// 1. The gradients of the loss are used by [first_step](Sam::first_step) to move the
+///    parameters to the point of highest loss in their neighborhood, `w + rho * g / ||g||`.
+/// 2. The gradients of the loss computed at that point are used by
+///    [second_step](Sam::second_step), which restores the original parameters and updates them
+///    with the inner optimizer.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_core::module::AutodiffModule;
+/// use burn_core::optim::{GradientsParams, Optimizer, Sam};
+/// use burn_core::tensor::{backend::AutodiffBackend, Tensor};
+///
+/// fn train_step<B, M, O, F>(sam: &mut Sam<O, M, B>, model: M, loss: F) -> M
+/// where
+///     B: AutodiffBackend,
+///     M: AutodiffModule<B>,
+///     O: Optimizer<M, B>,
+///     F: Fn(&M) -> Tensor<B, 1>,
+/// {
+///     let grads = GradientsParams::from_grads(loss(&model).backward(), &model);
+///     let model = sam.first_step(model, &grads);
+///
+///     let grads = GradientsParams::from_grads(loss(&model).backward(), &model);
+///     sam.second_step(1.0e-3, model, grads)
+/// }
+/// ```
+pub struct Sam<O, M, B> {
+    optim: O,
+    rho: f64,
+    perturbation: Option<GradientsParams>,
+    phantom: PhantomData<(M, B)>,
+}
+
+impl<O, M, B> Sam<O, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+    O: Optimizer<M, B>,
+{
+    /// Move the parameters of the module to the point of highest loss in their neighborhood,
+    /// given the gradients of the loss at the current parameters.
+    pub fn first_step(&mut self, module: M, grads: &GradientsParams) -> M {
+        let (module, perturbation) = perturb_module(module, grads, self.rho);
+        self.perturbation = Some(perturbation);
+
+        module
+    }
+
+    /// Restore the parameters moved by the [first step](Sam::first_step) and update them with
+    /// the inner optimizer, given the gradients of the loss at the moved parameters.
+    ///
+    /// Without a previous first step, the inner optimizer is applied directly.
+    pub fn second_step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M {
+        let module = match self.perturbation.take() {
+            Some(perturbation) => module.map(&mut ParamsShifter::<B> {
+                grads: &perturbation,
+                scale: None,
+                direction: -1.0,
+                perturbation: None,
+                phantom: PhantomData,
+            }),
+            None => module,
+        };
+
+        self.optim.step(lr, module, grads)
+    }
+
+    /// Get the current state of the inner optimizer.
+    pub fn to_record(&self) -> O::Record {
+        self.optim.to_record()
+    }
+
+    /// Load the state of the inner optimizer.
+    pub fn load_record(mut self, record: O::Record) -> Self {
+        self.optim = self.optim.load_record(record);
+        self
+    }
+}
+
+/// Move the parameters of the module by `rho * g / ||g||`, where `||g||` is the norm of all the
+/// gradients of the module.
+///
+/// Returns the moved module along with the perturbation applied to each parameter.
+pub fn perturb_module<B, M>(module: M, grads: &GradientsParams, rho: f64) -> (M, GradientsParams)
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    let mut norm = GradientsSquaredNorm::<B> { grads, value: None };
+    module.visit(&mut norm);
+
+    let Some(squared_norm) = norm.value else {
+        return (module, GradientsParams::new());
+    };
+    let scale = squared_norm
+        .sqrt()
+        .add_scalar(1e-12)
+        .recip()
+        .mul_scalar(rho);
+
+    let mut perturbation = GradientsParams::new();
+    let module = module.map(&mut ParamsShifter::<B> {
+        grads,
+        scale: Some(scale),
+        direction: 1.0,
+        perturbation: Some(&mut perturbation),
+        phantom: PhantomData,
+    });
+
+    (module, perturbation)
+}
+
+struct GradientsSquaredNorm<'a, B: AutodiffBackend> {
+    grads: &'a GradientsParams,
+    value: Option<Tensor<B::InnerBackend, 1>>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for GradientsSquaredNorm<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) else {
+            return;
+        };
+        let squared_norm = grad.powf_scalar(2.0).sum();
+
+        self.value = Some(match self.value.take() {
+            Some(value) => value.add(squared_norm),
+            None => squared_norm,
+        });
+    }
+}
+
+/// Add the scaled tensors of `grads` to the parameters, in the given direction.
+struct ParamsShifter<'a, B: AutodiffBackend> {
+    grads: &'a GradientsParams,
+    scale: Option<Tensor<B::InnerBackend, 1>>,
+    direction: f64,
+    perturbation: Option<&'a mut GradientsParams>,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleMapper<B> for ParamsShifter<'a, B> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let Some(mut shift) = self.grads.get::<B::InnerBackend, D>(id) else {
+            return tensor;
+        };
+
+        if let Some(scale) = &self.scale {
+            shift = shift.mul(scale.clone().reshape([1; D]));
+        }
+        if let Some(perturbation) = self.perturbation.as_mut() {
+            perturbation.register::<B::InnerBackend, D>(id.clone(), shift.clone());
+        }
+
+        let is_require_grad = tensor.is_require_grad();
+        let shift = shift.mul_scalar(self.direction);
+        let mut tensor = Tensor::from_inner(tensor.inner().add(shift));
+        if is_require_grad {
+            tensor = tensor.require_grad();
+        }
+
+        tensor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{list_param_ids, Module, Param};
+    use crate::nn::{Linear, LinearConfig, LinearRecord};
+    use crate::optim::SgdConfig;
+    use crate::tensor::{backend::Backend, Data};
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    const RHO: f64 = 0.5;
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    #[test]
+    fn first_step_moves_params_by_rho_along_gradients() {
+        let device = Default::default();
+        let layer = layer(&device);
+        let mut sam = SamConfig::new().with_rho(RHO).init(SgdConfig::new().init());
+
+        let grads = compute_grads(&layer);
+        let layer = sam.first_step(layer, &grads);
+
+        // The gradients are [[0, 0], [2, 2]] for the weight and [2, 2] for the bias, with a
+        // norm of 4.
+        let record = layer.into_record();
+        record
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, -1.0], [1.25, 0.25]]), 5);
+        record
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([0.25, 0.75]), 5);
+    }
+
+    #[test]
+    fn second_step_updates_original_params() {
+        let device = Default::default();
+        let layer = layer(&device);
+        let mut sam = SamConfig::new().with_rho(RHO).init(SgdConfig::new().init());
+
+        let grads = compute_grads(&layer);
+        let layer = sam.first_step(layer, &grads);
+        let grads = compute_grads(&layer);
+        let layer = sam.second_step(LEARNING_RATE, layer, grads);
+
+        // The gradients of a linear layer don't depend on its parameters, so the update is the
+        // same as the one of the inner optimizer.
+        let record = layer.into_record();
+        record
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, -1.0], [0.8, -0.2]]), 5);
+        record
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&Data::from([-0.2, 0.3]), 5);
+    }
+
+    #[test]
+    fn perturbation_has_norm_rho() {
+        let device = Default::default();
+        let layer = LinearConfig::new(2, 3).init::<TestAutodiffBackend>(&device);
+        let ids = list_param_ids(&layer);
+
+        let grads = compute_grads(&layer);
+        let (_, perturbation) = perturb_module(layer, &grads, RHO);
+
+        let weight: Tensor<TestBackend, 2> = perturbation.get(&ids[0]).unwrap();
+        let bias: Tensor<TestBackend, 1> = perturbation.get(&ids[1]).unwrap();
+        let norm = (weight.powf_scalar(2.0).sum() + bias.powf_scalar(2.0).sum()).sqrt();
+        norm.into_data()
+            .assert_approx_eq(&Data::from([RHO as f32]), 5);
+    }
+
+    fn compute_grads(layer: &Linear<TestAutodiffBackend>) -> GradientsParams {
+        let device = layer.weight.device();
+        let input = Tensor::from_floats([[0.0, 1.0], [0.0, 1.0]], &device);
+        let grads = layer.forward(input).sum().backward();
+
+        GradientsParams::from_grads(grads, layer)
+    }
+
+    fn layer(device: &<TestAutodiffBackend as Backend>::Device) -> Linear<TestAutodiffBackend> {
+        let record = LinearRecord {
+            weight: Param::from(Tensor::from_floats([[0.0, -1.0], [1.0, 0.0]], device)),
+            bias: Some(Param::from(Tensor::from_floats([0.0, 0.5], device))),
+        };
+
+        LinearConfig::new(2, 2).init_with(record)
+    }
+}
@@ -74,13 +74,12 @@ where
     type Record = HashMap<ParamId, AdaptorRecord<O, B>>;
 
     fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
-        let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
-            &self.optim,
-            &mut self.records,
-            &mut grads,
-            lr,
-            self.grad_clipping.as_ref(),
-        );
+        if let Some(grad_clipping) = &self.grad_clipping {
+            grads = grad_clipping.clip_gradients(&module, grads);
+        }
+
+        let mut mapper =
+            SimpleOptimizerMapper::<M, B, O>::new(&self.optim, &mut self.records, &mut grads, lr);
         module.map(&mut mapper)
     }
 
@@ -106,7 +105,6 @@ where
     grads: &'a mut GradientsParams,
     lr: LearningRate,
     phantom: PhantomData<M>,
-    grad_clipping: Option<&'a GradientClipping>,
 }
 
 impl<'a, M, B, O> ModuleMapper<B> for SimpleOptimizerMapper<'a, M, B, O>
@@ -123,16 +121,11 @@ where
             let is_require_grad = tensor.is_require_grad();
             let (key, record) = self.records.remove_entry(id).unzip();
 
-            let clipped_grad = if let Some(g_clipping) = self.grad_clipping {
-                g_clipping.clip_gradient(grad)
-            } else {
-                grad
-            };
-
-            let (tensor, state) = self.optimizer.step(
+            let (tensor, state) = self.optimizer.step_param(
+                id,
                 self.lr,
                 tensor.inner(),
-                clipped_grad,
+                grad,
                 record.map(|record| O::to_device(record.into_state(), &device)),
             );
 
@@ -1,4 +1,4 @@
-use crate::{record::Record, LearningRate};
+use crate::{module::ParamId, record::Record, LearningRate};
 use burn_tensor::{backend::Backend, Tensor};
 
 /// Simple optimizer is an opinionated trait to simplify the process of implementing an
@@ -25,6 +25,21 @@ where
         state: Option<Self::State<D>>,
     ) -> (Tensor<B, D>, Option<Self::State<D>>);
 
+    /// The optimizer step for the parameter with the given id.
+    ///
+    /// By default, the id is ignored and the [step](SimpleOptimizer::step) function is called.
+    /// Implementations can override it when the update depends on the parameter.
+    fn step_param<const D: usize>(
+        &self,
+        _id: &ParamId,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        self.step(lr, tensor, grad, state)
+    }
+
     /// Change the device of the state.
     ///
     /// This function will be called accordindly to have the state on the same device as the
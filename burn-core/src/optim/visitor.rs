@@ -17,6 +17,13 @@ pub struct GradientsParamsChangeDevice<'a, M: AutodiffModule<B>, B: AutodiffBack
     phatom: PhantomData<M>,
 }
 
+#[derive(new)]
+pub struct GradientsParamsScaler<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
+    scalar: f64,
+    grads: &'a mut GradientsParams,
+    phantom: PhantomData<(M, B)>,
+}
+
 impl<'a, B, M> ModuleVisitor<B> for GradientsParamsConverter<'a, M, B>
 where
     B: AutodiffBackend,
@@ -42,3 +49,16 @@ where
         }
     }
 }
+
+impl<'a, B, M> ModuleVisitor<B> for GradientsParamsScaler<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+            self.grads
+                .register::<B::InnerBackend, D>(id.clone(), grad.mul_scalar(self.scalar));
+        }
+    }
+}
@@ -0,0 +1,401 @@
+use super::serde::{
+    adapter::DefaultAdapter,
+    data::{unflatten, NestedValue, Serializable},
+    de::Deserializer,
+    error,
+    ser::Serializer,
+};
+use super::{FileRecorder, PrecisionSettings, Record, Recorder, RecorderError};
+use crate::module::ParamId;
+use crate::record::ParamSerde;
+use burn_tensor::{
+    backend::Backend,
+    safetensors::{deserialize, serialize, Dtype, SafetensorsData},
+    DataSerialize, Element,
+};
+use core::any::TypeId;
+use core::marker::PhantomData;
+use half::{bf16, f16};
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// File recorder using the [safetensors format](https://huggingface.co/docs/safetensors).
+///
+/// Each parameter is stored under the dot-separated path of its field in the record, such as
+/// `layers.0.weight`, which is the naming convention of PyTorch state dicts. The float parameters
+/// are stored with the float element type of the precision settings, and are converted to it when
+/// loaded, so that files saved with `f32`, `f16` or `bf16` weights can be loaded with any settings.
+#[derive(new, Debug, Default, Clone)]
+pub struct SafetensorsFileRecorder<S: PrecisionSettings> {
+    _settings: PhantomData<S>,
+}
+
+impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for SafetensorsFileRecorder<S> {
+    fn file_extension() -> &'static str {
+        "safetensors"
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for SafetensorsFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn record<R: Record<B>>(&self, record: R, args: PathBuf) -> Result<(), RecorderError> {
+        // The item isn't wrapped with its metadata, the keys being the paths of the parameters.
+        Recorder::<B>::save_item(self, record.into_item::<S>(), args)
+    }
+
+    fn save_item<I: Serialize>(&self, item: I, mut file: PathBuf) -> Result<(), RecorderError> {
+        let value = item
+            .serialize(Serializer::new())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        let mut tensors = Vec::new();
+        flatten::<S>(value, String::new(), &mut tensors)?;
+
+        let bytes = serialize(tensors).map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+        if let Some(parent) = file.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+
+        std::fs::write(file, bytes).map_err(|err| RecorderError::Unknown(err.to_string()))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: PathBuf) -> Result<I, RecorderError> {
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+        let bytes = std::fs::read(file).map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+            _ => RecorderError::Unknown(err.to_string()),
+        })?;
+
+        let tensors = deserialize(&bytes)
+            .map_err(|err| RecorderError::DeserializeError(err.to_string()))?
+            .into_iter()
+            .map(|(key, data)| (key, SafetensorsParam(data)))
+            .collect::<HashMap<_, _>>();
+
+        let value = unflatten::<S, _>(tensors)?;
+        let deserializer = Deserializer::<DefaultAdapter>::new(value, true);
+
+        Ok(I::deserialize(deserializer)?)
+    }
+
+    fn load<R: Record<B>>(&self, args: PathBuf, device: &B::Device) -> Result<R, RecorderError> {
+        let item = Recorder::<B>::load_item::<R::Item<S>>(self, args)?;
+
+        Ok(R::from_item(item, device))
+    }
+}
+
+/// A stored tensor, deserialized as a parameter.
+struct SafetensorsParam(SafetensorsData);
+
+impl Serializable for SafetensorsParam {
+    fn serialize<PS>(&self, serializer: Serializer) -> Result<NestedValue, error::Error>
+    where
+        PS: PrecisionSettings,
+    {
+        let data = self.0.clone();
+        let param_id = ParamId::new().into_string();
+        let map_err =
+            |err: burn_tensor::safetensors::SafetensorsError| error::Error::Other(err.to_string());
+
+        match data.dtype() {
+            Dtype::F16 | Dtype::BF16 | Dtype::F32 | Dtype::F64 => {
+                let data = data.into_data::<PS::FloatElem>().map_err(map_err)?;
+                ParamSerde::new(param_id, data).serialize(serializer)
+            }
+            Dtype::BOOL => {
+                let data = data.into_bool_data().map_err(map_err)?;
+                ParamSerde::new(param_id, data).serialize(serializer)
+            }
+            _ => {
+                let data = data.into_data::<PS::IntElem>().map_err(map_err)?;
+                ParamSerde::new(param_id, data).serialize(serializer)
+            }
+        }
+    }
+}
+
+/// Collects the parameters of a serialized record, with the dot-separated paths of their fields.
+fn flatten<S: PrecisionSettings>(
+    value: NestedValue,
+    path: String,
+    tensors: &mut Vec<(String, SafetensorsData)>,
+) -> Result<(), RecorderError> {
+    let join = |key: &str| match path.is_empty() {
+        true => key.to_string(),
+        false => format!("{path}.{key}"),
+    };
+
+    match value {
+        NestedValue::Map(mut map) if map.contains_key("id") && map.contains_key("param") => {
+            let param = map.remove("param").and_then(NestedValue::as_map);
+            let tensor = param.and_then(param_to_data::<S>).ok_or_else(|| {
+                RecorderError::Unknown(format!("Invalid parameter for the key '{path}'"))
+            })?;
+            tensors.push((path, tensor));
+        }
+        NestedValue::Map(map) => {
+            for (key, value) in map {
+                flatten::<S>(value, join(&key), tensors)?;
+            }
+        }
+        NestedValue::Vec(values) => {
+            for (index, value) in values.into_iter().enumerate() {
+                flatten::<S>(value, join(&index.to_string()), tensors)?;
+            }
+        }
+        // Constants and empty optional fields aren't stored.
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Converts the serialized data of a parameter, with its elements and its shape.
+fn param_to_data<S: PrecisionSettings>(
+    mut param: HashMap<String, NestedValue>,
+) -> Option<SafetensorsData> {
+    let shape = match param.remove("shape")? {
+        NestedValue::Vec(dims) => dims
+            .into_iter()
+            .map(|dim| dim.as_u64().map(|dim| dim as usize))
+            .collect::<Option<Vec<_>>>()?,
+        _ => return None,
+    };
+    let values = match param.remove("value")? {
+        NestedValue::Vec(values) => values,
+        _ => return None,
+    };
+
+    let data = match values.first() {
+        None => SafetensorsData::from_data(DataSerialize::<S::FloatElem>::new(vec![], shape)),
+        Some(NestedValue::F32(_)) => collect(values, shape, NestedValue::as_f32)?,
+        Some(NestedValue::F64(_)) => collect(values, shape, NestedValue::as_f64)?,
+        Some(NestedValue::I16(_)) => collect(values, shape, NestedValue::as_i16)?,
+        Some(NestedValue::I32(_)) => collect(values, shape, NestedValue::as_i32)?,
+        Some(NestedValue::I64(_)) => collect(values, shape, NestedValue::as_i64)?,
+        Some(NestedValue::U16(_)) if TypeId::of::<S::FloatElem>() == TypeId::of::<bf16>() => {
+            collect(values, shape, |value| value.as_u16().map(bf16::from_bits))?
+        }
+        // Half precision elements are serialized as their bits.
+        Some(NestedValue::U16(_)) => {
+            collect(values, shape, |value| value.as_u16().map(f16::from_bits))?
+        }
+        Some(NestedValue::Bool(_)) => SafetensorsData::from_bool_data(DataSerialize::new(
+            values
+                .into_iter()
+                .map(NestedValue::as_bool)
+                .collect::<Option<_>>()?,
+            shape,
+        )),
+        Some(_) => return None,
+    };
+
+    Some(data)
+}
+
+fn collect<E: Element, F: Fn(NestedValue) -> Option<E>>(
+    values: Vec<NestedValue>,
+    shape: Vec<usize>,
+    convert: F,
+) -> Option<SafetensorsData> {
+    let values = values.into_iter().map(convert).collect::<Option<_>>()?;
+
+    Some(SafetensorsData::from_data(DataSerialize::new(
+        values, shape,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::{
+        module::Module,
+        nn::{
+            conv::{Conv2d, Conv2dConfig},
+            Linear, LinearConfig,
+        },
+        record::{FullPrecisionSettings, HalfPrecisionSettings},
+        TestBackend,
+    };
+    use burn_tensor::{Bool, Int, Tensor};
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        conv: Conv2d<B>,
+        layers: Vec<Linear<B>>,
+        scale: usize,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                conv: Conv2dConfig::new([2, 3], [3, 3]).init(device),
+                layers: vec![
+                    LinearConfig::new(4, 8).init(device),
+                    LinearConfig::new(8, 2).with_bias(false).init(device),
+                ],
+                scale: 2,
+            }
+        }
+    }
+
+    fn file_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("burn_test_safetensors_{name}"))
+    }
+
+    fn assert_same_weights(
+        actual: &Model<TestBackend>,
+        expected: &Model<TestBackend>,
+        tolerance: usize,
+    ) {
+        let params = |model: &Model<TestBackend>| {
+            let mut params: Vec<Tensor<TestBackend, 1>> = vec![
+                model.conv.weight.val().flatten(0, 3),
+                model.conv.bias.as_ref().unwrap().val(),
+            ];
+            for layer in model.layers.iter() {
+                params.push(layer.weight.val().flatten(0, 1));
+                if let Some(bias) = &layer.bias {
+                    params.push(bias.val());
+                }
+            }
+            params
+        };
+
+        let actual = params(actual);
+        let expected = params(expected);
+        assert_eq!(actual.len(), expected.len());
+        for (actual, expected) in actual.into_iter().zip(expected) {
+            actual
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), tolerance);
+        }
+    }
+
+    #[test]
+    fn safetensors_recorder_should_round_trip_model() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let path = file_path("full");
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::new();
+
+        model
+            .clone()
+            .save_file(path.clone(), &recorder)
+            .expect("Should save the model");
+        let loaded = Model::<TestBackend>::new(&device)
+            .load_file(path, &recorder, &device)
+            .expect("Should load the model");
+
+        assert_same_weights(&loaded, &model, 6);
+        assert!(loaded.layers[1].bias.is_none());
+    }
+
+    #[test]
+    fn safetensors_recorder_should_use_pytorch_names() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let path = file_path("names");
+
+        model
+            .save_file(
+                path.clone(),
+                &SafetensorsFileRecorder::<FullPrecisionSettings>::new(),
+            )
+            .expect("Should save the model");
+
+        let bytes = std::fs::read(path.with_extension("safetensors")).unwrap();
+        let tensors = deserialize(&bytes).unwrap();
+        let mut keys: Vec<_> = tensors.keys().cloned().collect();
+        keys.sort();
+
+        assert_eq!(
+            keys,
+            vec![
+                "conv.bias",
+                "conv.weight",
+                "layers.0.bias",
+                "layers.0.weight",
+                "layers.1.weight"
+            ]
+        );
+        assert_eq!(tensors["conv.weight"].dtype(), Dtype::F32);
+        assert_eq!(tensors["layers.0.weight"].shape(), &[4, 8]);
+    }
+
+    #[test]
+    fn safetensors_recorder_should_load_half_precision_weights() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let path = file_path("half");
+
+        model
+            .clone()
+            .save_file(
+                path.clone(),
+                &SafetensorsFileRecorder::<HalfPrecisionSettings>::new(),
+            )
+            .expect("Should save the model");
+
+        let bytes = std::fs::read(path.with_extension("safetensors")).unwrap();
+        assert_eq!(
+            deserialize(&bytes).unwrap()["conv.weight"].dtype(),
+            Dtype::F16
+        );
+
+        let loaded = Model::<TestBackend>::new(&device)
+            .load_from_safetensors(path, &device)
+            .expect("Should load the model");
+
+        assert_same_weights(&loaded, &model, 2);
+    }
+
+    #[test]
+    fn tensors_should_round_trip_safetensors_bytes() {
+        let device = Default::default();
+        let float = Tensor::<TestBackend, 2>::from_floats([[1.5, -2.0], [0.25, 4.0]], &device);
+        let int = Tensor::<TestBackend, 1, Int>::from_ints([3, -1, 7], &device);
+        let bool = Tensor::<TestBackend, 1, Bool>::from_bool([true, false].into(), &device);
+
+        let float_bytes = float.clone().to_safetensors_bytes("float");
+        let int_bytes = int.clone().to_safetensors_bytes("int");
+        let bool_bytes = bool.clone().to_safetensors_bytes("bool");
+
+        assert_eq!(deserialize(&int_bytes).unwrap()["int"].dtype(), Dtype::I64);
+        assert_eq!(
+            Tensor::<TestBackend, 2>::from_safetensors_bytes(&float_bytes, "float", &device)
+                .unwrap()
+                .into_data(),
+            float.into_data()
+        );
+        assert_eq!(
+            Tensor::<TestBackend, 1, Int>::from_safetensors_bytes(&int_bytes, "int", &device)
+                .unwrap()
+                .into_data(),
+            int.into_data()
+        );
+        assert_eq!(
+            Tensor::<TestBackend, 1, Bool>::from_safetensors_bytes(&bool_bytes, "bool", &device)
+                .unwrap()
+                .into_data(),
+            bool.into_data()
+        );
+        assert!(
+            Tensor::<TestBackend, 1>::from_safetensors_bytes(&float_bytes, "float", &device)
+                .is_err()
+        );
+        assert!(
+            Tensor::<TestBackend, 2>::from_safetensors_bytes(&float_bytes, "missing", &device)
+                .is_err()
+        );
+    }
+}
@@ -6,7 +6,7 @@ use super::{
 };
 
 use serde::{
-    ser::{self, SerializeSeq, SerializeStruct, Serializer as SerializerTrait},
+    ser::{self, SerializeSeq, SerializeStruct, SerializeTuple, Serializer as SerializerTrait},
     Serialize,
 };
 
@@ -37,7 +37,7 @@ impl SerializerTrait for Serializer {
     type Ok = NestedValue;
     type Error = Error;
     type SerializeSeq = Self;
-    type SerializeTuple = ser::Impossible<NestedValue, Self::Error>;
+    type SerializeTuple = Self;
     type SerializeTupleStruct = ser::Impossible<NestedValue, Self::Error>;
     type SerializeTupleVariant = ser::Impossible<NestedValue, Self::Error>;
     type SerializeMap = ser::Impossible<NestedValue, Self::Error>;
@@ -67,6 +67,10 @@ impl SerializerTrait for Serializer {
         Ok(self)
     }
 
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(self)
+    }
+
     fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
         Ok(NestedValue::I32(v))
     }
@@ -99,6 +103,10 @@ impl SerializerTrait for Serializer {
         Ok(NestedValue::F64(v))
     }
 
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(NestedValue::Bool(v))
+    }
+
     // The following methods are not implemented because they are not needed for the
     // serialization of Param structs.
 
@@ -116,9 +124,6 @@ impl SerializerTrait for Serializer {
     fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
         unimplemented!()
     }
-    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
-    }
 
     fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
         unimplemented!()
@@ -165,10 +170,6 @@ impl SerializerTrait for Serializer {
         unimplemented!()
     }
 
-    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
-        unimplemented!()
-    }
-
     fn serialize_tuple_struct(
         self,
         _name: &'static str,
@@ -279,6 +280,20 @@ impl SerializeSeq for Serializer {
     }
 }
 
+// Fixed size arrays are serialized as tuples.
+impl SerializeTuple for Serializer {
+    type Ok = NestedValue;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        SerializeSeq::end(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
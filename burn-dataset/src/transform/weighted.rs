@@ -0,0 +1,247 @@
+use crate::Dataset;
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    rngs::StdRng,
+    Rng, SeedableRng,
+};
+use std::{marker::PhantomData, ops::DerefMut, sync::Mutex};
+
+/// Sample items from a dataset with a probability proportional to their weight.
+///
+/// This is useful with imbalanced datasets, where the items of the rare classes can be given a
+/// higher weight so that they are sampled as often as the others.
+///
+/// * With replacement: Each item is sampled independently of the previous ones.
+///
+/// * Without replacement: The items are drawn one at a time, with a probability proportional to
+///   their weight among the items not yet drawn. The items with a zero weight are never drawn.
+///   Once every other item has been used, a new cycle is created.
+pub struct WeightedSamplerDataset<D, I> {
+    dataset: D,
+    size: usize,
+    weights: Vec<f64>,
+    state: Mutex<WeightedSamplerState>,
+    input: PhantomData<I>,
+}
+
+enum WeightedSamplerState {
+    WithReplacement(StdRng, WeightedIndex<f64>),
+    WithoutReplacement(StdRng, Vec<usize>),
+}
+
+impl<D, I> WeightedSamplerDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    /// Creates a new weighted sampler dataset.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataset` - The dataset to sample from.
+    /// * `weights` - The weight of each item of the dataset, which doesn't need to sum to one.
+    /// * `size` - The number of items of the sampler dataset.
+    /// * `replacement` - Whether the items are sampled with replacement.
+    ///
+    /// # Panics
+    ///
+    /// If the number of weights isn't the number of items of the dataset, or if the weights
+    /// aren't finite and positive with at least one of them greater than zero.
+    pub fn new(dataset: D, weights: Vec<f64>, size: usize, replacement: bool) -> Self {
+        Self::with_rng(dataset, weights, size, replacement, StdRng::from_entropy())
+    }
+
+    /// Creates a new weighted sampler dataset with a seed, making the sampling reproducible.
+    ///
+    /// See [new](Self::new) for the arguments.
+    pub fn with_seed(
+        dataset: D,
+        weights: Vec<f64>,
+        size: usize,
+        replacement: bool,
+        seed: u64,
+    ) -> Self {
+        Self::with_rng(
+            dataset,
+            weights,
+            size,
+            replacement,
+            StdRng::seed_from_u64(seed),
+        )
+    }
+
+    fn with_rng(
+        dataset: D,
+        weights: Vec<f64>,
+        size: usize,
+        replacement: bool,
+        rng: StdRng,
+    ) -> Self {
+        assert_eq!(
+            weights.len(),
+            dataset.len(),
+            "There should be one weight per item of the dataset."
+        );
+        assert!(
+            weights
+                .iter()
+                .all(|weight| weight.is_finite() && *weight >= 0.0),
+            "The weights should be finite and positive."
+        );
+        assert!(
+            weights.iter().any(|weight| *weight > 0.0),
+            "At least one weight should be greater than zero."
+        );
+
+        let state = match replacement {
+            true => WeightedSamplerState::WithReplacement(
+                rng,
+                WeightedIndex::new(&weights).expect("The weights are valid."),
+            ),
+            false => WeightedSamplerState::WithoutReplacement(rng, Vec::new()),
+        };
+
+        Self {
+            dataset,
+            size,
+            weights,
+            state: Mutex::new(state),
+            input: PhantomData,
+        }
+    }
+
+    fn index(&self) -> usize {
+        let mut state = self.state.lock().unwrap();
+
+        match state.deref_mut() {
+            WeightedSamplerState::WithReplacement(rng, distribution) => distribution.sample(rng),
+            WeightedSamplerState::WithoutReplacement(rng, indices) => {
+                if indices.is_empty() {
+                    // Refill the state.
+                    *indices = weighted_permutation(rng, &self.weights);
+                }
+
+                indices.pop().expect("Indices are refilled when empty.")
+            }
+        }
+    }
+}
+
+/// The indices of the items with a positive weight, in the reverse order of a weighted sampling
+/// without replacement.
+///
+/// Each item is given the key `u^(1 / w)`, with `u` sampled uniformly in `[0, 1)`, and sorting the
+/// items by decreasing key is the same as drawing them one at a time (Efraimidis and Spirakis,
+/// 2006). The logarithm of the key is used to avoid underflows with small weights.
+fn weighted_permutation(rng: &mut StdRng, weights: &[f64]) -> Vec<usize> {
+    let mut keys: Vec<(f64, usize)> = weights
+        .iter()
+        .enumerate()
+        .filter(|(_, weight)| **weight > 0.0)
+        .map(|(index, weight)| (rng.gen::<f64>().ln() / weight, index))
+        .collect();
+
+    keys.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    keys.into_iter().map(|(_, index)| index).collect()
+}
+
+impl<D, I> Dataset<I> for WeightedSamplerDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        if index >= self.size {
+            return None;
+        }
+
+        self.dataset.get(self.index())
+    }
+
+    fn len(&self) -> usize {
+        self.size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemDataset;
+    use std::collections::HashMap;
+
+    #[test]
+    fn weighted_sampler_with_replacement_is_proportional_to_weights() {
+        let dataset = InMemDataset::new(vec![0, 1, 2, 3]);
+        let sampler =
+            WeightedSamplerDataset::with_seed(dataset, vec![1.0, 2.0, 7.0, 0.0], 10_000, true, 42);
+
+        let counts = count_items(&sampler);
+
+        assert_eq!(counts.values().sum::<usize>(), 10_000);
+        assert!(!counts.contains_key(&3));
+        for (item, expected) in [(0, 1_000.0), (1, 2_000.0), (2, 7_000.0)] {
+            let count = counts[&item] as f64;
+            assert!(
+                (count - expected).abs() < 0.1 * expected,
+                "Item {item} was sampled {count} times, expected about {expected}."
+            );
+        }
+    }
+
+    #[test]
+    fn weighted_sampler_without_replacement_uses_each_item_once_per_cycle() {
+        let dataset = InMemDataset::new(vec![0, 1, 2, 3, 4]);
+        let sampler =
+            WeightedSamplerDataset::with_seed(dataset, vec![1.0, 0.0, 3.0, 0.5, 2.0], 8, false, 1);
+
+        let items: Vec<_> = sampler.iter().collect();
+        let mut first_cycle = items[..4].to_vec();
+        let mut second_cycle = items[4..].to_vec();
+        first_cycle.sort();
+        second_cycle.sort();
+
+        assert_eq!(first_cycle, vec![0, 2, 3, 4]);
+        assert_eq!(second_cycle, vec![0, 2, 3, 4]);
+    }
+
+    #[test]
+    fn weighted_sampler_without_replacement_draws_heavy_items_first() {
+        let mut first_counts = HashMap::new();
+
+        for seed in 0..2_000 {
+            let dataset = InMemDataset::new(vec![0, 1]);
+            let sampler =
+                WeightedSamplerDataset::with_seed(dataset, vec![1.0, 3.0], 1, false, seed);
+            *first_counts.entry(sampler.get(0).unwrap()).or_insert(0) += 1;
+        }
+
+        // The first item drawn is the heavy one with a probability of 3/4.
+        let count = first_counts[&1] as f64;
+        assert!((count - 1_500.0).abs() < 150.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn weighted_sampler_should_panic_when_weights_dont_match_dataset() {
+        let dataset = InMemDataset::new(vec![0, 1, 2]);
+
+        WeightedSamplerDataset::new(dataset, vec![1.0, 2.0], 10, true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn weighted_sampler_should_panic_with_zero_weights() {
+        let dataset = InMemDataset::new(vec![0, 1]);
+
+        WeightedSamplerDataset::new(dataset, vec![0.0, 0.0], 10, false);
+    }
+
+    fn count_items<D: Dataset<i32>>(dataset: &D) -> HashMap<i32, usize> {
+        let mut counts = HashMap::new();
+        for item in dataset.iter() {
+            *counts.entry(item).or_insert(0) += 1;
+        }
+
+        counts
+    }
+}
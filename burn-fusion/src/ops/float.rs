@@ -11,8 +11,8 @@ use crate::{
         Operation, OperationDescription, RandomOperationDescription,
         ReduceDimWithIndicesDescription, ReshapeDescription, ScalarOperationDescription,
         ScatterOperationDescription, SelectAssignOperationDescription, SelectOperationDescription,
-        SliceAssignOperationDescription, SliceOperationDescription, StreamId, SwapDimsDescription,
-        UnaryOperationDescription,
+        SliceAssignOperationDescription, SliceOperationDescription, SortDescription, StreamId,
+        SwapDimsDescription, TopKDescription, UnaryOperationDescription,
     },
     unary_float_ops, Fusion, FusionBackend, TensorDescription,
 };
@@ -1694,6 +1694,92 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         (out, out_indices)
     }
 
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        #[derive(new)]
+        struct SortWithIndicesOps<const D: usize> {
+            desc: SortDescription,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B> for SortWithIndicesOps<D> {
+            fn execute(self: Box<Self>, handles: &mut crate::HandleContainer<B>) {
+                let tensor = handles.get_float_tensor::<D>(&self.desc.tensor);
+                let (output, indices) =
+                    B::float_sort_with_indices(tensor, self.desc.dim, self.desc.descending);
+
+                handles.register_float_tensor(&self.desc.out.id, output);
+                handles.register_int_tensor(&self.desc.out_indices.id, indices);
+            }
+        }
+
+        let stream = tensor.stream;
+        let shape = tensor.shape.clone();
+        let client = tensor.client.clone();
+        let out = client.tensor_uninitialized(shape.clone());
+        let out_indices = client.tensor_uninitialized(shape);
+
+        let desc = SortDescription {
+            tensor: tensor.into_description(),
+            dim,
+            descending,
+            out: out.to_description_out(),
+            out_indices: out_indices.to_description_out(),
+        };
+        client.register(
+            vec![stream],
+            OperationDescription::Float(FloatOperationDescription::SortWithIndices(desc.clone())),
+            SortWithIndicesOps::<D>::new(desc),
+        );
+
+        (out, out_indices)
+    }
+
+    fn float_topk<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        k: usize,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        #[derive(new)]
+        struct TopKOps<const D: usize> {
+            desc: TopKDescription,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B> for TopKOps<D> {
+            fn execute(self: Box<Self>, handles: &mut crate::HandleContainer<B>) {
+                let tensor = handles.get_float_tensor::<D>(&self.desc.tensor);
+                let (output, indices) = B::float_topk(tensor, self.desc.k, self.desc.dim);
+
+                handles.register_float_tensor(&self.desc.out.id, output);
+                handles.register_int_tensor(&self.desc.out_indices.id, indices);
+            }
+        }
+
+        let stream = tensor.stream;
+        let mut shape = tensor.shape.clone();
+        shape[dim] = k;
+        let client = tensor.client.clone();
+        let out = client.tensor_uninitialized(shape.clone());
+        let out_indices = client.tensor_uninitialized(shape);
+
+        let desc = TopKDescription {
+            tensor: tensor.into_description(),
+            k,
+            dim,
+            out: out.to_description_out(),
+            out_indices: out_indices.to_description_out(),
+        };
+        client.register(
+            vec![stream],
+            OperationDescription::Float(FloatOperationDescription::TopK(desc.clone())),
+            TopKOps::<D>::new(desc),
+        );
+
+        (out, out_indices)
+    }
+
     fn float_min<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
         unary_float_ops!(MinOps, B::float_min);
 
@@ -12,7 +12,7 @@ use super::{
     OperationDescription, RandomOperationDescription, ReduceDimWithIndicesDescription,
     ReshapeDescription, ScalarOperationDescription, ScatterOperationDescription,
     SelectAssignOperationDescription, SelectOperationDescription, SliceOperationDescription,
-    SwapDimsDescription, UnaryOperationDescription,
+    SortDescription, SwapDimsDescription, TopKDescription, UnaryOperationDescription,
 };
 use crate::{FusionBackend, HandleContainer, TensorDescription, TensorId};
 use burn_tensor::{Element, ElementConversion};
@@ -400,6 +400,24 @@ impl FloatOperationDescription {
                     out: desc.out.to_relative(converter),
                 })
             }
+            FloatOperationDescription::SortWithIndices(desc) => {
+                FloatOperationDescription::SortWithIndices(SortDescription {
+                    tensor: desc.tensor.to_relative(converter),
+                    dim: desc.dim,
+                    descending: desc.descending,
+                    out: desc.out.to_relative(converter),
+                    out_indices: desc.out_indices.to_relative(converter),
+                })
+            }
+            FloatOperationDescription::TopK(desc) => {
+                FloatOperationDescription::TopK(TopKDescription {
+                    tensor: desc.tensor.to_relative(converter),
+                    k: desc.k,
+                    dim: desc.dim,
+                    out: desc.out.to_relative(converter),
+                    out_indices: desc.out_indices.to_relative(converter),
+                })
+            }
         }
     }
 }
@@ -63,6 +63,10 @@ pub enum FloatOperationDescription {
     Random(RandomOperationDescription),
     /// Operation corresponding to [recip](burn_tensor::ops::FloatTensorOps::float_recip).
     Recip(UnaryOperationDescription),
+    /// Operation corresponding to [sort with indices](burn_tensor::ops::FloatTensorOps::float_sort_with_indices).
+    SortWithIndices(SortDescription),
+    /// Operation corresponding to [topk](burn_tensor::ops::FloatTensorOps::float_topk).
+    TopK(TopKDescription),
 }
 
 /// Operation description specific to module.
@@ -561,6 +565,26 @@ pub struct ReduceDimWithIndicesDescription {
     pub out_indices: TensorDescription,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct SortDescription {
+    pub tensor: TensorDescription,
+    pub dim: usize,
+    pub descending: bool,
+    pub out: TensorDescription,
+    pub out_indices: TensorDescription,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct TopKDescription {
+    pub tensor: TensorDescription,
+    pub k: usize,
+    pub dim: usize,
+    pub out: TensorDescription,
+    pub out_indices: TensorDescription,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct EmbeddingDescription {
@@ -1084,6 +1108,12 @@ impl FloatOperationDescription {
             FloatOperationDescription::Sin(desc) => vec![&desc.input, &desc.out],
             FloatOperationDescription::Tanh(desc) => vec![&desc.input, &desc.out],
             FloatOperationDescription::IntoInt(desc) => vec![&desc.input, &desc.out],
+            FloatOperationDescription::SortWithIndices(desc) => {
+                vec![&desc.tensor, &desc.out, &desc.out_indices]
+            }
+            FloatOperationDescription::TopK(desc) => {
+                vec![&desc.tensor, &desc.out, &desc.out_indices]
+            }
         }
     }
 }
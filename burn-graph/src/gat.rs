@@ -0,0 +1,282 @@
+use burn_core as burn;
+
+use crate::{add_self_loops, split_edge_index, Aggregation, MessagePassing, MessagePassingConfig};
+use burn_core::config::Config;
+use burn_core::module::{Module, Param};
+use burn_core::nn::{Initializer, Linear, LinearConfig};
+use burn_tensor::backend::Backend;
+use burn_tensor::{Int, SegmentReduce, Tensor};
+
+/// Configuration to create a [graph attention](GatConv) layer.
+#[derive(Config, Debug)]
+pub struct GatConvConfig {
+    /// The size of the input features of each node.
+    pub d_input: usize,
+    /// The size of the output features of each node, for each head.
+    pub d_output: usize,
+    /// The number of attention heads.
+    #[config(default = 1)]
+    pub n_heads: usize,
+    /// If the outputs of the heads are concatenated, otherwise they are averaged.
+    #[config(default = true)]
+    pub concat: bool,
+    /// The negative slope of the leaky ReLU applied to the attention scores.
+    #[config(default = 0.2)]
+    pub negative_slope: f64,
+    /// The message passing of the layer.
+    #[config(default = "MessagePassingConfig::new()")]
+    pub message_passing: MessagePassingConfig,
+    /// If a bias should be added to the output features.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize the weight and the attention parameters.
+    #[config(default = "Initializer::XavierUniform{gain:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// The graph attention layer of the paper
+/// [Graph Attention Networks](https://arxiv.org/abs/1710.10903).
+///
+/// The features of the nodes are transformed with a shared weight `W`, and each node receives the
+/// features of its neighbors weighted by the attention coefficients
+///
+/// `α_ij = softmax_j(LeakyReLU(a_dst · W x_i + a_src · W x_j))`
+///
+/// the softmax being computed over the incoming edges of each node `i`, for each head.
+#[derive(Module, Debug)]
+pub struct GatConv<B: Backend> {
+    /// The linear transformation applied to the features of the nodes, without bias.
+    pub linear: Linear<B>,
+    /// The attention parameters of the source nodes, of shape `[n_heads, d_output]`.
+    pub att_src: Param<Tensor<B, 2>>,
+    /// The attention parameters of the target nodes, of shape `[n_heads, d_output]`.
+    pub att_dst: Param<Tensor<B, 2>>,
+    /// The bias added to the output features.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    n_heads: usize,
+    d_output: usize,
+    concat: bool,
+    negative_slope: f64,
+    aggregation: Aggregation,
+    add_self_loops: bool,
+}
+
+impl GatConvConfig {
+    /// Initialize a new [graph attention](GatConv) layer.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> GatConv<B> {
+        let d_heads = self.n_heads * self.d_output;
+        let linear = LinearConfig::new(self.d_input, d_heads)
+            .with_bias(false)
+            .with_initializer(self.initializer.clone())
+            .init(device);
+        let init_attention = || {
+            let shape = [self.n_heads, self.d_output];
+            let attention = self
+                .initializer
+                .init_with(shape, Some(self.d_output), Some(1), device);
+            Param::from(attention)
+        };
+        let bias = match self.bias {
+            true => {
+                let d_bias = match self.concat {
+                    true => d_heads,
+                    false => self.d_output,
+                };
+                Some(Param::from(Tensor::zeros([d_bias], device)))
+            }
+            false => None,
+        };
+
+        GatConv {
+            linear,
+            att_src: init_attention(),
+            att_dst: init_attention(),
+            bias,
+            n_heads: self.n_heads,
+            d_output: self.d_output,
+            concat: self.concat,
+            negative_slope: self.negative_slope,
+            aggregation: self.message_passing.aggregation.clone(),
+            add_self_loops: self.message_passing.add_self_loops,
+        }
+    }
+}
+
+impl<B: Backend> GatConv<B> {
+    /// Applies the forward pass on the features of the nodes.
+    ///
+    /// # Shapes
+    ///
+    /// - nodes: `[num_nodes, d_input]`
+    /// - edge_index: `[2, num_edges]`
+    /// - output: `[num_nodes, n_heads * d_output]` when the heads are concatenated, otherwise
+    ///   `[num_nodes, d_output]`
+    pub fn forward(&self, nodes: Tensor<B, 2>, edge_index: Tensor<B, 2, Int>) -> Tensor<B, 2> {
+        let [num_nodes, _] = nodes.dims();
+        let edge_index = match self.add_self_loops {
+            true => add_self_loops(edge_index, num_nodes),
+            false => edge_index,
+        };
+        let nodes = self.linear.forward(nodes);
+        let attention = self.attention(nodes.clone(), edge_index.clone());
+
+        self.propagate(nodes, edge_index, Some(attention))
+    }
+
+    /// The attention coefficients of each edge, of shape `[num_edges, n_heads]`.
+    fn attention(&self, nodes: Tensor<B, 2>, edge_index: Tensor<B, 2, Int>) -> Tensor<B, 2> {
+        let [num_nodes, _] = nodes.dims();
+        let (src, dst) = split_edge_index(edge_index);
+
+        let nodes = nodes.reshape([num_nodes, self.n_heads, self.d_output]);
+        let score = |att: &Param<Tensor<B, 2>>| {
+            nodes
+                .clone()
+                .mul(att.val().unsqueeze())
+                .sum_dim(2)
+                .reshape([num_nodes, self.n_heads])
+        };
+        let scores =
+            score(&self.att_src).select(0, src) + score(&self.att_dst).select(0, dst.clone());
+        let scores =
+            scores.clone().clamp_min(0.0) + scores.clamp_max(0.0).mul_scalar(self.negative_slope);
+
+        segment_softmax(scores, dst, num_nodes)
+    }
+}
+
+impl<B: Backend> MessagePassing<B> for GatConv<B> {
+    fn aggregation(&self) -> Aggregation {
+        self.aggregation.clone()
+    }
+
+    fn message(
+        &self,
+        src: Tensor<B, 2>,
+        _dst: Tensor<B, 2>,
+        edge: Option<Tensor<B, 2>>,
+    ) -> Tensor<B, 2> {
+        let Some(attention) = edge else {
+            return src;
+        };
+        let [num_edges, d_heads] = src.dims();
+
+        src.reshape([num_edges, self.n_heads, self.d_output])
+            .mul(attention.reshape([num_edges, self.n_heads, 1]))
+            .reshape([num_edges, d_heads])
+    }
+
+    fn update(&self, _node: Tensor<B, 2>, aggregated: Tensor<B, 2>) -> Tensor<B, 2> {
+        let [num_nodes, _] = aggregated.dims();
+        let output = match self.concat {
+            true => aggregated,
+            false => aggregated
+                .reshape([num_nodes, self.n_heads, self.d_output])
+                .mean_dim(1)
+                .reshape([num_nodes, self.d_output]),
+        };
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+/// The softmax of the scores over the edges of each segment, for each column.
+fn segment_softmax<B: Backend>(
+    scores: Tensor<B, 2>,
+    segment_ids: Tensor<B, 1, Int>,
+    num_segments: usize,
+) -> Tensor<B, 2> {
+    // The softmax is invariant to the shift, which is only there for numerical stability.
+    let max = scores.clone().detach().segment_reduce(
+        segment_ids.clone(),
+        num_segments,
+        SegmentReduce::Max,
+    );
+    let exp = scores.sub(max.select(0, segment_ids.clone())).exp();
+    let sum = exp
+        .clone()
+        .segment_reduce(segment_ids.clone(), num_segments, SegmentReduce::Sum);
+
+    exp.div(sum.select(0, segment_ids))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::{Data, Distribution};
+
+    #[test]
+    fn test_gat_uniform_attention_averages_the_neighbors() {
+        let device = Default::default();
+        let mut layer = GatConvConfig::new(1, 1).init::<TestBackend>(&device);
+        layer.linear.weight = Param::from(Tensor::ones([1, 1], &device));
+        layer.att_src = Param::from(Tensor::zeros([1, 1], &device));
+        layer.att_dst = Param::from(Tensor::zeros([1, 1], &device));
+        let nodes = Tensor::from_floats([[1.0], [2.0], [3.0]], &device);
+        let edge_index = Tensor::from_ints([[0, 1], [1, 2]], &device);
+
+        let output = layer.forward(nodes, edge_index);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.5], [2.5]]), 5);
+    }
+
+    #[test]
+    fn test_segment_softmax() {
+        let device = Default::default();
+        let scores = Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, 0.0], [2.0, 0.0], [3.0, 5.0], [100.0, -100.0]],
+            &device,
+        );
+        let segment_ids = Tensor::from_ints([0, 0, 1, 0], &device);
+
+        let attention = segment_softmax(scores, segment_ids, 2);
+
+        attention.into_data().assert_approx_eq(
+            &Data::from([[0.0, 0.5], [0.0, 0.5], [1.0, 1.0], [1.0, 0.0]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_gat_heads_output_shape() {
+        let device = Default::default();
+        let nodes = Tensor::<TestBackend, 2>::random([5, 4], Distribution::Default, &device);
+        let edge_index =
+            Tensor::<TestBackend, 2, Int>::from_ints([[0, 1, 2, 3], [1, 2, 3, 4]], &device);
+
+        let layer = GatConvConfig::new(4, 3).with_n_heads(2).init(&device);
+        let output = layer.forward(nodes.clone(), edge_index.clone());
+        assert_eq!(output.dims(), [5, 6]);
+
+        let layer = GatConvConfig::new(4, 3)
+            .with_n_heads(2)
+            .with_concat(false)
+            .init(&device);
+        let output = layer.forward(nodes, edge_index);
+        assert_eq!(output.dims(), [5, 3]);
+    }
+
+    #[test]
+    fn test_gat_gradients() {
+        let device = Default::default();
+        let layer = GatConvConfig::new(2, 3)
+            .with_n_heads(2)
+            .init::<TestAutodiffBackend>(&device);
+        let nodes = Tensor::random([4, 2], Distribution::Default, &device).require_grad();
+        let edge_index = Tensor::from_ints([[0, 1, 2, 0], [1, 2, 3, 2]], &device);
+
+        let output = layer.forward(nodes.clone(), edge_index);
+        let grads = output.powf_scalar(2.0).sum().backward();
+
+        assert!(nodes.grad(&grads).is_some());
+        assert!(layer.linear.weight.grad(&grads).is_some());
+        assert!(layer.att_src.grad(&grads).is_some());
+        assert!(layer.att_dst.grad(&grads).is_some());
+    }
+}
@@ -0,0 +1,200 @@
+use burn_core as burn;
+
+use crate::{add_self_loops, split_edge_index, Aggregation, MessagePassing, MessagePassingConfig};
+use burn_core::config::Config;
+use burn_core::module::{Module, Param};
+use burn_core::nn::{Initializer, Linear, LinearConfig};
+use burn_tensor::backend::Backend;
+use burn_tensor::{Int, SegmentReduce, Tensor};
+
+/// Configuration to create a [graph convolutional](GcnConv) layer.
+#[derive(Config, Debug)]
+pub struct GcnConvConfig {
+    /// The size of the input features of each node.
+    pub d_input: usize,
+    /// The size of the output features of each node.
+    pub d_output: usize,
+    /// The message passing of the layer.
+    #[config(default = "MessagePassingConfig::new()")]
+    pub message_passing: MessagePassingConfig,
+    /// If a bias should be added to the output features.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize the weight.
+    #[config(default = "Initializer::XavierUniform{gain:1.0}")]
+    pub initializer: Initializer,
+}
+
+/// The graph convolutional layer of the paper
+/// [Semi-Supervised Classification with Graph Convolutional Networks](https://arxiv.org/abs/1609.02907).
+///
+/// `X' = D^(-1/2) A D^(-1/2) X W + b`
+///
+/// where `A` is the adjacency matrix of the graph, with its self-loops, and `D` the diagonal
+/// matrix of the number of edges received by each node. The messages of the nodes without any
+/// incoming edge are dropped.
+#[derive(Module, Debug)]
+pub struct GcnConv<B: Backend> {
+    /// The linear transformation applied to the features of the nodes, without bias.
+    pub linear: Linear<B>,
+    /// The bias added to the output features, of shape `[d_output]`.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    aggregation: Aggregation,
+    add_self_loops: bool,
+}
+
+impl GcnConvConfig {
+    /// Initialize a new [graph convolutional](GcnConv) layer.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> GcnConv<B> {
+        let linear = LinearConfig::new(self.d_input, self.d_output)
+            .with_bias(false)
+            .with_initializer(self.initializer.clone())
+            .init(device);
+        let bias = match self.bias {
+            true => Some(Param::from(Tensor::zeros([self.d_output], device))),
+            false => None,
+        };
+
+        GcnConv {
+            linear,
+            bias,
+            aggregation: self.message_passing.aggregation.clone(),
+            add_self_loops: self.message_passing.add_self_loops,
+        }
+    }
+}
+
+impl<B: Backend> GcnConv<B> {
+    /// Applies the forward pass on the features of the nodes.
+    ///
+    /// # Shapes
+    ///
+    /// - nodes: `[num_nodes, d_input]`
+    /// - edge_index: `[2, num_edges]`
+    /// - output: `[num_nodes, d_output]`
+    pub fn forward(&self, nodes: Tensor<B, 2>, edge_index: Tensor<B, 2, Int>) -> Tensor<B, 2> {
+        let [num_nodes, _] = nodes.dims();
+        let edge_index = match self.add_self_loops {
+            true => add_self_loops(edge_index, num_nodes),
+            false => edge_index,
+        };
+        let norm = self.normalization(edge_index.clone(), num_nodes);
+
+        self.propagate(self.linear.forward(nodes), edge_index, Some(norm))
+    }
+
+    /// The normalization `1 / sqrt(deg(src) * deg(dst))` of each edge, of shape `[num_edges, 1]`.
+    fn normalization(&self, edge_index: Tensor<B, 2, Int>, num_nodes: usize) -> Tensor<B, 2> {
+        let [_, num_edges] = edge_index.dims();
+        let (src, dst) = split_edge_index(edge_index);
+
+        let ones = Tensor::<B, 2>::ones([num_edges, 1], &src.device());
+        let degree = ones.segment_reduce(dst.clone(), num_nodes, SegmentReduce::Sum);
+        // The nodes without incoming edges have a degree of zero, their messages being dropped.
+        let degree_inv_sqrt = degree
+            .clone()
+            .clamp_min(1.0)
+            .powf_scalar(-0.5)
+            .mul(degree.clamp_max(1.0));
+
+        degree_inv_sqrt
+            .clone()
+            .select(0, src)
+            .mul(degree_inv_sqrt.select(0, dst))
+    }
+}
+
+impl<B: Backend> MessagePassing<B> for GcnConv<B> {
+    fn aggregation(&self) -> Aggregation {
+        self.aggregation.clone()
+    }
+
+    fn message(
+        &self,
+        src: Tensor<B, 2>,
+        _dst: Tensor<B, 2>,
+        edge: Option<Tensor<B, 2>>,
+    ) -> Tensor<B, 2> {
+        match edge {
+            Some(norm) => src.mul(norm),
+            None => src,
+        }
+    }
+
+    fn update(&self, _node: Tensor<B, 2>, aggregated: Tensor<B, 2>) -> Tensor<B, 2> {
+        match &self.bias {
+            Some(bias) => aggregated + bias.val().unsqueeze(),
+            None => aggregated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::{Data, Distribution};
+
+    fn layer<B: Backend>(config: GcnConvConfig, device: &B::Device) -> GcnConv<B> {
+        let mut layer = config.init::<B>(device);
+        layer.linear.weight = Param::from(Tensor::ones([1, 1], device));
+        layer
+    }
+
+    #[test]
+    fn test_gcn_symmetric_normalization() {
+        let device = Default::default();
+        let layer = layer::<TestBackend>(GcnConvConfig::new(1, 1), &device);
+        let nodes = Tensor::from_floats([[1.0], [2.0], [3.0]], &device);
+        let edge_index = Tensor::from_ints([[0, 1], [1, 2]], &device);
+
+        let output = layer.forward(nodes, edge_index);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.70711], [2.5]]), 4);
+    }
+
+    #[test]
+    fn test_gcn_without_self_loops_drops_the_messages_of_sources() {
+        let device = Default::default();
+        let config = GcnConvConfig::new(1, 1)
+            .with_message_passing(MessagePassingConfig::new().with_add_self_loops(false));
+        let layer = layer::<TestBackend>(config, &device);
+        let nodes = Tensor::from_floats([[1.0], [2.0], [3.0]], &device);
+        let edge_index = Tensor::from_ints([[0, 1], [1, 2]], &device);
+
+        let output = layer.forward(nodes, edge_index);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0], [0.0], [2.0]]), 4);
+    }
+
+    #[test]
+    fn test_gcn_output_shape() {
+        let device = Default::default();
+        let layer = GcnConvConfig::new(4, 3).init::<TestBackend>(&device);
+        let nodes = Tensor::random([5, 4], Distribution::Default, &device);
+        let edge_index = Tensor::from_ints([[0, 1, 2, 3], [1, 2, 3, 4]], &device);
+
+        let output = layer.forward(nodes, edge_index);
+
+        assert_eq!(output.dims(), [5, 3]);
+    }
+
+    #[test]
+    fn test_gcn_gradients() {
+        let device = Default::default();
+        let layer = GcnConvConfig::new(2, 3).init::<TestAutodiffBackend>(&device);
+        let nodes = Tensor::random([4, 2], Distribution::Default, &device).require_grad();
+        let edge_index = Tensor::from_ints([[0, 1, 2], [1, 2, 3]], &device);
+
+        let output = layer.forward(nodes.clone(), edge_index);
+        let grads = output.sum().backward();
+
+        assert!(nodes.grad(&grads).is_some());
+        assert!(layer.linear.weight.grad(&grads).is_some());
+        assert!(layer.bias.unwrap().grad(&grads).is_some());
+    }
+}
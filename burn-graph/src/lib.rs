@@ -0,0 +1,24 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(missing_docs)]
+
+//! # Burn Graph
+//!
+//! This library provides graph neural network layers for the Burn project. The layers are built
+//! on the [message passing](MessagePassing) scheme: each edge sends a message from its source node
+//! to its target node, the messages received by each node being aggregated with a
+//! [segment reduction](burn_tensor::Tensor::segment_reduce) before updating the node.
+
+extern crate alloc;
+
+mod gat;
+mod gcn;
+mod message_passing;
+
+pub use gat::*;
+pub use gcn::*;
+pub use message_passing::*;
+
+#[cfg(test)]
+pub(crate) type TestBackend = burn_ndarray::NdArray<f32>;
+#[cfg(test)]
+pub(crate) type TestAutodiffBackend = burn_autodiff::Autodiff<TestBackend>;
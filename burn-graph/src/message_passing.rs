@@ -0,0 +1,274 @@
+use burn_core as burn;
+
+use alloc::vec;
+use burn_core::config::Config;
+use burn_core::module::Module;
+use burn_tensor::backend::Backend;
+use burn_tensor::{Int, SegmentReduce, Tensor};
+
+/// The aggregation of the messages received by each node.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum Aggregation {
+    /// The sum of the messages.
+    Sum,
+    /// The mean of the messages.
+    Mean,
+    /// The maximum of the messages.
+    Max,
+    /// The minimum of the messages.
+    Min,
+}
+
+impl From<Aggregation> for SegmentReduce {
+    fn from(aggregation: Aggregation) -> Self {
+        match aggregation {
+            Aggregation::Sum => SegmentReduce::Sum,
+            Aggregation::Mean => SegmentReduce::Mean,
+            Aggregation::Max => SegmentReduce::Max,
+            Aggregation::Min => SegmentReduce::Min,
+        }
+    }
+}
+
+/// Configuration of the [message passing](MessagePassing) of a graph layer.
+#[derive(Config, Debug)]
+pub struct MessagePassingConfig {
+    /// The aggregation of the messages received by each node.
+    #[config(default = "Aggregation::Sum")]
+    pub aggregation: Aggregation,
+    /// If a self-loop is added to each node, so that its own features are part of its messages.
+    #[config(default = true)]
+    pub add_self_loops: bool,
+}
+
+/// A graph layer following the message passing scheme of the paper
+/// [Neural Message Passing for Quantum Chemistry](https://arxiv.org/abs/1704.01212).
+///
+/// The graph is given by its edges, of shape `[2, num_edges]`, the first row being the source
+/// nodes and the second row the target nodes. [Propagating](MessagePassing::propagate) the node
+/// features:
+///
+/// 1. computes a [message](MessagePassing::message) for each edge from the features of its source
+///    and target nodes, and its own features when any;
+/// 2. [aggregates](MessagePassing::aggregate) the messages received by each target node;
+/// 3. [updates](MessagePassing::update) each node with its aggregated messages.
+///
+/// Every step is made of tensor operations, so that the layers are differentiable with the
+/// autodiff backend.
+pub trait MessagePassing<B: Backend> {
+    /// The aggregation of the messages received by each node.
+    fn aggregation(&self) -> Aggregation;
+
+    /// Computes the message sent along each edge.
+    ///
+    /// # Shapes
+    ///
+    /// - src: `[num_edges, d_node]`
+    /// - dst: `[num_edges, d_node]`
+    /// - edge: `[num_edges, d_edge]`
+    /// - output: `[num_edges, d_message]`
+    fn message(
+        &self,
+        src: Tensor<B, 2>,
+        dst: Tensor<B, 2>,
+        edge: Option<Tensor<B, 2>>,
+    ) -> Tensor<B, 2>;
+
+    /// Aggregates the messages received by each node, `index` being the target node of each
+    /// message. The nodes without any message receive zeros.
+    ///
+    /// # Shapes
+    ///
+    /// - messages: `[num_edges, d_message]`
+    /// - index: `[num_edges]`
+    /// - output: `[num_nodes, d_message]`
+    fn aggregate(
+        &self,
+        messages: Tensor<B, 2>,
+        index: Tensor<B, 1, Int>,
+        num_nodes: usize,
+    ) -> Tensor<B, 2> {
+        messages.segment_reduce(index, num_nodes, self.aggregation().into())
+    }
+
+    /// Updates each node with its aggregated messages, returning the aggregated messages by
+    /// default.
+    ///
+    /// # Shapes
+    ///
+    /// - node: `[num_nodes, d_node]`
+    /// - aggregated: `[num_nodes, d_message]`
+    /// - output: `[num_nodes, d_output]`
+    fn update(&self, node: Tensor<B, 2>, aggregated: Tensor<B, 2>) -> Tensor<B, 2> {
+        let _ = node;
+        aggregated
+    }
+
+    /// Propagates the features of the nodes along the edges.
+    ///
+    /// # Shapes
+    ///
+    /// - nodes: `[num_nodes, d_node]`
+    /// - edge_index: `[2, num_edges]`
+    /// - edges: `[num_edges, d_edge]`
+    /// - output: `[num_nodes, d_output]`
+    fn propagate(
+        &self,
+        nodes: Tensor<B, 2>,
+        edge_index: Tensor<B, 2, Int>,
+        edges: Option<Tensor<B, 2>>,
+    ) -> Tensor<B, 2> {
+        let [num_nodes, _] = nodes.dims();
+        let (src, dst) = split_edge_index(edge_index);
+
+        let messages = self.message(
+            nodes.clone().select(0, src),
+            nodes.clone().select(0, dst.clone()),
+            edges,
+        );
+        let aggregated = self.aggregate(messages, dst, num_nodes);
+
+        self.update(nodes, aggregated)
+    }
+}
+
+/// Adds a self-loop to each of the `num_nodes` nodes, after the edges of the graph.
+///
+/// # Shapes
+///
+/// - edge_index: `[2, num_edges]`
+/// - output: `[2, num_edges + num_nodes]`
+pub fn add_self_loops<B: Backend>(
+    edge_index: Tensor<B, 2, Int>,
+    num_nodes: usize,
+) -> Tensor<B, 2, Int> {
+    let loops = Tensor::<B, 1, Int>::arange(0..num_nodes as i64, &edge_index.device())
+        .unsqueeze::<2>()
+        .repeat(0, 2);
+
+    Tensor::cat(vec![edge_index, loops], 1)
+}
+
+/// Splits the edges into their source and target nodes.
+pub(crate) fn split_edge_index<B: Backend>(
+    edge_index: Tensor<B, 2, Int>,
+) -> (Tensor<B, 1, Int>, Tensor<B, 1, Int>) {
+    let [_, num_edges] = edge_index.dims();
+    let src = edge_index
+        .clone()
+        .slice([0..1, 0..num_edges])
+        .reshape([num_edges]);
+    let dst = edge_index.slice([1..2, 0..num_edges]).reshape([num_edges]);
+
+    (src, dst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Data;
+
+    struct MeanNeighbors {
+        aggregation: Aggregation,
+    }
+
+    impl<B: Backend> MessagePassing<B> for MeanNeighbors {
+        fn aggregation(&self) -> Aggregation {
+            self.aggregation.clone()
+        }
+
+        fn message(
+            &self,
+            src: Tensor<B, 2>,
+            _dst: Tensor<B, 2>,
+            edge: Option<Tensor<B, 2>>,
+        ) -> Tensor<B, 2> {
+            match edge {
+                Some(edge) => src.mul(edge),
+                None => src,
+            }
+        }
+
+        fn update(&self, node: Tensor<B, 2>, aggregated: Tensor<B, 2>) -> Tensor<B, 2> {
+            node + aggregated
+        }
+    }
+
+    #[test]
+    fn test_propagate_aggregates_the_messages_of_the_neighbors() {
+        let device = Default::default();
+        let nodes = Tensor::<TestBackend, 2>::from_floats([[1.0], [2.0], [4.0], [8.0]], &device);
+        let edge_index = Tensor::from_ints([[0, 1, 2], [2, 2, 1]], &device);
+
+        let layer = MeanNeighbors {
+            aggregation: Aggregation::Sum,
+        };
+        let output = layer.propagate(nodes.clone(), edge_index.clone(), None);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [6.0], [7.0], [8.0]]), 5);
+
+        let layer = MeanNeighbors {
+            aggregation: Aggregation::Mean,
+        };
+        let output = layer.propagate(nodes, edge_index, None);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [6.0], [5.5], [8.0]]), 5);
+    }
+
+    #[test]
+    fn test_propagate_with_edge_features() {
+        let device = Default::default();
+        let nodes = Tensor::<TestBackend, 2>::from_floats([[1.0], [2.0], [4.0]], &device);
+        let edge_index = Tensor::from_ints([[0, 1, 0], [2, 2, 1]], &device);
+        let edges = Tensor::from_floats([[3.0], [-1.0], [0.5]], &device);
+
+        let layer = MeanNeighbors {
+            aggregation: Aggregation::Max,
+        };
+        let output = layer.propagate(nodes, edge_index, Some(edges));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [2.5], [7.0]]), 5);
+    }
+
+    #[test]
+    fn test_propagate_gradients() {
+        let device = Default::default();
+        let nodes = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]],
+            &device,
+        )
+        .require_grad();
+        let edge_index = Tensor::from_ints([[0, 1, 2], [1, 2, 1]], &device);
+
+        let layer = MeanNeighbors {
+            aggregation: Aggregation::Sum,
+        };
+        let output = layer.propagate(nodes.clone(), edge_index, None);
+        let grads = output.sum().backward();
+
+        // Each node counts once for itself, plus once for each of its outgoing edges.
+        nodes
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0], [2.0, 2.0], [2.0, 2.0]]), 5);
+    }
+
+    #[test]
+    fn test_add_self_loops() {
+        let device = Default::default();
+        let edge_index = Tensor::<TestBackend, 2, Int>::from_ints([[0, 1], [1, 2]], &device);
+
+        let edge_index = add_self_loops(edge_index, 3);
+
+        assert_eq!(
+            edge_index.into_data(),
+            Data::from([[0, 1, 0, 1, 2], [1, 2, 0, 1, 2]])
+        );
+    }
+}
@@ -2,8 +2,9 @@ use super::{
     avg_pool2d::AvgPool2dNode, batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode,
     concat::ConcatNode, constant::ConstantNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
     conv_transpose_2d::ConvTranspose2dNode, dropout::DropoutNode, gather::GatherNode,
-    global_avg_pool::GlobalAvgPoolNode, linear::LinearNode, matmul::MatmulNode,
-    max_pool2d::MaxPool2dNode, reshape::ReshapeNode, unary::UnaryNode,
+    global_avg_pool::GlobalAvgPoolNode, group_norm::GroupNormNode, gru::GruNode,
+    linear::LinearNode, lstm::LstmNode, matmul::MatmulNode, max_pool2d::MaxPool2dNode,
+    reshape::ReshapeNode, select::SelectNode, slice::SliceNode, unary::UnaryNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::backend::NdArray;
@@ -72,6 +73,7 @@ pub trait NodeCodegen<PS: PrecisionSettings>: std::fmt::Debug {
 }
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum Node<PS: PrecisionSettings> {
     AvgPool2d(AvgPool2dNode),
     BatchNorm(BatchNormNode<PS>),
@@ -85,10 +87,15 @@ pub enum Node<PS: PrecisionSettings> {
     Dropout(DropoutNode),
     Gather(GatherNode),
     GlobalAvgPool(GlobalAvgPoolNode),
+    GroupNorm(GroupNormNode<PS>),
+    Gru(GruNode<PS>),
     Linear(LinearNode<PS>),
+    Lstm(LstmNode<PS>),
     Matmul(MatmulNode),
     MaxPool2d(MaxPool2dNode),
     Reshape(ReshapeNode),
+    Select(SelectNode),
+    Slice(SliceNode),
     Unary(UnaryNode),
 }
 
@@ -108,10 +115,15 @@ macro_rules! match_all {
             Node::Dropout(node) => $func(node),
             Node::Gather(node) => $func(node),
             Node::GlobalAvgPool(node) => $func(node),
+            Node::GroupNorm(node) => $func(node),
+            Node::Gru(node) => $func(node),
             Node::Linear(node) => $func(node),
+            Node::Lstm(node) => $func(node),
             Node::Matmul(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
             Node::Reshape(node) => $func(node),
+            Node::Select(node) => $func(node),
+            Node::Slice(node) => $func(node),
             Node::Unary(node) => $func(node),
         }
     }};
@@ -141,10 +153,15 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Dropout(_) => "dropout",
             Node::Gather(_) => "gather",
             Node::GlobalAvgPool(_) => "global_avg_pool",
+            Node::GroupNorm(_) => "group_norm",
+            Node::Gru(_) => "gru",
             Node::Linear(_) => "linear",
+            Node::Lstm(_) => "lstm",
             Node::Matmul(_) => "matmul",
             Node::MaxPool2d(_) => "max_pool2d",
             Node::Reshape(_) => "reshape",
+            Node::Select(_) => "select",
+            Node::Slice(_) => "slice",
             Node::Unary(unary) => unary.kind.as_str(),
         }
     }
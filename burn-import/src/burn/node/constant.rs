@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{ScalarKind, ScalarType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, ScalarKind, ScalarType, Scope, TensorType, ToTokens, Type};
 use burn::{
     module::ParamId,
     record::{ParamSerde, PrecisionSettings},
@@ -129,17 +129,26 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantNode<PS> {
                 let shape = tensor_type.clone().shape.unwrap().to_tokens();
                 let dim = tensor_type.clone().dim.to_tokens();
 
-                if with_record {
-                    Some(quote! {
+                // Only float tensors track gradients
+                match (&self.value, with_record) {
+                    (ConstantValue::Tensor(_, TensorValue::Float(_)), true) => Some(quote! {
                         let #name = record.#name.map(|tensor| tensor.set_require_grad(false));
-                    })
-                } else {
-                    Some(quote! {
+                    }),
+                    (_, true) => Some(quote! {
+                        let #name = record.#name;
+                    }),
+                    (ConstantValue::Tensor(_, TensorValue::Float(_)), false) => Some(quote! {
                         let #name: burn::module::Param<#ty> = burn::module::Param::new(
                             burn::module::ParamId::new(),
                             Tensor::<B, #dim>::zeros(#shape, device).set_require_grad(false),
                         );
-                    })
+                    }),
+                    (_, false) => Some(quote! {
+                        let #name: burn::module::Param<#ty> = burn::module::Param::new(
+                            burn::module::ParamId::new(),
+                            Tensor::<B, #dim, Int>::zeros(#shape, device),
+                        );
+                    }),
                 }
             }
             _ => None,
@@ -167,18 +176,29 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantNode<PS> {
         }
     }
 
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if let ConstantValue::Tensor(_, TensorValue::Int(_)) = self.value {
+            imports.register("burn::tensor::Int");
+        }
+    }
+
     fn into_node(self) -> Node<PS> {
         Node::Constant(self)
     }
 
     fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        if let ConstantValue::Tensor(_, ds) = &self.value {
-            let data: DataSerialize<PS::FloatElem> = match ds {
-                TensorValue::Float(data) => data.clone().convert(),
-                TensorValue::Int(data) => data.clone().convert(),
-            };
-            let data = ParamSerde::new(ParamId::new().into_string(), data);
-            return data.serialize(serializer);
+        match &self.value {
+            ConstantValue::Tensor(_, TensorValue::Float(data)) => {
+                let data: DataSerialize<PS::FloatElem> = data.clone().convert();
+                let data = ParamSerde::new(ParamId::new().into_string(), data);
+                return data.serialize(serializer);
+            }
+            ConstantValue::Tensor(_, TensorValue::Int(data)) => {
+                let data: DataSerialize<PS::IntElem> = data.clone().convert();
+                let data = ParamSerde::new(ParamId::new().into_string(), data);
+                return data.serialize(serializer);
+            }
+            _ => {}
         }
 
         S::serialize_none(serializer)
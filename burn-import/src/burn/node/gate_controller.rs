@@ -0,0 +1,39 @@
+use super::SerializationBackend;
+use burn::{
+    module::{Param, ParamId},
+    nn::{GateControllerRecord, LinearRecord},
+    record::PrecisionSettings,
+    tensor::{DataSerialize, Tensor},
+};
+
+/// The weights of a gate of a recurrent module, in the layout of the [Linear](burn::nn::Linear)
+/// module: `[d_input, d_output]`.
+#[derive(Debug, Clone, new)]
+pub struct GateControllerData<PS: PrecisionSettings> {
+    pub input_weight: DataSerialize<PS::FloatElem>,
+    pub input_bias: Option<DataSerialize<PS::FloatElem>>,
+    pub hidden_weight: DataSerialize<PS::FloatElem>,
+    pub hidden_bias: Option<DataSerialize<PS::FloatElem>>,
+}
+
+impl<PS: PrecisionSettings> GateControllerData<PS> {
+    pub fn into_record(self) -> GateControllerRecord<SerializationBackend> {
+        GateControllerRecord {
+            input_transform: linear_record::<PS>(self.input_weight, self.input_bias),
+            hidden_transform: linear_record::<PS>(self.hidden_weight, self.hidden_bias),
+        }
+    }
+}
+
+fn linear_record<PS: PrecisionSettings>(
+    weight: DataSerialize<PS::FloatElem>,
+    bias: Option<DataSerialize<PS::FloatElem>>,
+) -> LinearRecord<SerializationBackend> {
+    let device = Default::default();
+
+    LinearRecord {
+        weight: Param::new(ParamId::new(), Tensor::from_data(weight.convert(), &device)),
+        bias: bias
+            .map(|bias| Param::new(ParamId::new(), Tensor::from_data(bias.convert(), &device))),
+    }
+}
@@ -0,0 +1,281 @@
+use super::{gate_controller::GateControllerData, Node, NodeCodegen};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::ConstantRecord,
+    nn::gru::{GruConfig, GruRecord},
+    record::{PrecisionSettings, Record},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Node for the ONNX GRU operation, in the forward direction.
+///
+/// The outputs follow the ONNX layout: `[seq_length, 1, batch_size, hidden_size]` for the hidden
+/// states and `[1, batch_size, hidden_size]` for the last hidden state, or
+/// `[batch_size, seq_length, 1, hidden_size]` and `[batch_size, 1, hidden_size]` when the batch
+/// comes first.
+#[derive(Debug, Clone)]
+pub struct GruNode<PS: PrecisionSettings> {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub initial_hidden: Option<TensorType>,
+    pub output: TensorType,
+    pub output_hidden: Option<TensorType>,
+    pub update_gate: GateControllerData<PS>,
+    pub reset_gate: GateControllerData<PS>,
+    pub new_gate: GateControllerData<PS>,
+    pub batch_first: bool,
+    pub config: GruConfig,
+}
+
+impl<PS: PrecisionSettings> GruNode<PS> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        initial_hidden: Option<TensorType>,
+        output: TensorType,
+        output_hidden: Option<TensorType>,
+        gates: [GateControllerData<PS>; 3],
+        batch_first: bool,
+        config: GruConfig,
+    ) -> Self {
+        let [update_gate, reset_gate, new_gate] = gates;
+
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    Gru<B>
+                },
+            ),
+            input,
+            initial_hidden,
+            output,
+            output_hidden,
+            update_gate,
+            reset_gate,
+            new_gate,
+            batch_first,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GruNode<PS> {
+    fn input_types(&self) -> Vec<Type> {
+        [Some(&self.input), self.initial_hidden.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|input| Type::Tensor(input.clone()))
+            .collect()
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [Some(&self.output), self.output_hidden.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|output| Type::Tensor(output.clone()))
+            .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self, with_record: bool) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.d_input.to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let bias = self.config.bias;
+
+        let init_line = match with_record {
+            true => quote! {
+                init_with(record.#name);
+            },
+            false => quote! {
+                init(device);
+            },
+        };
+
+        let tokens = quote! {
+            let #name = GruConfig::new(#d_input, #d_hidden, #bias)
+                .#init_line
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let record = GruRecord {
+            update_gate: self.update_gate.clone().into_record(),
+            reset_gate: self.reset_gate.clone().into_record(),
+            new_gate: self.new_gate.clone().into_record(),
+            d_hidden: ConstantRecord::new(),
+        };
+
+        let item = Record::into_item::<PS>(record);
+        item.serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let field = &self.field.name;
+
+        // The direction axis of the ONNX states and outputs
+        let (input, direction_axis) = match self.batch_first {
+            true => (input, 1usize.to_tokens()),
+            false => (quote! { #input.swap_dims(0, 1) }, 0usize.to_tokens()),
+        };
+
+        let state = match &self.initial_hidden {
+            Some(hidden) => {
+                let hidden = scope.tensor_use_owned(hidden, node_position);
+
+                quote! { Some(#hidden.squeeze(#direction_axis)) }
+            }
+            None => quote! { None },
+        };
+
+        let output = &self.output.name;
+        let (hidden_states, output_hidden) = match &self.output_hidden {
+            Some(output_hidden) => (quote! { hidden_states.clone() }, Some(output_hidden)),
+            None => (quote! { hidden_states }, None),
+        };
+        let value = match self.batch_first {
+            true => quote! { #hidden_states.unsqueeze_dim::<4>(2) },
+            false => quote! { #hidden_states.swap_dims(0, 1).unsqueeze_dim::<4>(1) },
+        };
+
+        match output_hidden {
+            Some(output_hidden) => {
+                let output_hidden_name = &output_hidden.name;
+                let last_state = match self.batch_first {
+                    true => quote! {
+                        hidden_states.slice([0..batch_size, seq_length - 1..seq_length, 0..d_hidden])
+                    },
+                    false => quote! {
+                        hidden_states
+                            .slice([0..batch_size, seq_length - 1..seq_length, 0..d_hidden])
+                            .swap_dims(0, 1)
+                    },
+                };
+
+                quote! {
+                    let (#output, #output_hidden_name) = {
+                        let hidden_states = self.#field.forward(#input, #state);
+                        let [batch_size, seq_length, d_hidden] = hidden_states.dims();
+
+                        (#value, #last_state)
+                    };
+                }
+            }
+            None => quote! {
+                let #output = {
+                    let hidden_states = self.#field.forward(#input, #state);
+
+                    #value
+                };
+            },
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::gru::Gru");
+        imports.register("burn::nn::gru::GruConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Gru(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{record::FullPrecisionSettings, tensor::Data};
+
+    fn gate() -> GateControllerData<FullPrecisionSettings> {
+        GateControllerData::new(
+            Data::from([[2.]]).serialize(),
+            None,
+            Data::from([[2.]]).serialize(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GruNode::new(
+            "gru",
+            TensorType::new_float("input", 3),
+            Some(TensorType::new_float("hidden", 3)),
+            TensorType::new_float("output", 4),
+            Some(TensorType::new_float("output_hidden", 3)),
+            [gate(), gate(), gate()],
+            false,
+            GruConfig::new(1, 1, false),
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "hidden".to_string()],
+            vec!["output".to_string(), "output_hidden".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::gru::Gru;
+            use burn::nn::gru::GruConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                gru: Gru<B>,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let gru = GruConfig::new(1, 1, false)
+                        .init_with(record.gru);
+
+                    Self {
+                        gru,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 3>,
+                    hidden: Tensor<B, 3>
+                ) -> (Tensor<B, 4>, Tensor<B, 3>) {
+                    let (output, output_hidden) = {
+                        let hidden_states = self
+                            .gru
+                            .forward(input.swap_dims(0, 1), Some(hidden.squeeze(0)));
+                        let [batch_size, seq_length, d_hidden] = hidden_states.dims();
+
+                        (
+                            hidden_states.clone().swap_dims(0, 1).unsqueeze_dim::<4>(1),
+                            hidden_states
+                                .slice([0..batch_size, seq_length - 1..seq_length, 0..d_hidden])
+                                .swap_dims(0, 1)
+                        )
+                    };
+
+                    (output, output_hidden)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,392 @@
+use super::{gate_controller::GateControllerData, Node, NodeCodegen};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::ConstantRecord,
+    nn::{LstmConfig, LstmRecord},
+    record::{PrecisionSettings, Record},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Node for the ONNX LSTM operation, in the forward direction.
+///
+/// The outputs follow the ONNX layout: `[seq_length, 1, batch_size, hidden_size]` for the hidden
+/// states and `[1, batch_size, hidden_size]` for the last hidden and cell states, or
+/// `[batch_size, seq_length, 1, hidden_size]` and `[batch_size, 1, hidden_size]` when the batch
+/// comes first.
+#[derive(Debug, Clone)]
+pub struct LstmNode<PS: PrecisionSettings> {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub initial_state: Option<(TensorType, TensorType)>,
+    pub output: TensorType,
+    pub output_hidden: Option<TensorType>,
+    pub output_cell: Option<TensorType>,
+    pub input_gate: GateControllerData<PS>,
+    pub forget_gate: GateControllerData<PS>,
+    pub output_gate: GateControllerData<PS>,
+    pub cell_gate: GateControllerData<PS>,
+    pub batch_first: bool,
+    pub config: LstmConfig,
+}
+
+impl<PS: PrecisionSettings> LstmNode<PS> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        initial_state: Option<(TensorType, TensorType)>,
+        output: TensorType,
+        output_hidden: Option<TensorType>,
+        output_cell: Option<TensorType>,
+        gates: [GateControllerData<PS>; 4],
+        batch_first: bool,
+        config: LstmConfig,
+    ) -> Self {
+        let [input_gate, forget_gate, output_gate, cell_gate] = gates;
+
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    Lstm<B>
+                },
+            ),
+            input,
+            initial_state,
+            output,
+            output_hidden,
+            output_cell,
+            input_gate,
+            forget_gate,
+            output_gate,
+            cell_gate,
+            batch_first,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for LstmNode<PS> {
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![Type::Tensor(self.input.clone())];
+
+        if let Some((cell, hidden)) = &self.initial_state {
+            inputs.push(Type::Tensor(hidden.clone()));
+            inputs.push(Type::Tensor(cell.clone()));
+        }
+
+        inputs
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [
+            Some(&self.output),
+            self.output_hidden.as_ref(),
+            self.output_cell.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|output| Type::Tensor(output.clone()))
+        .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self, with_record: bool) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.d_input.to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let bias = self.config.bias;
+
+        let init_line = match with_record {
+            true => quote! {
+                init_with(record.#name);
+            },
+            false => quote! {
+                init(device);
+            },
+        };
+
+        let tokens = quote! {
+            let #name = LstmConfig::new(#d_input, #d_hidden, #bias)
+                .#init_line
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let record = LstmRecord {
+            input_gate: self.input_gate.clone().into_record(),
+            forget_gate: self.forget_gate.clone().into_record(),
+            output_gate: self.output_gate.clone().into_record(),
+            cell_gate: self.cell_gate.clone().into_record(),
+            d_hidden: ConstantRecord::new(),
+        };
+
+        let item = Record::into_item::<PS>(record);
+        item.serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let field = &self.field.name;
+
+        // The direction axis of the ONNX states and outputs
+        let (input, direction_axis) = match self.batch_first {
+            true => (input, 1usize.to_tokens()),
+            false => (quote! { #input.swap_dims(0, 1) }, 0usize.to_tokens()),
+        };
+
+        let state = match &self.initial_state {
+            Some((cell, hidden)) => {
+                let hidden = scope.tensor_use_owned(hidden, node_position);
+                let cell = scope.tensor_use_owned(cell, node_position);
+
+                quote! {
+                    Some((#cell.squeeze(#direction_axis), #hidden.squeeze(#direction_axis)))
+                }
+            }
+            None => quote! { None },
+        };
+
+        let last_state = |states: TokenStream| match self.batch_first {
+            true => quote! {
+                #states.slice([0..batch_size, seq_length - 1..seq_length, 0..d_hidden])
+            },
+            false => quote! {
+                #states
+                    .slice([0..batch_size, seq_length - 1..seq_length, 0..d_hidden])
+                    .swap_dims(0, 1)
+            },
+        };
+
+        let mut outputs = vec![&self.output.name];
+        let mut values = Vec::new();
+
+        let hidden_states = match self.output_hidden.is_some() {
+            true => quote! { hidden_states.clone() },
+            false => quote! { hidden_states },
+        };
+        values.push(match self.batch_first {
+            true => quote! { #hidden_states.unsqueeze_dim::<4>(2) },
+            false => quote! { #hidden_states.swap_dims(0, 1).unsqueeze_dim::<4>(1) },
+        });
+
+        if let Some(output_hidden) = &self.output_hidden {
+            outputs.push(&output_hidden.name);
+            values.push(last_state(quote! { hidden_states }));
+        }
+
+        let cell_states = match &self.output_cell {
+            Some(output_cell) => {
+                outputs.push(&output_cell.name);
+                values.push(last_state(quote! { cell_states }));
+                quote! { cell_states }
+            }
+            None => quote! { _ },
+        };
+
+        let dims = match self.output_hidden.is_some() || self.output_cell.is_some() {
+            true => quote! {
+                let [batch_size, seq_length, d_hidden] = hidden_states.dims();
+            },
+            false => quote! {},
+        };
+
+        let value = match values.len() {
+            1 => quote! { #(#values)* },
+            _ => quote! { (#(#values),*) },
+        };
+        let output = match outputs.len() {
+            1 => quote! { #(#outputs)* },
+            _ => quote! { (#(#outputs),*) },
+        };
+
+        quote! {
+            let #output = {
+                let (#cell_states, hidden_states) = self.#field.forward(#input, #state);
+                #dims
+
+                #value
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::Lstm");
+        imports.register("burn::nn::LstmConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Lstm(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{record::FullPrecisionSettings, tensor::Data};
+
+    fn gate() -> GateControllerData<FullPrecisionSettings> {
+        GateControllerData::new(
+            Data::from([[2.]]).serialize(),
+            Some(Data::from([2.]).serialize()),
+            Data::from([[2.]]).serialize(),
+            Some(Data::from([2.]).serialize()),
+        )
+    }
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LstmNode::new(
+            "lstm",
+            TensorType::new_float("input", 3),
+            Some((
+                TensorType::new_float("cell", 3),
+                TensorType::new_float("hidden", 3),
+            )),
+            TensorType::new_float("output", 4),
+            Some(TensorType::new_float("output_hidden", 3)),
+            Some(TensorType::new_float("output_cell", 3)),
+            [gate(), gate(), gate(), gate()],
+            false,
+            LstmConfig::new(1, 1, true),
+        ));
+
+        graph.register_input_output(
+            vec![
+                "input".to_string(),
+                "hidden".to_string(),
+                "cell".to_string(),
+            ],
+            vec![
+                "output".to_string(),
+                "output_hidden".to_string(),
+                "output_cell".to_string(),
+            ],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::Lstm;
+            use burn::nn::LstmConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                lstm: Lstm<B>,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let lstm = LstmConfig::new(1, 1, true)
+                        .init_with(record.lstm);
+
+                    Self {
+                        lstm,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 3>,
+                    hidden: Tensor<B, 3>,
+                    cell: Tensor<B, 3>
+                ) -> (Tensor<B, 4>, Tensor<B, 3>, Tensor<B, 3>) {
+                    let (output, output_hidden, output_cell) = {
+                        let (cell_states, hidden_states) = self
+                            .lstm
+                            .forward(input.swap_dims(0, 1), Some((cell.squeeze(0), hidden.squeeze(0))));
+                        let [batch_size, seq_length, d_hidden] = hidden_states.dims();
+
+                        (
+                            hidden_states.clone().swap_dims(0, 1).unsqueeze_dim::<4>(1),
+                            hidden_states
+                                .slice([0..batch_size, seq_length - 1..seq_length, 0..d_hidden])
+                                .swap_dims(0, 1),
+                            cell_states
+                                .slice([0..batch_size, seq_length - 1..seq_length, 0..d_hidden])
+                                .swap_dims(0, 1)
+                        )
+                    };
+
+                    (output, output_hidden, output_cell)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_batch_first_without_states() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LstmNode::new(
+            "lstm",
+            TensorType::new_float("input", 3),
+            None,
+            TensorType::new_float("output", 4),
+            None,
+            None,
+            [gate(), gate(), gate(), gate()],
+            true,
+            LstmConfig::new(1, 1, true),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::Lstm;
+            use burn::nn::LstmConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                lstm: Lstm<B>,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let lstm = LstmConfig::new(1, 1, true)
+                        .init_with(record.lstm);
+
+                    Self {
+                        lstm,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 4> {
+                    let output = {
+                        let (_, hidden_states) = self.lstm.forward(input, None);
+
+                        hidden_states.unsqueeze_dim::<4>(2)
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -10,12 +10,18 @@ pub(crate) mod conv1d;
 pub(crate) mod conv2d;
 pub(crate) mod conv_transpose_2d;
 pub(crate) mod dropout;
+pub(crate) mod gate_controller;
 pub(crate) mod gather;
 pub(crate) mod global_avg_pool;
+pub(crate) mod group_norm;
+pub(crate) mod gru;
 pub(crate) mod linear;
+pub(crate) mod lstm;
 pub(crate) mod matmul;
 pub(crate) mod max_pool2d;
 pub(crate) mod reshape;
+pub(crate) mod select;
+pub(crate) mod slice;
 pub(crate) mod unary;
 
 pub(crate) use base::*;
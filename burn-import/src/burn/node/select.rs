@@ -0,0 +1,181 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Node for the ONNX Gather operation, selecting the entries of the input along an axis.
+///
+/// A scalar index removes the axis from the output, like the ONNX specification.
+#[derive(Debug, Clone, new)]
+pub struct SelectNode {
+    pub input: TensorType,
+    pub index: Type,
+    pub output: TensorType,
+    pub dim: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SelectNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone()), self.index.clone()]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let dim = self.dim.to_tokens();
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+
+        match &self.index {
+            Type::Tensor(index) => {
+                let index = scope.tensor_use_owned(index, node_position);
+
+                quote! {
+                    let #output = #input.select(#dim, #index);
+                }
+            }
+            Type::Scalar(index) => {
+                let index = &index.name;
+                let input_name = &self.input.name;
+                let output_dim = self.output.dim.to_tokens();
+
+                quote! {
+                    let #output = {
+                        let index = Tensor::<B, 1, Int>::from_ints([#index as i32], &#input_name.device());
+                        #input.select(#dim, index).squeeze::<#output_dim>(#dim)
+                    };
+                }
+            }
+            _ => panic!("Gather: the index must be a tensor or a scalar"),
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if let Type::Scalar(_) = self.index {
+            imports.register("burn::tensor::Int");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Select(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{select::SelectNode, test::assert_tokens},
+        ScalarKind, ScalarType, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_select() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SelectNode::new(
+            TensorType::new_float("tensor1", 2),
+            Type::Tensor(TensorType::new_int("tensor2", 1)),
+            TensorType::new_float("tensor3", 2),
+            1,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 1, Int>
+                ) -> Tensor<B, 2> {
+                    let tensor3 = tensor1.select(1, tensor2);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_select_scalar() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SelectNode::new(
+            TensorType::new_float("tensor1", 3),
+            Type::Scalar(ScalarType::new("scalar1", ScalarKind::Int64)),
+            TensorType::new_float("tensor2", 2),
+            1,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "scalar1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>, scalar1: i64) -> Tensor<B, 2> {
+                    let tensor2 = {
+                        let index = Tensor::<B, 1, Int>::from_ints([scalar1 as i32], &tensor1.device());
+                        tensor1.select(1, index).squeeze::<2>(1)
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
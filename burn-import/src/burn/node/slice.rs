@@ -0,0 +1,206 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Node for the ONNX Slice operation.
+///
+/// Each range is the axis being sliced with its start and end. Negative bounds are counted from
+/// the end of the axis and an end bigger than `i32::MAX` means the end of the axis, like the
+/// ONNX specification.
+#[derive(Debug, Clone, new)]
+pub struct SliceNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub ranges: Vec<(usize, i64, i64)>,
+}
+
+impl SliceNode {
+    /// The bounds of each axis up to the last one being sliced, and if the dimensions of the
+    /// input are required to compute them.
+    fn bounds(&self) -> (Vec<TokenStream>, bool) {
+        let num_axes = self
+            .ranges
+            .iter()
+            .map(|(axis, _, _)| axis + 1)
+            .max()
+            .unwrap_or(0);
+
+        let mut use_dims = false;
+        let mut bound = |axis: usize, value: i64| {
+            let axis = axis.to_tokens();
+
+            if value < 0 {
+                use_dims = true;
+                let value = (-value as usize).to_tokens();
+                quote! { dims[#axis] - #value }
+            } else if value >= i32::MAX as i64 {
+                use_dims = true;
+                quote! { dims[#axis] }
+            } else {
+                (value as usize).to_tokens()
+            }
+        };
+
+        let bounds = (0..num_axes)
+            .map(|axis| {
+                match self
+                    .ranges
+                    .iter()
+                    .find(|(range_axis, _, _)| *range_axis == axis)
+                {
+                    Some((_, start, end)) => {
+                        let start = bound(axis, *start);
+                        let end = bound(axis, *end);
+                        quote! { #start..#end }
+                    }
+                    None => {
+                        let end = bound(axis, i64::MAX);
+                        quote! { 0..#end }
+                    }
+                }
+            })
+            .collect();
+
+        (bounds, use_dims)
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SliceNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let (bounds, use_dims) = self.bounds();
+
+        if use_dims {
+            let input_name = &self.input.name;
+            let dim = self.input.dim.to_tokens();
+
+            quote! {
+                let #output = {
+                    let dims: [usize; #dim] = #input_name.dims();
+                    #input.slice([#(#bounds),*])
+                };
+            }
+        } else {
+            quote! {
+                let #output = #input.slice([#(#bounds),*]);
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Slice(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{slice::SliceNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_slice() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SliceNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            vec![(0, 0, 2), (1, 1, 3)],
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = tensor1.slice([0..2, 1..3]);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_slice_from_the_end() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SliceNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            vec![(1, 1, i64::MAX), (2, -3, -1)],
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = {
+                        let dims: [usize; 3] = tensor1.dims();
+                        tensor1.slice([0..dims[0], 1..dims[1], dims[2] - 3..dims[2] - 1])
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -1,6 +1,6 @@
 use std::{iter::Peekable, slice::IterMut};
 
-use super::ir::{AttributeValue, Node, NodeType};
+use super::ir::{Node, NodeType};
 use crate::onnx::ir::{ArgType, Data, TensorType};
 
 /// The function transforms the graph into a new one where the nodes are coalesced into a single node.
@@ -26,34 +26,136 @@ pub fn coalesce(nodes: &mut Vec<Node>) {
 /// This function converts a Gemm node into a Linear node
 ///
 /// PyTorch and other frameworks use Gemm node to represent Linear layer.
+///
+/// Gemm computes `alpha * A * B + beta * C`, where the weights `B` and the bias `C` must be
+/// constants. The scaling factors are folded into the weights and the bias of the Linear node.
 fn convert_gemm_to_linear(node: &mut Node) {
     if node.outputs.len() != 1 {
         panic!("Gemm node must have 1 output");
     }
-    let straight_linear = match (
-        node.attrs.get("alpha"),
-        node.attrs.get("beta"),
-        node.attrs.get("transB"),
-    ) {
-        (
-            Some(AttributeValue::Float32(alpha)),
-            Some(AttributeValue::Float32(beta)),
-            Some(AttributeValue::Int64(trans_b)),
-        ) => *alpha == 1.0 && *beta == 1.0 && *trans_b == 1,
-        _ => false,
-    };
 
-    if straight_linear {
-        node.node_type = NodeType::Linear;
-        node.attrs.remove("alpha");
-        node.attrs.remove("beta");
-        node.attrs.remove("transB");
+    // Default: alpha = beta = 1 and no transposition per ONNX spec
+    let mut alpha = 1.0;
+    let mut beta = 1.0;
+    let mut trans_a = 0;
+    let mut trans_b = 0;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "alpha" => alpha = value.clone().into_f32(),
+            "beta" => beta = value.clone().into_f32(),
+            "transA" => trans_a = value.clone().into_i64(),
+            "transB" => trans_b = value.clone().into_i64(),
+            _ => {}
+        }
+    }
 
-        // Transpose the weights
+    if trans_a != 0 {
+        panic!("Gemm: transposing the input (transA) is not supported");
+    }
+
+    if node.inputs.len() < 2 || node.inputs[1].value.is_none() {
+        panic!("Gemm: the weights (B) must be constant");
+    }
+
+    node.node_type = NodeType::Linear;
+    node.attrs.remove("alpha");
+    node.attrs.remove("beta");
+    node.attrs.remove("transA");
+    node.attrs.remove("transB");
+
+    // Transpose the weights when they are stored as [out_features, in_features]
+    if trans_b == 1 {
         transpose_linear_node_weights(node);
-    } else {
-        panic!("Full Gemm node not supported yet.");
     }
+
+    if alpha != 1.0 {
+        scale_node_input(node, 1, alpha);
+    }
+
+    if node.inputs.len() > 2 {
+        assert!(
+            node.inputs[2].value.is_some(),
+            "Gemm: the bias (C) must be constant"
+        );
+
+        reshape_linear_node_bias(node);
+
+        if beta != 1.0 {
+            scale_node_input(node, 2, beta);
+        }
+    }
+}
+
+/// Reshape the bias of a Linear node converted from a Gemm node to `[out_features]`.
+///
+/// Gemm supports unidirectional broadcasting of the bias, but only the bias shared by all the
+/// rows of the output can be represented by a Linear node.
+fn reshape_linear_node_bias(node: &mut Node) {
+    let out_features = match &node.inputs[1].ty {
+        ArgType::Tensor(weight) => weight.shape.as_ref().unwrap()[1],
+        _ => panic!("Tensor input is expected"),
+    };
+
+    let (elem_type, num_elements) = match &node.inputs[2].ty {
+        ArgType::Scalar(elem_type) => (elem_type.clone(), 1),
+        ArgType::Tensor(bias) => {
+            let shape = bias.shape.clone().unwrap();
+            let rows = shape.iter().rev().skip(1).product::<usize>();
+            if rows != 1 {
+                panic!("Gemm: only a bias shared by all the rows of the output is supported");
+            }
+
+            (bias.elem_type.clone(), shape.iter().product())
+        }
+        _ => panic!("Gemm: the bias must be a tensor or a scalar"),
+    };
+
+    // Broadcast the bias along the output features
+    if num_elements == 1 {
+        let bias = node.inputs[2].value.take().unwrap();
+        node.inputs[2].value = Some(match bias {
+            Data::Float16(value) => Data::Float16s(vec![value; out_features]),
+            Data::Float16s(data) => Data::Float16s(vec![data[0]; out_features]),
+            Data::Float32(value) => Data::Float32s(vec![value; out_features]),
+            Data::Float32s(data) => Data::Float32s(vec![data[0]; out_features]),
+            Data::Float64(value) => Data::Float64s(vec![value; out_features]),
+            Data::Float64s(data) => Data::Float64s(vec![data[0]; out_features]),
+            _ => panic!("Only float types are supported for Linear node"),
+        });
+    } else if num_elements != out_features {
+        panic!("Gemm: the bias must have {} elements", out_features);
+    }
+
+    node.inputs[2].ty = ArgType::Tensor(TensorType {
+        shape: Some(vec![out_features]),
+        elem_type,
+        dim: 1,
+    });
+}
+
+/// Multiply the constant values of an input by a factor.
+fn scale_node_input(node: &mut Node, input_index: usize, factor: f32) {
+    let data = node.inputs[input_index]
+        .value
+        .take()
+        .expect("Input must have a value");
+
+    node.inputs[input_index].value = Some(match data {
+        Data::Float16s(data) => {
+            let factor = half::f16::from_f32(factor);
+            Data::Float16s(data.into_iter().map(|value| value * factor).collect())
+        }
+        Data::Float32s(data) => {
+            Data::Float32s(data.into_iter().map(|value| value * factor).collect())
+        }
+        Data::Float64s(data) => Data::Float64s(
+            data.into_iter()
+                .map(|value| value * factor as f64)
+                .collect(),
+        ),
+        _ => panic!("Only float types are supported for Linear node"),
+    });
 }
 
 // Transpose linear weights (required for Gemm -> Linear conversion)
@@ -79,12 +79,16 @@ pub fn dim_inference(
             NodeType::Exp => same_as_input(node),
             NodeType::Flatten => flatten_update_outputs(node),
             NodeType::Gelu => same_as_input(node),
+            NodeType::Gather => gather_update_outputs(node),
             NodeType::GatherElements => same_as_input(node),
             NodeType::GlobalAveragePool => same_as_input(node),
+            NodeType::GRU => rnn_update_outputs(node),
+            NodeType::InstanceNormalization => same_as_input(node),
             NodeType::ConvTranspose2d => conv_transpose2d_update_outputs(node),
             NodeType::Linear => linear_update_outputs(node),
             NodeType::Log => same_as_input(node),
             NodeType::LogSoftmax => same_as_input(node),
+            NodeType::LSTM => rnn_update_outputs(node),
             NodeType::MaxPool2d => same_as_input(node),
             NodeType::Mul => same_as_input(node),
             NodeType::Neg => same_as_input(node),
@@ -94,6 +98,7 @@ pub fn dim_inference(
             NodeType::Reshape => reshape_update_outputs(node),
             NodeType::Shape => shape_update_outputs(node),
             NodeType::Sigmoid => same_as_input(node),
+            NodeType::Slice => same_as_input(node),
             NodeType::Softmax => same_as_input(node),
             NodeType::Sqrt => same_as_input(node),
             NodeType::Sub => same_as_input(node),
@@ -312,6 +317,49 @@ fn unsqueeze_update_outputs(node: &mut Node) {
     }
 }
 
+/// Infers the rank of the output of a Gather node, the indexed axis being replaced by the
+/// dimensions of the indices.
+fn gather_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Gather: only tensor input is valid"),
+    };
+
+    let index_dim = match &node.inputs[1].ty {
+        ArgType::Tensor(index) => index.dim,
+        ArgType::Scalar(_) => 0,
+        _ => panic!("Gather: only tensor or scalar indices are valid"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim: tensor.dim + index_dim - 1,
+        shape: None,
+        ..tensor
+    });
+}
+
+/// Infers the rank of the outputs of a LSTM or GRU node: the hidden states of each step and the
+/// last hidden (and cell) states.
+fn rnn_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Only tensor input is valid"),
+    };
+
+    for (index, output) in node.outputs.iter_mut().enumerate() {
+        let dim = match index {
+            0 => 4,
+            _ => 3,
+        };
+
+        output.ty = ArgType::Tensor(TensorType {
+            dim,
+            shape: None,
+            ..tensor.clone()
+        });
+    }
+}
+
 fn same_as_input(node: &mut Node) {
     node.outputs[0].ty = node.inputs[0].ty.clone();
 }
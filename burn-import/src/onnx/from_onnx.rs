@@ -15,13 +15,18 @@ use super::protos::{ModelProto, TensorProto};
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 6] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 11] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
     NodeType::Dropout,
+    NodeType::Gemm,
+    NodeType::GRU,
+    NodeType::InstanceNormalization,
+    NodeType::LSTM,
     NodeType::Reshape,
+    NodeType::Slice,
 ];
 
 /// Open an onnx file and convert it to a Graph (intermediate representation)
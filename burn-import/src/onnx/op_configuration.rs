@@ -1,11 +1,13 @@
 use burn::nn::{
     conv::Conv1dConfig,
     conv::{Conv2dConfig, ConvTranspose2dConfig},
+    gru::GruConfig,
     pool::{AvgPool2dConfig, MaxPool2dConfig},
-    BatchNormConfig, DropoutConfig, LinearConfig, PaddingConfig1d, PaddingConfig2d,
+    BatchNormConfig, DropoutConfig, GroupNormConfig, LinearConfig, LstmConfig, PaddingConfig1d,
+    PaddingConfig2d,
 };
 
-use super::ir::{ArgType, AttributeValue, Data, Node};
+use super::ir::{ArgType, Argument, AttributeValue, Data, Node};
 
 /// Create a Conv1dConfig from the attributes of the node
 pub fn conv1d_config(curr: &Node) -> Conv1dConfig {
@@ -591,6 +593,220 @@ pub fn clip_config(node: &Node) -> (Option<f64>, Option<f64>) {
     (min_result, max_result)
 }
 
+/// Create the slice ranges from the attributes or the inputs of the node
+///
+/// Each entry is the axis being sliced with its start and end, as defined in the ONNX
+/// specification: negative values are counted from the end of the axis.
+pub fn slice_config(node: &Node) -> Vec<(usize, i64, i64)> {
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("Slice: only tensor input is valid"),
+    };
+
+    let mut starts = None;
+    let mut ends = None;
+    let mut axes = None;
+
+    // Opset 1 to 9 store the ranges as attributes
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "starts" => starts = Some(value.clone().into_i64s()),
+            "ends" => ends = Some(value.clone().into_i64s()),
+            "axes" => axes = Some(value.clone().into_i64s()),
+            _ => {}
+        }
+    }
+
+    // Opset 10+ store the ranges as inputs
+    let input_ints = |index: usize| {
+        node.inputs
+            .get(index)
+            .and_then(|input| input.value.clone())
+            .map(|value| match value {
+                Data::Int64s(values) => values,
+                Data::Int32s(values) => values.into_iter().map(|value| value as i64).collect(),
+                Data::Int64(value) => vec![value],
+                Data::Int32(value) => vec![value as i64],
+                _ => panic!("Slice: the ranges must be integers"),
+            })
+    };
+
+    let starts = starts
+        .or_else(|| input_ints(1))
+        .expect("Slice: starts must be constant");
+    let ends = ends
+        .or_else(|| input_ints(2))
+        .expect("Slice: ends must be constant");
+    let axes = axes
+        .or_else(|| input_ints(3))
+        .unwrap_or_else(|| (0..starts.len() as i64).collect());
+
+    if let Some(steps) = input_ints(4) {
+        if steps.iter().any(|step| *step != 1) {
+            panic!("Slice: only a step of 1 is supported (got {:?})", steps);
+        }
+    }
+
+    if starts.len() != ends.len() || starts.len() != axes.len() {
+        panic!("Slice: starts, ends and axes must have the same length");
+    }
+
+    axes.into_iter()
+        .zip(starts.into_iter().zip(ends))
+        .map(|(axis, (start, end))| {
+            // if axis is negative, it is counted from the end
+            let axis = if axis < 0 { axis + rank as i64 } else { axis };
+
+            (axis as usize, start, end)
+        })
+        .collect()
+}
+
+/// Create a GroupNormConfig equivalent to the InstanceNormalization node, each channel
+/// being its own group
+pub fn instance_norm_config(node: &Node) -> GroupNormConfig {
+    // extract the number of channels from the scale tensor
+    let tensor_type = if let ArgType::Tensor(ref tensor_type) = node.inputs[1].ty {
+        tensor_type
+    } else {
+        panic!("InstanceNorm: scale tensor must be present");
+    };
+
+    let num_channels: usize = tensor_type.shape.clone().unwrap()[0];
+
+    // Default: 1e-5 per ONNX spec
+    let mut epsilon = 1e-5f32;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "epsilon" => epsilon = value.clone().into_f32(),
+            _ => {}
+        }
+    }
+
+    GroupNormConfig::new(num_channels, num_channels).with_epsilon(epsilon as f64)
+}
+
+/// Create a LstmConfig from the attributes of the node
+pub fn lstm_config(node: &Node) -> LstmConfig {
+    check_rnn_attributes(node, &["Sigmoid", "Tanh", "Tanh"]);
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "input_forget" if value.clone().into_i64() != 0 => {
+                panic!("LSTM: coupling the input and forget gates is not supported")
+            }
+            _ => {}
+        }
+    }
+
+    // the peepholes are not supported by the Lstm module
+    if node.inputs.get(7).is_some_and(is_present) {
+        panic!("LSTM: peephole connections are not supported");
+    }
+
+    let (d_input, d_hidden, bias) = rnn_dims(node, 4);
+
+    LstmConfig::new(d_input, d_hidden, bias)
+}
+
+/// Create a GruConfig from the attributes of the node
+pub fn gru_config(node: &Node) -> GruConfig {
+    check_rnn_attributes(node, &["Sigmoid", "Tanh"]);
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            // The Gru module applies the reset gate before the hidden transformation
+            "linear_before_reset" if value.clone().into_i64() != 0 => {
+                panic!(
+                    "GRU: applying the reset gate after the linear transformation is not supported"
+                )
+            }
+            _ => {}
+        }
+    }
+
+    let (d_input, d_hidden, bias) = rnn_dims(node, 3);
+
+    GruConfig::new(d_input, d_hidden, bias)
+}
+
+/// The layout of the inputs and outputs of a recurrent node, `true` when the batch comes first.
+pub fn rnn_batch_first(node: &Node) -> bool {
+    match node.attrs.get("layout") {
+        Some(layout) => layout.clone().into_i64() == 1,
+        None => false,
+    }
+}
+
+/// Whether an optional input of a node is provided.
+pub fn is_present(input: &Argument) -> bool {
+    input.passed || input.value.is_some()
+}
+
+/// Check that the attributes of a recurrent node are supported by the Burn modules.
+fn check_rnn_attributes(node: &Node, default_activations: &[&str]) {
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "direction" => {
+                let direction = value.clone().into_string();
+                if direction != "forward" {
+                    panic!(
+                        "{:?}: only the forward direction is supported (got {})",
+                        node.node_type, direction
+                    );
+                }
+            }
+            "activations" => {
+                let activations = value.clone().into_strings();
+                if activations != default_activations {
+                    panic!(
+                        "{:?}: only the default activations are supported (got {:?})",
+                        node.node_type, activations
+                    );
+                }
+            }
+            "clip" => panic!("{:?}: clipping the cell is not supported", node.node_type),
+            _ => {}
+        }
+    }
+
+    // the sequence lengths are the fifth input of the recurrent nodes
+    if node.inputs.get(4).is_some_and(is_present) {
+        panic!(
+            "{:?}: sequences with different lengths are not supported",
+            node.node_type
+        );
+    }
+}
+
+/// Extract the input size, the hidden size and the presence of a bias of a recurrent node from
+/// its weights of shape `[num_directions, num_gates * hidden_size, input_size]`.
+fn rnn_dims(node: &Node, num_gates: usize) -> (usize, usize, bool) {
+    let weight = if let ArgType::Tensor(ref weight) = node.inputs[1].ty {
+        weight
+    } else {
+        panic!("{:?}: weight tensor must be present", node.node_type);
+    };
+
+    let shape = weight.shape.clone().unwrap();
+    let (d_input, d_hidden) = (shape[2], shape[1] / num_gates);
+
+    if let Some(hidden_size) = node.attrs.get("hidden_size") {
+        assert_eq!(
+            hidden_size.clone().into_i64() as usize,
+            d_hidden,
+            "{:?}: the hidden size doesn't match the weight tensor",
+            node.node_type
+        );
+    }
+
+    // check if the bias is present
+    let bias = node.inputs.get(3).is_some_and(|bias| bias.value.is_some());
+
+    (d_input, d_hidden, bias)
+}
+
 /// Calculate the padding configuration for a 1D operations such as Convolution and Pooling.
 ///
 /// # Arguments
@@ -23,12 +23,18 @@ use crate::{
             conv2d::Conv2dNode,
             conv_transpose_2d::ConvTranspose2dNode,
             dropout::DropoutNode,
+            gate_controller::GateControllerData,
             gather::GatherNode,
             global_avg_pool::GlobalAvgPoolNode,
+            group_norm::GroupNormNode,
+            gru::GruNode,
             linear::LinearNode,
+            lstm::LstmNode,
             matmul::MatmulNode,
             max_pool2d::MaxPool2dNode,
             reshape::ReshapeNode,
+            select::SelectNode,
+            slice::SliceNode,
             unary::UnaryNode,
         },
         ScalarKind, ScalarType, TensorKind, TensorType, Type,
@@ -246,6 +252,7 @@ impl ONNXGraph {
                 NodeType::Relu => graph.register(Self::relu_conversion(node)),
                 NodeType::Gelu => graph.register(Self::gelu_conversion(node)),
                 NodeType::Flatten => graph.register(Self::flatten_conversion(node)),
+                NodeType::Gather => graph.register(Self::select_conversion(node)),
                 NodeType::GatherElements => graph.register(Self::gather_conversion(node)),
                 NodeType::Log => graph.register(Self::log_conversion(node)),
                 NodeType::LogSoftmax => graph.register(Self::log_softmax_conversion(node)),
@@ -267,6 +274,12 @@ impl ONNXGraph {
                     graph.register(Self::conv_transpose2d_conversion(node))
                 }
                 NodeType::Pow => graph.register(Self::pow_conversion(node)),
+                NodeType::Slice => graph.register(Self::slice_conversion(node)),
+                NodeType::InstanceNormalization => {
+                    graph.register(Self::instance_norm_conversion::<PS>(node))
+                }
+                NodeType::LSTM => graph.register(Self::lstm_conversion::<PS>(node)),
+                NodeType::GRU => graph.register(Self::gru_conversion::<PS>(node)),
                 _ => panic!("Unsupported node conversion {}", node.node_type),
             }
         }
@@ -433,6 +446,29 @@ impl ONNXGraph {
         GatherNode::new(input, index, output, dim)
     }
 
+    fn select_conversion(node: Node) -> SelectNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let index = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let dim = gather_config(&node);
+
+        if let Type::Tensor(ref index) = index {
+            if index.dim != 1 {
+                panic!("Gather: only scalar or 1D indices are supported");
+            }
+        }
+
+        SelectNode::new(input, index, output, dim)
+    }
+
+    fn slice_conversion(node: Node) -> SliceNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let ranges = slice_config(&node);
+
+        SliceNode::new(input, output, ranges)
+    }
+
     fn transpose_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
@@ -570,6 +606,79 @@ impl ONNXGraph {
         )
     }
 
+    fn instance_norm_conversion<PS: PrecisionSettings>(node: Node) -> GroupNormNode<PS> {
+        let config = instance_norm_config(&node);
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+
+        let gamma = extract_data_serialize::<PS::FloatElem>(1, &node).expect("Scale is required");
+        let beta = extract_data_serialize::<PS::FloatElem>(2, &node).expect("Bias is required");
+
+        let name = &node.name;
+
+        GroupNormNode::new(name, input, output, Some(gamma), Some(beta), config)
+    }
+
+    fn lstm_conversion<PS: PrecisionSettings>(node: Node) -> LstmNode<PS> {
+        let config = lstm_config(&node);
+        let batch_first = rnn_batch_first(&node);
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let output_hidden = node.outputs.get(1).map(Argument::to_tensor_type);
+        let output_cell = node.outputs.get(2).map(Argument::to_tensor_type);
+
+        let initial_state = match (
+            rnn_initial_state(&node, 6), // initial_c
+            rnn_initial_state(&node, 5), // initial_h
+        ) {
+            (Some(cell), Some(hidden)) => Some((cell, hidden)),
+            (None, None) => None,
+            _ => panic!("LSTM: the initial hidden and cell states must be provided together"),
+        };
+
+        // The gates are stored in the order input, output, forget and cell
+        let [input_gate, output_gate, forget_gate, cell_gate] = rnn_gates::<PS, 4>(&node);
+
+        let name = &node.name;
+
+        LstmNode::new(
+            name,
+            input,
+            initial_state,
+            output,
+            output_hidden,
+            output_cell,
+            [input_gate, forget_gate, output_gate, cell_gate],
+            batch_first,
+            config,
+        )
+    }
+
+    fn gru_conversion<PS: PrecisionSettings>(node: Node) -> GruNode<PS> {
+        let config = gru_config(&node);
+        let batch_first = rnn_batch_first(&node);
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let output_hidden = node.outputs.get(1).map(Argument::to_tensor_type);
+        let initial_hidden = rnn_initial_state(&node, 5);
+
+        // The gates are stored in the order update, reset and new (hidden)
+        let gates = rnn_gates::<PS, 3>(&node);
+
+        let name = &node.name;
+
+        GruNode::new(
+            name,
+            input,
+            initial_hidden,
+            output,
+            output_hidden,
+            gates,
+            batch_first,
+            config,
+        )
+    }
+
     fn conv1d_conversion<PS: PrecisionSettings>(node: Node) -> Conv1dNode<PS> {
         let input = node.inputs.first().unwrap().to_tensor_type();
         let output = node.outputs.first().unwrap().to_tensor_type();
@@ -715,6 +824,78 @@ fn extract_data_serialize<E: Element>(input_index: usize, node: &Node) -> Option
     }
 }
 
+/// Extract the weights of each gate of a recurrent node.
+///
+/// The input weights `W` and the hidden weights `R` have the shape
+/// `[num_directions, num_gates * hidden_size, size]` and the biases `B` the shape
+/// `[num_directions, 2 * num_gates * hidden_size]`, the biases of `W` coming first.
+fn rnn_gates<PS: PrecisionSettings, const N: usize>(node: &Node) -> [GateControllerData<PS>; N] {
+    let input_weights =
+        extract_data_serialize::<PS::FloatElem>(1, node).expect("Input weights are required");
+    let hidden_weights =
+        extract_data_serialize::<PS::FloatElem>(2, node).expect("Hidden weights are required");
+    let biases = extract_data_serialize::<PS::FloatElem>(3, node);
+
+    core::array::from_fn(|gate| {
+        GateControllerData::new(
+            gate_weight(&input_weights, gate, N),
+            biases.as_ref().map(|biases| gate_bias(biases, gate, 2 * N)),
+            gate_weight(&hidden_weights, gate, N),
+            biases
+                .as_ref()
+                .map(|biases| gate_bias(biases, N + gate, 2 * N)),
+        )
+    })
+}
+
+/// Extract the weights of a gate, transposed to the `[d_input, d_output]` layout of the Linear
+/// module.
+fn gate_weight<E: Element>(
+    weights: &DataSerialize<E>,
+    gate: usize,
+    num_gates: usize,
+) -> DataSerialize<E> {
+    let (d_output, d_input) = (weights.shape[1] / num_gates, weights.shape[2]);
+    let offset = gate * d_output * d_input;
+
+    let mut value = Vec::with_capacity(d_output * d_input);
+    for i in 0..d_input {
+        for o in 0..d_output {
+            value.push(weights.value[offset + o * d_input + i]);
+        }
+    }
+
+    DataSerialize::new(value, vec![d_input, d_output])
+}
+
+fn gate_bias<E: Element>(
+    biases: &DataSerialize<E>,
+    index: usize,
+    num_biases: usize,
+) -> DataSerialize<E> {
+    let d_output = biases.shape[1] / num_biases;
+    let value = biases.value[index * d_output..(index + 1) * d_output].to_vec();
+
+    DataSerialize::new(value, vec![d_output])
+}
+
+/// The initial state of a recurrent node, if provided.
+fn rnn_initial_state(node: &Node, input_index: usize) -> Option<TensorType> {
+    let input = node
+        .inputs
+        .get(input_index)
+        .filter(|input| is_present(input))?;
+
+    if input.value.is_some() {
+        panic!(
+            "{:?}: constant initial states are not supported",
+            node.node_type
+        );
+    }
+
+    Some(input.to_tensor_type())
+}
+
 /// Convert data to `DataSerialize`.
 fn serialize_data<E: Element>(data: Data, shape: Vec<usize>) -> DataSerialize<E> {
     match data {
@@ -1,9 +1,12 @@
 use alloc::vec::Vec;
+use burn_tensor::sort_compare;
 use burn_tensor::Data;
 use burn_tensor::ElementConversion;
 use core::{marker::PhantomData, ops::Range};
 use ndarray::s;
+use ndarray::Array;
 use ndarray::Array2;
+use ndarray::ArrayViewMut1;
 use ndarray::Zip;
 
 use burn_tensor::Shape;
@@ -51,6 +54,21 @@ where
         NdArrayTensor { array }
     }
 
+    pub fn expand<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        shape: Shape<D>,
+    ) -> NdArrayTensor<E, D> {
+        // The owned arrays can't have a stride of 0, so the broadcast view is copied once.
+        let array = tensor
+            .array
+            .broadcast(shape.dims.to_vec())
+            .expect("The tensor should be broadcastable to the shape")
+            .to_owned()
+            .into_shared();
+
+        NdArrayTensor { array }
+    }
+
     pub fn reshape<const D1: usize, const D2: usize>(
         tensor: NdArrayTensor<E, D1>,
         shape: Shape<D2>,
@@ -322,17 +340,27 @@ where
         mask: NdArrayTensor<bool, D>,
         source: NdArrayTensor<E, D>,
     ) -> NdArrayTensor<E, D> {
-        let mask_mul_4tensor = mask.array.mapv(|x| match x {
-            true => 0.elem(),
-            false => 1.elem(),
-        });
-        let mask_mul_4source = mask.array.mapv(|x| match x {
-            true => 1.elem(),
-            false => 0.elem(),
-        });
-        let array = (tensor.array * mask_mul_4tensor) + (source.array * mask_mul_4source);
+        let shape = broadcast_shape(&[
+            tensor.array.shape(),
+            mask.array.shape(),
+            source.array.shape(),
+        ]);
+        let tensor = tensor.array.broadcast(shape.clone()).unwrap();
+        let mask = mask.array.broadcast(shape.clone()).unwrap();
+        let source = source.array.broadcast(shape).unwrap();
+
+        // The elements are selected instead of being multiplied by the mask, so that the values
+        // which aren't finite are replaced too.
+        let array =
+            Zip::from(&tensor)
+                .and(&mask)
+                .and(&source)
+                .map_collect(|&value, &mask, &source| match mask {
+                    true => source,
+                    false => value,
+                });
 
-        NdArrayTensor::new(array)
+        NdArrayTensor::new(array.into_shared())
     }
 
     pub fn mask_fill<const D: usize>(
@@ -340,17 +368,18 @@ where
         mask: NdArrayTensor<bool, D>,
         value: E,
     ) -> NdArrayTensor<E, D> {
-        let mask_mul = mask.array.mapv(|x| match x {
-            true => 0.elem(),
-            false => 1.elem(),
-        });
-        let mask_add = mask.array.mapv(|x| match x {
-            true => value,
-            false => 0.elem(),
-        });
-        let array = (tensor.array * mask_mul) + mask_add;
+        let shape = broadcast_shape(&[tensor.array.shape(), mask.array.shape()]);
+        let tensor = tensor.array.broadcast(shape.clone()).unwrap();
+        let mask = mask.array.broadcast(shape).unwrap();
 
-        NdArrayTensor::new(array)
+        let array = Zip::from(&tensor)
+            .and(&mask)
+            .map_collect(|&current, &mask| match mask {
+                true => value,
+                false => current,
+            });
+
+        NdArrayTensor::new(array.into_shared())
     }
 
     fn gather_batch_size<const D: usize>(
@@ -421,6 +450,86 @@ where
         arg(tensor, dim, CmpType::Min)
     }
 
+    pub fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr += prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cumprod<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr = *curr * prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn logcumsumexp<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| {
+            let (prev, value): (f64, f64) = (prev.elem(), curr.elem());
+            let max = libm::fmax(prev, value);
+
+            if max != f64::NEG_INFINITY {
+                *curr = (max + libm::log1p(libm::exp(-libm::fabs(prev - value)))).elem();
+            }
+        });
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn sort_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        let mut values = tensor.array.into_owned();
+        let mut indices = Array::<i64, IxDyn>::zeros(values.raw_dim());
+
+        // Each lane along the dimension is sorted independently.
+        let zip = Zip::from(values.lanes_mut(Axis(dim))).and(indices.lanes_mut(Axis(dim)));
+        let sort = |values, indices| sort_lane(values, indices, descending);
+
+        #[cfg(feature = "std")]
+        zip.par_for_each(sort);
+
+        #[cfg(not(feature = "std"))]
+        zip.for_each(sort);
+
+        (
+            NdArrayTensor::new(values.into_shared()),
+            NdArrayTensor::new(indices.into_shared()),
+        )
+    }
+
+    pub fn fft<const D: usize>(
+        real: NdArrayTensor<E, D>,
+        imag: NdArrayTensor<E, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        let mut real = real.array.into_owned();
+        let mut imag = imag.array.into_owned();
+
+        // Each lane along the dimension is transformed independently.
+        let zip = Zip::from(real.lanes_mut(Axis(dim))).and(imag.lanes_mut(Axis(dim)));
+        let fft = |real, imag| fft_lane(real, imag, inverse);
+
+        #[cfg(feature = "std")]
+        zip.par_for_each(fft);
+
+        #[cfg(not(feature = "std"))]
+        zip.for_each(fft);
+
+        (
+            NdArrayTensor::new(real.into_shared()),
+            NdArrayTensor::new(imag.into_shared()),
+        )
+    }
+
     pub fn clamp_min<const D: usize>(
         mut tensor: NdArrayTensor<E, D>,
         min: E,
@@ -482,6 +591,115 @@ where
     }
 }
 
+fn sort_lane<E: NdArrayElement>(
+    mut values: ArrayViewMut1<E>,
+    mut indices: ArrayViewMut1<i64>,
+    descending: bool,
+) {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|a, b| sort_compare(&values[*a], &values[*b], descending));
+
+    let sorted: Vec<E> = order.iter().map(|index| values[*index]).collect();
+
+    for (position, (index, value)) in order.into_iter().zip(sorted).enumerate() {
+        values[position] = value;
+        indices[position] = index as i64;
+    }
+}
+
+/// Computes the discrete Fourier transform of a lane in place, with a radix-2 fast Fourier
+/// transform when its size is a power of two.
+fn fft_lane<E: NdArrayElement>(
+    mut real: ArrayViewMut1<E>,
+    mut imag: ArrayViewMut1<E>,
+    inverse: bool,
+) {
+    let size = real.len();
+    let mut values: Vec<(f64, f64)> = real
+        .iter()
+        .zip(imag.iter())
+        .map(|(re, im)| (re.elem::<f64>(), im.elem::<f64>()))
+        .collect();
+
+    let sign = match inverse {
+        true => 1.0,
+        false => -1.0,
+    };
+
+    match size.is_power_of_two() {
+        true => radix_2_fft(&mut values, sign),
+        false => values = dft(&values, sign),
+    }
+
+    let scale = match inverse {
+        true => 1.0 / size as f64,
+        false => 1.0,
+    };
+
+    for (position, (re, im)) in values.into_iter().enumerate() {
+        real[position] = (re * scale).elem();
+        imag[position] = (im * scale).elem();
+    }
+}
+
+/// Iterative Cooley-Tukey transform, computing `sum_n x[n] e^(sign 2i pi k n / N)`.
+fn radix_2_fft(values: &mut [(f64, f64)], sign: f64) {
+    let size = values.len();
+    let bits = size.trailing_zeros();
+
+    if size < 2 {
+        return;
+    }
+
+    for index in 0..size {
+        let reversed = index.reverse_bits() >> (usize::BITS - bits);
+        if index < reversed {
+            values.swap(index, reversed);
+        }
+    }
+
+    let mut half = 1;
+    while half < size {
+        let step = sign * core::f64::consts::PI / half as f64;
+
+        for start in (0..size).step_by(2 * half) {
+            for offset in 0..half {
+                let (sin, cos) = libm::sincos(step * offset as f64);
+                let (even_re, even_im) = values[start + offset];
+                let (odd_re, odd_im) = values[start + offset + half];
+                let twiddled = (odd_re * cos - odd_im * sin, odd_re * sin + odd_im * cos);
+
+                values[start + offset] = (even_re + twiddled.0, even_im + twiddled.1);
+                values[start + offset + half] = (even_re - twiddled.0, even_im - twiddled.1);
+            }
+        }
+
+        half *= 2;
+    }
+}
+
+/// Direct transform for sizes that aren't a power of two, computing
+/// `sum_n x[n] e^(sign 2i pi k n / N)`.
+fn dft(values: &[(f64, f64)], sign: f64) -> Vec<(f64, f64)> {
+    let size = values.len();
+
+    (0..size)
+        .map(|k| {
+            values
+                .iter()
+                .enumerate()
+                .fold((0.0, 0.0), |(acc_re, acc_im), (n, (re, im))| {
+                    // The angle is computed modulo the size to keep its precision.
+                    let angle =
+                        sign * 2.0 * core::f64::consts::PI * ((k * n) % size) as f64 / size as f64;
+                    let (sin, cos) = libm::sincos(angle);
+
+                    (acc_re + re * cos - im * sin, acc_im + re * sin + im * cos)
+                })
+        })
+        .collect()
+}
+
 enum CmpType {
     Min,
     Max,
@@ -520,6 +738,18 @@ fn arg<E: NdArrayElement, const D: usize>(
     }
 }
 
+/// The shape of the arrays broadcast together, the arrays having the same number of dimensions.
+fn broadcast_shape(shapes: &[&[usize]]) -> IxDyn {
+    let mut shape = shapes[0].to_vec();
+    for other in &shapes[1..] {
+        for (dim, size) in shape.iter_mut().zip(other.iter()) {
+            *dim = usize::max(*dim, *size);
+        }
+    }
+
+    IxDyn(&shape)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,6 +45,13 @@ impl<E: FloatNdArrayElement> BoolTensorOps<Self> for NdArray<E> {
         tensor
     }
 
+    fn bool_expand<const D: usize>(
+        tensor: NdArrayTensor<bool, D>,
+        shape: Shape<D>,
+    ) -> NdArrayTensor<bool, D> {
+        NdArrayOps::expand(tensor, shape)
+    }
+
     fn bool_reshape<const D1: usize, const D2: usize>(
         tensor: NdArrayTensor<bool, D1>,
         shape: Shape<D2>,
@@ -114,6 +121,36 @@ impl<E: FloatNdArrayElement> BoolTensorOps<Self> for NdArray<E> {
         NdArrayTensor { array }
     }
 
+    fn bool_and<const D: usize>(
+        lhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+        rhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <NdArray<E> as Backend>::BoolTensorPrimitive<D> {
+        let mut array = lhs.array;
+        array.zip_mut_with(&rhs.array, |a, b| *a = *a && *b);
+
+        NdArrayTensor { array }
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+        rhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <NdArray<E> as Backend>::BoolTensorPrimitive<D> {
+        let mut array = lhs.array;
+        array.zip_mut_with(&rhs.array, |a, b| *a = *a || *b);
+
+        NdArrayTensor { array }
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+        rhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <NdArray<E> as Backend>::BoolTensorPrimitive<D> {
+        let mut array = lhs.array;
+        array.zip_mut_with(&rhs.array, |a, b| *a = *a != *b);
+
+        NdArrayTensor { array }
+    }
+
     fn bool_into_float<const D: usize>(
         tensor: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
     ) -> <NdArray<E> as Backend>::FloatTensorPrimitive<D> {
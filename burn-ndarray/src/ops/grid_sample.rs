@@ -0,0 +1,126 @@
+use crate::{
+    element::FloatNdArrayElement, iter_range_par, run_par, sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+use burn_tensor::{
+    ops::{GridSampleMode, GridSamplePaddingMode},
+    ElementConversion,
+};
+use ndarray::Array4;
+
+pub(crate) fn grid_sample<E: FloatNdArrayElement>(
+    input: NdArrayTensor<E, 4>,
+    grid: NdArrayTensor<E, 4>,
+    mode: GridSampleMode,
+    padding_mode: GridSamplePaddingMode,
+    align_corners: bool,
+) -> NdArrayTensor<E, 4> {
+    let [batch_size, channels, input_height, input_width] = input.shape().dims;
+    let [_, output_height, output_width, _] = grid.shape().dims;
+
+    let input = input.array;
+    let grid = grid.array;
+    let mut output = Array4::from_elem(
+        (batch_size, channels, output_height, output_width),
+        0.elem(),
+    );
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+            let value = |y: i64, x: i64| -> f64 {
+                match (0..input_height as i64).contains(&y) && (0..input_width as i64).contains(&x)
+                {
+                    true => input[[b, c, y as usize, x as usize]].elem(),
+                    false => 0.0,
+                }
+            };
+
+            for oh in 0..output_height {
+                for ow in 0..output_width {
+                    let x = source_index(
+                        grid[[b, oh, ow, 0]].elem(),
+                        input_width,
+                        padding_mode,
+                        align_corners,
+                    );
+                    let y = source_index(
+                        grid[[b, oh, ow, 1]].elem(),
+                        input_height,
+                        padding_mode,
+                        align_corners,
+                    );
+
+                    let sum_val = match mode {
+                        GridSampleMode::Nearest => {
+                            value(libm::rint(y) as i64, libm::rint(x) as i64)
+                        }
+                        GridSampleMode::Bilinear => {
+                            let x0 = libm::floor(x);
+                            let y0 = libm::floor(y);
+                            let (tx, ty) = (x - x0, y - y0);
+                            let (x0, y0) = (x0 as i64, y0 as i64);
+
+                            value(y0, x0) * (1.0 - tx) * (1.0 - ty)
+                                + value(y0, x0 + 1) * tx * (1.0 - ty)
+                                + value(y0 + 1, x0) * (1.0 - tx) * ty
+                                + value(y0 + 1, x0 + 1) * tx * ty
+                        }
+                    };
+
+                    output[[b, c, oh, ow]] = sum_val.elem();
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+/// Maps a normalized coordinate of the grid to a position in the input, following the PyTorch
+/// convention.
+fn source_index(
+    coordinate: f64,
+    size: usize,
+    padding_mode: GridSamplePaddingMode,
+    align_corners: bool,
+) -> f64 {
+    let size_f = size as f64;
+    let coordinate = match align_corners {
+        true => (coordinate + 1.0) / 2.0 * (size_f - 1.0),
+        false => ((coordinate + 1.0) * size_f - 1.0) / 2.0,
+    };
+    let clip = |coordinate: f64| coordinate.clamp(0.0, size_f - 1.0);
+
+    match padding_mode {
+        GridSamplePaddingMode::Zeros => coordinate,
+        GridSamplePaddingMode::Border => clip(coordinate),
+        GridSamplePaddingMode::Reflection => clip(match align_corners {
+            true => reflect(coordinate, 0.0, 2.0 * (size_f - 1.0)),
+            false => reflect(coordinate, -1.0, 2.0 * size_f - 1.0),
+        }),
+    }
+}
+
+/// Reflects a coordinate until it falls between the given bounds, which are doubled so that they
+/// can be half integers.
+fn reflect(coordinate: f64, twice_low: f64, twice_high: f64) -> f64 {
+    if twice_low == twice_high {
+        return 0.0;
+    }
+
+    let min = twice_low / 2.0;
+    let span = (twice_high - twice_low) / 2.0;
+    let coordinate = libm::fabs(coordinate - min);
+    let extra = libm::fmod(coordinate, span);
+    let flips = libm::floor(coordinate / span) as i64;
+
+    match flips % 2 {
+        0 => extra + min,
+        _ => span - extra + min,
+    }
+}
@@ -44,6 +44,13 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         tensor
     }
 
+    fn int_expand<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        shape: Shape<D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayOps::expand(tensor, shape)
+    }
+
     fn int_reshape<const D1: usize, const D2: usize>(
         tensor: NdArrayTensor<i64, D1>,
         shape: Shape<D2>,
@@ -0,0 +1,152 @@
+use crate::{
+    element::FloatNdArrayElement, iter_range_par, run_par, sharing::UnsafeSharedRef,
+    tensor::NdArrayTensor,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::{ops::InterpolateMode, ElementConversion};
+use ndarray::Array4;
+
+pub(crate) fn interpolate<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    output_size: [usize; 2],
+    mode: InterpolateMode,
+) -> NdArrayTensor<E, 4> {
+    let [batch_size, channels, input_height, input_width] = x.shape().dims;
+    let [output_height, output_width] = output_size;
+
+    let taps_height = axis_taps::<E>(input_height, output_height, mode);
+    let taps_width = axis_taps::<E>(input_width, output_width, mode);
+
+    let x = x.array;
+    let mut output = Array4::from_elem(
+        (batch_size, channels, output_height, output_width),
+        0.elem(),
+    );
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+            for (oh, taps_h) in taps_height.iter().enumerate() {
+                for (ow, taps_w) in taps_width.iter().enumerate() {
+                    let mut sum_val: E = 0.elem();
+
+                    for (ih, weight_h) in taps_h.iter() {
+                        for (iw, weight_w) in taps_w.iter() {
+                            sum_val += x[[b, c, *ih, *iw]] * *weight_h * *weight_w;
+                        }
+                    }
+
+                    output[[b, c, oh, ow]] = sum_val;
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn interpolate_backward<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 4>,
+    grad: NdArrayTensor<E, 4>,
+    output_size: [usize; 2],
+    mode: InterpolateMode,
+) -> NdArrayTensor<E, 4> {
+    let [batch_size, channels, input_height, input_width] = x.shape().dims;
+    let [output_height, output_width] = output_size;
+
+    let taps_height = axis_taps::<E>(input_height, output_height, mode);
+    let taps_width = axis_taps::<E>(input_width, output_width, mode);
+
+    let mut output_grad =
+        Array4::from_elem((batch_size, channels, input_height, input_width), 0.elem());
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output_grad);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output_grad = unsafe_shared_out.get();
+            for (oh, taps_h) in taps_height.iter().enumerate() {
+                for (ow, taps_w) in taps_width.iter().enumerate() {
+                    let grad = grad.array[[b, c, oh, ow]];
+
+                    for (ih, weight_h) in taps_h.iter() {
+                        for (iw, weight_w) in taps_w.iter() {
+                            output_grad[[b, c, *ih, *iw]] += grad * *weight_h * *weight_w;
+                        }
+                    }
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output_grad.into_dyn().into_shared())
+}
+
+/// Returns the input positions and their weights used to compute each output position along one
+/// axis, following the PyTorch convention.
+fn axis_taps<E: FloatNdArrayElement>(
+    input_size: usize,
+    output_size: usize,
+    mode: InterpolateMode,
+) -> Vec<Vec<(usize, E)>> {
+    let scale = input_size as f64 / output_size as f64;
+    let last = input_size as i64 - 1;
+    let clamp = |index: i64| index.clamp(0, last) as usize;
+
+    (0..output_size)
+        .map(|output_index| {
+            let position = output_index as f64;
+
+            match mode {
+                InterpolateMode::Nearest => {
+                    let index = libm::floor(position * scale) as i64;
+                    vec![(clamp(index), 1.elem())]
+                }
+                InterpolateMode::Bilinear { align_corners } => {
+                    let source = match align_corners {
+                        true if output_size > 1 => {
+                            position * last as f64 / (output_size - 1) as f64
+                        }
+                        true => 0.0,
+                        false => f64::max((position + 0.5) * scale - 0.5, 0.0),
+                    };
+                    let index = libm::floor(source) as i64;
+                    let lambda = source - index as f64;
+
+                    vec![
+                        (clamp(index), (1.0 - lambda).elem()),
+                        (clamp(index + 1), lambda.elem()),
+                    ]
+                }
+                InterpolateMode::Bicubic => {
+                    let source = (position + 0.5) * scale - 0.5;
+                    let index = libm::floor(source) as i64;
+                    let t = source - index as f64;
+
+                    cubic_weights(t)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, weight)| (clamp(index - 1 + i as i64), weight.elem()))
+                        .collect()
+                }
+            }
+        })
+        .collect()
+}
+
+/// Weights of the cubic convolution for the four pixels surrounding a position at distance `t`
+/// of the second pixel, with the same coefficient as PyTorch.
+fn cubic_weights(t: f64) -> [f64; 4] {
+    let a = -0.75;
+    let far = |x: f64| ((a * x - 5.0 * a) * x + 8.0 * a) * x - 4.0 * a;
+    let near = |x: f64| ((a + 2.0) * x - (a + 3.0)) * x * x + 1.0;
+
+    [far(t + 1.0), near(t), near(1.0 - t), far(2.0 - t)]
+}
@@ -2,6 +2,8 @@ use super::{
     adaptive_avgpool::{adaptive_avg_pool2d, adaptive_avg_pool2d_backward},
     avgpool::{avg_pool2d, avg_pool2d_backward},
     conv::{conv2d, conv_transpose2d},
+    grid_sample::grid_sample,
+    interpolate::{interpolate, interpolate_backward},
     maxpool::{max_pool2d, max_pool2d_backward, max_pool2d_with_indices},
 };
 use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray};
@@ -99,4 +101,31 @@ impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
     ) -> NdArrayTensor<E, 4> {
         adaptive_avg_pool2d_backward(x, grad)
     }
+
+    fn interpolate(
+        x: NdArrayTensor<E, 4>,
+        output_size: [usize; 2],
+        mode: InterpolateMode,
+    ) -> NdArrayTensor<E, 4> {
+        interpolate(x, output_size, mode)
+    }
+
+    fn interpolate_backward(
+        x: NdArrayTensor<E, 4>,
+        grad: NdArrayTensor<E, 4>,
+        output_size: [usize; 2],
+        mode: InterpolateMode,
+    ) -> NdArrayTensor<E, 4> {
+        interpolate_backward(x, grad, output_size, mode)
+    }
+
+    fn grid_sample(
+        input: NdArrayTensor<E, 4>,
+        grid: NdArrayTensor<E, 4>,
+        mode: GridSampleMode,
+        padding_mode: GridSamplePaddingMode,
+        align_corners: bool,
+    ) -> NdArrayTensor<E, 4> {
+        grid_sample(input, grid, mode, padding_mode, align_corners)
+    }
 }
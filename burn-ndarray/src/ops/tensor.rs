@@ -14,7 +14,7 @@ use burn_tensor::{backend::Backend, ops::FloatTensorOps, Data, ElementConversion
 use burn_tensor::{Distribution, Reader};
 
 // External crates
-use libm::{cos, erf, sin, tanh};
+use libm::{cos, erf, exp, log, sin, sqrt, tanh};
 
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
@@ -142,6 +142,13 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
         NdArrayOps::swap_dims(tensor, dim1, dim2)
     }
 
+    fn float_expand<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        shape: Shape<D>,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayOps::expand(tensor, shape)
+    }
+
     fn float_reshape<const D1: usize, const D2: usize>(
         tensor: NdArrayTensor<E, D1>,
         shape: Shape<D2>,
@@ -347,6 +354,44 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
         NdArrayTensor::new(array)
     }
 
+    fn float_cumsum<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn float_cumprod<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
+    fn float_logcumsumexp<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::logcumsumexp(tensor, dim)
+    }
+
+    fn float_fft<const D: usize>(
+        real: NdArrayTensor<E, D>,
+        imag: NdArrayTensor<E, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        NdArrayMathOps::fft(real, imag, dim, inverse)
+    }
+
+    fn float_sort_with_indices<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::sort_with_indices(tensor, dim, descending)
+    }
+
     fn float_argmax<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         dim: usize,
@@ -448,6 +493,15 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
         NdArrayTensor::new(array)
     }
 
+    fn float_erfinv<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| erfinv(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn float_cat<const D: usize>(
         tensors: Vec<NdArrayTensor<E, D>>,
         dim: usize,
@@ -485,3 +539,58 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
         NdArrayMathOps::elementwise_op(lhs, rhs, |a, b| a.powf_elem(b.to_f32().unwrap()))
     }
 }
+
+/// The inverse error function, approximated with the polynomials of
+/// [Approximating the erfinv function](https://people.maths.ox.ac.uk/gilesm/files/gems_erfinv.pdf)
+/// and refined with Halley's method.
+fn erfinv(x: f64) -> f64 {
+    if x.is_nan() || !(-1.0..=1.0).contains(&x) {
+        return f64::NAN;
+    }
+    if x.abs() == 1.0 {
+        return x * f64::INFINITY;
+    }
+
+    let w = -log((1.0 - x) * (1.0 + x));
+    let (w, coefficients) = match w < 5.0 {
+        true => (
+            w - 2.5,
+            [
+                2.810_226_36e-08,
+                3.432_739_39e-07,
+                -3.523_387_7e-06,
+                -4.391_506_54e-06,
+                2.185_808_7e-04,
+                -1.253_725_03e-03,
+                -4.177_681_64e-03,
+                2.466_407_27e-01,
+                1.501_409_41,
+            ],
+        ),
+        false => (
+            sqrt(w) - 3.0,
+            [
+                -2.002_142_57e-04,
+                1.009_505_58e-04,
+                1.349_343_22e-03,
+                -3.673_428_44e-03,
+                5.739_507_73e-03,
+                -7.622_461_3e-03,
+                9.438_870_47e-03,
+                1.001_674_06,
+                2.832_976_82,
+            ],
+        ),
+    };
+    let mut y = coefficients.iter().fold(0.0, |p, c| p * w + c) * x;
+
+    // Halley's method, f(y) = erf(y) - x having the derivatives f'(y) = 2 / sqrt(pi) * exp(-y^2)
+    // and f''(y) = -2y f'(y).
+    for _ in 0..2 {
+        let error = erf(y) - x;
+        let derivative = core::f64::consts::FRAC_2_SQRT_PI * exp(-y * y);
+        y -= error / (derivative + y * error);
+    }
+
+    y
+}
@@ -0,0 +1,9 @@
+fn main() {
+    // Generate the onnx protobuf files from the definition shared with burn-import
+    protobuf_codegen::Codegen::new()
+        .pure()
+        .includes(["../burn-import/src/onnx"])
+        .input("../burn-import/src/onnx/protos/onnx.proto")
+        .cargo_out_dir("onnx-protos")
+        .run_from_script();
+}
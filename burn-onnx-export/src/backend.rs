@@ -0,0 +1,43 @@
+use crate::TracedTensor;
+use burn_tensor::backend::Backend;
+use core::marker::PhantomData;
+
+/// Backend recording the operations applied to the traced input in an ONNX graph, while
+/// computing them with the inner backend.
+///
+/// The model to export should be created with this backend, see [export](crate::export).
+#[derive(Clone, Copy, Default, Debug)]
+pub struct Tracer<B: Backend> {
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> Backend for Tracer<B> {
+    type Device = B::Device;
+
+    type FullPrecisionBackend = Tracer<B::FullPrecisionBackend>;
+    type FullPrecisionElem = B::FullPrecisionElem;
+
+    type FloatTensorPrimitive<const D: usize> = TracedTensor<B::FloatTensorPrimitive<D>>;
+    type FloatElem = B::FloatElem;
+
+    type IntTensorPrimitive<const D: usize> = TracedTensor<B::IntTensorPrimitive<D>>;
+    type IntElem = B::IntElem;
+
+    type BoolTensorPrimitive<const D: usize> = TracedTensor<B::BoolTensorPrimitive<D>>;
+
+    fn ad_enabled() -> bool {
+        false
+    }
+
+    fn name() -> String {
+        format!("tracer<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn sync(device: &Self::Device) {
+        B::sync(device)
+    }
+}
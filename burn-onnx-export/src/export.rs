@@ -0,0 +1,203 @@
+use crate::graph::{Dim, TraceGraph, ValueInfo};
+use crate::protos::{tensor_proto::DataType, GraphProto, ModelProto, OperatorSetIdProto};
+use crate::{TracedTensor, TracedValue, Tracer};
+use burn_tensor::{backend::Backend, Tensor};
+use protobuf::Message;
+use std::path::Path;
+
+/// The version of the ONNX intermediate representation of the exported models.
+const IR_VERSION: i64 = 8;
+/// The version of the ONNX operator set used by the exported models.
+const OPSET_VERSION: i64 = 17;
+
+/// Name of the input of the exported graph.
+pub const INPUT_NAME: &str = "input";
+/// Name of the output of the exported graph.
+pub const OUTPUT_NAME: &str = "output";
+
+/// Configuration of the [export](export_with_config) of a model.
+#[derive(Debug, Clone, Default)]
+pub struct ExportConfig {
+    dynamic_dims: Vec<(usize, String)>,
+}
+
+impl ExportConfig {
+    /// Creates a configuration where every dimension of the input is static.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes a dimension of the input symbolic, so that the exported model accepts any size for
+    /// it, such as the batch size or the sequence length.
+    ///
+    /// The dimensions of the output computed from it are symbolic with the same name when they
+    /// are copied from the input, and dynamic otherwise.
+    pub fn with_dynamic_dim(mut self, axis: usize, name: &str) -> Self {
+        self.dynamic_dims.push((axis, name.to_string()));
+        self
+    }
+}
+
+/// Error that can occur when exporting a model.
+#[derive(Debug)]
+pub enum ExportError {
+    /// The output depends on the input through an operation which can't be exported.
+    UnsupportedOperation(String),
+
+    /// The operations applied to the input can't be represented by a graph.
+    InvalidTrace(String),
+
+    /// The output doesn't depend on the input.
+    ConstantOutput,
+
+    /// Failed to write the model.
+    Io(std::io::Error),
+}
+
+impl core::fmt::Display for ExportError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(format!("{self:?}").as_str())
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+impl From<std::io::Error> for ExportError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Stops tracing when dropped, even if the model panics.
+struct TraceGuard;
+
+impl Drop for TraceGuard {
+    fn drop(&mut self) {
+        TraceGraph::finish();
+    }
+}
+
+/// Exports a model to an ONNX file, with static dimensions.
+///
+/// See [export_with_config] for more details.
+pub fn export<B, F, P, const D1: usize, const D2: usize>(
+    model: F,
+    example_input: Tensor<Tracer<B>, D1>,
+    path: P,
+) -> Result<(), ExportError>
+where
+    B: Backend,
+    F: FnOnce(Tensor<Tracer<B>, D1>) -> Tensor<Tracer<B>, D2>,
+    P: AsRef<Path>,
+{
+    export_with_config(model, example_input, path, &ExportConfig::new())
+}
+
+/// Exports a model to an ONNX file.
+///
+/// The model, created with the [Tracer] backend, is the function computing the output from the
+/// input, such as `|input| model.forward(input)`. It is called once with the example input, and
+/// every operation applied to the input is recorded in the graph, the tensors which don't depend
+/// on the input, such as the parameters, being stored as initializers.
+///
+/// The graph has one float input named [input](INPUT_NAME) and one float output named
+/// [output](OUTPUT_NAME).
+///
+/// # Notes
+///
+/// The graph records what the model computes for the example input: branches depending on the
+/// values of the input aren't exported, and reading them while tracing fails with an
+/// [invalid trace](ExportError::InvalidTrace).
+pub fn export_with_config<B, F, P, const D1: usize, const D2: usize>(
+    model: F,
+    example_input: Tensor<Tracer<B>, D1>,
+    path: P,
+    config: &ExportConfig,
+) -> Result<(), ExportError>
+where
+    B: Backend,
+    F: FnOnce(Tensor<Tracer<B>, D1>) -> Tensor<Tracer<B>, D2>,
+    P: AsRef<Path>,
+{
+    let model = trace(model, example_input, config)?;
+    let bytes = model
+        .write_to_bytes()
+        .map_err(|error| ExportError::InvalidTrace(error.to_string()))?;
+
+    std::fs::write(path, bytes)?;
+
+    Ok(())
+}
+
+fn trace<B, F, const D1: usize, const D2: usize>(
+    model: F,
+    example_input: Tensor<Tracer<B>, D1>,
+    config: &ExportConfig,
+) -> Result<ModelProto, ExportError>
+where
+    B: Backend,
+    F: FnOnce(Tensor<Tracer<B>, D1>) -> Tensor<Tracer<B>, D2>,
+{
+    let primitive = example_input.into_primitive().primitive;
+    let shape = B::float_shape(&primitive).dims.to_vec();
+    let mut dims = vec![Dim::Static; D1];
+    for (axis, name) in config.dynamic_dims.iter() {
+        dims[*axis] = Dim::Symbolic(name.clone());
+    }
+    let input = ValueInfo {
+        name: INPUT_NAME.to_string(),
+        elem_type: DataType::FLOAT,
+        shape,
+        dims,
+    };
+
+    TraceGraph::start();
+    let guard = TraceGuard;
+
+    let value = TraceGraph::register_value(input);
+    let input = Tensor::from_primitive(TracedTensor::new(primitive, value));
+    let output = model(input).into_primitive();
+
+    let graph = TraceGraph::finish().expect("The model should be traced.");
+    core::mem::drop(guard);
+
+    if let Some(error) = graph.errors.first() {
+        return Err(ExportError::InvalidTrace(error.clone()));
+    }
+
+    match output.value {
+        TracedValue::Node(id) => Ok(model_proto(graph, id)),
+        TracedValue::Constant => Err(ExportError::ConstantOutput),
+        TracedValue::Unsupported(op) => Err(ExportError::UnsupportedOperation(op.to_string())),
+    }
+}
+
+fn model_proto(mut graph: TraceGraph, output: usize) -> ModelProto {
+    let output_name = core::mem::replace(&mut graph.values[output].name, OUTPUT_NAME.to_string());
+
+    for node in graph.nodes.iter_mut() {
+        for name in node.input.iter_mut().chain(node.output.iter_mut()) {
+            if *name == output_name {
+                *name = OUTPUT_NAME.to_string();
+            }
+        }
+    }
+
+    let mut graph_proto = GraphProto::new();
+    graph_proto.name = "burn".to_string();
+    graph_proto.input.push(graph.values[0].to_proto());
+    graph_proto.output.push(graph.values[output].to_proto());
+    graph_proto.node = graph.nodes;
+    graph_proto.initializer = graph.initializers;
+
+    let mut opset = OperatorSetIdProto::new();
+    opset.version = OPSET_VERSION;
+
+    let mut model = ModelProto::new();
+    model.ir_version = IR_VERSION;
+    model.producer_name = "burn".to_string();
+    model.producer_version = env!("CARGO_PKG_VERSION").to_string();
+    model.opset_import.push(opset);
+    model.graph = protobuf::MessageField::some(graph_proto);
+    model
+}
@@ -0,0 +1,370 @@
+use crate::protos::{
+    attribute_proto::AttributeType, tensor_proto::DataType, tensor_shape_proto::Dimension,
+    type_proto, AttributeProto, NodeProto, TensorProto, TensorShapeProto, TypeProto,
+    ValueInfoProto,
+};
+use crate::TracedValue;
+use protobuf::{EnumOrUnknown, MessageField};
+use std::cell::RefCell;
+
+std::thread_local! {
+    /// The graph being traced on the current thread, if any.
+    static GRAPH: RefCell<Option<TraceGraph>> = const { RefCell::new(None) };
+}
+
+/// A dimension of a traced value.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Dim {
+    /// The size is the one computed with the example input.
+    Static,
+    /// The size is the named dimension of the input.
+    Symbolic(String),
+    /// The size depends on the dimensions of the input, but can't be named.
+    Dynamic,
+}
+
+impl Dim {
+    pub(crate) fn is_static(&self) -> bool {
+        matches!(self, Dim::Static)
+    }
+}
+
+/// A value computed by the graph.
+#[derive(Debug, Clone)]
+pub(crate) struct ValueInfo {
+    pub(crate) name: String,
+    pub(crate) elem_type: DataType,
+    pub(crate) shape: Vec<usize>,
+    pub(crate) dims: Vec<Dim>,
+}
+
+impl ValueInfo {
+    pub(crate) fn to_proto(&self) -> ValueInfoProto {
+        let mut shape = TensorShapeProto::new();
+        for (size, dim) in self.shape.iter().zip(self.dims.iter()) {
+            let mut dimension = Dimension::new();
+            match dim {
+                Dim::Static => dimension.set_dim_value(*size as i64),
+                Dim::Symbolic(name) => dimension.set_dim_param(name.clone()),
+                Dim::Dynamic => {}
+            }
+            shape.dim.push(dimension);
+        }
+
+        let mut tensor_type = type_proto::Tensor::new();
+        tensor_type.elem_type = self.elem_type as i32;
+        tensor_type.shape = MessageField::some(shape);
+
+        let mut value_type = TypeProto::new();
+        value_type.set_tensor_type(tensor_type);
+
+        let mut value_info = ValueInfoProto::new();
+        value_info.name = self.name.clone();
+        value_info.type_ = MessageField::some(value_type);
+        value_info
+    }
+}
+
+/// The operations recorded while tracing a model.
+#[derive(Default, Debug)]
+pub(crate) struct TraceGraph {
+    pub(crate) nodes: Vec<NodeProto>,
+    pub(crate) initializers: Vec<TensorProto>,
+    pub(crate) values: Vec<ValueInfo>,
+    pub(crate) errors: Vec<String>,
+}
+
+impl TraceGraph {
+    /// Starts tracing on the current thread.
+    ///
+    /// # Panics
+    ///
+    /// If a graph is already being traced on the current thread.
+    pub(crate) fn start() {
+        GRAPH.with(|graph| {
+            let mut graph = graph.borrow_mut();
+            assert!(graph.is_none(), "A model is already being traced.");
+            *graph = Some(TraceGraph::default());
+        })
+    }
+
+    /// Stops tracing on the current thread, returning the recorded graph.
+    pub(crate) fn finish() -> Option<TraceGraph> {
+        GRAPH.with(|graph| graph.borrow_mut().take())
+    }
+
+    /// Whether a graph is being traced on the current thread.
+    pub(crate) fn is_tracing() -> bool {
+        GRAPH.with(|graph| graph.borrow().is_some())
+    }
+
+    /// Adds a value computed outside of the recorded operations, such as the input of the graph.
+    pub(crate) fn register_value(value: ValueInfo) -> TracedValue {
+        GRAPH.with(|graph| {
+            let mut graph = graph.borrow_mut();
+            let graph = graph.as_mut().expect("A model should be traced.");
+            graph.values.push(value);
+
+            TracedValue::Node(graph.values.len() - 1)
+        })
+    }
+
+    /// The dimensions of a value, which are all static for a constant.
+    pub(crate) fn dims(value: &TracedValue, rank: usize) -> Vec<Dim> {
+        let dims = match value {
+            TracedValue::Node(id) => GRAPH.with(|graph| {
+                graph
+                    .borrow()
+                    .as_ref()
+                    .map(|graph| graph.values[*id].dims.clone())
+            }),
+            _ => None,
+        };
+
+        dims.unwrap_or_else(|| vec![Dim::Static; rank])
+    }
+
+    /// Registers an error making the traced graph invalid.
+    pub(crate) fn register_error(error: String) {
+        GRAPH.with(|graph| {
+            if let Some(graph) = graph.borrow_mut().as_mut() {
+                graph.errors.push(error);
+            }
+        })
+    }
+}
+
+/// The elements of a constant.
+pub(crate) enum ConstantData {
+    Float(Vec<f32>),
+    Int(Vec<i64>),
+    Bool(Vec<bool>),
+}
+
+/// An input of a recorded operation.
+pub(crate) struct Operand {
+    value: TracedValue,
+    shape: Vec<usize>,
+    elem_type: DataType,
+    data: Box<dyn FnOnce() -> ConstantData>,
+}
+
+impl Operand {
+    /// Creates an operand, with a function reading its elements when it's a constant.
+    pub(crate) fn new<F: FnOnce() -> ConstantData + 'static>(
+        value: TracedValue,
+        shape: Vec<usize>,
+        elem_type: DataType,
+        data: F,
+    ) -> Self {
+        Self {
+            value,
+            shape,
+            elem_type,
+            data: Box::new(data),
+        }
+    }
+
+    /// A constant float scalar.
+    pub(crate) fn float_scalar(value: f32) -> Self {
+        Self::new(TracedValue::Constant, vec![], DataType::FLOAT, move || {
+            ConstantData::Float(vec![value])
+        })
+    }
+
+    /// A constant int scalar.
+    pub(crate) fn int_scalar(value: i64) -> Self {
+        Self::new(TracedValue::Constant, vec![], DataType::INT64, move || {
+            ConstantData::Int(vec![value])
+        })
+    }
+
+    /// A constant vector of ints, such as a shape or axes.
+    pub(crate) fn ints(values: Vec<i64>) -> Self {
+        Self::new(
+            TracedValue::Constant,
+            vec![values.len()],
+            DataType::INT64,
+            move || ConstantData::Int(values),
+        )
+    }
+
+    pub(crate) fn value(&self) -> &TracedValue {
+        &self.value
+    }
+}
+
+/// An operation to record in the traced graph.
+pub(crate) struct Node {
+    op_type: &'static str,
+    inputs: Vec<Operand>,
+    attributes: Vec<AttributeProto>,
+}
+
+impl Node {
+    pub(crate) fn new(op_type: &'static str) -> Self {
+        Self {
+            op_type,
+            inputs: Vec::new(),
+            attributes: Vec::new(),
+        }
+    }
+
+    pub(crate) fn input(mut self, operand: Operand) -> Self {
+        self.inputs.push(operand);
+        self
+    }
+
+    pub(crate) fn attr_int(mut self, name: &str, value: i64) -> Self {
+        let mut attribute = attribute(name, AttributeType::INT);
+        attribute.i = value;
+        self.attributes.push(attribute);
+        self
+    }
+
+    pub(crate) fn attr_ints(mut self, name: &str, values: Vec<i64>) -> Self {
+        let mut attribute = attribute(name, AttributeType::INTS);
+        attribute.ints = values;
+        self.attributes.push(attribute);
+        self
+    }
+
+    pub(crate) fn attr_string(mut self, name: &str, value: &str) -> Self {
+        let mut attribute = attribute(name, AttributeType::STRING);
+        attribute.s = value.as_bytes().to_vec();
+        self.attributes.push(attribute);
+        self
+    }
+
+    /// Records the operation when one of its inputs depends on the traced input, returning the
+    /// value of its output.
+    ///
+    /// The dimensions of the output are computed from the dimensions of the inputs.
+    pub(crate) fn output<F>(self, elem_type: DataType, shape: Vec<usize>, dims: F) -> TracedValue
+    where
+        F: FnOnce(&[Vec<Dim>]) -> Vec<Dim>,
+    {
+        let values: Vec<&TracedValue> = self.inputs.iter().map(Operand::value).collect();
+        if let Some(value) = untraced(&values) {
+            return value;
+        }
+
+        GRAPH.with(|graph| {
+            let mut graph = graph.borrow_mut();
+            let graph = graph.as_mut().expect("A model should be traced.");
+
+            let mut names = Vec::with_capacity(self.inputs.len());
+            let mut input_dims = Vec::with_capacity(self.inputs.len());
+
+            for operand in self.inputs {
+                match operand.value {
+                    TracedValue::Node(id) => {
+                        names.push(graph.values[id].name.clone());
+                        input_dims.push(graph.values[id].dims.clone());
+                    }
+                    _ => {
+                        let name = format!("constant_{}", graph.initializers.len());
+                        let data = (operand.data)();
+                        graph.initializers.push(tensor_proto(
+                            &name,
+                            &operand.shape,
+                            operand.elem_type,
+                            data,
+                        ));
+                        names.push(name);
+                        input_dims.push(vec![Dim::Static; operand.shape.len()]);
+                    }
+                }
+            }
+
+            let id = graph.values.len();
+            let output = format!("value_{id}");
+            graph.values.push(ValueInfo {
+                name: output.clone(),
+                elem_type,
+                dims: dims(&input_dims),
+                shape,
+            });
+
+            let mut node = NodeProto::new();
+            node.name = format!("{}_{}", self.op_type, graph.nodes.len());
+            node.op_type = self.op_type.to_string();
+            node.input = names;
+            node.output = vec![output];
+            node.attribute = self.attributes;
+            graph.nodes.push(node);
+
+            TracedValue::Node(id)
+        })
+    }
+}
+
+/// The value of the output of an operation which isn't recorded, because it can't be exported
+/// or because none of its inputs depend on the traced input.
+pub(crate) fn untraced(inputs: &[&TracedValue]) -> Option<TracedValue> {
+    if !TraceGraph::is_tracing() {
+        return Some(TracedValue::Constant);
+    }
+
+    if let Some(value) = inputs
+        .iter()
+        .find(|value| matches!(value, TracedValue::Unsupported(_)))
+    {
+        return Some((*value).clone());
+    }
+
+    match inputs.iter().all(|value| **value == TracedValue::Constant) {
+        true => Some(TracedValue::Constant),
+        false => None,
+    }
+}
+
+/// The value of the output of an operation which can't be exported.
+pub(crate) fn unsupported(op: &'static str, inputs: &[&TracedValue]) -> TracedValue {
+    untraced(inputs).unwrap_or(TracedValue::Unsupported(op))
+}
+
+fn attribute(name: &str, attribute_type: AttributeType) -> AttributeProto {
+    let mut attribute = AttributeProto::new();
+    attribute.name = name.to_string();
+    attribute.type_ = EnumOrUnknown::new(attribute_type);
+    attribute
+}
+
+fn tensor_proto(
+    name: &str,
+    shape: &[usize],
+    elem_type: DataType,
+    data: ConstantData,
+) -> TensorProto {
+    let mut tensor = TensorProto::new();
+    tensor.name = name.to_string();
+    tensor.dims = shape.iter().map(|dim| *dim as i64).collect();
+    tensor.data_type = elem_type as i32;
+    tensor.raw_data = match data {
+        ConstantData::Float(values) => values.iter().flat_map(|v| v.to_le_bytes()).collect(),
+        ConstantData::Int(values) => values.iter().flat_map(|v| v.to_le_bytes()).collect(),
+        ConstantData::Bool(values) => values.into_iter().map(u8::from).collect(),
+    };
+    tensor
+}
+
+/// The dimensions of the output of an element-wise operation, the inputs being broadcasted.
+pub(crate) fn elementwise_dims(inputs: &[Vec<Dim>]) -> Vec<Dim> {
+    let rank = inputs.iter().map(Vec::len).max().unwrap_or(0);
+
+    (0..rank)
+        .map(|axis| {
+            inputs
+                .iter()
+                .filter_map(|dims| {
+                    (axis + dims.len())
+                        .checked_sub(rank)
+                        .map(|axis| &dims[axis])
+                })
+                .find(|dim| !dim.is_static())
+                .cloned()
+                .unwrap_or(Dim::Static)
+        })
+        .collect()
+}
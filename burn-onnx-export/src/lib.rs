@@ -0,0 +1,23 @@
+#![warn(missing_docs)]
+
+//! Export of Burn models to the [ONNX](https://onnx.ai) format.
+//!
+//! The model is traced with an example input on the [Tracer] backend, which records the
+//! operations applied to the input in an ONNX graph. See [export] for more details.
+
+#[macro_use]
+extern crate derive_new;
+
+mod backend;
+mod export;
+mod graph;
+mod ops;
+mod protos;
+mod tensor;
+
+pub use backend::*;
+pub use export::*;
+pub use tensor::*;
+
+#[cfg(test)]
+mod tests;
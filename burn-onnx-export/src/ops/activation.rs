@@ -0,0 +1,21 @@
+use super::base::{float_operand, float_output};
+use crate::graph::{elementwise_dims, Node};
+use crate::Tracer;
+use burn_tensor::{
+    backend::Backend,
+    ops::{ActivationOps, FloatTensor},
+};
+
+impl<B: Backend> ActivationOps<Self> for Tracer<B> {
+    fn relu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let node = Node::new("Relu").input(float_operand::<B, D>(&tensor));
+
+        float_output::<B, D, _>(B::relu(tensor.primitive), node, elementwise_dims)
+    }
+
+    fn sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let node = Node::new("Sigmoid").input(float_operand::<B, D>(&tensor));
+
+        float_output::<B, D, _>(B::sigmoid(tensor.primitive), node, elementwise_dims)
+    }
+}
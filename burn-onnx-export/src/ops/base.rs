@@ -0,0 +1,235 @@
+use crate::graph::{ConstantData, Dim, Node, Operand, TraceGraph};
+use crate::protos::tensor_proto::DataType;
+use crate::{TracedTensor, TracedValue, Tracer};
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntTensor},
+    ElementConversion,
+};
+use core::ops::Range;
+
+/// The float tensor of the inner backend.
+pub(crate) type InnerFloat<B, const D: usize> = <B as Backend>::FloatTensorPrimitive<D>;
+/// The int tensor of the inner backend.
+pub(crate) type InnerInt<B, const D: usize> = <B as Backend>::IntTensorPrimitive<D>;
+/// The bool tensor of the inner backend.
+pub(crate) type InnerBool<B, const D: usize> = <B as Backend>::BoolTensorPrimitive<D>;
+
+pub(crate) fn float_operand<B: Backend, const D: usize>(
+    tensor: &FloatTensor<Tracer<B>, D>,
+) -> Operand {
+    let primitive = tensor.primitive.clone();
+    let shape = B::float_shape(&primitive).dims.to_vec();
+
+    Operand::new(tensor.value.clone(), shape, DataType::FLOAT, move || {
+        let data = B::float_into_data(primitive).read();
+        ConstantData::Float(data.value.into_iter().map(|elem| elem.elem()).collect())
+    })
+}
+
+pub(crate) fn int_operand<B: Backend, const D: usize>(tensor: &IntTensor<Tracer<B>, D>) -> Operand {
+    let primitive = tensor.primitive.clone();
+    let shape = B::int_shape(&primitive).dims.to_vec();
+
+    Operand::new(tensor.value.clone(), shape, DataType::INT64, move || {
+        let data = B::int_into_data(primitive).read();
+        ConstantData::Int(data.value.into_iter().map(|elem| elem.elem()).collect())
+    })
+}
+
+pub(crate) fn bool_operand<B: Backend, const D: usize>(
+    tensor: &BoolTensor<Tracer<B>, D>,
+) -> Operand {
+    let primitive = tensor.primitive.clone();
+    let shape = B::bool_shape(&primitive).dims.to_vec();
+
+    Operand::new(tensor.value.clone(), shape, DataType::BOOL, move || {
+        ConstantData::Bool(B::bool_into_data(primitive).read().value)
+    })
+}
+
+pub(crate) fn float_output<B: Backend, const D: usize, F>(
+    primitive: InnerFloat<B, D>,
+    node: Node,
+    dims: F,
+) -> FloatTensor<Tracer<B>, D>
+where
+    F: FnOnce(&[Vec<Dim>]) -> Vec<Dim>,
+{
+    let shape = B::float_shape(&primitive).dims.to_vec();
+    let value = node.output(DataType::FLOAT, shape, dims);
+
+    TracedTensor::new(primitive, value)
+}
+
+pub(crate) fn int_output<B: Backend, const D: usize, F>(
+    primitive: InnerInt<B, D>,
+    node: Node,
+    dims: F,
+) -> IntTensor<Tracer<B>, D>
+where
+    F: FnOnce(&[Vec<Dim>]) -> Vec<Dim>,
+{
+    let shape = B::int_shape(&primitive).dims.to_vec();
+    let value = node.output(DataType::INT64, shape, dims);
+
+    TracedTensor::new(primitive, value)
+}
+
+pub(crate) fn bool_output<B: Backend, const D: usize, F>(
+    primitive: InnerBool<B, D>,
+    node: Node,
+    dims: F,
+) -> BoolTensor<Tracer<B>, D>
+where
+    F: FnOnce(&[Vec<Dim>]) -> Vec<Dim>,
+{
+    let shape = B::bool_shape(&primitive).dims.to_vec();
+    let value = node.output(DataType::BOOL, shape, dims);
+
+    TracedTensor::new(primitive, value)
+}
+
+/// Registers an error when the elements of a traced tensor are read, since the graph can't depend
+/// on them.
+pub(crate) fn read_value(value: &TracedValue) {
+    if *value != TracedValue::Constant {
+        TraceGraph::register_error(
+            "The elements of a tensor depending on the input can't be read while tracing."
+                .to_string(),
+        );
+    }
+}
+
+/// The dimensions of the output of a reduction along an axis, which keeps the reduced axis.
+pub(crate) fn reduce_dims(axis: usize) -> impl FnOnce(&[Vec<Dim>]) -> Vec<Dim> {
+    move |inputs| {
+        let mut dims = inputs[0].clone();
+        dims[axis] = Dim::Static;
+        dims
+    }
+}
+
+/// The dimensions of the output of a transposition.
+pub(crate) fn swap_dims(dim1: usize, dim2: usize) -> impl FnOnce(&[Vec<Dim>]) -> Vec<Dim> {
+    move |inputs| {
+        let mut dims = inputs[0].clone();
+        dims.swap(dim1, dim2);
+        dims
+    }
+}
+
+/// The permutation of the axes swapping two dimensions.
+pub(crate) fn swap_perm(rank: usize, dim1: usize, dim2: usize) -> Vec<i64> {
+    let mut perm: Vec<i64> = (0..rank as i64).collect();
+    perm.swap(dim1, dim2);
+    perm
+}
+
+/// The shape given to a reshape operation and the dimensions of its output.
+///
+/// The dynamic dimensions of the input kept at the same position are copied with a `0`, and
+/// when other dynamic dimensions are merged or split, the first dimension which isn't copied is
+/// inferred with a `-1`.
+pub(crate) fn reshape_target(
+    input_shape: &[usize],
+    input_dims: &[Dim],
+    shape: &[usize],
+) -> (Vec<i64>, Vec<Dim>) {
+    let mut target: Vec<i64> = shape.iter().map(|size| *size as i64).collect();
+    let mut dims = vec![Dim::Static; shape.len()];
+    let mut copied = vec![false; input_shape.len()];
+
+    for axis in 0..usize::min(input_shape.len(), shape.len()) {
+        if !input_dims[axis].is_static() && input_shape[axis] == shape[axis] {
+            target[axis] = 0;
+            dims[axis] = input_dims[axis].clone();
+            copied[axis] = true;
+        }
+    }
+
+    let merged = input_dims
+        .iter()
+        .zip(copied)
+        .any(|(dim, copied)| !dim.is_static() && !copied);
+
+    if merged {
+        if let Some(axis) = target.iter().position(|size| *size != 0) {
+            target[axis] = -1;
+            dims[axis] = Dim::Dynamic;
+        }
+    }
+
+    (target, dims)
+}
+
+/// The starts, ends and axes given to a slice operation, and the dimensions of its output.
+///
+/// The dynamic dimensions which are sliced entirely stay dynamic.
+pub(crate) fn slice_target<const D2: usize>(
+    input_shape: &[usize],
+    input_dims: &[Dim],
+    ranges: &[Range<usize>; D2],
+) -> (Vec<i64>, Vec<i64>, Vec<i64>, Vec<Dim>) {
+    let mut dims = input_dims.to_vec();
+    let mut ends = Vec::with_capacity(D2);
+
+    for (axis, range) in ranges.iter().enumerate() {
+        let full = range.start == 0 && range.end == input_shape[axis];
+
+        if full && !input_dims[axis].is_static() {
+            ends.push(i64::MAX);
+        } else {
+            ends.push(range.end as i64);
+            dims[axis] = Dim::Static;
+        }
+    }
+
+    let starts = ranges.iter().map(|range| range.start as i64).collect();
+    let axes = (0..D2 as i64).collect();
+
+    (starts, ends, axes, dims)
+}
+
+/// The dimensions of the output of a concatenation.
+pub(crate) fn cat_dims(dim: usize) -> impl FnOnce(&[Vec<Dim>]) -> Vec<Dim> {
+    move |inputs| {
+        let mut dims = inputs[0].clone();
+        for (axis, output) in dims.iter_mut().enumerate() {
+            let dynamic = inputs
+                .iter()
+                .map(|dims| &dims[axis])
+                .find(|dim| !dim.is_static());
+
+            *output = match (axis == dim, dynamic) {
+                (_, None) => Dim::Static,
+                (true, Some(_)) => Dim::Dynamic,
+                (false, Some(dim)) => dim.clone(),
+            };
+        }
+        dims
+    }
+}
+
+/// The dimensions of the output of a selection along an axis with a vector of indices.
+pub(crate) fn select_dims(dim: usize) -> impl FnOnce(&[Vec<Dim>]) -> Vec<Dim> {
+    move |inputs| {
+        let mut dims = inputs[0].clone();
+        dims[dim] = inputs[1][0].clone();
+        dims
+    }
+}
+
+/// The dimensions of the output of a spatial operation, such as a convolution or a pooling, on
+/// the layout `[batch_size, channels, ...]`.
+pub(crate) fn spatial_dims(inputs: &[Vec<Dim>]) -> Vec<Dim> {
+    inputs[0]
+        .iter()
+        .enumerate()
+        .map(|(axis, dim)| match (axis, dim) {
+            (_, Dim::Static) | (1, _) => Dim::Static,
+            (0, dim) => dim.clone(),
+            _ => Dim::Dynamic,
+        })
+        .collect()
+}
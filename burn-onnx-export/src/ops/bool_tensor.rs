@@ -0,0 +1,159 @@
+use super::base::{
+    bool_operand, bool_output, cat_dims, float_output, int_output, read_value, reshape_target,
+    slice_target, swap_dims, swap_perm,
+};
+use crate::graph::{elementwise_dims, unsupported, Node, Operand, TraceGraph};
+use crate::protos::tensor_proto::DataType;
+use crate::{TracedTensor, Tracer};
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, BoolTensorOps, FloatTensor, IntTensor},
+    Data, Device, Reader, Shape,
+};
+use core::ops::Range;
+
+impl<B: Backend> BoolTensorOps<Self> for Tracer<B> {
+    fn bool_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> BoolTensor<Self, D> {
+        TracedTensor::constant(B::bool_empty(shape, device))
+    }
+
+    fn bool_shape<const D: usize>(tensor: &BoolTensor<Self, D>) -> Shape<D> {
+        B::bool_shape(&tensor.primitive)
+    }
+
+    fn bool_into_data<const D: usize>(tensor: BoolTensor<Self, D>) -> Reader<Data<bool, D>> {
+        read_value(&tensor.value);
+        B::bool_into_data(tensor.primitive)
+    }
+
+    fn bool_from_data<const D: usize>(
+        data: Data<bool, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        TracedTensor::constant(B::bool_from_data(data, device))
+    }
+
+    fn bool_into_int<const D: usize>(tensor: BoolTensor<Self, D>) -> IntTensor<Self, D> {
+        let node = Node::new("Cast")
+            .input(bool_operand::<B, D>(&tensor))
+            .attr_int("to", DataType::INT64 as i64);
+
+        int_output::<B, D, _>(B::bool_into_int(tensor.primitive), node, elementwise_dims)
+    }
+
+    fn bool_into_float<const D: usize>(tensor: BoolTensor<Self, D>) -> FloatTensor<Self, D> {
+        let node = Node::new("Cast")
+            .input(bool_operand::<B, D>(&tensor))
+            .attr_int("to", DataType::FLOAT as i64);
+
+        float_output::<B, D, _>(B::bool_into_float(tensor.primitive), node, elementwise_dims)
+    }
+
+    fn bool_device<const D: usize>(tensor: &BoolTensor<Self, D>) -> Device<Self> {
+        B::bool_device(&tensor.primitive)
+    }
+
+    fn bool_to_device<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        TracedTensor::new(B::bool_to_device(tensor.primitive, device), tensor.value)
+    }
+
+    fn bool_reshape<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        let input_shape = B::bool_shape(&tensor.primitive);
+        let input_dims = TraceGraph::dims(&tensor.value, D1);
+        let (target, dims) = reshape_target(&input_shape.dims, &input_dims, &shape.dims);
+
+        let node = Node::new("Reshape")
+            .input(bool_operand::<B, D1>(&tensor))
+            .input(Operand::ints(target));
+
+        bool_output::<B, D2, _>(B::bool_reshape(tensor.primitive, shape), node, |_| dims)
+    }
+
+    fn bool_slice<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> BoolTensor<Self, D1> {
+        let input_shape = B::bool_shape(&tensor.primitive);
+        let input_dims = TraceGraph::dims(&tensor.value, D1);
+        let (starts, ends, axes, dims) = slice_target(&input_shape.dims, &input_dims, &ranges);
+
+        let node = Node::new("Slice")
+            .input(bool_operand::<B, D1>(&tensor))
+            .input(Operand::ints(starts))
+            .input(Operand::ints(ends))
+            .input(Operand::ints(axes));
+
+        bool_output::<B, D1, _>(B::bool_slice(tensor.primitive, ranges), node, |_| dims)
+    }
+
+    fn bool_slice_assign<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: BoolTensor<Self, D1>,
+    ) -> BoolTensor<Self, D1> {
+        let output = unsupported("slice_assign", &[&tensor.value, &value.value]);
+
+        TracedTensor::new(
+            B::bool_slice_assign(tensor.primitive, ranges, value.primitive),
+            output,
+        )
+    }
+
+    fn bool_cat<const D: usize>(
+        tensors: Vec<BoolTensor<Self, D>>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        let node = tensors
+            .iter()
+            .fold(Node::new("Concat"), |node, tensor| {
+                node.input(bool_operand::<B, D>(tensor))
+            })
+            .attr_int("axis", dim as i64);
+        let primitives = tensors.into_iter().map(|tensor| tensor.primitive).collect();
+
+        bool_output::<B, D, _>(B::bool_cat(primitives, dim), node, cat_dims(dim))
+    }
+
+    fn bool_equal<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        let node = Node::new("Equal")
+            .input(bool_operand::<B, D>(&lhs))
+            .input(bool_operand::<B, D>(&rhs));
+
+        bool_output::<B, D, _>(
+            B::bool_equal(lhs.primitive, rhs.primitive),
+            node,
+            elementwise_dims,
+        )
+    }
+
+    fn bool_not<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        let node = Node::new("Not").input(bool_operand::<B, D>(&tensor));
+
+        bool_output::<B, D, _>(B::bool_not(tensor.primitive), node, elementwise_dims)
+    }
+
+    fn bool_swap_dims<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> BoolTensor<Self, D> {
+        let node = Node::new("Transpose")
+            .input(bool_operand::<B, D>(&tensor))
+            .attr_ints("perm", swap_perm(D, dim1, dim2));
+
+        bool_output::<B, D, _>(
+            B::bool_swap_dims(tensor.primitive, dim1, dim2),
+            node,
+            swap_dims(dim1, dim2),
+        )
+    }
+}
@@ -0,0 +1,488 @@
+use super::base::{
+    bool_operand, bool_output, cat_dims, float_output, int_operand, int_output, read_value,
+    reduce_dims, reshape_target, select_dims, slice_target, swap_dims, swap_perm, InnerBool,
+    InnerInt,
+};
+use crate::graph::{elementwise_dims, unsupported, Node, Operand, TraceGraph};
+use crate::protos::tensor_proto::DataType;
+use crate::{TracedTensor, Tracer};
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
+    Data, Device, ElementConversion, Reader, Shape,
+};
+use core::ops::Range;
+
+fn binary<B: Backend, const D: usize, F>(
+    op: &'static str,
+    lhs: IntTensor<Tracer<B>, D>,
+    rhs: IntTensor<Tracer<B>, D>,
+    compute: F,
+) -> IntTensor<Tracer<B>, D>
+where
+    F: FnOnce(InnerInt<B, D>, InnerInt<B, D>) -> InnerInt<B, D>,
+{
+    let node = Node::new(op)
+        .input(int_operand::<B, D>(&lhs))
+        .input(int_operand::<B, D>(&rhs));
+
+    int_output::<B, D, _>(
+        compute(lhs.primitive, rhs.primitive),
+        node,
+        elementwise_dims,
+    )
+}
+
+fn binary_scalar<B: Backend, const D: usize, F>(
+    op: &'static str,
+    lhs: IntTensor<Tracer<B>, D>,
+    rhs: IntElem<B>,
+    compute: F,
+) -> IntTensor<Tracer<B>, D>
+where
+    F: FnOnce(InnerInt<B, D>, IntElem<B>) -> InnerInt<B, D>,
+{
+    let node = Node::new(op)
+        .input(int_operand::<B, D>(&lhs))
+        .input(Operand::int_scalar(rhs.elem()));
+
+    int_output::<B, D, _>(compute(lhs.primitive, rhs), node, elementwise_dims)
+}
+
+fn compare<B: Backend, const D: usize, F>(
+    op: &'static str,
+    lhs: IntTensor<Tracer<B>, D>,
+    rhs: IntTensor<Tracer<B>, D>,
+    compute: F,
+) -> BoolTensor<Tracer<B>, D>
+where
+    F: FnOnce(InnerInt<B, D>, InnerInt<B, D>) -> InnerBool<B, D>,
+{
+    let node = Node::new(op)
+        .input(int_operand::<B, D>(&lhs))
+        .input(int_operand::<B, D>(&rhs));
+
+    bool_output::<B, D, _>(
+        compute(lhs.primitive, rhs.primitive),
+        node,
+        elementwise_dims,
+    )
+}
+
+fn compare_scalar<B: Backend, const D: usize, F>(
+    op: &'static str,
+    lhs: IntTensor<Tracer<B>, D>,
+    rhs: IntElem<B>,
+    compute: F,
+) -> BoolTensor<Tracer<B>, D>
+where
+    F: FnOnce(InnerInt<B, D>, IntElem<B>) -> InnerBool<B, D>,
+{
+    let node = Node::new(op)
+        .input(int_operand::<B, D>(&lhs))
+        .input(Operand::int_scalar(rhs.elem()));
+
+    bool_output::<B, D, _>(compute(lhs.primitive, rhs), node, elementwise_dims)
+}
+
+impl<B: Backend> IntTensorOps<Self> for Tracer<B> {
+    fn int_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        TracedTensor::constant(B::int_empty(shape, device))
+    }
+
+    fn int_shape<const D: usize>(tensor: &IntTensor<Self, D>) -> Shape<D> {
+        B::int_shape(&tensor.primitive)
+    }
+
+    fn int_into_data<const D: usize>(tensor: IntTensor<Self, D>) -> Reader<Data<IntElem<Self>, D>> {
+        read_value(&tensor.value);
+        B::int_into_data(tensor.primitive)
+    }
+
+    fn int_from_data<const D: usize>(
+        data: Data<IntElem<Self>, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        TracedTensor::constant(B::int_from_data(data, device))
+    }
+
+    fn int_device<const D: usize>(tensor: &IntTensor<Self, D>) -> Device<Self> {
+        B::int_device(&tensor.primitive)
+    }
+
+    fn int_to_device<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        TracedTensor::new(B::int_to_device(tensor.primitive, device), tensor.value)
+    }
+
+    fn int_reshape<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        let input_shape = B::int_shape(&tensor.primitive);
+        let input_dims = TraceGraph::dims(&tensor.value, D1);
+        let (target, dims) = reshape_target(&input_shape.dims, &input_dims, &shape.dims);
+
+        let node = Node::new("Reshape")
+            .input(int_operand::<B, D1>(&tensor))
+            .input(Operand::ints(target));
+
+        int_output::<B, D2, _>(B::int_reshape(tensor.primitive, shape), node, |_| dims)
+    }
+
+    fn int_slice<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> IntTensor<Self, D1> {
+        let input_shape = B::int_shape(&tensor.primitive);
+        let input_dims = TraceGraph::dims(&tensor.value, D1);
+        let (starts, ends, axes, dims) = slice_target(&input_shape.dims, &input_dims, &ranges);
+
+        let node = Node::new("Slice")
+            .input(int_operand::<B, D1>(&tensor))
+            .input(Operand::ints(starts))
+            .input(Operand::ints(ends))
+            .input(Operand::ints(axes));
+
+        int_output::<B, D1, _>(B::int_slice(tensor.primitive, ranges), node, |_| dims)
+    }
+
+    fn int_slice_assign<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: IntTensor<Self, D1>,
+    ) -> IntTensor<Self, D1> {
+        let output = unsupported("slice_assign", &[&tensor.value, &value.value]);
+
+        TracedTensor::new(
+            B::int_slice_assign(tensor.primitive, ranges, value.primitive),
+            output,
+        )
+    }
+
+    fn int_into_float<const D: usize>(tensor: IntTensor<Self, D>) -> FloatTensor<Self, D> {
+        let node = Node::new("Cast")
+            .input(int_operand::<B, D>(&tensor))
+            .attr_int("to", DataType::FLOAT as i64);
+
+        float_output::<B, D, _>(B::int_into_float(tensor.primitive), node, elementwise_dims)
+    }
+
+    fn int_mask_where<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        source: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let node = Node::new("Where")
+            .input(bool_operand::<B, D>(&mask))
+            .input(int_operand::<B, D>(&source))
+            .input(int_operand::<B, D>(&tensor));
+
+        int_output::<B, D, _>(
+            B::int_mask_where(tensor.primitive, mask.primitive, source.primitive),
+            node,
+            elementwise_dims,
+        )
+    }
+
+    fn int_mask_fill<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        let node = Node::new("Where")
+            .input(bool_operand::<B, D>(&mask))
+            .input(Operand::int_scalar(value.elem()))
+            .input(int_operand::<B, D>(&tensor));
+
+        int_output::<B, D, _>(
+            B::int_mask_fill(tensor.primitive, mask.primitive, value),
+            node,
+            elementwise_dims,
+        )
+    }
+
+    fn int_gather<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let node = Node::new("GatherElements")
+            .input(int_operand::<B, D>(&tensor))
+            .input(int_operand::<B, D>(&indices))
+            .attr_int("axis", dim as i64);
+
+        int_output::<B, D, _>(
+            B::int_gather(dim, tensor.primitive, indices.primitive),
+            node,
+            |inputs| inputs[1].clone(),
+        )
+    }
+
+    fn int_scatter<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let node = Node::new("ScatterElements")
+            .input(int_operand::<B, D>(&tensor))
+            .input(int_operand::<B, D>(&indices))
+            .input(int_operand::<B, D>(&value))
+            .attr_int("axis", dim as i64)
+            .attr_string("reduction", "add");
+
+        int_output::<B, D, _>(
+            B::int_scatter(dim, tensor.primitive, indices.primitive, value.primitive),
+            node,
+            |inputs| inputs[0].clone(),
+        )
+    }
+
+    fn int_select<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> IntTensor<Self, D> {
+        let node = Node::new("Gather")
+            .input(int_operand::<B, D>(&tensor))
+            .input(int_operand::<B, 1>(&indices))
+            .attr_int("axis", dim as i64);
+
+        int_output::<B, D, _>(
+            B::int_select(tensor.primitive, dim, indices.primitive),
+            node,
+            select_dims(dim),
+        )
+    }
+
+    fn int_select_assign<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        let output = unsupported(
+            "select_assign",
+            &[&tensor.value, &indices.value, &value.value],
+        );
+
+        TracedTensor::new(
+            B::int_select_assign(tensor.primitive, dim, indices.primitive, value.primitive),
+            output,
+        )
+    }
+
+    fn int_cat<const D: usize>(tensors: Vec<IntTensor<Self, D>>, dim: usize) -> IntTensor<Self, D> {
+        let node = tensors
+            .iter()
+            .fold(Node::new("Concat"), |node, tensor| {
+                node.input(int_operand::<B, D>(tensor))
+            })
+            .attr_int("axis", dim as i64);
+        let primitives = tensors.into_iter().map(|tensor| tensor.primitive).collect();
+
+        int_output::<B, D, _>(B::int_cat(primitives, dim), node, cat_dims(dim))
+    }
+
+    fn int_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        compare::<B, D, _>("Equal", lhs, rhs, B::int_equal)
+    }
+
+    fn int_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        compare_scalar::<B, D, _>("Equal", lhs, rhs, B::int_equal_elem)
+    }
+
+    fn int_greater<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        compare::<B, D, _>("Greater", lhs, rhs, B::int_greater)
+    }
+
+    fn int_greater_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        compare_scalar::<B, D, _>("Greater", lhs, rhs, B::int_greater_elem)
+    }
+
+    fn int_greater_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        compare::<B, D, _>("GreaterOrEqual", lhs, rhs, B::int_greater_equal)
+    }
+
+    fn int_greater_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        compare_scalar::<B, D, _>("GreaterOrEqual", lhs, rhs, B::int_greater_equal_elem)
+    }
+
+    fn int_lower<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        compare::<B, D, _>("Less", lhs, rhs, B::int_lower)
+    }
+
+    fn int_lower_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        compare_scalar::<B, D, _>("Less", lhs, rhs, B::int_lower_elem)
+    }
+
+    fn int_lower_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        compare::<B, D, _>("LessOrEqual", lhs, rhs, B::int_lower_equal)
+    }
+
+    fn int_lower_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        compare_scalar::<B, D, _>("LessOrEqual", lhs, rhs, B::int_lower_equal_elem)
+    }
+
+    fn int_add<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary::<B, D, _>("Add", lhs, rhs, B::int_add)
+    }
+
+    fn int_add_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        binary_scalar::<B, D, _>("Add", lhs, rhs, B::int_add_scalar)
+    }
+
+    fn int_sub<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary::<B, D, _>("Sub", lhs, rhs, B::int_sub)
+    }
+
+    fn int_sub_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        binary_scalar::<B, D, _>("Sub", lhs, rhs, B::int_sub_scalar)
+    }
+
+    fn int_mul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary::<B, D, _>("Mul", lhs, rhs, B::int_mul)
+    }
+
+    fn int_mul_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        binary_scalar::<B, D, _>("Mul", lhs, rhs, B::int_mul_scalar)
+    }
+
+    fn int_div<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary::<B, D, _>("Div", lhs, rhs, B::int_div)
+    }
+
+    fn int_div_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        binary_scalar::<B, D, _>("Div", lhs, rhs, B::int_div_scalar)
+    }
+
+    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        TracedTensor::constant(B::int_zeros(shape, device))
+    }
+
+    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        TracedTensor::constant(B::int_ones(shape, device))
+    }
+
+    fn int_sum<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        let output = unsupported("sum", &[&tensor.value]);
+
+        TracedTensor::new(B::int_sum(tensor.primitive), output)
+    }
+
+    fn int_sum_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let node = Node::new("ReduceSum")
+            .input(int_operand::<B, D>(&tensor))
+            .input(Operand::ints(vec![dim as i64]))
+            .attr_int("keepdims", 1);
+
+        int_output::<B, D, _>(
+            B::int_sum_dim(tensor.primitive, dim),
+            node,
+            reduce_dims(dim),
+        )
+    }
+
+    fn int_mean_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let output = unsupported("mean_dim", &[&tensor.value]);
+
+        TracedTensor::new(B::int_mean_dim(tensor.primitive, dim), output)
+    }
+
+    fn int_argmax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let node = Node::new("ArgMax")
+            .input(int_operand::<B, D>(&tensor))
+            .attr_int("axis", dim as i64)
+            .attr_int("keepdims", 1);
+
+        int_output::<B, D, _>(B::int_argmax(tensor.primitive, dim), node, reduce_dims(dim))
+    }
+
+    fn int_argmin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        let node = Node::new("ArgMin")
+            .input(int_operand::<B, D>(&tensor))
+            .attr_int("axis", dim as i64)
+            .attr_int("keepdims", 1);
+
+        int_output::<B, D, _>(B::int_argmin(tensor.primitive, dim), node, reduce_dims(dim))
+    }
+
+    fn int_abs<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        let node = Node::new("Abs").input(int_operand::<B, D>(&tensor));
+
+        int_output::<B, D, _>(B::int_abs(tensor.primitive), node, elementwise_dims)
+    }
+
+    fn int_swap_dims<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> IntTensor<Self, D> {
+        let node = Node::new("Transpose")
+            .input(int_operand::<B, D>(&tensor))
+            .attr_ints("perm", swap_perm(D, dim1, dim2));
+
+        int_output::<B, D, _>(
+            B::int_swap_dims(tensor.primitive, dim1, dim2),
+            node,
+            swap_dims(dim1, dim2),
+        )
+    }
+}
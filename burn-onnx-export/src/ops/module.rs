@@ -0,0 +1,226 @@
+use super::base::{float_operand, float_output, spatial_dims};
+use crate::graph::{unsupported, Node};
+use crate::{TracedTensor, Tracer};
+use burn_tensor::{
+    backend::Backend,
+    ops::{
+        ConvOptions, ConvTransposeOptions, FloatTensor, IntTensor, MaxPool2dBackward,
+        MaxPool2dWithIndices, ModuleOps,
+    },
+};
+
+/// The padding of the beginning and the end of each spatial axis.
+fn pads(padding: [usize; 2]) -> Vec<i64> {
+    [padding, padding]
+        .concat()
+        .into_iter()
+        .map(|pad| pad as i64)
+        .collect()
+}
+
+fn ints(values: [usize; 2]) -> Vec<i64> {
+    values.into_iter().map(|value| value as i64).collect()
+}
+
+impl<B: Backend> ModuleOps<Self> for Tracer<B> {
+    fn conv2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        let mut node = Node::new("Conv")
+            .input(float_operand::<B, 4>(&x))
+            .input(float_operand::<B, 4>(&weight));
+        if let Some(bias) = &bias {
+            node = node.input(float_operand::<B, 1>(bias));
+        }
+        let node = node
+            .attr_ints("dilations", ints(options.dilation))
+            .attr_int("group", options.groups as i64)
+            .attr_ints("pads", pads(options.padding))
+            .attr_ints("strides", ints(options.stride));
+
+        float_output::<B, 4, _>(
+            B::conv2d(
+                x.primitive,
+                weight.primitive,
+                bias.map(|bias| bias.primitive),
+                options,
+            ),
+            node,
+            spatial_dims,
+        )
+    }
+
+    fn conv_transpose2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        let mut node = Node::new("ConvTranspose")
+            .input(float_operand::<B, 4>(&x))
+            .input(float_operand::<B, 4>(&weight));
+        if let Some(bias) = &bias {
+            node = node.input(float_operand::<B, 1>(bias));
+        }
+        let node = node
+            .attr_ints("dilations", ints(options.dilation))
+            .attr_int("group", options.groups as i64)
+            .attr_ints("output_padding", ints(options.padding_out))
+            .attr_ints("pads", pads(options.padding))
+            .attr_ints("strides", ints(options.stride));
+
+        float_output::<B, 4, _>(
+            B::conv_transpose2d(
+                x.primitive,
+                weight.primitive,
+                bias.map(|bias| bias.primitive),
+                options,
+            ),
+            node,
+            spatial_dims,
+        )
+    }
+
+    fn avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        let node = Node::new("AveragePool")
+            .input(float_operand::<B, 4>(&x))
+            .attr_int("count_include_pad", count_include_pad as i64)
+            .attr_ints("kernel_shape", ints(kernel_size))
+            .attr_ints("pads", pads(padding))
+            .attr_ints("strides", ints(stride));
+
+        float_output::<B, 4, _>(
+            B::avg_pool2d(x.primitive, kernel_size, stride, padding, count_include_pad),
+            node,
+            spatial_dims,
+        )
+    }
+
+    fn avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        let output = unsupported("avg_pool2d_backward", &[&x.value, &grad.value]);
+
+        TracedTensor::new(
+            B::avg_pool2d_backward(
+                x.primitive,
+                grad.primitive,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            ),
+            output,
+        )
+    }
+
+    fn adaptive_avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        // Only the global pooling has an equivalent operation with ONNX.
+        if output_size != [1, 1] {
+            let output = unsupported("adaptive_avg_pool2d", &[&x.value]);
+
+            return TracedTensor::new(B::adaptive_avg_pool2d(x.primitive, output_size), output);
+        }
+
+        let node = Node::new("GlobalAveragePool").input(float_operand::<B, 4>(&x));
+
+        float_output::<B, 4, _>(
+            B::adaptive_avg_pool2d(x.primitive, output_size),
+            node,
+            spatial_dims,
+        )
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+    ) -> FloatTensor<Self, 4> {
+        let output = unsupported("adaptive_avg_pool2d_backward", &[&x.value, &grad.value]);
+
+        TracedTensor::new(
+            B::adaptive_avg_pool2d_backward(x.primitive, grad.primitive),
+            output,
+        )
+    }
+
+    fn max_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        let node = Node::new("MaxPool")
+            .input(float_operand::<B, 4>(&x))
+            .attr_ints("dilations", ints(dilation))
+            .attr_ints("kernel_shape", ints(kernel_size))
+            .attr_ints("pads", pads(padding))
+            .attr_ints("strides", ints(stride));
+
+        float_output::<B, 4, _>(
+            B::max_pool2d(x.primitive, kernel_size, stride, padding, dilation),
+            node,
+            spatial_dims,
+        )
+    }
+
+    fn max_pool2d_with_indices(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        // The indices of ONNX are flattened over the batch and the channels, unlike Burn.
+        let output = unsupported("max_pool2d_with_indices", &[&x.value]);
+        let pool = B::max_pool2d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+
+        MaxPool2dWithIndices::new(
+            TracedTensor::new(pool.output, output.clone()),
+            TracedTensor::new(pool.indices, output),
+        )
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        output_grad: FloatTensor<Self, 4>,
+        indices: IntTensor<Self, 4>,
+    ) -> MaxPool2dBackward<Self> {
+        let output = unsupported(
+            "max_pool2d_with_indices_backward",
+            &[&x.value, &output_grad.value, &indices.value],
+        );
+        let backward = B::max_pool2d_with_indices_backward(
+            x.primitive,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            output_grad.primitive,
+            indices.primitive,
+        );
+
+        MaxPool2dBackward::new(TracedTensor::new(backward.x_grad, output))
+    }
+}
@@ -0,0 +1,714 @@
+use super::base::{
+    bool_operand, bool_output, cat_dims, float_operand, float_output, int_operand, int_output,
+    read_value, reduce_dims, reshape_target, select_dims, slice_target, swap_dims, swap_perm,
+    InnerBool, InnerFloat,
+};
+use crate::graph::{elementwise_dims, unsupported, Dim, Node, Operand, TraceGraph};
+use crate::protos::tensor_proto::DataType;
+use crate::{TracedTensor, Tracer};
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, FullPrecisionBackend, IntTensor},
+    Data, Device, Distribution, ElementConversion, Reader, Shape,
+};
+use core::ops::Range;
+
+fn unary<B: Backend, const D: usize, F>(
+    op: &'static str,
+    tensor: FloatTensor<Tracer<B>, D>,
+    compute: F,
+) -> FloatTensor<Tracer<B>, D>
+where
+    F: FnOnce(InnerFloat<B, D>) -> InnerFloat<B, D>,
+{
+    let node = Node::new(op).input(float_operand::<B, D>(&tensor));
+
+    float_output::<B, D, _>(compute(tensor.primitive), node, elementwise_dims)
+}
+
+fn binary<B: Backend, const D: usize, F>(
+    op: &'static str,
+    lhs: FloatTensor<Tracer<B>, D>,
+    rhs: FloatTensor<Tracer<B>, D>,
+    compute: F,
+) -> FloatTensor<Tracer<B>, D>
+where
+    F: FnOnce(InnerFloat<B, D>, InnerFloat<B, D>) -> InnerFloat<B, D>,
+{
+    let node = Node::new(op)
+        .input(float_operand::<B, D>(&lhs))
+        .input(float_operand::<B, D>(&rhs));
+
+    float_output::<B, D, _>(
+        compute(lhs.primitive, rhs.primitive),
+        node,
+        elementwise_dims,
+    )
+}
+
+fn binary_scalar<B: Backend, const D: usize, F>(
+    op: &'static str,
+    lhs: FloatTensor<Tracer<B>, D>,
+    rhs: FloatElem<B>,
+    compute: F,
+) -> FloatTensor<Tracer<B>, D>
+where
+    F: FnOnce(InnerFloat<B, D>, FloatElem<B>) -> InnerFloat<B, D>,
+{
+    let node = Node::new(op)
+        .input(float_operand::<B, D>(&lhs))
+        .input(Operand::float_scalar(rhs.elem()));
+
+    float_output::<B, D, _>(compute(lhs.primitive, rhs), node, elementwise_dims)
+}
+
+fn compare<B: Backend, const D: usize, F>(
+    op: &'static str,
+    lhs: FloatTensor<Tracer<B>, D>,
+    rhs: FloatTensor<Tracer<B>, D>,
+    compute: F,
+) -> BoolTensor<Tracer<B>, D>
+where
+    F: FnOnce(InnerFloat<B, D>, InnerFloat<B, D>) -> InnerBool<B, D>,
+{
+    let node = Node::new(op)
+        .input(float_operand::<B, D>(&lhs))
+        .input(float_operand::<B, D>(&rhs));
+
+    bool_output::<B, D, _>(
+        compute(lhs.primitive, rhs.primitive),
+        node,
+        elementwise_dims,
+    )
+}
+
+fn compare_scalar<B: Backend, const D: usize, F>(
+    op: &'static str,
+    lhs: FloatTensor<Tracer<B>, D>,
+    rhs: FloatElem<B>,
+    compute: F,
+) -> BoolTensor<Tracer<B>, D>
+where
+    F: FnOnce(InnerFloat<B, D>, FloatElem<B>) -> InnerBool<B, D>,
+{
+    let node = Node::new(op)
+        .input(float_operand::<B, D>(&lhs))
+        .input(Operand::float_scalar(rhs.elem()));
+
+    bool_output::<B, D, _>(compute(lhs.primitive, rhs), node, elementwise_dims)
+}
+
+fn reduce<B: Backend, const D: usize, F>(
+    op: &'static str,
+    tensor: FloatTensor<Tracer<B>, D>,
+    dim: usize,
+    compute: F,
+) -> FloatTensor<Tracer<B>, D>
+where
+    F: FnOnce(InnerFloat<B, D>, usize) -> InnerFloat<B, D>,
+{
+    // The axes are an attribute of the reductions other than the sum with the opset 17.
+    let node = Node::new(op)
+        .input(float_operand::<B, D>(&tensor))
+        .attr_ints("axes", vec![dim as i64])
+        .attr_int("keepdims", 1);
+
+    float_output::<B, D, _>(compute(tensor.primitive, dim), node, reduce_dims(dim))
+}
+
+impl<B: Backend> FloatTensorOps<Self> for Tracer<B> {
+    fn float_from_data<const D: usize>(
+        data: Data<FloatElem<Self>, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        TracedTensor::constant(B::float_from_data(data, device))
+    }
+
+    fn float_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        TracedTensor::constant(B::float_random(shape, distribution, device))
+    }
+
+    fn float_shape<const D: usize>(tensor: &FloatTensor<Self, D>) -> Shape<D> {
+        B::float_shape(&tensor.primitive)
+    }
+
+    fn float_into_data<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> Reader<Data<FloatElem<Self>, D>> {
+        read_value(&tensor.value);
+        B::float_into_data(tensor.primitive)
+    }
+
+    fn float_device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<Self> {
+        B::float_device(&tensor.primitive)
+    }
+
+    fn float_to_device<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        TracedTensor::new(B::float_to_device(tensor.primitive, device), tensor.value)
+    }
+
+    fn float_into_int<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, D> {
+        let node = Node::new("Cast")
+            .input(float_operand::<B, D>(&tensor))
+            .attr_int("to", DataType::INT64 as i64);
+
+        int_output::<B, D, _>(B::float_into_int(tensor.primitive), node, elementwise_dims)
+    }
+
+    fn float_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        TracedTensor::constant(B::float_empty(shape, device))
+    }
+
+    fn float_add<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary::<B, D, _>("Add", lhs, rhs, B::float_add)
+    }
+
+    fn float_add_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        binary_scalar::<B, D, _>("Add", lhs, rhs, B::float_add_scalar)
+    }
+
+    fn float_sub<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary::<B, D, _>("Sub", lhs, rhs, B::float_sub)
+    }
+
+    fn float_sub_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        binary_scalar::<B, D, _>("Sub", lhs, rhs, B::float_sub_scalar)
+    }
+
+    fn float_mul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary::<B, D, _>("Mul", lhs, rhs, B::float_mul)
+    }
+
+    fn float_mul_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        binary_scalar::<B, D, _>("Mul", lhs, rhs, B::float_mul_scalar)
+    }
+
+    fn float_div<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary::<B, D, _>("Div", lhs, rhs, B::float_div)
+    }
+
+    fn float_div_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        binary_scalar::<B, D, _>("Div", lhs, rhs, B::float_div_scalar)
+    }
+
+    fn float_matmul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let node = Node::new("MatMul")
+            .input(float_operand::<B, D>(&lhs))
+            .input(float_operand::<B, D>(&rhs));
+
+        float_output::<B, D, _>(
+            B::float_matmul(lhs.primitive, rhs.primitive),
+            node,
+            |inputs| {
+                let (lhs, rhs) = (&inputs[0], &inputs[1]);
+                let mut dims = elementwise_dims(&[lhs[..D - 2].to_vec(), rhs[..D - 2].to_vec()]);
+                dims.push(lhs[D - 2].clone());
+                dims.push(rhs[D - 1].clone());
+                dims
+            },
+        )
+    }
+
+    fn float_recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary::<B, D, _>("Reciprocal", tensor, B::float_recip)
+    }
+
+    fn float_neg<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary::<B, D, _>("Neg", tensor, B::float_neg)
+    }
+
+    fn float_swap_dims<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> FloatTensor<Self, D> {
+        let node = Node::new("Transpose")
+            .input(float_operand::<B, D>(&tensor))
+            .attr_ints("perm", swap_perm(D, dim1, dim2));
+
+        float_output::<B, D, _>(
+            B::float_swap_dims(tensor.primitive, dim1, dim2),
+            node,
+            swap_dims(dim1, dim2),
+        )
+    }
+
+    fn float_reshape<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        let input_shape = B::float_shape(&tensor.primitive);
+        let input_dims = TraceGraph::dims(&tensor.value, D1);
+        let (target, dims) = reshape_target(&input_shape.dims, &input_dims, &shape.dims);
+
+        let node = Node::new("Reshape")
+            .input(float_operand::<B, D1>(&tensor))
+            .input(Operand::ints(target));
+
+        float_output::<B, D2, _>(B::float_reshape(tensor.primitive, shape), node, |_| dims)
+    }
+
+    fn float_gather<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let node = Node::new("GatherElements")
+            .input(float_operand::<B, D>(&tensor))
+            .input(int_operand::<B, D>(&indices))
+            .attr_int("axis", dim as i64);
+
+        float_output::<B, D, _>(
+            B::float_gather(dim, tensor.primitive, indices.primitive),
+            node,
+            |inputs| inputs[1].clone(),
+        )
+    }
+
+    fn float_scatter<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let node = Node::new("ScatterElements")
+            .input(float_operand::<B, D>(&tensor))
+            .input(int_operand::<B, D>(&indices))
+            .input(float_operand::<B, D>(&value))
+            .attr_int("axis", dim as i64)
+            .attr_string("reduction", "add");
+
+        float_output::<B, D, _>(
+            B::float_scatter(dim, tensor.primitive, indices.primitive, value.primitive),
+            node,
+            |inputs| inputs[0].clone(),
+        )
+    }
+
+    fn float_select<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> FloatTensor<Self, D> {
+        let node = Node::new("Gather")
+            .input(float_operand::<B, D>(&tensor))
+            .input(int_operand::<B, 1>(&indices))
+            .attr_int("axis", dim as i64);
+
+        float_output::<B, D, _>(
+            B::float_select(tensor.primitive, dim, indices.primitive),
+            node,
+            select_dims(dim),
+        )
+    }
+
+    fn float_select_assign<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let output = unsupported(
+            "select_assign",
+            &[&tensor.value, &indices.value, &value.value],
+        );
+
+        TracedTensor::new(
+            B::float_select_assign(tensor.primitive, dim, indices.primitive, value.primitive),
+            output,
+        )
+    }
+
+    fn float_slice<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> FloatTensor<Self, D1> {
+        let input_shape = B::float_shape(&tensor.primitive);
+        let input_dims = TraceGraph::dims(&tensor.value, D1);
+        let (starts, ends, axes, dims) = slice_target(&input_shape.dims, &input_dims, &ranges);
+
+        let node = Node::new("Slice")
+            .input(float_operand::<B, D1>(&tensor))
+            .input(Operand::ints(starts))
+            .input(Operand::ints(ends))
+            .input(Operand::ints(axes));
+
+        float_output::<B, D1, _>(B::float_slice(tensor.primitive, ranges), node, |_| dims)
+    }
+
+    fn float_slice_assign<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: FloatTensor<Self, D1>,
+    ) -> FloatTensor<Self, D1> {
+        let output = unsupported("slice_assign", &[&tensor.value, &value.value]);
+
+        TracedTensor::new(
+            B::float_slice_assign(tensor.primitive, ranges, value.primitive),
+            output,
+        )
+    }
+
+    fn float_mask_where<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let node = Node::new("Where")
+            .input(bool_operand::<B, D>(&mask))
+            .input(float_operand::<B, D>(&value))
+            .input(float_operand::<B, D>(&tensor));
+
+        float_output::<B, D, _>(
+            B::float_mask_where(tensor.primitive, mask.primitive, value.primitive),
+            node,
+            elementwise_dims,
+        )
+    }
+
+    fn float_mask_fill<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let node = Node::new("Where")
+            .input(bool_operand::<B, D>(&mask))
+            .input(Operand::float_scalar(value.elem()))
+            .input(float_operand::<B, D>(&tensor));
+
+        float_output::<B, D, _>(
+            B::float_mask_fill(tensor.primitive, mask.primitive, value),
+            node,
+            elementwise_dims,
+        )
+    }
+
+    fn float_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        compare::<B, D, _>("Equal", lhs, rhs, B::float_equal)
+    }
+
+    fn float_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        compare_scalar::<B, D, _>("Equal", lhs, rhs, B::float_equal_elem)
+    }
+
+    fn float_greater<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        compare::<B, D, _>("Greater", lhs, rhs, B::float_greater)
+    }
+
+    fn float_greater_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        compare_scalar::<B, D, _>("Greater", lhs, rhs, B::float_greater_elem)
+    }
+
+    fn float_greater_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        compare::<B, D, _>("GreaterOrEqual", lhs, rhs, B::float_greater_equal)
+    }
+
+    fn float_greater_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        compare_scalar::<B, D, _>("GreaterOrEqual", lhs, rhs, B::float_greater_equal_elem)
+    }
+
+    fn float_lower<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        compare::<B, D, _>("Less", lhs, rhs, B::float_lower)
+    }
+
+    fn float_lower_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        compare_scalar::<B, D, _>("Less", lhs, rhs, B::float_lower_elem)
+    }
+
+    fn float_lower_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        compare::<B, D, _>("LessOrEqual", lhs, rhs, B::float_lower_equal)
+    }
+
+    fn float_lower_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        compare_scalar::<B, D, _>("LessOrEqual", lhs, rhs, B::float_lower_equal_elem)
+    }
+
+    fn float_sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        let node = Node::new("ReduceSum")
+            .input(float_operand::<B, D>(&tensor))
+            .attr_int("keepdims", 0);
+        let sum = float_output::<B, 1, _>(B::float_sum(tensor.primitive), node, |_| vec![]);
+
+        // The sum is a scalar with ONNX, and a tensor of one element with Burn.
+        let node = Node::new("Reshape")
+            .input(float_operand::<B, 1>(&sum))
+            .input(Operand::ints(vec![1]));
+
+        float_output::<B, 1, _>(sum.primitive, node, |_| vec![Dim::Static])
+    }
+
+    fn float_sum_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        // The axes are an input of the sum since the opset 13.
+        let node = Node::new("ReduceSum")
+            .input(float_operand::<B, D>(&tensor))
+            .input(Operand::ints(vec![dim as i64]))
+            .attr_int("keepdims", 1);
+
+        float_output::<B, D, _>(
+            B::float_sum_dim(tensor.primitive, dim),
+            node,
+            reduce_dims(dim),
+        )
+    }
+
+    fn float_mean_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        reduce::<B, D, _>("ReduceMean", tensor, dim, B::float_mean_dim)
+    }
+
+    fn float_max_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        reduce::<B, D, _>("ReduceMax", tensor, dim, B::float_max_dim)
+    }
+
+    fn float_min_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        reduce::<B, D, _>("ReduceMin", tensor, dim, B::float_min_dim)
+    }
+
+    fn float_to_full_precision<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+    ) -> FloatTensor<FullPrecisionBackend<Self>, D> {
+        TracedTensor::new(
+            B::float_to_full_precision(&tensor.primitive),
+            tensor.value.clone(),
+        )
+    }
+
+    fn float_from_full_precision<const D: usize>(
+        tensor: FloatTensor<FullPrecisionBackend<Self>, D>,
+    ) -> FloatTensor<Self, D> {
+        TracedTensor::new(B::float_from_full_precision(tensor.primitive), tensor.value)
+    }
+
+    fn float_exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary::<B, D, _>("Exp", tensor, B::float_exp)
+    }
+
+    fn float_log<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary::<B, D, _>("Log", tensor, B::float_log)
+    }
+
+    fn float_log1p<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let output = B::float_log1p(tensor.primitive.clone());
+        let tensor = binary_scalar::<B, D, _>("Add", tensor, 1.elem(), B::float_add_scalar);
+
+        unary::<B, D, _>("Log", tensor, |_| output)
+    }
+
+    fn float_powf<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        binary::<B, D, _>("Pow", lhs, rhs, B::float_powf)
+    }
+
+    fn float_powf_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        value: f32,
+    ) -> FloatTensor<Self, D> {
+        binary_scalar::<B, D, _>("Pow", tensor, value.elem(), |tensor, _| {
+            B::float_powf_scalar(tensor, value)
+        })
+    }
+
+    fn float_sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary::<B, D, _>("Sqrt", tensor, B::float_sqrt)
+    }
+
+    fn float_abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary::<B, D, _>("Abs", tensor, B::float_abs)
+    }
+
+    fn float_cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary::<B, D, _>("Cos", tensor, B::float_cos)
+    }
+
+    fn float_sin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary::<B, D, _>("Sin", tensor, B::float_sin)
+    }
+
+    fn float_tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary::<B, D, _>("Tanh", tensor, B::float_tanh)
+    }
+
+    fn float_erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary::<B, D, _>("Erf", tensor, B::float_erf)
+    }
+
+    fn float_clamp<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        let node = Node::new("Clip")
+            .input(float_operand::<B, D>(&tensor))
+            .input(Operand::float_scalar(min.elem()))
+            .input(Operand::float_scalar(max.elem()));
+
+        float_output::<B, D, _>(
+            B::float_clamp(tensor.primitive, min, max),
+            node,
+            elementwise_dims,
+        )
+    }
+
+    fn float_clamp_min<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        binary_scalar::<B, D, _>("Max", tensor, min, B::float_clamp_min)
+    }
+
+    fn float_clamp_max<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        binary_scalar::<B, D, _>("Min", tensor, max, B::float_clamp_max)
+    }
+
+    fn float_repeat<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> FloatTensor<Self, D> {
+        let mut repeats = vec![1; D];
+        repeats[dim] = times as i64;
+
+        let node = Node::new("Tile")
+            .input(float_operand::<B, D>(&tensor))
+            .input(Operand::ints(repeats));
+
+        float_output::<B, D, _>(
+            B::float_repeat(tensor.primitive, dim, times),
+            node,
+            |inputs| {
+                let mut dims = inputs[0].clone();
+                if !dims[dim].is_static() {
+                    dims[dim] = Dim::Dynamic;
+                }
+                dims
+            },
+        )
+    }
+
+    fn float_cat<const D: usize>(
+        tensors: Vec<FloatTensor<Self, D>>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let node = tensors
+            .iter()
+            .fold(Node::new("Concat"), |node, tensor| {
+                node.input(float_operand::<B, D>(tensor))
+            })
+            .attr_int("axis", dim as i64);
+        let primitives = tensors.into_iter().map(|tensor| tensor.primitive).collect();
+
+        float_output::<B, D, _>(B::float_cat(primitives, dim), node, cat_dims(dim))
+    }
+
+    fn float_argmax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        let node = Node::new("ArgMax")
+            .input(float_operand::<B, D>(&tensor))
+            .attr_int("axis", dim as i64)
+            .attr_int("keepdims", 1);
+
+        int_output::<B, D, _>(
+            B::float_argmax(tensor.primitive, dim),
+            node,
+            reduce_dims(dim),
+        )
+    }
+
+    fn float_argmin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        let node = Node::new("ArgMin")
+            .input(float_operand::<B, D>(&tensor))
+            .attr_int("axis", dim as i64)
+            .attr_int("keepdims", 1);
+
+        int_output::<B, D, _>(
+            B::float_argmin(tensor.primitive, dim),
+            node,
+            reduce_dims(dim),
+        )
+    }
+}
@@ -0,0 +1,25 @@
+/// A tensor of the [tracer](crate::Tracer) backend, wrapping a tensor of the inner backend.
+#[derive(new, Debug, Clone)]
+pub struct TracedTensor<P> {
+    pub(crate) primitive: P,
+    pub(crate) value: TracedValue,
+}
+
+/// Where the elements of a [traced tensor](TracedTensor) come from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TracedValue {
+    /// The tensor doesn't depend on the traced input, it's stored in the graph as a constant when
+    /// an operation uses it.
+    Constant,
+    /// The tensor is the output of an operation of the graph, identified by its index.
+    Node(usize),
+    /// The tensor depends on the traced input through an operation that can't be exported.
+    Unsupported(&'static str),
+}
+
+impl<P> TracedTensor<P> {
+    /// Creates a tensor which doesn't depend on the traced input.
+    pub(crate) fn constant(primitive: P) -> Self {
+        Self::new(primitive, TracedValue::Constant)
+    }
+}
@@ -0,0 +1,529 @@
+//! A reference implementation of the ONNX operators used by the exported graphs, evaluating them
+//! on the CPU to check the exported models.
+
+use crate::protos::{tensor_proto::DataType, AttributeProto, ModelProto, NodeProto, TensorProto};
+use protobuf::Enum;
+use std::collections::HashMap;
+
+/// A tensor of the evaluator, the elements of every type being stored as `f64`.
+#[derive(Debug, Clone)]
+pub struct Array {
+    pub shape: Vec<usize>,
+    pub data: Vec<f64>,
+    pub elem_type: DataType,
+}
+
+impl Array {
+    pub fn new(shape: Vec<usize>, data: Vec<f64>, elem_type: DataType) -> Self {
+        assert_eq!(shape.iter().product::<usize>(), data.len());
+        Self {
+            shape,
+            data,
+            elem_type,
+        }
+    }
+
+    fn map(&self, elem_type: DataType, func: impl Fn(f64) -> f64) -> Self {
+        Self::new(
+            self.shape.clone(),
+            self.data.iter().map(|value| func(*value)).collect(),
+            elem_type,
+        )
+    }
+
+    fn ints(&self) -> Vec<i64> {
+        self.data.iter().map(|value| *value as i64).collect()
+    }
+}
+
+/// Evaluates the graph of a model with one input and one output.
+pub fn evaluate(model: &ModelProto, input: Array) -> Array {
+    let graph = model
+        .graph
+        .as_ref()
+        .expect("The model should have a graph.");
+    let mut values = HashMap::new();
+
+    for initializer in graph.initializer.iter() {
+        values.insert(initializer.name.clone(), from_proto(initializer));
+    }
+    values.insert(graph.input[0].name.clone(), input);
+
+    for node in graph.node.iter() {
+        let inputs: Vec<&Array> = node.input.iter().map(|name| &values[name]).collect();
+        let output = evaluate_node(node, &inputs);
+        values.insert(node.output[0].clone(), output);
+    }
+
+    values.remove(&graph.output[0].name).unwrap()
+}
+
+fn from_proto(tensor: &TensorProto) -> Array {
+    let shape = tensor.dims.iter().map(|dim| *dim as usize).collect();
+    let elem_type = DataType::from_i32(tensor.data_type).unwrap();
+    let data = match elem_type {
+        DataType::FLOAT => tensor
+            .raw_data
+            .chunks(4)
+            .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()) as f64)
+            .collect(),
+        DataType::INT64 => tensor
+            .raw_data
+            .chunks(8)
+            .map(|bytes| i64::from_le_bytes(bytes.try_into().unwrap()) as f64)
+            .collect(),
+        DataType::BOOL => tensor.raw_data.iter().map(|byte| *byte as f64).collect(),
+        elem_type => panic!("Unsupported element type {elem_type:?}"),
+    };
+
+    Array::new(shape, data, elem_type)
+}
+
+fn attribute<'a>(node: &'a NodeProto, name: &str) -> Option<&'a AttributeProto> {
+    node.attribute
+        .iter()
+        .find(|attribute| attribute.name == name)
+}
+
+fn attr_int(node: &NodeProto, name: &str, default: i64) -> i64 {
+    attribute(node, name)
+        .map(|attribute| attribute.i)
+        .unwrap_or(default)
+}
+
+fn attr_ints(node: &NodeProto, name: &str) -> Option<Vec<i64>> {
+    attribute(node, name).map(|attribute| attribute.ints.clone())
+}
+
+fn evaluate_node(node: &NodeProto, inputs: &[&Array]) -> Array {
+    let elem_type = inputs[0].elem_type;
+
+    match node.op_type.as_str() {
+        "Add" => broadcast(inputs, elem_type, |values| values[0] + values[1]),
+        "Sub" => broadcast(inputs, elem_type, |values| values[0] - values[1]),
+        "Mul" => broadcast(inputs, elem_type, |values| values[0] * values[1]),
+        "Div" if elem_type == DataType::INT64 => {
+            broadcast(inputs, elem_type, |values| (values[0] / values[1]).trunc())
+        }
+        "Div" => broadcast(inputs, elem_type, |values| values[0] / values[1]),
+        "Pow" => broadcast(inputs, elem_type, |values| values[0].powf(values[1])),
+        "Max" => broadcast(inputs, elem_type, |values| values[0].max(values[1])),
+        "Min" => broadcast(inputs, elem_type, |values| values[0].min(values[1])),
+        "Equal" => compare(inputs, |lhs, rhs| lhs == rhs),
+        "Greater" => compare(inputs, |lhs, rhs| lhs > rhs),
+        "GreaterOrEqual" => compare(inputs, |lhs, rhs| lhs >= rhs),
+        "Less" => compare(inputs, |lhs, rhs| lhs < rhs),
+        "LessOrEqual" => compare(inputs, |lhs, rhs| lhs <= rhs),
+        "Where" => broadcast(inputs, inputs[1].elem_type, |values| {
+            match values[0] != 0.0 {
+                true => values[1],
+                false => values[2],
+            }
+        }),
+        "Clip" => broadcast(inputs, elem_type, |values| {
+            values[0].max(values[1]).min(values[2])
+        }),
+        "Neg" => inputs[0].map(elem_type, |value| -value),
+        "Exp" => inputs[0].map(elem_type, f64::exp),
+        "Log" => inputs[0].map(elem_type, f64::ln),
+        "Sqrt" => inputs[0].map(elem_type, f64::sqrt),
+        "Abs" => inputs[0].map(elem_type, f64::abs),
+        "Cos" => inputs[0].map(elem_type, f64::cos),
+        "Sin" => inputs[0].map(elem_type, f64::sin),
+        "Tanh" => inputs[0].map(elem_type, f64::tanh),
+        "Erf" => inputs[0].map(elem_type, libm::erf),
+        "Reciprocal" => inputs[0].map(elem_type, f64::recip),
+        "Relu" => inputs[0].map(elem_type, |value| value.max(0.0)),
+        "Sigmoid" => inputs[0].map(elem_type, |value| 1.0 / (1.0 + (-value).exp())),
+        "Not" => inputs[0].map(elem_type, |value| (value == 0.0) as i64 as f64),
+        "Cast" => {
+            let elem_type = DataType::from_i32(attr_int(node, "to", 0) as i32).unwrap();
+            match elem_type {
+                DataType::INT64 => inputs[0].map(elem_type, f64::trunc),
+                DataType::BOOL => inputs[0].map(elem_type, |value| (value != 0.0) as i64 as f64),
+                _ => inputs[0].map(elem_type, |value| value as f32 as f64),
+            }
+        }
+        "MatMul" => matmul(inputs[0], inputs[1]),
+        "Transpose" => transpose(inputs[0], &attr_ints(node, "perm").unwrap()),
+        "Reshape" => reshape(inputs[0], &inputs[1].ints()),
+        "Slice" => slice(
+            inputs[0],
+            &inputs[1].ints(),
+            &inputs[2].ints(),
+            &inputs[3].ints(),
+        ),
+        "Concat" => concat(inputs, attr_int(node, "axis", 0) as usize),
+        "Gather" => gather(inputs[0], inputs[1], attr_int(node, "axis", 0) as usize),
+        "ReduceSum" => {
+            let axes = inputs.get(1).map(|axes| axes.ints()).unwrap_or_default();
+            reduce(
+                inputs[0],
+                &axes,
+                attr_int(node, "keepdims", 1),
+                0.0,
+                |acc, v| acc + v,
+            )
+        }
+        "ReduceMean" => {
+            let axes = attr_ints(node, "axes").unwrap();
+            let size: usize = axes
+                .iter()
+                .map(|axis| inputs[0].shape[*axis as usize])
+                .product();
+            reduce(
+                inputs[0],
+                &axes,
+                attr_int(node, "keepdims", 1),
+                0.0,
+                |acc, v| acc + v,
+            )
+            .map(elem_type, |value| value / size as f64)
+        }
+        "ReduceMax" => {
+            let axes = attr_ints(node, "axes").unwrap();
+            let keepdims = attr_int(node, "keepdims", 1);
+            reduce(inputs[0], &axes, keepdims, f64::NEG_INFINITY, f64::max)
+        }
+        "ReduceMin" => {
+            let axes = attr_ints(node, "axes").unwrap();
+            let keepdims = attr_int(node, "keepdims", 1);
+            reduce(inputs[0], &axes, keepdims, f64::INFINITY, f64::min)
+        }
+        "Conv" => conv(node, inputs),
+        op => panic!("Unsupported operator {op}"),
+    }
+}
+
+fn strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for axis in (0..shape.len().saturating_sub(1)).rev() {
+        strides[axis] = strides[axis + 1] * shape[axis + 1];
+    }
+    strides
+}
+
+fn unravel(mut index: usize, shape: &[usize]) -> Vec<usize> {
+    let mut position = vec![0; shape.len()];
+    for axis in (0..shape.len()).rev() {
+        position[axis] = index % shape[axis];
+        index /= shape[axis];
+    }
+    position
+}
+
+/// The index of an element of an array broadcasted to the position of the output.
+fn broadcast_index(array: &Array, position: &[usize]) -> usize {
+    let offset = position.len() - array.shape.len();
+
+    array
+        .shape
+        .iter()
+        .zip(strides(&array.shape))
+        .enumerate()
+        .map(|(axis, (size, stride))| match size {
+            1 => 0,
+            _ => position[axis + offset] * stride,
+        })
+        .sum()
+}
+
+fn broadcast_shape(shapes: &[&[usize]]) -> Vec<usize> {
+    let rank = shapes.iter().map(|shape| shape.len()).max().unwrap();
+
+    (0..rank)
+        .map(|axis| {
+            shapes
+                .iter()
+                .filter_map(|shape| (axis + shape.len()).checked_sub(rank).map(|a| shape[a]))
+                .max()
+                .unwrap()
+        })
+        .collect()
+}
+
+fn broadcast(inputs: &[&Array], elem_type: DataType, func: impl Fn(&[f64]) -> f64) -> Array {
+    let shapes: Vec<&[usize]> = inputs.iter().map(|input| input.shape.as_slice()).collect();
+    let shape = broadcast_shape(&shapes);
+    let num_elements = shape.iter().product();
+
+    let data = (0..num_elements)
+        .map(|index| {
+            let position = unravel(index, &shape);
+            let values: Vec<f64> = inputs
+                .iter()
+                .map(|input| input.data[broadcast_index(input, &position)])
+                .collect();
+            func(&values)
+        })
+        .collect();
+
+    Array::new(shape, data, elem_type)
+}
+
+fn compare(inputs: &[&Array], func: impl Fn(f64, f64) -> bool) -> Array {
+    broadcast(inputs, DataType::BOOL, |values| {
+        func(values[0], values[1]) as i64 as f64
+    })
+}
+
+fn matmul(lhs: &Array, rhs: &Array) -> Array {
+    let rank = lhs.shape.len();
+    let [m, k, n] = [
+        lhs.shape[rank - 2],
+        lhs.shape[rank - 1],
+        rhs.shape[rank - 1],
+    ];
+    let batch = broadcast_shape(&[&lhs.shape[..rank - 2], &rhs.shape[..rank - 2]]);
+    let mut shape = batch.clone();
+    shape.extend([m, n]);
+
+    let num_elements = shape.iter().product();
+    let data = (0..num_elements)
+        .map(|index| {
+            let position = unravel(index, &shape);
+            let (i, j) = (position[rank - 2], position[rank - 1]);
+
+            (0..k)
+                .map(|l| {
+                    let mut lhs_position = position.clone();
+                    lhs_position[rank - 2] = i;
+                    lhs_position[rank - 1] = l;
+                    let mut rhs_position = position.clone();
+                    rhs_position[rank - 2] = l;
+                    rhs_position[rank - 1] = j;
+
+                    lhs.data[broadcast_index(lhs, &lhs_position)]
+                        * rhs.data[broadcast_index(rhs, &rhs_position)]
+                })
+                .sum()
+        })
+        .collect();
+
+    Array::new(shape, data, lhs.elem_type)
+}
+
+fn transpose(input: &Array, perm: &[i64]) -> Array {
+    let shape: Vec<usize> = perm
+        .iter()
+        .map(|axis| input.shape[*axis as usize])
+        .collect();
+    let input_strides = strides(&input.shape);
+
+    let data = (0..input.data.len())
+        .map(|index| {
+            let position = unravel(index, &shape);
+            let input_index: usize = perm
+                .iter()
+                .zip(position)
+                .map(|(axis, pos)| pos * input_strides[*axis as usize])
+                .sum();
+            input.data[input_index]
+        })
+        .collect();
+
+    Array::new(shape, data, input.elem_type)
+}
+
+fn reshape(input: &Array, target: &[i64]) -> Array {
+    let mut shape: Vec<usize> = target
+        .iter()
+        .enumerate()
+        .map(|(axis, size)| match size {
+            0 => input.shape[axis],
+            -1 => 1,
+            size => *size as usize,
+        })
+        .collect();
+
+    if let Some(axis) = target.iter().position(|size| *size == -1) {
+        shape[axis] = input.data.len() / shape.iter().product::<usize>();
+    }
+
+    Array::new(shape, input.data.clone(), input.elem_type)
+}
+
+fn slice(input: &Array, starts: &[i64], ends: &[i64], axes: &[i64]) -> Array {
+    let mut ranges: Vec<(usize, usize)> = input.shape.iter().map(|size| (0, *size)).collect();
+    for ((start, end), axis) in starts.iter().zip(ends).zip(axes) {
+        let size = input.shape[*axis as usize] as i64;
+        ranges[*axis as usize] = (
+            (*start).clamp(0, size) as usize,
+            (*end).clamp(0, size) as usize,
+        );
+    }
+
+    let shape: Vec<usize> = ranges.iter().map(|(start, end)| end - start).collect();
+    let input_strides = strides(&input.shape);
+    let num_elements = shape.iter().product();
+
+    let data = (0..num_elements)
+        .map(|index| {
+            let position = unravel(index, &shape);
+            let input_index: usize = position
+                .iter()
+                .zip(ranges.iter())
+                .zip(input_strides.iter())
+                .map(|((pos, (start, _)), stride)| (pos + start) * stride)
+                .sum();
+            input.data[input_index]
+        })
+        .collect();
+
+    Array::new(shape, data, input.elem_type)
+}
+
+fn concat(inputs: &[&Array], axis: usize) -> Array {
+    let mut shape = inputs[0].shape.clone();
+    shape[axis] = inputs.iter().map(|input| input.shape[axis]).sum();
+    let num_elements = shape.iter().product();
+
+    let data = (0..num_elements)
+        .map(|index| {
+            let mut position = unravel(index, &shape);
+            for input in inputs {
+                if position[axis] < input.shape[axis] {
+                    return input.data[broadcast_index(input, &position)];
+                }
+                position[axis] -= input.shape[axis];
+            }
+            unreachable!()
+        })
+        .collect();
+
+    Array::new(shape, data, inputs[0].elem_type)
+}
+
+fn gather(input: &Array, indices: &Array, axis: usize) -> Array {
+    let mut shape = input.shape[..axis].to_vec();
+    shape.extend(indices.shape.iter());
+    shape.extend(input.shape[axis + 1..].iter());
+    let input_strides = strides(&input.shape);
+    let num_elements = shape.iter().product();
+
+    let data = (0..num_elements)
+        .map(|index| {
+            let position = unravel(index, &shape);
+            let rank = indices.shape.len();
+            let indices_index = broadcast_index(indices, &position[axis..axis + rank]);
+            let selected = indices.data[indices_index] as usize;
+
+            let input_position = position[..axis]
+                .iter()
+                .copied()
+                .chain([selected])
+                .chain(position[axis + rank..].iter().copied());
+            let input_index: usize = input_position
+                .zip(input_strides.iter())
+                .map(|(pos, stride)| pos * stride)
+                .sum();
+            input.data[input_index]
+        })
+        .collect();
+
+    Array::new(shape, data, input.elem_type)
+}
+
+fn reduce(
+    input: &Array,
+    axes: &[i64],
+    keepdims: i64,
+    init: f64,
+    func: impl Fn(f64, f64) -> f64,
+) -> Array {
+    let reduced: Vec<bool> = (0..input.shape.len())
+        .map(|axis| axes.is_empty() || axes.contains(&(axis as i64)))
+        .collect();
+    let kept_shape: Vec<usize> = input
+        .shape
+        .iter()
+        .zip(reduced.iter())
+        .map(|(size, reduced)| if *reduced { 1 } else { *size })
+        .collect();
+
+    let mut data = vec![init; kept_shape.iter().product()];
+    let kept_strides = strides(&kept_shape);
+    for (index, value) in input.data.iter().enumerate() {
+        let position = unravel(index, &input.shape);
+        let output_index: usize = position
+            .iter()
+            .zip(reduced.iter())
+            .zip(kept_strides.iter())
+            .map(|((pos, reduced), stride)| if *reduced { 0 } else { pos * stride })
+            .sum();
+        data[output_index] = func(data[output_index], *value);
+    }
+
+    let shape = match keepdims {
+        0 => kept_shape
+            .into_iter()
+            .zip(reduced)
+            .filter(|(_, reduced)| !reduced)
+            .map(|(size, _)| size)
+            .collect(),
+        _ => kept_shape,
+    };
+
+    Array::new(shape, data, input.elem_type)
+}
+
+fn conv(node: &NodeProto, inputs: &[&Array]) -> Array {
+    let (x, weight) = (inputs[0], inputs[1]);
+    let [batch_size, channels_in, height, width] = [x.shape[0], x.shape[1], x.shape[2], x.shape[3]];
+    let [channels_out, channels_per_group, kernel_height, kernel_width] = [
+        weight.shape[0],
+        weight.shape[1],
+        weight.shape[2],
+        weight.shape[3],
+    ];
+    let groups = attr_int(node, "group", 1) as usize;
+    let pads = attr_ints(node, "pads").unwrap_or(vec![0; 4]);
+    let strides = attr_ints(node, "strides").unwrap_or(vec![1; 2]);
+    let dilations = attr_ints(node, "dilations").unwrap_or(vec![1; 2]);
+
+    let output_size = |size: usize, kernel: usize, axis: usize| {
+        let padded = size as i64 + pads[axis] + pads[axis + 2];
+        ((padded - dilations[axis] * (kernel as i64 - 1) - 1) / strides[axis] + 1) as usize
+    };
+    let shape = vec![
+        batch_size,
+        channels_out,
+        output_size(height, kernel_height, 0),
+        output_size(width, kernel_width, 1),
+    ];
+    let out_per_group = channels_out / groups;
+    assert_eq!(channels_in, channels_per_group * groups);
+
+    let num_elements = shape.iter().product();
+    let data = (0..num_elements)
+        .map(|index| {
+            let [b, c, i, j]: [usize; 4] = unravel(index, &shape).try_into().unwrap();
+            let group = c / out_per_group;
+            let mut sum = inputs.get(2).map(|bias| bias.data[c]).unwrap_or(0.0);
+
+            for ci in 0..channels_per_group {
+                let channel = group * channels_per_group + ci;
+                for ki in 0..kernel_height {
+                    for kj in 0..kernel_width {
+                        let y = (i as i64 * strides[0] + ki as i64 * dilations[0]) - pads[0];
+                        let x_pos = (j as i64 * strides[1] + kj as i64 * dilations[1]) - pads[1];
+                        if y < 0 || x_pos < 0 || y >= height as i64 || x_pos >= width as i64 {
+                            continue;
+                        }
+                        let input_index = ((b * channels_in + channel) * height + y as usize)
+                            * width
+                            + x_pos as usize;
+                        let weight_index = ((c * channels_per_group + ci) * kernel_height + ki)
+                            * kernel_width
+                            + kj;
+                        sum += x.data[input_index] * weight.data[weight_index];
+                    }
+                }
+            }
+
+            sum
+        })
+        .collect();
+
+    Array::new(shape, data, x.elem_type)
+}
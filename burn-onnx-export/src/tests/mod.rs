@@ -0,0 +1,202 @@
+mod evaluator;
+
+use crate::protos::{tensor_proto::DataType, ModelProto};
+use crate::{export, export_with_config, ExportConfig, ExportError, Tracer};
+use burn_core::nn::{
+    attention::{MhaInput, MultiHeadAttentionConfig},
+    conv::Conv2dConfig,
+    BatchNormConfig, LinearConfig, PaddingConfig2d, ReLU, GELU,
+};
+use burn_core::tensor::{activation, Distribution, Tensor};
+use burn_ndarray::{NdArray, NdArrayDevice};
+use evaluator::{evaluate, Array};
+use protobuf::Message;
+
+type TestBackend = Tracer<NdArray<f32>>;
+
+fn load(path: &std::path::Path) -> ModelProto {
+    ModelProto::parse_from_bytes(&std::fs::read(path).unwrap()).unwrap()
+}
+
+fn to_array<const D: usize>(tensor: Tensor<TestBackend, D>) -> Array {
+    let data = tensor.into_data();
+    let values = data.value.into_iter().map(|value| value as f64).collect();
+
+    Array::new(data.shape.dims.to_vec(), values, DataType::FLOAT)
+}
+
+/// Checks that the exported model computes the same output as the traced model.
+fn assert_same_output<F, const D1: usize, const D2: usize>(
+    model: &ModelProto,
+    forward: F,
+    input: Tensor<TestBackend, D1>,
+) where
+    F: FnOnce(Tensor<TestBackend, D1>) -> Tensor<TestBackend, D2>,
+{
+    let expected = to_array(forward(input.clone()));
+    let output = evaluate(model, to_array(input));
+
+    assert_eq!(output.shape, expected.shape);
+    for (value, expected) in output.data.iter().zip(expected.data.iter()) {
+        assert!(
+            (value - expected).abs() < 1e-4,
+            "Expected {expected}, got {value}"
+        );
+    }
+}
+
+fn input_dims(model: &ModelProto) -> Vec<String> {
+    let input = &model.graph.input[0];
+    let shape = &input.type_.tensor_type().shape;
+
+    shape
+        .dim
+        .iter()
+        .map(|dim| match dim.has_dim_param() {
+            true => dim.dim_param().to_string(),
+            false => dim.dim_value().to_string(),
+        })
+        .collect()
+}
+
+fn output_dims(model: &ModelProto) -> Vec<String> {
+    let output = &model.graph.output[0];
+    let shape = &output.type_.tensor_type().shape;
+
+    shape
+        .dim
+        .iter()
+        .map(|dim| match (dim.has_dim_param(), dim.has_dim_value()) {
+            (true, _) => dim.dim_param().to_string(),
+            (false, true) => dim.dim_value().to_string(),
+            (false, false) => "?".to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn should_export_linear_gelu_softmax() {
+    let device = NdArrayDevice::default();
+    let linear_1 = LinearConfig::new(4, 8).init::<TestBackend>(&device);
+    let linear_2 = LinearConfig::new(8, 3).init::<TestBackend>(&device);
+    let gelu = GELU::new();
+    let forward = |input: Tensor<TestBackend, 2>| {
+        let x = gelu.forward(linear_1.forward(input));
+        activation::softmax(linear_2.forward(x), 1)
+    };
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("mlp.onnx");
+
+    let input = Tensor::random([2, 4], Distribution::Default, &device);
+    let config = ExportConfig::new().with_dynamic_dim(0, "batch_size");
+    export_with_config(forward, input, &path, &config).unwrap();
+
+    let model = load(&path);
+    assert_eq!(input_dims(&model), ["batch_size", "4"]);
+    assert_eq!(output_dims(&model), ["batch_size", "3"]);
+    let input = Tensor::random([5, 4], Distribution::Default, &device);
+    assert_same_output(&model, forward, input);
+}
+
+#[test]
+fn should_export_conv2d_batch_norm_relu() {
+    let device = NdArrayDevice::default();
+    let conv = Conv2dConfig::new([3, 4], [3, 3])
+        .with_stride([2, 1])
+        .with_padding(PaddingConfig2d::Explicit(1, 1))
+        .init::<TestBackend>(&device);
+    let norm = BatchNormConfig::new(4).init::<TestBackend, 2>(&device);
+    let relu = ReLU::new();
+    let forward = |input: Tensor<TestBackend, 4>| relu.forward(norm.forward(conv.forward(input)));
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("conv.onnx");
+
+    let input = Tensor::random([2, 3, 8, 6], Distribution::Default, &device);
+    let config = ExportConfig::new().with_dynamic_dim(0, "batch_size");
+    export_with_config(forward, input, &path, &config).unwrap();
+
+    let model = load(&path);
+    assert_eq!(output_dims(&model), ["batch_size", "4", "4", "6"]);
+    let input = Tensor::random([3, 3, 8, 6], Distribution::Default, &device);
+    assert_same_output(&model, forward, input);
+}
+
+#[test]
+fn should_export_multi_head_attention_with_symbolic_dims() {
+    let device = NdArrayDevice::default();
+    let mha = MultiHeadAttentionConfig::new(8, 2).init::<TestBackend>(&device);
+    let forward = |input: Tensor<TestBackend, 3>| mha.forward(MhaInput::self_attn(input)).context;
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("mha.onnx");
+
+    let input = Tensor::random([2, 5, 8], Distribution::Default, &device);
+    let config = ExportConfig::new()
+        .with_dynamic_dim(0, "batch_size")
+        .with_dynamic_dim(1, "seq_length");
+    export_with_config(forward, input, &path, &config).unwrap();
+
+    let model = load(&path);
+    assert_eq!(input_dims(&model), ["batch_size", "seq_length", "8"]);
+    assert_eq!(output_dims(&model), ["batch_size", "seq_length", "8"]);
+    let input = Tensor::random([3, 7, 8], Distribution::Default, &device);
+    assert_same_output(&model, forward, input);
+}
+
+#[test]
+fn should_export_static_dims_by_default() {
+    let device = NdArrayDevice::default();
+    let forward = |input: Tensor<TestBackend, 2>| input.exp().sum_dim(1);
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("static.onnx");
+
+    let input = Tensor::random([2, 4], Distribution::Default, &device);
+    export(forward, input.clone(), &path).unwrap();
+
+    let model = load(&path);
+    assert_eq!(input_dims(&model), ["2", "4"]);
+    assert_eq!(output_dims(&model), ["2", "1"]);
+    assert_same_output(&model, forward, input);
+}
+
+#[test]
+fn should_fail_with_unsupported_operation() {
+    let device = NdArrayDevice::default();
+    let values = Tensor::<TestBackend, 2>::zeros([1, 4], &device);
+    let forward = |input: Tensor<TestBackend, 2>| input.slice_assign([0..1, 0..4], values);
+    let dir = tempfile::tempdir().unwrap();
+
+    let input = Tensor::random([2, 4], Distribution::Default, &device);
+    let result = export(forward, input, dir.path().join("model.onnx"));
+
+    assert!(matches!(
+        result,
+        Err(ExportError::UnsupportedOperation(op)) if op == "slice_assign"
+    ));
+}
+
+#[test]
+fn should_fail_when_reading_the_input() {
+    let device = NdArrayDevice::default();
+    let forward = |input: Tensor<TestBackend, 2>| {
+        let _data = input.to_data();
+        input.exp()
+    };
+    let dir = tempfile::tempdir().unwrap();
+
+    let input = Tensor::random([2, 4], Distribution::Default, &device);
+    let result = export(forward, input, dir.path().join("model.onnx"));
+
+    assert!(matches!(result, Err(ExportError::InvalidTrace(_))));
+}
+
+#[test]
+fn should_fail_when_the_output_is_constant() {
+    let device = NdArrayDevice::default();
+    let forward = |input: Tensor<TestBackend, 2>| Tensor::zeros(input.dims(), &input.device());
+    let dir = tempfile::tempdir().unwrap();
+
+    let input = Tensor::random([2, 4], Distribution::Default, &device);
+    let result = export(forward, input, dir.path().join("model.onnx"));
+
+    assert!(matches!(result, Err(ExportError::ConstantOutput)));
+}
@@ -0,0 +1,25 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(missing_docs)]
+
+//! # Burn Prune
+//!
+//! This library provides model pruning for the Burn project. The [pruner](Pruner) zeroes out the
+//! least important weights of a model, either individually by magnitude or by whole output
+//! features with the lowest L1 norm, and the model can then be fine-tuned as usual. With the
+//! [pruning optimizer](PruningOptimizer), the sparsity is increased a little each epoch while
+//! training with the `Learner`.
+
+extern crate alloc;
+
+mod pruner;
+#[cfg(feature = "std")]
+mod schedule;
+
+pub use pruner::*;
+#[cfg(feature = "std")]
+pub use schedule::*;
+
+#[cfg(test)]
+pub(crate) type TestBackend = burn_ndarray::NdArray<f32>;
+#[cfg(test)]
+pub(crate) type TestAutodiffBackend = burn_autodiff::Autodiff<TestBackend>;
@@ -0,0 +1,406 @@
+use alloc::vec::Vec;
+use burn_core::module::{Module, ModuleMapper, ModuleVisitor, ParamId};
+use burn_tensor::{backend::Backend, container::TensorContainer, ElementConversion, Tensor};
+use core::marker::PhantomData;
+
+/// How the weights are selected for pruning.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PruningMethod {
+    /// Prune the individual weights with the lowest magnitude, over all the weights of the model.
+    Unstructured,
+    /// Prune the slices of each weight along the given dimension with the lowest L1 norm, such as
+    /// the output features of a linear layer (dimension 1) or the filters of a 2D convolution
+    /// (dimension 0).
+    Structured {
+        /// The dimension of the pruned slices.
+        dim: usize,
+    },
+}
+
+/// Prunes the weights of a model by zeroing them out.
+///
+/// Only the parameters with a rank of at least the [minimum rank](Pruner::with_min_rank) are
+/// pruned, which are the weights of the linear and convolution layers by default, while the
+/// biases and normalization parameters are kept.
+///
+/// The pruned model has the same structure as the original one, so it can be fine-tuned, and
+/// the [mask](PruningMask) of the pruned weights can be applied again after each optimizer step
+/// to keep them at zero.
+#[derive(Clone, Debug)]
+pub struct Pruner {
+    min_rank: usize,
+}
+
+impl Default for Pruner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pruner {
+    /// Creates a pruner of the parameters with a rank of at least 2.
+    pub fn new() -> Self {
+        Self { min_rank: 2 }
+    }
+
+    /// Sets the minimum rank of the pruned parameters.
+    pub fn with_min_rank(mut self, min_rank: usize) -> Self {
+        self.min_rank = min_rank;
+        self
+    }
+
+    /// Zeroes out the given fraction of the weights with the lowest magnitude, over all the
+    /// weights of the model.
+    pub fn prune_unstructured<B: Backend, M: Module<B>>(&self, model: M, sparsity: f64) -> M {
+        self.mask(&model, sparsity, PruningMethod::Unstructured)
+            .apply(model)
+    }
+
+    /// Zeroes out the given fraction of the slices of each weight along the dimension, the
+    /// slices with the lowest L1 norm being pruned.
+    ///
+    /// The weights with a rank lower or equal to the dimension are left unchanged.
+    pub fn prune_structured<B: Backend, M: Module<B>>(
+        &self,
+        model: M,
+        sparsity: f64,
+        dim: usize,
+    ) -> M {
+        self.mask(&model, sparsity, PruningMethod::Structured { dim })
+            .apply(model)
+    }
+
+    /// Computes the mask of the weights to prune with the given method, without modifying the
+    /// model.
+    pub fn mask<B: Backend, M: Module<B>>(
+        &self,
+        model: &M,
+        sparsity: f64,
+        method: PruningMethod,
+    ) -> PruningMask<B> {
+        assert!(
+            (0.0..=1.0).contains(&sparsity),
+            "The sparsity should be between 0 and 1, got {sparsity}."
+        );
+
+        let mut masks = TensorContainer::new();
+
+        match method {
+            PruningMethod::Unstructured => {
+                let mut magnitudes = Magnitudes {
+                    min_rank: self.min_rank,
+                    values: Vec::new(),
+                };
+                model.visit(&mut magnitudes);
+
+                let num_pruned = (sparsity * magnitudes.values.len() as f64).round() as usize;
+                if num_pruned > 0 {
+                    let mut values = magnitudes.values;
+                    values.sort_unstable_by(|a, b| a.total_cmp(b));
+
+                    model.visit(&mut MagnitudeMasks {
+                        min_rank: self.min_rank,
+                        threshold: values[num_pruned - 1],
+                        masks: &mut masks,
+                        _backend: PhantomData,
+                    });
+                }
+            }
+            PruningMethod::Structured { dim } => model.visit(&mut NormMasks {
+                min_rank: self.min_rank,
+                dim,
+                sparsity,
+                masks: &mut masks,
+                _backend: PhantomData,
+            }),
+        }
+
+        PruningMask {
+            masks,
+            _backend: PhantomData,
+        }
+    }
+
+    /// The fraction of the weights of the model that are zero.
+    pub fn sparsity<B: Backend, M: Module<B>>(&self, model: &M) -> f64 {
+        let mut counter = ZeroCounter {
+            min_rank: self.min_rank,
+            num_zeros: 0,
+            num_weights: 0,
+        };
+        model.visit(&mut counter);
+
+        match counter.num_weights {
+            0 => 0.0,
+            num_weights => counter.num_zeros as f64 / num_weights as f64,
+        }
+    }
+}
+
+/// The weights of a model to keep, with a mask of zeros and ones for each pruned parameter.
+#[derive(Debug)]
+pub struct PruningMask<B: Backend> {
+    masks: TensorContainer<ParamId>,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> PruningMask<B> {
+    /// Zeroes out the pruned weights of the model.
+    ///
+    /// The parameters keep requiring gradients, so the model can be fine-tuned.
+    pub fn apply<M: Module<B>>(&self, model: M) -> M {
+        model.map(&mut MaskMapper { mask: self })
+    }
+}
+
+struct Magnitudes {
+    min_rank: usize,
+    values: Vec<f32>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for Magnitudes {
+    fn visit_float<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        if D < self.min_rank {
+            return;
+        }
+
+        let values = tensor.clone().abs().into_data().convert::<f32>().value;
+        self.values.extend(values);
+    }
+}
+
+struct MagnitudeMasks<'a, B: Backend> {
+    min_rank: usize,
+    threshold: f32,
+    masks: &'a mut TensorContainer<ParamId>,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for MagnitudeMasks<'_, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        if D < self.min_rank {
+            return;
+        }
+
+        let mask = tensor.clone().abs().greater_elem(self.threshold).float();
+        self.masks.register::<B, D>(id.clone(), mask);
+    }
+}
+
+struct NormMasks<'a, B: Backend> {
+    min_rank: usize,
+    dim: usize,
+    sparsity: f64,
+    masks: &'a mut TensorContainer<ParamId>,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for NormMasks<'_, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        if D < self.min_rank || D <= self.dim {
+            return;
+        }
+
+        let num_slices = tensor.dims()[self.dim];
+        let num_pruned = (self.sparsity * num_slices as f64).round() as usize;
+        let norms = tensor
+            .clone()
+            .swap_dims(0, self.dim)
+            .reshape([num_slices as i32, -1])
+            .abs()
+            .sum_dim(1)
+            .into_data()
+            .convert::<f32>()
+            .value;
+
+        let mut order: Vec<usize> = (0..num_slices).collect();
+        order.sort_by(|a, b| norms[*a].total_cmp(&norms[*b]));
+        let mut keep = alloc::vec![1.0f32; num_slices];
+        for slice in order.into_iter().take(num_pruned) {
+            keep[slice] = 0.0;
+        }
+
+        let mut shape = [1; D];
+        shape[self.dim] = num_slices;
+        let mask = Tensor::<B, 1>::from_floats(keep.as_slice(), &tensor.device()).reshape(shape);
+        self.masks.register::<B, D>(id.clone(), mask);
+    }
+}
+
+struct MaskMapper<'a, B: Backend> {
+    mask: &'a PruningMask<B>,
+}
+
+impl<B: Backend> ModuleMapper<B> for MaskMapper<'_, B> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let mask = match self.mask.masks.get::<B, D>(id) {
+            Some(mask) => mask,
+            None => return tensor,
+        };
+
+        let require_grad = tensor.is_require_grad();
+        let tensor = tensor.mul(mask).detach();
+
+        match require_grad {
+            true => tensor.require_grad(),
+            false => tensor,
+        }
+    }
+}
+
+struct ZeroCounter {
+    min_rank: usize,
+    num_zeros: usize,
+    num_weights: usize,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ZeroCounter {
+    fn visit_float<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        if D < self.min_rank {
+            return;
+        }
+
+        let num_zeros = tensor
+            .clone()
+            .equal_elem(0.0)
+            .int()
+            .sum()
+            .into_scalar()
+            .elem::<i64>();
+        self.num_zeros += num_zeros as usize;
+        self.num_weights += tensor.shape().num_elements();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core as burn;
+    use burn_core::{
+        module::Param,
+        nn::{
+            conv::{Conv2d, Conv2dConfig},
+            Linear, LinearConfig,
+        },
+    };
+    use burn_tensor::{Data, Distribution};
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        linear: Linear<B>,
+        conv: Conv2d<B>,
+    }
+
+    fn model<B: Backend>() -> Model<B> {
+        let device = Default::default();
+
+        Model {
+            linear: LinearConfig::new(8, 16).init(&device),
+            conv: Conv2dConfig::new([2, 4], [3, 3]).init(&device),
+        }
+    }
+
+    #[derive(Module, Debug)]
+    struct Pair<B: Backend> {
+        small: Linear<B>,
+        large: Linear<B>,
+    }
+
+    fn linear<B: Backend>(weight: Tensor<B, 2>) -> Linear<B> {
+        let [d_input, d_output] = weight.dims();
+        let mut linear = LinearConfig::new(d_input, d_output).init(&weight.device());
+        linear.weight = Param::from(weight);
+        linear
+    }
+
+    #[test]
+    fn unstructured_should_reach_the_sparsity() {
+        let pruner = Pruner::new();
+        // 8 * 16 + 4 * 2 * 3 * 3 = 200 weights.
+        let model = pruner.prune_unstructured(model::<TestBackend>(), 0.3);
+
+        assert_eq!(pruner.sparsity(&model), 0.3);
+        // The biases are never pruned.
+        let bias = model.linear.bias.unwrap().val();
+        assert_eq!(bias.equal_elem(0.0).int().sum().into_scalar(), 0);
+    }
+
+    #[test]
+    fn unstructured_should_prune_the_lowest_magnitudes_globally() {
+        let device = Default::default();
+        let small = Tensor::<TestBackend, 2>::from_floats([[0.1, -0.2], [0.3, -0.4]], &device);
+        let large = Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0], [3.0, -4.0]], &device);
+        let model = Pair {
+            small: linear(small),
+            large: linear(large),
+        };
+
+        let model = Pruner::new().prune_unstructured(model, 0.5);
+
+        assert_eq!(
+            model.small.weight.val().into_data(),
+            Data::from([[0.0, 0.0], [0.0, 0.0]])
+        );
+        assert_eq!(
+            model.large.weight.val().into_data(),
+            Data::from([[1.0, -2.0], [3.0, -4.0]])
+        );
+    }
+
+    #[test]
+    fn structured_should_prune_the_output_features_with_the_lowest_norm() {
+        let device = Default::default();
+        let weight = Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, -0.1, 2.0, 0.2], [-1.0, 0.1, 0.5, -0.2]],
+            &device,
+        );
+
+        let model = Pruner::new().prune_structured(linear(weight), 0.5, 1);
+
+        assert_eq!(
+            model.weight.val().into_data(),
+            Data::from([[1.0, 0.0, 2.0, 0.0], [-1.0, 0.0, 0.5, 0.0]])
+        );
+    }
+
+    #[test]
+    fn structured_should_prune_whole_filters() {
+        let pruner = Pruner::new();
+        let conv = Conv2dConfig::new([3, 8], [3, 3]).init::<TestBackend>(&Default::default());
+
+        let conv = pruner.prune_structured(conv, 0.25, 0);
+
+        let weight = conv.weight.val();
+        assert_eq!(pruner.sparsity(&conv), 0.25);
+        let filters = weight.abs().sum_dim(3).sum_dim(2).sum_dim(1).reshape([8]);
+        let num_pruned = filters.equal_elem(0.0).int().sum().into_scalar();
+        assert_eq!(num_pruned, 2);
+    }
+
+    #[test]
+    fn pruned_model_should_be_trainable() {
+        let device = Default::default();
+        let model = Pruner::new().prune_unstructured(model::<TestAutodiffBackend>(), 0.5);
+        let input =
+            Tensor::<TestAutodiffBackend, 2>::random([2, 8], Distribution::Default, &device);
+
+        assert!(model.linear.weight.val().is_require_grad());
+        let grads = model.linear.forward(input).sum().backward();
+
+        assert!(model.linear.weight.grad(&grads).is_some());
+    }
+
+    #[test]
+    fn should_not_prune_with_zero_sparsity() {
+        let pruner = Pruner::new();
+        let model = model::<TestBackend>();
+        let expected = model.linear.weight.val().into_data();
+
+        let model = pruner.prune_unstructured(model, 0.0);
+        let model = pruner.prune_structured(model, 0.0, 0);
+
+        assert_eq!(model.linear.weight.val().into_data(), expected);
+        assert_eq!(pruner.sparsity(&model), 0.0);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{Pruner, PruningMask, PruningMethod};
+use burn_core as burn;
+
+use burn_core::{
+    config::Config,
+    module::AutodiffModule,
+    optim::{GradientsParams, Optimizer},
+    tensor::backend::AutodiffBackend,
+    LearningRate,
+};
+
+/// Configuration of the sparsity of a model over the epochs of an iterative pruning.
+///
+/// The sparsity grows from the initial to the final sparsity following the cubic schedule of
+/// [To prune, or not to prune](https://arxiv.org/abs/1710.01878), pruning more in the first
+/// epochs while there are many redundant weights.
+#[derive(Config, Debug)]
+pub struct PruningScheduleConfig {
+    /// The sparsity reached at the end of the schedule.
+    pub final_sparsity: f64,
+    /// The sparsity of the first pruning epoch.
+    #[config(default = 0.0)]
+    pub initial_sparsity: f64,
+    /// The first epoch where the model is pruned, epochs starting at 1.
+    #[config(default = 1)]
+    pub start_epoch: usize,
+    /// The number of epochs to go from the initial to the final sparsity.
+    #[config(default = 10)]
+    pub num_epochs: usize,
+}
+
+impl PruningScheduleConfig {
+    /// The sparsity of the given epoch, epochs starting at 1, or `None` before the start epoch.
+    pub fn sparsity(&self, epoch: usize) -> Option<f64> {
+        if epoch < self.start_epoch {
+            return None;
+        }
+
+        let progress = match self.num_epochs {
+            0 | 1 => 1.0,
+            num_epochs => ((epoch - self.start_epoch) as f64 / (num_epochs - 1) as f64).min(1.0),
+        };
+        let remaining = (1.0 - progress).powi(3);
+
+        Some(self.final_sparsity + (self.initial_sparsity - self.final_sparsity) * remaining)
+    }
+}
+
+/// Wraps an optimizer to prune the model iteratively while training.
+///
+/// At the first step of each epoch, the model is pruned to the sparsity of the
+/// [schedule](PruningScheduleConfig), and the pruned weights are kept at zero after every
+/// optimizer step of the epoch, so the remaining weights are fine-tuned to compensate. Since it
+/// is an optimizer, it can be given to the `Learner` like any other one.
+///
+/// The mask isn't part of the optimizer record, so when resuming a training, the pruned weights
+/// are kept at zero again from the next epoch.
+pub struct PruningOptimizer<O, B: AutodiffBackend> {
+    optim: O,
+    pruner: Pruner,
+    method: PruningMethod,
+    schedule: PruningScheduleConfig,
+    steps_per_epoch: usize,
+    num_steps: usize,
+    mask: Option<PruningMask<B>>,
+}
+
+impl<O, B: AutodiffBackend> PruningOptimizer<O, B> {
+    /// Wraps the optimizer, pruning the model by magnitude following the schedule.
+    ///
+    /// The number of steps per epoch is the number of batches of the training data loader,
+    /// divided by the number of gradient accumulation steps if any.
+    pub fn new(optim: O, schedule: PruningScheduleConfig, steps_per_epoch: usize) -> Self {
+        assert!(
+            steps_per_epoch > 0,
+            "The number of steps per epoch must be positive"
+        );
+
+        Self {
+            optim,
+            pruner: Pruner::new(),
+            method: PruningMethod::Unstructured,
+            schedule,
+            steps_per_epoch,
+            num_steps: 0,
+            mask: None,
+        }
+    }
+
+    /// Sets the pruning method.
+    pub fn with_method(mut self, method: PruningMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Sets the pruner.
+    pub fn with_pruner(mut self, pruner: Pruner) -> Self {
+        self.pruner = pruner;
+        self
+    }
+}
+
+impl<O, B, M> Optimizer<M, B> for PruningOptimizer<O, B>
+where
+    O: Optimizer<M, B>,
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    type Record = O::Record;
+
+    fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M {
+        if self.num_steps.is_multiple_of(self.steps_per_epoch) {
+            let epoch = self.num_steps / self.steps_per_epoch + 1;
+
+            if let Some(sparsity) = self.schedule.sparsity(epoch) {
+                self.mask = Some(self.pruner.mask(&module, sparsity, self.method));
+            }
+        }
+        self.num_steps += 1;
+
+        let module = self.optim.step(lr, module, grads);
+
+        match &self.mask {
+            Some(mask) => mask.apply(module),
+            None => module,
+        }
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.optim.to_record()
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.optim = self.optim.load_record(record);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core::{
+        module::AutodiffModule,
+        nn::{Linear, LinearConfig},
+        optim::SgdConfig,
+    };
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn schedule_should_go_from_the_initial_to_the_final_sparsity() {
+        let schedule = PruningScheduleConfig::new(0.8)
+            .with_initial_sparsity(0.2)
+            .with_start_epoch(2)
+            .with_num_epochs(4);
+
+        assert_eq!(schedule.sparsity(1), None);
+        assert!((schedule.sparsity(2).unwrap() - 0.2).abs() < 1e-9);
+        assert!((schedule.sparsity(5).unwrap() - 0.8).abs() < 1e-9);
+        assert!((schedule.sparsity(9).unwrap() - 0.8).abs() < 1e-9);
+
+        let sparsities: Vec<f64> = (2..=5)
+            .map(|epoch| schedule.sparsity(epoch).unwrap())
+            .collect();
+        for window in sparsities.windows(2) {
+            assert!(window[0] < window[1]);
+        }
+        // The cubic schedule prunes more at the beginning.
+        assert!(sparsities[1] - sparsities[0] > sparsities[3] - sparsities[2]);
+    }
+
+    #[test]
+    fn optimizer_should_increase_the_sparsity_each_epoch() {
+        let device = Default::default();
+        let pruner = Pruner::new();
+        let schedule = PruningScheduleConfig::new(0.75)
+            .with_initial_sparsity(0.25)
+            .with_num_epochs(3);
+        let steps_per_epoch = 2;
+        let mut model: Linear<TestAutodiffBackend> = LinearConfig::new(8, 8).init(&device);
+        let mut optim =
+            PruningOptimizer::new(SgdConfig::new().init(), schedule.clone(), steps_per_epoch);
+
+        for epoch in 1..=4 {
+            for _ in 0..steps_per_epoch {
+                let input = Tensor::<TestAutodiffBackend, 2>::random(
+                    [4, 8],
+                    Distribution::Default,
+                    &device,
+                );
+                let grads = model.forward(input).sum().backward();
+                let grads = GradientsParams::from_grads(grads, &model);
+                model = optim.step(0.1, model, grads);
+
+                // The pruned weights stay at zero while the others are updated.
+                let sparsity = pruner.sparsity::<TestBackend, _>(&model.valid());
+                let expected = schedule.sparsity(epoch).unwrap();
+                assert!(
+                    (sparsity - expected).abs() < 1.0 / 64.0,
+                    "Epoch {epoch}: sparsity {sparsity}, expected {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn optimizer_should_prune_structured() {
+        let device = Default::default();
+        let schedule = PruningScheduleConfig::new(0.5).with_num_epochs(1);
+        let mut model: Linear<TestAutodiffBackend> = LinearConfig::new(4, 6).init(&device);
+        let mut optim = PruningOptimizer::new(SgdConfig::new().init(), schedule, 1)
+            .with_method(PruningMethod::Structured { dim: 1 });
+
+        let input =
+            Tensor::<TestAutodiffBackend, 2>::random([4, 4], Distribution::Default, &device);
+        let grads = model.forward(input).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &model);
+        model = optim.step(0.1, model, grads);
+
+        let weight = model.weight.val().inner();
+        let pruned = weight.abs().sum_dim(0).equal_elem(0.0).int().sum();
+        assert_eq!(pruned.into_scalar(), 3);
+    }
+}
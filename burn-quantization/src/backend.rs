@@ -0,0 +1,99 @@
+use crate::{CalibrationRange, QTensor, QuantizationConfig, QuantizationType, QuantizedTensor};
+use burn_tensor::{backend::Backend, Tensor};
+use core::marker::PhantomData;
+
+/// Enable quantized tensors on a backend.
+///
+/// This works as a backend decorator: float tensors are either float tensors of the inner
+/// backend or [quantized tensors](QuantizedTensor), which are dequantized on the fly by every
+/// operation using them. The outputs of the operations are always float tensors, so only the
+/// tensors explicitly [quantized](QuantizedBackend::quantize), such as the weights of a model,
+/// are stored quantized.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QuantizedBackend<B> {
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> Backend for QuantizedBackend<B> {
+    type Device = B::Device;
+
+    type FullPrecisionElem = B::FullPrecisionElem;
+    type FullPrecisionBackend = QuantizedBackend<B::FullPrecisionBackend>;
+
+    type FloatTensorPrimitive<const D: usize> = QTensor<B, D>;
+    type FloatElem = B::FloatElem;
+
+    type IntTensorPrimitive<const D: usize> = B::IntTensorPrimitive<D>;
+    type IntElem = B::IntElem;
+
+    type BoolTensorPrimitive<const D: usize> = B::BoolTensorPrimitive<D>;
+
+    fn name() -> String {
+        format!("quantized<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn sync(device: &B::Device) {
+        B::sync(device);
+    }
+}
+
+impl<B: Backend> QuantizedBackend<B> {
+    /// Quantizes the tensor following the configuration.
+    ///
+    /// For per-channel quantization, `axis` is the dimension of the channels, and it is ignored
+    /// for per-tensor quantization. A tensor already quantized is quantized again from its
+    /// dequantized values.
+    pub fn quantize<const D: usize>(
+        tensor: Tensor<Self, D>,
+        config: &QuantizationConfig,
+        axis: usize,
+    ) -> Tensor<Self, D> {
+        let tensor = QuantizedTensor::quantize(
+            tensor.into_primitive().dequantize(),
+            config.dtype,
+            config.granularity,
+            axis,
+        );
+
+        Tensor::from_primitive(QTensor::Quantized(tensor))
+    }
+
+    /// Quantizes the tensor with a calibrated range, the values outside of it being clamped.
+    ///
+    /// When the range has one entry per channel, `axis` is the dimension of the channels, and it
+    /// is ignored otherwise.
+    pub fn quantize_with_range<const D: usize>(
+        tensor: Tensor<Self, D>,
+        range: &CalibrationRange,
+        dtype: QuantizationType,
+        axis: usize,
+    ) -> Tensor<Self, D> {
+        let tensor = tensor.into_primitive().dequantize();
+        let device = B::float_device(&tensor);
+        let mut shape = [1; D];
+        if range.min.len() > 1 {
+            shape[axis] = range.min.len();
+        }
+        let min = Tensor::<B, 1>::from_floats(range.min.as_slice(), &device).reshape(shape);
+        let max = Tensor::<B, 1>::from_floats(range.max.as_slice(), &device).reshape(shape);
+
+        let tensor = QuantizedTensor::quantize_with_range(tensor, min, max, dtype);
+
+        Tensor::from_primitive(QTensor::Quantized(tensor))
+    }
+
+    /// Returns the float tensor approximated by the quantized tensor, or the tensor itself when
+    /// it isn't quantized.
+    pub fn dequantize<const D: usize>(tensor: Tensor<Self, D>) -> Tensor<Self, D> {
+        Tensor::from_primitive(QTensor::Float(tensor.into_primitive().dequantize()))
+    }
+
+    /// Returns whether the tensor is stored quantized.
+    pub fn is_quantized<const D: usize>(tensor: &Tensor<Self, D>) -> bool {
+        matches!(tensor.clone().into_primitive(), QTensor::Quantized(_))
+    }
+}
@@ -0,0 +1,337 @@
+use crate::{CalibrationRange, QuantizationConfig, QuantizationGranularity};
+use burn_core as burn;
+
+use burn_core::{config::Config, data::dataloader::DataLoader, module::Module};
+use burn_tensor::{backend::Backend, Tensor};
+use core::marker::PhantomData;
+use std::sync::Arc;
+
+/// How the range of the activations is computed from the calibration data.
+#[derive(Config, Debug, Copy, PartialEq)]
+pub enum CalibrationMethod {
+    /// The minimum and maximum values observed over all the batches.
+    MinMax,
+    /// The lower and upper percentiles of each batch, averaged over the batches, which ignores
+    /// the outliers that would otherwise waste most of the quantized range.
+    Percentile {
+        /// The upper percentile, between 50 and 100, the lower one being `100 - percentile`.
+        percentile: f32,
+    },
+}
+
+/// Calibrates the ranges of the activations of a model by running it on representative data.
+///
+/// The activations to observe are returned by the forward function given to
+/// [run](CalibrationRunner::run), and their calibrated ranges are added to the quantization
+/// configuration, in the same order.
+pub struct CalibrationRunner<B: Backend, M: Module<B>> {
+    model: M,
+    config: QuantizationConfig,
+    method: CalibrationMethod,
+    channel_axis: usize,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend, M: Module<B>> CalibrationRunner<B, M> {
+    /// Creates a runner calibrating the model with the [min-max](CalibrationMethod::MinMax)
+    /// method, completing the given configuration.
+    ///
+    /// With the [per-channel](QuantizationGranularity::PerChannel) granularity, the channels of
+    /// the activations are on the dimension 1 by default.
+    pub fn new(model: M, config: QuantizationConfig) -> Self {
+        Self {
+            model,
+            config,
+            method: CalibrationMethod::MinMax,
+            channel_axis: 1,
+            _backend: PhantomData,
+        }
+    }
+
+    /// Sets the calibration method.
+    pub fn with_method(mut self, method: CalibrationMethod) -> Self {
+        if let CalibrationMethod::Percentile { percentile } = method {
+            assert!(
+                (50.0..=100.0).contains(&percentile),
+                "The percentile should be between 50 and 100, got {percentile}."
+            );
+        }
+
+        self.method = method;
+        self
+    }
+
+    /// Sets the dimension of the channels of the activations.
+    pub fn with_channel_axis(mut self, axis: usize) -> Self {
+        self.channel_axis = axis;
+        self
+    }
+
+    /// Runs the model on every batch of the data loader, observing the activations returned by
+    /// the forward function, and returns the configuration with their calibrated ranges.
+    ///
+    /// # Panics
+    ///
+    /// If the forward function doesn't return the same number of activations for every batch.
+    pub fn run<I, F, const D: usize>(
+        &self,
+        dataloader: Arc<dyn DataLoader<I>>,
+        forward: F,
+    ) -> QuantizationConfig
+    where
+        F: Fn(&M, I) -> Vec<Tensor<B, D>>,
+    {
+        let mut observers: Option<Vec<Observer>> = None;
+
+        for batch in dataloader.iter() {
+            let activations = forward(&self.model, batch);
+            let observers = observers.get_or_insert_with(|| {
+                activations
+                    .iter()
+                    .map(|_| Observer::new(self.method))
+                    .collect()
+            });
+            assert_eq!(
+                observers.len(),
+                activations.len(),
+                "The forward function should return the same number of activations for every batch."
+            );
+
+            for (observer, activation) in observers.iter_mut().zip(activations) {
+                observer.observe(self.channels(activation));
+            }
+        }
+
+        let activations = observers
+            .unwrap_or_default()
+            .into_iter()
+            .map(Observer::into_range)
+            .collect();
+
+        self.config.clone().with_activations(activations)
+    }
+
+    /// The values of each channel of the activation.
+    fn channels<const D: usize>(&self, activation: Tensor<B, D>) -> Vec<Vec<f32>> {
+        let activation = match self.config.granularity {
+            QuantizationGranularity::PerTensor => activation.reshape([1, -1]),
+            QuantizationGranularity::PerChannel => {
+                let num_channels = activation.dims()[self.channel_axis];
+                activation
+                    .swap_dims(0, self.channel_axis)
+                    .reshape([num_channels as i32, -1])
+            }
+        };
+        let [num_channels, channel_size] = activation.dims();
+        let values = activation.into_data().convert::<f32>().value;
+
+        match channel_size {
+            0 => vec![Vec::new(); num_channels],
+            _ => values.chunks(channel_size).map(|c| c.to_vec()).collect(),
+        }
+    }
+}
+
+/// Accumulates the range of each channel of an activation over the batches.
+struct Observer {
+    method: CalibrationMethod,
+    min: Vec<f32>,
+    max: Vec<f32>,
+    num_batches: usize,
+}
+
+impl Observer {
+    fn new(method: CalibrationMethod) -> Self {
+        Self {
+            method,
+            min: Vec::new(),
+            max: Vec::new(),
+            num_batches: 0,
+        }
+    }
+
+    fn observe(&mut self, channels: Vec<Vec<f32>>) {
+        if self.num_batches == 0 {
+            // The percentiles are summed over the batches to be averaged at the end.
+            let (min, max) = match self.method {
+                CalibrationMethod::MinMax => (f32::INFINITY, f32::NEG_INFINITY),
+                CalibrationMethod::Percentile { .. } => (0.0, 0.0),
+            };
+            self.min = vec![min; channels.len()];
+            self.max = vec![max; channels.len()];
+        }
+
+        for (channel, mut values) in channels.into_iter().enumerate() {
+            if values.is_empty() {
+                continue;
+            }
+
+            match self.method {
+                CalibrationMethod::MinMax => {
+                    for value in values {
+                        self.min[channel] = self.min[channel].min(value);
+                        self.max[channel] = self.max[channel].max(value);
+                    }
+                }
+                CalibrationMethod::Percentile { percentile } => {
+                    values.sort_by(|a, b| a.total_cmp(b));
+                    let last = (values.len() - 1) as f32;
+                    let upper = (last * percentile / 100.0).round() as usize;
+                    let lower = (last * (1.0 - percentile / 100.0)).round() as usize;
+
+                    self.min[channel] += values[lower];
+                    self.max[channel] += values[upper];
+                }
+            }
+        }
+
+        self.num_batches += 1;
+    }
+
+    fn into_range(self) -> CalibrationRange {
+        match self.method {
+            CalibrationMethod::MinMax => CalibrationRange::new(self.min, self.max),
+            CalibrationMethod::Percentile { .. } => {
+                let num_batches = self.num_batches as f32;
+                CalibrationRange::new(
+                    self.min.into_iter().map(|min| min / num_batches).collect(),
+                    self.max.into_iter().map(|max| max / num_batches).collect(),
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QuantizationType, QuantizedBackend, TestBackend};
+    use burn_core::{
+        data::{
+            dataloader::batcher::Batcher, dataloader::DataLoaderBuilder, dataset::InMemDataset,
+        },
+        nn::{Linear, LinearConfig},
+    };
+    use burn_tensor::{Data, Distribution};
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        linear: Linear<B>,
+    }
+
+    struct RowBatcher;
+
+    impl<const N: usize> Batcher<[f32; N], Tensor<TestBackend, 2>> for RowBatcher {
+        fn batch(&self, items: Vec<[f32; N]>) -> Tensor<TestBackend, 2> {
+            let items = items
+                .into_iter()
+                .map(|item| Tensor::from_floats(item, &Default::default()).unsqueeze())
+                .collect();
+
+            Tensor::cat(items, 0)
+        }
+    }
+
+    fn dataloader<const N: usize>(
+        items: Vec<[f32; N]>,
+        batch_size: usize,
+    ) -> Arc<dyn DataLoader<Tensor<TestBackend, 2>>> {
+        DataLoaderBuilder::new(RowBatcher)
+            .batch_size(batch_size)
+            .build(InMemDataset::new(items))
+    }
+
+    fn model() -> Model<TestBackend> {
+        Model {
+            linear: LinearConfig::new(4, 3).init(&Default::default()),
+        }
+    }
+
+    #[test]
+    fn should_compute_the_min_max_of_each_channel() {
+        let items = vec![[1.0, -2.0], [3.0, 0.5], [-1.0, 4.0], [0.0, 0.0]];
+        let runner = CalibrationRunner::new(model(), QuantizationConfig::new());
+
+        let config = runner.run(dataloader(items, 2), |_, batch| vec![batch]);
+
+        assert_eq!(
+            config.activations,
+            vec![CalibrationRange::new(vec![-1.0, -2.0], vec![3.0, 4.0])]
+        );
+    }
+
+    #[test]
+    fn should_compute_the_min_max_of_the_tensor() {
+        let items = vec![[1.0, -2.0], [3.0, 0.5], [-1.0, 4.0], [0.0, 0.0]];
+        let config = QuantizationConfig::new().with_granularity(QuantizationGranularity::PerTensor);
+        let runner = CalibrationRunner::new(model(), config);
+
+        let config = runner.run(dataloader(items, 2), |_, batch| vec![batch.clone(), -batch]);
+
+        assert_eq!(
+            config.activations,
+            vec![
+                CalibrationRange::new(vec![-2.0], vec![4.0]),
+                CalibrationRange::new(vec![-4.0], vec![2.0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn percentile_should_ignore_outliers() {
+        let items = (0..=100).map(|i| [i as f32]).collect();
+        let runner = CalibrationRunner::new(model(), QuantizationConfig::new())
+            .with_method(CalibrationMethod::Percentile { percentile: 99.0 });
+
+        let config = runner.run(dataloader(items, 101), |_, batch| vec![batch]);
+
+        assert_eq!(
+            config.activations,
+            vec![CalibrationRange::new(vec![1.0], vec![99.0])]
+        );
+    }
+
+    #[test]
+    fn calibrated_scales_should_be_tighter_than_the_theoretical_limits() {
+        TestBackend::seed(0);
+        let items = Tensor::<TestBackend, 2>::random(
+            [64, 4],
+            Distribution::Uniform(-0.5, 0.5),
+            &Default::default(),
+        )
+        .into_data()
+        .value
+        .chunks(4)
+        .map(|item| [item[0], item[1], item[2], item[3]])
+        .collect();
+        let runner = CalibrationRunner::new(model(), QuantizationConfig::new());
+
+        let config = runner.run(dataloader(items, 16), |model, batch| {
+            vec![model.linear.forward(batch).tanh()]
+        });
+
+        // The tanh activation can take any value in [-1, 1].
+        let theoretical = CalibrationRange::new(vec![-1.0], vec![1.0]).scales(config.dtype);
+        let calibrated = config.activations[0].scales(config.dtype);
+        assert_eq!(calibrated.len(), 3);
+        for scale in calibrated {
+            assert!(scale < theoretical[0], "{scale} >= {}", theoretical[0]);
+        }
+    }
+
+    #[test]
+    fn should_quantize_activations_with_the_calibrated_range() {
+        let device = Default::default();
+        let range = CalibrationRange::new(vec![-1.0, -2.0], vec![3.0, 4.0]);
+        let activation = Tensor::<TestBackend, 2>::from_floats([[0.5, -1.5], [2.0, 10.0]], &device);
+
+        let output =
+            QuantizedBackend::quantize_with_range(activation, &range, QuantizationType::QInt8, 1);
+
+        assert!(QuantizedBackend::is_quantized(&output));
+        // The values outside of the range are clamped.
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.5, -1.5], [2.0, 4.0]]), 1);
+    }
+}
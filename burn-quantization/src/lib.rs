@@ -0,0 +1,32 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(missing_docs)]
+
+//! # Burn Quantization
+//!
+//! This library provides quantized inference for the Burn project. The [quantized
+//! backend](QuantizedBackend) decorates any backend so that float tensors can be stored as
+//! quantized integers with an affine scale and zero-point, which are dequantized on the fly when
+//! used by an operation.
+//!
+//! The ranges of the activations can be calibrated from representative data with the
+//! `CalibrationRunner`, available with the `calibration` feature.
+
+extern crate alloc;
+
+mod backend;
+#[cfg(feature = "calibration")]
+mod calibration;
+mod module;
+mod ops;
+mod scheme;
+mod tensor;
+
+pub use backend::*;
+#[cfg(feature = "calibration")]
+pub use calibration::*;
+pub use module::*;
+pub use scheme::*;
+pub use tensor::*;
+
+#[cfg(test)]
+pub(crate) type TestBackend = QuantizedBackend<burn_ndarray::NdArray<f32>>;
@@ -0,0 +1,143 @@
+use crate::{QuantizationConfig, QuantizedBackend};
+use burn_core::module::{Module, ModuleMapper, ParamId};
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Quantize the weights of a module running on the [quantized backend](QuantizedBackend).
+pub trait QuantizeModule<B: Backend>: Module<QuantizedBackend<B>> {
+    /// Quantizes the weights of the `Linear` and `Conv2d` layers of the module.
+    ///
+    /// The layers are recognized by the rank of their weights: the 2D weights of the linear
+    /// layers are quantized per output feature and the 4D weights of the 2D convolutions per
+    /// output channel, while the other parameters, such as the biases, stay in float. Note that
+    /// other 2D weights, such as the ones of an embedding, are quantized as well.
+    fn quantize(self, config: &QuantizationConfig) -> Self {
+        self.map(&mut Quantizer { config })
+    }
+
+    /// Dequantizes every quantized parameter of the module.
+    fn dequantize(self) -> Self {
+        self.map(&mut Dequantizer)
+    }
+}
+
+impl<B: Backend, M: Module<QuantizedBackend<B>>> QuantizeModule<B> for M {}
+
+struct Quantizer<'a> {
+    config: &'a QuantizationConfig,
+}
+
+impl<B: Backend> ModuleMapper<QuantizedBackend<B>> for Quantizer<'_> {
+    fn map_float<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        tensor: Tensor<QuantizedBackend<B>, D>,
+    ) -> Tensor<QuantizedBackend<B>, D> {
+        // The linear weights have the shape [d_input, d_output] and the convolution weights
+        // [channels_out, channels_in, kernel_height, kernel_width].
+        match D {
+            2 => QuantizedBackend::quantize(tensor, self.config, 1),
+            4 => QuantizedBackend::quantize(tensor, self.config, 0),
+            _ => tensor,
+        }
+    }
+}
+
+struct Dequantizer;
+
+impl<B: Backend> ModuleMapper<QuantizedBackend<B>> for Dequantizer {
+    fn map_float<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        tensor: Tensor<QuantizedBackend<B>, D>,
+    ) -> Tensor<QuantizedBackend<B>, D> {
+        QuantizedBackend::dequantize(tensor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QuantizationType, TestBackend};
+    use burn_core as burn;
+    use burn_core::nn::{
+        conv::{Conv2d, Conv2dConfig},
+        Linear, LinearConfig,
+    };
+    use burn_tensor::Distribution;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        conv: Conv2d<B>,
+        linear: Linear<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                conv: Conv2dConfig::new([3, 4], [3, 3]).init(device),
+                linear: LinearConfig::new(4 * 6 * 6, 10).init(device),
+            }
+        }
+
+        fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 2> {
+            let x = self.conv.forward(input);
+            self.linear.forward(x.flatten(1, 3))
+        }
+    }
+
+    fn relative_error(config: &QuantizationConfig) -> f32 {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let input = Tensor::<TestBackend, 4>::random([2, 3, 8, 8], Distribution::Default, &device);
+        let expected = model.forward(input.clone());
+
+        let model = model.quantize(config);
+        let output = model.forward(input);
+
+        let error = (output - expected.clone()).abs().max().into_scalar();
+        error / expected.abs().max().into_scalar()
+    }
+
+    #[test]
+    fn should_quantize_linear_and_conv_weights_only() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device).quantize(&QuantizationConfig::new());
+
+        assert!(QuantizedBackend::is_quantized(&model.conv.weight.val()));
+        assert!(QuantizedBackend::is_quantized(&model.linear.weight.val()));
+        assert!(!QuantizedBackend::is_quantized(
+            &model.conv.bias.as_ref().unwrap().val()
+        ));
+        assert!(!QuantizedBackend::is_quantized(
+            &model.linear.bias.as_ref().unwrap().val()
+        ));
+
+        let model = model.dequantize();
+        assert!(!QuantizedBackend::is_quantized(&model.linear.weight.val()));
+    }
+
+    #[test]
+    fn int8_output_should_be_close_to_f32() {
+        let error = relative_error(&QuantizationConfig::new());
+
+        assert!(error < 0.03, "Relative error {error} is too large");
+    }
+
+    #[test]
+    fn int8_per_tensor_output_should_be_close_to_f32() {
+        let config =
+            QuantizationConfig::new().with_granularity(crate::QuantizationGranularity::PerTensor);
+        let error = relative_error(&config);
+
+        assert!(error < 0.03, "Relative error {error} is too large");
+    }
+
+    #[test]
+    fn int4_output_should_be_close_to_f32() {
+        let config = QuantizationConfig::new().with_dtype(QuantizationType::QInt4);
+        let error = relative_error(&config);
+
+        assert!(error < 0.2, "Relative error {error} is too large");
+    }
+}
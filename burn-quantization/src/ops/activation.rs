@@ -0,0 +1,4 @@
+use crate::QuantizedBackend;
+use burn_tensor::{backend::Backend, ops::ActivationOps};
+
+impl<B: Backend> ActivationOps<Self> for QuantizedBackend<B> {}
@@ -0,0 +1,243 @@
+use crate::{QTensor, QuantizedBackend};
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, Device, FloatTensor, IntElem, IntTensor, IntTensorOps},
+    Data, Reader, Shape,
+};
+use core::ops::Range;
+
+impl<B: Backend> IntTensorOps<Self> for QuantizedBackend<B> {
+    fn int_empty<const D: usize>(shape: Shape<D>, device: &Device<B>) -> IntTensor<B, D> {
+        B::int_empty(shape, device)
+    }
+
+    fn int_shape<const D: usize>(tensor: &IntTensor<B, D>) -> Shape<D> {
+        B::int_shape(tensor)
+    }
+
+    fn int_into_data<const D: usize>(tensor: IntTensor<B, D>) -> Reader<Data<IntElem<B>, D>> {
+        B::int_into_data(tensor)
+    }
+
+    fn int_from_data<const D: usize>(
+        data: Data<IntElem<B>, D>,
+        device: &Device<B>,
+    ) -> IntTensor<B, D> {
+        B::int_from_data(data, device)
+    }
+
+    fn int_device<const D: usize>(tensor: &IntTensor<B, D>) -> Device<B> {
+        B::int_device(tensor)
+    }
+
+    fn int_to_device<const D: usize>(
+        tensor: IntTensor<B, D>,
+        device: &Device<B>,
+    ) -> IntTensor<B, D> {
+        B::int_to_device(tensor, device)
+    }
+
+    fn int_reshape<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<B, D2> {
+        B::int_reshape(tensor, shape)
+    }
+
+    fn int_slice<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, D1>,
+        indices: [Range<usize>; D2],
+    ) -> IntTensor<B, D1> {
+        B::int_slice(tensor, indices)
+    }
+
+    fn int_slice_assign<const D1: usize, const D2: usize>(
+        tensor: IntTensor<B, D1>,
+        indices: [Range<usize>; D2],
+        value: IntTensor<B, D1>,
+    ) -> IntTensor<B, D1> {
+        B::int_slice_assign(tensor, indices, value)
+    }
+
+    fn int_into_float<const D: usize>(tensor: IntTensor<B, D>) -> FloatTensor<Self, D> {
+        QTensor::Float(B::int_into_float(tensor))
+    }
+
+    fn int_mask_where<const D: usize>(
+        tensor: IntTensor<B, D>,
+        mask: BoolTensor<B, D>,
+        source: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_mask_where(tensor, mask, source)
+    }
+
+    fn int_mask_fill<const D: usize>(
+        tensor: IntTensor<B, D>,
+        mask: BoolTensor<B, D>,
+        value: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        B::int_mask_fill(tensor, mask, value)
+    }
+
+    fn int_gather<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<B, D>,
+        indices: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_gather(dim, tensor, indices)
+    }
+
+    fn int_scatter<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<B, D>,
+        indices: IntTensor<B, D>,
+        value: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_scatter(dim, tensor, indices, value)
+    }
+
+    fn int_select<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+    ) -> IntTensor<B, D> {
+        B::int_select(tensor, dim, indices)
+    }
+
+    fn int_select_assign<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+        value: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_select_assign(tensor, dim, indices, value)
+    }
+
+    fn int_cat<const D: usize>(tensors: Vec<IntTensor<B, D>>, dim: usize) -> IntTensor<B, D> {
+        B::int_cat(tensors, dim)
+    }
+
+    fn int_equal<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> BoolTensor<B, D> {
+        B::int_equal(lhs, rhs)
+    }
+
+    fn int_equal_elem<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> BoolTensor<B, D> {
+        B::int_equal_elem(lhs, rhs)
+    }
+
+    fn int_greater<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> BoolTensor<B, D> {
+        B::int_greater(lhs, rhs)
+    }
+
+    fn int_greater_elem<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> BoolTensor<B, D> {
+        B::int_greater_elem(lhs, rhs)
+    }
+
+    fn int_greater_equal<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> BoolTensor<B, D> {
+        B::int_greater_equal(lhs, rhs)
+    }
+
+    fn int_greater_equal_elem<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> BoolTensor<B, D> {
+        B::int_greater_equal_elem(lhs, rhs)
+    }
+
+    fn int_lower<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> BoolTensor<B, D> {
+        B::int_lower(lhs, rhs)
+    }
+
+    fn int_lower_elem<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> BoolTensor<B, D> {
+        B::int_lower_elem(lhs, rhs)
+    }
+
+    fn int_lower_equal<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> BoolTensor<B, D> {
+        B::int_lower_equal(lhs, rhs)
+    }
+
+    fn int_lower_equal_elem<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> BoolTensor<B, D> {
+        B::int_lower_equal_elem(lhs, rhs)
+    }
+
+    fn int_add<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::int_add(lhs, rhs)
+    }
+
+    fn int_add_scalar<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> IntTensor<B, D> {
+        B::int_add_scalar(lhs, rhs)
+    }
+
+    fn int_sub<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::int_sub(lhs, rhs)
+    }
+
+    fn int_sub_scalar<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> IntTensor<B, D> {
+        B::int_sub_scalar(lhs, rhs)
+    }
+
+    fn int_mul<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::int_mul(lhs, rhs)
+    }
+
+    fn int_mul_scalar<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> IntTensor<B, D> {
+        B::int_mul_scalar(lhs, rhs)
+    }
+
+    fn int_div<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::int_div(lhs, rhs)
+    }
+
+    fn int_div_scalar<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> IntTensor<B, D> {
+        B::int_div_scalar(lhs, rhs)
+    }
+
+    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<B>) -> IntTensor<B, D> {
+        B::int_zeros(shape, device)
+    }
+
+    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<B>) -> IntTensor<B, D> {
+        B::int_ones(shape, device)
+    }
+
+    fn int_sum<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
+        B::int_sum(tensor)
+    }
+
+    fn int_sum_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::int_sum_dim(tensor, dim)
+    }
+
+    fn int_mean_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::int_mean_dim(tensor, dim)
+    }
+
+    fn int_argmax<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::int_argmax(tensor, dim)
+    }
+
+    fn int_argmin<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::int_argmin(tensor, dim)
+    }
+
+    fn int_abs<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::int_abs(tensor)
+    }
+
+    fn int_swap_dims<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> IntTensor<B, D> {
+        B::int_swap_dims(tensor, dim1, dim2)
+    }
+}
@@ -0,0 +1,5 @@
+mod activation;
+mod bool_tensor;
+mod int_tensor;
+mod module;
+mod tensor;
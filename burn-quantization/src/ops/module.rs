@@ -0,0 +1,140 @@
+use crate::{QTensor, QuantizedBackend};
+use burn_tensor::{
+    backend::Backend,
+    ops::{
+        ConvOptions, ConvTransposeOptions, FloatTensor, IntTensor, MaxPool2dBackward,
+        MaxPool2dWithIndices, ModuleOps,
+    },
+};
+
+impl<B: Backend> ModuleOps<Self> for QuantizedBackend<B> {
+    fn conv2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        QTensor::Float(B::conv2d(
+            x.dequantize(),
+            weight.dequantize(),
+            bias.map(QTensor::dequantize),
+            options,
+        ))
+    }
+
+    fn conv_transpose2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        QTensor::Float(B::conv_transpose2d(
+            x.dequantize(),
+            weight.dequantize(),
+            bias.map(QTensor::dequantize),
+            options,
+        ))
+    }
+
+    fn avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        QTensor::Float(B::avg_pool2d(
+            x.dequantize(),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        ))
+    }
+
+    fn avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        QTensor::Float(B::avg_pool2d_backward(
+            x.dequantize(),
+            grad.dequantize(),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        ))
+    }
+
+    fn adaptive_avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        QTensor::Float(B::adaptive_avg_pool2d(x.dequantize(), output_size))
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+    ) -> FloatTensor<Self, 4> {
+        QTensor::Float(B::adaptive_avg_pool2d_backward(
+            x.dequantize(),
+            grad.dequantize(),
+        ))
+    }
+
+    fn max_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        QTensor::Float(B::max_pool2d(
+            x.dequantize(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+        ))
+    }
+
+    fn max_pool2d_with_indices(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        let output =
+            B::max_pool2d_with_indices(x.dequantize(), kernel_size, stride, padding, dilation);
+
+        MaxPool2dWithIndices::new(QTensor::Float(output.output), output.indices)
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        output_grad: FloatTensor<Self, 4>,
+        indices: IntTensor<B, 4>,
+    ) -> MaxPool2dBackward<Self> {
+        let output = B::max_pool2d_with_indices_backward(
+            x.dequantize(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            output_grad.dequantize(),
+            indices,
+        );
+
+        MaxPool2dBackward::new(QTensor::Float(output.x_grad))
+    }
+}
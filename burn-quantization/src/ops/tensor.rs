@@ -0,0 +1,388 @@
+use crate::{QTensor, QuantizedBackend};
+use burn_tensor::{
+    backend::Backend,
+    ops::{
+        BoolTensor, Device, FloatElem, FloatTensor, FloatTensorOps, FullPrecisionBackend, IntTensor,
+    },
+    Data, Distribution, Reader, Shape,
+};
+use core::ops::Range;
+
+impl<B: Backend> FloatTensorOps<Self> for QuantizedBackend<B> {
+    fn float_from_data<const D: usize>(
+        data: Data<FloatElem<B>, D>,
+        device: &Device<B>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_from_data(data, device))
+    }
+
+    fn float_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<B>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_random(shape, distribution, device))
+    }
+
+    fn float_empty<const D: usize>(shape: Shape<D>, device: &Device<B>) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_empty(shape, device))
+    }
+
+    fn float_shape<const D: usize>(tensor: &FloatTensor<Self, D>) -> Shape<D> {
+        tensor.shape()
+    }
+
+    fn float_device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<B> {
+        tensor.device()
+    }
+
+    fn float_to_device<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        device: &Device<B>,
+    ) -> FloatTensor<Self, D> {
+        tensor.to_device(device)
+    }
+
+    fn float_into_data<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> Reader<Data<FloatElem<B>, D>> {
+        B::float_into_data(tensor.dequantize())
+    }
+
+    fn float_to_full_precision<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+    ) -> FloatTensor<FullPrecisionBackend<Self>, D> {
+        QTensor::Float(B::float_to_full_precision(&tensor.clone().dequantize()))
+    }
+
+    fn float_from_full_precision<const D: usize>(
+        tensor: FloatTensor<FullPrecisionBackend<Self>, D>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_from_full_precision(tensor.dequantize()))
+    }
+
+    fn float_into_int<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<B, D> {
+        B::float_into_int(tensor.dequantize())
+    }
+
+    fn float_add<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_add(lhs.dequantize(), rhs.dequantize()))
+    }
+
+    fn float_add_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_add_scalar(lhs.dequantize(), rhs))
+    }
+
+    fn float_sub<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_sub(lhs.dequantize(), rhs.dequantize()))
+    }
+
+    fn float_sub_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_sub_scalar(lhs.dequantize(), rhs))
+    }
+
+    fn float_mul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_mul(lhs.dequantize(), rhs.dequantize()))
+    }
+
+    fn float_mul_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_mul_scalar(lhs.dequantize(), rhs))
+    }
+
+    fn float_div<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_div(lhs.dequantize(), rhs.dequantize()))
+    }
+
+    fn float_div_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_div_scalar(lhs.dequantize(), rhs))
+    }
+
+    fn float_matmul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_matmul(lhs.dequantize(), rhs.dequantize()))
+    }
+
+    fn float_recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_recip(tensor.dequantize()))
+    }
+
+    fn float_swap_dims<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_swap_dims(tensor.dequantize(), dim1, dim2))
+    }
+
+    fn float_reshape<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        QTensor::Float(B::float_reshape(tensor.dequantize(), shape))
+    }
+
+    fn float_gather<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<B, D>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_gather(dim, tensor.dequantize(), indices))
+    }
+
+    fn float_scatter<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<B, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_scatter(
+            dim,
+            tensor.dequantize(),
+            indices,
+            value.dequantize(),
+        ))
+    }
+
+    fn float_select<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_select(tensor.dequantize(), dim, indices))
+    }
+
+    fn float_select_assign<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_select_assign(
+            tensor.dequantize(),
+            dim,
+            indices,
+            value.dequantize(),
+        ))
+    }
+
+    fn float_slice<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> FloatTensor<Self, D1> {
+        QTensor::Float(B::float_slice(tensor.dequantize(), ranges))
+    }
+
+    fn float_slice_assign<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: FloatTensor<Self, D1>,
+    ) -> FloatTensor<Self, D1> {
+        QTensor::Float(B::float_slice_assign(
+            tensor.dequantize(),
+            ranges,
+            value.dequantize(),
+        ))
+    }
+
+    fn float_mask_where<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<B, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_mask_where(
+            tensor.dequantize(),
+            mask,
+            value.dequantize(),
+        ))
+    }
+
+    fn float_mask_fill<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<B, D>,
+        value: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_mask_fill(tensor.dequantize(), mask, value))
+    }
+
+    fn float_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<B, D> {
+        B::float_equal(lhs.dequantize(), rhs.dequantize())
+    }
+
+    fn float_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, D> {
+        B::float_equal_elem(lhs.dequantize(), rhs)
+    }
+
+    fn float_greater<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<B, D> {
+        B::float_greater(lhs.dequantize(), rhs.dequantize())
+    }
+
+    fn float_greater_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, D> {
+        B::float_greater_elem(lhs.dequantize(), rhs)
+    }
+
+    fn float_greater_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<B, D> {
+        B::float_greater_equal(lhs.dequantize(), rhs.dequantize())
+    }
+
+    fn float_greater_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, D> {
+        B::float_greater_equal_elem(lhs.dequantize(), rhs)
+    }
+
+    fn float_lower<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<B, D> {
+        B::float_lower(lhs.dequantize(), rhs.dequantize())
+    }
+
+    fn float_lower_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, D> {
+        B::float_lower_elem(lhs.dequantize(), rhs)
+    }
+
+    fn float_lower_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<B, D> {
+        B::float_lower_equal(lhs.dequantize(), rhs.dequantize())
+    }
+
+    fn float_lower_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, D> {
+        B::float_lower_equal_elem(lhs.dequantize(), rhs)
+    }
+
+    fn float_sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        QTensor::Float(B::float_sum(tensor.dequantize()))
+    }
+
+    fn float_sum_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_sum_dim(tensor.dequantize(), dim))
+    }
+
+    fn float_mean_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_mean_dim(tensor.dequantize(), dim))
+    }
+
+    fn float_exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_exp(tensor.dequantize()))
+    }
+
+    fn float_log<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_log(tensor.dequantize()))
+    }
+
+    fn float_log1p<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_log1p(tensor.dequantize()))
+    }
+
+    fn float_powf<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_powf(lhs.dequantize(), rhs.dequantize()))
+    }
+
+    fn float_powf_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        value: f32,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_powf_scalar(tensor.dequantize(), value))
+    }
+
+    fn float_sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_sqrt(tensor.dequantize()))
+    }
+
+    fn float_abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_abs(tensor.dequantize()))
+    }
+
+    fn float_cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_cos(tensor.dequantize()))
+    }
+
+    fn float_sin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_sin(tensor.dequantize()))
+    }
+
+    fn float_tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_tanh(tensor.dequantize()))
+    }
+
+    fn float_erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_erf(tensor.dequantize()))
+    }
+
+    fn float_cat<const D: usize>(
+        tensors: Vec<FloatTensor<Self, D>>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        QTensor::Float(B::float_cat(
+            tensors.into_iter().map(QTensor::dequantize).collect(),
+            dim,
+        ))
+    }
+
+    fn float_argmax<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> IntTensor<B, D> {
+        B::float_argmax(tensor.dequantize(), dim)
+    }
+
+    fn float_argmin<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> IntTensor<B, D> {
+        B::float_argmin(tensor.dequantize(), dim)
+    }
+}
@@ -0,0 +1,90 @@
+use burn_core as burn;
+
+use alloc::vec::Vec;
+use burn_core::config::Config;
+
+/// The integer type used to store the quantized values.
+#[derive(Config, Debug, Copy, PartialEq, Eq)]
+pub enum QuantizationType {
+    /// Signed 8-bit integers, in the range `[-128, 127]`.
+    QInt8,
+    /// Signed 4-bit integers, in the range `[-8, 7]`.
+    QInt4,
+}
+
+impl QuantizationType {
+    /// The smallest quantized value.
+    pub fn q_min(&self) -> i32 {
+        match self {
+            Self::QInt8 => i8::MIN as i32,
+            Self::QInt4 => -8,
+        }
+    }
+
+    /// The largest quantized value.
+    pub fn q_max(&self) -> i32 {
+        match self {
+            Self::QInt8 => i8::MAX as i32,
+            Self::QInt4 => 7,
+        }
+    }
+}
+
+/// How many scales and zero-points are used to quantize a tensor.
+#[derive(Config, Debug, Copy, PartialEq, Eq)]
+pub enum QuantizationGranularity {
+    /// A single scale and zero-point for the whole tensor.
+    PerTensor,
+    /// A scale and zero-point for each channel of the tensor, which keeps the quantization error
+    /// of a channel independent of the range of the others.
+    PerChannel,
+}
+
+/// Configuration to quantize the weights of a module.
+///
+/// The affine scheme maps a float value `x` to `q = round(x / scale) + zero_point`, where the
+/// scale and zero-point are chosen so that the observed range, extended to contain zero, covers
+/// the whole [quantized range](QuantizationType).
+#[derive(Config, Debug)]
+pub struct QuantizationConfig {
+    /// The integer type of the quantized values.
+    #[config(default = "QuantizationType::QInt8")]
+    pub dtype: QuantizationType,
+    /// The granularity of the scales and zero-points.
+    #[config(default = "QuantizationGranularity::PerChannel")]
+    pub granularity: QuantizationGranularity,
+    /// The ranges of the activations observed during calibration, in the order they were
+    /// observed, to quantize them with [quantize_with_range](crate::QuantizedBackend::quantize_with_range).
+    #[config(default = "Vec::new()")]
+    pub activations: Vec<CalibrationRange>,
+}
+
+/// The range of values of a tensor, either a single one for the whole tensor or one for each
+/// channel.
+#[derive(Config, Debug, PartialEq)]
+pub struct CalibrationRange {
+    /// The minimum of each channel.
+    pub min: Vec<f32>,
+    /// The maximum of each channel.
+    pub max: Vec<f32>,
+}
+
+impl CalibrationRange {
+    /// The scale of each channel when quantizing its range to the quantization type.
+    pub fn scales(&self, dtype: QuantizationType) -> Vec<f32> {
+        let num_levels = (dtype.q_max() - dtype.q_min()) as f32;
+
+        self.min
+            .iter()
+            .zip(self.max.iter())
+            .map(|(min, max)| {
+                // The range always contains zero, as when quantizing a tensor.
+                let scale = (max.max(0.0) - min.min(0.0)) / num_levels;
+                match scale == 0.0 {
+                    true => 1.0,
+                    false => scale,
+                }
+            })
+            .collect()
+    }
+}
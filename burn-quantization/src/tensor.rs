@@ -0,0 +1,298 @@
+use crate::{QuantizationGranularity, QuantizationType};
+use burn_tensor::{backend::Backend, Int, Shape, Tensor};
+
+/// The float tensor primitive of the [quantized backend](crate::QuantizedBackend).
+#[derive(Clone, Debug)]
+pub enum QTensor<B: Backend, const D: usize> {
+    /// A float tensor of the inner backend.
+    Float(B::FloatTensorPrimitive<D>),
+    /// A quantized tensor, dequantized when used by an operation.
+    Quantized(QuantizedTensor<B, D>),
+}
+
+/// A tensor quantized with the affine scheme `q = round(x / scale) + zero_point`.
+///
+/// The scale and zero-point have the rank of the values and are broadcast over them, with a size
+/// of one on every dimension but the channel axis for per-channel quantization.
+///
+/// The values are stored with the int element type of the inner backend, while staying in the
+/// range of the [quantization type](QuantizationType).
+#[derive(Clone, Debug)]
+pub struct QuantizedTensor<B: Backend, const D: usize> {
+    /// The quantized values.
+    pub values: B::IntTensorPrimitive<D>,
+    /// The scale of the quantized values.
+    pub scale: B::FloatTensorPrimitive<D>,
+    /// The integer that the zero float value is quantized to.
+    pub zero_point: B::IntTensorPrimitive<D>,
+    /// The quantization type of the values.
+    pub dtype: QuantizationType,
+}
+
+impl<B: Backend, const D: usize> QTensor<B, D> {
+    /// Returns the float tensor of the inner backend, dequantizing it if needed.
+    pub fn dequantize(self) -> B::FloatTensorPrimitive<D> {
+        match self {
+            Self::Float(tensor) => tensor,
+            Self::Quantized(tensor) => tensor.dequantize(),
+        }
+    }
+
+    /// Returns the shape of the tensor.
+    pub fn shape(&self) -> Shape<D> {
+        match self {
+            Self::Float(tensor) => B::float_shape(tensor),
+            Self::Quantized(tensor) => B::int_shape(&tensor.values),
+        }
+    }
+
+    /// Returns the device of the tensor.
+    pub fn device(&self) -> B::Device {
+        match self {
+            Self::Float(tensor) => B::float_device(tensor),
+            Self::Quantized(tensor) => B::int_device(&tensor.values),
+        }
+    }
+
+    /// Moves the tensor to the given device, without dequantizing it.
+    pub fn to_device(self, device: &B::Device) -> Self {
+        match self {
+            Self::Float(tensor) => Self::Float(B::float_to_device(tensor, device)),
+            Self::Quantized(tensor) => Self::Quantized(QuantizedTensor {
+                values: B::int_to_device(tensor.values, device),
+                scale: B::float_to_device(tensor.scale, device),
+                zero_point: B::int_to_device(tensor.zero_point, device),
+                dtype: tensor.dtype,
+            }),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> QuantizedTensor<B, D> {
+    /// Quantizes the float tensor with scales and zero-points covering its range.
+    ///
+    /// For per-channel quantization, `axis` is the dimension of the channels, and it is ignored
+    /// for per-tensor quantization.
+    pub fn quantize(
+        tensor: B::FloatTensorPrimitive<D>,
+        dtype: QuantizationType,
+        granularity: QuantizationGranularity,
+        axis: usize,
+    ) -> Self {
+        let tensor = Tensor::<B, D>::from_primitive(tensor);
+        let (min, max) = match granularity {
+            QuantizationGranularity::PerTensor => (
+                tensor.clone().min().reshape([1; D]),
+                tensor.clone().max().reshape([1; D]),
+            ),
+            QuantizationGranularity::PerChannel => channel_range(tensor.clone(), axis),
+        };
+
+        Self::quantize_with_range(tensor.into_primitive(), min, max, dtype)
+    }
+
+    /// Quantizes the float tensor with scales and zero-points covering the given range, the
+    /// values outside of it being clamped.
+    ///
+    /// The minimum and maximum are broadcast over the tensor.
+    pub fn quantize_with_range(
+        tensor: B::FloatTensorPrimitive<D>,
+        min: Tensor<B, D>,
+        max: Tensor<B, D>,
+        dtype: QuantizationType,
+    ) -> Self {
+        let q_min = dtype.q_min();
+        let q_max = dtype.q_max();
+
+        // The range always contains zero so that it is exactly representable, which keeps
+        // padding and sparse values exact.
+        let min = min.clamp_max(0.0);
+        let max = max.clamp_min(0.0);
+        let scale = (max - min.clone()).div_scalar((q_max - q_min) as f32);
+        // An all-zero range would make every value zero, so any scale works.
+        let scale = scale.clone().mask_fill(scale.equal_elem(0.0), 1.0);
+        let zero_point = round(
+            min.div(scale.clone())
+                .neg()
+                .add_scalar(q_min as f32)
+                .clamp(q_min, q_max),
+        );
+
+        let tensor = Tensor::<B, D>::from_primitive(tensor);
+        let values = round(
+            tensor
+                .div(scale.clone())
+                .add(zero_point.clone().float())
+                .clamp(q_min, q_max),
+        );
+
+        Self {
+            values: values.into_primitive(),
+            scale: scale.into_primitive(),
+            zero_point: zero_point.into_primitive(),
+            dtype,
+        }
+    }
+
+    /// Returns the float tensor approximated by the quantized values.
+    pub fn dequantize(self) -> B::FloatTensorPrimitive<D> {
+        let values = Tensor::<B, D, Int>::from_primitive(self.values);
+        let zero_point = Tensor::<B, D, Int>::from_primitive(self.zero_point);
+        let scale = Tensor::<B, D>::from_primitive(self.scale);
+
+        values.sub(zero_point).float().mul(scale).into_primitive()
+    }
+}
+
+/// The minimum and maximum of each channel, with a size of one on every other dimension.
+fn channel_range<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    axis: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let mut shape = [1; D];
+    shape[axis] = tensor.dims()[axis];
+
+    let channels = tensor.swap_dims(0, axis).reshape([shape[axis] as i32, -1]);
+    let min = channels.clone().min_dim(1).reshape(shape);
+    let max = channels.max_dim(1).reshape(shape);
+
+    (min, max)
+}
+
+/// Rounds half away from zero, the values being small enough to fit in an int.
+fn round<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D, Int> {
+    let magnitude = tensor.clone().abs().add_scalar(0.5).int();
+
+    magnitude
+        .clone()
+        .mask_where(tensor.lower_elem(0.0), magnitude.neg())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::{Data, Distribution};
+
+    type InnerBackend = burn_ndarray::NdArray<f32>;
+
+    #[test]
+    fn should_quantize_with_the_affine_scheme() {
+        let device = Default::default();
+        let tensor = Tensor::<InnerBackend, 1>::from_floats([-1.0, 0.0, 0.5, 1.55], &device)
+            .into_primitive();
+
+        let quantized = QuantizedTensor::<InnerBackend, 1>::quantize(
+            tensor,
+            QuantizationType::QInt8,
+            QuantizationGranularity::PerTensor,
+            0,
+        );
+
+        // scale = (1.55 + 1.0) / 255 = 0.01, zero_point = -128 + round(1.0 / 0.01) = -28
+        let scale = Tensor::<InnerBackend, 1>::from_primitive(quantized.scale.clone());
+        scale.into_data().assert_approx_eq(&Data::from([0.01]), 5);
+        let zero_point = Tensor::<InnerBackend, 1, Int>::from_primitive(quantized.zero_point);
+        assert_eq!(zero_point.into_data(), Data::from([-28]));
+        let values = Tensor::<InnerBackend, 1, Int>::from_primitive(quantized.values);
+        assert_eq!(values.into_data(), Data::from([-128, -28, 22, 127]));
+    }
+
+    #[test]
+    fn should_dequantize_within_half_a_scale() {
+        let device = Default::default();
+        let tensor = Tensor::<InnerBackend, 2>::random([8, 16], Distribution::Default, &device);
+
+        for dtype in [QuantizationType::QInt8, QuantizationType::QInt4] {
+            let quantized = QuantizedTensor::<InnerBackend, 2>::quantize(
+                tensor.clone().into_primitive(),
+                dtype,
+                QuantizationGranularity::PerTensor,
+                0,
+            );
+            let scale =
+                Tensor::<InnerBackend, 2>::from_primitive(quantized.scale.clone()).into_scalar();
+            let output = Tensor::<InnerBackend, 2>::from_primitive(quantized.dequantize());
+
+            let error = (output - tensor.clone()).abs().max().into_scalar();
+            assert!(
+                error <= scale / 2.0 + 1e-6,
+                "{dtype:?}: {error} > {scale} / 2"
+            );
+        }
+    }
+
+    #[test]
+    fn should_keep_zero_exact() {
+        let device = Default::default();
+        let tensor = Tensor::<InnerBackend, 1>::from_floats([0.3, 0.0, 0.7, 0.9], &device);
+
+        let quantized = QuantizedTensor::<InnerBackend, 1>::quantize(
+            tensor.into_primitive(),
+            QuantizationType::QInt4,
+            QuantizationGranularity::PerTensor,
+            0,
+        );
+        let output = Tensor::<InnerBackend, 1>::from_primitive(quantized.dequantize());
+
+        assert_eq!(output.into_data().value[1], 0.0);
+    }
+
+    #[test]
+    fn should_quantize_each_channel_with_its_own_range() {
+        let device = Default::default();
+        // The channels are the columns, the second one having a range a hundred times larger.
+        let tensor = Tensor::<InnerBackend, 2>::from_floats(
+            [[0.01, 1.0], [-0.02, -2.0], [0.005, 0.5]],
+            &device,
+        );
+
+        let per_channel = QuantizedTensor::<InnerBackend, 2>::quantize(
+            tensor.clone().into_primitive(),
+            QuantizationType::QInt8,
+            QuantizationGranularity::PerChannel,
+            1,
+        );
+        let per_tensor = QuantizedTensor::<InnerBackend, 2>::quantize(
+            tensor.clone().into_primitive(),
+            QuantizationType::QInt8,
+            QuantizationGranularity::PerTensor,
+            1,
+        );
+
+        assert_eq!(
+            Tensor::<InnerBackend, 2>::from_primitive(per_channel.scale.clone()).dims(),
+            [1, 2]
+        );
+        let error = |quantized: QuantizedTensor<InnerBackend, 2>| {
+            let output = Tensor::<InnerBackend, 2>::from_primitive(quantized.dequantize());
+            (output - tensor.clone())
+                .abs()
+                .slice([0..3, 0..1])
+                .max()
+                .into_scalar()
+        };
+        assert!(error(per_channel) < error(per_tensor) / 10.0);
+    }
+
+    #[test]
+    fn should_dequantize_on_the_fly_in_operations() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 2>::random([4, 8], Distribution::Default, &device);
+        let rhs = Tensor::<TestBackend, 2>::random([8, 3], Distribution::Default, &device);
+        let config = crate::QuantizationConfig::new();
+
+        let quantized = crate::QuantizedBackend::quantize(rhs.clone(), &config, 1);
+        assert!(crate::QuantizedBackend::is_quantized(&quantized));
+        let dequantized = crate::QuantizedBackend::dequantize(quantized.clone());
+        assert!(!crate::QuantizedBackend::is_quantized(&dequantized));
+
+        let expected = lhs.clone().matmul(dequantized);
+        let output = lhs.matmul(quantized);
+
+        assert_eq!(output.shape(), Shape::new([4, 3]));
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+}
@@ -0,0 +1,293 @@
+use burn_tensor::{backend::Backend, Int, Shape, Tensor};
+
+/// A sparse tensor stored in the coordinate (COO) format.
+///
+/// The tensor is defined by the coordinates of its non-zero elements along with their values,
+/// every other element being zero. When the same coordinates are given more than once, their
+/// values are summed.
+///
+/// The operations are implemented with the dense tensor operations of the backend, which makes
+/// them differentiable with respect to the values and the dense operands.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_sparse::SparseTensor;
+/// use burn_tensor::{backend::Backend, Int, Shape, Tensor};
+///
+/// fn example<B: Backend>(device: &B::Device) {
+///     // [[0, 2, 0],
+///     //  [1, 0, 3]]
+///     let indices = Tensor::<B, 2, Int>::from_ints([[0, 1, 1], [1, 0, 2]], device);
+///     let values = Tensor::<B, 1>::from_floats([2.0, 1.0, 3.0], device);
+///     let sparse = SparseTensor::from_coo(indices, values, Shape::new([2, 3]));
+///
+///     let dense = Tensor::<B, 2>::ones([3, 4], device);
+///     let output = sparse.spmm(dense); // Shape [2, 4]
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SparseTensor<B: Backend, const D: usize> {
+    indices: Tensor<B, 2, Int>,
+    values: Tensor<B, 1>,
+    shape: Shape<D>,
+}
+
+impl<B: Backend, const D: usize> SparseTensor<B, D> {
+    /// Create a sparse tensor from the coordinates of its non-zero elements and their values.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The coordinates of the elements, of shape `[D, nnz]`.
+    /// * `values` - The values of the elements, of shape `[nnz]`.
+    /// * `shape` - The shape of the tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the indices and the values don't match the number of dimensions and the
+    /// number of elements. The coordinates aren't checked against the shape, since it would
+    /// require reading them from the device.
+    pub fn from_coo(indices: Tensor<B, 2, Int>, values: Tensor<B, 1>, shape: Shape<D>) -> Self {
+        let [num_dims, nnz] = indices.dims();
+        let [num_values] = values.dims();
+
+        assert_eq!(
+            num_dims, D,
+            "The indices should have one row per dimension of the tensor, got {num_dims} rows for {D} dimensions"
+        );
+        assert_eq!(
+            nnz, num_values,
+            "The indices should have one column per value, got {nnz} columns for {num_values} values"
+        );
+
+        Self {
+            indices,
+            values,
+            shape,
+        }
+    }
+
+    /// The coordinates of the non-zero elements, of shape `[D, nnz]`.
+    pub fn indices(&self) -> Tensor<B, 2, Int> {
+        self.indices.clone()
+    }
+
+    /// The values of the non-zero elements, of shape `[nnz]`.
+    pub fn values(&self) -> Tensor<B, 1> {
+        self.values.clone()
+    }
+
+    /// The shape of the tensor.
+    pub fn shape(&self) -> Shape<D> {
+        self.shape.clone()
+    }
+
+    /// The number of stored elements.
+    pub fn nnz(&self) -> usize {
+        self.values.dims()[0]
+    }
+
+    /// The device of the tensor.
+    pub fn device(&self) -> B::Device {
+        self.values.device()
+    }
+
+    /// Convert the sparse tensor into a dense tensor.
+    pub fn to_dense(&self) -> Tensor<B, D> {
+        let device = self.device();
+        let nnz = self.nnz();
+
+        if nnz == 0 {
+            return Tensor::zeros(self.shape.clone(), &device);
+        }
+
+        let mut stride = 1;
+        let mut flat_indices = Tensor::<B, 1, Int>::zeros([nnz], &device);
+        for dim in (0..D).rev() {
+            let indices = self.coordinates(dim).mul_scalar(stride as i64);
+            flat_indices = flat_indices.add(indices);
+            stride *= self.shape.dims[dim];
+        }
+
+        Tensor::<B, 1>::zeros([self.shape.num_elements()], &device)
+            .scatter(0, flat_indices, self.values.clone())
+            .reshape(self.shape.clone())
+    }
+
+    /// The coordinates of the non-zero elements along the given dimension, of shape `[nnz]`.
+    fn coordinates(&self, dim: usize) -> Tensor<B, 1, Int> {
+        let nnz = self.nnz();
+
+        self.indices
+            .clone()
+            .slice([dim..dim + 1, 0..nnz])
+            .reshape([nnz])
+    }
+}
+
+impl<B: Backend> SparseTensor<B, 2> {
+    /// Sparse-dense matrix multiplication.
+    ///
+    /// Each row of the output is the sum of the rows of the dense matrix selected by the columns
+    /// of the non-zero elements of the corresponding sparse row, weighted by their values.
+    ///
+    /// # Panics
+    ///
+    /// If the number of columns of the sparse matrix isn't the number of rows of the dense
+    /// matrix.
+    pub fn spmm(&self, dense: Tensor<B, 2>) -> Tensor<B, 2> {
+        let [num_rows, num_cols] = self.shape.dims;
+        let [dense_rows, dense_cols] = dense.dims();
+        let nnz = self.nnz();
+
+        assert_eq!(
+            num_cols, dense_rows,
+            "The sparse matrix should have as many columns as the dense matrix has rows, got shapes {:?} and {:?}",
+            self.shape.dims,
+            [dense_rows, dense_cols]
+        );
+
+        let output = Tensor::zeros([num_rows, dense_cols], &dense.device());
+        if nnz == 0 {
+            return output;
+        }
+
+        let rows = self.coordinates(0);
+        let cols = self.coordinates(1);
+        let weighted = dense
+            .select(0, cols)
+            .mul(self.values.clone().unsqueeze_dim(1));
+
+        output.select_assign(0, rows, weighted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Data;
+
+    #[test]
+    fn to_dense_2d() {
+        let sparse = sparse_matrix::<TestBackend>();
+
+        let dense = sparse.to_dense();
+
+        assert_eq!(
+            dense.into_data(),
+            Data::from([[0.0, 2.0, 0.0], [1.0, 0.0, 3.0]])
+        );
+    }
+
+    #[test]
+    fn to_dense_sums_duplicated_coordinates() {
+        let device = Default::default();
+        let indices = Tensor::from_ints([[0, 1, 0], [2, 0, 2]], &device);
+        let values = Tensor::from_floats([1.0, 2.0, 3.0], &device);
+        let sparse = SparseTensor::<TestBackend, 2>::from_coo(indices, values, Shape::new([2, 3]));
+
+        let dense = sparse.to_dense();
+
+        assert_eq!(
+            dense.into_data(),
+            Data::from([[0.0, 0.0, 4.0], [2.0, 0.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn to_dense_3d() {
+        let device = Default::default();
+        let indices = Tensor::from_ints([[0, 1], [1, 0], [1, 0]], &device);
+        let values = Tensor::from_floats([5.0, 7.0], &device);
+        let sparse =
+            SparseTensor::<TestBackend, 3>::from_coo(indices, values, Shape::new([2, 2, 2]));
+
+        let dense = sparse.to_dense();
+
+        assert_eq!(
+            dense.into_data(),
+            Data::from([[[0.0, 0.0], [0.0, 5.0]], [[7.0, 0.0], [0.0, 0.0]]])
+        );
+    }
+
+    #[test]
+    fn spmm_matches_dense_matmul() {
+        let device = Default::default();
+        let sparse = sparse_matrix::<TestBackend>();
+        let dense = Tensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+
+        let output = sparse.spmm(dense.clone());
+
+        assert_eq!(output.into_data(), Data::from([[6.0, 8.0], [16.0, 20.0]]));
+        assert_eq!(
+            sparse.spmm(dense.clone()).into_data(),
+            sparse.to_dense().matmul(dense).into_data()
+        );
+    }
+
+    #[test]
+    fn spmm_empty_is_zeros() {
+        let device = Default::default();
+        let indices = Tensor::<TestBackend, 2, Int>::zeros([2, 0], &device);
+        let values = Tensor::zeros([0], &device);
+        let sparse = SparseTensor::from_coo(indices, values, Shape::new([2, 3]));
+
+        let output = sparse.spmm(Tensor::ones([3, 2], &device));
+
+        assert_eq!(output.into_data(), Data::from([[0.0, 0.0], [0.0, 0.0]]));
+    }
+
+    #[test]
+    fn spmm_backward() {
+        let device = Default::default();
+        let sparse = sparse_matrix::<TestAutodiffBackend>();
+        let values = sparse.values().require_grad();
+        let sparse = SparseTensor::from_coo(sparse.indices(), values.clone(), sparse.shape());
+        let dense = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]],
+            &device,
+        )
+        .require_grad();
+
+        let grads = sparse.spmm(dense.clone()).sum().backward();
+
+        // The gradient of each value is the sum of the dense row it selects, and the gradient of
+        // each dense row is the sum of the values of its column.
+        let grad_values = values.grad(&grads).unwrap();
+        let grad_dense = dense.grad(&grads).unwrap();
+        assert_eq!(grad_values.into_data(), Data::from([7.0, 3.0, 11.0]));
+        assert_eq!(
+            grad_dense.into_data(),
+            Data::from([[1.0, 1.0], [2.0, 2.0], [3.0, 3.0]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn spmm_should_panic_on_shape_mismatch() {
+        let sparse = sparse_matrix::<TestBackend>();
+
+        sparse.spmm(Tensor::ones([2, 2], &Default::default()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_coo_should_panic_when_values_dont_match_indices() {
+        let device = Default::default();
+        let indices = Tensor::<TestBackend, 2, Int>::from_ints([[0, 1], [1, 0]], &device);
+        let values = Tensor::from_floats([1.0, 2.0, 3.0], &device);
+
+        SparseTensor::from_coo(indices, values, Shape::new([2, 2]));
+    }
+
+    /// [[0, 2, 0],
+    ///  [1, 0, 3]]
+    fn sparse_matrix<B: Backend>() -> SparseTensor<B, 2> {
+        let device = Default::default();
+        let indices = Tensor::from_ints([[0, 1, 1], [1, 0, 2]], &device);
+        let values = Tensor::from_floats([2.0, 1.0, 3.0], &device);
+
+        SparseTensor::from_coo(indices, values, Shape::new([2, 3]))
+    }
+}
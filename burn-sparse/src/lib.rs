@@ -0,0 +1,17 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(missing_docs)]
+
+//! # Burn Sparse
+//!
+//! This library provides sparse tensors for the Burn project. The sparse tensors are stored in
+//! the coordinate (COO) format with the dense tensors of any backend, so that they work with all
+//! of them, including the autodiff backend.
+
+mod coo;
+
+pub use coo::*;
+
+#[cfg(test)]
+pub(crate) type TestBackend = burn_ndarray::NdArray<f32>;
+#[cfg(test)]
+pub(crate) type TestAutodiffBackend = burn_autodiff::Autodiff<TestBackend>;
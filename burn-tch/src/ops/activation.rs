@@ -26,4 +26,11 @@ impl<E: TchElement> ActivationOps<Self> for LibTorch<E> {
     fn sigmoid<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
         tensor.unary_ops(|mut tensor| tensor.sigmoid_(), |tensor| tensor.sigmoid())
     }
+
+    fn log_sigmoid<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        let storage = tensor.storage.clone();
+        let tensor = tensor.tensor.log_sigmoid();
+
+        TchTensor::from_existing(tensor, storage)
+    }
 }
@@ -24,6 +24,23 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         TchTensor::new(tensor.tensor.to(device))
     }
 
+    pub fn to_device_async<const D: usize>(
+        tensor: TchTensor<E, D>,
+        device: &LibTorchDevice,
+    ) -> TchTensor<E, D> {
+        let device = (*device).into();
+
+        if tensor.tensor.device() == device {
+            return tensor;
+        }
+
+        // The copy is queued on the current CUDA stream, which orders it before the operations
+        // using the tensor without blocking the host. Copies from pageable host memory are still
+        // synchronous.
+        let kind = tensor.tensor.kind();
+        TchTensor::new(tensor.tensor.to_device_(device, kind, true, false))
+    }
+
     pub fn reshape<const D1: usize, const D2: usize>(
         tensor: TchTensor<E, D1>,
         shape: Shape<D2>,
@@ -33,6 +50,15 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         TchTensor::from_existing(tensor.tensor.reshape(shape_tch.dims), tensor.storage)
     }
 
+    pub fn expand<const D: usize>(tensor: TchTensor<E, D>, shape: Shape<D>) -> TchTensor<E, D> {
+        let shape_tch: TchShape<D> = shape.into();
+        // The expanded view is made contiguous, since the in-place operations would otherwise
+        // write the same memory location for several elements.
+        let tensor = tensor.tensor.expand(shape_tch.dims, false).contiguous();
+
+        TchTensor::new(tensor)
+    }
+
     pub fn repeat<const D: usize>(
         tensor: TchTensor<E, D>,
         dim: usize,
@@ -44,6 +70,16 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         TchTensor::new(tensor)
     }
 
+    pub fn roll<const D: usize>(
+        tensor: TchTensor<E, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> TchTensor<E, D> {
+        let dims: Vec<i64> = dims.iter().map(|dim| *dim as i64).collect();
+        let tensor = tensor.tensor.roll(shifts, dims);
+        TchTensor::new(tensor)
+    }
+
     pub fn slice<const D1: usize, const D2: usize>(
         tensor: TchTensor<E, D1>,
         ranges: [Range<usize>; D2],
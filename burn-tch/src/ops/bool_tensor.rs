@@ -15,6 +15,13 @@ impl<E: TchElement> BoolTensorOps<Self> for LibTorch<E> {
         tensor.shape()
     }
 
+    fn bool_expand<const D: usize>(
+        tensor: TchTensor<bool, D>,
+        shape: Shape<D>,
+    ) -> TchTensor<bool, D> {
+        TchOps::expand(tensor, shape)
+    }
+
     fn bool_repeat<const D: usize>(
         tensor: TchTensor<bool, D>,
         dim: usize,
@@ -23,6 +30,14 @@ impl<E: TchElement> BoolTensorOps<Self> for LibTorch<E> {
         TchOps::repeat(tensor, dim, times)
     }
 
+    fn bool_roll<const D: usize>(
+        tensor: TchTensor<bool, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> TchTensor<bool, D> {
+        TchOps::roll(tensor, shifts, dims)
+    }
+
     fn bool_into_data<const D: usize>(tensor: TchTensor<bool, D>) -> Reader<Data<bool, D>> {
         let shape = Self::bool_shape(&tensor);
         let tensor = Self::bool_reshape(tensor.clone(), Shape::new([shape.num_elements()]));
@@ -38,6 +53,13 @@ impl<E: TchElement> BoolTensorOps<Self> for LibTorch<E> {
         TchOps::to_device(tensor, device)
     }
 
+    fn bool_to_device_async<const D: usize>(
+        tensor: TchTensor<bool, D>,
+        device: &LibTorchDevice,
+    ) -> Reader<TchTensor<bool, D>> {
+        Reader::Concrete(TchOps::to_device_async(tensor, device))
+    }
+
     fn bool_reshape<const D1: usize, const D2: usize>(
         tensor: TchTensor<bool, D1>,
         shape: Shape<D2>,
@@ -97,6 +119,45 @@ impl<E: TchElement> BoolTensorOps<Self> for LibTorch<E> {
         )
     }
 
+    fn bool_and<const D: usize>(
+        lhs: TchTensor<bool, D>,
+        rhs: TchTensor<bool, D>,
+    ) -> TchTensor<bool, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.logical_and_(rhs),
+            |lhs, rhs| rhs.logical_and_(lhs),
+            |lhs, rhs| lhs.logical_and(rhs),
+        )
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: TchTensor<bool, D>,
+        rhs: TchTensor<bool, D>,
+    ) -> TchTensor<bool, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.logical_or_(rhs),
+            |lhs, rhs| rhs.logical_or_(lhs),
+            |lhs, rhs| lhs.logical_or(rhs),
+        )
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: TchTensor<bool, D>,
+        rhs: TchTensor<bool, D>,
+    ) -> TchTensor<bool, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.logical_xor_(rhs),
+            |lhs, rhs| rhs.logical_xor_(lhs),
+            |lhs, rhs| lhs.logical_xor(rhs),
+        )
+    }
+
     fn bool_into_int<const D: usize>(tensor: TchTensor<bool, D>) -> TchTensor<i64, D> {
         let tensor = tensor.tensor.to_kind(tch::Kind::Int64);
         TchTensor::new(tensor)
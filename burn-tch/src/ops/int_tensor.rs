@@ -18,6 +18,10 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         tensor.shape()
     }
 
+    fn int_expand<const D: usize>(tensor: TchTensor<i64, D>, shape: Shape<D>) -> TchTensor<i64, D> {
+        TchOps::expand(tensor, shape)
+    }
+
     fn int_repeat<const D: usize>(
         tensor: TchTensor<i64, D>,
         dim: usize,
@@ -26,6 +30,14 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         TchOps::repeat(tensor, dim, times)
     }
 
+    fn int_roll<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> TchTensor<i64, D> {
+        TchOps::roll(tensor, shifts, dims)
+    }
+
     fn int_into_data<const D: usize>(tensor: TchTensor<i64, D>) -> Reader<Data<i64, D>> {
         let shape = Self::int_shape(&tensor);
         let tensor = Self::int_reshape(tensor.clone(), Shape::new([shape.num_elements()]));
@@ -41,6 +53,13 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         TchOps::to_device(tensor, device)
     }
 
+    fn int_to_device_async<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        device: &LibTorchDevice,
+    ) -> Reader<TchTensor<i64, D>> {
+        Reader::Concrete(TchOps::to_device_async(tensor, device))
+    }
+
     fn int_reshape<const D1: usize, const D2: usize>(
         tensor: TchTensor<i64, D1>,
         shape: Shape<D2>,
@@ -347,6 +366,10 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         TchOps::argmin(tensor, dim)
     }
 
+    fn int_nonzero<const D: usize>(tensor: TchTensor<i64, D>) -> TchTensor<i64, 2> {
+        TchTensor::new(tensor.tensor.nonzero())
+    }
+
     fn int_max_dim<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
         TchOps::max_dim(tensor, dim)
     }
@@ -1,7 +1,7 @@
 use crate::{element::TchElement, LibTorch, TchTensor};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, MaxPool1dWithIndices, MaxPool2dBackward,
-    MaxPool2dWithIndices, ModuleOps,
+    ConvOptions, ConvTransposeOptions, GridSampleMode, GridSamplePaddingMode, InterpolateMode,
+    MaxPool1dWithIndices, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
 };
 
 impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
@@ -283,4 +283,90 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
 
         TchTensor::new(tensor)
     }
+
+    fn interpolate(
+        x: TchTensor<E, 4>,
+        output_size: [usize; 2],
+        mode: InterpolateMode,
+    ) -> TchTensor<E, 4> {
+        let output_size = output_size.map(|e| e as i64);
+
+        let tensor = match mode {
+            InterpolateMode::Nearest => x.tensor.upsample_nearest2d(output_size, None, None),
+            InterpolateMode::Bilinear { align_corners } => {
+                x.tensor
+                    .upsample_bilinear2d(output_size, align_corners, None, None)
+            }
+            InterpolateMode::Bicubic => x.tensor.upsample_bicubic2d(output_size, false, None, None),
+        };
+
+        TchTensor::new(tensor)
+    }
+
+    fn interpolate_backward(
+        x: TchTensor<E, 4>,
+        grad: TchTensor<E, 4>,
+        output_size: [usize; 2],
+        mode: InterpolateMode,
+    ) -> TchTensor<E, 4> {
+        let output_size = output_size.map(|e| e as i64);
+        let input_size = x.tensor.size();
+
+        let tensor = match mode {
+            InterpolateMode::Nearest => tch::Tensor::upsample_nearest2d_backward(
+                &grad.tensor,
+                output_size,
+                input_size,
+                None,
+                None,
+            ),
+            InterpolateMode::Bilinear { align_corners } => {
+                tch::Tensor::upsample_bilinear2d_backward(
+                    &grad.tensor,
+                    output_size,
+                    input_size,
+                    align_corners,
+                    None,
+                    None,
+                )
+            }
+            InterpolateMode::Bicubic => tch::Tensor::upsample_bicubic2d_backward(
+                &grad.tensor,
+                output_size,
+                input_size,
+                false,
+                None,
+                None,
+            ),
+        };
+
+        TchTensor::new(tensor)
+    }
+
+    fn grid_sample(
+        input: TchTensor<E, 4>,
+        grid: TchTensor<E, 4>,
+        mode: GridSampleMode,
+        padding_mode: GridSamplePaddingMode,
+        align_corners: bool,
+    ) -> TchTensor<E, 4> {
+        let interpolation_mode = match mode {
+            GridSampleMode::Bilinear => 0,
+            GridSampleMode::Nearest => 1,
+        };
+        let padding_mode = match padding_mode {
+            GridSamplePaddingMode::Zeros => 0,
+            GridSamplePaddingMode::Border => 1,
+            GridSamplePaddingMode::Reflection => 2,
+        };
+
+        let tensor = input.tensor.grid_sampler_2d(
+            &grid.tensor,
+            interpolation_mode,
+            padding_mode,
+            align_corners,
+        );
+
+        TchTensor::new(tensor)
+    }
 }
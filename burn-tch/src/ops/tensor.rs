@@ -42,6 +42,10 @@ impl<E: TchElement> FloatTensorOps<Self> for LibTorch<E> {
         }
     }
 
+    fn float_expand<const D: usize>(tensor: TchTensor<E, D>, shape: Shape<D>) -> TchTensor<E, D> {
+        TchOps::expand(tensor, shape)
+    }
+
     fn float_repeat<const D: usize>(
         tensor: TchTensor<E, D>,
         dim: usize,
@@ -50,6 +54,14 @@ impl<E: TchElement> FloatTensorOps<Self> for LibTorch<E> {
         TchOps::repeat(tensor, dim, times)
     }
 
+    fn float_roll<const D: usize>(
+        tensor: TchTensor<E, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> TchTensor<E, D> {
+        TchOps::roll(tensor, shifts, dims)
+    }
+
     fn float_zeros<const D: usize>(shape: Shape<D>, device: &LibTorchDevice) -> TchTensor<E, D> {
         let shape = TchShape::from(shape);
         let device: tch::Device = (*device).into();
@@ -91,6 +103,13 @@ impl<E: TchElement> FloatTensorOps<Self> for LibTorch<E> {
         TchOps::to_device(tensor, device)
     }
 
+    fn float_to_device_async<const D: usize>(
+        tensor: TchTensor<E, D>,
+        device: &LibTorchDevice,
+    ) -> Reader<TchTensor<E, D>> {
+        Reader::Concrete(TchOps::to_device_async(tensor, device))
+    }
+
     fn float_empty<const D: usize>(
         shape: Shape<D>,
         device: &<LibTorch<E> as Backend>::Device,
@@ -370,6 +389,107 @@ impl<E: TchElement> FloatTensorOps<Self> for LibTorch<E> {
         TchOps::min_dim_with_indices(tensor, dim)
     }
 
+    fn float_cumsum<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.cumsum(dim as i64, E::KIND))
+    }
+
+    fn float_cumprod<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.cumprod(dim as i64, E::KIND))
+    }
+
+    fn float_logcumsumexp<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.logcumsumexp(dim as i64))
+    }
+
+    fn float_svd<const D: usize>(
+        tensor: TchTensor<E, D>,
+        full_matrices: bool,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>, TchTensor<E, D>) {
+        let (u, s, v) = tensor.tensor.svd(!full_matrices, true);
+
+        (
+            TchTensor::new(u),
+            TchTensor::new(s.unsqueeze(-2)),
+            TchTensor::new(v.transpose(-2, -1).contiguous()),
+        )
+    }
+
+    fn float_eigh<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (values, vectors) = tensor.tensor.linalg_eigh("L");
+
+        (
+            TchTensor::new(vectors),
+            TchTensor::new(values.unsqueeze(-2)),
+        )
+    }
+
+    fn float_solve<const D: usize>(a: TchTensor<E, D>, b: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tch::Tensor::linalg_solve(&a.tensor, &b.tensor, true))
+    }
+
+    fn float_lstsq<const D: usize>(a: TchTensor<E, D>, b: TchTensor<E, D>) -> TchTensor<E, D> {
+        // Only the QR driver, which requires full rank matrices, is available on CUDA.
+        let driver = match a.tensor.device() {
+            tch::Device::Cpu => "gelsd",
+            _ => "gels",
+        };
+        let (solution, _, _, _) = a.tensor.linalg_lstsq(&b.tensor, None, driver);
+
+        TchTensor::new(solution)
+    }
+
+    fn float_cholesky<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.linalg_cholesky(false))
+    }
+
+    fn float_cholesky_solve<const D: usize>(
+        factor: TchTensor<E, D>,
+        b: TchTensor<E, D>,
+        upper: bool,
+    ) -> TchTensor<E, D> {
+        TchTensor::new(b.tensor.cholesky_solve(&factor.tensor, upper))
+    }
+
+    fn float_sort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<E, D> {
+        Self::float_sort_with_indices(tensor, dim, descending).0
+    }
+
+    fn float_sort_with_indices<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let (values, indices) = tensor.tensor.sort_stable(true, dim as i64, descending);
+
+        (TchTensor::new(values), TchTensor::new(indices))
+    }
+
+    fn float_argsort<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> TchTensor<i64, D> {
+        TchTensor::new(tensor.tensor.argsort_stable(true, dim as i64, descending))
+    }
+
+    fn float_nonzero<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<i64, 2> {
+        TchTensor::new(tensor.tensor.nonzero())
+    }
+
+    fn float_topk<const D: usize>(
+        tensor: TchTensor<E, D>,
+        k: usize,
+        dim: usize,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let (values, indices) = tensor.tensor.topk(k as i64, dim as i64, true, true);
+
+        (TchTensor::new(values), TchTensor::new(indices))
+    }
+
     fn float_exp<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
         tensor.unary_ops(|mut tensor| tensor.exp_(), |tensor| tensor.exp())
     }
@@ -413,6 +533,10 @@ impl<E: TchElement> FloatTensorOps<Self> for LibTorch<E> {
         tensor.unary_ops(|mut tensor| tensor.erf_(), |tensor| tensor.erf())
     }
 
+    fn float_erfinv<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.erfinv_(), |tensor| tensor.erfinv())
+    }
+
     fn float_cat<const D: usize>(tensors: Vec<TchTensor<E, D>>, dim: usize) -> TchTensor<E, D> {
         TchOps::cat(tensors, dim)
     }
@@ -1,7 +1,6 @@
 use crate::backend::Backend;
 use crate::check::TensorCheck;
-use crate::{check, Tensor};
-use crate::{ElementPrecision, Precision};
+use crate::{check, Distribution, Tensor};
 
 /// Applies the rectified linear unit function.
 pub fn relu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
@@ -21,6 +20,9 @@ pub fn gelu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
 ///
 /// The dimension argument `dim` specifies the dimension along which the function will be computed.
 /// It must in the range of `0` and `D-1`.
+///
+/// The maximum along the dimension is subtracted before the exponentiation, which doesn't change
+/// the result but prevents overflows with large inputs.
 pub fn softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
     check!(TensorCheck::dim_ops::<D>("softmax", dim));
 
@@ -67,6 +69,10 @@ pub fn quiet_softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usiz
 ///
 /// The dimension argument `dim` specifies the dimension along which the function will be computed.
 /// It must in the range of `0` and `D-1`.
+///
+/// The function is computed with the log-sum-exp trick as
+/// `x_i - max(x) - log(sum_j(exp(x_j - max(x))))`, which neither overflows with large inputs nor
+/// underflows to the logarithm of zero.
 pub fn log_softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
     check!(TensorCheck::dim_ops::<D>("log softmax", dim));
 
@@ -76,21 +82,62 @@ pub fn log_softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize)
     tensor.sub(tensor_tmp)
 }
 
+/// Draws a sample from the Gumbel-softmax (concrete) distribution, a differentiable relaxation of
+/// the categorical distribution given by the logits.
+///
+/// `y_i = softmax((x_i + g_i) / tau)`, where `g_i` is sampled from the Gumbel distribution.
+///
+/// # Arguments
+///
+/// * `tau` - The temperature, the samples getting closer to one-hot vectors as it goes to zero.
+/// * `hard` - If the samples are discretized into one-hot vectors of their largest element. The
+///   gradient is then the one of the soft samples, with the straight-through estimator.
+/// * `dim` - The dimension of the categories, which can be negative to count from the last one.
+pub fn gumbel_softmax<const D: usize, B: Backend>(
+    logits: Tensor<B, D>,
+    tau: f64,
+    hard: bool,
+    dim: i64,
+) -> Tensor<B, D> {
+    check!(TensorCheck::gumbel_softmax::<D>(tau, dim));
+
+    let dim = dim.rem_euclid(D as i64) as usize;
+    let gumbel = logits
+        .random_like(Distribution::Default)
+        .clamp(f32::EPSILON, 1.0 - f32::EPSILON)
+        .log()
+        .neg()
+        .log()
+        .neg();
+    let soft = softmax(logits.add(gumbel).div_scalar(tau), dim);
+
+    if !hard {
+        return soft;
+    }
+
+    let index = soft.clone().detach().argmax(dim);
+    let mut shape = soft.dims();
+    shape[dim] = 1;
+    let one_hot = soft
+        .zeros_like()
+        .scatter(dim, index, Tensor::ones(shape, &soft.device()));
+
+    one_hot.sub(soft.clone().detach()).add(soft)
+}
+
 /// Applies the sigmoid function.
 pub fn sigmoid<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     Tensor::from_primitive(B::sigmoid(tensor.primitive))
 }
 
 /// Applies the log sigmoid function.
+///
+/// `log_sigmoid(x) = log(1 / (1 + exp(-x)))`
+///
+/// The function is computed as `min(x, 0) - log(1 + exp(-|x|))`, which doesn't overflow for
+/// large negative inputs, and its gradient as `sigmoid(-x)`.
 pub fn log_sigmoid<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
-    match B::FloatElem::precision() {
-        Precision::Half => {
-            let tensor_full = tensor.to_full_precision();
-            let tensor_tmp = tensor_full.neg().exp().add_scalar(1.0_f32).log().neg();
-            Tensor::from_full_precision(tensor_tmp)
-        }
-        _ => tensor.neg().exp().add_scalar(1.0_f32).log().neg(),
-    }
+    Tensor::from_primitive(B::log_sigmoid(tensor.primitive))
 }
 
 /// Applies the silu function
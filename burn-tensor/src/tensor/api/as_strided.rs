@@ -0,0 +1,79 @@
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Element, Int, Numeric, Tensor};
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Creates a tensor of the given size whose elements are read from the contiguous row-major
+    /// data of the tensor, with the given strides and offset, like PyTorch's `as_strided`.
+    ///
+    /// The element at position `[i_0, i_1, ...]` of the output is the element of index
+    /// `storage_offset + i_0 * stride[0] + i_1 * stride[1] + ...` of the
+    /// [flattened](Tensor::flatten) tensor. A stride of 0 repeats the same elements, and
+    /// overlapping windows can be created with strides smaller than the size of the windows.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The shape of the output tensor.
+    /// * `stride` - The stride of each dimension of the output, in number of elements.
+    /// * `storage_offset` - The index of the first element.
+    ///
+    /// # Notes
+    ///
+    /// Burn doesn't expose the memory layout of the tensors, so the elements are gathered into a
+    /// new tensor instead of being a view. The gradient of the tensor accumulates the gradient of
+    /// each output element at the position it was read from.
+    ///
+    /// # Panics
+    ///
+    /// If an element is read outside of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0, 4.0, 5.0], &device);
+    ///     // The sliding windows of size 3 with a step of 1.
+    ///     let windows: Tensor<B, 2> = tensor.as_strided([3, 3], [1, 1], 0);
+    ///     // [[1.0, 2.0, 3.0], [2.0, 3.0, 4.0], [3.0, 4.0, 5.0]]
+    /// }
+    /// ```
+    pub fn as_strided<const D2: usize>(
+        self,
+        size: [usize; D2],
+        stride: [usize; D2],
+        storage_offset: usize,
+    ) -> Tensor<B, D2, K> {
+        let num_elements = self.shape().num_elements();
+        check!(TensorCheck::as_strided(
+            num_elements,
+            &size,
+            &stride,
+            storage_offset
+        ));
+
+        let device = self.device();
+        let mut indices = Tensor::<B, D2, Int>::full(size, storage_offset as i64, &device);
+        for dim in 0..D2 {
+            let mut shape = [1; D2];
+            shape[dim] = size[dim];
+            let steps = Tensor::<B, 1, Int>::arange(0..size[dim] as i64, &device)
+                .mul_scalar(stride[dim] as i64)
+                .reshape(shape);
+            indices = indices.add(steps);
+        }
+
+        let num_outputs = size.iter().product::<usize>();
+        self.reshape([num_elements])
+            .select(0, indices.reshape([num_outputs]))
+            .reshape(size)
+    }
+}
@@ -10,7 +10,7 @@ use alloc::string::String;
 use alloc::vec;
 
 use burn_common::{reader::Reader, stub::Mutex};
-use core::{fmt::Debug, ops::Range};
+use core::{fmt::Debug, future::Future, ops::Range};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::check::TensorCheck;
@@ -110,6 +110,32 @@ where
         Tensor::new(K::reshape::<D, D2>(self.primitive, shape))
     }
 
+    /// Reshape the tensor to have the given shape, like [reshape](Tensor::reshape), with the
+    /// intent of not copying the data.
+    ///
+    /// Burn doesn't expose the memory layout of the tensors, so there is no non-contiguous
+    /// tensor to reject: the backends reinterpret the data without copying when its layout
+    /// allows it, and copy it otherwise, exactly as with [reshape](Tensor::reshape). This method
+    /// only documents the intent when porting code using PyTorch's `view`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = Default::default();
+    ///    let tensor = Tensor::<B, 3>::ones([2, 3, 4], &device);
+    ///    let viewed: Tensor::<B, 2> = tensor.view([6, -1]);
+    ///    // The resulting tensor will have dimensions (6, 4).
+    ///    println!("{:?}", viewed.shape());
+    /// }
+    /// ```
+    #[must_use = "the view is a new tensor, the original tensor being consumed"]
+    pub fn view<const D2: usize, S: ReshapeArgs<D2>>(self, shape: S) -> Tensor<B, D2, K> {
+        self.reshape(shape)
+    }
+
     /// Transpose the tensor.
     ///
     /// # Arguments
@@ -399,6 +425,25 @@ where
         Self::new(K::to_device(self.primitive, device))
     }
 
+    /// Starts moving the tensor to the given device, returning a future that resolves to the
+    /// tensor on that device.
+    ///
+    /// The transfer is started when the function is called, so the next batch can be prepared
+    /// while it completes. Backends that can't transfer tensors asynchronously move the tensor
+    /// before returning, in which case the future is immediately ready.
+    pub fn to_device_async(self, device: &B::Device) -> impl Future<Output = Self> {
+        let reader = K::to_device_async(self.primitive, device);
+
+        async move {
+            #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+            let primitive = reader.read().await;
+            #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+            let primitive = reader.read();
+
+            Self::new(primitive)
+        }
+    }
+
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     /// Returns the data of the current tensor.
     pub async fn into_data(self) -> Data<K::Elem, D> {
@@ -440,6 +485,38 @@ where
         Self::new(K::repeat(self.primitive, dim, times))
     }
 
+    /// Broadcasts the tensor to the given shape, the dimensions of size 1 being expanded to the
+    /// size of the shape.
+    ///
+    /// The result is always materialized: the data is copied along the expanded dimensions rather
+    /// than shared through a view with a stride of 0, so expanding a tensor uses as much memory as
+    /// [repeating](Tensor::repeat) it. Use the broadcasting of the element-wise operations when a
+    /// copy isn't needed.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension of the tensor differs from the shape without being of size 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0], [2.0]], &device);
+    ///     let tensor = tensor.expand([2, 3]);
+    ///     // [[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]]
+    /// }
+    /// ```
+    pub fn expand<S: Into<Shape<D>>>(self, shape: S) -> Self {
+        let shape = shape.into();
+        check!(TensorCheck::expand(&self.shape(), &shape));
+
+        Self::new(K::expand(self.primitive, shape))
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     ///
     /// # Panics
@@ -490,6 +567,73 @@ where
         DimIter::new(self, dim)
     }
 
+    /// Removes the given dimension, returning the slices of the tensor along that dimension.
+    ///
+    /// This is the inverse of [stack](Tensor::stack): stacking the slices along the same
+    /// dimension gives back the tensor.
+    ///
+    /// # Panics
+    ///
+    /// - If the dimension is greater than the number of dimensions of the tensor.
+    /// - If the number of dimensions of the slices isn't one less than the tensor's.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+    ///     let columns: Vec<Tensor<B, 1>> = tensor.unbind(1);
+    ///     // [[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]
+    /// }
+    /// ```
+    pub fn unbind<const D2: usize>(self, dim: usize) -> Vec<Tensor<B, D2, K>> {
+        check!(TensorCheck::unbind::<D, D2>(dim));
+
+        let size = self.dims()[dim];
+        (0..size)
+            .map(|index| self.clone().narrow(dim, index, 1).squeeze(dim))
+            .collect()
+    }
+
+    /// Applies the function independently to each sample of the batch, the first dimension of
+    /// the tensor, and stacks the results into a new batch.
+    ///
+    /// The samples may be mapped to a different shape, as long as all the results have the same
+    /// shape. The function is called once per sample, so the gradients flow through each call
+    /// separately and a function that already supports a leading batch dimension is faster when
+    /// called on the whole batch.
+    ///
+    /// # Panics
+    ///
+    /// - If the number of dimensions of the samples isn't one less than the tensor's.
+    /// - If the batch is empty, or if the results don't all have the same shape.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+    ///     let tensor = tensor.vmap(|sample: Tensor<B, 1>| sample.clone().mul(sample).cumsum(0));
+    ///     // [[1.0, 5.0], [9.0, 25.0], [25.0, 61.0]]
+    /// }
+    /// ```
+    pub fn vmap<const D2: usize, F>(self, f: F) -> Self
+    where
+        F: FnMut(Tensor<B, D2, K>) -> Tensor<B, D2, K>,
+    {
+        let outputs = self.unbind::<D2>(0).into_iter().map(f).collect();
+
+        Tensor::stack(outputs, 0)
+    }
+
     /// Returns a new tensor with the given dimension narrowed to the given range.
     ///
     /// # Panics
@@ -525,6 +669,36 @@ where
             .map(|v| Self::new(v))
             .collect()
     }
+
+    /// Rolls the tensor along the given dimensions, the elements shifted beyond the last
+    /// position being re-introduced at the first position.
+    ///
+    /// A positive shift moves the elements toward higher indices. When several dimensions are
+    /// given, each (shift, dimension) pair is applied from left to right.
+    ///
+    /// # Panics
+    ///
+    /// - If the number of shifts isn't the same as the number of dimensions.
+    /// - If a dimension is greater than the number of dimensions of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    ///     let tensor = tensor.roll(&[1], &[1]);
+    ///     println!("{}", tensor);
+    ///     // [[3.0, 1.0, 2.0], [6.0, 4.0, 5.0]]
+    /// }
+    /// ```
+    pub fn roll(self, shifts: &[i64], dims: &[usize]) -> Self {
+        check!(TensorCheck::roll::<D>(shifts, dims));
+        Self::new(K::roll(self.primitive, shifts, dims))
+    }
 }
 
 /// Iterator given by (Tensor::iter_dim).
@@ -1014,6 +1188,30 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
         device: &B::Device,
     ) -> Self::Primitive<D>;
 
+    /// Moves the tensor to the given device without waiting for the transfer to complete.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `device` - The device on which the tensor will be moved.
+    ///
+    /// # Returns
+    ///
+    /// The reader of the tensor on the given device.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For moving a tensor to a device, users should prefer the [Tensor::to_device_async](Tensor::to_device_async)
+    /// function, which is more high-level and designed for public use.
+    fn to_device_async<const D: usize>(
+        tensor: Self::Primitive<D>,
+        device: &B::Device,
+    ) -> Reader<Self::Primitive<D>>;
+
     /// Extracts the data from the tensor.
     ///
     /// # Arguments
@@ -1084,6 +1282,27 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
         times: usize,
     ) -> Self::Primitive<D>;
 
+    /// Broadcasts the tensor to the given shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `shape` - The shape to broadcast to.
+    ///
+    /// # Returns
+    ///
+    /// The tensor broadcast to the given shape.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For expanding a tensor, users should prefer the [Tensor::expand](Tensor::expand) function,
+    /// which is more high-level and designed for public use.
+    fn expand<const D: usize>(tensor: Self::Primitive<D>, shape: Shape<D>) -> Self::Primitive<D>;
+
     /// Concatenates the given tensors along the given dimension.
     ///
     /// # Arguments
@@ -1105,6 +1324,32 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
     /// which is more high-level and designed for public use.
     fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D>;
 
+    /// Rolls the tensor along the given dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `shifts` - The number of places by which the elements are shifted along each dimension.
+    /// * `dims` - The dimensions along which the tensor will be rolled.
+    ///
+    /// # Returns
+    ///
+    /// The rolled tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For rolling a tensor, users should prefer the [Tensor::roll](Tensor::roll) function,
+    /// which is more high-level and designed for public use.
+    fn roll<const D: usize>(
+        tensor: Self::Primitive<D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> Self::Primitive<D>;
+
     /// Equates the given tensors.
     ///
     /// # Arguments
@@ -1191,6 +1436,13 @@ impl<B: Backend> BasicOps<B> for Float {
         B::float_to_device(tensor, device)
     }
 
+    fn to_device_async<const D: usize>(
+        tensor: Self::Primitive<D>,
+        device: &<B as Backend>::Device,
+    ) -> Reader<Self::Primitive<D>> {
+        B::float_to_device_async(tensor, device)
+    }
+
     fn into_data<const D: usize>(tensor: Self::Primitive<D>) -> Reader<Data<Self::Elem, D>> {
         B::float_into_data(tensor)
     }
@@ -1210,10 +1462,22 @@ impl<B: Backend> BasicOps<B> for Float {
         B::float_repeat(tensor, dim, times)
     }
 
+    fn expand<const D: usize>(tensor: Self::Primitive<D>, shape: Shape<D>) -> Self::Primitive<D> {
+        B::float_expand(tensor, shape)
+    }
+
     fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D> {
         B::float_cat(vectors, dim)
     }
 
+    fn roll<const D: usize>(
+        tensor: Self::Primitive<D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> Self::Primitive<D> {
+        B::float_roll(tensor, shifts, dims)
+    }
+
     fn equal<const D: usize>(
         lhs: Self::Primitive<D>,
         rhs: Self::Primitive<D>,
@@ -1278,6 +1542,13 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_to_device(tensor, device)
     }
 
+    fn to_device_async<const D: usize>(
+        tensor: Self::Primitive<D>,
+        device: &<B as Backend>::Device,
+    ) -> Reader<Self::Primitive<D>> {
+        B::int_to_device_async(tensor, device)
+    }
+
     fn into_data<const D: usize>(tensor: Self::Primitive<D>) -> Reader<Data<Self::Elem, D>> {
         B::int_into_data(tensor)
     }
@@ -1297,6 +1568,10 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_repeat(tensor, dim, times)
     }
 
+    fn expand<const D: usize>(tensor: Self::Primitive<D>, shape: Shape<D>) -> Self::Primitive<D> {
+        B::int_expand(tensor, shape)
+    }
+
     fn equal<const D: usize>(
         lhs: Self::Primitive<D>,
         rhs: Self::Primitive<D>,
@@ -1307,6 +1582,14 @@ impl<B: Backend> BasicOps<B> for Int {
     fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D> {
         B::int_cat(vectors, dim)
     }
+
+    fn roll<const D: usize>(
+        tensor: Self::Primitive<D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> Self::Primitive<D> {
+        B::int_roll(tensor, shifts, dims)
+    }
 }
 
 impl<B: Backend> BasicOps<B> for Bool {
@@ -1365,6 +1648,13 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_to_device(tensor, device)
     }
 
+    fn to_device_async<const D: usize>(
+        tensor: Self::Primitive<D>,
+        device: &<B as Backend>::Device,
+    ) -> Reader<Self::Primitive<D>> {
+        B::bool_to_device_async(tensor, device)
+    }
+
     fn into_data<const D: usize>(tensor: Self::Primitive<D>) -> Reader<Data<Self::Elem, D>> {
         B::bool_into_data(tensor)
     }
@@ -1384,6 +1674,10 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_repeat(tensor, dim, times)
     }
 
+    fn expand<const D: usize>(tensor: Self::Primitive<D>, shape: Shape<D>) -> Self::Primitive<D> {
+        B::bool_expand(tensor, shape)
+    }
+
     fn equal<const D: usize>(
         lhs: Self::Primitive<D>,
         rhs: Self::Primitive<D>,
@@ -1394,6 +1688,14 @@ impl<B: Backend> BasicOps<B> for Bool {
     fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D> {
         B::bool_cat(vectors, dim)
     }
+
+    fn roll<const D: usize>(
+        tensor: Self::Primitive<D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> Self::Primitive<D> {
+        B::bool_roll(tensor, shifts, dims)
+    }
 }
 
 /// Trait used for reshape arguments.
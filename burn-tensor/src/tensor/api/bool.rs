@@ -1,4 +1,4 @@
-use crate::{backend::Backend, Bool, Data, Int, Tensor};
+use crate::{backend::Backend, check, check::TensorCheck, Bool, Data, Int, Tensor};
 
 impl<B, const D: usize> Tensor<B, D, Bool>
 where
@@ -23,4 +23,71 @@ where
     pub fn bool_not(self) -> Self {
         Tensor::new(B::bool_not(self.primitive))
     }
+
+    /// Applies element wise logical and, also available with the `&` operator.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors don't have compatible shapes.
+    pub fn logical_and(self, rhs: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("LogicalAnd", &self, &rhs));
+        Tensor::new(B::bool_and(self.primitive, rhs.primitive))
+    }
+
+    /// Applies element wise logical or, also available with the `|` operator.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors don't have compatible shapes.
+    pub fn logical_or(self, rhs: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("LogicalOr", &self, &rhs));
+        Tensor::new(B::bool_or(self.primitive, rhs.primitive))
+    }
+
+    /// Applies element wise logical exclusive or, also available with the `^` operator.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors don't have compatible shapes.
+    pub fn logical_xor(self, rhs: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("LogicalXor", &self, &rhs));
+        Tensor::new(B::bool_xor(self.primitive, rhs.primitive))
+    }
+
+    /// Applies element wise logical not, also available with the `!` operator.
+    pub fn logical_not(self) -> Self {
+        Tensor::new(B::bool_not(self.primitive))
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::BitAnd for Tensor<B, D, Bool> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.logical_and(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::BitOr for Tensor<B, D, Bool> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.logical_or(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::BitXor for Tensor<B, D, Bool> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        self.logical_xor(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::Not for Tensor<B, D, Bool> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.logical_not()
+    }
 }
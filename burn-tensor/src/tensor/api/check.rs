@@ -1,3 +1,5 @@
+use crate::tensor::einsum::{Equation, MAX_RANK};
+use crate::tensor::signal::PadMode;
 use crate::{backend::Backend, BasicOps, Shape, Tensor};
 use alloc::format;
 use alloc::string::{String, ToString};
@@ -80,6 +82,28 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn gumbel_softmax<const D: usize>(tau: f64, dim: i64) -> Self {
+        let mut check = Self::Ok;
+
+        if tau <= 0.0 {
+            check = check.register(
+                "Gumbel Softmax",
+                TensorError::new("The temperature should be greater than zero.")
+                    .details(format!("Got temperature {tau}.")),
+            );
+        }
+
+        if dim < -(D as i64) || dim >= D as i64 {
+            check = check.register(
+                "Gumbel Softmax",
+                TensorError::new("The dimension should be valid for the rank of the tensor.")
+                    .details(format!("Got dimension {dim} for a tensor of rank {D}.")),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn narrow<B: Backend, const D: usize, K: BasicOps<B>>(
         tensor: &Tensor<B, D, K>,
         dim: usize,
@@ -125,6 +149,570 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn roll<const D: usize>(shifts: &[i64], dims: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        if shifts.len() != dims.len() {
+            check = check.register(
+                "Roll",
+                TensorError::new(
+                    "The number of shifts should be the same as the number of dimensions.",
+                )
+                .details(format!(
+                    "Got {} shifts and {} dimensions.",
+                    shifts.len(),
+                    dims.len()
+                )),
+            );
+        }
+
+        for dim in dims {
+            if *dim >= D {
+                check = check.register(
+                    "Roll",
+                    TensorError::new("Given dimension is higher than the tensor rank.")
+                        .details(format!("Tensor rank: '{D}', given dimension: '{dim}'.")),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn mask_broadcast<const D1: usize, const D2: usize>(
+        shape: &Shape<D1>,
+        mask_shape: &Shape<D2>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D2 > D1 {
+            return check.register(
+                "Mask Broadcast",
+                TensorError::new("The mask can't have more dimensions than the tensor.")
+                    .details(format!("Tensor rank: '{D1}', mask rank: '{D2}'.")),
+            );
+        }
+
+        for i in 0..D2 {
+            let dim_mask = mask_shape.dims[D2 - i - 1];
+            let dim_tensor = shape.dims[D1 - i - 1];
+
+            if dim_mask != 1 && dim_mask != dim_tensor {
+                check = check.register(
+                    "Mask Broadcast",
+                    TensorError::new(
+                        "The shape of the mask can't be broadcasted to the shape of the tensor.",
+                    )
+                    .details(format!(
+                        "Tensor shape: {:?}, mask shape: {:?}.",
+                        shape.dims, mask_shape.dims
+                    )),
+                );
+                break;
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn histogram(bins: usize, min: f64, max: f64) -> Self {
+        let mut check = Self::Ok;
+
+        if bins == 0 {
+            check = check.register(
+                "Histogram",
+                TensorError::new("The number of bins should be greater than zero."),
+            );
+        }
+
+        if min >= max || !min.is_finite() || !max.is_finite() {
+            check = check.register(
+                "Histogram",
+                TensorError::new(
+                    "The range should be finite with a lower bound below the upper bound.",
+                )
+                .details(format!("Got range ({min}, {max}).")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn cdist(shape_x1: &Shape<3>, shape_x2: &Shape<3>, p: f64) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_x1.dims[0] != shape_x2.dims[0] || shape_x1.dims[2] != shape_x2.dims[2] {
+            check = check.register(
+                "Cdist",
+                TensorError::new(
+                    "The tensors should have the same batch size and the same number of features.",
+                )
+                .details(format!(
+                    "Got shapes {:?} and {:?}.",
+                    shape_x1.dims, shape_x2.dims
+                )),
+            );
+        }
+
+        if p.is_nan() || p < 0.0 {
+            check = check.register(
+                "Cdist",
+                TensorError::new("The norm order p should be non-negative.")
+                    .details(format!("Got p = {p}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn pairwise_cosine_similarity(shape_x1: &Shape<2>, shape_x2: &Shape<2>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_x1.dims[1] != shape_x2.dims[1] {
+            check = check.register(
+                "Pairwise Cosine Similarity",
+                TensorError::new("The tensors should have the same number of features.").details(
+                    format!("Got shapes {:?} and {:?}.", shape_x1.dims, shape_x2.dims),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn bucketize(boundaries: &[f64]) -> Self {
+        let mut check = Self::Ok;
+
+        if let Some(position) = boundaries.windows(2).position(|pair| pair[0] > pair[1]) {
+            check = check.register(
+                "Bucketize",
+                TensorError::new("The boundaries should be sorted in ascending order.").details(
+                    format!(
+                        "Got boundary {} at index {} followed by boundary {}.",
+                        boundaries[position],
+                        position,
+                        boundaries[position + 1]
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn multinomial(
+        num_categories: usize,
+        num_samples: usize,
+        replacement: bool,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if !replacement && num_samples > num_categories {
+            check = check.register(
+                "Multinomial",
+                TensorError::new("Can't draw more samples than categories without replacement.")
+                    .details(format!(
+                        "Got {num_samples} samples for {num_categories} categories."
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn segment_reduce(shape: &Shape<2>, shape_ids: &Shape<1>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape.dims[0] != shape_ids.dims[0] {
+            check = check.register(
+                "Segment Reduce",
+                TensorError::new("The number of segment ids should be the number of rows.")
+                    .details(format!(
+                        "Got {} segment ids for a tensor of shape {:?}.",
+                        shape_ids.dims[0], shape.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn index_put<const D: usize>(
+        shape: &Shape<D>,
+        lengths: &[Option<usize>],
+        shape_values: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if lengths.len() > D {
+            check = check.register(
+                "Index Put",
+                TensorError::new("There should be at most one index per dimension.").details(
+                    format!(
+                        "Got {} indices for a tensor of {D} dimensions.",
+                        lengths.len()
+                    ),
+                ),
+            );
+            return check;
+        }
+
+        let indexed = lengths
+            .iter()
+            .enumerate()
+            .filter_map(|(dim, length)| length.map(|length| (dim, length)))
+            .collect::<Vec<_>>();
+
+        let Some((_, num_indices)) = indexed.first() else {
+            return check.register(
+                "Index Put",
+                TensorError::new("At least one dimension should be indexed."),
+            );
+        };
+
+        if indexed.iter().any(|(_, length)| length != num_indices) {
+            check = check.register(
+                "Index Put",
+                TensorError::new("The indices should all have the same length.")
+                    .details(format!("Got indices of lengths {lengths:?}.")),
+            );
+        }
+
+        let mut expected = shape.dims;
+        for (position, (dim, _)) in indexed.iter().enumerate() {
+            expected[*dim] = match position {
+                0 => *num_indices,
+                _ => 1,
+            };
+        }
+
+        if shape_values.dims != expected {
+            check = check.register(
+                "Index Put",
+                TensorError::new(
+                    "The values should have the shape of the tensor, with the first indexed \
+                     dimension of the number of indices and the other indexed dimensions of size 1.",
+                )
+                .details(format!(
+                    "Expected values of shape {:?}, got {:?}.",
+                    expected, shape_values.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn normalize<const D: usize>(p: f64, dim: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                "Normalize",
+                TensorError::new("The dimension should be lower than the number of dimensions.")
+                    .details(format!("Got dim = {dim} for a tensor of {D} dimensions.")),
+            );
+        }
+
+        if p.is_nan() || p <= 0.0 {
+            check = check.register(
+                "Normalize",
+                TensorError::new("The norm order p should be positive.")
+                    .details(format!("Got p = {p}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn stft(
+        signal_length: usize,
+        n_fft: usize,
+        hop_length: usize,
+        win_length: usize,
+        window_length: usize,
+        padding: usize,
+        pad_mode: PadMode,
+    ) -> Self {
+        let mut check = Self::stft_args(n_fft, hop_length, win_length, window_length, "Stft");
+
+        if signal_length + 2 * padding < n_fft {
+            check = check.register(
+                "Stft",
+                TensorError::new("The padded signal should be at least as long as a frame.")
+                    .details(format!(
+                        "Got a signal of length {signal_length}, a padding of {padding} and \
+                         n_fft = {n_fft}."
+                    )),
+            );
+        }
+
+        if pad_mode == PadMode::Reflect && padding >= signal_length {
+            check = check.register(
+                "Stft",
+                TensorError::new("The reflect padding should be shorter than the signal.").details(
+                    format!("Got a signal of length {signal_length} and a padding of {padding}."),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn istft(
+        shape: &Shape<4>,
+        n_fft: usize,
+        hop_length: usize,
+        win_length: usize,
+        window_length: usize,
+    ) -> Self {
+        let mut check = Self::stft_args(n_fft, hop_length, win_length, window_length, "Istft");
+        let [_, num_freqs, num_frames, parts] = shape.dims;
+
+        if num_freqs != n_fft / 2 + 1 || num_frames == 0 || parts != 2 {
+            check = check.register(
+                "Istft",
+                TensorError::new(
+                    "The spectrogram should have n_fft / 2 + 1 frequencies, at least one frame \
+                     and the real and imaginary parts in the last dimension.",
+                )
+                .details(format!("Got shape {:?} with n_fft = {n_fft}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    fn stft_args(
+        n_fft: usize,
+        hop_length: usize,
+        win_length: usize,
+        window_length: usize,
+        ops: &str,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if n_fft == 0 || hop_length == 0 {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The size of the transform and the hop length should be positive.",
+                )
+                .details(format!(
+                    "Got n_fft = {n_fft} and hop_length = {hop_length}."
+                )),
+            );
+        }
+
+        if win_length > n_fft || window_length != win_length {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The window should be of length win_length, which can't be greater than n_fft.",
+                )
+                .details(format!(
+                    "Got a window of length {window_length}, win_length = {win_length} and \
+                     n_fft = {n_fft}."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn fft<const D: usize>(
+        ops: &str,
+        shape_real: &Shape<D>,
+        shape_imag: &Shape<D>,
+        n: Option<usize>,
+        dim: i64,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_real != shape_imag {
+            check = check.register(
+                ops,
+                TensorError::new("The real and imaginary parts should have the same shape.")
+                    .details(format!(
+                        "Got shapes {:?} and {:?}.",
+                        shape_real.dims, shape_imag.dims
+                    )),
+            );
+        }
+
+        if dim < -(D as i64) || dim >= D as i64 {
+            check = check.register(
+                ops,
+                TensorError::new("The dimension should be valid for the rank of the tensor.")
+                    .details(format!("Got dimension {dim} for a tensor of rank {D}.")),
+            );
+        }
+
+        if n == Some(0) {
+            check = check.register(
+                ops,
+                TensorError::new("The length of the transform should be greater than zero."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn flash_attention(
+        shape_query: &Shape<4>,
+        shape_key: &Shape<4>,
+        shape_value: &Shape<4>,
+        causal: bool,
+    ) -> Self {
+        Self::attention(
+            "FlashAttention",
+            shape_query,
+            shape_key,
+            shape_value,
+            causal,
+        )
+    }
+
+    pub(crate) fn scaled_dot_product_attention(
+        shape_query: &Shape<4>,
+        shape_key: &Shape<4>,
+        shape_value: &Shape<4>,
+        shape_mask: Option<&Shape<4>>,
+        dropout_p: f64,
+        causal: bool,
+    ) -> Self {
+        let ops = "ScaledDotProductAttention";
+        let mut check = Self::attention(ops, shape_query, shape_key, shape_value, causal);
+        let [batch_size, num_heads, seq_length_1, _] = shape_query.dims;
+        let [_, _, seq_length_2, _] = shape_key.dims;
+
+        if let Some(shape_mask) = shape_mask {
+            let [mask_batch_size, mask_num_heads, mask_seq_length_1, mask_seq_length_2] =
+                shape_mask.dims;
+
+            if ![1, batch_size].contains(&mask_batch_size)
+                || ![1, num_heads].contains(&mask_num_heads)
+                || [mask_seq_length_1, mask_seq_length_2] != [seq_length_1, seq_length_2]
+            {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "The mask should have the shape of the attention weights, with a batch size and number of heads of one or of the queries.",
+                    )
+                    .details(format!(
+                        "Got a mask of shape {:?}, expected {:?}.",
+                        shape_mask.dims,
+                        [batch_size, num_heads, seq_length_1, seq_length_2]
+                    )),
+                );
+            }
+        }
+
+        if !(0.0..1.0).contains(&dropout_p) {
+            check = check.register(
+                ops,
+                TensorError::new("The dropout probability should be in the range [0, 1).")
+                    .details(format!("Got {dropout_p}.")),
+            );
+        }
+
+        check
+    }
+
+    fn attention(
+        ops: &str,
+        shape_query: &Shape<4>,
+        shape_key: &Shape<4>,
+        shape_value: &Shape<4>,
+        causal: bool,
+    ) -> Self {
+        let mut check = Self::Ok;
+        let [batch_size, num_heads, seq_length_1, head_dim] = shape_query.dims;
+        let [batch_size_key, num_heads_key, seq_length_2, head_dim_key] = shape_key.dims;
+        let [batch_size_value, num_heads_value, seq_length_value, _] = shape_value.dims;
+
+        if [batch_size_key, num_heads_key, head_dim_key] != [batch_size, num_heads, head_dim] {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The keys should have the batch size, number of heads and head size of the queries.",
+                )
+                .details(format!(
+                    "Got queries of shape {:?} and keys of shape {:?}.",
+                    shape_query.dims, shape_key.dims
+                )),
+            );
+        }
+
+        if [batch_size_value, num_heads_value, seq_length_value]
+            != [batch_size_key, num_heads_key, seq_length_2]
+        {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The values should have the batch size, number of heads and sequence length of the keys.",
+                )
+                .details(format!(
+                    "Got keys of shape {:?} and values of shape {:?}.",
+                    shape_key.dims, shape_value.dims
+                )),
+            );
+        }
+
+        if causal && seq_length_1 > seq_length_2 {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "With a causal mask, there should be at least as many keys as queries.",
+                )
+                .details(format!(
+                    "Got {seq_length_1} queries and {seq_length_2} keys."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn rope_embed(
+        shape_query: &Shape<4>,
+        shape_key: &Shape<4>,
+        shape_cos: &Shape<2>,
+        shape_sin: &Shape<2>,
+    ) -> Self {
+        let mut check = Self::Ok;
+        let [seq_length, half_head_dim] = shape_cos.dims;
+
+        if shape_cos != shape_sin {
+            check = check.register(
+                "RopeEmbed",
+                TensorError::new("The cosines and sines should have the same shape.").details(
+                    format!("Got shapes {:?} and {:?}.", shape_cos.dims, shape_sin.dims),
+                ),
+            );
+        }
+
+        for (name, shape) in [("query", shape_query), ("key", shape_key)] {
+            let [_, _, length, head_dim] = shape.dims;
+
+            if length != seq_length || head_dim != 2 * half_head_dim {
+                check = check.register(
+                    "RopeEmbed",
+                    TensorError::new(format!(
+                        "The {name} should have the sequence length of the cosines and sines, \
+                         with heads of twice their size."
+                    ))
+                    .details(format!(
+                        "Got a {name} of shape {:?} with cosines and sines of shape {:?}.",
+                        shape.dims, shape_cos.dims
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
     pub(crate) fn reshape_args_usize<const D1: usize, const D2: usize>(
         original: &Shape<D1>,
         target: &Shape<D2>,
@@ -248,6 +836,22 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn unbind<const D1: usize, const D2: usize>(dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D1>("Unbind", dim);
+        if D2 + 1 != D1 {
+            check = check.register(
+                "Unbind",
+                TensorError::new(format!(
+                    "Can't unbind a tensor of {D1} dimensions into tensors of {D2} dimensions, \
+                     expected {} dimensions",
+                    D1 - 1
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn unsqueeze<const D1: usize, const D2: usize>() -> Self {
         let mut check = Self::Ok;
         if D2 < D1 {
@@ -277,6 +881,75 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn where_cond<const D: usize>(
+        condition: &Shape<D>,
+        x: &Shape<D>,
+        y: &Shape<D>,
+    ) -> Self {
+        Self::Ok
+            .binary_ops_ew_shape("WhereCond", condition, x)
+            .binary_ops_ew_shape("WhereCond", condition, y)
+            .binary_ops_ew_shape("WhereCond", x, y)
+    }
+
+    pub(crate) fn expand<const D: usize>(shape: &Shape<D>, target: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        for dim in 0..D {
+            let size = shape.dims[dim];
+            if size != 1 && size != target.dims[dim] {
+                check = check.register(
+                    "Expand",
+                    TensorError::new(format!(
+                        "Can't expand dimension {dim} of size {size} to size {}, only the \
+                         dimensions of size 1 can be expanded",
+                        target.dims[dim]
+                    ))
+                    .details(format!(
+                        "The tensor shape is {:?}, the target shape {:?}.",
+                        shape.dims, target.dims
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn as_strided(
+        num_elements: usize,
+        size: &[usize],
+        stride: &[usize],
+        storage_offset: usize,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if size.contains(&0) {
+            return check;
+        }
+
+        let last = storage_offset
+            + size
+                .iter()
+                .zip(stride)
+                .map(|(size, stride)| (size - 1) * stride)
+                .sum::<usize>();
+        if last >= num_elements {
+            check = check.register(
+                "As Strided",
+                TensorError::new(format!(
+                    "Can't read the element of index {last} of a tensor of {num_elements} elements"
+                ))
+                .details(format!(
+                    "The size is {size:?}, the stride {stride:?} and the storage offset \
+                     {storage_offset}."
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn one_hot(index: usize, num_classes: usize) -> Self {
         let mut check = Self::Ok;
         if index >= num_classes {
@@ -342,6 +1015,163 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn matrix_decomposition<const D: usize, const D2: usize>(ops: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                ops,
+                TensorError::new("The tensor should have at least two dimensions.")
+                    .details(format!("Got a tensor of rank {D}.")),
+            );
+        }
+
+        if D2 + 1 != D {
+            check = check.register(
+                ops,
+                TensorError::new("The values should have one dimension less than the tensor.")
+                    .details(format!("Got rank {D2} for a tensor of rank {D}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn square_matrix<const D: usize>(ops: &str, shape: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            return check.register(
+                ops,
+                TensorError::new("The tensor should have at least two dimensions.")
+                    .details(format!("Got a tensor of rank {D}.")),
+            );
+        }
+
+        if shape.dims[D - 2] != shape.dims[D - 1] {
+            check = check.register(
+                ops,
+                TensorError::new("The matrices should be square.")
+                    .details(format!("Got shape {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn linear_system<const D: usize>(
+        ops: &str,
+        shape_a: &Shape<D>,
+        shape_b: &Shape<D>,
+        square: bool,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            return check.register(
+                ops,
+                TensorError::new("The tensors should have at least two dimensions.")
+                    .details(format!("Got {D} dimensions.")),
+            );
+        }
+
+        if shape_a.dims[..D - 2] != shape_b.dims[..D - 2] {
+            check = check.register(
+                ops,
+                TensorError::new("The batch dimensions of the tensors should be the same.")
+                    .details(format!(
+                        "Coefficients shape {:?}, right-hand sides shape {:?}.",
+                        shape_a.dims, shape_b.dims
+                    )),
+            );
+        }
+
+        if shape_a.dims[D - 2] != shape_b.dims[D - 2] {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The coefficients and the right-hand sides should have the same number of rows.",
+                )
+                .details(format!(
+                    "Coefficients shape {:?}, right-hand sides shape {:?}.",
+                    shape_a.dims, shape_b.dims
+                )),
+            );
+        }
+
+        if square && shape_a.dims[D - 2] != shape_a.dims[D - 1] {
+            check = check.register(
+                ops,
+                TensorError::new("The coefficients should be square matrices.")
+                    .details(format!("Got shape {:?}.", shape_a.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn tensordot<const D1: usize, const D2: usize, const D3: usize>(
+        shape_lhs: &Shape<D1>,
+        shape_rhs: &Shape<D2>,
+        dims_lhs: &[usize],
+        dims_rhs: &[usize],
+    ) -> Self {
+        let mut check = Self::Ok;
+        let num_dims = dims_lhs.len();
+
+        for (dims, rank) in [(dims_lhs, D1), (dims_rhs, D2)] {
+            if let Some(dim) = dims.iter().find(|dim| **dim >= rank) {
+                check = check.register(
+                    "Tensordot",
+                    TensorError::new("The contracted dimensions should be lower than the rank.")
+                        .details(format!("Got dimension {dim} for a tensor of rank {rank}.")),
+                );
+            }
+
+            let mut sorted = dims.to_vec();
+            sorted.sort();
+            sorted.dedup();
+            if sorted.len() != num_dims {
+                check = check.register(
+                    "Tensordot",
+                    TensorError::new("The contracted dimensions should be distinct.")
+                        .details(format!("Got dimensions {dims:?}.")),
+                );
+            }
+        }
+
+        // The sizes can't be compared when the dimensions are invalid.
+        if matches!(check, Self::Failed(_)) {
+            return check;
+        }
+
+        let sizes_lhs: Vec<_> = dims_lhs.iter().map(|dim| shape_lhs.dims[*dim]).collect();
+        let sizes_rhs: Vec<_> = dims_rhs.iter().map(|dim| shape_rhs.dims[*dim]).collect();
+        if sizes_lhs != sizes_rhs {
+            check = check.register(
+                "Tensordot",
+                TensorError::new("The contracted dimensions should have the same sizes.").details(
+                    format!(
+                        "Got sizes {sizes_lhs:?} and {sizes_rhs:?} for shapes {:?} and {:?}.",
+                        shape_lhs.dims, shape_rhs.dims
+                    ),
+                ),
+            );
+        }
+
+        let rank = usize::max(D1 + D2 - 2 * num_dims, 1);
+        if D3 != rank {
+            check = check.register(
+                "Tensordot",
+                TensorError::new(format!(
+                    "The output should have {rank} dimensions, but got {D3}."
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn stack<B: Backend, const D: usize, K: BasicOps<B>>(
         tensors: &[Tensor<B, D, K>],
         dim: usize,
@@ -695,6 +1525,213 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn top_k<const D: usize>(k: usize, dim: usize, shape: &Shape<D>) -> Self {
+        let ops = "Top k";
+        let mut check = Self::dim_ops::<D>(ops, dim);
+
+        if let Self::Ok = check {
+            if k > shape.dims[dim] {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "The number of elements to keep can't exceed the size of the dimension.",
+                    )
+                    .details(format!(
+                        "Given k: '{k}', size of dimension {dim}: '{}'.",
+                        shape.dims[dim]
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn einsum<B: Backend, const D: usize, const D2: usize>(
+        equation: &str,
+        tensors: &[Tensor<B, D>],
+    ) -> Self {
+        let ops = "Einsum";
+        let check = Self::Ok;
+
+        if tensors.is_empty() {
+            return check.register(
+                ops,
+                TensorError::new("Can't compute the einsum of an empty list of tensors."),
+            );
+        }
+
+        let equation = match Equation::parse(equation) {
+            Ok(equation) => equation,
+            Err(details) => {
+                return check.register(ops, TensorError::new("Invalid equation.").details(details))
+            }
+        };
+
+        if equation.inputs.len() != tensors.len() {
+            return check.register(
+                ops,
+                TensorError::new("The number of operands doesn't match the equation.").details(
+                    format!(
+                        "The equation has {} operands, but {} tensors were provided.",
+                        equation.inputs.len(),
+                        tensors.len()
+                    ),
+                ),
+            );
+        }
+
+        if D > MAX_RANK || D2 > MAX_RANK || equation.labels().len() > MAX_RANK {
+            return check.register(
+                ops,
+                TensorError::new(format!(
+                    "Einsum is only supported up to {MAX_RANK} distinct labels and dimensions."
+                )),
+            );
+        }
+
+        let num_dims_output = equation.output.len().max(1);
+        if num_dims_output != D2 {
+            return check.register(
+                ops,
+                TensorError::new("The number of output labels doesn't match the output rank.")
+                    .details(format!(
+                        "The equation has {} output labels, but the output rank is {D2}.",
+                        equation.output.len()
+                    )),
+            );
+        }
+
+        let mut sizes: Vec<(char, usize)> = Vec::new();
+
+        for (i, (labels, tensor)) in equation.inputs.iter().zip(tensors).enumerate() {
+            if labels.len() != D {
+                return check.register(
+                    ops,
+                    TensorError::new("The number of labels doesn't match the operand rank.")
+                        .details(format!(
+                            "Operand {i} has {} labels, but its rank is {D}.",
+                            labels.len()
+                        )),
+                );
+            }
+
+            let dims = tensor.dims();
+
+            for (label, size) in labels.iter().zip(dims) {
+                match sizes.iter().find(|(other, _)| other == label) {
+                    Some((_, other_size)) if *other_size != size => {
+                        return check.register(
+                            ops,
+                            TensorError::new(
+                                "Dimensions sharing the same label must have the same size.",
+                            )
+                            .details(format!(
+                                "Label '{label}' has size {other_size} and {size} (operand {i})."
+                            )),
+                        );
+                    }
+                    Some(_) => {}
+                    None => sizes.push((*label, size)),
+                }
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn unfold<const D: usize, const D2: usize>(
+        dim: usize,
+        size: usize,
+        step: usize,
+        shape: &Shape<D>,
+    ) -> Self {
+        let ops = "Unfold";
+        let mut check = Self::dim_ops::<D>(ops, dim);
+
+        if D2 != D + 1 {
+            check = check.register(
+                ops,
+                TensorError::new("The output rank must be one more than the input rank.")
+                    .details(format!("Input rank: '{D}', output rank: '{D2}'.")),
+            );
+        }
+
+        if size == 0 || step == 0 {
+            check = check.register(
+                ops,
+                TensorError::new("The size and the step of the windows must be greater than 0.")
+                    .details(format!("Size: '{size}', step: '{step}'.")),
+            );
+        }
+
+        if dim < D && size > shape.dims[dim] {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The size of the windows can't exceed the size of the unfolded dimension.",
+                )
+                .details(format!(
+                    "Window size: '{size}', dimension {dim} has size '{}'.",
+                    shape.dims[dim]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn fold<const D: usize, const D2: usize>(
+        dim: usize,
+        length: usize,
+        step: usize,
+        shape: &Shape<D>,
+    ) -> Self {
+        let ops = "Fold";
+        let mut check = Self::Ok;
+
+        if D != D2 + 1 {
+            check = check.register(
+                ops,
+                TensorError::new("The output rank must be one less than the input rank.")
+                    .details(format!("Input rank: '{D}', output rank: '{D2}'.")),
+            );
+        }
+
+        if dim + 1 >= D {
+            return check.register(
+                ops,
+                TensorError::new(
+                    "The dimension of the windows must be lower than the last dimension.",
+                )
+                .details(format!("Tensor rank: '{D}', given dimension: '{dim}'.")),
+            );
+        }
+
+        if step == 0 {
+            check = check.register(
+                ops,
+                TensorError::new("The step of the windows must be greater than 0."),
+            );
+        }
+
+        let num_windows = shape.dims[dim];
+        let size = shape.dims[D - 1];
+
+        if num_windows > 0 && (num_windows - 1) * step + size > length {
+            check = check.register(
+                ops,
+                TensorError::new("The windows don't fit in the given length.").details(format!(
+                    "{num_windows} windows of size {size} with a step of {step} need a length of \
+                     at least {}, but the given length is {length}.",
+                    (num_windows - 1) * step + size
+                )),
+            );
+        }
+
+        check
+    }
+
     /// The goal is to minimize the cost of checks when there are no error, but it's way less
     /// important when an error occurred, crafting a comprehensive error message is more important
     /// than optimizing string manipulation.
@@ -0,0 +1,135 @@
+use crate::{backend::Backend, BasicOps, Data, Element, ElementConversion, TensorKind};
+
+/// Computes the cumulative sum of the elements of the tensor along the given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `dim` - The dimension along which the cumulative sum is computed.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back on the host to be accumulated, so the operation is synchronous.
+pub fn cumsum<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D>
+where
+    K::Elem: Element,
+{
+    cumulative::<B, D, K>(tensor, dim, 0.0, |acc, value| acc + value)
+}
+
+/// Computes the cumulative product of the elements of the tensor along the given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `dim` - The dimension along which the cumulative product is computed.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back on the host to be accumulated, so the operation is synchronous.
+pub fn cumprod<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D>
+where
+    K::Elem: Element,
+{
+    cumulative::<B, D, K>(tensor, dim, 1.0, |acc, value| acc * value)
+}
+
+/// Computes the cumulative log-sum-exp of the elements of the tensor along the given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `dim` - The dimension along which the cumulative log-sum-exp is computed.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back on the host to be accumulated, so the operation is synchronous.
+pub fn logcumsumexp<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D>
+where
+    K::Elem: Element,
+{
+    cumulative::<B, D, K>(tensor, dim, f64::NEG_INFINITY, log_add_exp)
+}
+
+/// Computes `log(exp(a) + exp(b))` relative to the largest value, so that it doesn't overflow.
+fn log_add_exp(a: f64, b: f64) -> f64 {
+    let max = libm::fmax(a, b);
+
+    if max == f64::NEG_INFINITY {
+        return max;
+    }
+
+    max + libm::log1p(libm::exp(-libm::fabs(a - b)))
+}
+
+fn cumulative<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+    initial: f64,
+    ops: fn(f64, f64) -> f64,
+) -> K::Primitive<D>
+where
+    K::Elem: Element,
+{
+    let device = K::device(&tensor);
+    let mut data = K::into_data(tensor)
+        .read_sync()
+        .expect("Can't accumulate a tensor with async data.");
+
+    let dim_size = data.shape.dims[dim];
+    let stride: usize = data.shape.dims[dim + 1..].iter().product();
+    let num_elements = data.shape.num_elements();
+
+    if num_elements > 0 {
+        for outer in 0..num_elements / (dim_size * stride) {
+            for inner in 0..stride {
+                let offset = outer * dim_size * stride + inner;
+                let mut accumulator = initial;
+
+                for i in 0..dim_size {
+                    let index = offset + i * stride;
+                    accumulator = ops(accumulator, data.value[index].elem());
+                    data.value[index] = accumulator.elem();
+                }
+            }
+        }
+    }
+
+    K::from_data(Data::new(data.value, data.shape), &device)
+}
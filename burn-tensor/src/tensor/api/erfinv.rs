@@ -0,0 +1,95 @@
+use crate::{backend::Backend, Tensor};
+
+/// Computes the inverse error function of the elements of the tensor.
+///
+/// The inverse is approximated with the polynomials of
+/// [Approximating the erfinv function](https://people.maths.ox.ac.uk/gilesm/files/gems_erfinv.pdf),
+/// then refined with a step of Halley's method.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, the elements equal to -1 and 1 being mapped
+/// to negative and positive infinity, and the elements outside of [-1, 1] to NaN.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn erfinv<B: Backend, const D: usize>(
+    tensor: B::FloatTensorPrimitive<D>,
+) -> B::FloatTensorPrimitive<D> {
+    let x = Tensor::<B, D>::new(tensor);
+
+    let w = x
+        .clone()
+        .neg()
+        .add_scalar(1.0)
+        .mul(x.clone().add_scalar(1.0))
+        .log()
+        .neg();
+
+    let central = polynomial(
+        w.clone().sub_scalar(2.5),
+        &[
+            2.810_226_36e-08,
+            3.432_739_39e-07,
+            -3.523_387_7e-06,
+            -4.391_506_54e-06,
+            2.185_808_7e-04,
+            -1.253_725_03e-03,
+            -4.177_681_64e-03,
+            2.466_407_27e-01,
+            1.501_409_41,
+        ],
+    );
+    let tail = polynomial(
+        w.clone().sqrt().sub_scalar(3.0),
+        &[
+            -2.002_142_57e-04,
+            1.009_505_58e-04,
+            1.349_343_22e-03,
+            -3.673_428_44e-03,
+            5.739_507_73e-03,
+            -7.622_461_3e-03,
+            9.438_870_47e-03,
+            1.001_674_06,
+            2.832_976_82,
+        ],
+    );
+    let y = central
+        .mask_where(w.greater_equal_elem(5.0), tail)
+        .mul(x.clone());
+
+    // One step of Halley's method, f(y) = erf(y) - x having the derivatives
+    // f'(y) = 2 / sqrt(pi) * exp(-y^2) and f''(y) = -2y f'(y).
+    let error = y.clone().erf().sub(x.clone());
+    let derivative = y
+        .clone()
+        .powf_scalar(2.0)
+        .neg()
+        .exp()
+        .mul_scalar(core::f64::consts::FRAC_2_SQRT_PI);
+    let y = y
+        .clone()
+        .sub(error.clone().div(derivative.add(y.mul(error))));
+
+    // The bounds are mapped to the infinities, which the refinement can't handle.
+    let bounds = x.clone().abs().equal_elem(1.0);
+    y.mask_where(bounds, x.mul_scalar(f64::INFINITY))
+        .into_primitive()
+}
+
+/// Evaluates the polynomial with the given coefficients, from the highest degree to the lowest.
+fn polynomial<B: Backend, const D: usize>(w: Tensor<B, D>, coefficients: &[f64]) -> Tensor<B, D> {
+    coefficients[1..]
+        .iter()
+        .fold(w.zeros_like().add_scalar(coefficients[0]), |p, c| {
+            p.mul(w.clone()).add_scalar(*c)
+        })
+}
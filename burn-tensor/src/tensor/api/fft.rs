@@ -0,0 +1,81 @@
+use crate::tensor::signal::{angle, basis};
+use crate::{backend::Backend, Tensor};
+
+/// Computes the discrete Fourier transform of a complex tensor along the given dimension.
+///
+/// # Arguments
+///
+/// * `real` - The real part of the tensor.
+/// * `imag` - The imaginary part of the tensor.
+/// * `dim` - The dimension along which the transform is computed.
+/// * `inverse` - Whether the inverse transform is computed, which is normalized by the size of
+///   the dimension.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the transform.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The transform is computed with matrix multiplications by the `N x N` Fourier matrix, which
+/// requires `O(N^2)` operations per transform instead of the `O(N log N)` of a fast Fourier
+/// transform.
+pub fn fft<B: Backend, const D: usize>(
+    real: B::FloatTensorPrimitive<D>,
+    imag: B::FloatTensorPrimitive<D>,
+    dim: usize,
+    inverse: bool,
+) -> (B::FloatTensorPrimitive<D>, B::FloatTensorPrimitive<D>) {
+    let real = Tensor::<B, D>::new(real).swap_dims(dim, D - 1);
+    let imag = Tensor::<B, D>::new(imag).swap_dims(dim, D - 1);
+    let shape = real.shape();
+    let size = shape.dims[D - 1];
+
+    if size == 0 {
+        return (
+            real.swap_dims(dim, D - 1).into_primitive(),
+            imag.swap_dims(dim, D - 1).into_primitive(),
+        );
+    }
+
+    let num_lanes = shape.num_elements() / size;
+    let real = real.reshape([num_lanes, size]);
+    let imag = imag.reshape([num_lanes, size]);
+
+    // The Fourier matrix is symmetric, with the elements e^(-2i pi k n / N), or e^(2i pi k n / N)
+    // for the inverse transform.
+    let (cos, sin) = basis::<B, _>(size, size, &real.device(), |k, n| angle(k * n, size));
+    let sin = match inverse {
+        true => sin,
+        false => sin.neg(),
+    };
+
+    let real_cos = real.clone().matmul(cos.clone());
+    let real_sin = real.matmul(sin.clone());
+    let imag_cos = imag.clone().matmul(cos);
+    let imag_sin = imag.matmul(sin);
+
+    let mut output_real = real_cos - imag_sin;
+    let mut output_imag = imag_cos + real_sin;
+
+    if inverse {
+        output_real = output_real.div_scalar(size as f32);
+        output_imag = output_imag.div_scalar(size as f32);
+    }
+
+    (
+        output_real
+            .reshape(shape.clone())
+            .swap_dims(dim, D - 1)
+            .into_primitive(),
+        output_imag
+            .reshape(shape)
+            .swap_dims(dim, D - 1)
+            .into_primitive(),
+    )
+}
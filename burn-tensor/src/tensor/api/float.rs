@@ -1,13 +1,21 @@
+use alloc::vec;
 use alloc::vec::Vec;
 use core::convert::TryInto;
 
+use crate::activation;
 use crate::check;
 use crate::check::TensorCheck;
 use crate::tensor::backend::Backend;
+use crate::tensor::einsum;
 use crate::tensor::stats;
 use crate::tensor::{Data, Distribution, Shape};
-use crate::Int;
+use crate::ElementConversion;
+use crate::ElementLimits;
 use crate::Tensor;
+use crate::{Bool, Int};
+
+/// The lower bound of the norms of the rows compared by the cosine similarity.
+const COSINE_SIMILARITY_EPS: f64 = 1e-8;
 
 impl<const D: usize, B> Tensor<B, D>
 where
@@ -52,6 +60,26 @@ where
         Self::new(B::float_log1p(self.primitive))
     }
 
+    /// Applies the logit function element wise, the inverse of the sigmoid.
+    ///
+    /// `y = log(x / (1 - x))`
+    ///
+    /// The function is computed as `log(x) - log(1 - x)` with [log1p](Tensor::log1p), which keeps
+    /// its precision near 0 and 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps` - When set, the elements are clamped to `[eps, 1 - eps]` first, so that the output
+    ///   stays finite.
+    pub fn logit(self, eps: Option<f64>) -> Self {
+        let tensor = match eps {
+            Some(eps) => self.clamp(eps, 1.0 - eps),
+            None => self,
+        };
+
+        tensor.clone().log().sub(tensor.neg().log1p())
+    }
+
     /// Applies the [error function](https://en.wikipedia.org/wiki/Error_function) element wise.
     ///
     /// `y = erf(x)`
@@ -59,6 +87,17 @@ where
         Self::new(B::float_erf(self.primitive))
     }
 
+    /// Applies the inverse of the [error function](https://en.wikipedia.org/wiki/Error_function)
+    /// element wise.
+    ///
+    /// `y = erfinv(x)`
+    ///
+    /// The elements equal to -1 and 1 are mapped to negative and positive infinity, and the
+    /// elements outside of [-1, 1] to NaN.
+    pub fn erfinv(self) -> Self {
+        Self::new(B::float_erfinv(self.primitive))
+    }
+
     /// Applies element wise reciprocal operation.
     pub fn recip(self) -> Self {
         Self::new(B::float_recip(self.primitive))
@@ -84,6 +123,20 @@ where
         Self::new(B::float_tanh(self.primitive))
     }
 
+    /// Applies the softmax function along the given dimension.
+    ///
+    /// See [softmax](crate::activation::softmax).
+    pub fn softmax(self, dim: usize) -> Self {
+        activation::softmax(self, dim)
+    }
+
+    /// Applies the log softmax function along the given dimension.
+    ///
+    /// See [log_softmax](crate::activation::log_softmax).
+    pub fn log_softmax(self, dim: usize) -> Self {
+        activation::log_softmax(self, dim)
+    }
+
     /// Create a tensor from floats (f32) on a given device.
     ///
     /// # Example
@@ -178,97 +231,1531 @@ where
         Self::new(B::float_matmul(self.primitive, other.primitive))
     }
 
-    /// Calculate the variance along the given dimension.
-    pub fn var(self, dim: usize) -> Self {
-        stats::var(self, dim)
-    }
+    /// Computes the Kronecker product of the two tensors.
+    ///
+    /// The output is made of one block per element of the tensor, equal to the element times the
+    /// other tensor, so that each of its dimensions is the product of the two tensors' dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let lhs = Tensor::<B, 2>::from_floats([[1.0, 2.0]], &device);
+    ///     let rhs = Tensor::<B, 2>::from_floats([[1.0], [10.0]], &device);
+    ///     let tensor = lhs.kron(rhs);
+    ///     // [[1.0, 2.0], [10.0, 20.0]]
+    /// }
+    /// ```
+    pub fn kron(self, other: Self) -> Self {
+        let dims_lhs = self.dims();
+        let dims_rhs = other.dims();
+        let mut lhs = self;
+        let mut rhs = other;
 
-    /// Calculate the variance along the given dimension without applying the Bessel’s correction.
-    pub fn var_bias(self, dim: usize) -> Self {
-        stats::var_bias(self, dim)
+        // Each element of the left tensor is repeated and the right tensor is tiled, one
+        // dimension after the other, so that both have the shape of the output.
+        for dim in 0..D {
+            lhs = lhs.kron_expand(dim, dims_rhs[dim], false);
+            rhs = rhs.kron_expand(dim, dims_lhs[dim], true);
+        }
+
+        lhs.mul(rhs)
     }
 
-    /// Calculate the variance along the given dimension and also returns the mean.
-    pub fn var_mean(self, dim: usize) -> (Self, Self) {
-        let mean = self.clone().mean_dim(dim);
-        let var = stats::var_with_mean(self, mean.clone(), dim);
-        (var, mean)
+    /// Contracts the tensor with another tensor along the given pairs of dimensions, summing the
+    /// products of their elements.
+    ///
+    /// This generalizes the matrix multiplication, which contracts the last dimension of the
+    /// first matrix with the first dimension of the second one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor to contract with.
+    /// * `dims` - The dimensions of the tensor and the dimensions of the other tensor that are
+    ///   contracted together, in the same order.
+    ///
+    /// # Returns
+    ///
+    /// The output has the dimensions of the tensor that aren't contracted followed by the ones of
+    /// the other tensor. When all the dimensions are contracted, it has a single element.
+    ///
+    /// # Panics
+    ///
+    /// - If the contracted dimensions are invalid or don't have the same sizes.
+    /// - If `D3` isn't the number of dimensions of the output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let lhs = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+    ///     let rhs = Tensor::<B, 2>::from_floats([[5.0, 6.0], [7.0, 8.0]], &device);
+    ///     let product = lhs.clone().tensordot::<2, 2, 1>(rhs.clone(), [[1], [0]]);
+    ///     // [[19.0, 22.0], [43.0, 50.0]]
+    ///     let inner = lhs.tensordot::<2, 1, 2>(rhs, [[0, 1], [0, 1]]);
+    ///     // [70.0]
+    /// }
+    /// ```
+    pub fn tensordot<const D2: usize, const D3: usize, const N: usize>(
+        self,
+        other: Tensor<B, D2>,
+        dims: [[usize; N]; 2],
+    ) -> Tensor<B, D3> {
+        let [dims_lhs, dims_rhs] = dims;
+        check!(TensorCheck::tensordot::<D, D2, D3>(
+            &self.shape(),
+            &other.shape(),
+            &dims_lhs,
+            &dims_rhs
+        ));
+
+        let shape_lhs = self.dims();
+        let shape_rhs = other.dims();
+        let free_lhs: Vec<usize> = (0..D).filter(|dim| !dims_lhs.contains(dim)).collect();
+        let free_rhs: Vec<usize> = (0..D2).filter(|dim| !dims_rhs.contains(dim)).collect();
+        let size = |shape: &[usize], dims: &[usize]| -> usize {
+            dims.iter().map(|dim| shape[*dim]).product()
+        };
+
+        // Both tensors are turned into matrices, with the contracted dimensions last for the
+        // tensor and first for the other tensor.
+        let lhs = self
+            .permute_with_swaps(&[free_lhs.as_slice(), &dims_lhs].concat())
+            .reshape([size(&shape_lhs, &free_lhs), size(&shape_lhs, &dims_lhs)]);
+        let rhs = other
+            .permute_with_swaps(&[&dims_rhs, free_rhs.as_slice()].concat())
+            .reshape([size(&shape_rhs, &dims_rhs), size(&shape_rhs, &free_rhs)]);
+
+        let mut shape = [1; D3];
+        let sizes = free_lhs
+            .iter()
+            .map(|dim| shape_lhs[*dim])
+            .chain(free_rhs.iter().map(|dim| shape_rhs[*dim]));
+        for (i, size) in sizes.enumerate() {
+            shape[i] = size;
+        }
+
+        lhs.matmul(rhs).reshape(shape)
     }
 
-    /// Calculate the variance along the given dimension without applying the Bessel’s correction and also returns the mean.
-    pub fn var_mean_bias(self, dim: usize) -> (Self, Self) {
-        let mean = self.clone().mean_dim(dim);
-        let var = stats::var_with_mean_bias(self, mean.clone(), dim);
-        (var, mean)
+    /// Computes the singular value decomposition of the matrices of the tensor, over its last two
+    /// dimensions.
+    ///
+    /// `A = U diag(S) Vh`
+    ///
+    /// # Arguments
+    ///
+    /// * `full_matrices` - If `U` and `Vh` are square matrices. Otherwise, for a matrix of shape
+    ///   `[m, n]`, `U` only has `k = min(m, n)` columns and `Vh` only has `k` rows, which is faster
+    ///   to compute.
+    ///
+    /// # Returns
+    ///
+    /// The left singular vectors `U` of shape `[..., m, k]` or `[..., m, m]`, the singular values
+    /// `S` in descending order of shape `[..., k]`, and the transposed right singular vectors `Vh`
+    /// of shape `[..., k, n]` or `[..., n, n]`.
+    ///
+    /// # Notes
+    ///
+    /// The singular vectors are only unique up to their sign. The gradient is undefined when
+    /// singular vectors are used and some singular values are repeated, and the gradient of the
+    /// extra singular vectors of the full matrices is ignored.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than two dimensions, or if `D2` isn't one less than `D`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[3.0, 0.0], [0.0, -4.0]], &device);
+    ///     let (u, s, vh) = tensor.svd::<1>(false);
+    ///     // s: [4.0, 3.0]
+    ///     let tensor = u.mul(s.unsqueeze()).matmul(vh);
+    ///     // [[3.0, 0.0], [0.0, -4.0]]
+    /// }
+    /// ```
+    pub fn svd<const D2: usize>(self, full_matrices: bool) -> (Self, Tensor<B, D2>, Self) {
+        check!(TensorCheck::matrix_decomposition::<D, D2>("Svd"));
+
+        let (u, s, vh) = B::float_svd(self.primitive, full_matrices);
+
+        (Self::new(u), Self::drop_rows(s), Self::new(vh))
     }
 
-    /// Create a random tensor of the given shape on the given device where each element is
-    /// sampled from the given distribution.
-    pub fn random<S: Into<Shape<D>>>(
-        shape: S,
-        distribution: Distribution,
-        device: &B::Device,
-    ) -> Self {
-        let tensor = B::float_random(shape.into(), distribution, device);
-        Self::new(tensor)
+    /// Computes the eigendecomposition of the symmetric matrices of the tensor, over its last two
+    /// dimensions.
+    ///
+    /// `A = V diag(L) V^T`
+    ///
+    /// Only the lower triangle of the matrices is used, the upper triangle being assumed to be
+    /// its transpose.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvectors `V` as the columns of a tensor of shape `[..., n, n]`, and the eigenvalues
+    /// `L` in ascending order of shape `[..., n]`.
+    ///
+    /// # Notes
+    ///
+    /// The eigenvectors are only unique up to their sign, and up to a rotation for repeated
+    /// eigenvalues. The gradient through the eigenvectors divides by the differences between the
+    /// eigenvalues, following the Daleckiĭ-Kreĭn formula, so it is inaccurate when eigenvalues
+    /// are close and undefined when they are repeated, in which case the corresponding terms are
+    /// dropped. The gradient through the eigenvalues alone is always defined.
+    ///
+    /// # Panics
+    ///
+    /// If the matrices aren't square, or if `D2` isn't one less than `D`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[2.0, 1.0], [1.0, 2.0]], &device);
+    ///     let (vectors, values) = tensor.eigh::<1>();
+    ///     // values: [1.0, 3.0]
+    ///     let tensor = vectors.clone().mul(values.unsqueeze()).matmul(vectors.transpose());
+    ///     // [[2.0, 1.0], [1.0, 2.0]]
+    /// }
+    /// ```
+    pub fn eigh<const D2: usize>(self) -> (Self, Tensor<B, D2>) {
+        check!(TensorCheck::matrix_decomposition::<D, D2>("Eigh"));
+        check!(TensorCheck::square_matrix::<D>("Eigh", &self.shape()));
+
+        let (vectors, values) = B::float_eigh(self.primitive);
+
+        (Self::new(vectors), Self::drop_rows(values))
     }
-    /// Returns a tensor with full precision based on the selected backend.
-    pub fn to_full_precision(&self) -> Tensor<B::FullPrecisionBackend, D> {
-        Tensor::new(B::float_to_full_precision(&self.primitive))
+
+    /// Solves the square linear systems `A X = B` over the last two dimensions, with the tensor as
+    /// the coefficients `A` of shape `[..., n, n]` and the right-hand sides `B` of shape
+    /// `[..., n, k]`.
+    ///
+    /// The gradient follows from the implicit function theorem, with `grad_B = solve(A^T, grad_X)`
+    /// and `grad_A = -grad_B X^T`.
+    ///
+    /// # Panics
+    ///
+    /// If the coefficients aren't square, if the shapes don't match, or if a matrix is singular.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let a = Tensor::<B, 2>::from_floats([[3.0, 1.0], [1.0, 2.0]], &device);
+    ///     let b = Tensor::<B, 2>::from_floats([[9.0], [8.0]], &device);
+    ///     let x = a.solve(b);
+    ///     // [[2.0], [3.0]]
+    /// }
+    /// ```
+    pub fn solve(self, b: Self) -> Self {
+        check!(TensorCheck::linear_system::<D>(
+            "Solve",
+            &self.shape(),
+            &b.shape(),
+            true
+        ));
+
+        Self::new(B::float_solve(self.primitive, b.primitive))
     }
 
-    /// Returns a tensor on the selected backend from a full precision tensor.
-    pub fn from_full_precision(tensor: Tensor<B::FullPrecisionBackend, D>) -> Self {
-        Self::new(B::float_from_full_precision(tensor.primitive))
+    /// Computes the least-squares solutions of the linear systems `A X = B` over the last two
+    /// dimensions, with the tensor as the coefficients `A` of shape `[..., m, n]` and the
+    /// right-hand sides `B` of shape `[..., m, k]`.
+    ///
+    /// The solutions `X`, of shape `[..., n, k]`, minimize `||A X - B||`, and have the minimal
+    /// norm when the systems are underdetermined or rank deficient.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes don't match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     // Fits a line through (0, 1), (1, 3) and (2, 5).
+    ///     let a = Tensor::<B, 2>::from_floats([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]], &device);
+    ///     let b = Tensor::<B, 2>::from_floats([[1.0], [3.0], [5.0]], &device);
+    ///     let x = a.lstsq(b);
+    ///     // [[1.0], [2.0]]
+    /// }
+    /// ```
+    pub fn lstsq(self, b: Self) -> Self {
+        check!(TensorCheck::linear_system::<D>(
+            "Lstsq",
+            &self.shape(),
+            &b.shape(),
+            false
+        ));
+
+        Self::new(B::float_lstsq(self.primitive, b.primitive))
     }
 
-    /// Detach the current tensor from the autodiff graph.
+    /// Computes the Cholesky decomposition of the symmetric positive-definite matrices of the
+    /// tensor, over its last two dimensions.
     ///
-    /// This function does nothing when autodiff is not enabled.
-    /// This can be used in batchers or elsewhere to ensure that previous operations are not
-    /// considered in the autodiff graph.
-    pub fn detach(self) -> Self {
-        Self::new(B::float_detach(self.primitive))
+    /// `A = L L^T`
+    ///
+    /// Only the lower triangle of the matrices is used, the upper triangle being assumed to be
+    /// its transpose, and the gradient is symmetric accordingly.
+    ///
+    /// # Returns
+    ///
+    /// The lower triangular factors `L`, of shape `[..., n, n]`.
+    ///
+    /// # Panics
+    ///
+    /// If the matrices aren't square, or aren't positive-definite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[4.0, 2.0], [2.0, 5.0]], &device);
+    ///     let factor = tensor.cholesky();
+    ///     // [[2.0, 0.0], [1.0, 2.0]]
+    /// }
+    /// ```
+    pub fn cholesky(self) -> Self {
+        check!(TensorCheck::square_matrix::<D>("Cholesky", &self.shape()));
+
+        Self::new(B::float_cholesky(self.primitive))
     }
 
-    /// Mark the tensor to keep gradients during the backward pass.
+    /// Solves the linear systems `A X = B` over the last two dimensions, with the tensor as the
+    /// Cholesky factors of the symmetric positive-definite matrices `A`, of shape `[..., n, n]`,
+    /// and the right-hand sides `B` of shape `[..., n, k]`.
     ///
-    /// This function does nothing when autodiff is not enabled.
-    pub fn require_grad(self) -> Self {
-        self.set_require_grad(true)
+    /// The factors are lower triangular with `A = L L^T`, as returned by
+    /// [cholesky](Tensor::cholesky), unless `upper` is true in which case `A = U^T U`.
+    ///
+    /// # Panics
+    ///
+    /// If the factors aren't square, or if the shapes don't match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[4.0, 2.0], [2.0, 5.0]], &device);
+    ///     let b = Tensor::<B, 2>::from_floats([[8.0], [12.0]], &device);
+    ///     let x = tensor.cholesky().cholesky_solve(b, false);
+    ///     // [[1.0], [2.0]]
+    /// }
+    /// ```
+    pub fn cholesky_solve(self, b: Self, upper: bool) -> Self {
+        check!(TensorCheck::linear_system::<D>(
+            "CholeskySolve",
+            &self.shape(),
+            &b.shape(),
+            true
+        ));
+
+        Self::new(B::float_cholesky_solve(self.primitive, b.primitive, upper))
     }
 
-    /// Returns true if the tensor requires gradients during the backward pass.
-    pub fn is_require_grad(&self) -> bool {
-        B::float_is_require_grad(&self.primitive)
+    /// Removes the dimension of the rows of a tensor of shape `[..., 1, n]`.
+    fn drop_rows<const D2: usize>(tensor: B::FloatTensorPrimitive<D>) -> Tensor<B, D2> {
+        let tensor = Self::new(tensor);
+        let dims = tensor.dims();
+        let mut shape = [0; D2];
+        shape[..D2 - 1].copy_from_slice(&dims[..D - 2]);
+        shape[D2 - 1] = dims[D - 1];
+
+        tensor.reshape(shape)
     }
 
-    /// Mark the tensor as tracked or untracked depending on the require grad argument.
-    /// When tracked, the gradients will be available after the backward pass.
-    ///
-    /// This function does nothing when autodiff is not enabled.
-    pub fn set_require_grad(self, require_grad: bool) -> Self {
-        Self::new(B::float_set_require_grad(self.primitive, require_grad))
+    /// Reorders the dimensions so that the dimension `i` of the output is the dimension
+    /// `order[i]` of the tensor.
+    fn permute_with_swaps(self, order: &[usize]) -> Self {
+        let mut tensor = self;
+        let mut current: Vec<usize> = (0..D).collect();
+
+        for (i, dim) in order.iter().enumerate() {
+            let j = current.iter().position(|current| current == dim).unwrap();
+            if i != j {
+                tensor = tensor.swap_dims(i, j);
+                current.swap(i, j);
+            }
+        }
+
+        tensor
     }
 
-    /// Applies the relu function to the tensor.
-    pub(crate) fn relu(self) -> Self {
-        Self::new(B::relu(self.primitive))
+    /// Repeats each element of the given dimension `times` times when `tile` is false, or the
+    /// whole dimension `times` times otherwise.
+    fn kron_expand(self, dim: usize, times: usize, tile: bool) -> Self {
+        let mut dims = self.dims();
+        let prefix = dims[..dim].iter().product();
+        let size = dims[dim];
+        let suffix = dims[dim + 1..].iter().product();
+
+        let tensor = match tile {
+            true => self
+                .reshape([prefix, 1, size, suffix])
+                .expand([prefix, times, size, suffix]),
+            false => self
+                .reshape([prefix, size, 1, suffix])
+                .expand([prefix, size, times, suffix]),
+        };
+        dims[dim] = size * times;
+
+        tensor.reshape(dims)
     }
 
-    /// Calculate covaraince matrix between different entries alongside a given dimension.
+    /// Returns the `k` largest elements of the tensor along the given dimension, sorted in
+    /// descending order, along with their indices in the original tensor.
+    ///
+    /// # Panics
+    ///
+    /// - If the dimension is greater than the number of dimensions of the tensor.
+    /// - If `k` is greater than the size of the given dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 5.0, 3.0], [4.0, 2.0, 6.0]], &device);
+    ///     let (values, indices) = tensor.top_k(2, 1);
+    ///     println!("{}", values);
+    ///     // [[5.0, 3.0], [6.0, 4.0]]
+    ///     println!("{}", indices);
+    ///     // [[1, 2], [2, 0]]
+    /// }
+    /// ```
+    pub fn top_k(self, k: usize, dim: usize) -> (Tensor<B, D>, Tensor<B, D, Int>) {
+        check!(TensorCheck::top_k::<D>(k, dim, &self.shape()));
+
+        let (values, indices) = B::float_topk(self.primitive, k, dim);
+
+        (Tensor::new(values), Tensor::new(indices))
+    }
+
+    /// Sort the elements of the tensor along the given dimension.
     ///
     /// # Arguments
     ///
-    /// * `size` - The size of the square matrix.
-    /// * `correction_factor` - Is usually 1 for samples and 0 for population.
-    pub fn cov(self, dim: usize, correction_factor: usize) -> Tensor<B, D> {
-        let n = self.dims()[dim];
-        let centered = (self.clone() - self.mean_dim(dim)).swap_dims(dim, 0);
-        centered
-            .clone()
-            .transpose()
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - Whether the elements should be sorted in descending order.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is greater than the number of dimensions of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 5.0, 3.0], [4.0, 2.0, 6.0]], &device);
+    ///     let sorted = tensor.sort(1, false);
+    ///     println!("{}", sorted);
+    ///     // [[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]
+    /// }
+    /// ```
+    pub fn sort(self, dim: usize, descending: bool) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+
+        Tensor::new(B::float_sort(self.primitive, dim, descending))
+    }
+
+    /// Sort the elements of the tensor along the given dimension and also returns the indices
+    /// of the sorted elements in the original tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is greater than the number of dimensions of the tensor.
+    pub fn sort_with_indices(self, dim: usize, descending: bool) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::dim_ops::<D>("Sort with indices", dim));
+
+        let (values, indices) = B::float_sort_with_indices(self.primitive, dim, descending);
+
+        (Tensor::new(values), Tensor::new(indices))
+    }
+
+    /// Returns the indices that would sort the elements of the tensor along the given dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is greater than the number of dimensions of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 5.0, 3.0], [4.0, 2.0, 6.0]], &device);
+    ///     let indices = tensor.argsort(1, true);
+    ///     println!("{}", indices);
+    ///     // [[1, 2, 0], [2, 0, 1]]
+    /// }
+    /// ```
+    pub fn argsort(self, dim: usize, descending: bool) -> Tensor<B, D, Int> {
+        check!(TensorCheck::dim_ops::<D>("Argsort", dim));
+
+        Tensor::new(B::float_argsort(self.primitive, dim, descending))
+    }
+
+    /// Computes the sum of products of the elements of the given tensors following the
+    /// [Einstein summation](https://numpy.org/doc/stable/reference/generated/numpy.einsum.html)
+    /// convention.
+    ///
+    /// Each operand is described by one label per dimension, separated by commas, and the
+    /// labels of the output follow the arrow. Labels missing from the output are summed, and
+    /// repeated labels in the same operand take the diagonal. When the output is omitted, it is
+    /// made of the labels appearing only once, in alphabetical order.
+    ///
+    /// # Arguments
+    ///
+    /// * `equation` - The equation, e.g. `ij,jk->ik` for a matrix multiplication.
+    /// * `tensors` - The operands, all of the same rank.
+    ///
+    /// # Panics
+    ///
+    /// - If the equation is invalid or doesn't match the number of tensors or their ranks.
+    /// - If dimensions sharing the same label have different sizes.
+    /// - If the equation has more than 6 distinct labels, or the tensors more than 6 dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let lhs = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+    ///     let rhs = Tensor::<B, 2>::from_floats([[5.0, 6.0], [7.0, 8.0]], &device);
+    ///
+    ///     let output = Tensor::einsum::<2>("ij,jk->ik", vec![lhs.clone(), rhs]);
+    ///     println!("{}", output);
+    ///     // [[19.0, 22.0], [43.0, 50.0]]
+    ///
+    ///     let trace = Tensor::einsum::<1>("ii->", vec![lhs]);
+    ///     println!("{}", trace);
+    ///     // [5.0]
+    /// }
+    /// ```
+    pub fn einsum<const D2: usize>(equation: &str, tensors: Vec<Self>) -> Tensor<B, D2> {
+        check!(TensorCheck::einsum::<B, D, D2>(equation, &tensors));
+
+        einsum::einsum(equation, tensors)
+    }
+
+    /// Computes the cumulative sum of the elements along the given dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is greater than the number of dimensions of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    ///     let tensor = tensor.cumsum(1);
+    ///     println!("{}", tensor);
+    ///     // [[1.0, 3.0, 6.0], [4.0, 9.0, 15.0]]
+    /// }
+    /// ```
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Cumsum", dim));
+
+        Tensor::new(B::float_cumsum(self.primitive, dim))
+    }
+
+    /// Computes the cumulative product of the elements along the given dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is greater than the number of dimensions of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    ///     let tensor = tensor.cumprod(1);
+    ///     println!("{}", tensor);
+    ///     // [[1.0, 2.0, 6.0], [4.0, 20.0, 120.0]]
+    /// }
+    /// ```
+    pub fn cumprod(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Cumprod", dim));
+
+        Tensor::new(B::float_cumprod(self.primitive, dim))
+    }
+
+    /// Computes the cumulative log-sum-exp of the elements along the given dimension.
+    ///
+    /// `y_i = log(sum_{j <= i} exp(x_j))`
+    ///
+    /// The sum is accumulated relative to the running maximum, so that large elements don't
+    /// overflow.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is greater than the number of dimensions of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([0.0, 0.0, 1000.0], &device);
+    ///     let tensor = tensor.logcumsumexp(0);
+    ///     println!("{}", tensor);
+    ///     // [0.0, 0.6931, 1000.0]
+    /// }
+    /// ```
+    pub fn logcumsumexp(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Logcumsumexp", dim));
+
+        Tensor::new(B::float_logcumsumexp(self.primitive, dim))
+    }
+
+    /// Calculate the variance along the given dimension.
+    pub fn var(self, dim: usize) -> Self {
+        stats::var(self, dim)
+    }
+
+    /// Calculate the variance along the given dimension without applying the Bessel’s correction.
+    pub fn var_bias(self, dim: usize) -> Self {
+        stats::var_bias(self, dim)
+    }
+
+    /// Calculate the variance along the given dimension and also returns the mean.
+    pub fn var_mean(self, dim: usize) -> (Self, Self) {
+        let mean = self.clone().mean_dim(dim);
+        let var = stats::var_with_mean(self, mean.clone(), dim);
+        (var, mean)
+    }
+
+    /// Calculate the variance along the given dimension without applying the Bessel’s correction and also returns the mean.
+    pub fn var_mean_bias(self, dim: usize) -> (Self, Self) {
+        let mean = self.clone().mean_dim(dim);
+        let var = stats::var_with_mean_bias(self, mean.clone(), dim);
+        (var, mean)
+    }
+
+    /// Calculate the sum along the given dimension, ignoring the NaN values.
+    ///
+    /// The sum of values which are all NaN is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, f32::NAN, 3.0], [f32::NAN; 3]], &device);
+    ///     let tensor = tensor.nan_sum(1);
+    ///     // [[4.0], [0.0]]
+    /// }
+    /// ```
+    pub fn nan_sum(self, dim: usize) -> Self {
+        // NaN is the only value which isn't equal to itself.
+        let is_nan = self.clone().equal(self.clone()).bool_not();
+
+        self.mask_fill(is_nan, 0.0).sum_dim(dim)
+    }
+
+    /// Calculate the mean along the given dimension, ignoring the NaN values.
+    ///
+    /// The sum of the values is divided by the number of values which aren't NaN, so that the
+    /// mean of values which are all NaN is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, f32::NAN, 3.0], [f32::NAN; 3]], &device);
+    ///     let tensor = tensor.nan_mean(1);
+    ///     // [[2.0], [NaN]]
+    /// }
+    /// ```
+    pub fn nan_mean(self, dim: usize) -> Self {
+        let is_number = self.clone().equal(self.clone());
+        let count = is_number.clone().float().sum_dim(dim);
+
+        self.mask_fill(is_number.bool_not(), 0.0)
+            .sum_dim(dim)
+            .div(count)
+    }
+
+    /// Create a random tensor of the given shape on the given device where each element is
+    /// sampled from the given distribution.
+    pub fn random<S: Into<Shape<D>>>(
+        shape: S,
+        distribution: Distribution,
+        device: &B::Device,
+    ) -> Self {
+        let tensor = B::float_random(shape.into(), distribution, device);
+        Self::new(tensor)
+    }
+    /// Returns a tensor with full precision based on the selected backend.
+    pub fn to_full_precision(&self) -> Tensor<B::FullPrecisionBackend, D> {
+        Tensor::new(B::float_to_full_precision(&self.primitive))
+    }
+
+    /// Returns a tensor on the selected backend from a full precision tensor.
+    pub fn from_full_precision(tensor: Tensor<B::FullPrecisionBackend, D>) -> Self {
+        Self::new(B::float_from_full_precision(tensor.primitive))
+    }
+
+    /// Detach the current tensor from the autodiff graph.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    /// This can be used in batchers or elsewhere to ensure that previous operations are not
+    /// considered in the autodiff graph.
+    pub fn detach(self) -> Self {
+        Self::new(B::float_detach(self.primitive))
+    }
+
+    /// Mark the tensor to keep gradients during the backward pass.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn require_grad(self) -> Self {
+        self.set_require_grad(true)
+    }
+
+    /// Returns true if the tensor requires gradients during the backward pass.
+    pub fn is_require_grad(&self) -> bool {
+        B::float_is_require_grad(&self.primitive)
+    }
+
+    /// Mark the tensor as tracked or untracked depending on the require grad argument.
+    /// When tracked, the gradients will be available after the backward pass.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn set_require_grad(self, require_grad: bool) -> Self {
+        Self::new(B::float_set_require_grad(self.primitive, require_grad))
+    }
+
+    /// Applies the relu function to the tensor.
+    pub(crate) fn relu(self) -> Self {
+        Self::new(B::relu(self.primitive))
+    }
+
+    /// Calculate covaraince matrix between different entries alongside a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the square matrix.
+    /// * `correction_factor` - Is usually 1 for samples and 0 for population.
+    pub fn cov(self, dim: usize, correction_factor: usize) -> Tensor<B, D> {
+        let n = self.dims()[dim];
+        let centered = (self.clone() - self.mean_dim(dim)).swap_dims(dim, 0);
+        centered
+            .clone()
+            .transpose()
             .matmul(centered)
             .div_scalar(n as f32 - correction_factor as f32)
     }
+
+    /// Computes the histogram of the elements of the tensor.
+    ///
+    /// The elements are counted in `bins` bins of equal width between the bounds of the range,
+    /// the last bin including its upper bound. The elements outside of the range are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `bins` - The number of bins.
+    /// * `range` - The lower and upper bounds of the bins, which default to the minimum and the
+    ///   maximum of the elements. When both bounds are equal, they are widened by one.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements in each bin, of shape `[bins]`, and the edges of the bins, of shape
+    /// `[bins + 1]`.
+    ///
+    /// # Notes
+    ///
+    /// The histogram isn't differentiable. When the range isn't provided, the minimum and the
+    /// maximum are read from the device, which requires a synchronization.
+    pub fn histogram(self, bins: usize, range: Option<(f64, f64)>) -> (Tensor<B, 1>, Tensor<B, 1>) {
+        let tensor = self.detach().reshape([-1]);
+        let device = tensor.device();
+
+        let (min, max) = range.unwrap_or_else(|| {
+            let min = tensor.clone().min().into_scalar().elem::<f64>();
+            let max = tensor.clone().max().into_scalar().elem::<f64>();
+            (min, max)
+        });
+        let (min, max) = match min == max {
+            true => (min - 1.0, max + 1.0),
+            false => (min, max),
+        };
+        check!(TensorCheck::histogram(bins, min, max));
+
+        let width = (max - min) / bins as f64;
+        let edges = Tensor::<B, 1, Int>::arange(0..bins as i64 + 1, &device)
+            .float()
+            .mul_scalar(width)
+            .add_scalar(min);
+
+        let ignored_lower = tensor.clone().lower_elem(min);
+        let ignored_greater = tensor.clone().greater_elem(max);
+        let counts = tensor
+            .ones_like()
+            .mask_fill(ignored_lower, 0)
+            .mask_fill(ignored_greater, 0);
+
+        // The indices of the elements outside of the range don't matter, their count being zero.
+        let indices = tensor
+            .sub_scalar(min)
+            .div_scalar(width)
+            .int()
+            .clamp(0, bins as i64 - 1);
+        let counts = Tensor::zeros([bins], &device).scatter(0, indices, counts);
+
+        (counts, edges)
+    }
+
+    /// Returns the index of the bucket of each element, the buckets being delimited by the
+    /// boundaries.
+    ///
+    /// The index of an element `x` is the number of boundaries lower than `x`, so that
+    /// `boundaries[i - 1] < x <= boundaries[i]`. With `right`, it is the number of boundaries
+    /// lower than or equal to `x`, so that `boundaries[i - 1] <= x < boundaries[i]`. The elements
+    /// below the first boundary are in the bucket 0, and the elements above the last boundary in
+    /// the bucket `num_boundaries`.
+    ///
+    /// # Arguments
+    ///
+    /// * `boundaries` - The boundaries of the buckets, sorted in ascending order.
+    /// * `right` - If the buckets include their right boundary instead of their left one.
+    ///
+    /// # Notes
+    ///
+    /// The bucket of each element is found with a binary search over the boundaries. The
+    /// bucketization isn't differentiable. The boundaries are checked to be sorted in debug builds
+    /// only, which requires reading them from the device.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([-1.0, 1.0, 2.5, 7.0], &device);
+    ///     let boundaries = Tensor::<B, 1>::from_floats([1.0, 3.0, 5.0], &device);
+    ///     let buckets = tensor.bucketize(boundaries, false);
+    ///     // [0, 0, 1, 3]
+    /// }
+    /// ```
+    pub fn bucketize(self, boundaries: Tensor<B, 1>, right: bool) -> Tensor<B, D, Int> {
+        #[cfg(debug_assertions)]
+        check!(TensorCheck::bucketize(
+            &boundaries.to_data().convert::<f64>().value
+        ));
+
+        let shape = self.shape();
+        let device = self.device();
+        let values = self.detach().reshape([-1]);
+        let boundaries = boundaries.detach();
+        let [num_values] = values.dims();
+        let [num_boundaries] = boundaries.dims();
+
+        // The bucket of each element is between the lower and the upper bound, the difference
+        // between both being halved on each iteration.
+        let mut lower = Tensor::<B, 1, Int>::zeros([num_values], &device);
+        let mut upper = Tensor::<B, 1, Int>::full([num_values], num_boundaries as i64, &device);
+        let num_iterations = usize::BITS - num_boundaries.leading_zeros();
+
+        for _ in 0..num_iterations {
+            let middle = lower.clone().add(upper.clone()).div_scalar(2);
+            let boundary = boundaries
+                .clone()
+                .select(0, middle.clone().clamp_max(num_boundaries as i64 - 1));
+            let below = match right {
+                true => boundary.lower_equal(values.clone()),
+                false => boundary.lower(values.clone()),
+            };
+            // The search is over for the elements whose bounds are equal.
+            let go_up = below
+                .int()
+                .mul(middle.clone().lower(upper.clone()).int())
+                .equal_elem(1);
+
+            lower = lower.mask_where(go_up.clone(), middle.clone().add_scalar(1));
+            upper = upper.mask_where(go_up.bool_not(), middle);
+        }
+
+        lower.reshape(shape)
+    }
+
+    /// Computes the one-dimensional discrete Fourier transform of the tensor along the given
+    /// dimension.
+    ///
+    /// `X[k] = sum_n x[n] e^(-2i pi k n / N)`
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The length of the transform, the dimension being truncated or padded with zeros
+    ///   to this length. Defaults to the size of the dimension.
+    /// * `dim` - The dimension, negative values counting from the last dimension.
+    ///
+    /// # Returns
+    ///
+    /// The real and imaginary parts of the transform.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0, 4.0], &device);
+    ///     let (real, imag) = tensor.fft(None, -1);
+    ///     // real: [10.0, -2.0, -2.0, -2.0]
+    ///     // imag: [0.0, 2.0, 0.0, -2.0]
+    /// }
+    /// ```
+    pub fn fft(self, n: Option<usize>, dim: i64) -> (Self, Self) {
+        let imag = self.zeros_like();
+        self.fft_complex(imag, n, dim)
+    }
+
+    /// Computes the one-dimensional discrete Fourier transform of a complex tensor along the
+    /// given dimension, the tensor being its real part.
+    ///
+    /// See [fft](Tensor::fft) for the arguments.
+    pub fn fft_complex(self, imag: Self, n: Option<usize>, dim: i64) -> (Self, Self) {
+        self.fourier_transform(imag, n, dim, false, "Fft")
+    }
+
+    /// Computes the one-dimensional inverse discrete Fourier transform of a complex tensor along
+    /// the given dimension, the tensor being its real part.
+    ///
+    /// `x[n] = 1/N sum_k X[k] e^(2i pi k n / N)`
+    ///
+    /// See [fft](Tensor::fft) for the arguments.
+    pub fn ifft(self, imag: Self, n: Option<usize>, dim: i64) -> (Self, Self) {
+        self.fourier_transform(imag, n, dim, true, "Ifft")
+    }
+
+    /// Computes the N-dimensional discrete Fourier transform of the tensor, with a
+    /// one-dimensional transform along each of the given dimensions.
+    pub fn fftn(self, dims: &[i64]) -> (Self, Self) {
+        let imag = self.zeros_like();
+
+        dims.iter().fold((self, imag), |(real, imag), dim| {
+            real.fft_complex(imag, None, *dim)
+        })
+    }
+
+    /// Computes the N-dimensional inverse discrete Fourier transform of a complex tensor, with a
+    /// one-dimensional inverse transform along each of the given dimensions.
+    pub fn ifftn(self, imag: Self, dims: &[i64]) -> (Self, Self) {
+        dims.iter().fold((self, imag), |(real, imag), dim| {
+            real.ifft(imag, None, *dim)
+        })
+    }
+
+    fn fourier_transform(
+        self,
+        imag: Self,
+        n: Option<usize>,
+        dim: i64,
+        inverse: bool,
+        ops: &str,
+    ) -> (Self, Self) {
+        check!(TensorCheck::fft::<D>(
+            ops,
+            &self.shape(),
+            &imag.shape(),
+            n,
+            dim
+        ));
+
+        let dim = dim.rem_euclid(D as i64) as usize;
+        let real = Self::resize_dim(self, dim, n);
+        let imag = Self::resize_dim(imag, dim, n);
+        let (real, imag) = B::float_fft(real.primitive, imag.primitive, dim, inverse);
+
+        (Self::new(real), Self::new(imag))
+    }
+
+    /// Normalizes the tensor along the given dimension, dividing each vector by its `p`-norm.
+    ///
+    /// `y = x / max(||x||_p, eps)`
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The order of the norm, which can be infinite.
+    /// * `dim` - The dimension along which the vectors are normalized.
+    /// * `eps` - The lower bound of the norm, to avoid a division by zero.
+    ///
+    /// # Notes
+    ///
+    /// Vectors whose norm is lower than `eps` are divided by `eps`, and the gradient doesn't flow
+    /// through their norm. There is no in-place variant: the tensor is taken by value, so the
+    /// backends reuse its memory when it isn't shared.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[3.0, 4.0], [0.0, 0.0]], &device);
+    ///     let normalized = tensor.normalize(2.0, 1, 1e-12);
+    ///     // [[0.6, 0.8], [0.0, 0.0]]
+    /// }
+    /// ```
+    pub fn normalize(self, p: f64, dim: usize, eps: f64) -> Self {
+        check!(TensorCheck::normalize::<D>(p, dim));
+
+        // The absolute value is computed with the signs of the elements, so the gradient of a
+        // zero element is zero instead of undefined.
+        let signs = self.clone().greater_elem(0.0).float() - self.clone().lower_elem(0.0).float();
+        let magnitudes = self.clone().mul(signs);
+
+        let norm = if p == 1.0 {
+            magnitudes.sum_dim(dim)
+        } else if p == f64::INFINITY {
+            magnitudes.max_dim(dim)
+        } else {
+            magnitudes
+                .powf_nonzero(p)
+                .sum_dim(dim)
+                .powf_nonzero(1.0 / p)
+        };
+
+        self.div(norm.clamp_min(eps))
+    }
+
+    /// Returns the next representable value after each element in the direction of the
+    /// corresponding element of `other`.
+    ///
+    /// The value is computed from the IEEE 754 representation of the element: moving away from
+    /// zero increases the magnitude by one unit in the last place, and the smallest subnormal
+    /// value follows zero. The element of `other` is returned when both are equal, and NaN when
+    /// either is NaN.
+    ///
+    /// # Notes
+    ///
+    /// The operation isn't differentiable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([1.0, 1.0], &device);
+    ///     let other = Tensor::<B, 1>::from_floats([2.0, 0.0], &device);
+    ///     let next = tensor.nextafter(other);
+    ///     // [1.0 + f32::EPSILON, 1.0 - f32::EPSILON / 2.0]
+    /// }
+    /// ```
+    pub fn nextafter(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Nextafter", &self, &other));
+
+        // The backends compute the values element-wise, the tensors being broadcast first.
+        let mut lhs = self.detach();
+        let mut rhs = other.detach();
+        for dim in 0..D {
+            let [size_lhs, size_rhs] = [lhs.dims()[dim], rhs.dims()[dim]];
+            if size_lhs < size_rhs {
+                lhs = lhs.repeat(dim, size_rhs);
+            } else if size_rhs < size_lhs {
+                rhs = rhs.repeat(dim, size_lhs);
+            }
+        }
+
+        Self::new(B::float_nextafter(lhs.primitive, rhs.primitive))
+    }
+
+    /// Replaces the NaN, positive infinity and negative infinity elements of the tensor with the
+    /// given values.
+    ///
+    /// # Arguments
+    ///
+    /// * `nan` - The replacement of the NaN elements.
+    /// * `posinf` - The replacement of the positive infinity elements, which defaults to the
+    ///   largest finite value of the element type.
+    /// * `neginf` - The replacement of the negative infinity elements, which defaults to the
+    ///   smallest finite value of the element type.
+    ///
+    /// # Notes
+    ///
+    /// The gradient is zero where the elements are replaced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor =
+    ///         Tensor::<B, 1>::from_floats([f32::NAN, f32::INFINITY, f32::NEG_INFINITY, 1.0], &device);
+    ///     let tensor = tensor.nan_to_num(0.0, Some(1e3), None);
+    ///     // [0.0, 1e3, f32::MIN, 1.0]
+    /// }
+    /// ```
+    pub fn nan_to_num(self, nan: f64, posinf: Option<f64>, neginf: Option<f64>) -> Self {
+        let posinf = posinf.unwrap_or_else(|| B::FloatElem::MAX.elem());
+        let neginf = neginf.unwrap_or_else(|| B::FloatElem::MIN.elem());
+
+        // NaN is the only value which isn't equal to itself.
+        let is_nan = self.clone().equal(self.clone()).bool_not();
+        let is_posinf = self.clone().equal_elem(f64::INFINITY);
+        let is_neginf = self.clone().equal_elem(f64::NEG_INFINITY);
+
+        self.mask_fill(is_nan, nan)
+            .mask_fill(is_posinf, posinf)
+            .mask_fill(is_neginf, neginf)
+    }
+
+    /// Truncates or pads with zeros the tensor to the given size along the dimension.
+    fn resize_dim(self, dim: usize, size: Option<usize>) -> Self {
+        let current = self.dims()[dim];
+
+        match size {
+            Some(size) if size < current => self.narrow(dim, 0, size),
+            Some(size) if size > current => {
+                let mut shape = self.shape();
+                shape.dims[dim] = size - current;
+                let zeros = Self::zeros(shape, &self.device());
+
+                Self::cat(vec![self, zeros], dim)
+            }
+            _ => self,
+        }
+    }
+
+    /// Raises the non-negative elements of the tensor to the given power, with a zero gradient
+    /// where the elements are zero instead of an undefined one.
+    fn powf_nonzero(self, exponent: f64) -> Self {
+        let zeros = self.clone().equal_elem(0.0);
+
+        self.mask_fill(zeros.clone(), 1.0)
+            .powf_scalar(exponent)
+            .mask_fill(zeros, 0.0)
+    }
+}
+
+impl<B> Tensor<B, 1>
+where
+    B: Backend,
+{
+    /// Computes the outer product of the two vectors.
+    ///
+    /// `y_ij = x_i * z_j`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let lhs = Tensor::<B, 1>::from_floats([1.0, 2.0], &device);
+    ///     let rhs = Tensor::<B, 1>::from_floats([3.0, 4.0, 5.0], &device);
+    ///     let tensor = lhs.outer(rhs);
+    ///     // [[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]
+    /// }
+    /// ```
+    pub fn outer(self, other: Self) -> Tensor<B, 2> {
+        let lhs: Tensor<B, 2> = self.unsqueeze_dim(1);
+        let rhs: Tensor<B, 2> = other.unsqueeze_dim(0);
+
+        lhs.mul(rhs)
+    }
+
+    /// Draws samples from the categorical distribution given by the weights, returning the index
+    /// of the category of each sample.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_samples` - The number of samples to draw.
+    /// * `replacement` - If a category can be drawn more than once.
+    /// * `seed` - The seed of the random samples.
+    ///
+    /// # Notes
+    ///
+    /// The weights must be non-negative, but don't have to sum to one. With replacement, each
+    /// sample is found with a binary search over the cumulative distribution of the weights.
+    /// Without replacement, the categories with the largest weights perturbed by a Gumbel noise
+    /// are drawn, which doesn't require any data transfer between the host and the device.
+    ///
+    /// The backend is seeded with the given seed, which also affects the following random
+    /// operations.
+    ///
+    /// # Panics
+    ///
+    /// If `num_samples` is greater than the number of categories without replacement.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let weights = Tensor::<B, 1>::from_floats([0.1, 0.0, 0.9], &device);
+    ///     let samples = weights.multinomial(4, true, 42);
+    ///     // e.g. [2, 2, 0, 2]
+    /// }
+    /// ```
+    pub fn multinomial(
+        self,
+        num_samples: usize,
+        replacement: bool,
+        seed: u64,
+    ) -> Tensor<B, 1, Int> {
+        let [num_categories] = self.dims();
+        check!(TensorCheck::multinomial(
+            num_categories,
+            num_samples,
+            replacement
+        ));
+
+        B::seed(seed);
+        let device = self.device();
+        let weights = self.detach();
+
+        if replacement {
+            let uniform = Tensor::<B, 1>::random([num_samples], Distribution::Default, &device);
+            let cdf = weights.clone().cumsum(0).div(weights.sum());
+
+            // Rounding may leave the last value of the distribution slightly below one.
+            return uniform
+                .bucketize(cdf, true)
+                .clamp_max(num_categories as i64 - 1);
+        }
+
+        // Gumbel-top-k: the categories with the largest perturbed log weights are a sample
+        // without replacement.
+        let gumbel = Tensor::<B, 1>::random([num_categories], Distribution::Default, &device)
+            .clamp(f32::EPSILON, 1.0 - f32::EPSILON)
+            .log()
+            .neg()
+            .log()
+            .neg();
+
+        let (_, indices) = weights.log().add(gumbel).top_k(num_samples, 0);
+        indices
+    }
+}
+
+impl<B> Tensor<B, 2>
+where
+    B: Backend,
+{
+    /// Computes the sample covariance matrix of the variables, with the Bessel's correction.
+    ///
+    /// # Arguments
+    ///
+    /// * `rowvar` - If each row is a variable, of shape `[num_variables, num_observations]`.
+    ///   Otherwise, each column is a variable, of shape `[num_observations, num_variables]`.
+    ///
+    /// # Returns
+    ///
+    /// The covariance between each pair of variables, of shape `[num_variables, num_variables]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[0.0, 1.0, 2.0], [2.0, 1.0, 0.0]], &device);
+    ///     let covariance = tensor.covariance(true);
+    ///     // [[1.0, -1.0], [-1.0, 1.0]]
+    /// }
+    /// ```
+    pub fn covariance(self, rowvar: bool) -> Self {
+        match rowvar {
+            true => self.cov(1, 1),
+            false => self.cov(0, 1),
+        }
+    }
+
+    /// Computes the Pearson correlation coefficients of the variables, each row being a
+    /// variable, of shape `[num_variables, num_observations]`.
+    ///
+    /// The [covariance](Tensor::covariance) is normalized by the standard deviations of the
+    /// variables. The coefficients are clamped to `[-1, 1]` and the diagonal is exactly one, even
+    /// when the rounding errors would make it slightly different.
+    ///
+    /// # Returns
+    ///
+    /// The correlation between each pair of variables, of shape `[num_variables, num_variables]`.
+    /// The coefficients of a variable whose values are all equal are NaN, except on the diagonal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[0.0, 1.0, 2.0], [4.0, 2.0, 0.0]], &device);
+    ///     let correlation = tensor.corrcoef();
+    ///     // [[1.0, -1.0], [-1.0, 1.0]]
+    /// }
+    /// ```
+    pub fn corrcoef(self) -> Self {
+        let [num_variables, _] = self.dims();
+        let device = self.device();
+        let covariance = self.covariance(true);
+
+        let diagonal = Self::diagonal_mask(num_variables, &device);
+        let std = covariance
+            .clone()
+            .mul(diagonal.clone().float())
+            .sum_dim(1)
+            .sqrt();
+        let correlation = covariance
+            .div(std.clone())
+            .div(std.reshape([1, num_variables]))
+            .clamp(-1.0, 1.0);
+
+        correlation.mask_fill(diagonal, 1.0)
+    }
+
+    /// Computes the cosine similarity between each row of the tensor and each row of `other`.
+    ///
+    /// The rows are [normalized](Tensor::normalize) to a unit euclidean norm and the similarities
+    /// are computed with a single matrix multiplication, `normalize(x) @ normalize(other)^T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The second tensor, of shape `[num_rows_other, num_features]`.
+    ///
+    /// # Returns
+    ///
+    /// The similarity between each row of the tensor and each row of `other`, of shape
+    /// `[num_rows, num_rows_other]`.
+    ///
+    /// # Notes
+    ///
+    /// The norms are bounded below by `1e-8`, so the similarities of a zero row are zero rather
+    /// than NaN, and the gradient doesn't flow through its norm.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let x1 = Tensor::<B, 2>::from_floats([[1.0, 0.0], [1.0, 1.0]], &device);
+    ///     let x2 = Tensor::<B, 2>::from_floats([[0.0, 2.0], [-3.0, 0.0]], &device);
+    ///     let similarities = x1.pairwise_cosine_similarity(x2);
+    ///     // [[0.0, -1.0], [0.7071, -0.7071]]
+    /// }
+    /// ```
+    pub fn pairwise_cosine_similarity(self, other: Self) -> Self {
+        check!(TensorCheck::pairwise_cosine_similarity(
+            &self.shape(),
+            &other.shape()
+        ));
+
+        let other = other.normalize(2.0, 1, COSINE_SIMILARITY_EPS);
+
+        self.normalize(2.0, 1, COSINE_SIMILARITY_EPS)
+            .matmul(other.transpose())
+    }
+
+    /// Computes the cosine similarity between each pair of rows of the tensor, of shape
+    /// `[num_rows, num_features]`.
+    ///
+    /// It is the same as [pairwise_cosine_similarity](Tensor::pairwise_cosine_similarity) with
+    /// the tensor itself, but the rows are only normalized once.
+    ///
+    /// # Returns
+    ///
+    /// The symmetric matrix of the similarities, of shape `[num_rows, num_rows]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let embeddings = Tensor::<B, 2>::from_floats([[1.0, 0.0], [1.0, 1.0]], &device);
+    ///     let similarities = embeddings.cosine_similarity_matrix();
+    ///     // [[1.0, 0.7071], [0.7071, 1.0]]
+    /// }
+    /// ```
+    pub fn cosine_similarity_matrix(self) -> Self {
+        let normalized = self.normalize(2.0, 1, COSINE_SIMILARITY_EPS);
+
+        normalized.clone().matmul(normalized.transpose())
+    }
+
+    /// The mask of the diagonal of a square matrix of the given size.
+    fn diagonal_mask(size: usize, device: &B::Device) -> Tensor<B, 2, Bool> {
+        let indices = Tensor::<B, 1, Int>::arange(0..size as i64, device);
+
+        indices
+            .clone()
+            .reshape([size, 1])
+            .repeat(1, size)
+            .equal(indices.reshape([1, size]).repeat(0, size))
+    }
+}
+
+impl<B> Tensor<B, 3>
+where
+    B: Backend,
+{
+    /// Computes the batched pairwise `p`-norm distances between the rows of two tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The second tensor, of shape `[batch_size, num_rows_other, num_features]`.
+    /// * `p` - The order of the norm, which can be zero or infinite.
+    ///
+    /// # Returns
+    ///
+    /// The distance between each row of the tensor and each row of `other`, of shape
+    /// `[batch_size, num_rows, num_rows_other]`.
+    ///
+    /// # Notes
+    ///
+    /// The euclidean distance (`p = 2`) is computed with matrix multiplications from the
+    /// expansion `||x - y||^2 = ||x||^2 + ||y||^2 - 2 x.y`, without materializing the difference
+    /// between every pair of rows. It is faster and lighter, but less precise when the distances
+    /// are small compared to the norms of the rows. The other orders compute the differences
+    /// explicitly, which requires `num_rows * num_rows_other * num_features` elements per batch.
+    ///
+    /// The gradient is zero where the distance, or the difference between two features, is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let x1 = Tensor::<B, 3>::from_floats([[[0.0, 0.0], [1.0, 1.0]]], &device);
+    ///     let x2 = Tensor::<B, 3>::from_floats([[[3.0, 4.0]]], &device);
+    ///     let distances = x1.cdist(x2, 2.0);
+    ///     // [[[5.0], [3.6056]]]
+    /// }
+    /// ```
+    pub fn cdist(self, other: Self, p: f64) -> Self {
+        check!(TensorCheck::cdist(&self.shape(), &other.shape(), p));
+
+        if p == 2.0 {
+            return self.cdist_euclidean(other);
+        }
+
+        let differences = self.unsqueeze_dim::<4>(2).sub(other.unsqueeze_dim(1));
+        // The absolute value is computed with the signs of the differences, so the gradient of a
+        // zero difference is zero instead of undefined.
+        let signs = differences.clone().greater_elem(0.0).float()
+            - differences.clone().lower_elem(0.0).float();
+        let differences = differences.mul(signs);
+
+        let distances = if p == 0.0 {
+            differences.equal_elem(0.0).bool_not().float().sum_dim(3)
+        } else if p == 1.0 {
+            differences.sum_dim(3)
+        } else if p == f64::INFINITY {
+            differences.max_dim(3)
+        } else {
+            let sum = differences.powf_nonzero(p).sum_dim(3);
+            sum.powf_nonzero(1.0 / p)
+        };
+
+        distances.squeeze(3)
+    }
+
+    fn cdist_euclidean(self, other: Self) -> Self {
+        let norms = self.clone().powf_scalar(2.0).sum_dim(2);
+        let norms_other = other.clone().powf_scalar(2.0).sum_dim(2).swap_dims(1, 2);
+        let products = self.matmul(other.swap_dims(1, 2));
+
+        // The rounding errors of the expansion can make the squared distances slightly negative.
+        let squared = (norms + norms_other - products.mul_scalar(2.0)).clamp_min(0.0);
+
+        squared.powf_nonzero(0.5)
+    }
 }
@@ -0,0 +1,143 @@
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Int, Tensor};
+use alloc::vec::Vec;
+
+impl<const D: usize, B> Tensor<B, D>
+where
+    B: Backend,
+{
+    /// Puts the values at the given multi-dimensional index, adding them to the current values
+    /// when `accumulate` is true.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The index of each of the first dimensions, `None` selecting the whole
+    ///   dimension. The indices, all of the same length `num_indices`, are combined element-wise.
+    /// * `values` - The values to put, of the shape of the tensor where the first indexed dimension
+    ///   is of size `num_indices` and the other indexed dimensions of size 1.
+    /// * `accumulate` - If the values are added to the current values, the values of duplicate
+    ///   indices being summed. Otherwise, the current values are replaced, the last value of
+    ///   duplicate indices being kept.
+    ///
+    /// # Notes
+    ///
+    /// The gradient with respect to the values is a gather of the upstream gradient, and the
+    /// gradient with respect to the tensor is zero at the replaced positions. Without
+    /// accumulation, the duplicate indices are found by comparing every index with every other
+    /// one, which requires `num_indices * num_indices` elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::zeros([2, 3], &device);
+    ///     let rows = Tensor::<B, 1, Int>::from_ints([0, 1, 0], &device);
+    ///     let cols = Tensor::<B, 1, Int>::from_ints([2, 0, 2], &device);
+    ///     let values = Tensor::<B, 2>::from_floats([[1.0], [2.0], [3.0]], &device);
+    ///     let tensor = tensor.index_put(&[Some(rows), Some(cols)], values, false);
+    ///     // [[0.0, 0.0, 3.0], [2.0, 0.0, 0.0]]
+    /// }
+    /// ```
+    pub fn index_put(
+        self,
+        indices: &[Option<Tensor<B, 1, Int>>],
+        values: Tensor<B, D>,
+        accumulate: bool,
+    ) -> Self {
+        let lengths = indices
+            .iter()
+            .map(|index| index.as_ref().map(|index| index.dims()[0]))
+            .collect::<Vec<_>>();
+        check!(TensorCheck::index_put::<D>(
+            &self.shape(),
+            &lengths,
+            &values.shape()
+        ));
+
+        let indexed = indices
+            .iter()
+            .enumerate()
+            .filter_map(|(dim, index)| index.as_ref().map(|index| (dim, index.clone())))
+            .collect::<Vec<_>>();
+        let device = self.device();
+
+        // The indexed dimensions are moved first, so that they can be flattened into a single
+        // one, indexed by the linear index.
+        let mut order = (0..D).collect::<Vec<_>>();
+        let mut swaps = Vec::with_capacity(indexed.len());
+        for (target, (dim, _)) in indexed.iter().enumerate() {
+            let current = order.iter().position(|d| d == dim).unwrap();
+            order.swap(target, current);
+            swaps.push((target, current));
+        }
+        let swap = |tensor: Tensor<B, D>| {
+            swaps
+                .iter()
+                .fold(tensor, |tensor, (a, b)| tensor.swap_dims(*a, *b))
+        };
+
+        let tensor = swap(self);
+        let values = swap(values);
+        let shape = tensor.shape();
+        let num_indexed = indexed.len();
+        let num_positions = shape.dims[..num_indexed].iter().product::<usize>();
+        let num_features = shape.dims[num_indexed..].iter().product::<usize>();
+        let num_indices = values.dims()[0];
+
+        let mut stride = 1;
+        let mut linear = Tensor::<B, 1, Int>::zeros([num_indices], &device);
+        for (position, (_, index)) in indexed.into_iter().enumerate().rev() {
+            linear = linear.add(index.mul_scalar(stride as i64));
+            stride *= shape.dims[position];
+        }
+
+        let tensor = tensor.reshape([num_positions, num_features]);
+        let values = values.reshape([num_indices, num_features]);
+
+        let output = match accumulate {
+            true => tensor.select_assign(0, linear, values),
+            false => {
+                // The values of the indices put again later are discarded, the last one winning.
+                let positions = Tensor::<B, 1, Int>::arange(0..num_indices as i64, &device);
+                let same_index = linear
+                    .clone()
+                    .reshape([num_indices, 1])
+                    .repeat(1, num_indices)
+                    .equal(
+                        linear
+                            .clone()
+                            .reshape([1, num_indices])
+                            .repeat(0, num_indices),
+                    )
+                    .int();
+                let later = positions
+                    .clone()
+                    .reshape([1, num_indices])
+                    .repeat(0, num_indices)
+                    .greater(positions.reshape([num_indices, 1]).repeat(1, num_indices))
+                    .int();
+                let is_last = same_index.mul(later).sum_dim(1).equal_elem(0).float();
+
+                let written = Tensor::<B, 2>::zeros([num_positions, 1], &device)
+                    .select_assign(0, linear.clone(), Tensor::ones([num_indices, 1], &device))
+                    .greater_elem(0.0)
+                    .repeat(1, num_features);
+                let source = Tensor::<B, 2>::zeros([num_positions, num_features], &device)
+                    .select_assign(0, linear, values.mul(is_last));
+
+                tensor.mask_where(written, source)
+            }
+        };
+
+        let output = output.reshape(shape);
+        swaps
+            .iter()
+            .rev()
+            .fold(output, |tensor, (a, b)| tensor.swap_dims(*a, *b))
+    }
+}
@@ -0,0 +1,623 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{backend::Backend, Data, Shape, Tensor};
+
+/// Computes the singular value decomposition of the matrices of a tensor, over its last two
+/// dimensions, `A = U diag(S) Vh`.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor, of shape `[..., m, n]`.
+/// * `full_matrices` - If `U` and `Vh` are square, instead of having `k = min(m, n)` columns and
+///   rows.
+///
+/// # Returns
+///
+/// The left singular vectors `U` of shape `[..., m, k]` or `[..., m, m]`, the singular values `S`
+/// in descending order of shape `[..., 1, k]`, and the right singular vectors `Vh` of shape
+/// `[..., k, n]` or `[..., n, n]`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back on the host, where each matrix is decomposed with the one-sided Jacobi
+/// algorithm in double precision, so the operation is synchronous.
+pub fn svd<B: Backend, const D: usize>(
+    tensor: B::FloatTensorPrimitive<D>,
+    full_matrices: bool,
+) -> (
+    B::FloatTensorPrimitive<D>,
+    B::FloatTensorPrimitive<D>,
+    B::FloatTensorPrimitive<D>,
+) {
+    let device = B::float_device(&tensor);
+    let data = B::float_into_data(tensor)
+        .read_sync()
+        .expect("Can't decompose a tensor with async data.")
+        .convert::<f64>();
+
+    let dims = data.shape.dims;
+    let [m, n] = [dims[D - 2], dims[D - 1]];
+    let k = usize::min(m, n);
+    let [cols_u, rows_vh] = match full_matrices {
+        true => [m, n],
+        false => [k, k],
+    };
+    let num_matrices: usize = dims[..D - 2].iter().product();
+
+    let mut u = Vec::with_capacity(num_matrices * m * cols_u);
+    let mut s = Vec::with_capacity(num_matrices * k);
+    let mut vh = Vec::with_capacity(num_matrices * rows_vh * n);
+
+    for index in 0..num_matrices {
+        let matrix = &data.value[index * m * n..(index + 1) * m * n];
+        let (u_matrix, s_matrix, vh_matrix) = svd_matrix(matrix, m, n, full_matrices);
+
+        u.extend(u_matrix);
+        s.extend(s_matrix);
+        vh.extend(vh_matrix);
+    }
+
+    let with_matrix_dims = |rows: usize, cols: usize| {
+        let mut dims = dims;
+        dims[D - 2] = rows;
+        dims[D - 1] = cols;
+        Shape::new(dims)
+    };
+    let from_values = |values: Vec<f64>, shape: Shape<D>| {
+        B::float_from_data(Data::new(values, shape).convert(), &device)
+    };
+
+    (
+        from_values(u, with_matrix_dims(m, cols_u)),
+        from_values(s, with_matrix_dims(1, k)),
+        from_values(vh, with_matrix_dims(rows_vh, n)),
+    )
+}
+
+/// Decomposes a row-major `m x n` matrix, returning `U`, `S` and `Vh` in row-major order.
+fn svd_matrix(
+    matrix: &[f64],
+    m: usize,
+    n: usize,
+    full_matrices: bool,
+) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    // The algorithm orthogonalizes the columns of a tall matrix, so a wide matrix is decomposed
+    // through its transpose, `A^T = U S Vh` giving `A = Vh^T S U^T`.
+    if m < n {
+        let cols_u = match full_matrices {
+            true => n,
+            false => m,
+        };
+        let (u, s, vh) = svd_matrix(&transpose(matrix, m, n), n, m, full_matrices);
+
+        return (transpose(&vh, m, m), s, transpose(&u, n, cols_u));
+    }
+
+    let mut columns: Vec<Vec<f64>> = (0..n)
+        .map(|j| (0..m).map(|i| matrix[i * n + j]).collect())
+        .collect();
+    let mut vectors: Vec<Vec<f64>> = (0..n).map(|j| unit_vector(n, j)).collect();
+
+    // Each rotation makes a pair of columns orthogonal, until all of them are.
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = dot(&columns[p], &columns[p]);
+                let beta = dot(&columns[q], &columns[q]);
+                let gamma = dot(&columns[p], &columns[q]);
+
+                if gamma == 0.0 || libm::fabs(gamma) <= f64::EPSILON * libm::sqrt(alpha * beta) {
+                    continue;
+                }
+
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let (cos, sin) = rotation(zeta);
+
+                rotate(&mut columns, p, q, cos, sin);
+                rotate(&mut vectors, p, q, cos, sin);
+                rotated = true;
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut order: Vec<(f64, usize)> = columns
+        .iter()
+        .enumerate()
+        .map(|(j, column)| (libm::sqrt(dot(column, column)), j))
+        .collect();
+    order.sort_by(|lhs, rhs| rhs.0.total_cmp(&lhs.0));
+
+    // The left singular vectors of the null singular values can't be normalized, and are
+    // completed into an orthonormal basis along with the extra vectors of the full matrix.
+    let tolerance = order.first().map_or(0.0, |(value, _)| *value) * m as f64 * f64::EPSILON;
+    let cols_u = match full_matrices {
+        true => m,
+        false => n,
+    };
+    let mut u: Vec<Option<Vec<f64>>> = order
+        .iter()
+        .map(|(value, j)| match *value > tolerance {
+            true => Some(columns[*j].iter().map(|x| x / value).collect()),
+            false => None,
+        })
+        .collect();
+    u.resize(cols_u, None);
+    let u = complete_basis(u, m);
+
+    let s = order.iter().map(|(value, _)| *value).collect();
+    let vh = order
+        .iter()
+        .flat_map(|(_, j)| vectors[*j].iter().copied())
+        .collect();
+    let u = (0..m)
+        .flat_map(|i| u.iter().map(move |column| column[i]))
+        .collect();
+
+    (u, s, vh)
+}
+
+/// Computes the eigendecomposition of the symmetric matrices of a tensor, over its last two
+/// dimensions, `A = V diag(L) V^T`.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor, of shape `[..., n, n]`, of which only the lower triangle is read.
+///
+/// # Returns
+///
+/// The eigenvectors `V` as columns of shape `[..., n, n]`, and the eigenvalues `L` in ascending
+/// order of shape `[..., 1, n]`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back on the host, where each matrix is diagonalized with the cyclic Jacobi
+/// algorithm in double precision, so the operation is synchronous.
+pub fn eigh<B: Backend, const D: usize>(
+    tensor: B::FloatTensorPrimitive<D>,
+) -> (B::FloatTensorPrimitive<D>, B::FloatTensorPrimitive<D>) {
+    let device = B::float_device(&tensor);
+    let data = B::float_into_data(tensor)
+        .read_sync()
+        .expect("Can't decompose a tensor with async data.")
+        .convert::<f64>();
+
+    let dims = data.shape.dims;
+    let n = dims[D - 1];
+    let num_matrices: usize = dims[..D - 2].iter().product();
+
+    let mut vectors = Vec::with_capacity(num_matrices * n * n);
+    let mut values = Vec::with_capacity(num_matrices * n);
+
+    for index in 0..num_matrices {
+        let matrix = &data.value[index * n * n..(index + 1) * n * n];
+        let (vectors_matrix, values_matrix) = eigh_matrix(matrix, n);
+
+        vectors.extend(vectors_matrix);
+        values.extend(values_matrix);
+    }
+
+    let mut dims_values = dims;
+    dims_values[D - 2] = 1;
+
+    (
+        B::float_from_data(Data::new(vectors, Shape::new(dims)).convert(), &device),
+        B::float_from_data(
+            Data::new(values, Shape::new(dims_values)).convert(),
+            &device,
+        ),
+    )
+}
+
+/// Diagonalizes a row-major symmetric `n x n` matrix, returning the eigenvectors in row-major
+/// order and the eigenvalues.
+fn eigh_matrix(matrix: &[f64], n: usize) -> (Vec<f64>, Vec<f64>) {
+    // The matrix is symmetric, so its columns are also its rows.
+    let mut columns: Vec<Vec<f64>> = (0..n)
+        .map(|j| {
+            (0..n)
+                .map(|i| match i >= j {
+                    true => matrix[i * n + j],
+                    false => matrix[j * n + i],
+                })
+                .collect()
+        })
+        .collect();
+    let mut vectors: Vec<Vec<f64>> = (0..n).map(|j| unit_vector(n, j)).collect();
+    let norm = libm::sqrt(columns.iter().map(|column| dot(column, column)).sum());
+    let tolerance = f64::EPSILON * norm;
+
+    // Each rotation cancels a pair of off-diagonal elements, until all of them are negligible
+    // relative to the norm of the matrix.
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let (diagonal_p, diagonal_q) = (columns[p][p], columns[q][q]);
+                let element = columns[q][p];
+
+                if libm::fabs(element) <= tolerance {
+                    continue;
+                }
+
+                let theta = (diagonal_q - diagonal_p) / (2.0 * element);
+                let (cos, sin) = rotation(theta);
+
+                rotate(&mut columns, p, q, cos, sin);
+                columns.iter_mut().for_each(|column| {
+                    let (x, y) = (column[p], column[q]);
+                    column[p] = cos * x - sin * y;
+                    column[q] = sin * x + cos * y;
+                });
+                rotate(&mut vectors, p, q, cos, sin);
+                rotated = true;
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut order: Vec<(f64, usize)> = (0..n).map(|j| (columns[j][j], j)).collect();
+    order.sort_by(|lhs, rhs| lhs.0.total_cmp(&rhs.0));
+
+    let values = order.iter().map(|(value, _)| *value).collect();
+    let vectors = (0..n)
+        .flat_map(|i| {
+            order
+                .iter()
+                .map(|(_, j)| vectors[*j][i])
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    (vectors, values)
+}
+
+/// Solves the square linear systems `A X = B` over the last two dimensions of the tensors.
+///
+/// # Arguments
+///
+/// * `a` - The coefficients, of shape `[..., n, n]`.
+/// * `b` - The right-hand sides, of shape `[..., n, k]`.
+///
+/// # Returns
+///
+/// The solutions `X`, of shape `[..., n, k]`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back on the host, where each system is solved with the LU decomposition with
+/// partial pivoting in double precision, so the operation is synchronous.
+pub fn solve<B: Backend, const D: usize>(
+    a: B::FloatTensorPrimitive<D>,
+    b: B::FloatTensorPrimitive<D>,
+) -> B::FloatTensorPrimitive<D> {
+    let device = B::float_device(&a);
+    let read = |tensor| {
+        B::float_into_data(tensor)
+            .read_sync()
+            .expect("Can't solve a system with async data.")
+            .convert::<f64>()
+    };
+    let (a, b) = (read(a), read(b));
+
+    let dims = b.shape.dims;
+    let [n, k] = [dims[D - 2], dims[D - 1]];
+    let num_matrices: usize = dims[..D - 2].iter().product();
+
+    let mut x = Vec::with_capacity(num_matrices * n * k);
+
+    for index in 0..num_matrices {
+        let matrix = &a.value[index * n * n..(index + 1) * n * n];
+        let rhs = &b.value[index * n * k..(index + 1) * n * k];
+
+        x.extend(solve_matrix(matrix, rhs, n, k));
+    }
+
+    B::float_from_data(Data::new(x, Shape::new(dims)).convert(), &device)
+}
+
+/// Solves a row-major `n x n` system for the `k` columns of the row-major right-hand side,
+/// returning the solution in row-major order.
+fn solve_matrix(matrix: &[f64], rhs: &[f64], n: usize, k: usize) -> Vec<f64> {
+    let mut rows: Vec<Vec<f64>> = (0..n)
+        .map(|i| matrix[i * n..(i + 1) * n].to_vec())
+        .collect();
+    let mut x: Vec<Vec<f64>> = (0..n).map(|i| rhs[i * k..(i + 1) * k].to_vec()).collect();
+
+    // The rows are eliminated below each pivot, chosen as the largest element of its column to
+    // bound the growth of the rounding errors.
+    for j in 0..n {
+        let pivot = (j..n)
+            .max_by(|lhs, rhs| libm::fabs(rows[*lhs][j]).total_cmp(&libm::fabs(rows[*rhs][j])))
+            .unwrap();
+
+        if rows[pivot][j] == 0.0 {
+            panic!("The matrix is singular, the linear system can't be solved.");
+        }
+
+        rows.swap(j, pivot);
+        x.swap(j, pivot);
+
+        let (pivot_row, pivot_x) = (rows[j].clone(), x[j].clone());
+
+        for i in j + 1..n {
+            let factor = rows[i][j] / pivot_row[j];
+
+            rows[i]
+                .iter_mut()
+                .zip(&pivot_row)
+                .skip(j)
+                .for_each(|(value, pivot)| *value -= factor * pivot);
+            x[i].iter_mut()
+                .zip(&pivot_x)
+                .for_each(|(value, pivot)| *value -= factor * pivot);
+        }
+    }
+
+    // The upper triangular system is then solved by back substitution.
+    for i in (0..n).rev() {
+        let (unsolved, solved) = x.split_at_mut(i + 1);
+        let row = &mut unsolved[i];
+
+        for (coefficient, solved) in rows[i][i + 1..].iter().zip(solved.iter()) {
+            row.iter_mut()
+                .zip(solved)
+                .for_each(|(value, solved)| *value -= coefficient * solved);
+        }
+        row.iter_mut().for_each(|value| *value /= rows[i][i]);
+    }
+
+    x.into_iter().flatten().collect()
+}
+
+/// Computes the least-squares solutions of the linear systems `A X = B` over the last two
+/// dimensions of the tensors, minimizing `||A X - B||`.
+///
+/// # Arguments
+///
+/// * `a` - The coefficients, of shape `[..., m, n]`.
+/// * `b` - The right-hand sides, of shape `[..., m, k]`.
+///
+/// # Returns
+///
+/// The solutions `X`, of shape `[..., n, k]`, of minimal norm when the system is underdetermined
+/// or rank deficient.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The solutions are computed with the pseudo-inverse `V diag(S)^+ U^T` from the singular value
+/// decomposition of the backend, ignoring the singular values that are negligible relative to the
+/// largest one.
+pub fn lstsq<B: Backend, const D: usize>(
+    a: B::FloatTensorPrimitive<D>,
+    b: B::FloatTensorPrimitive<D>,
+) -> B::FloatTensorPrimitive<D> {
+    let dims = B::float_shape(&a).dims;
+    let [m, n] = [dims[D - 2], dims[D - 1]];
+    let (u, s, vh) = B::float_svd(a, false);
+    let [u, s, vh, b] = [u, s, vh, b].map(Tensor::<B, D>::from_primitive);
+
+    // The comparison also masks the singular values of null matrices, whose ratios are NaN.
+    let tolerance = usize::max(m, n) as f32 * f32::EPSILON;
+    let ratio = s.clone().div(s.clone().narrow(D - 1, 0, 1));
+    let negligible = ratio.greater_elem(tolerance).bool_not();
+    let s_inv = s.recip().mask_fill(negligible, 0.0);
+
+    let projected = u.transpose().matmul(b).mul(s_inv.transpose());
+
+    vh.transpose().matmul(projected).into_primitive()
+}
+
+/// Computes the Cholesky decomposition of the symmetric positive-definite matrices of a tensor,
+/// over its last two dimensions, `A = L L^T`.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor, of shape `[..., n, n]`, of which only the lower triangle is read.
+///
+/// # Returns
+///
+/// The lower triangular factors `L`, of shape `[..., n, n]`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back on the host, where each matrix is decomposed with the
+/// Cholesky-Banachiewicz algorithm in double precision, so the operation is synchronous.
+pub fn cholesky<B: Backend, const D: usize>(
+    tensor: B::FloatTensorPrimitive<D>,
+) -> B::FloatTensorPrimitive<D> {
+    let device = B::float_device(&tensor);
+    let data = B::float_into_data(tensor)
+        .read_sync()
+        .expect("Can't decompose a tensor with async data.")
+        .convert::<f64>();
+
+    let dims = data.shape.dims;
+    let n = dims[D - 1];
+
+    let factors = data
+        .value
+        .chunks(n * n)
+        .flat_map(|matrix| cholesky_matrix(matrix, n))
+        .collect();
+
+    B::float_from_data(Data::new(factors, Shape::new(dims)).convert(), &device)
+}
+
+/// Decomposes a row-major symmetric positive-definite `n x n` matrix, returning the lower
+/// triangular factor in row-major order.
+fn cholesky_matrix(matrix: &[f64], n: usize) -> Vec<f64> {
+    let mut factor = vec![0.0; n * n];
+
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| factor[i * n + k] * factor[j * n + k]).sum();
+            let value = matrix[i * n + j] - sum;
+
+            factor[i * n + j] = match i == j {
+                true if value > 0.0 => libm::sqrt(value),
+                true => {
+                    panic!("The matrix isn't positive-definite, it has no Cholesky decomposition.")
+                }
+                false => value / factor[j * n + j],
+            };
+        }
+    }
+
+    factor
+}
+
+/// Solves the linear systems `A X = B` from the Cholesky factors of the symmetric
+/// positive-definite matrices `A`.
+///
+/// # Arguments
+///
+/// * `factor` - The lower triangular factors `L` with `A = L L^T`, or the upper triangular factors
+///   `U` with `A = U^T U`, of shape `[..., n, n]`.
+/// * `b` - The right-hand sides, of shape `[..., n, k]`.
+/// * `upper` - If the factors are upper triangular.
+///
+/// # Returns
+///
+/// The solutions `X`, of shape `[..., n, k]`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The triangular systems are solved one after the other with the general linear solver of the
+/// backend.
+pub fn cholesky_solve<B: Backend, const D: usize>(
+    factor: B::FloatTensorPrimitive<D>,
+    b: B::FloatTensorPrimitive<D>,
+    upper: bool,
+) -> B::FloatTensorPrimitive<D> {
+    let lower = match upper {
+        true => B::float_transpose(factor),
+        false => factor,
+    };
+
+    // `L Y = B`, then `L^T X = Y`.
+    let y = B::float_solve(lower.clone(), b);
+
+    B::float_solve(B::float_transpose(lower), y)
+}
+
+const MAX_SWEEPS: usize = 64;
+
+fn transpose(matrix: &[f64], rows: usize, cols: usize) -> Vec<f64> {
+    (0..cols)
+        .flat_map(|j| (0..rows).map(move |i| matrix[i * cols + j]))
+        .collect()
+}
+
+fn dot(lhs: &[f64], rhs: &[f64]) -> f64 {
+    lhs.iter().zip(rhs).map(|(lhs, rhs)| lhs * rhs).sum()
+}
+
+fn unit_vector(size: usize, index: usize) -> Vec<f64> {
+    let mut vector = vec![0.0; size];
+    vector[index] = 1.0;
+    vector
+}
+
+/// Returns the cosine and sine of the rotation canceling the off-diagonal element `x_pq` of a
+/// symmetric matrix, from `theta = (x_qq - x_pp) / (2 x_pq)`.
+fn rotation(theta: f64) -> (f64, f64) {
+    let sign = match theta >= 0.0 {
+        true => 1.0,
+        false => -1.0,
+    };
+    let tan = sign / (libm::fabs(theta) + libm::sqrt(1.0 + theta * theta));
+    let cos = 1.0 / libm::sqrt(1.0 + tan * tan);
+
+    (cos, cos * tan)
+}
+
+/// Applies the Givens rotation of the given cosine and sine to the columns `p` and `q`.
+fn rotate(columns: &mut [Vec<f64>], p: usize, q: usize, cos: f64, sin: f64) {
+    for i in 0..columns[p].len() {
+        let (x, y) = (columns[p][i], columns[q][i]);
+        columns[p][i] = cos * x - sin * y;
+        columns[q][i] = sin * x + cos * y;
+    }
+}
+
+/// Replaces the missing vectors with unit vectors orthogonalized against the other ones, with the
+/// Gram-Schmidt process.
+fn complete_basis(vectors: Vec<Option<Vec<f64>>>, size: usize) -> Vec<Vec<f64>> {
+    let mut basis: Vec<Vec<f64>> = vectors.iter().flatten().cloned().collect();
+    let mut candidates = (0..size).map(|index| unit_vector(size, index));
+    // The squared norms of the remaining parts of the unit vectors sum to the number of missing
+    // vectors, so one of them is always above this threshold.
+    let threshold = 0.5 / libm::sqrt(size as f64);
+
+    vectors
+        .into_iter()
+        .map(|vector| match vector {
+            Some(vector) => vector,
+            None => loop {
+                let mut candidate = candidates
+                    .next()
+                    .expect("The unit vectors should span the space.");
+
+                // The projections are removed twice, which keeps the vectors orthogonal despite
+                // the rounding errors.
+                for _ in 0..2 {
+                    for vector in basis.iter() {
+                        let projection = dot(&candidate, vector);
+                        candidate
+                            .iter_mut()
+                            .zip(vector)
+                            .for_each(|(x, y)| *x -= projection * y);
+                    }
+                }
+
+                let norm = libm::sqrt(dot(&candidate, &candidate));
+                if norm > threshold {
+                    candidate.iter_mut().for_each(|x| *x /= norm);
+                    basis.push(candidate.clone());
+                    break candidate;
+                }
+            },
+        })
+        .collect()
+}
@@ -1,18 +1,38 @@
 pub(crate) mod check;
 
+mod as_strided;
 mod autodiff;
 mod base;
 mod bool;
 mod chunk;
+mod cumulative;
+mod erfinv;
+mod fft;
 mod float;
+mod index_put;
 mod int;
 mod kind;
+mod linalg;
 mod narrow;
+mod nonzero;
 mod numeric;
+mod roll;
+mod segment;
+mod sort;
+mod unique;
 
 pub use autodiff::*;
 pub use base::*;
 pub use chunk::chunk;
+pub use cumulative::{cumprod, cumsum, logcumsumexp};
+pub use erfinv::erfinv;
+pub use fft::fft;
 pub use kind::*;
+pub use linalg::{cholesky, cholesky_solve, eigh, lstsq, solve, svd};
 pub use narrow::narrow;
+pub use nonzero::nonzero;
 pub use numeric::*;
+pub use roll::roll;
+pub use segment::SegmentReduce;
+pub use sort::{sort_compare, sort_with_indices};
+pub use unique::{unique, unique_consecutive};
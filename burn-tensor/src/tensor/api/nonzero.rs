@@ -0,0 +1,58 @@
+use crate::{backend::Backend, BasicOps, Data, Element, ElementConversion, Shape, TensorKind};
+use alloc::vec::Vec;
+use num_traits::ToPrimitive;
+
+/// Returns the indices of the non-zero elements of the tensor, one row per element in row-major
+/// order.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+///
+/// # Returns
+///
+/// An int tensor of shape `[N, D]`, where `N` is the number of non-zero elements.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back on the host, since the number of non-zero elements determines the shape
+/// of the output, so the operation is synchronous.
+pub fn nonzero<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+) -> B::IntTensorPrimitive<2>
+where
+    K::Elem: Element,
+{
+    let device = K::device(&tensor);
+    let data = K::into_data(tensor)
+        .read_sync()
+        .expect("Can't compute the non-zero indices of a tensor with async data.");
+    let dims = data.shape.dims;
+
+    let mut indices = Vec::new();
+    let mut num_nonzero = 0;
+
+    for (index, value) in data.value.iter().enumerate() {
+        // NaN values are different from zero, so they are counted as well.
+        if value.to_f64().unwrap() == 0.0 {
+            continue;
+        }
+
+        let start = indices.len();
+        let mut remainder = index;
+        for dim in (0..D).rev() {
+            indices.push((remainder % dims[dim]) as i64);
+            remainder /= dims[dim];
+        }
+        indices[start..].reverse();
+        num_nonzero += 1;
+    }
+
+    let indices = indices.into_iter().map(|index| index.elem()).collect();
+    B::int_from_data(Data::new(indices, Shape::new([num_nonzero, D])), &device)
+}
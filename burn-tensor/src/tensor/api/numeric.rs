@@ -1,7 +1,8 @@
 use crate::{
-    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Element, ElementConversion, Float,
-    Int, Shape, Tensor, TensorKind,
+    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Data, Element, ElementConversion,
+    Float, Int, Shape, Tensor, TensorKind,
 };
+use alloc::vec::Vec;
 
 impl<B, const D: usize, K> Tensor<B, D, K>
 where
@@ -213,20 +214,125 @@ where
 
     /// Update the given tensor with the value tensor where the mask is true.
     ///
-    /// This is similar to [mask_fill](Tensor::mask_fill), however the value is a tensor instead of
-    /// a scalar.
+    /// This is similar to [masked_fill](Tensor::masked_fill), however the value is a tensor instead
+    /// of a scalar.
     pub fn mask_where(self, mask: Tensor<B, D, Bool>, value: Self) -> Self {
         Self::new(K::mask_where(self.primitive, mask, value.primitive))
     }
 
+    /// Select the elements of `x` where the condition is true and the elements of `y` otherwise.
+    ///
+    /// The three tensors are broadcasted to the same shape, each of their dimensions must either
+    /// be equal to the others or be one. Unlike [mask_where](Tensor::mask_where), none of the
+    /// tensors has to have the shape of the output.
+    ///
+    /// When differentiated, the gradient flows to `x` where the condition is true and to `y`
+    /// where it is false.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted together.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Data, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let condition =
+    ///         Tensor::<B, 2, Bool>::from_bool(Data::from([[true], [false]]), &device);
+    ///     let x = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0]], &device);
+    ///     let y = Tensor::<B, 2>::from_floats([[0.0]], &device);
+    ///     let output = Tensor::where_cond(condition, x, y);
+    ///     // [[1.0, 2.0, 3.0], [0.0, 0.0, 0.0]]
+    /// }
+    /// ```
+    pub fn where_cond(condition: Tensor<B, D, Bool>, x: Self, y: Self) -> Self {
+        let shape_condition = condition.shape();
+        let shape_x = x.shape();
+        let shape_y = y.shape();
+        check!(TensorCheck::where_cond(
+            &shape_condition,
+            &shape_x,
+            &shape_y
+        ));
+
+        let mut shape = shape_condition.clone();
+        for dim in 0..D {
+            for size in [shape_x.dims[dim], shape_y.dims[dim]] {
+                if shape.dims[dim] == 1 {
+                    shape.dims[dim] = size;
+                }
+            }
+        }
+
+        let condition = match shape_condition == shape {
+            true => condition,
+            false => condition.expand(shape.clone()),
+        };
+        let x = match shape_x == shape {
+            true => x,
+            false => x.expand(shape.clone()),
+        };
+        let y = match shape_y == shape {
+            true => y,
+            false => y.expand(shape),
+        };
+
+        y.mask_where(condition, x)
+    }
+
     /// Update the given tensor with the value where the mask is true.
     ///
     /// This is similar to [mask_where](Tensor::mask_where), however the value is a scalar instead of
     /// a tensor.
-    pub fn mask_fill<E: ElementConversion>(self, mask: Tensor<B, D, Bool>, value: E) -> Self {
+    ///
+    /// When differentiated, the gradient is zero at the positions where the mask is true, since
+    /// the output doesn't depend on the input there.
+    pub fn masked_fill<E: ElementConversion>(self, mask: Tensor<B, D, Bool>, value: E) -> Self {
         Self::new(K::mask_fill(self.primitive, mask, value.elem()))
     }
 
+    /// Update the given tensor with the value where the mask is true.
+    ///
+    /// Alias of [masked_fill](Tensor::masked_fill).
+    pub fn mask_fill<E: ElementConversion>(self, mask: Tensor<B, D, Bool>, value: E) -> Self {
+        self.masked_fill(mask, value)
+    }
+
+    /// Update the given tensor with the value where the mask is true, the mask being broadcasted
+    /// to the shape of the tensor.
+    ///
+    /// The dimensions of the mask are aligned with the last dimensions of the tensor, and each of
+    /// them must either be equal to the corresponding dimension of the tensor or be one. This is
+    /// useful to apply a `[seq_length, seq_length]` attention mask to scores of shape
+    /// `[batch_size, n_heads, seq_length, seq_length]`.
+    ///
+    /// # Panics
+    ///
+    /// If the mask has more dimensions than the tensor or if its shape can't be broadcasted to the
+    /// shape of the tensor.
+    pub fn masked_fill_broadcast<const D2: usize, E: ElementConversion>(
+        self,
+        mask: Tensor<B, D2, Bool>,
+        value: E,
+    ) -> Self {
+        let shape = self.shape();
+        check!(TensorCheck::mask_broadcast::<D, D2>(&shape, &mask.shape()));
+
+        let mut mask: Tensor<B, D, Bool> = mask.unsqueeze();
+        let mask_shape = mask.shape();
+        for dim in 0..D {
+            if mask_shape.dims[dim] != shape.dims[dim] {
+                mask = mask.repeat(dim, shape.dims[dim]);
+            }
+        }
+
+        self.masked_fill(mask, value)
+    }
+
     /// Gather tensor elements corresponding to the given indices from the specified dim.
     ///
     /// Example using a 3D tensor:
@@ -311,6 +417,117 @@ where
         ))
     }
 
+    /// Extracts the sliding windows of the given size along a dimension, the windows starting
+    /// every `step` elements.
+    ///
+    /// The dimension is replaced by the number of windows, and a new last dimension of size
+    /// `size` holds the elements of each window, e.g. unfolding a tensor of shape `[2, 7, 3]`
+    /// along the dimension 1 with windows of size 3 and a step of 2 gives a tensor of shape
+    /// `[2, 3, 3, 3]`. Trailing elements not covered by a full window are dropped.
+    ///
+    /// This is the `im2col` operation along a single dimension, see [fold](Tensor::fold) for its
+    /// inverse.
+    ///
+    /// # Panics
+    ///
+    /// - If the dimension is greater than the rank of the tensor.
+    /// - If `D2` isn't equal to `D + 1`.
+    /// - If the size or the step is 0, or if the size is greater than the size of the dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0, 4.0, 5.0], &device);
+    ///
+    ///     let windows = tensor.unfold::<2>(0, 3, 2);
+    ///     println!("{}", windows);
+    ///     // [[1.0, 2.0, 3.0], [3.0, 4.0, 5.0]]
+    /// }
+    /// ```
+    pub fn unfold<const D2: usize>(self, dim: usize, size: usize, step: usize) -> Tensor<B, D2, K> {
+        check!(TensorCheck::unfold::<D, D2>(dim, size, step, &self.shape()));
+
+        let dims = self.dims();
+        let num_windows = (dims[dim] - size) / step + 1;
+        let indices = window_indices::<B>(num_windows, size, step, &self.device());
+
+        let mut shape = [1; D2];
+        shape[..dim].copy_from_slice(&dims[..dim]);
+        shape[dim] = num_windows;
+        shape[dim + 1] = size;
+        shape[dim + 2..].copy_from_slice(&dims[dim + 1..]);
+
+        let mut output = self.select(dim, indices).reshape(shape);
+
+        // The elements of the windows are moved to the last dimension.
+        for i in dim + 1..D2 - 1 {
+            output = output.swap_dims(i, i + 1);
+        }
+
+        output
+    }
+
+    /// Sums sliding windows back into a dimension of the given length, the windows starting
+    /// every `step` elements.
+    ///
+    /// The tensor is expected to have the layout produced by [unfold](Tensor::unfold): the
+    /// windows along `dim` and the elements of each window along the last dimension. Elements
+    /// covered by more than one window are summed, and elements covered by none are zeros, which
+    /// is the `col2im` operation along a single dimension. When the step is equal to the size of
+    /// the windows, folding an unfolded tensor gives back the original tensor.
+    ///
+    /// # Panics
+    ///
+    /// - If the dimension isn't lower than the last dimension of the tensor.
+    /// - If `D2` isn't equal to `D - 1`.
+    /// - If the step is 0, or if the windows don't fit in the given length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let windows = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [3.0, 4.0, 5.0]], &device);
+    ///
+    ///     let tensor = windows.fold::<1>(0, 5, 2);
+    ///     println!("{}", tensor);
+    ///     // [1.0, 2.0, 6.0, 4.0, 5.0]
+    /// }
+    /// ```
+    pub fn fold<const D2: usize>(self, dim: usize, length: usize, step: usize) -> Tensor<B, D2, K> {
+        check!(TensorCheck::fold::<D, D2>(dim, length, step, &self.shape()));
+
+        let dims = self.dims();
+        let device = self.device();
+        let num_windows = dims[dim];
+        let size = dims[D - 1];
+        let indices = window_indices::<B>(num_windows, size, step, &device);
+
+        // The elements of the windows are moved next to the windows.
+        let mut tensor = self;
+        for i in (dim + 2..D).rev() {
+            tensor = tensor.swap_dims(i - 1, i);
+        }
+
+        let mut shape_windows = [1; D2];
+        shape_windows[..dim].copy_from_slice(&dims[..dim]);
+        shape_windows[dim] = num_windows * size;
+        shape_windows[dim + 1..].copy_from_slice(&dims[dim + 1..D - 1]);
+
+        let mut shape = shape_windows;
+        shape[dim] = length;
+
+        Tensor::zeros(shape, &device).select_assign(dim, indices, tensor.reshape(shape_windows))
+    }
+
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
     /// # Example
@@ -568,6 +785,133 @@ where
     pub fn powi_scalar<E: ElementConversion>(self, other: E) -> Self {
         Self::new(K::powi_scalar(self.primitive, other))
     }
+
+    /// Returns the unique elements of the tensor in ascending order, along with the index of the
+    /// unique element of each element of the flattened tensor.
+    ///
+    /// # Notes
+    ///
+    /// The number of unique elements is only known once the tensor is read, which makes this
+    /// operation synchronous on most backends.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_floats([[3.0, 1.0], [1.0, 2.0]], &device);
+    ///    let (values, inverse) = tensor.unique();
+    ///    // values: [1.0, 2.0, 3.0]
+    ///    // inverse: [2, 0, 0, 1]
+    /// }
+    /// ```
+    pub fn unique(self) -> (Tensor<B, 1, K>, Tensor<B, 1, Int>) {
+        let (values, inverse, _counts) = K::unique(self.primitive);
+
+        (Tensor::new(values), Tensor::new(inverse))
+    }
+
+    /// Returns the unique elements of the tensor in ascending order, along with the index of the
+    /// unique element of each element of the flattened tensor and the number of occurrences of
+    /// each unique element.
+    ///
+    /// # Notes
+    ///
+    /// The number of unique elements is only known once the tensor is read, which makes this
+    /// operation synchronous on most backends.
+    pub fn unique_with_counts(self) -> (Tensor<B, 1, K>, Tensor<B, 1, Int>, Tensor<B, 1, Int>) {
+        let (values, inverse, counts) = K::unique(self.primitive);
+
+        (
+            Tensor::new(values),
+            Tensor::new(inverse),
+            Tensor::new(counts),
+        )
+    }
+
+    /// Collapses the consecutive equal slices of the tensor along the given dimension, keeping
+    /// only the first slice of each group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 1>::from_floats([1.0, 1.0, 2.0, 2.0, 1.0], &device);
+    ///    let tensor = tensor.unique_consecutive(0);
+    ///    // [1.0, 2.0, 1.0]
+    /// }
+    /// ```
+    pub fn unique_consecutive(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Unique consecutive", dim));
+
+        Self::new(K::unique_consecutive(self.primitive, dim))
+    }
+
+    /// Returns the indices of the non-zero elements of the tensor.
+    ///
+    /// The output has the shape `[N, D]`, where `N` is the number of non-zero elements, each row
+    /// being the index of one element in row-major order.
+    ///
+    /// The number of non-zero elements is only known once the tensor is read, which makes this
+    /// operation synchronous.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::diagonal(3, &device);
+    ///    let indices = tensor.nonzero();
+    ///    // [[0, 0], [1, 1], [2, 2]]
+    /// }
+    /// ```
+    pub fn nonzero(self) -> Tensor<B, 2, Int> {
+        Tensor::new(K::nonzero(self.primitive))
+    }
+
+    /// Counts the non-zero elements of the tensor, either along the given dimension or over the
+    /// whole tensor.
+    ///
+    /// The dimensions are kept, with a size of one for the counted dimension, or for every
+    /// dimension when no dimension is given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_floats([[1.0, 0.0, 2.0], [0.0, 0.0, 3.0]], &device);
+    ///    let count = tensor.clone().count_nonzero(Some(1));
+    ///    // [[2], [1]]
+    ///    let count = tensor.count_nonzero(None);
+    ///    // [[3]]
+    /// }
+    /// ```
+    pub fn count_nonzero(self, dim: Option<usize>) -> Tensor<B, D, Int> {
+        if let Some(dim) = dim {
+            check!(TensorCheck::dim_ops::<D>("Count nonzero", dim));
+        }
+
+        let nonzero = self.equal_elem(0).bool_not().int();
+
+        match dim {
+            Some(dim) => nonzero.sum_dim(dim),
+            None => nonzero.sum().reshape([1; D]),
+        }
+    }
 }
 
 impl<B, K> Tensor<B, 2, K>
@@ -589,6 +933,24 @@ where
     }
 }
 
+/// Returns the position of every element of the sliding windows, window after window.
+fn window_indices<B: Backend>(
+    num_windows: usize,
+    size: usize,
+    step: usize,
+    device: &B::Device,
+) -> Tensor<B, 1, Int> {
+    let indices: Vec<i64> = (0..num_windows)
+        .flat_map(|window| (0..size).map(move |i| (window * step + i) as i64))
+        .collect();
+    let num_indices = indices.len();
+
+    Tensor::from_data(
+        Data::new(indices, Shape::new([num_indices])).convert(),
+        device,
+    )
+}
+
 /// Trait that list all operations that can be applied on all numerical tensors.
 ///
 /// # Warnings
@@ -1645,6 +2007,71 @@ where
         lhs: Self::Primitive<D>,
         rhs: E,
     ) -> Self::Primitive<D>;
+
+    /// Returns the unique elements of the tensor in ascending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the unique elements, the index of the unique element of each element of the
+    /// flattened tensor and the number of occurrences of each unique element.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For returning the unique elements of a tensor, users should prefer the
+    /// [Tensor::unique](Tensor::unique) function, which is more high-level and designed for public use.
+    fn unique<const D: usize>(
+        tensor: Self::Primitive<D>,
+    ) -> (
+        Self::Primitive<1>,
+        B::IntTensorPrimitive<1>,
+        B::IntTensorPrimitive<1>,
+    );
+
+    /// Collapses the consecutive equal slices of the tensor along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which the slices are compared.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For collapsing the consecutive equal slices of a tensor, users should prefer the
+    /// [Tensor::unique_consecutive](Tensor::unique_consecutive) function, which is more high-level
+    /// and designed for public use.
+    fn unique_consecutive<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> Self::Primitive<D>;
+
+    /// Returns the indices of the non-zero elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For returning the indices of the non-zero elements of a tensor, users should prefer the
+    /// [Tensor::nonzero](Tensor::nonzero) function, which is more high-level and designed for
+    /// public use.
+    fn nonzero<const D: usize>(tensor: Self::Primitive<D>) -> B::IntTensorPrimitive<2>;
 }
 
 impl<B: Backend> Numeric<B> for Int {
@@ -1927,6 +2354,27 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> Self::Primitive<D> {
         B::int_powf_scalar(lhs, rhs.elem())
     }
+
+    fn unique<const D: usize>(
+        tensor: Self::Primitive<D>,
+    ) -> (
+        Self::Primitive<1>,
+        B::IntTensorPrimitive<1>,
+        B::IntTensorPrimitive<1>,
+    ) {
+        B::int_unique(tensor)
+    }
+
+    fn unique_consecutive<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> Self::Primitive<D> {
+        B::int_unique_consecutive(tensor, dim)
+    }
+
+    fn nonzero<const D: usize>(tensor: Self::Primitive<D>) -> B::IntTensorPrimitive<2> {
+        B::int_nonzero(tensor)
+    }
 }
 
 impl<B: Backend> Numeric<B> for Float {
@@ -2210,6 +2658,27 @@ impl<B: Backend> Numeric<B> for Float {
     ) -> Self::Primitive<D> {
         B::float_powf_scalar(lhs, rhs.elem())
     }
+
+    fn unique<const D: usize>(
+        tensor: Self::Primitive<D>,
+    ) -> (
+        Self::Primitive<1>,
+        B::IntTensorPrimitive<1>,
+        B::IntTensorPrimitive<1>,
+    ) {
+        B::float_unique(tensor)
+    }
+
+    fn unique_consecutive<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> Self::Primitive<D> {
+        B::float_unique_consecutive(tensor, dim)
+    }
+
+    fn nonzero<const D: usize>(tensor: Self::Primitive<D>) -> B::IntTensorPrimitive<2> {
+        B::float_nonzero(tensor)
+    }
 }
 
 impl<B, const D: usize, K> core::ops::Add<Self> for Tensor<B, D, K>
@@ -0,0 +1,49 @@
+use super::narrow::narrow;
+use crate::{backend::Backend, BasicOps, TensorKind};
+use alloc::vec;
+
+/// Rolls the tensor along the given dimensions, the elements shifted beyond the last position
+/// being re-introduced at the first position.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `shifts` - The number of places by which the elements are shifted along each dimension.
+/// * `dims` - The dimensions along which the tensor will be rolled.
+///
+/// # Returns
+///
+/// The rolled tensor.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn roll<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+    shifts: &[i64],
+    dims: &[usize],
+) -> K::Primitive<D> {
+    shifts
+        .iter()
+        .zip(dims.iter())
+        .fold(tensor, |tensor, (shift, dim)| {
+            let size = K::shape(&tensor).dims[*dim];
+            if size == 0 {
+                return tensor;
+            }
+
+            let shift = shift.rem_euclid(size as i64) as usize;
+            if shift == 0 {
+                return tensor;
+            }
+
+            // The last `shift` elements are moved in front of the others.
+            let front = narrow::<B, D, K>(tensor.clone(), *dim, size - shift, shift);
+            let back = narrow::<B, D, K>(tensor, *dim, 0, size - shift);
+
+            K::cat(vec![front, back], *dim)
+        })
+}
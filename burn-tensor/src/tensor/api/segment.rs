@@ -0,0 +1,121 @@
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Int, Tensor};
+
+/// The reduction applied by [segment_reduce](Tensor::segment_reduce) to the rows of each segment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SegmentReduce {
+    /// The sum of the rows.
+    Sum,
+    /// The mean of the rows.
+    Mean,
+    /// The maximum of the rows.
+    Max,
+    /// The minimum of the rows.
+    Min,
+}
+
+impl<B> Tensor<B, 2>
+where
+    B: Backend,
+{
+    /// Reduces the rows of the tensor into segments, the segment of each row being given by
+    /// `segment_ids`.
+    ///
+    /// # Arguments
+    ///
+    /// * `segment_ids` - The segment of each row, of shape `[num_rows]`, lower than `num_segments`.
+    /// * `num_segments` - The number of segments.
+    /// * `reduce` - The reduction applied to the rows of each segment.
+    ///
+    /// # Returns
+    ///
+    /// The reduction of the rows of each segment, of shape `[num_segments, num_features]`. The
+    /// rows of the empty segments are filled with zeros.
+    ///
+    /// # Notes
+    ///
+    /// The sum and the mean are accumulated with [select_assign](Tensor::select_assign), whose
+    /// gradient is a gather of the upstream gradient. The maximum and the minimum compare every
+    /// row with every segment, which requires `num_segments * num_rows * num_features` elements,
+    /// and their gradient flows to the selected row of each segment only.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, SegmentReduce, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let data = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+    ///     let segment_ids = Tensor::<B, 1, Int>::from_ints([0, 2, 0], &device);
+    ///     let reduced = data.segment_reduce(segment_ids, 3, SegmentReduce::Sum);
+    ///     // [[6.0, 8.0], [0.0, 0.0], [3.0, 4.0]]
+    /// }
+    /// ```
+    pub fn segment_reduce(
+        self,
+        segment_ids: Tensor<B, 1, Int>,
+        num_segments: usize,
+        reduce: SegmentReduce,
+    ) -> Self {
+        check!(TensorCheck::segment_reduce(
+            &self.shape(),
+            &segment_ids.shape()
+        ));
+        let [num_rows, num_features] = self.dims();
+        let device = self.device();
+
+        match reduce {
+            SegmentReduce::Sum => Self::zeros([num_segments, num_features], &device).select_assign(
+                0,
+                segment_ids,
+                self,
+            ),
+            SegmentReduce::Mean => {
+                let counts = Tensor::<B, 1>::zeros([num_segments], &device).select_assign(
+                    0,
+                    segment_ids.clone(),
+                    Tensor::ones([num_rows], &device),
+                );
+                let sum = self.segment_reduce(segment_ids, num_segments, SegmentReduce::Sum);
+
+                sum.div(counts.clamp_min(1.0).unsqueeze_dim(1))
+            }
+            SegmentReduce::Max | SegmentReduce::Min => {
+                // Membership of each row in each segment, of shape [num_segments, num_rows].
+                let segments = Tensor::<B, 1, Int>::arange(0..num_segments as i64, &device)
+                    .reshape([num_segments, 1])
+                    .repeat(1, num_rows);
+                let membership = segment_ids
+                    .reshape([1, num_rows])
+                    .repeat(0, num_segments)
+                    .equal(segments)
+                    .int();
+                let empty = membership.clone().sum_dim(1).equal_elem(0);
+                // The rows of the empty segments are all reduced, so that their values stay
+                // finite until they are filled with zeros. The rows are reduced along the last
+                // dimension, of shape [num_segments, num_features, num_rows].
+                let outside = membership
+                    .add(empty.clone().int().repeat(1, num_rows))
+                    .equal_elem(0)
+                    .reshape([num_segments, 1, num_rows])
+                    .repeat(1, num_features);
+                let values = self
+                    .transpose()
+                    .reshape([1, num_features, num_rows])
+                    .repeat(0, num_segments);
+
+                let reduced = match reduce {
+                    SegmentReduce::Max => values.mask_fill(outside, f32::NEG_INFINITY).max_dim(2),
+                    _ => values.mask_fill(outside, f32::INFINITY).min_dim(2),
+                };
+
+                reduced
+                    .reshape([num_segments, num_features])
+                    .mask_fill(empty.repeat(1, num_features), 0.0)
+            }
+        }
+    }
+}
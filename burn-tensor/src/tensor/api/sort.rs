@@ -0,0 +1,125 @@
+use crate::{backend::Backend, BasicOps, Data, Element, Int, TensorKind};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Sort the elements of the tensor along the given dimension, returning the sorted values
+/// and the indices of the sorted elements in the original tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `dim` - The dimension along which the tensor will be sorted.
+/// * `descending` - Whether the elements should be sorted in descending order.
+///
+/// # Returns
+///
+/// A tuple with the sorted tensor and the indices tensor.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back on the host to be sorted, so the operation is synchronous.
+pub fn sort_with_indices<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+    descending: bool,
+) -> (K::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>)
+where
+    K::Elem: Element,
+{
+    let device = K::device(&tensor);
+    let data = K::into_data(tensor)
+        .read_sync()
+        .expect("Can't sort a tensor with async data.");
+    let shape = data.shape.clone();
+
+    let dim_size = shape.dims[dim];
+    let stride: usize = shape.dims[dim + 1..].iter().product();
+    let num_elements = shape.num_elements();
+
+    let mut values = data.value.clone();
+    let mut indices = vec![0i64; num_elements];
+
+    if num_elements > 0 {
+        let mut order: Vec<usize> = Vec::with_capacity(dim_size);
+
+        for outer in 0..num_elements / (dim_size * stride) {
+            for inner in 0..stride {
+                let offset = outer * dim_size * stride + inner;
+
+                order.clear();
+                order.extend(0..dim_size);
+                order.sort_by(|a, b| {
+                    let a = &data.value[offset + a * stride];
+                    let b = &data.value[offset + b * stride];
+                    sort_compare(a, b, descending)
+                });
+
+                for (position, index) in order.iter().enumerate() {
+                    values[offset + position * stride] = data.value[offset + index * stride];
+                    indices[offset + position * stride] = *index as i64;
+                }
+            }
+        }
+    }
+
+    let values = K::from_data(Data::new(values, shape.clone()), &device);
+    let indices = B::int_from_data(Data::new(indices, shape).convert(), &device);
+
+    (values, indices)
+}
+
+/// Compares two elements, placing NaN values last regardless of the sort order.
+///
+/// This is the ordering of the sorting operations, which the backends sorting on the host use so
+/// they give the same results as the fallback.
+///
+/// The elements are compared as `f64`, which is exact for every float element, and integers that
+/// are rounded to the same float are then compared exactly as `i64`.
+pub fn sort_compare<E: Element>(a: &E, b: &E, descending: bool) -> Ordering {
+    let a_float = a.to_f64().unwrap_or(f64::NAN);
+    let b_float = b.to_f64().unwrap_or(f64::NAN);
+
+    let ordering = match a_float.partial_cmp(&b_float) {
+        Some(Ordering::Equal) => a.to_i64().cmp(&b.to_i64()),
+        Some(ordering) => ordering,
+        // At least one of the values is NaN.
+        None => return a_float.is_nan().cmp(&b_float.is_nan()),
+    };
+
+    match descending {
+        true => ordering.reverse(),
+        false => ordering,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_compare_should_order_integers_rounded_to_the_same_float() {
+        let a = 2i64.pow(53);
+        let b = a + 1;
+
+        assert_eq!(sort_compare(&a, &b, false), Ordering::Less);
+        assert_eq!(sort_compare(&a, &b, true), Ordering::Greater);
+        assert_eq!(
+            sort_compare(&i64::MAX, &(i64::MAX - 1), false),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn sort_compare_should_place_nan_last() {
+        assert_eq!(sort_compare(&f32::NAN, &1.0, false), Ordering::Greater);
+        assert_eq!(sort_compare(&f32::NAN, &1.0, true), Ordering::Greater);
+        assert_eq!(sort_compare(&1.0, &f32::NAN, true), Ordering::Less);
+        assert_eq!(sort_compare(&0.5f32, &1.0, false), Ordering::Less);
+    }
+}
@@ -0,0 +1,146 @@
+use super::sort::sort_compare;
+use crate::{backend::Backend, BasicOps, Data, Element, Int, Shape, TensorKind};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Returns the unique elements of the tensor in ascending order, along with the index of the
+/// unique element of each element of the flattened tensor and the number of occurrences of each
+/// unique element.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+///
+/// # Returns
+///
+/// A tuple with the unique elements, the inverse indices and the counts.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back on the host to be sorted, so the operation is synchronous.
+pub fn unique<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+) -> (
+    K::Primitive<1>,
+    <Int as TensorKind<B>>::Primitive<1>,
+    <Int as TensorKind<B>>::Primitive<1>,
+)
+where
+    K::Elem: Element,
+{
+    let device = K::device(&tensor);
+    let data = K::into_data(tensor)
+        .read_sync()
+        .expect("Can't compute the unique elements of a tensor with async data.");
+    let num_elements = data.value.len();
+
+    let mut order: Vec<usize> = (0..num_elements).collect();
+    order.sort_by(|a, b| sort_compare(&data.value[*a], &data.value[*b], false));
+
+    let mut values = Vec::new();
+    let mut counts = Vec::new();
+    let mut inverse = vec![0i64; num_elements];
+
+    for index in order {
+        let value = data.value[index];
+        let is_new = match values.last() {
+            Some(last) => sort_compare(last, &value, false) != Ordering::Equal,
+            None => true,
+        };
+
+        if is_new {
+            values.push(value);
+            counts.push(0i64);
+        }
+
+        *counts.last_mut().unwrap() += 1;
+        inverse[index] = values.len() as i64 - 1;
+    }
+
+    let num_values = values.len();
+    let values = K::from_data(Data::new(values, Shape::new([num_values])), &device);
+    let inverse = B::int_from_data(
+        Data::new(inverse, Shape::new([num_elements])).convert(),
+        &device,
+    );
+    let counts = B::int_from_data(
+        Data::new(counts, Shape::new([num_values])).convert(),
+        &device,
+    );
+
+    (values, inverse, counts)
+}
+
+/// Collapses the consecutive equal slices of the tensor along the given dimension, keeping only
+/// the first slice of each group.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor.
+/// * `dim` - The dimension along which the slices are compared.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, except along the given dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back on the host to be compared, so the operation is synchronous.
+pub fn unique_consecutive<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+) -> K::Primitive<D>
+where
+    K::Elem: Element,
+{
+    let device = K::device(&tensor);
+    let data = K::into_data(tensor)
+        .read_sync()
+        .expect("Can't compute the unique elements of a tensor with async data.");
+    let mut shape = data.shape.clone();
+
+    let dim_size = shape.dims[dim];
+    let stride: usize = shape.dims[dim + 1..].iter().product();
+    let num_outer: usize = shape.dims[..dim].iter().product();
+
+    let slice = |outer: usize, index: usize| {
+        let start = (outer * dim_size + index) * stride;
+        &data.value[start..start + stride]
+    };
+    let slices_equal = |a: usize, b: usize| {
+        (0..num_outer).all(|outer| {
+            slice(outer, a)
+                .iter()
+                .zip(slice(outer, b))
+                .all(|(a, b)| sort_compare(a, b, false) == Ordering::Equal)
+        })
+    };
+
+    let mut kept: Vec<usize> = Vec::with_capacity(dim_size);
+    for index in 0..dim_size {
+        if index == 0 || !slices_equal(index - 1, index) {
+            kept.push(index);
+        }
+    }
+
+    let mut values = Vec::with_capacity(num_outer * kept.len() * stride);
+    for outer in 0..num_outer {
+        for index in kept.iter() {
+            values.extend_from_slice(slice(outer, *index));
+        }
+    }
+    shape.dims[dim] = kept.len();
+
+    K::from_data(Data::new(values, shape), &device)
+}
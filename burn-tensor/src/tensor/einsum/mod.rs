@@ -0,0 +1,398 @@
+use crate::{backend::Backend, Int, Tensor};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The rank used to represent every operand while the contraction is computed, since the rank of
+/// the intermediate results depends on the equation.
+pub(crate) const MAX_RANK: usize = 6;
+
+/// A parsed einsum equation, with one label per dimension of each operand.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Equation {
+    pub inputs: Vec<Vec<char>>,
+    pub output: Vec<char>,
+}
+
+impl Equation {
+    /// Parses an equation following the NumPy notation, e.g. `ij,jk->ik`.
+    ///
+    /// When the output is omitted, it is made of the labels appearing exactly once in the
+    /// inputs, in alphabetical order.
+    pub(crate) fn parse(equation: &str) -> Result<Self, String> {
+        let equation: String = equation.chars().filter(|c| !c.is_whitespace()).collect();
+        let (inputs, output) = match equation.split_once("->") {
+            Some((inputs, output)) => (inputs, Some(output)),
+            None => (equation.as_str(), None),
+        };
+
+        let inputs = inputs
+            .split(',')
+            .map(Self::parse_labels)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let output = match output {
+            Some(output) => {
+                let output = Self::parse_labels(output)?;
+
+                for (i, label) in output.iter().enumerate() {
+                    if output[..i].contains(label) {
+                        return Err(format!("Label '{label}' is repeated in the output."));
+                    }
+                    if !inputs.iter().any(|input| input.contains(label)) {
+                        return Err(format!(
+                            "Output label '{label}' doesn't appear in any input."
+                        ));
+                    }
+                }
+
+                output
+            }
+            None => {
+                let mut output: Vec<char> = inputs
+                    .iter()
+                    .flatten()
+                    .filter(|label| {
+                        inputs
+                            .iter()
+                            .flatten()
+                            .filter(|other| other == label)
+                            .count()
+                            == 1
+                    })
+                    .copied()
+                    .collect();
+                output.sort();
+                output
+            }
+        };
+
+        Ok(Self { inputs, output })
+    }
+
+    fn parse_labels(labels: &str) -> Result<Vec<char>, String> {
+        labels
+            .chars()
+            .map(|label| match label.is_ascii_alphabetic() {
+                true => Ok(label),
+                false => Err(format!(
+                    "Invalid character '{label}', only ASCII letters can be used as labels."
+                )),
+            })
+            .collect()
+    }
+
+    /// Returns all the distinct labels of the equation.
+    pub(crate) fn labels(&self) -> Vec<char> {
+        let mut labels: Vec<char> = Vec::new();
+
+        for label in self.inputs.iter().flatten() {
+            if !labels.contains(label) {
+                labels.push(*label);
+            }
+        }
+
+        labels
+    }
+}
+
+/// An operand of the contraction, padded to [MAX_RANK] with trailing dimensions of size 1.
+struct Operand<B: Backend> {
+    tensor: Tensor<B, MAX_RANK>,
+    labels: Vec<char>,
+    dims: Vec<usize>,
+}
+
+impl<B: Backend> Operand<B> {
+    fn new<const D: usize>(tensor: Tensor<B, D>, labels: Vec<char>) -> Self {
+        let dims = tensor.dims().to_vec();
+        let tensor = tensor.reshape(padded_shape(&dims));
+
+        Self {
+            tensor,
+            labels,
+            dims,
+        }
+    }
+
+    fn reshape(self, labels: Vec<char>, dims: Vec<usize>) -> Self {
+        Self {
+            tensor: self.tensor.reshape(padded_shape(&dims)),
+            labels,
+            dims,
+        }
+    }
+
+    /// Reorders the dimensions of the operand to follow the given labels.
+    fn permute(mut self, labels: &[char]) -> Self {
+        for (i, label) in labels.iter().enumerate() {
+            let j = self.labels.iter().position(|l| l == label).unwrap();
+
+            if i != j {
+                self.tensor = self.tensor.swap_dims(i, j);
+                self.labels.swap(i, j);
+                self.dims.swap(i, j);
+            }
+        }
+
+        self
+    }
+
+    /// Takes the diagonal of the dimensions sharing the same label, e.g. `ii->i`.
+    fn diagonal(mut self) -> Self {
+        while let Some((first, second)) = self.repeated_label() {
+            let size = self.dims[first];
+            let operand = self.move_last(first, second);
+            let device = operand.tensor.device();
+
+            // The repeated dimensions are flattened, and every (n + 1)-th element is kept,
+            // which are the elements of the diagonal.
+            let mut labels = operand.labels;
+            labels.pop();
+            let mut dims = operand.dims;
+            dims.truncate(dims.len() - 2);
+            dims.push(size * size);
+
+            let indices =
+                Tensor::<B, 1, Int>::arange_step(0..(size * size) as i64, size + 1, &device);
+            let tensor = operand
+                .tensor
+                .reshape(padded_shape(&dims))
+                .select(dims.len() - 1, indices);
+            dims.pop();
+            dims.push(size);
+
+            self = Self {
+                tensor,
+                labels,
+                dims,
+            };
+        }
+
+        self
+    }
+
+    fn repeated_label(&self) -> Option<(usize, usize)> {
+        for (second, label) in self.labels.iter().enumerate() {
+            if let Some(first) = self.labels[..second].iter().position(|l| l == label) {
+                return Some((first, second));
+            }
+        }
+
+        None
+    }
+
+    /// Moves the two given dimensions last, keeping the order of the other dimensions.
+    fn move_last(mut self, first: usize, second: usize) -> Self {
+        let mut positions: Vec<usize> = (0..self.labels.len())
+            .filter(|i| *i != first && *i != second)
+            .collect();
+        positions.push(first);
+        positions.push(second);
+
+        // Labels can be repeated, so the permutation is done on the positions.
+        let mut current: Vec<usize> = (0..self.labels.len()).collect();
+
+        for (i, position) in positions.iter().enumerate() {
+            let j = current.iter().position(|p| p == position).unwrap();
+
+            if i != j {
+                self.tensor = self.tensor.swap_dims(i, j);
+                current.swap(i, j);
+                self.labels.swap(i, j);
+                self.dims.swap(i, j);
+            }
+        }
+
+        self
+    }
+
+    /// Sums the dimensions of the given labels.
+    fn sum_labels(self, labels: &[char]) -> Self {
+        let mut tensor = self.tensor;
+        let mut kept_labels = Vec::with_capacity(self.labels.len());
+        let mut kept_dims = Vec::with_capacity(self.dims.len());
+
+        for (i, label) in self.labels.iter().enumerate() {
+            if labels.contains(label) {
+                tensor = tensor.sum_dim(i);
+            } else {
+                kept_labels.push(*label);
+                kept_dims.push(self.dims[i]);
+            }
+        }
+
+        Self { tensor, ..self }.reshape(kept_labels, kept_dims)
+    }
+
+    fn dim(&self, label: &char) -> usize {
+        self.dims[self.labels.iter().position(|l| l == label).unwrap()]
+    }
+
+    fn size(&self, labels: &[char]) -> usize {
+        labels.iter().map(|label| self.dim(label)).product()
+    }
+
+    /// Contracts two operands with a batched matrix multiplication, keeping only the given labels.
+    fn contract(self, other: Self, keep: &[char]) -> Self {
+        let shared = |label: &char| self.labels.contains(label) && other.labels.contains(label);
+
+        // Labels only present in one operand and not needed anymore can be summed right away.
+        let lhs_unused: Vec<char> = self
+            .labels
+            .iter()
+            .filter(|label| !shared(label) && !keep.contains(label))
+            .copied()
+            .collect();
+        let rhs_unused: Vec<char> = other
+            .labels
+            .iter()
+            .filter(|label| !shared(label) && !keep.contains(label))
+            .copied()
+            .collect();
+
+        let batch: Vec<char> = self
+            .labels
+            .iter()
+            .filter(|label| shared(label) && keep.contains(label))
+            .copied()
+            .collect();
+        let contracted: Vec<char> = self
+            .labels
+            .iter()
+            .filter(|label| shared(label) && !keep.contains(label))
+            .copied()
+            .collect();
+
+        let lhs = self.sum_labels(&lhs_unused);
+        let rhs = other.sum_labels(&rhs_unused);
+
+        let lhs_only: Vec<char> = lhs
+            .labels
+            .iter()
+            .filter(|label| !rhs.labels.contains(label))
+            .copied()
+            .collect();
+        let rhs_only: Vec<char> = rhs
+            .labels
+            .iter()
+            .filter(|label| !lhs.labels.contains(label))
+            .copied()
+            .collect();
+
+        let size_batch = lhs.size(&batch);
+        let size_lhs = lhs.size(&lhs_only);
+        let size_rhs = rhs.size(&rhs_only);
+        let size_contracted = lhs.size(&contracted);
+
+        let labels: Vec<char> = [&batch[..], &lhs_only[..], &rhs_only[..]].concat();
+        let dims: Vec<usize> = labels
+            .iter()
+            .map(|label| match rhs_only.contains(label) {
+                true => rhs.dim(label),
+                false => lhs.dim(label),
+            })
+            .collect();
+
+        let lhs_order: Vec<char> = [&batch[..], &lhs_only[..], &contracted[..]].concat();
+        let rhs_order: Vec<char> = [&batch[..], &contracted[..], &rhs_only[..]].concat();
+
+        let lhs: Tensor<B, 3> =
+            lhs.permute(&lhs_order)
+                .tensor
+                .reshape([size_batch, size_lhs, size_contracted]);
+        let rhs: Tensor<B, 3> =
+            rhs.permute(&rhs_order)
+                .tensor
+                .reshape([size_batch, size_contracted, size_rhs]);
+
+        Operand {
+            tensor: lhs.matmul(rhs).reshape(padded_shape(&dims)),
+            labels,
+            dims,
+        }
+    }
+}
+
+fn padded_shape(dims: &[usize]) -> [usize; MAX_RANK] {
+    let mut shape = [1; MAX_RANK];
+    shape[..dims.len()].copy_from_slice(dims);
+    shape
+}
+
+/// Computes the einsum of the given tensors, see [Tensor::einsum](Tensor::einsum).
+///
+/// The operands are contracted from left to right, each contraction being a batched matrix
+/// multiplication, so the operation is differentiable through the existing operations.
+pub(crate) fn einsum<B: Backend, const D: usize, const D2: usize>(
+    equation: &str,
+    tensors: Vec<Tensor<B, D>>,
+) -> Tensor<B, D2> {
+    let equation = Equation::parse(equation).unwrap();
+    let num_operands = tensors.len();
+
+    let mut operands = tensors
+        .into_iter()
+        .zip(equation.inputs.iter())
+        .map(|(tensor, labels)| Operand::new(tensor, labels.clone()).diagonal());
+
+    let mut result = operands.next().unwrap();
+
+    for (i, operand) in operands.enumerate() {
+        // Labels are kept while they are part of the output or of the following operands.
+        let keep: Vec<char> = equation.inputs[i + 2..num_operands]
+            .iter()
+            .flatten()
+            .chain(equation.output.iter())
+            .copied()
+            .collect();
+
+        result = result.contract(operand, &keep);
+    }
+
+    let unused: Vec<char> = result
+        .labels
+        .iter()
+        .filter(|label| !equation.output.contains(label))
+        .copied()
+        .collect();
+    let result = result.sum_labels(&unused).permute(&equation.output);
+
+    let mut shape = [1; D2];
+    shape[..result.dims.len()].copy_from_slice(&result.dims);
+
+    result.tensor.reshape(shape)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn should_parse_explicit_equation() {
+        let equation = Equation::parse("bij, bjk -> bik").unwrap();
+
+        assert_eq!(
+            equation,
+            Equation {
+                inputs: vec![vec!['b', 'i', 'j'], vec!['b', 'j', 'k']],
+                output: vec!['b', 'i', 'k'],
+            }
+        );
+    }
+
+    #[test]
+    fn should_parse_implicit_equation_in_alphabetical_order() {
+        let equation = Equation::parse("kj,ji").unwrap();
+
+        assert_eq!(equation.output, vec!['i', 'k']);
+    }
+
+    #[test]
+    fn should_not_parse_invalid_labels() {
+        assert!(Equation::parse("i...,ij->j").is_err());
+        assert!(Equation::parse("ij,jk->ii").is_err());
+        assert!(Equation::parse("ij,jk->il").is_err());
+    }
+}
@@ -9,6 +9,8 @@ pub trait Element:
     + ElementRandom
     + ElementConversion
     + ElementPrecision
+    + ElementLimits
+    + ElementNextAfter
     + core::fmt::Debug
     + core::fmt::Display
     + Default
@@ -75,6 +77,84 @@ pub trait ElementPrecision {
     fn precision() -> Precision;
 }
 
+/// Element trait for the limits of the finite values of a tensor.
+pub trait ElementLimits {
+    /// The smallest finite value.
+    const MIN: Self;
+    /// The largest finite value.
+    const MAX: Self;
+}
+
+/// Element trait for the next representable value of a tensor.
+pub trait ElementNextAfter {
+    /// Returns the next representable value after the element in the direction of `toward`.
+    ///
+    /// For floats, the value is computed from the IEEE 754 representation of the element, and
+    /// NaN is returned when either value is NaN. For integers, the element is moved by one.
+    fn next_after(self, toward: Self) -> Self;
+}
+
+/// Implements [ElementNextAfter] for a float type with its bit representation.
+macro_rules! impl_next_after_float {
+    ($type:ident) => {
+        impl ElementNextAfter for $type {
+            fn next_after(self, toward: Self) -> Self {
+                if self.is_nan() || toward.is_nan() {
+                    return $type::NAN;
+                }
+                if self == toward {
+                    return toward;
+                }
+
+                let zero = $type::from_bits(0);
+                // The smallest subnormal value, with the sign of the direction.
+                if self == zero {
+                    let smallest = $type::from_bits(1);
+                    return match toward > zero {
+                        true => smallest,
+                        false => -smallest,
+                    };
+                }
+
+                // The magnitude of the value grows with its representation, whatever its sign.
+                let bits = self.to_bits();
+                let bits = match (toward > self) == (self > zero) {
+                    true => bits + 1,
+                    false => bits - 1,
+                };
+
+                $type::from_bits(bits)
+            }
+        }
+    };
+}
+
+/// Implements [ElementNextAfter] for an integer type.
+macro_rules! impl_next_after_int {
+    ($type:ident) => {
+        impl ElementNextAfter for $type {
+            fn next_after(self, toward: Self) -> Self {
+                match self.cmp(&toward) {
+                    core::cmp::Ordering::Less => self + 1,
+                    core::cmp::Ordering::Greater => self - 1,
+                    core::cmp::Ordering::Equal => self,
+                }
+            }
+        }
+    };
+}
+
+impl_next_after_float!(f64);
+impl_next_after_float!(f32);
+impl_next_after_float!(f16);
+impl_next_after_float!(bf16);
+impl_next_after_int!(i64);
+impl_next_after_int!(i32);
+impl_next_after_int!(u32);
+impl_next_after_int!(i16);
+impl_next_after_int!(i8);
+impl_next_after_int!(u8);
+
 /// Macro to implement the element trait for a type.
 #[macro_export]
 macro_rules! make_element {
@@ -102,6 +182,11 @@ macro_rules! make_element {
             }
         }
 
+        impl ElementLimits for $type {
+            const MIN: Self = $type::MIN;
+            const MAX: Self = $type::MAX;
+        }
+
         impl ElementRandom for $type {
             fn random<R: RngCore>(distribution: Distribution, rng: &mut R) -> Self {
                 #[allow(clippy::redundant_closure_call)]
@@ -1,3 +1,4 @@
+pub(crate) mod einsum;
 pub(crate) mod stats;
 
 mod api;
@@ -28,6 +29,13 @@ pub mod module;
 /// Operations on tensors module.
 pub mod ops;
 
+/// The signal processing module.
+pub mod signal;
+
+/// The [safetensors](https://huggingface.co/docs/safetensors) serialization module.
+#[cfg(feature = "safetensors")]
+pub mod safetensors;
+
 #[cfg(feature = "experimental-named-tensor")]
 mod named;
 #[cfg(feature = "experimental-named-tensor")]
@@ -1,7 +1,12 @@
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions, UnfoldOptions},
-    Int, Tensor,
+    check,
+    check::TensorCheck,
+    ops::{
+        ConvOptions, ConvTransposeOptions, GridSampleMode, GridSamplePaddingMode, InterpolateMode,
+        UnfoldOptions,
+    },
+    Bool, Int, Tensor,
 };
 
 /// Applies the [embedding module](crate::ops::ModuleOps::embedding).
@@ -219,3 +224,163 @@ where
 {
     Tensor::new(B::adaptive_avg_pool1d(x.primitive, output_size))
 }
+
+/// Applies a [2D interpolation](crate::ops::ModuleOps::interpolate).
+pub fn interpolate<B>(
+    x: Tensor<B, 4>,
+    output_size: [usize; 2],
+    mode: InterpolateMode,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(B::interpolate(x.primitive, output_size, mode))
+}
+
+/// Applies a [2D grid sample](crate::ops::ModuleOps::grid_sample).
+pub fn grid_sample<B>(
+    input: Tensor<B, 4>,
+    grid: Tensor<B, 4>,
+    mode: GridSampleMode,
+    padding_mode: GridSamplePaddingMode,
+    align_corners: bool,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(B::grid_sample(
+        input.primitive,
+        grid.primitive,
+        mode,
+        padding_mode,
+        align_corners,
+    ))
+}
+
+/// Applies the [rotary position embedding](crate::ops::ModuleOps::rope_embed) to the queries and
+/// the keys of an attention layer, as described in the paper
+/// [RoFormer: Enhanced Transformer with Rotary Position Embedding](https://arxiv.org/abs/2104.09864).
+///
+/// The pairs of elements `(x[i], x[i + head_dim / 2])` of each head are rotated by the angle of
+/// their position, whose cosines and sines are given.
+///
+/// # Shapes
+///
+/// - query: `[batch_size, num_query_heads, seq_length, head_dim]`
+/// - key: `[batch_size, num_kv_heads, seq_length, head_dim]`
+/// - cos: `[seq_length, head_dim / 2]`
+/// - sin: `[seq_length, head_dim / 2]`
+pub fn rope_embed<B>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    cos: Tensor<B, 2>,
+    sin: Tensor<B, 2>,
+) -> (Tensor<B, 4>, Tensor<B, 4>)
+where
+    B: Backend,
+{
+    check!(TensorCheck::rope_embed(
+        &query.shape(),
+        &key.shape(),
+        &cos.shape(),
+        &sin.shape()
+    ));
+
+    let query = B::rope_embed(
+        query.primitive,
+        cos.primitive.clone(),
+        sin.primitive.clone(),
+    );
+    let key = B::rope_embed(key.primitive, cos.primitive, sin.primitive);
+
+    (Tensor::new(query), Tensor::new(key))
+}
+
+/// Applies the [flash attention](crate::ops::ModuleOps::flash_attention), computing the scaled
+/// dot-product attention without materializing the attention weights, as described in the paper
+/// [FlashAttention-2: Faster Attention with Better Parallelism and Work Partitioning](https://arxiv.org/abs/2307.08691).
+///
+/// When `causal` is true, each query only attends to the keys up to its position, the last query
+/// being aligned with the last key.
+///
+/// # Shapes
+///
+/// - query: `[batch_size, num_heads, seq_length_1, head_dim]`
+/// - key: `[batch_size, num_heads, seq_length_2, head_dim]`
+/// - value: `[batch_size, num_heads, seq_length_2, head_dim_value]`
+/// - output: `[batch_size, num_heads, seq_length_1, head_dim_value]`
+pub fn flash_attention<B>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    causal: bool,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::flash_attention(
+        &query.shape(),
+        &key.shape(),
+        &value.shape(),
+        causal
+    ));
+
+    Tensor::new(B::flash_attention(
+        query.primitive,
+        key.primitive,
+        value.primitive,
+        causal,
+    ))
+}
+
+/// Computes the scaled dot-product attention `softmax(Q K^T / sqrt(d)) V` as a single operation,
+/// so that backends can provide a fused implementation.
+///
+/// # Arguments
+///
+/// * `query` - The queries.
+/// * `key` - The keys.
+/// * `value` - The values.
+/// * `mask` - The optional attention mask, where the attention scores are masked when the mask is
+///   true. Contrary to PyTorch, a true value excludes the key from the attention.
+/// * `dropout_p` - The probability of dropping the attention weights, which should be zero
+///   during inference.
+/// * `is_causal` - Whether each query only attends to the keys up to its position, the last query
+///   being aligned with the last key.
+///
+/// # Shapes
+///
+/// - query: `[batch_size, num_heads, seq_length_1, head_dim]`
+/// - key: `[batch_size, num_heads, seq_length_2, head_dim]`
+/// - value: `[batch_size, num_heads, seq_length_2, head_dim_value]`
+/// - mask: `[batch_size or 1, num_heads or 1, seq_length_1, seq_length_2]`
+/// - output: `[batch_size, num_heads, seq_length_1, head_dim_value]`
+pub fn scaled_dot_product_attention<B>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    mask: Option<Tensor<B, 4, Bool>>,
+    dropout_p: f64,
+    is_causal: bool,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::scaled_dot_product_attention(
+        &query.shape(),
+        &key.shape(),
+        &value.shape(),
+        mask.as_ref().map(|mask| mask.shape()).as_ref(),
+        dropout_p,
+        is_causal
+    ));
+
+    Tensor::new(B::scaled_dot_product_attention(
+        query.primitive,
+        key.primitive,
+        value.primitive,
+        mask.map(|mask| mask.primitive),
+        dropout_p,
+        is_causal,
+    ))
+}
@@ -145,4 +145,45 @@ pub trait ActivationOps<B: Backend> {
         );
         B::float_mul(value, grad)
     }
+
+    /// Applies the LogSigmoid activation function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn log_sigmoid<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        // log_sigmoid(x) = min(x, 0) - log(1 + exp(-|x|)), so that the exponential never
+        // overflows.
+        let tensor_full = B::float_to_full_precision(&tensor);
+        let lower = B::FullPrecisionBackend::float_clamp_max(tensor_full.clone(), 0.0.elem());
+        let correction = B::FullPrecisionBackend::float_log1p(B::FullPrecisionBackend::float_exp(
+            B::FullPrecisionBackend::float_neg(B::FullPrecisionBackend::float_abs(tensor_full)),
+        ));
+        let tensor_tmp = B::FullPrecisionBackend::float_sub(lower, correction);
+
+        B::float_from_full_precision(tensor_tmp)
+    }
+
+    /// Applies the LogSigmoid activation function backward.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The input tensor of the log sigmoid function.
+    /// * `grad` - The gradient.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn log_sigmoid_backward<const D: usize>(
+        x: FloatTensor<B, D>,
+        grad: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        // d/dx log_sigmoid(x) = 1 - sigmoid(x) = sigmoid(-x)
+        let value = B::sigmoid(B::float_neg(x));
+        B::float_mul(value, grad)
+    }
 }
@@ -1,5 +1,5 @@
 use super::{BoolTensor, Device, FloatTensor, IntTensor};
-use crate::{backend::Backend, chunk, narrow, tensor::Shape, Bool, Data};
+use crate::{backend::Backend, chunk, narrow, roll, tensor::Shape, Bool, Data, ElementConversion};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
@@ -106,6 +106,16 @@ pub trait BoolTensorOps<B: Backend> {
         device: &Device<B>,
     ) -> BoolTensor<B, D>;
 
+    /// Moves the tensor to the device without waiting for the transfer to complete.
+    ///
+    /// The tensor is immediately available by default.
+    fn bool_to_device_async<const D: usize>(
+        tensor: BoolTensor<B, D>,
+        device: &Device<B>,
+    ) -> Reader<BoolTensor<B, D>> {
+        Reader::Concrete(B::bool_to_device(tensor, device))
+    }
+
     /// Reshapes the tensor.
     ///
     /// # Arguments
@@ -153,6 +163,32 @@ pub trait BoolTensorOps<B: Backend> {
         value: BoolTensor<B, D1>,
     ) -> BoolTensor<B, D1>;
 
+    /// Broadcasts the tensor to the given shape, the dimensions of size 1 being expanded.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `shape` - The shape to broadcast to, of the same rank as the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor broadcast to the given shape.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation repeats the expanded dimensions. The returned tensor owns its
+    /// data: it must not share memory with the input, since the in-place operations would
+    /// otherwise write the same element several times.
+    fn bool_expand<const D: usize>(tensor: BoolTensor<B, D>, shape: Shape<D>) -> BoolTensor<B, D> {
+        let current = B::bool_shape(&tensor);
+
+        (0..D)
+            .filter(|dim| current.dims[*dim] != shape.dims[*dim])
+            .fold(tensor, |tensor, dim| {
+                B::bool_repeat(tensor, dim, shape.dims[dim])
+            })
+    }
+
     /// Repeats one dimension of the tensor a given number of times along that dimension.
     ///
     /// # Arguments
@@ -229,6 +265,50 @@ pub trait BoolTensorOps<B: Backend> {
     /// The tensor with the result of the negation.
     fn bool_not<const D: usize>(tensor: BoolTensor<B, D>) -> BoolTensor<B, D>;
 
+    /// Computes the logical and of two boolean tensors element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the result of the logical and.
+    fn bool_and<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        let product = B::int_mul(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_equal_elem(product, 1.elem())
+    }
+
+    /// Computes the logical or of two boolean tensors element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the result of the logical or.
+    fn bool_or<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        let sum = B::int_add(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_greater_elem(sum, 0.elem())
+    }
+
+    /// Computes the logical exclusive or of two boolean tensors element-wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the result of the logical exclusive or.
+    fn bool_xor<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        B::bool_not(B::bool_equal(lhs, rhs))
+    }
+
     /// Transposes a bool tensor.
     ///
     /// # Arguments
@@ -302,4 +382,24 @@ pub trait BoolTensorOps<B: Backend> {
     ) -> Vec<BoolTensor<B, D>> {
         chunk::<B, D, Bool>(tensor, chunks, dim)
     }
+
+    /// Rolls the tensor along the given dimensions, the elements shifted beyond the last position
+    /// being re-introduced at the first position.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `shifts` - The number of places by which the elements are shifted along each dimension.
+    /// * `dims` - The dimensions along which the tensor will be rolled.
+    ///
+    /// # Returns
+    ///
+    /// The rolled tensor.
+    fn bool_roll<const D: usize>(
+        tensor: BoolTensor<B, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> BoolTensor<B, D> {
+        roll::<B, D, Bool>(tensor, shifts, dims)
+    }
 }
@@ -1,6 +1,9 @@
 use super::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
 use crate::{backend::Backend, tensor::Shape, Data, ElementConversion, Int};
-use crate::{tensor::api::chunk, tensor::api::narrow};
+use crate::{
+    tensor::api::chunk, tensor::api::narrow, tensor::api::nonzero, tensor::api::roll,
+    tensor::api::unique, tensor::api::unique_consecutive,
+};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
@@ -88,6 +91,16 @@ pub trait IntTensorOps<B: Backend> {
         device: &Device<B>,
     ) -> IntTensor<B, D>;
 
+    /// Moves the tensor to the given device without waiting for the transfer to complete.
+    ///
+    /// The tensor is immediately available by default.
+    fn int_to_device_async<const D: usize>(
+        tensor: IntTensor<B, D>,
+        device: &Device<B>,
+    ) -> Reader<IntTensor<B, D>> {
+        Reader::Concrete(B::int_to_device(tensor, device))
+    }
+
     /// Reshapes the tensor.
     ///
     /// # Arguments
@@ -249,6 +262,32 @@ pub trait IntTensorOps<B: Backend> {
         value: IntTensor<B, D>,
     ) -> IntTensor<B, D>;
 
+    /// Broadcasts the tensor to the given shape, the dimensions of size 1 being expanded.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `shape` - The shape to broadcast to, of the same rank as the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor broadcast to the given shape.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation repeats the expanded dimensions. The returned tensor owns its
+    /// data: it must not share memory with the input, since the in-place operations would
+    /// otherwise write the same element several times.
+    fn int_expand<const D: usize>(tensor: IntTensor<B, D>, shape: Shape<D>) -> IntTensor<B, D> {
+        let current = B::int_shape(&tensor);
+
+        (0..D)
+            .filter(|dim| current.dims[*dim] != shape.dims[*dim])
+            .fold(tensor, |tensor, dim| {
+                B::int_repeat(tensor, dim, shape.dims[dim])
+            })
+    }
+
     /// Repeats the tensor along the given dimension the given number of times.
     ///
     /// # Arguments
@@ -959,6 +998,73 @@ pub trait IntTensorOps<B: Backend> {
         chunk::<B, D, Int>(tensor, chunks, dim)
     }
 
+    /// Rolls the tensor along the given dimensions, the elements shifted beyond the last position
+    /// being re-introduced at the first position.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `shifts` - The number of places by which the elements are shifted along each dimension.
+    /// * `dims` - The dimensions along which the tensor will be rolled.
+    ///
+    /// # Returns
+    ///
+    /// The rolled tensor.
+    fn int_roll<const D: usize>(
+        tensor: IntTensor<B, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> IntTensor<B, D> {
+        roll::<B, D, Int>(tensor, shifts, dims)
+    }
+
+    /// Returns the unique elements of the tensor in ascending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the unique elements, the index of the unique element of each element of the
+    /// flattened tensor and the number of occurrences of each unique element.
+    fn int_unique<const D: usize>(
+        tensor: IntTensor<B, D>,
+    ) -> (IntTensor<B, 1>, IntTensor<B, 1>, IntTensor<B, 1>) {
+        unique::<B, D, Int>(tensor)
+    }
+
+    /// Returns the indices of the non-zero elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// An int tensor of shape `[N, D]` with the indices of the `N` non-zero elements in row-major
+    /// order.
+    fn int_nonzero<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 2> {
+        nonzero::<B, D, Int>(tensor)
+    }
+
+    /// Collapses the consecutive equal slices of the tensor along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which the slices are compared.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the first slice of each group of consecutive equal slices.
+    fn int_unique_consecutive<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+    ) -> IntTensor<B, D> {
+        unique_consecutive::<B, D, Int>(tensor, dim)
+    }
+
     /// Creates a new tensor with values from the given range with the given step size.
     ///
     /// # Arguments
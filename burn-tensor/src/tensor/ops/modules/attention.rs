@@ -0,0 +1,141 @@
+use crate::backend::Backend;
+use crate::ops::{BoolTensor, FloatTensor};
+use crate::{activation, Bool, Data, Distribution, Shape, Tensor};
+use alloc::vec::Vec;
+
+use super::AttentionBackward;
+
+/// Computes the scaled dot-product attention with matrix multiplications and a softmax,
+/// materializing the attention weights.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn scaled_dot_product_attention_from_decomposition<B: Backend>(
+    query: FloatTensor<B, 4>,
+    key: FloatTensor<B, 4>,
+    value: FloatTensor<B, 4>,
+    mask: Option<BoolTensor<B, 4>>,
+    dropout_p: f64,
+    causal: bool,
+) -> FloatTensor<B, 4> {
+    let mut weights = attention_weights::<B>(
+        Tensor::new(query),
+        Tensor::new(key),
+        mask.map(Tensor::new),
+        causal,
+    );
+
+    if dropout_p > 0.0 {
+        let prob_keep = 1.0 - dropout_p;
+        let keep = weights.random_like(Distribution::Bernoulli(prob_keep));
+        weights = weights.mul(keep).div_scalar(prob_keep);
+    }
+
+    weights.matmul(Tensor::new(value)).into_primitive()
+}
+
+/// Computes the gradients of the scaled dot-product attention without dropout, recomputing the
+/// attention weights from the queries and keys.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn scaled_dot_product_attention_backward_from_decomposition<B: Backend>(
+    query: FloatTensor<B, 4>,
+    key: FloatTensor<B, 4>,
+    value: FloatTensor<B, 4>,
+    mask: Option<BoolTensor<B, 4>>,
+    output_grad: FloatTensor<B, 4>,
+    causal: bool,
+) -> AttentionBackward<B> {
+    let query = Tensor::<B, 4>::new(query);
+    let key = Tensor::<B, 4>::new(key);
+    let value = Tensor::<B, 4>::new(value);
+    let output_grad = Tensor::<B, 4>::new(output_grad);
+    let scale = scale(&query);
+
+    let weights = attention_weights::<B>(query.clone(), key.clone(), mask.map(Tensor::new), causal);
+    let value_grad = weights.clone().transpose().matmul(output_grad.clone());
+
+    // Gradient of the softmax: dS = P * (dP - sum(dP * P)).
+    let weights_grad = output_grad.matmul(value.transpose());
+    let scores_grad =
+        weights.clone() * (weights_grad.clone() - (weights_grad * weights).sum_dim(3));
+    let scores_grad = scores_grad.mul_scalar(scale);
+
+    let query_grad = scores_grad.clone().matmul(key);
+    let key_grad = scores_grad.transpose().matmul(query);
+
+    AttentionBackward::new(
+        query_grad.into_primitive(),
+        key_grad.into_primitive(),
+        value_grad.into_primitive(),
+    )
+}
+
+fn attention_weights<B: Backend>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    mask: Option<Tensor<B, 4, Bool>>,
+    causal: bool,
+) -> Tensor<B, 4> {
+    let scale = scale(&query);
+    let mut scores = query.matmul(key.transpose()).mul_scalar(scale);
+    let [batch_size, num_heads, seq_length_1, seq_length_2] = scores.dims();
+
+    if let Some(mask) = mask {
+        let [mask_batch_size, mask_num_heads, _, _] = mask.dims();
+        let mask = match mask_batch_size == batch_size {
+            true => mask,
+            false => mask.repeat(0, batch_size),
+        };
+        let mask = match mask_num_heads == num_heads {
+            true => mask,
+            false => mask.repeat(1, num_heads),
+        };
+
+        scores = scores.mask_fill(mask, f32::NEG_INFINITY);
+    }
+
+    if causal {
+        let mask = causal_mask::<B>(seq_length_1, seq_length_2, &scores.device());
+        let mask = mask
+            .reshape([1, 1, seq_length_1, seq_length_2])
+            .repeat(0, batch_size)
+            .repeat(1, num_heads);
+
+        scores = scores.mask_fill(mask, f32::NEG_INFINITY);
+    }
+
+    activation::softmax(scores, 3)
+}
+
+fn scale<B: Backend>(query: &Tensor<B, 4>) -> f32 {
+    let [_, _, _, head_dim] = query.dims();
+
+    1.0 / libm::sqrtf(head_dim as f32)
+}
+
+/// The mask of the keys following each query, the last query being aligned with the last key.
+fn causal_mask<B: Backend>(
+    seq_length_1: usize,
+    seq_length_2: usize,
+    device: &B::Device,
+) -> Tensor<B, 2, Bool> {
+    let offset = seq_length_2 - seq_length_1;
+    let mask: Vec<bool> = (0..seq_length_1)
+        .flat_map(|i| (0..seq_length_2).map(move |j| j > i + offset))
+        .collect();
+
+    Tensor::from_data(
+        Data::new(mask, Shape::new([seq_length_1, seq_length_2])),
+        device,
+    )
+}
@@ -1,7 +1,7 @@
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+use super::{attention, conv, grid_sample, interpolate, pool, rope, unfold::unfold4d_using_conv2d};
 use crate::{
     backend::Backend,
-    ops::{FloatTensor, IntTensor},
+    ops::{BoolTensor, FloatTensor, IntTensor},
     Shape,
 };
 
@@ -18,6 +18,21 @@ pub struct Conv2dBackward<B: Backend> {
     pub bias_grad: Option<FloatTensor<B, 1>>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by
+/// [flash attention](ModuleOps::flash_attention) and
+/// [scaled dot-product attention](ModuleOps::scaled_dot_product_attention).
+#[derive(new)]
+pub struct AttentionBackward<B: Backend> {
+    /// Query gradient.
+    pub query_grad: FloatTensor<B, 4>,
+
+    /// Key gradient.
+    pub key_grad: FloatTensor<B, 4>,
+
+    /// Value gradient.
+    pub value_grad: FloatTensor<B, 4>,
+}
+
 /// Gradient computed during the backward pass for each tensor used by [max_pool1d](ModuleOps::max_pool1d).
 #[derive(new)]
 pub struct MaxPool1dBackward<B: Backend> {
@@ -114,6 +129,47 @@ pub struct UnfoldOptions {
     pub dilation: [usize; 2],
 }
 
+/// Algorithm used to compute the output values of [interpolate](ModuleOps::interpolate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InterpolateMode {
+    /// Each output value is the value of the nearest input pixel.
+    Nearest,
+
+    /// Each output value is a linear interpolation of the 2x2 nearest input pixels.
+    Bilinear {
+        /// If the corner pixels of the input and the output are aligned, in which case the
+        /// values of the corner pixels are preserved.
+        align_corners: bool,
+    },
+
+    /// Each output value is a cubic interpolation of the 4x4 nearest input pixels.
+    Bicubic,
+}
+
+/// Algorithm used to compute the output values of [grid sample](ModuleOps::grid_sample).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GridSampleMode {
+    /// Each output value is a linear interpolation of the 2x2 input pixels nearest to the
+    /// sampled location.
+    Bilinear,
+
+    /// Each output value is the value of the input pixel nearest to the sampled location.
+    Nearest,
+}
+
+/// How [grid sample](ModuleOps::grid_sample) handles locations outside of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GridSamplePaddingMode {
+    /// Values outside of the input are zeros.
+    Zeros,
+
+    /// Values outside of the input are the values of the nearest border pixels.
+    Border,
+
+    /// Locations outside of the input are reflected by the borders until they fall inside.
+    Reflection,
+}
+
 /// Module operations trait.
 pub trait ModuleOps<B: Backend> {
     /// Embedding operation.
@@ -404,6 +460,177 @@ pub trait ModuleOps<B: Backend> {
         )
     }
 
+    /// Two dimensional interpolation, resizing the spatial dimensions to the given output size.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    /// output: [batch_size, channels, output_size[0], output_size[1]],
+    fn interpolate(
+        x: FloatTensor<B, 4>,
+        output_size: [usize; 2],
+        mode: InterpolateMode,
+    ) -> FloatTensor<B, 4> {
+        interpolate::interpolate_from_select::<B>(x, output_size, mode)
+    }
+
+    /// Backward pass for the [interpolate](ModuleOps::interpolate) operation.
+    fn interpolate_backward(
+        x: FloatTensor<B, 4>,
+        grad: FloatTensor<B, 4>,
+        output_size: [usize; 2],
+        mode: InterpolateMode,
+    ) -> FloatTensor<B, 4> {
+        interpolate::interpolate_backward_from_select_assign::<B>(x, grad, output_size, mode)
+    }
+
+    /// Samples the input at the locations given by the grid.
+    ///
+    /// The locations are normalized so that `-1` and `1` are the left and right (or top and
+    /// bottom) borders of the input, with `grid[.., .., .., 0]` being the horizontal coordinate
+    /// and `grid[.., .., .., 1]` the vertical coordinate. When `align_corners` is true, they are
+    /// the centers of the corner pixels instead of their edges.
+    ///
+    /// # Shapes
+    ///
+    /// input: [batch_size, channels, height, width],
+    /// grid: [batch_size, height_out, width_out, 2],
+    /// output: [batch_size, channels, height_out, width_out],
+    fn grid_sample(
+        input: FloatTensor<B, 4>,
+        grid: FloatTensor<B, 4>,
+        mode: GridSampleMode,
+        padding_mode: GridSamplePaddingMode,
+        align_corners: bool,
+    ) -> FloatTensor<B, 4> {
+        grid_sample::grid_sample_from_gather::<B>(input, grid, mode, padding_mode, align_corners)
+    }
+
+    /// Computes the scaled dot-product attention `softmax(Q K^T / sqrt(d)) V` without
+    /// materializing the attention weights.
+    ///
+    /// When `causal` is true, each query only attends to the keys up to its position, the last
+    /// query being aligned with the last key.
+    ///
+    /// # Shapes
+    ///
+    /// query: [batch_size, num_heads, seq_length_1, head_dim],
+    /// key: [batch_size, num_heads, seq_length_2, head_dim],
+    /// value: [batch_size, num_heads, seq_length_2, head_dim_value],
+    /// output: [batch_size, num_heads, seq_length_1, head_dim_value],
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation computes the attention weights with matrix multiplications,
+    /// backends should implement a fused kernel computing the attention in tiles.
+    fn flash_attention(
+        query: FloatTensor<B, 4>,
+        key: FloatTensor<B, 4>,
+        value: FloatTensor<B, 4>,
+        causal: bool,
+    ) -> FloatTensor<B, 4> {
+        attention::scaled_dot_product_attention_from_decomposition::<B>(
+            query, key, value, None, 0.0, causal,
+        )
+    }
+
+    /// Backward pass for the [flash attention](ModuleOps::flash_attention) operation, recomputing
+    /// the attention weights.
+    fn flash_attention_backward(
+        query: FloatTensor<B, 4>,
+        key: FloatTensor<B, 4>,
+        value: FloatTensor<B, 4>,
+        output_grad: FloatTensor<B, 4>,
+        causal: bool,
+    ) -> AttentionBackward<B> {
+        attention::scaled_dot_product_attention_backward_from_decomposition::<B>(
+            query,
+            key,
+            value,
+            None,
+            output_grad,
+            causal,
+        )
+    }
+
+    /// Computes the scaled dot-product attention `softmax(Q K^T / sqrt(d)) V`.
+    ///
+    /// The masked attention scores, where the mask is true, are set to negative infinity before
+    /// the softmax. When `causal` is true, each query only attends to the keys up to its position,
+    /// the last query being aligned with the last key. The dropout is applied on the attention
+    /// weights with the probability `dropout_p`.
+    ///
+    /// # Shapes
+    ///
+    /// query: [batch_size, num_heads, seq_length_1, head_dim],
+    /// key: [batch_size, num_heads, seq_length_2, head_dim],
+    /// value: [batch_size, num_heads, seq_length_2, head_dim_value],
+    /// mask: [batch_size or 1, num_heads or 1, seq_length_1, seq_length_2],
+    /// output: [batch_size, num_heads, seq_length_1, head_dim_value],
+    ///
+    /// # Remarks
+    ///
+    /// Without mask and dropout, the default implementation uses the
+    /// [flash attention](ModuleOps::flash_attention), otherwise it computes the attention weights
+    /// with matrix multiplications.
+    fn scaled_dot_product_attention(
+        query: FloatTensor<B, 4>,
+        key: FloatTensor<B, 4>,
+        value: FloatTensor<B, 4>,
+        mask: Option<BoolTensor<B, 4>>,
+        dropout_p: f64,
+        causal: bool,
+    ) -> FloatTensor<B, 4> {
+        match (mask, dropout_p > 0.0) {
+            (None, false) => B::flash_attention(query, key, value, causal),
+            (mask, _) => attention::scaled_dot_product_attention_from_decomposition::<B>(
+                query, key, value, mask, dropout_p, causal,
+            ),
+        }
+    }
+
+    /// Backward pass for the [scaled dot-product attention](ModuleOps::scaled_dot_product_attention)
+    /// operation without dropout, recomputing the attention weights.
+    fn scaled_dot_product_attention_backward(
+        query: FloatTensor<B, 4>,
+        key: FloatTensor<B, 4>,
+        value: FloatTensor<B, 4>,
+        mask: Option<BoolTensor<B, 4>>,
+        output_grad: FloatTensor<B, 4>,
+        causal: bool,
+    ) -> AttentionBackward<B> {
+        match mask {
+            None => B::flash_attention_backward(query, key, value, output_grad, causal),
+            mask => attention::scaled_dot_product_attention_backward_from_decomposition::<B>(
+                query,
+                key,
+                value,
+                mask,
+                output_grad,
+                causal,
+            ),
+        }
+    }
+
+    /// Applies the rotary position embedding, rotating the pairs of elements `(x[i], x[i + d / 2])`
+    /// of the last dimension by the angles whose cosines and sines are given for each position.
+    ///
+    /// The cosines and sines are constants, they don't receive gradients.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, num_heads, seq_length, head_dim],
+    /// cos: [seq_length, head_dim / 2],
+    /// sin: [seq_length, head_dim / 2],
+    /// output: [batch_size, num_heads, seq_length, head_dim],
+    fn rope_embed(
+        x: FloatTensor<B, 4>,
+        cos: FloatTensor<B, 2>,
+        sin: FloatTensor<B, 2>,
+    ) -> FloatTensor<B, 4> {
+        rope::rope_embed_from_decomposition::<B>(x, cos, sin)
+    }
+
     /// Two dimensional max pooling.
     ///
     /// # Shapes
@@ -0,0 +1,218 @@
+use crate::backend::Backend;
+use crate::ops::{FloatTensor, IntTensor};
+use crate::{ElementConversion, Shape};
+
+use super::{GridSampleMode, GridSamplePaddingMode};
+
+/// Compute the grid sample operation with gather and element wise operations, so that it can be
+/// differentiated with respect to both the input and the grid.
+pub(crate) fn grid_sample_from_gather<B: Backend>(
+    input: FloatTensor<B, 4>,
+    grid: FloatTensor<B, 4>,
+    mode: GridSampleMode,
+    padding_mode: GridSamplePaddingMode,
+    align_corners: bool,
+) -> FloatTensor<B, 4> {
+    let [batch_size, channels, height, width] = B::float_shape(&input).dims;
+    let [_, height_out, width_out, _] = B::float_shape(&grid).dims;
+    let num_outputs = height_out * width_out;
+
+    let grid = B::float_reshape(grid, Shape::new([batch_size, num_outputs, 2]));
+    let coordinate = |index: usize| {
+        let coordinate = B::float_slice(
+            grid.clone(),
+            [0..batch_size, 0..num_outputs, index..index + 1],
+        );
+        B::float_reshape(coordinate, Shape::new([batch_size, num_outputs]))
+    };
+
+    let x = source_index::<B>(coordinate(0), width, padding_mode, align_corners);
+    let y = source_index::<B>(coordinate(1), height, padding_mode, align_corners);
+
+    let input = B::float_reshape(input, Shape::new([batch_size, channels, height * width]));
+    let sampler = Sampler::<B> {
+        input,
+        channels,
+        height,
+        width,
+    };
+
+    let output = match mode {
+        GridSampleMode::Nearest => {
+            let device = B::float_device(&x);
+            let weight = B::float_ones(Shape::new([batch_size, num_outputs]), &device);
+
+            sampler.sample(round_half_even::<B>(x), round_half_even::<B>(y), weight)
+        }
+        GridSampleMode::Bilinear => {
+            let x0 = floor::<B>(x.clone());
+            let y0 = floor::<B>(y.clone());
+            let x1 = B::float_add_scalar(x0.clone(), 1.elem());
+            let y1 = B::float_add_scalar(y0.clone(), 1.elem());
+
+            // The weights are computed from the coordinates, which propagates the gradient to
+            // the grid.
+            let weight_x1 = B::float_sub(x, x0.clone());
+            let weight_y1 = B::float_sub(y, y0.clone());
+            let weight_x0 = one_minus::<B>(weight_x1.clone());
+            let weight_y0 = one_minus::<B>(weight_y1.clone());
+
+            let corners = [
+                (x0.clone(), y0.clone(), weight_x0.clone(), weight_y0.clone()),
+                (x1.clone(), y0, weight_x1.clone(), weight_y0),
+                (x0, y1.clone(), weight_x0, weight_y1.clone()),
+                (x1, y1, weight_x1, weight_y1),
+            ];
+
+            corners
+                .into_iter()
+                .map(|(x, y, weight_x, weight_y)| {
+                    sampler.sample(x, y, B::float_mul(weight_x, weight_y))
+                })
+                .reduce(B::float_add)
+                .unwrap()
+        }
+    };
+
+    B::float_reshape(
+        output,
+        Shape::new([batch_size, channels, height_out, width_out]),
+    )
+}
+
+struct Sampler<B: Backend> {
+    input: FloatTensor<B, 3>,
+    channels: usize,
+    height: usize,
+    width: usize,
+}
+
+impl<B: Backend> Sampler<B> {
+    /// Weighted values of the input at the given integer coordinates, which are zeros when the
+    /// coordinates are out of bounds.
+    fn sample(
+        &self,
+        x: FloatTensor<B, 2>,
+        y: FloatTensor<B, 2>,
+        weight: FloatTensor<B, 2>,
+    ) -> FloatTensor<B, 3> {
+        let [batch_size, num_outputs] = B::float_shape(&x).dims;
+        let max_x = (self.width - 1) as f32;
+        let max_y = (self.height - 1) as f32;
+
+        let mut weight = weight;
+        for (coordinate, max) in [(&x, max_x), (&y, max_y)] {
+            let lower = B::float_lower_elem(coordinate.clone(), 0.elem());
+            let greater = B::float_greater_elem(coordinate.clone(), max.elem());
+
+            weight = B::float_mask_fill(weight, lower, 0.elem());
+            weight = B::float_mask_fill(weight, greater, 0.elem());
+        }
+
+        let x = B::float_clamp(x, 0.elem(), max_x.elem());
+        let y = B::float_clamp(y, 0.elem(), max_y.elem());
+        let indices = B::float_add(B::float_mul_scalar(y, (self.width as f32).elem()), x);
+        let indices = B::float_into_int(indices);
+        let indices = B::int_reshape(indices, Shape::new([batch_size, 1, num_outputs]));
+        let indices: IntTensor<B, 3> = B::int_repeat(indices, 1, self.channels);
+
+        let values = B::float_gather(2, self.input.clone(), indices);
+        let weight = B::float_reshape(weight, Shape::new([batch_size, 1, num_outputs]));
+
+        B::float_mul(values, weight)
+    }
+}
+
+/// Maps the normalized coordinates of the grid to positions in the input, following the
+/// PyTorch convention.
+fn source_index<B: Backend>(
+    coordinate: FloatTensor<B, 2>,
+    size: usize,
+    padding_mode: GridSamplePaddingMode,
+    align_corners: bool,
+) -> FloatTensor<B, 2> {
+    let size_f = size as f32;
+
+    // With aligned corners, -1 and 1 are the centers of the corner pixels, otherwise they are
+    // the edges of the corner pixels.
+    let coordinate = B::float_add_scalar(coordinate, 1.elem());
+    let coordinate = match align_corners {
+        true => B::float_mul_scalar(coordinate, ((size_f - 1.) / 2.).elem()),
+        false => B::float_add_scalar(
+            B::float_mul_scalar(coordinate, (size_f / 2.).elem()),
+            (-0.5).elem(),
+        ),
+    };
+
+    match padding_mode {
+        GridSamplePaddingMode::Zeros => coordinate,
+        GridSamplePaddingMode::Border => clip::<B>(coordinate, size),
+        GridSamplePaddingMode::Reflection => {
+            let coordinate = match align_corners {
+                true => reflect::<B>(coordinate, 0., 2. * (size_f - 1.)),
+                false => reflect::<B>(coordinate, -1., 2. * size_f - 1.),
+            };
+            clip::<B>(coordinate, size)
+        }
+    }
+}
+
+fn clip<B: Backend>(coordinate: FloatTensor<B, 2>, size: usize) -> FloatTensor<B, 2> {
+    B::float_clamp(coordinate, 0.elem(), ((size - 1) as f32).elem())
+}
+
+/// Reflects the coordinates until they fall between the given bounds, which are doubled so that
+/// they can be half integers.
+fn reflect<B: Backend>(
+    coordinate: FloatTensor<B, 2>,
+    twice_low: f32,
+    twice_high: f32,
+) -> FloatTensor<B, 2> {
+    if twice_low == twice_high {
+        return B::float_zeros(B::float_shape(&coordinate), &B::float_device(&coordinate));
+    }
+
+    let min = twice_low / 2.;
+    let span = (twice_high - twice_low) / 2.;
+
+    let coordinate = B::float_abs(B::float_add_scalar(coordinate, (-min).elem()));
+    let flips = floor::<B>(B::float_div_scalar(coordinate.clone(), span.elem()));
+    let extra = B::float_sub(coordinate, B::float_mul_scalar(flips.clone(), span.elem()));
+
+    let half_flips = floor::<B>(B::float_div_scalar(flips.clone(), 2.elem()));
+    let parity = B::float_sub(flips, B::float_mul_scalar(half_flips, 2.elem()));
+    let odd = B::float_greater_elem(parity, 0.5.elem());
+
+    let even = B::float_add_scalar(extra.clone(), min.elem());
+    let odd_value = B::float_add_scalar(B::float_neg(extra), (span + min).elem());
+
+    B::float_mask_where(even, odd, odd_value)
+}
+
+/// The largest integer lower or equal to each value, without gradient.
+fn floor<B: Backend>(tensor: FloatTensor<B, 2>) -> FloatTensor<B, 2> {
+    let truncated = B::int_into_float(B::float_into_int(tensor.clone()));
+    let mask = B::float_greater(truncated.clone(), tensor);
+    let lowered = B::float_add_scalar(truncated.clone(), (-1.0).elem());
+
+    B::float_mask_where(truncated, mask, lowered)
+}
+
+/// Rounds to the nearest integer, ties to even, the same as `nearbyint` used by PyTorch.
+fn round_half_even<B: Backend>(tensor: FloatTensor<B, 2>) -> FloatTensor<B, 2> {
+    let rounded = floor::<B>(B::float_add_scalar(tensor.clone(), 0.5.elem()));
+    let is_tie = B::float_equal_elem(B::float_sub(rounded.clone(), tensor), 0.5.elem());
+
+    let half = floor::<B>(B::float_div_scalar(rounded.clone(), 2.elem()));
+    let parity = B::float_sub(rounded.clone(), B::float_mul_scalar(half, 2.elem()));
+    // Only the odd values coming from a tie are lowered.
+    let parity = B::float_mask_fill(parity, B::bool_not(is_tie), 0.elem());
+    let lower = B::float_greater_elem(parity, 0.5.elem());
+
+    let lowered = B::float_add_scalar(rounded.clone(), (-1.0).elem());
+    B::float_mask_where(rounded, lower, lowered)
+}
+
+fn one_minus<B: Backend>(tensor: FloatTensor<B, 2>) -> FloatTensor<B, 2> {
+    B::float_add_scalar(B::float_neg(tensor), 1.elem())
+}
@@ -0,0 +1,173 @@
+use crate::backend::Backend;
+use crate::ops::FloatTensor;
+use crate::{Data, Shape};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::InterpolateMode;
+
+/// Coefficient of the cubic convolution kernel, the same value as PyTorch.
+const CUBIC_COEFFICIENT: f64 = -0.75;
+
+/// Returns the input positions and their weights used to compute each output position along one
+/// axis.
+///
+/// The coordinates follow the PyTorch convention, where pixels are considered as areas unless
+/// the corners are aligned.
+pub(crate) fn axis_taps(
+    input_size: usize,
+    output_size: usize,
+    mode: InterpolateMode,
+) -> Vec<Vec<(usize, f64)>> {
+    let scale = input_size as f64 / output_size as f64;
+    let last = input_size as i64 - 1;
+    let clamp = |index: i64| index.clamp(0, last) as usize;
+
+    (0..output_size)
+        .map(|output_index| {
+            let position = output_index as f64;
+
+            match mode {
+                InterpolateMode::Nearest => {
+                    let index = libm::floor(position * scale) as i64;
+                    vec![(clamp(index), 1.0)]
+                }
+                InterpolateMode::Bilinear { align_corners } => {
+                    let source = match align_corners {
+                        true if output_size > 1 => {
+                            position * last as f64 / (output_size - 1) as f64
+                        }
+                        true => 0.0,
+                        false => f64::max((position + 0.5) * scale - 0.5, 0.0),
+                    };
+                    let index = libm::floor(source) as i64;
+                    let lambda = source - index as f64;
+
+                    vec![(clamp(index), 1.0 - lambda), (clamp(index + 1), lambda)]
+                }
+                InterpolateMode::Bicubic => {
+                    let source = (position + 0.5) * scale - 0.5;
+                    let index = libm::floor(source) as i64;
+                    let t = source - index as f64;
+
+                    cubic_weights(t)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, weight)| (clamp(index - 1 + i as i64), weight))
+                        .collect()
+                }
+            }
+        })
+        .collect()
+}
+
+/// Weights of the four pixels surrounding a position at distance `t` of the second pixel.
+fn cubic_weights(t: f64) -> [f64; 4] {
+    let a = CUBIC_COEFFICIENT;
+    // Used for distances in [1, 2].
+    let far = |x: f64| ((a * x - 5.0 * a) * x + 8.0 * a) * x - 4.0 * a;
+    // Used for distances in [0, 1].
+    let near = |x: f64| ((a + 2.0) * x - (a + 3.0)) * x * x + 1.0;
+
+    [far(t + 1.0), near(t), near(1.0 - t), far(2.0 - t)]
+}
+
+/// For each combination of taps along the height and the width, the flattened input indices and
+/// the weights of every output position.
+fn spatial_taps(
+    input_size: [usize; 2],
+    output_size: [usize; 2],
+    mode: InterpolateMode,
+) -> Vec<(Vec<i64>, Vec<f32>)> {
+    let taps_height = axis_taps(input_size[0], output_size[0], mode);
+    let taps_width = axis_taps(input_size[1], output_size[1], mode);
+    let num_taps_height = taps_height.first().map(|taps| taps.len()).unwrap_or(0);
+    let num_taps_width = taps_width.first().map(|taps| taps.len()).unwrap_or(0);
+    let num_outputs = output_size[0] * output_size[1];
+
+    let mut taps = Vec::with_capacity(num_taps_height * num_taps_width);
+
+    for kh in 0..num_taps_height {
+        for kw in 0..num_taps_width {
+            let mut indices = Vec::with_capacity(num_outputs);
+            let mut weights = Vec::with_capacity(num_outputs);
+
+            for (ih, wh) in taps_height.iter().map(|taps| taps[kh]) {
+                for (iw, ww) in taps_width.iter().map(|taps| taps[kw]) {
+                    indices.push((ih * input_size[1] + iw) as i64);
+                    weights.push((wh * ww) as f32);
+                }
+            }
+
+            taps.push((indices, weights));
+        }
+    }
+
+    taps
+}
+
+/// Compute the interpolate operation as a weighted sum of the input pixels selected for every
+/// output pixel.
+pub(crate) fn interpolate_from_select<B: Backend>(
+    x: FloatTensor<B, 4>,
+    output_size: [usize; 2],
+    mode: InterpolateMode,
+) -> FloatTensor<B, 4> {
+    let [batch_size, channels, height, width] = B::float_shape(&x).dims;
+    let device = B::float_device(&x);
+    let num_outputs = output_size[0] * output_size[1];
+
+    let x = B::float_reshape(x, Shape::new([batch_size, channels, height * width]));
+    let mut output = B::float_zeros(Shape::new([batch_size, channels, num_outputs]), &device);
+
+    for (indices, weights) in spatial_taps([height, width], output_size, mode) {
+        let indices = B::int_from_data(
+            Data::new(indices, Shape::new([num_outputs])).convert(),
+            &device,
+        );
+        let weights = B::float_from_data(
+            Data::new(weights, Shape::new([1, 1, num_outputs])).convert(),
+            &device,
+        );
+        let values = B::float_select(x.clone(), 2, indices);
+
+        output = B::float_add(output, B::float_mul(values, weights));
+    }
+
+    B::float_reshape(
+        output,
+        Shape::new([batch_size, channels, output_size[0], output_size[1]]),
+    )
+}
+
+/// Compute the interpolate backward operation by accumulating the weighted output gradient into
+/// the input pixels selected for every output pixel.
+pub(crate) fn interpolate_backward_from_select_assign<B: Backend>(
+    x: FloatTensor<B, 4>,
+    grad: FloatTensor<B, 4>,
+    output_size: [usize; 2],
+    mode: InterpolateMode,
+) -> FloatTensor<B, 4> {
+    let [batch_size, channels, height, width] = B::float_shape(&x).dims;
+    let device = B::float_device(&x);
+    let num_outputs = output_size[0] * output_size[1];
+
+    let grad = B::float_reshape(grad, Shape::new([batch_size, channels, num_outputs]));
+    let mut x_grad = B::float_zeros(Shape::new([batch_size, channels, height * width]), &device);
+
+    for (indices, weights) in spatial_taps([height, width], output_size, mode) {
+        let indices = B::int_from_data(
+            Data::new(indices, Shape::new([num_outputs])).convert(),
+            &device,
+        );
+        let weights = B::float_from_data(
+            Data::new(weights, Shape::new([1, 1, num_outputs])).convert(),
+            &device,
+        );
+        let values = B::float_mul(grad.clone(), weights);
+
+        x_grad = B::float_select_assign(x_grad, 2, indices, values);
+    }
+
+    B::float_reshape(x_grad, Shape::new([batch_size, channels, height, width]))
+}
@@ -1,9 +1,21 @@
+/// Module with attention operations.
+pub mod attention;
+
 /// Module with convolution operations.
 pub mod conv;
 
+/// Module with grid sample operations.
+pub(crate) mod grid_sample;
+
+/// Module with interpolate operations.
+pub(crate) mod interpolate;
+
 /// Module with unfold operations.
 pub(crate) mod unfold;
 
+/// Module with rotary position embedding operations.
+pub(crate) mod rope;
+
 /// Module with pooling operations.
 pub mod pool;
 
@@ -0,0 +1,28 @@
+use crate::backend::Backend;
+use crate::ops::FloatTensor;
+use crate::Shape;
+use alloc::vec;
+
+/// Rotate the pairs of elements of the last dimension with element wise operations.
+pub(crate) fn rope_embed_from_decomposition<B: Backend>(
+    x: FloatTensor<B, 4>,
+    cos: FloatTensor<B, 2>,
+    sin: FloatTensor<B, 2>,
+) -> FloatTensor<B, 4> {
+    let [_, _, seq_length, head_dim] = B::float_shape(&x).dims;
+    let half = head_dim / 2;
+
+    let first = B::float_narrow(x.clone(), 3, 0, half);
+    let second = B::float_narrow(x, 3, half, half);
+    let cos = B::float_reshape(cos, Shape::new([1, 1, seq_length, half]));
+    let sin = B::float_reshape(sin, Shape::new([1, 1, seq_length, half]));
+
+    // [x1, x2] -> [x1 cos - x2 sin, x2 cos + x1 sin]
+    let output_first = B::float_sub(
+        B::float_mul(first.clone(), cos.clone()),
+        B::float_mul(second.clone(), sin.clone()),
+    );
+    let output_second = B::float_add(B::float_mul(second, cos), B::float_mul(first, sin));
+
+    B::float_cat(vec![output_first, output_second], 3)
+}
@@ -1,6 +1,15 @@
 use super::{BoolTensor, Device, FloatElem, FloatTensor, FullPrecisionBackend, IntElem, IntTensor};
-use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion, Float};
-use crate::{tensor::api::chunk, tensor::api::narrow};
+use crate::{
+    backend::Backend, tensor::Shape, Data, Distribution, ElementConversion, ElementNextAfter,
+    Float, Int,
+};
+use crate::{
+    tensor::api::cholesky, tensor::api::cholesky_solve, tensor::api::chunk, tensor::api::cumprod,
+    tensor::api::cumsum, tensor::api::eigh, tensor::api::erfinv, tensor::api::fft,
+    tensor::api::logcumsumexp, tensor::api::lstsq, tensor::api::narrow, tensor::api::nonzero,
+    tensor::api::roll, tensor::api::solve, tensor::api::sort_with_indices, tensor::api::svd,
+    tensor::api::unique, tensor::api::unique_consecutive,
+};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
@@ -148,6 +157,23 @@ pub trait FloatTensorOps<B: Backend> {
         device: &Device<B>,
     ) -> FloatTensor<B, D>;
 
+    /// Moves the tensor to the given device without waiting for the transfer to complete.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `device` - The device to move the tensor to.
+    ///
+    /// # Returns
+    ///
+    /// The reader of the tensor on the given device, which is immediately available by default.
+    fn float_to_device_async<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        device: &Device<B>,
+    ) -> Reader<FloatTensor<B, D>> {
+        Reader::Concrete(B::float_to_device(tensor, device))
+    }
+
     /// Converts float tensor to int tensor.
     ///
     /// # Arguments
@@ -171,6 +197,35 @@ pub trait FloatTensorOps<B: Backend> {
     /// The empty tensor with the given shape.
     fn float_empty<const D: usize>(shape: Shape<D>, device: &Device<B>) -> FloatTensor<B, D>;
 
+    /// Broadcasts the tensor to the given shape, the dimensions of size 1 being expanded.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `shape` - The shape to broadcast to, of the same rank as the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor broadcast to the given shape.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation repeats the expanded dimensions. The returned tensor owns its
+    /// data: it must not share memory with the input, since the in-place operations would
+    /// otherwise write the same element several times.
+    fn float_expand<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        shape: Shape<D>,
+    ) -> FloatTensor<B, D> {
+        let current = B::float_shape(&tensor);
+
+        (0..D)
+            .filter(|dim| current.dims[*dim] != shape.dims[*dim])
+            .fold(tensor, |tensor, dim| {
+                B::float_repeat(tensor, dim, shape.dims[dim])
+            })
+    }
+
     /// Repeat the tensor along the given dimension.
     ///
     /// # Arguments
@@ -996,6 +1051,19 @@ pub trait FloatTensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with error function values.
     fn float_erf<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
 
+    /// Returns a new tensor with the inverse error function values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse error function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse error function values.
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        erfinv::<B, D>(tensor)
+    }
+
     /// Catcatenates tensors along a dimension.
     ///
     /// # Arguments
@@ -1136,6 +1204,135 @@ pub trait FloatTensorOps<B: Backend> {
         (values, index)
     }
 
+    /// Sorts the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - Whether the elements should be sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, with its elements sorted along `dim`.
+    fn float_sort<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<B, D> {
+        B::float_sort_with_indices(tensor, dim, descending).0
+    }
+
+    /// Sorts the elements of a tensor along an axis and returns their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - Whether the elements should be sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the sorted tensor and the indices of the sorted elements in `tensor`.
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
+        sort_with_indices::<B, D, Float>(tensor, dim, descending)
+    }
+
+    /// Returns the indices that would sort the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sort.
+    /// * `dim` - The dimension along which to sort.
+    /// * `descending` - Whether the elements should be sorted in descending order.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the indices of the sorted elements of `tensor` along `dim`.
+    fn float_argsort<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::float_sort_with_indices(tensor, dim, descending).1
+    }
+
+    /// Gets the `k` largest elements of a tensor along an axis and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the largest elements of.
+    /// * `k` - The number of elements to keep.
+    /// * `dim` - The dimension along which to get the largest elements.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the `k` largest elements of `tensor` along `dim`, sorted in descending order,
+    /// and their indices.
+    fn float_topk<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        k: usize,
+        dim: usize,
+    ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
+        let (values, indices) = B::float_sort_with_indices(tensor, dim, true);
+        let values = narrow::<B, D, Float>(values, dim, 0, k);
+        let indices = narrow::<B, D, Int>(indices, dim, 0, k);
+
+        (values, indices)
+    }
+
+    /// Computes the cumulative sum of the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where each element is the sum of all the
+    /// elements before it along `dim`, itself included.
+    fn float_cumsum<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cumsum::<B, D, Float>(tensor, dim)
+    }
+
+    /// Computes the cumulative product of the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where each element is the product of all the
+    /// elements before it along `dim`, itself included.
+    fn float_cumprod<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cumprod::<B, D, Float>(tensor, dim)
+    }
+
+    /// Computes the cumulative log-sum-exp of the elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where each element is the logarithm of the sum of
+    /// the exponentials of all the elements before it along `dim`, itself included.
+    fn float_logcumsumexp<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+    ) -> FloatTensor<B, D> {
+        logcumsumexp::<B, D, Float>(tensor, dim)
+    }
+
     /// Returns a new tensor with the given dimension narrowed to the given range.
     ///
     /// # Arguments
@@ -1179,4 +1376,237 @@ pub trait FloatTensorOps<B: Backend> {
     ) -> Vec<FloatTensor<B, D>> {
         chunk::<B, D, Float>(tensor, chunks, dim)
     }
+
+    /// Rolls the tensor along the given dimensions, the elements shifted beyond the last position
+    /// being re-introduced at the first position.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `shifts` - The number of places by which the elements are shifted along each dimension.
+    /// * `dims` - The dimensions along which the tensor will be rolled.
+    ///
+    /// # Returns
+    ///
+    /// The rolled tensor.
+    fn float_roll<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> FloatTensor<B, D> {
+        roll::<B, D, Float>(tensor, shifts, dims)
+    }
+
+    /// Computes the discrete Fourier transform of a complex tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part of the tensor.
+    /// * `imag` - The imaginary part of the tensor.
+    /// * `dim` - The dimension along which the transform is computed.
+    /// * `inverse` - Whether the inverse transform is computed, which is normalized by the size
+    ///   of the dimension.
+    ///
+    /// # Returns
+    ///
+    /// The real and imaginary parts of the transform.
+    fn float_fft<const D: usize>(
+        real: FloatTensor<B, D>,
+        imag: FloatTensor<B, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        fft::<B, D>(real, imag, dim, inverse)
+    }
+
+    /// Computes the singular value decomposition of the matrices of a tensor, over its last two
+    /// dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor, of shape `[..., m, n]`.
+    /// * `full_matrices` - If the singular vectors are square matrices, instead of having
+    ///   `k = min(m, n)` columns for `U` and rows for `Vh`.
+    ///
+    /// # Returns
+    ///
+    /// The left singular vectors `U`, the singular values `S` in descending order with the shape
+    /// `[..., 1, k]`, and the transposed right singular vectors `Vh`.
+    fn float_svd<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        full_matrices: bool,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>) {
+        svd::<B, D>(tensor, full_matrices)
+    }
+
+    /// Computes the eigendecomposition of the symmetric matrices of a tensor, over its last two
+    /// dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor, of shape `[..., n, n]`, of which only the lower triangle is used.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvectors as the columns of a tensor of shape `[..., n, n]`, and the eigenvalues in
+    /// ascending order with the shape `[..., 1, n]`.
+    fn float_eigh<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        eigh::<B, D>(tensor)
+    }
+
+    /// Solves the square linear systems `A X = B` over the last two dimensions of the tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The coefficients, of shape `[..., n, n]`.
+    /// * `b` - The right-hand sides, of shape `[..., n, k]`.
+    ///
+    /// # Returns
+    ///
+    /// The solutions `X`, of shape `[..., n, k]`.
+    fn float_solve<const D: usize>(
+        a: FloatTensor<B, D>,
+        b: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        solve::<B, D>(a, b)
+    }
+
+    /// Computes the least-squares solutions of the linear systems `A X = B` over the last two
+    /// dimensions of the tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The coefficients, of shape `[..., m, n]`.
+    /// * `b` - The right-hand sides, of shape `[..., m, k]`.
+    ///
+    /// # Returns
+    ///
+    /// The solutions `X` minimizing `||A X - B||`, of shape `[..., n, k]`.
+    fn float_lstsq<const D: usize>(
+        a: FloatTensor<B, D>,
+        b: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        lstsq::<B, D>(a, b)
+    }
+
+    /// Computes the Cholesky decomposition of the symmetric positive-definite matrices of a
+    /// tensor, over its last two dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor, of shape `[..., n, n]`, of which only the lower triangle is used.
+    ///
+    /// # Returns
+    ///
+    /// The lower triangular factors `L` with `A = L L^T`, of shape `[..., n, n]`.
+    fn float_cholesky<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        cholesky::<B, D>(tensor)
+    }
+
+    /// Solves the linear systems `A X = B` from the Cholesky factors of the matrices `A`.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - The lower triangular factors `L` with `A = L L^T`, or the upper triangular
+    ///   factors `U` with `A = U^T U`, of shape `[..., n, n]`.
+    /// * `b` - The right-hand sides, of shape `[..., n, k]`.
+    /// * `upper` - If the factors are upper triangular.
+    ///
+    /// # Returns
+    ///
+    /// The solutions `X`, of shape `[..., n, k]`.
+    fn float_cholesky_solve<const D: usize>(
+        factor: FloatTensor<B, D>,
+        b: FloatTensor<B, D>,
+        upper: bool,
+    ) -> FloatTensor<B, D> {
+        cholesky_solve::<B, D>(factor, b, upper)
+    }
+
+    /// Returns the unique elements of the tensor in ascending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the unique elements, the index of the unique element of each element of the
+    /// flattened tensor and the number of occurrences of each unique element.
+    fn float_unique<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, 1>, IntTensor<B, 1>, IntTensor<B, 1>) {
+        unique::<B, D, Float>(tensor)
+    }
+
+    /// Returns the indices of the non-zero elements of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// An int tensor of shape `[N, D]` with the indices of the `N` non-zero elements in row-major
+    /// order.
+    fn float_nonzero<const D: usize>(tensor: FloatTensor<B, D>) -> IntTensor<B, 2> {
+        nonzero::<B, D, Float>(tensor)
+    }
+
+    /// Collapses the consecutive equal slices of the tensor along the given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which the slices are compared.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the first slice of each group of consecutive equal slices.
+    fn float_unique_consecutive<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+    ) -> FloatTensor<B, D> {
+        unique_consecutive::<B, D, Float>(tensor, dim)
+    }
+
+    /// Returns the next representable value after each element of `lhs` in the direction of
+    /// the corresponding element of `rhs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The starting values.
+    /// * `rhs` - The directions, of the same shape as `lhs`.
+    ///
+    /// # Returns
+    ///
+    /// The next representable values, computed from the IEEE 754 representation of the elements.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation reads the data back on the host, so the operation is
+    /// synchronous.
+    fn float_nextafter<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        let device = B::float_device(&lhs);
+        let lhs = B::float_into_data(lhs)
+            .read_sync()
+            .expect("Can't compute the next values of a tensor with async data.");
+        let rhs = B::float_into_data(rhs)
+            .read_sync()
+            .expect("Can't compute the next values of a tensor with async data.");
+
+        let value = lhs
+            .value
+            .into_iter()
+            .zip(rhs.value)
+            .map(|(value, toward)| value.next_after(toward))
+            .collect();
+
+        B::float_from_data(Data::new(value, lhs.shape), &device)
+    }
 }
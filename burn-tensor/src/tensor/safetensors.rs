@@ -0,0 +1,347 @@
+use core::any::TypeId;
+use core::fmt::Display;
+use std::collections::HashMap;
+
+use crate::{backend::Backend, Bool, Data, DataSerialize, Element, Int, Tensor};
+use ::safetensors::{tensor::TensorView, SafeTensors};
+use half::{bf16, f16};
+
+pub use ::safetensors::Dtype;
+
+/// Error that can occur when reading or writing the [safetensors](https://huggingface.co/docs/safetensors)
+/// format.
+#[derive(Debug)]
+pub enum SafetensorsError {
+    /// The bytes aren't in the safetensors format.
+    Format(String),
+
+    /// No tensor is stored under the key.
+    KeyNotFound(String),
+
+    /// The tensor doesn't have the expected rank.
+    Rank {
+        /// The rank of the tensor type.
+        expected: usize,
+        /// The rank of the stored tensor.
+        found: usize,
+    },
+
+    /// The data type isn't supported.
+    Dtype(String),
+}
+
+impl Display for SafetensorsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Format(message) => write!(f, "Invalid safetensors format: {message}"),
+            Self::KeyNotFound(key) => write!(f, "No tensor found for the key '{key}'"),
+            Self::Rank { expected, found } => write!(
+                f,
+                "Expected a tensor of rank {expected}, found a tensor of rank {found}"
+            ),
+            Self::Dtype(dtype) => write!(f, "Unsupported data type {dtype}"),
+        }
+    }
+}
+
+impl std::error::Error for SafetensorsError {}
+
+/// A tensor in the safetensors format, with its elements encoded as little-endian bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SafetensorsData {
+    dtype: Dtype,
+    shape: Vec<usize>,
+    bytes: Vec<u8>,
+}
+
+impl SafetensorsData {
+    /// Encodes the elements of a tensor, with the data type of the elements.
+    pub fn from_data<E: Element>(data: DataSerialize<E>) -> Self {
+        let (dtype, bytes) = encode(&data.value);
+
+        Self {
+            dtype,
+            shape: data.shape,
+            bytes,
+        }
+    }
+
+    /// Encodes the elements of a boolean tensor.
+    pub fn from_bool_data(data: DataSerialize<bool>) -> Self {
+        Self {
+            dtype: Dtype::BOOL,
+            shape: data.shape,
+            bytes: data.value.into_iter().map(u8::from).collect(),
+        }
+    }
+
+    /// Decodes the elements, converting them to the given element type.
+    pub fn into_data<E: Element>(self) -> Result<DataSerialize<E>, SafetensorsError> {
+        let value = decode(self.dtype, &self.bytes)?;
+
+        Ok(DataSerialize::new(value, self.shape))
+    }
+
+    /// Decodes the elements of a boolean tensor, any non-zero element being true.
+    pub fn into_bool_data(self) -> Result<DataSerialize<bool>, SafetensorsError> {
+        let value = match self.dtype {
+            Dtype::BOOL => self.bytes.into_iter().map(|byte| byte != 0).collect(),
+            dtype => decode::<f64>(dtype, &self.bytes)?
+                .into_iter()
+                .map(|elem| elem != 0.0)
+                .collect(),
+        };
+
+        Ok(DataSerialize::new(value, self.shape))
+    }
+
+    /// The data type of the elements.
+    pub fn dtype(&self) -> Dtype {
+        self.dtype
+    }
+
+    /// The shape of the tensor.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+}
+
+/// Serializes named tensors in the safetensors format.
+pub fn serialize(tensors: Vec<(String, SafetensorsData)>) -> Result<Vec<u8>, SafetensorsError> {
+    let views = tensors
+        .iter()
+        .map(|(key, tensor)| {
+            TensorView::new(tensor.dtype, tensor.shape.clone(), &tensor.bytes)
+                .map(|view| (key.as_str(), view))
+                .map_err(|err| SafetensorsError::Format(format!("{err:?}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    ::safetensors::serialize(views, &None)
+        .map_err(|err| SafetensorsError::Format(format!("{err:?}")))
+}
+
+/// Deserializes all the named tensors in the safetensors format.
+pub fn deserialize(bytes: &[u8]) -> Result<HashMap<String, SafetensorsData>, SafetensorsError> {
+    let tensors = SafeTensors::deserialize(bytes)
+        .map_err(|err| SafetensorsError::Format(format!("{err:?}")))?;
+
+    Ok(tensors
+        .tensors()
+        .into_iter()
+        .map(|(key, view)| (key, view_to_data(&view)))
+        .collect())
+}
+
+/// Deserializes the tensor stored under the given key in the safetensors format.
+fn deserialize_key(bytes: &[u8], key: &str) -> Result<SafetensorsData, SafetensorsError> {
+    let tensors = SafeTensors::deserialize(bytes)
+        .map_err(|err| SafetensorsError::Format(format!("{err:?}")))?;
+    let view = tensors
+        .tensor(key)
+        .map_err(|_| SafetensorsError::KeyNotFound(key.to_string()))?;
+
+    Ok(view_to_data(&view))
+}
+
+fn view_to_data(view: &TensorView<'_>) -> SafetensorsData {
+    SafetensorsData {
+        dtype: view.dtype(),
+        shape: view.shape().to_vec(),
+        bytes: view.data().to_vec(),
+    }
+}
+
+fn check_rank<E, const D: usize>(data: &DataSerialize<E>) -> Result<(), SafetensorsError> {
+    match data.shape.len() == D {
+        true => Ok(()),
+        false => Err(SafetensorsError::Rank {
+            expected: D,
+            found: data.shape.len(),
+        }),
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Serializes the tensor in the [safetensors](https://huggingface.co/docs/safetensors) format,
+    /// stored under the given key.
+    ///
+    /// The data type is the one of the float elements of the backend.
+    pub fn to_safetensors_bytes(self, key: &str) -> Vec<u8> {
+        let tensor = SafetensorsData::from_data(self.into_data().serialize());
+
+        serialize(vec![(key.to_string(), tensor)]).expect("The tensor should be serializable")
+    }
+
+    /// Deserializes the tensor stored under the given key in the
+    /// [safetensors](https://huggingface.co/docs/safetensors) format.
+    ///
+    /// The elements are converted to the float elements of the backend.
+    pub fn from_safetensors_bytes(
+        bytes: &[u8],
+        key: &str,
+        device: &B::Device,
+    ) -> Result<Self, SafetensorsError> {
+        let data = deserialize_key(bytes, key)?.into_data::<B::FloatElem>()?;
+        check_rank::<_, D>(&data)?;
+
+        Ok(Self::from_data(Data::from(data), device))
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D, Int> {
+    /// Serializes the tensor in the [safetensors](https://huggingface.co/docs/safetensors) format,
+    /// stored under the given key.
+    ///
+    /// The data type is the one of the integer elements of the backend.
+    pub fn to_safetensors_bytes(self, key: &str) -> Vec<u8> {
+        let tensor = SafetensorsData::from_data(self.into_data().serialize());
+
+        serialize(vec![(key.to_string(), tensor)]).expect("The tensor should be serializable")
+    }
+
+    /// Deserializes the tensor stored under the given key in the
+    /// [safetensors](https://huggingface.co/docs/safetensors) format.
+    ///
+    /// The elements are converted to the integer elements of the backend.
+    pub fn from_safetensors_bytes(
+        bytes: &[u8],
+        key: &str,
+        device: &B::Device,
+    ) -> Result<Self, SafetensorsError> {
+        let data = deserialize_key(bytes, key)?.into_data::<B::IntElem>()?;
+        check_rank::<_, D>(&data)?;
+
+        Ok(Self::from_data(Data::from(data), device))
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D, Bool> {
+    /// Serializes the tensor in the [safetensors](https://huggingface.co/docs/safetensors) format,
+    /// stored under the given key.
+    pub fn to_safetensors_bytes(self, key: &str) -> Vec<u8> {
+        let tensor = SafetensorsData::from_bool_data(self.into_data().serialize());
+
+        serialize(vec![(key.to_string(), tensor)]).expect("The tensor should be serializable")
+    }
+
+    /// Deserializes the tensor stored under the given key in the
+    /// [safetensors](https://huggingface.co/docs/safetensors) format.
+    ///
+    /// The numeric elements are true when they aren't zero.
+    pub fn from_safetensors_bytes(
+        bytes: &[u8],
+        key: &str,
+        device: &B::Device,
+    ) -> Result<Self, SafetensorsError> {
+        let data = deserialize_key(bytes, key)?.into_bool_data()?;
+        check_rank::<_, D>(&data)?;
+
+        Ok(Self::from_data(Data::from(data), device))
+    }
+}
+
+macro_rules! encode_as {
+    ($values:expr, $ty:ty) => {
+        $values
+            .iter()
+            .flat_map(|elem| elem.elem::<$ty>().to_le_bytes())
+            .collect()
+    };
+}
+
+fn encode<E: Element>(values: &[E]) -> (Dtype, Vec<u8>) {
+    let type_id = TypeId::of::<E>();
+
+    if type_id == TypeId::of::<f64>() {
+        (Dtype::F64, encode_as!(values, f64))
+    } else if type_id == TypeId::of::<f16>() {
+        (Dtype::F16, encode_as!(values, f16))
+    } else if type_id == TypeId::of::<bf16>() {
+        (Dtype::BF16, encode_as!(values, bf16))
+    } else if type_id == TypeId::of::<i32>() {
+        (Dtype::I32, encode_as!(values, i32))
+    } else if type_id == TypeId::of::<i16>() {
+        (Dtype::I16, encode_as!(values, i16))
+    } else if type_id == TypeId::of::<i8>() {
+        (Dtype::I8, encode_as!(values, i8))
+    } else if type_id == TypeId::of::<u8>() {
+        (Dtype::U8, encode_as!(values, u8))
+    } else if type_id == TypeId::of::<u32>() {
+        (Dtype::U32, encode_as!(values, u32))
+    } else if type_id == TypeId::of::<i64>() {
+        (Dtype::I64, encode_as!(values, i64))
+    } else {
+        (Dtype::F32, encode_as!(values, f32))
+    }
+}
+
+macro_rules! decode_as {
+    ($bytes:expr, $ty:ty) => {
+        $bytes
+            .chunks_exact(core::mem::size_of::<$ty>())
+            .map(|chunk| E::from_elem(<$ty>::from_le_bytes(chunk.try_into().unwrap())))
+            .collect()
+    };
+}
+
+fn decode<E: Element>(dtype: Dtype, bytes: &[u8]) -> Result<Vec<E>, SafetensorsError> {
+    let values = match dtype {
+        Dtype::BOOL | Dtype::U8 => bytes.iter().map(|byte| E::from_elem(*byte)).collect(),
+        Dtype::I8 => decode_as!(bytes, i8),
+        Dtype::I16 => decode_as!(bytes, i16),
+        Dtype::U16 => decode_as!(bytes, u16),
+        Dtype::I32 => decode_as!(bytes, i32),
+        Dtype::U32 => decode_as!(bytes, u32),
+        Dtype::I64 => decode_as!(bytes, i64),
+        Dtype::U64 => decode_as!(bytes, u64),
+        Dtype::F16 => decode_as!(bytes, f16),
+        Dtype::BF16 => decode_as!(bytes, bf16),
+        Dtype::F32 => decode_as!(bytes, f32),
+        Dtype::F64 => decode_as!(bytes, f64),
+        dtype => return Err(SafetensorsError::Dtype(format!("{dtype:?}"))),
+    };
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safetensors_data_should_round_trip_half_precision() {
+        let data = DataSerialize::new(vec![f16::from_f32(1.5), f16::from_f32(-2.0)], vec![2]);
+        let tensor = SafetensorsData::from_data(data.clone());
+
+        assert_eq!(tensor.dtype(), Dtype::F16);
+        assert_eq!(tensor.clone().into_data::<f16>().unwrap(), data);
+        assert_eq!(
+            tensor.into_data::<f32>().unwrap(),
+            DataSerialize::new(vec![1.5, -2.0], vec![2])
+        );
+    }
+
+    #[test]
+    fn safetensors_data_should_decode_bf16_and_bool() {
+        let data = DataSerialize::new(vec![bf16::from_f32(0.5), bf16::from_f32(3.0)], vec![2, 1]);
+        let bytes = serialize(vec![
+            ("bf16".to_string(), SafetensorsData::from_data(data.clone())),
+            (
+                "bool".to_string(),
+                SafetensorsData::from_bool_data(DataSerialize::new(vec![true, false], vec![2])),
+            ),
+        ])
+        .unwrap();
+
+        let tensors = deserialize(&bytes).unwrap();
+
+        assert_eq!(tensors["bf16"].dtype(), Dtype::BF16);
+        assert_eq!(tensors["bf16"].clone().into_data::<bf16>().unwrap(), data);
+        assert_eq!(tensors["bool"].dtype(), Dtype::BOOL);
+        assert_eq!(
+            tensors["bool"].clone().into_bool_data().unwrap(),
+            DataSerialize::new(vec![true, false], vec![2])
+        );
+    }
+}
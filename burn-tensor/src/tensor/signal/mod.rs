@@ -0,0 +1,298 @@
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Data, Int, Shape, Tensor};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+/// The padding applied to the signal when the frames are centered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadMode {
+    /// Pads with zeros.
+    Constant,
+    /// Pads with the reflection of the signal, without repeating its first and last elements.
+    Reflect,
+    /// Pads by repeating the first and last elements of the signal.
+    Replicate,
+}
+
+/// Computes the short-time Fourier transform of a batch of signals.
+///
+/// Each frame of `n_fft` elements, starting every `hop_length` elements, is multiplied by the
+/// window and transformed with a discrete Fourier transform. Only the `n_fft / 2 + 1`
+/// non-negative frequencies are returned, the others being their complex conjugates.
+///
+/// # Arguments
+///
+/// * `waveform` - The signals, of shape `[batch_size, signal_length]`.
+/// * `n_fft` - The size of the Fourier transform.
+/// * `hop_length` - The distance between the starts of two consecutive frames.
+/// * `win_length` - The size of the window, which is padded with zeros on both sides to `n_fft`.
+/// * `window` - The window, of shape `[win_length]`.
+/// * `center` - Whether the signals are padded with `n_fft / 2` elements on both sides, so that
+///   the frame `t` is centered on the element `t * hop_length`.
+/// * `pad_mode` - The padding used when the frames are centered.
+///
+/// # Returns
+///
+/// The complex spectrograms, of shape `[batch_size, n_fft / 2 + 1, num_frames, 2]`, with the
+/// real parts and the imaginary parts in the last dimension.
+///
+/// # Notes
+///
+/// The Fourier transform of the frames is computed with matrix multiplications, so it's
+/// differentiable with every backend.
+#[allow(clippy::too_many_arguments)]
+pub fn stft<B: Backend>(
+    waveform: Tensor<B, 2>,
+    n_fft: usize,
+    hop_length: usize,
+    win_length: usize,
+    window: Tensor<B, 1>,
+    center: bool,
+    pad_mode: PadMode,
+) -> Tensor<B, 4> {
+    let [batch_size, signal_length] = waveform.dims();
+    let padding = if center { n_fft / 2 } else { 0 };
+    check!(TensorCheck::stft(
+        signal_length,
+        n_fft,
+        hop_length,
+        win_length,
+        window.dims()[0],
+        padding,
+        pad_mode,
+    ));
+
+    let device = waveform.device();
+    let waveform = pad(waveform, padding, pad_mode);
+    let window = pad_window(window, n_fft);
+
+    let frames = waveform
+        .unfold::<3>(1, n_fft, hop_length)
+        .mul(window.reshape([1, 1, n_fft]));
+    let num_frames = frames.dims()[1];
+    let frames = frames.reshape([batch_size * num_frames, n_fft]);
+
+    // X[k] = sum_n x[n] e^(-2i pi k n / N)
+    let (cos, sin) = dft_basis::<B>(n_fft, &device);
+    let real = frames.clone().matmul(cos);
+    let imag = frames.matmul(sin).neg();
+
+    let num_freqs = n_fft / 2 + 1;
+    Tensor::stack::<3>(vec![real, imag], 2)
+        .reshape([batch_size, num_frames, num_freqs, 2])
+        .swap_dims(1, 2)
+}
+
+/// Computes the inverse short-time Fourier transform of a batch of spectrograms.
+///
+/// The frames are transformed back with an inverse discrete Fourier transform, multiplied by the
+/// window and summed where they overlap, the result being divided by the sum of the squared
+/// windows. With the same arguments, this is the inverse of [stft].
+///
+/// # Arguments
+///
+/// * `spectrogram` - The complex spectrograms, of shape `[batch_size, n_fft / 2 + 1, num_frames, 2]`,
+///   as returned by [stft].
+/// * `n_fft` - The size of the Fourier transform.
+/// * `hop_length` - The distance between the starts of two consecutive frames.
+/// * `win_length` - The size of the window, which is padded with zeros on both sides to `n_fft`.
+/// * `window` - The window, of shape `[win_length]`.
+/// * `center` - Whether the signals were padded with `n_fft / 2` elements on both sides.
+/// * `length` - The length of the signals, which are trimmed or padded with zeros. Defaults to
+///   the length covered by the frames, without the centering padding.
+///
+/// # Returns
+///
+/// The signals, of shape `[batch_size, length]`.
+///
+/// # Notes
+///
+/// The elements not covered by a non-zero part of the window can't be recovered, they are left
+/// as zeros.
+pub fn istft<B: Backend>(
+    spectrogram: Tensor<B, 4>,
+    n_fft: usize,
+    hop_length: usize,
+    win_length: usize,
+    window: Tensor<B, 1>,
+    center: bool,
+    length: Option<usize>,
+) -> Tensor<B, 2> {
+    let [batch_size, num_freqs, num_frames, _] = spectrogram.dims();
+    check!(TensorCheck::istft(
+        &spectrogram.shape(),
+        n_fft,
+        hop_length,
+        win_length,
+        window.dims()[0],
+    ));
+
+    let device = spectrogram.device();
+    let window = pad_window(window, n_fft);
+
+    let spectrogram = spectrogram
+        .swap_dims(1, 2)
+        .reshape([batch_size * num_frames, num_freqs, 2]);
+    let real = spectrogram
+        .clone()
+        .slice([0..batch_size * num_frames, 0..num_freqs, 0..1])
+        .reshape([batch_size * num_frames, num_freqs]);
+    let imag = spectrogram
+        .slice([0..batch_size * num_frames, 0..num_freqs, 1..2])
+        .reshape([batch_size * num_frames, num_freqs]);
+
+    // x[n] = 1/N sum_k X[k] e^(2i pi k n / N), with the negative frequencies being the complex
+    // conjugates of the positive ones.
+    let (cos, sin) = inverse_dft_basis::<B>(n_fft, &device);
+    let frames = real.matmul(cos) - imag.matmul(sin);
+    let frames = frames
+        .reshape([batch_size, num_frames, n_fft])
+        .mul(window.clone().reshape([1, 1, n_fft]));
+
+    let full_length = n_fft + hop_length * (num_frames - 1);
+    let signal = frames.fold::<2>(1, full_length, hop_length);
+    let envelope = window
+        .powf_scalar(2.0)
+        .reshape([1, n_fft])
+        .repeat(0, num_frames)
+        .fold::<1>(0, full_length, hop_length);
+
+    let start = if center { n_fft / 2 } else { 0 };
+    let end = match length {
+        Some(length) => start + length,
+        None => full_length - start,
+    };
+    let available = usize::min(end, full_length);
+
+    let signal = signal.narrow(1, start, available - start);
+    let envelope = envelope.narrow(0, start, available - start);
+    let uncovered = envelope.clone().lower_elem(1e-11);
+    let signal = signal.div(envelope.mask_fill(uncovered, 1.0).unsqueeze());
+
+    match end > available {
+        true => Tensor::cat(
+            vec![
+                signal,
+                Tensor::zeros([batch_size, end - available], &device),
+            ],
+            1,
+        ),
+        false => signal,
+    }
+}
+
+/// Pads the signals on both sides along the last dimension.
+fn pad<B: Backend>(waveform: Tensor<B, 2>, padding: usize, mode: PadMode) -> Tensor<B, 2> {
+    if padding == 0 {
+        return waveform;
+    }
+
+    let [batch_size, length] = waveform.dims();
+    let device = waveform.device();
+
+    let index = |position: i64| -> i64 {
+        let last = length as i64 - 1;
+        match mode {
+            PadMode::Reflect if position < 0 => -position,
+            PadMode::Reflect if position > last => 2 * last - position,
+            _ => position.clamp(0, last),
+        }
+    };
+
+    match mode {
+        PadMode::Constant => {
+            let zeros = Tensor::zeros([batch_size, padding], &device);
+            Tensor::cat(vec![zeros.clone(), waveform, zeros], 1)
+        }
+        PadMode::Reflect | PadMode::Replicate => {
+            let indices: Vec<i64> = (-(padding as i64)..(length + padding) as i64)
+                .map(index)
+                .collect();
+            let num_indices = indices.len();
+            let indices = Tensor::<B, 1, Int>::from_data(
+                Data::new(indices, Shape::new([num_indices])).convert(),
+                &device,
+            );
+
+            waveform.select(1, indices)
+        }
+    }
+}
+
+/// Pads the window with zeros on both sides to the size of the Fourier transform.
+fn pad_window<B: Backend>(window: Tensor<B, 1>, n_fft: usize) -> Tensor<B, 1> {
+    let [win_length] = window.dims();
+    if win_length == n_fft {
+        return window;
+    }
+
+    let device = window.device();
+    let left = (n_fft - win_length) / 2;
+    let right = n_fft - win_length - left;
+
+    Tensor::cat(
+        vec![
+            Tensor::zeros([left], &device),
+            window,
+            Tensor::zeros([right], &device),
+        ],
+        0,
+    )
+}
+
+/// The real and imaginary parts of `e^(2i pi k n / N)`, of shape `[N, N / 2 + 1]`.
+fn dft_basis<B: Backend>(n_fft: usize, device: &B::Device) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let num_freqs = n_fft / 2 + 1;
+
+    basis(n_fft, num_freqs, device, |n, k| angle(k * n, n_fft))
+}
+
+/// The real and imaginary parts of `c_k e^(2i pi k n / N) / N`, of shape `[N / 2 + 1, N]`, where
+/// `c_k` accounts for the negative frequencies.
+fn inverse_dft_basis<B: Backend>(n_fft: usize, device: &B::Device) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let num_freqs = n_fft / 2 + 1;
+    let (cos, sin) = basis(num_freqs, n_fft, device, |k, n| angle(k * n, n_fft));
+
+    let scales: Vec<f32> = (0..num_freqs)
+        .map(|k| match k == 0 || 2 * k == n_fft {
+            true => 1.0 / n_fft as f32,
+            false => 2.0 / n_fft as f32,
+        })
+        .collect();
+    let scales = Tensor::<B, 1>::from_floats(Data::new(scales, Shape::new([num_freqs])), device)
+        .reshape([num_freqs, 1]);
+
+    (cos.mul(scales.clone()), sin.mul(scales))
+}
+
+/// The cosines and sines of the angles given for each position of a matrix.
+pub(crate) fn basis<B: Backend, F: Fn(usize, usize) -> f64>(
+    rows: usize,
+    cols: usize,
+    device: &B::Device,
+    angle: F,
+) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let mut cos = Vec::with_capacity(rows * cols);
+    let mut sin = Vec::with_capacity(rows * cols);
+
+    for row in 0..rows {
+        for col in 0..cols {
+            let angle = angle(row, col);
+            cos.push(libm::cos(angle) as f32);
+            sin.push(libm::sin(angle) as f32);
+        }
+    }
+
+    let shape = Shape::new([rows, cols]);
+    (
+        Tensor::from_floats(Data::new(cos, shape.clone()), device),
+        Tensor::from_floats(Data::new(sin, shape), device),
+    )
+}
+
+/// The angle `2 pi m / N`, computed modulo `N` to keep its precision.
+pub(crate) fn angle(m: usize, n_fft: usize) -> f64 {
+    2.0 * PI * (m % n_fft) as f64 / n_fft as f64
+}
@@ -0,0 +1,67 @@
+#[burn_tensor_testgen::testgen(gumbel_softmax)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_gumbel_softmax_sums_to_one() {
+        let tensor = TestTensor::from([[1.0, 2.0, 0.5], [-1.0, 0.0, 3.0]]);
+
+        let output = activation::gumbel_softmax(tensor, 1.0, false, -1);
+
+        output
+            .sum_dim(1)
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0]]), 4);
+    }
+
+    #[test]
+    fn test_gumbel_softmax_hard_is_one_hot() {
+        let tensor = TestTensor::from([[1.0, 2.0, 0.5, 0.0], [-1.0, 0.0, 3.0, 1.0]]);
+
+        let output = activation::gumbel_softmax(tensor, 0.5, true, 1);
+
+        let values = output.clone().into_data().convert::<f32>().value;
+        for value in values {
+            assert!(value.abs() < 1e-5 || (value - 1.0).abs() < 1e-5);
+        }
+        output
+            .sum_dim(1)
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0]]), 4);
+    }
+
+    #[test]
+    fn test_gumbel_softmax_low_temperature_concentrates_on_argmax() {
+        // The logit gap exceeds the range of the Gumbel noise, so the argmax is deterministic.
+        let tensor = TestTensor::from([[0.0, 40.0, 0.0], [0.0, 0.0, 40.0]]);
+
+        let output = activation::gumbel_softmax(tensor, 0.01, false, 1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]), 4);
+    }
+
+    #[test]
+    fn test_gumbel_softmax_along_first_dim() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[0.0, 40.0], [40.0, 0.0], [0.0, 0.0]],
+            &Default::default(),
+        );
+
+        let output = activation::gumbel_softmax(tensor, 1.0, true, 0);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 1.0], [1.0, 0.0], [0.0, 0.0]]), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gumbel_softmax_invalid_temperature() {
+        let tensor = TestTensor::from([[1.0, 2.0]]);
+
+        let _output = activation::gumbel_softmax(tensor, 0.0, false, 1);
+    }
+}
@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(log_sigmoid)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_log_sigmoid() {
+        let tensor = TestTensor::from([[1.0, 2.0], [-1.0, -5.0]]);
+
+        let data_actual = activation::log_sigmoid(tensor).into_data();
+
+        let data_expected = Data::from([[-0.3133, -0.1269], [-1.3133, -5.0067]]);
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+
+    #[test]
+    fn test_log_sigmoid_large_values() {
+        let tensor = TestTensor::from([100.0, -100.0, 1000.0, -1000.0]);
+
+        let data_actual = activation::log_sigmoid(tensor).into_data();
+
+        let data_expected = Data::from([0.0, -100.0, 0.0, -1000.0]);
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+}
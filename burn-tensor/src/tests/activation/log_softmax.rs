@@ -0,0 +1,37 @@
+#[burn_tensor_testgen::testgen(log_softmax)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_log_softmax_d2() {
+        let tensor = TestTensor::from([[1.0, 7.0], [13.0, -3.0]]);
+
+        let data_actual = activation::log_softmax(tensor, 1).into_data();
+
+        let data_expected = Data::from([[-6.0025, -2.4757e-03], [-1.1254e-07, -16.0]]);
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+
+    #[test]
+    fn test_log_softmax_large_logits() {
+        let tensor = TestTensor::from([[1000.0, 1000.0, 1000.0], [-1000.0, 0.0, 1000.0]]);
+
+        let data_actual = tensor.log_softmax(1).into_data();
+
+        let log_third = -(3.0f32.ln());
+        let data_expected =
+            Data::from([[log_third, log_third, log_third], [-2000.0, -1000.0, 0.0]]);
+        data_actual.assert_approx_eq(&data_expected, 3);
+    }
+
+    #[test]
+    fn test_log_softmax_matches_log_of_softmax() {
+        let tensor = TestTensor::from([[0.5, -1.5, 2.0], [3.0, 0.0, -2.5]]);
+
+        let data_actual = tensor.clone().log_softmax(0).into_data();
+
+        let data_expected = activation::softmax(tensor, 0).log().into_data();
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+}
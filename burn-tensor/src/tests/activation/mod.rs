@@ -1,4 +1,7 @@
 pub(crate) mod gelu;
+pub(crate) mod gumbel_softmax;
+pub(crate) mod log_sigmoid;
+pub(crate) mod log_softmax;
 pub(crate) mod mish;
 pub(crate) mod relu;
 pub(crate) mod sigmoid;
@@ -12,4 +12,25 @@ mod tests {
         let data_expected = Data::from([[2.47e-03, 9.975e-01], [1.0, 1.1254e-07]]);
         data_actual.assert_approx_eq(&data_expected, 4);
     }
+
+    #[test]
+    fn test_softmax_large_logits() {
+        let tensor = TestTensor::from([[1000.0, 1000.0, 1000.0], [-1000.0, 0.0, 1000.0]]);
+
+        let data_actual = activation::softmax(tensor, 1).into_data();
+
+        let third = 1.0 / 3.0;
+        let data_expected = Data::from([[third, third, third], [0.0, 0.0, 1.0]]);
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+
+    #[test]
+    fn test_softmax_method() {
+        let tensor = TestTensor::from([[1.0, 7.0], [13.0, -3.0]]);
+
+        let data_actual = tensor.clone().softmax(0).into_data();
+
+        let data_expected = activation::softmax(tensor, 0).into_data();
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
 }
@@ -2,6 +2,7 @@ mod activation;
 mod clone_invariance;
 mod module;
 mod ops;
+mod signal;
 mod stats;
 
 #[allow(missing_docs)]
@@ -10,11 +11,14 @@ macro_rules! testgen_all {
     () => {
         // test activation
         burn_tensor::testgen_gelu!();
+        burn_tensor::testgen_gumbel_softmax!();
         burn_tensor::testgen_mish!();
         burn_tensor::testgen_relu!();
         burn_tensor::testgen_softmax!();
         burn_tensor::testgen_softplus!();
         burn_tensor::testgen_sigmoid!();
+        burn_tensor::testgen_log_sigmoid!();
+        burn_tensor::testgen_log_softmax!();
         burn_tensor::testgen_silu!();
         burn_tensor::testgen_tanh_activation!();
 
@@ -31,6 +35,11 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_avg_pool2d!();
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
         burn_tensor::testgen_module_adaptive_avg_pool2d!();
+        burn_tensor::testgen_module_interpolate!();
+        burn_tensor::testgen_module_grid_sample!();
+        burn_tensor::testgen_module_flash_attention!();
+        burn_tensor::testgen_module_rope_embed!();
+        burn_tensor::testgen_module_scaled_dot_product_attention!();
 
         // test ops
         burn_tensor::testgen_add!();
@@ -40,33 +49,52 @@ macro_rules! testgen_all {
         burn_tensor::testgen_arg!();
         burn_tensor::testgen_cast!();
         burn_tensor::testgen_cat!();
+        burn_tensor::testgen_cdist!();
         burn_tensor::testgen_chunk!();
         burn_tensor::testgen_clamp!();
         burn_tensor::testgen_cos!();
+        burn_tensor::testgen_cosine_similarity!();
         burn_tensor::testgen_create_like!();
         burn_tensor::testgen_div!();
         burn_tensor::testgen_erf!();
+        burn_tensor::testgen_erfinv!();
         burn_tensor::testgen_exp!();
+        burn_tensor::testgen_expand!();
         burn_tensor::testgen_flatten!();
         burn_tensor::testgen_full!();
         burn_tensor::testgen_gather_scatter!();
+        burn_tensor::testgen_histogram!();
+        burn_tensor::testgen_as_strided!();
+        burn_tensor::testgen_bucketize!();
+        burn_tensor::testgen_multinomial!();
+        burn_tensor::testgen_index_put!();
         burn_tensor::testgen_init!();
         burn_tensor::testgen_iter_dim!();
         burn_tensor::testgen_log!();
         burn_tensor::testgen_log1p!();
+        burn_tensor::testgen_logit!();
+        burn_tensor::testgen_logical!();
         burn_tensor::testgen_map_comparison!();
         burn_tensor::testgen_mask!();
         burn_tensor::testgen_matmul!();
+        burn_tensor::testgen_kron!();
         burn_tensor::testgen_maxmin!();
         burn_tensor::testgen_mul!();
         burn_tensor::testgen_narrow!();
         burn_tensor::testgen_neg!();
+        burn_tensor::testgen_nonzero!();
+        burn_tensor::testgen_normalize!();
+        burn_tensor::testgen_nan_aggregation!();
+        burn_tensor::testgen_nan_to_num!();
+        burn_tensor::testgen_nextafter!();
         burn_tensor::testgen_one_hot!();
         burn_tensor::testgen_powf_scalar!();
         burn_tensor::testgen_random!();
         burn_tensor::testgen_recip!();
         burn_tensor::testgen_repeat!();
         burn_tensor::testgen_reshape!();
+        burn_tensor::testgen_roll!();
+        burn_tensor::testgen_segment_reduce!();
         burn_tensor::testgen_select!();
         burn_tensor::testgen_sin!();
         burn_tensor::testgen_slice!();
@@ -75,11 +103,31 @@ macro_rules! testgen_all {
         burn_tensor::testgen_abs!();
         burn_tensor::testgen_squeeze!();
         burn_tensor::testgen_sub!();
+        burn_tensor::testgen_svd!();
+        burn_tensor::testgen_eigh!();
+        burn_tensor::testgen_solve!();
+        burn_tensor::testgen_cholesky!();
         burn_tensor::testgen_tanh!();
+        burn_tensor::testgen_tensordot!();
+        burn_tensor::testgen_to_device_async!();
+        burn_tensor::testgen_topk!();
+        burn_tensor::testgen_sort!();
+        burn_tensor::testgen_unique!();
+        burn_tensor::testgen_fft!();
+        burn_tensor::testgen_cumulative!();
+        burn_tensor::testgen_logcumsumexp!();
+        burn_tensor::testgen_einsum!();
+        burn_tensor::testgen_unbind!();
+        burn_tensor::testgen_vmap!();
+        burn_tensor::testgen_where_cond!();
+        burn_tensor::testgen_unfold!();
         burn_tensor::testgen_transpose!();
         burn_tensor::testgen_tri!();
         burn_tensor::testgen_powf!();
 
+        // test signal
+        burn_tensor::testgen_signal_stft!();
+
         // test stats
         burn_tensor::testgen_var!();
         burn_tensor::testgen_cov!();
@@ -0,0 +1,91 @@
+#[burn_tensor_testgen::testgen(module_flash_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::flash_attention;
+    use burn_tensor::{activation, Data, Distribution, Tensor};
+
+    #[test]
+    fn test_flash_attention() {
+        let device = Default::default();
+        let query = TestTensor::from_floats([[[[1.0, 0.0], [0.0, 1.0]]]], &device);
+        let key = TestTensor::from_floats([[[[1.0, 1.0], [2.0, 0.0], [0.0, -1.0]]]], &device);
+        let value = TestTensor::from_floats([[[[1.0, 2.0], [3.0, 4.0], [-1.0, 0.0]]]], &device);
+
+        let output = flash_attention(query, key, value, false);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[1.8719, 2.8719], [1.2879, 2.2879]]]]), 3);
+    }
+
+    #[test]
+    fn test_flash_attention_causal() {
+        let device = Default::default();
+        let query = TestTensor::from_floats([[[[1.0, 0.0], [0.0, 1.0]]]], &device);
+        let key = TestTensor::from_floats([[[[1.0, 1.0], [2.0, 0.0], [0.0, -1.0]]]], &device);
+        let value = TestTensor::from_floats([[[[1.0, 2.0], [3.0, 4.0], [-1.0, 0.0]]]], &device);
+
+        let output = flash_attention(query, key, value, true);
+
+        // The last query is aligned with the last key, so the first query sees the two first keys.
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[2.3395, 3.3395], [1.2879, 2.2879]]]]), 3);
+    }
+
+    #[test]
+    fn test_flash_attention_should_match_reference() {
+        let device = Default::default();
+        let query = TestTensor::<4>::random([2, 3, 19, 16], Distribution::Default, &device);
+        let key = TestTensor::<4>::random([2, 3, 37, 16], Distribution::Default, &device);
+        let value = TestTensor::<4>::random([2, 3, 37, 16], Distribution::Default, &device);
+
+        for causal in [false, true] {
+            let output = flash_attention(query.clone(), key.clone(), value.clone(), causal);
+            let expected = reference(query.clone(), key.clone(), value.clone(), causal);
+
+            output
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 4);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_flash_attention_causal_should_panic_with_fewer_keys_than_queries() {
+        let device = Default::default();
+        let query = TestTensor::<4>::ones([1, 1, 3, 2], &device);
+        let key = TestTensor::<4>::ones([1, 1, 2, 2], &device);
+        let value = TestTensor::<4>::ones([1, 1, 2, 2], &device);
+
+        flash_attention(query, key, value, true);
+    }
+
+    fn reference(
+        query: TestTensor<4>,
+        key: TestTensor<4>,
+        value: TestTensor<4>,
+        causal: bool,
+    ) -> TestTensor<4> {
+        let [_, _, seq_length_1, head_dim] = query.dims();
+        let [_, _, seq_length_2, _] = key.dims();
+        let mut scores = query
+            .matmul(key.transpose())
+            .div_scalar((head_dim as f32).sqrt());
+
+        if causal {
+            let offset = seq_length_2 - seq_length_1;
+            let mask: Vec<bool> = (0..seq_length_1)
+                .flat_map(|i| (0..seq_length_2).map(move |j| j > i + offset))
+                .collect();
+            let mask = Tensor::<TestBackend, 1, burn_tensor::Bool>::from_data(
+                Data::from(mask.as_slice()),
+                &scores.device(),
+            )
+            .reshape([1, 1, seq_length_1, seq_length_2]);
+            scores = scores.mask_fill(mask, f32::NEG_INFINITY);
+        }
+
+        activation::softmax(scores, 3).matmul(value)
+    }
+}
@@ -0,0 +1,138 @@
+#[burn_tensor_testgen::testgen(module_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample;
+    use burn_tensor::ops::{GridSampleMode, GridSamplePaddingMode};
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_grid_sample_bilinear_zeros() {
+        let output = grid_sample_arange(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            false,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 6.9, 2.38], [1.0, 0.0, 6.5]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_zeros_align_corners() {
+        let output =
+            grid_sample_arange(GridSampleMode::Bilinear, GridSamplePaddingMode::Zeros, true);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 6.4, 4.45], [6.3, 3.96, 6.25]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_border() {
+        let output = grid_sample_arange(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Border,
+            false,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 6.9, 3.4], [10.0, 8.0, 6.5]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_reflection() {
+        let output = grid_sample_arange(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Reflection,
+            false,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 6.9, 3.4], [10.0, 8.1, 6.5]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_reflection_align_corners() {
+        let output = grid_sample_arange(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Reflection,
+            true,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 6.4, 4.45], [8.7, 8.05, 6.25]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_nearest_zeros() {
+        // The horizontal coordinate of the last location is exactly between two pixels, it is
+        // rounded to the even one.
+        let output =
+            grid_sample_arange(GridSampleMode::Nearest, GridSamplePaddingMode::Zeros, false);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 5.0, 3.0], [0.0, 0.0, 6.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_nearest_border() {
+        let output = grid_sample_arange(
+            GridSampleMode::Nearest,
+            GridSamplePaddingMode::Border,
+            false,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 5.0, 3.0], [11.0, 8.0, 6.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_batch_and_channels() {
+        let input = TestTensor::from([
+            [[[1.0, 2.0], [3.0, 4.0]], [[-1.0, -2.0], [-3.0, -4.0]]],
+            [[[5.0, 6.0], [7.0, 8.0]], [[-5.0, -6.0], [-7.0, -8.0]]],
+        ]);
+        let grid = TestTensor::from([[[[0.0, 0.0], [0.5, -0.5]]], [[[-0.5, 0.5], [1.0, 1.0]]]]);
+
+        let output = grid_sample(
+            input,
+            grid,
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Border,
+            true,
+        );
+
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [[[2.5, 2.25]], [[-2.5, -2.25]]],
+                [[[6.75, 8.0]], [[-6.75, -8.0]]],
+            ]),
+            3,
+        );
+    }
+
+    /// Samples a `3x4` image with values from 0 to 11 at six locations, some of them outside of
+    /// the image.
+    fn grid_sample_arange(
+        mode: GridSampleMode,
+        padding_mode: GridSamplePaddingMode,
+        align_corners: bool,
+    ) -> TestTensor<4> {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 1, burn_tensor::Int>::arange(0..12, &device)
+            .reshape(Shape::new([1, 1, 3, 4]))
+            .float();
+        let grid = TestTensor::from([[
+            [[-1.0, -1.0], [-0.2, 0.3], [0.9, -0.6]],
+            [[1.2, 0.5], [-1.3, 1.1], [0.5, 0.0]],
+        ]]);
+
+        grid_sample(input, grid, mode, padding_mode, align_corners)
+    }
+}
@@ -0,0 +1,146 @@
+#[burn_tensor_testgen::testgen(module_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::interpolate;
+    use burn_tensor::ops::InterpolateMode;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_interpolate_nearest_downsample() {
+        let x = arange_image(4, 4);
+
+        let output = interpolate(x, [2, 3], InterpolateMode::Nearest);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0., 1., 2.], [8., 9., 10.]]]]), 3);
+    }
+
+    #[test]
+    fn test_interpolate_bilinear_upsample() {
+        let x = TestTensor::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = interpolate(
+            x,
+            [4, 4],
+            InterpolateMode::Bilinear {
+                align_corners: false,
+            },
+        );
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [1.0, 1.25, 1.75, 2.0],
+                [1.5, 1.75, 2.25, 2.5],
+                [2.5, 2.75, 3.25, 3.5],
+                [3.0, 3.25, 3.75, 4.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_interpolate_bilinear_align_corners_upsample() {
+        let x = TestTensor::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = interpolate(
+            x,
+            [4, 4],
+            InterpolateMode::Bilinear {
+                align_corners: true,
+            },
+        );
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [1.0, 1.3333, 1.6667, 2.0],
+                [1.6667, 2.0, 2.3333, 2.6667],
+                [2.3333, 2.6667, 3.0, 3.3333],
+                [3.0, 3.3333, 3.6667, 4.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_interpolate_bilinear_downsample() {
+        let x = arange_image(4, 4);
+
+        let output = interpolate(
+            x.clone(),
+            [3, 2],
+            InterpolateMode::Bilinear {
+                align_corners: false,
+            },
+        );
+        let output_corners = interpolate(
+            x,
+            [2, 2],
+            InterpolateMode::Bilinear {
+                align_corners: true,
+            },
+        );
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[[1.1667, 3.1667], [6.5, 8.5], [11.8333, 13.8333]]]]),
+            3,
+        );
+        output_corners
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0., 3.], [12., 15.]]]]), 3);
+    }
+
+    #[test]
+    fn test_interpolate_bicubic_upsample() {
+        let x = TestTensor::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = interpolate(x, [4, 4], InterpolateMode::Bicubic);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [0.6836, 1.0156, 1.5625, 1.8945],
+                [1.3477, 1.6797, 2.2266, 2.5586],
+                [2.4414, 2.7734, 3.3203, 3.6523],
+                [3.1055, 3.4375, 3.9844, 4.3164],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_interpolate_bicubic_downsample() {
+        let x = arange_image(4, 4);
+
+        let output = interpolate(x, [3, 3], InterpolateMode::Bicubic);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [0.6308, 2.0046, 3.3785],
+                [6.1262, 7.5, 8.8738],
+                [11.6215, 12.9954, 14.3692],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_interpolate_keeps_batch_and_channels() {
+        let device = Default::default();
+        let x = TestTensor::ones([2, 3, 5, 7], &device);
+
+        let output = interpolate(x, [9, 4], InterpolateMode::Bicubic);
+
+        assert_eq!(output.dims(), [2, 3, 9, 4]);
+        output
+            .into_data()
+            .assert_approx_eq(&TestTensor::ones([2, 3, 9, 4], &device).into_data(), 3);
+    }
+
+    fn arange_image(height: usize, width: usize) -> TestTensor<4> {
+        let device = Default::default();
+
+        Tensor::<TestBackend, 1, burn_tensor::Int>::arange(0..(height * width) as i64, &device)
+            .reshape(Shape::new([1, 1, height, width]))
+            .float()
+    }
+}
@@ -6,7 +6,12 @@ mod conv1d;
 mod conv2d;
 mod conv_transpose1d;
 mod conv_transpose2d;
+mod flash_attention;
 mod forward;
+mod grid_sample;
+mod interpolate;
 mod maxpool1d;
 mod maxpool2d;
+mod rope_embed;
+mod scaled_dot_product_attention;
 mod unfold4d;
@@ -0,0 +1,85 @@
+#[burn_tensor_testgen::testgen(module_rope_embed)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::rope_embed;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_rope_embed() {
+        let device = Default::default();
+        let query =
+            TestTensor::from_floats([[[[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]]], &device);
+        let key =
+            TestTensor::from_floats([[[[0.5, -1.0, 2.0, 0.0], [1.0, 1.0, 1.0, 1.0]]]], &device);
+        let (cos, sin) = tables(&device);
+
+        let (query, key) = rope_embed(query, key, cos, sin);
+
+        query.into_data().assert_approx_eq(
+            &Data::from([[[[1.0, 2.0, 3.0, 4.0], [1.0319, -3.49, 8.5402, 9.3712]]]]),
+            3,
+        );
+        key.into_data().assert_approx_eq(
+            &Data::from([[[[0.5, -1.0, 2.0, 0.0], [0.3982, -0.3012, 1.357, 1.3818]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_rope_embed_with_different_number_of_heads() {
+        let device = Default::default();
+        let query = TestTensor::<4>::ones([2, 4, 2, 4], &device);
+        let key = TestTensor::<4>::ones([2, 1, 2, 4], &device);
+        let (cos, sin) = tables(&device);
+
+        let (query, key) = rope_embed(query, key, cos, sin);
+
+        assert_eq!(query.dims(), [2, 4, 2, 4]);
+        assert_eq!(key.dims(), [2, 1, 2, 4]);
+        query
+            .slice([0..2, 0..4, 1..2, 0..4])
+            .into_data()
+            .assert_approx_eq(
+                &TestTensor::<1>::from_floats([0.3982, -0.3012, 1.357, 1.3818], &device)
+                    .reshape([1, 1, 1, 4])
+                    .repeat(0, 2)
+                    .repeat(1, 4)
+                    .into_data(),
+                3,
+            );
+    }
+
+    #[test]
+    fn test_rope_embed_should_preserve_norms() {
+        let device = Default::default();
+        let query =
+            TestTensor::from_floats([[[[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]]], &device);
+        let (cos, sin) = tables(&device);
+
+        let (output, _) = rope_embed(query.clone(), query.clone(), cos, sin);
+
+        output
+            .powf_scalar(2.0)
+            .sum_dim(3)
+            .into_data()
+            .assert_approx_eq(&query.powf_scalar(2.0).sum_dim(3).into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rope_embed_should_panic_when_tables_dont_match_heads() {
+        let device = Default::default();
+        let query = TestTensor::<4>::ones([1, 1, 2, 6], &device);
+        let (cos, sin) = tables(&device);
+
+        let _ = rope_embed(query.clone(), query, cos, sin);
+    }
+
+    fn tables(
+        device: &<TestBackend as burn_tensor::backend::Backend>::Device,
+    ) -> (Tensor<TestBackend, 2>, Tensor<TestBackend, 2>) {
+        let angles = TestTensor::from_floats([[0.0, 0.0], [0.5, 1.0]], device);
+
+        (angles.clone().cos(), angles.sin())
+    }
+}
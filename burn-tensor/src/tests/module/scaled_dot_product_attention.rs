@@ -0,0 +1,151 @@
+#[burn_tensor_testgen::testgen(module_scaled_dot_product_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{flash_attention, scaled_dot_product_attention};
+    use burn_tensor::{activation, Bool, Data, Distribution, Int, Tensor};
+
+    #[test]
+    fn test_scaled_dot_product_attention_with_mask() {
+        let device = Default::default();
+        let query = TestTensor::from_floats([[[[1.0, 0.0], [0.0, 1.0]]]], &device);
+        let key = TestTensor::from_floats([[[[1.0, 1.0], [2.0, 0.0], [0.0, -1.0]]]], &device);
+        let value = TestTensor::from_floats([[[[1.0, 2.0], [3.0, 4.0], [-1.0, 0.0]]]], &device);
+        let mask = Tensor::<TestBackend, 4, Bool>::from_bool(
+            Data::from([[[[false, true, false], [false, false, true]]]]),
+            &device,
+        );
+
+        let output = scaled_dot_product_attention(query, key, value, Some(mask), 0.0, false);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.3395, 1.3395], [1.6605, 2.6605]]]]), 3);
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_should_broadcast_mask() {
+        let device = Default::default();
+        let query = TestTensor::<4>::random([2, 3, 5, 4], Distribution::Default, &device);
+        let key = TestTensor::<4>::random([2, 3, 6, 4], Distribution::Default, &device);
+        let value = TestTensor::<4>::random([2, 3, 6, 4], Distribution::Default, &device);
+        let mask: Vec<bool> = (0..30).map(|i| i % 4 == 1).collect();
+        let mask = Tensor::<TestBackend, 1, Bool>::from_data(Data::from(mask.as_slice()), &device)
+            .reshape([1, 1, 5, 6]);
+
+        for causal in [false, true] {
+            let output = scaled_dot_product_attention(
+                query.clone(),
+                key.clone(),
+                value.clone(),
+                Some(mask.clone()),
+                0.0,
+                causal,
+            );
+            let expected = reference(
+                query.clone(),
+                key.clone(),
+                value.clone(),
+                mask.clone().repeat(0, 2).repeat(1, 3),
+                causal,
+            );
+
+            output
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 4);
+        }
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_without_mask_should_match_flash_attention() {
+        let device = Default::default();
+        let query = TestTensor::<4>::random([2, 2, 7, 8], Distribution::Default, &device);
+        let key = TestTensor::<4>::random([2, 2, 9, 8], Distribution::Default, &device);
+        let value = TestTensor::<4>::random([2, 2, 9, 8], Distribution::Default, &device);
+
+        let output = scaled_dot_product_attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            None,
+            0.0,
+            true,
+        );
+        let expected = flash_attention(query, key, value, true);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_with_dropout_should_scale_kept_weights() {
+        let device = Default::default();
+        let query = TestTensor::<4>::random([1, 2, 50, 4], Distribution::Default, &device);
+        let key = TestTensor::<4>::zeros([1, 2, 8, 4], &device);
+        let value = TestTensor::<4>::ones([1, 2, 8, 1], &device);
+
+        // With uniform weights of 1/8, each output is the number of kept keys divided by 4.
+        let output = scaled_dot_product_attention(query, key, value, None, 0.5, false)
+            .mul_scalar(4.0)
+            .into_data()
+            .convert::<f32>();
+
+        for output in output.value {
+            assert_eq!(output, output.round());
+            assert!((0.0..=8.0).contains(&output));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scaled_dot_product_attention_should_panic_with_wrong_mask_shape() {
+        let device = Default::default();
+        let query = TestTensor::<4>::ones([2, 1, 3, 2], &device);
+        let key = TestTensor::<4>::ones([2, 1, 4, 2], &device);
+        let value = TestTensor::<4>::ones([2, 1, 4, 2], &device);
+        let mask = Tensor::<TestBackend, 4, Int>::zeros([2, 1, 4, 3], &device).equal_elem(1);
+
+        scaled_dot_product_attention(query, key, value, Some(mask), 0.0, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scaled_dot_product_attention_should_panic_with_invalid_dropout() {
+        let device = Default::default();
+        let query = TestTensor::<4>::ones([1, 1, 3, 2], &device);
+
+        scaled_dot_product_attention(query.clone(), query.clone(), query, None, 1.0, false);
+    }
+
+    fn reference(
+        query: TestTensor<4>,
+        key: TestTensor<4>,
+        value: TestTensor<4>,
+        mask: Tensor<TestBackend, 4, Bool>,
+        causal: bool,
+    ) -> TestTensor<4> {
+        let [batch_size, num_heads, seq_length_1, head_dim] = query.dims();
+        let [_, _, seq_length_2, _] = key.dims();
+        let mut scores = query
+            .matmul(key.transpose())
+            .div_scalar((head_dim as f32).sqrt())
+            .mask_fill(mask, f32::NEG_INFINITY);
+
+        if causal {
+            let offset = seq_length_2 - seq_length_1;
+            let mask: Vec<bool> = (0..seq_length_1)
+                .flat_map(|i| (0..seq_length_2).map(move |j| j > i + offset))
+                .collect();
+            let mask = Tensor::<TestBackend, 1, Bool>::from_data(
+                Data::from(mask.as_slice()),
+                &scores.device(),
+            )
+            .reshape([1, 1, seq_length_1, seq_length_2])
+            .repeat(0, batch_size)
+            .repeat(1, num_heads);
+            scores = scores.mask_fill(mask, f32::NEG_INFINITY);
+        }
+
+        activation::softmax(scores, 3).matmul(value)
+    }
+}
@@ -0,0 +1,43 @@
+#[burn_tensor_testgen::testgen(as_strided)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_create_sliding_windows() {
+        let tensor = TestTensor::from([1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        let output: TestTensor<2> = tensor.as_strided([3, 3], [1, 1], 0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 2.0, 3.0], [2.0, 3.0, 4.0], [3.0, 4.0, 5.0]])
+        );
+    }
+
+    #[test]
+    fn should_transpose_with_strides() {
+        let tensor = TestTensorInt::arange(0..6, &Default::default()).reshape([2, 3]);
+
+        let output = tensor.as_strided([3, 2], [1, 3], 0);
+
+        assert_eq!(output.into_data(), Data::from([[0, 3], [1, 4], [2, 5]]));
+    }
+
+    #[test]
+    fn should_support_offset_and_zero_stride() {
+        let tensor = TestTensorInt::arange(0..6, &Default::default());
+
+        let output = tensor.as_strided([2, 3], [0, 2], 1);
+
+        assert_eq!(output.into_data(), Data::from([[1, 3, 5], [1, 3, 5]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_reading_out_of_bounds() {
+        let tensor = TestTensor::from([1.0, 2.0, 3.0, 4.0]);
+
+        let _output: TestTensor<2> = tensor.as_strided([2, 2], [2, 1], 1);
+    }
+}
@@ -0,0 +1,82 @@
+#[burn_tensor_testgen::testgen(bucketize)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    fn boundaries() -> TestTensor<1> {
+        TestTensor::from_floats([1.0, 3.0, 5.0, 7.0, 9.0], &Default::default())
+    }
+
+    #[test]
+    fn should_bucketize_boundary_values_left() {
+        let tensor =
+            TestTensor::from_floats([[3.0, 6.0, 9.0], [1.0, 4.0, 7.0]], &Default::default());
+
+        let buckets = tensor.bucketize(boundaries(), false);
+
+        assert_eq!(buckets.into_data(), Data::from([[1, 3, 4], [0, 2, 3]]));
+    }
+
+    #[test]
+    fn should_bucketize_boundary_values_right() {
+        let tensor =
+            TestTensor::from_floats([[3.0, 6.0, 9.0], [1.0, 4.0, 7.0]], &Default::default());
+
+        let buckets = tensor.bucketize(boundaries(), true);
+
+        assert_eq!(buckets.into_data(), Data::from([[2, 3, 5], [1, 2, 4]]));
+    }
+
+    #[test]
+    fn should_bucketize_extremes() {
+        let tensor = TestTensor::from_floats(
+            [
+                f32::NEG_INFINITY,
+                -100.0,
+                0.999,
+                9.001,
+                100.0,
+                f32::INFINITY,
+            ],
+            &Default::default(),
+        );
+
+        let left = tensor.clone().bucketize(boundaries(), false);
+        let right = tensor.bucketize(boundaries(), true);
+
+        assert_eq!(left.into_data(), Data::from([0, 0, 0, 5, 5, 5]));
+        assert_eq!(right.into_data(), Data::from([0, 0, 0, 5, 5, 5]));
+    }
+
+    #[test]
+    fn should_bucketize_with_duplicate_boundaries() {
+        let tensor = TestTensor::from_floats([1.0, 2.0, 3.0], &Default::default());
+        let boundaries = TestTensor::from_floats([2.0, 2.0, 2.0], &Default::default());
+
+        let left = tensor.clone().bucketize(boundaries.clone(), false);
+        let right = tensor.bucketize(boundaries, true);
+
+        assert_eq!(left.into_data(), Data::from([0, 0, 3]));
+        assert_eq!(right.into_data(), Data::from([0, 3, 3]));
+    }
+
+    #[test]
+    fn should_bucketize_with_a_single_boundary() {
+        let tensor = TestTensor::from_floats([-1.0, 0.0, 1.0], &Default::default());
+        let boundaries = TestTensor::from_floats([0.0], &Default::default());
+
+        let buckets = tensor.bucketize(boundaries, false);
+
+        assert_eq!(buckets.into_data(), Data::from([0, 0, 1]));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn should_panic_when_boundaries_are_not_sorted() {
+        let tensor = TestTensor::from_floats([1.0, 2.0], &Default::default());
+        let boundaries = TestTensor::from_floats([1.0, 3.0, 2.0], &Default::default());
+
+        let _ = tensor.bucketize(boundaries, false);
+    }
+}
@@ -0,0 +1,116 @@
+#[burn_tensor_testgen::testgen(cdist)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+    use core::f32::consts::SQRT_2;
+
+    #[test]
+    fn test_cdist_euclidean() {
+        let (x1, x2) = inputs();
+
+        let distances = x1.cdist(x2, 2.0);
+
+        distances.into_data().assert_approx_eq(
+            &Data::from([
+                [[5.0, SQRT_2], [3.6056, 0.0], [5.099, 2.2361]],
+                [[2.2361, 0.0], [1.0, 3.1623], [3.0, 2.8284]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_cdist_manhattan() {
+        let (x1, x2) = inputs();
+
+        let distances = x1.cdist(x2, 1.0);
+
+        distances.into_data().assert_approx_eq(
+            &Data::from([
+                [[7.0, 2.0], [5.0, 0.0], [6.0, 3.0]],
+                [[3.0, 0.0], [1.0, 4.0], [3.0, 4.0]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_cdist_p_3() {
+        let (x1, x2) = inputs();
+
+        let distances = x1.cdist(x2, 3.0);
+
+        distances.into_data().assert_approx_eq(
+            &Data::from([
+                [[4.4979, 1.2599], [3.2711, 0.0], [5.0133, 2.0801]],
+                [[2.0801, 0.0], [1.0, 3.0366], [3.0, 2.5198]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_cdist_infinity() {
+        let (x1, x2) = inputs();
+
+        let distances = x1.cdist(x2, f64::INFINITY);
+
+        distances.into_data().assert_approx_eq(
+            &Data::from([
+                [[4.0, 1.0], [3.0, 0.0], [5.0, 2.0]],
+                [[2.0, 0.0], [1.0, 3.0], [3.0, 2.0]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_cdist_zero_counts_different_features() {
+        let (x1, x2) = inputs();
+
+        let distances = x1.cdist(x2, 0.0);
+
+        distances.into_data().assert_approx_eq(
+            &Data::from([
+                [[2.0, 2.0], [2.0, 0.0], [2.0, 2.0]],
+                [[2.0, 0.0], [1.0, 2.0], [1.0, 2.0]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cdist_should_panic_when_features_dont_match() {
+        let device = Default::default();
+        let x1 = TestTensor::<3>::zeros([1, 2, 3], &device);
+        let x2 = TestTensor::<3>::zeros([1, 2, 2], &device);
+
+        x1.cdist(x2, 2.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cdist_should_panic_on_negative_p() {
+        let (x1, x2) = inputs();
+
+        x1.cdist(x2, -1.0);
+    }
+
+    fn inputs() -> (TestTensor<3>, TestTensor<3>) {
+        let device = Default::default();
+        let x1 = Tensor::from_data(
+            Data::from([
+                [[0.0, 0.0], [1.0, 1.0], [2.0, -1.0]],
+                [[1.0, 2.0], [0.0, -1.0], [3.0, 0.0]],
+            ]),
+            &device,
+        );
+        let x2 = Tensor::from_data(
+            Data::from([[[3.0, 4.0], [1.0, 1.0]], [[0.0, 0.0], [1.0, 2.0]]]),
+            &device,
+        );
+
+        (x1, x2)
+    }
+}
@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(cholesky)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_cholesky() {
+        let tensor = TestTensor::from([[4.0, 2.0], [2.0, 5.0]]);
+
+        let factor = tensor.cholesky();
+
+        factor
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0, 0.0], [1.0, 2.0]]), 5);
+    }
+
+    #[test]
+    fn test_cholesky_reconstruction() {
+        let tensor = TestTensor::from([[6.0, 2.0, 1.0], [2.0, 5.0, -1.0], [1.0, -1.0, 4.0]]);
+
+        let factor = tensor.clone().cholesky();
+
+        factor.clone().triu(1).into_data().assert_approx_eq(
+            &TestTensor::zeros([3, 3], &Default::default()).into_data(),
+            5,
+        );
+        factor
+            .clone()
+            .matmul(factor.transpose())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+    }
+
+    #[test]
+    fn test_cholesky_batched() {
+        let tensor = Tensor::<TestBackend, 3>::from_floats(
+            [[[1.0, 0.0], [0.0, 9.0]], [[2.0, 1.0], [1.0, 2.0]]],
+            &Default::default(),
+        );
+
+        let factor = tensor.clone().cholesky();
+
+        factor
+            .clone()
+            .matmul(factor.transpose())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cholesky_not_positive_definite() {
+        let tensor = TestTensor::from([[1.0, 2.0], [2.0, 1.0]]);
+
+        let _ = tensor.cholesky().into_data();
+    }
+
+    #[test]
+    fn test_cholesky_solve() {
+        let tensor = TestTensor::from([[6.0, 2.0, 1.0], [2.0, 5.0, -1.0], [1.0, -1.0, 4.0]]);
+        let b = TestTensor::from([[1.0, 0.0], [2.0, 1.0], [3.0, -1.0]]);
+
+        let x = tensor.clone().cholesky().cholesky_solve(b.clone(), false);
+
+        tensor
+            .matmul(x)
+            .into_data()
+            .assert_approx_eq(&b.into_data(), 4);
+    }
+
+    #[test]
+    fn test_cholesky_solve_upper() {
+        let tensor = TestTensor::from([[4.0, 2.0], [2.0, 5.0]]);
+        let b = TestTensor::from([[8.0], [12.0]]);
+
+        let x = tensor.cholesky().transpose().cholesky_solve(b, true);
+
+        x.into_data()
+            .assert_approx_eq(&Data::from([[1.0], [2.0]]), 4);
+    }
+}
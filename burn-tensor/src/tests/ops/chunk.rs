@@ -89,6 +89,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_chunk_cat_round_trip() {
+        let tensor: Tensor<TestBackend, 2, Int> =
+            Tensor::arange(0..15, &Default::default()).reshape([3, 5]);
+
+        let tensors = tensor.clone().chunk(2, 1);
+        let output = Tensor::cat(tensors, 1);
+
+        assert_eq!(output.into_data(), tensor.into_data());
+    }
+
     #[test]
     #[should_panic]
     fn test_invalid_dim() {
@@ -0,0 +1,78 @@
+#[burn_tensor_testgen::testgen(cosine_similarity)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+    use core::f32::consts::FRAC_1_SQRT_2;
+
+    #[test]
+    fn test_pairwise_cosine_similarity() {
+        let x1 = TestTensor::from([[1.0, 0.0], [1.0, 1.0], [3.0, 4.0]]);
+        let x2 = TestTensor::from([[0.0, 2.0], [-3.0, 0.0]]);
+
+        let similarities = x1.pairwise_cosine_similarity(x2);
+
+        similarities.into_data().assert_approx_eq(
+            &Data::from([[0.0, -1.0], [FRAC_1_SQRT_2, -FRAC_1_SQRT_2], [0.8, -0.6]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_pairwise_cosine_similarity_is_scale_invariant() {
+        let x1 = TestTensor::from([[1.0, -2.0, 0.5], [0.3, 0.1, 2.0]]);
+        let x2 = TestTensor::from([[2.0, 1.0, -1.0]]);
+
+        let similarities = x1.clone().pairwise_cosine_similarity(x2.clone());
+        let scaled = x1
+            .mul_scalar(10.0)
+            .pairwise_cosine_similarity(x2.mul_scalar(0.1));
+
+        similarities
+            .into_data()
+            .assert_approx_eq(&scaled.into_data(), 4);
+    }
+
+    #[test]
+    fn test_pairwise_cosine_similarity_of_zero_rows() {
+        let x1 = TestTensor::from([[0.0, 0.0], [1.0, 0.0]]);
+        let x2 = TestTensor::from([[1.0, 1.0]]);
+
+        let similarities = x1.pairwise_cosine_similarity(x2);
+
+        similarities
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0], [FRAC_1_SQRT_2]]), 4);
+    }
+
+    #[test]
+    fn test_cosine_similarity_matrix() {
+        let tensor = TestTensor::from([[1.0, 0.0], [1.0, 1.0], [0.0, -2.0]]);
+
+        let similarities = tensor.clone().cosine_similarity_matrix();
+
+        similarities.clone().into_data().assert_approx_eq(
+            &Data::from([
+                [1.0, FRAC_1_SQRT_2, 0.0],
+                [FRAC_1_SQRT_2, 1.0, -FRAC_1_SQRT_2],
+                [0.0, -FRAC_1_SQRT_2, 1.0],
+            ]),
+            4,
+        );
+        similarities.into_data().assert_approx_eq(
+            &tensor
+                .clone()
+                .pairwise_cosine_similarity(tensor)
+                .into_data(),
+            4,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pairwise_cosine_similarity_should_panic_with_different_features() {
+        let x1 = TestTensor::from([[1.0, 0.0]]);
+        let x2 = TestTensor::from([[1.0, 0.0, 1.0]]);
+
+        let _similarities = x1.pairwise_cosine_similarity(x2);
+    }
+}
@@ -0,0 +1,90 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_cumsum_last_dim() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 2., 3.], [4., 5., 6.]]),
+            &Default::default(),
+        );
+
+        let output = tensor.cumsum(1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1., 3., 6.], [4., 9., 15.]]), 3);
+    }
+
+    #[test]
+    fn test_cumsum_first_dim() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 2., 3.], [4., 5., 6.]]),
+            &Default::default(),
+        );
+
+        let output = tensor.cumsum(0);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1., 2., 3.], [5., 7., 9.]]), 3);
+    }
+
+    #[test]
+    fn test_cumsum_3d_middle_dim() {
+        let tensor = TestTensor::from_data(
+            Data::from([
+                [[1., -1.], [2., 0.], [3., 1.]],
+                [[0., 4.], [-2., 5.], [1., 6.]],
+            ]),
+            &Default::default(),
+        );
+
+        let output = tensor.cumsum(1);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [[1., -1.], [3., -1.], [6., 0.]],
+                [[0., 4.], [-2., 9.], [-1., 15.]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_cumprod_last_dim() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 2., 3.], [4., 5., 6.]]),
+            &Default::default(),
+        );
+
+        let output = tensor.cumprod(1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1., 2., 6.], [4., 20., 120.]]), 3);
+    }
+
+    #[test]
+    fn test_cumprod_first_dim_with_zero() {
+        let tensor = TestTensor::from_data(
+            Data::from([[2., -1.], [0., 3.], [4., 0.5]]),
+            &Default::default(),
+        );
+
+        let output = tensor.cumprod(0);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[2., -1.], [0., -3.], [0., -1.5]]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cumsum_invalid_dim() {
+        let tensor = TestTensor::from_data(Data::from([[3., 1., 2.]]), &Default::default());
+
+        let output = tensor.cumsum(2);
+    }
+}
@@ -0,0 +1,109 @@
+#[burn_tensor_testgen::testgen(eigh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    fn reconstruct<const D: usize, const D2: usize>(
+        vectors: Tensor<TestBackend, D>,
+        values: Tensor<TestBackend, D2>,
+    ) -> Tensor<TestBackend, D> {
+        vectors
+            .clone()
+            .mul(values.unsqueeze_dim(D2 - 1))
+            .matmul(vectors.transpose())
+    }
+
+    fn assert_orthonormal_columns<const D: usize>(tensor: Tensor<TestBackend, D>) {
+        let size = tensor.dims()[D - 1];
+        let gram = tensor.clone().transpose().matmul(tensor);
+        let identity = TestTensor::from_data(
+            Data::new(
+                (0..size * size)
+                    .map(|index| if index % (size + 1) == 0 { 1.0 } else { 0.0 })
+                    .collect(),
+                [size, size].into(),
+            )
+            .convert(),
+            &Default::default(),
+        );
+
+        gram.into_data()
+            .assert_approx_eq(&identity.unsqueeze::<D>().into_data(), 4);
+    }
+
+    #[test]
+    fn test_eigh() {
+        let tensor = TestTensor::from([[4.0, 1.0, -2.0], [1.0, 3.0, 0.5], [-2.0, 0.5, 2.0]]);
+
+        let (vectors, values) = tensor.clone().eigh::<1>();
+
+        assert_eq!((vectors.dims(), values.dims()), ([3, 3], [3]));
+        reconstruct(vectors.clone(), values.clone())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+        assert_orthonormal_columns(vectors);
+        let values = values.into_data().convert::<f32>().value;
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn test_eigh_known_values() {
+        let tensor = TestTensor::from([[2.0, 1.0], [1.0, 2.0]]);
+
+        let (_vectors, values) = tensor.eigh::<1>();
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 3.0]), 5);
+    }
+
+    #[test]
+    fn test_eigh_uses_lower_triangle() {
+        let tensor = TestTensor::from([[2.0, 100.0], [1.0, 2.0]]);
+
+        let (_vectors, values) = tensor.eigh::<1>();
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 3.0]), 5);
+    }
+
+    #[test]
+    fn test_eigh_repeated_values() {
+        let tensor = TestTensor::from([[2.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, -1.0]]);
+
+        let (vectors, values) = tensor.clone().eigh::<1>();
+
+        values
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([-1.0, 2.0, 2.0]), 5);
+        assert_orthonormal_columns(vectors.clone());
+        reconstruct(vectors, values)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+    }
+
+    #[test]
+    fn test_eigh_batched() {
+        let tensor = Tensor::<TestBackend, 3>::from_floats(
+            [[[1.0, 2.0], [2.0, -1.0]], [[3.0, 0.5], [0.5, 1.0]]],
+            &Default::default(),
+        );
+
+        let (vectors, values) = tensor.clone().eigh::<2>();
+
+        assert_eq!((vectors.dims(), values.dims()), ([2, 2, 2], [2, 2]));
+        reconstruct(vectors, values)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_eigh_non_square() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let _ = tensor.eigh::<1>();
+    }
+}
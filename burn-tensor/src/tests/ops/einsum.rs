@@ -0,0 +1,155 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_einsum_matmul_same_as_matmul() {
+        let device = Default::default();
+        let lhs = TestTensor::from_data(Data::from([[1., 2., 3.], [4., 5., 6.]]), &device);
+        let rhs = TestTensor::from_data(Data::from([[1., -1.], [0.5, 2.], [3., 0.]]), &device);
+
+        let output = Tensor::einsum::<2>("ij,jk->ik", vec![lhs.clone(), rhs.clone()]);
+
+        assert_eq!(output.into_data(), lhs.matmul(rhs).into_data());
+    }
+
+    #[test]
+    fn test_einsum_batched_matmul_same_as_matmul() {
+        let device = Default::default();
+        let lhs = TestTensor::from_data(
+            Data::from([[[1., 2.], [3., 4.]], [[-1., 0.], [2., 5.]]]),
+            &device,
+        );
+        let rhs = TestTensor::from_data(
+            Data::from([[[2., 0., 1.], [1., 3., 0.]], [[4., 1., -2.], [0., 1., 1.]]]),
+            &device,
+        );
+
+        let output = Tensor::einsum::<3>("bij,bjk->bik", vec![lhs.clone(), rhs.clone()]);
+
+        assert_eq!(output.into_data(), lhs.matmul(rhs).into_data());
+    }
+
+    #[test]
+    fn test_einsum_implicit_output() {
+        let device = Default::default();
+        let lhs = TestTensor::from_data(Data::from([[1., 2.], [3., 4.]]), &device);
+        let rhs = TestTensor::from_data(Data::from([[5., 6.], [7., 8.]]), &device);
+
+        let output = Tensor::einsum::<2>("ij,jk", vec![lhs, rhs]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[19., 22.], [43., 50.]]), 3);
+    }
+
+    #[test]
+    fn test_einsum_transpose() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 2., 3.], [4., 5., 6.]]),
+            &Default::default(),
+        );
+
+        let output = Tensor::einsum::<2>("ij->ji", vec![tensor]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1., 4.], [2., 5.], [3., 6.]]), 3);
+    }
+
+    #[test]
+    fn test_einsum_sum_dim() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 2., 3.], [4., 5., 6.]]),
+            &Default::default(),
+        );
+
+        let output = Tensor::einsum::<1>("ij->j", vec![tensor.clone()]);
+        let total = Tensor::einsum::<1>("ij->", vec![tensor]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([5., 7., 9.]), 3);
+        total.into_data().assert_approx_eq(&Data::from([21.]), 3);
+    }
+
+    #[test]
+    fn test_einsum_diagonal_and_trace() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]),
+            &Default::default(),
+        );
+
+        let diagonal = Tensor::einsum::<1>("ii->i", vec![tensor.clone()]);
+        let trace = Tensor::einsum::<1>("ii", vec![tensor]);
+
+        diagonal
+            .into_data()
+            .assert_approx_eq(&Data::from([1., 5., 9.]), 3);
+        trace.into_data().assert_approx_eq(&Data::from([15.]), 3);
+    }
+
+    #[test]
+    fn test_einsum_outer_product() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 1>::from_data(Data::from([1., 2.]), &device);
+        let rhs = Tensor::<TestBackend, 1>::from_data(Data::from([3., 4., 5.]), &device);
+
+        let output = Tensor::einsum::<2>("i,j->ij", vec![lhs, rhs]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[3., 4., 5.], [6., 8., 10.]]), 3);
+    }
+
+    #[test]
+    fn test_einsum_three_operands() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::from_data(Data::from([[1., 2.], [3., 4.]]), &device);
+        let tensor_2 = TestTensor::from_data(Data::from([[0., 1., 2.], [1., 0., -1.]]), &device);
+        let tensor_3 = TestTensor::from_data(Data::from([[1.], [2.], [3.]]), &device);
+
+        let output = Tensor::einsum::<2>(
+            "ij,jk,kl->il",
+            vec![tensor_1.clone(), tensor_2.clone(), tensor_3.clone()],
+        );
+        let expected = tensor_1.matmul(tensor_2).matmul(tensor_3);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn test_einsum_bilinear() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::from_data(Data::from([[1., 2.], [0., 1.]]), &device);
+        let weights = TestTensor::from_data(Data::from([[1., 2.], [3., 4.]]), &device);
+        let tensor_2 = TestTensor::from_data(Data::from([[1., 1.], [2., -1.]]), &device);
+
+        let output = Tensor::einsum::<1>("bn,nm,bm->b", vec![tensor_1, weights, tensor_2]);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([17., 2.]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_einsum_invalid_equation() {
+        let tensor = TestTensor::from_data(Data::from([[1., 2.], [3., 4.]]), &Default::default());
+
+        let output = Tensor::einsum::<2>("ij,jk->ik", vec![tensor]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_einsum_mismatched_sizes() {
+        let device = Default::default();
+        let lhs = TestTensor::from_data(Data::from([[1., 2., 3.]]), &device);
+        let rhs = TestTensor::from_data(Data::from([[1., 2.], [3., 4.]]), &device);
+
+        let output = Tensor::einsum::<2>("ij,jk->ik", vec![lhs, rhs]);
+    }
+}
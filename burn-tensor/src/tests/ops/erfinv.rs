@@ -0,0 +1,56 @@
+#[burn_tensor_testgen::testgen(erfinv)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    fn values() -> TestTensor<1> {
+        TestTensor::from_floats([0.0, 0.5, -0.9, 0.999, -0.1], &Default::default())
+    }
+
+    fn expected() -> Data<f32, 1> {
+        Data::from([0.0, 0.476_936_3, -1.163_087_2, 2.326_753_8, -0.088_855_99])
+    }
+
+    #[test]
+    fn should_support_erfinv_ops() {
+        let output = values().erfinv();
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected().convert(), 4);
+    }
+
+    #[test]
+    fn should_support_erfinv_fallback() {
+        let output = TestTensor::from_primitive(burn_tensor::erfinv::<TestBackend, 1>(
+            values().into_primitive(),
+        ));
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected().convert(), 4);
+    }
+
+    #[test]
+    fn should_round_trip_erf_erfinv() {
+        let tensor = TestTensorInt::arange(-19..20, &Default::default())
+            .float()
+            .div_scalar(20.0);
+
+        let output = tensor.clone().erfinv().erf();
+
+        output.into_data().assert_approx_eq(&tensor.into_data(), 4);
+    }
+
+    #[test]
+    fn should_map_bounds_to_infinities() {
+        let tensor = TestTensor::from_floats([1.0, -1.0, 1.5, -2.0], &Default::default());
+
+        let output = tensor.erfinv().into_data().convert::<f32>();
+
+        assert_eq!(output.value[0], f32::INFINITY);
+        assert_eq!(output.value[1], f32::NEG_INFINITY);
+        assert!(output.value[2].is_nan());
+        assert!(output.value[3].is_nan());
+    }
+}
@@ -0,0 +1,61 @@
+#[burn_tensor_testgen::testgen(expand)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Int, Tensor};
+
+    #[test]
+    fn should_expand_float_tensor() {
+        let tensor = TestTensor::from([[1.0], [2.0]]);
+
+        let output = tensor.expand([2, 3]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]])
+        );
+    }
+
+    #[test]
+    fn should_expand_several_dims() {
+        let tensor = TestTensorInt::from([[[1, 2]]]);
+
+        let output = tensor.expand([2, 3, 2]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[1, 2], [1, 2], [1, 2]], [[1, 2], [1, 2], [1, 2]]])
+        );
+    }
+
+    #[test]
+    fn should_expand_bool_tensor() {
+        let tensor = Tensor::<TestBackend, 2, Bool>::from([[true, false]]);
+
+        let output = tensor.expand([2, 2]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[true, false], [true, false]])
+        );
+    }
+
+    #[test]
+    fn should_support_operations_on_expanded_tensor() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0]]);
+
+        let output = tensor.expand([2, 3]).add_scalar(1.0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[2.0, 3.0, 4.0], [2.0, 3.0, 4.0]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_expanding_non_unit_dim() {
+        let tensor = TestTensor::from([[1.0, 2.0]]);
+
+        let _output = tensor.expand([2, 3]);
+    }
+}
@@ -0,0 +1,132 @@
+#[burn_tensor_testgen::testgen(fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_fft_1d() {
+        let tensor = TestTensor::from([1.0, 2.0, 3.0, 4.0]);
+
+        let (real, imag) = tensor.fft(None, 0);
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([10.0, -2.0, -2.0, -2.0]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([0.0, 2.0, 0.0, -2.0]), 3);
+    }
+
+    #[test]
+    fn test_fft_last_dim_not_power_of_two() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let (real, imag) = tensor.fft(None, -1);
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([[6.0, -1.5, -1.5], [15.0, -1.5, -1.5]]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.866, -0.866], [0.0, 0.866, -0.866]]), 3);
+    }
+
+    #[test]
+    fn test_fft_first_dim() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let (real, imag) = tensor.fft(None, 0);
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([[5.0, 7.0, 9.0], [-3.0, -3.0, -3.0]]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn test_fft_should_pad_and_truncate_to_n() {
+        let tensor = TestTensor::from([1.0, 2.0, 3.0, 4.0]);
+
+        let (real, imag) = tensor.clone().fft(Some(6), 0);
+        real.into_data()
+            .assert_approx_eq(&Data::from([10.0, -3.5, 2.5, -2.0, 2.5, -3.5]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([0.0, -4.3301, 0.866, 0.0, -0.866, 4.3301]), 3);
+
+        let (real, imag) = tensor.fft(Some(2), 0);
+        real.into_data()
+            .assert_approx_eq(&Data::from([3.0, -1.0]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0]), 3);
+    }
+
+    #[test]
+    fn test_fft_and_ifft_complex() {
+        let real = TestTensor::from([1.0, -1.0, 3.0]);
+        let imag = TestTensor::from([2.0, 0.5, -1.0]);
+
+        let (output_real, output_imag) = real.clone().fft_complex(imag.clone(), None, 0);
+        output_real
+            .into_data()
+            .assert_approx_eq(&Data::from([3.0, 1.299, -1.299]), 3);
+        output_imag
+            .into_data()
+            .assert_approx_eq(&Data::from([1.5, 5.7141, -1.2141]), 3);
+
+        let (output_real, output_imag) = real.ifft(imag, None, 0);
+        output_real
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, -0.433, 0.433]), 3);
+        output_imag
+            .into_data()
+            .assert_approx_eq(&Data::from([0.5, -0.4047, 1.9047]), 3);
+    }
+
+    #[test]
+    fn test_ifft_should_invert_fft() {
+        let tensor = TestTensor::from([
+            [[0.5, -1.0, 2.0, 3.0, 0.0], [1.5, 2.5, -0.5, 4.0, 1.0]],
+            [[3.0, 1.0, -2.0, 0.5, 2.0], [0.0, 1.0, 2.0, 3.0, 4.0]],
+        ]);
+
+        for dim in 0..3 {
+            let (real, imag) = tensor.clone().fft(None, dim);
+            let (real, imag) = real.ifft(imag, None, dim);
+
+            real.into_data().assert_approx_eq(&tensor.to_data(), 3);
+            imag.into_data()
+                .assert_approx_eq(&tensor.zeros_like().into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn test_fftn_and_ifftn() {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let (real, imag) = tensor.clone().fftn(&[0, 1]);
+        real.clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[10.0, -2.0], [-4.0, 0.0]]), 3);
+        imag.clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [0.0, 0.0]]), 3);
+
+        let (real, imag) = real.ifftn(imag, &[0, 1]);
+        real.into_data().assert_approx_eq(&tensor.to_data(), 3);
+        imag.into_data()
+            .assert_approx_eq(&tensor.zeros_like().into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fft_should_panic_with_invalid_dim() {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _ = tensor.fft(None, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ifft_should_panic_when_shapes_differ() {
+        let real = TestTensor::from([1.0, 2.0, 3.0]);
+        let imag = TestTensor::from([1.0, 2.0]);
+
+        let _ = real.ifft(imag, None, 0);
+    }
+}
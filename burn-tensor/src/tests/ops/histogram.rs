@@ -0,0 +1,70 @@
+#[burn_tensor_testgen::testgen(histogram)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_histogram_with_default_range() {
+        let tensor = TestTensor::from_floats(
+            [[0.0, 1.0, 1.5, 2.0], [2.5, 3.0, 3.5, 4.0]],
+            &Default::default(),
+        );
+
+        let (counts, edges) = tensor.histogram(4, None);
+
+        // The last bin includes the maximum.
+        assert_eq!(counts.into_data(), Data::from([1.0, 2.0, 2.0, 3.0]));
+        edges
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 1.0, 2.0, 3.0, 4.0]), 3);
+    }
+
+    #[test]
+    fn should_support_histogram_with_range() {
+        let tensor = TestTensor::from_floats(
+            [-3.0, -1.0, -0.5, 0.0, 0.2, 0.7, 1.0, 5.0],
+            &Default::default(),
+        );
+
+        let (counts, edges) = tensor.histogram(2, Some((-1.0, 1.0)));
+
+        // The elements outside of the range are ignored.
+        assert_eq!(counts.into_data(), Data::from([2.0, 4.0]));
+        edges
+            .into_data()
+            .assert_approx_eq(&Data::from([-1.0, 0.0, 1.0]), 3);
+    }
+
+    #[test]
+    fn should_support_histogram_of_constant_tensor() {
+        let tensor = TestTensor::<2>::ones([2, 3], &Default::default());
+
+        let (counts, edges) = tensor.histogram(2, None);
+
+        assert_eq!(counts.into_data(), Data::from([0.0, 6.0]));
+        edges
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 1.0, 2.0]), 3);
+    }
+
+    #[test]
+    fn histogram_counts_should_sum_to_num_elements() {
+        let tensor = TestTensor::<3>::random(
+            [4, 5, 6],
+            burn_tensor::Distribution::Normal(0.0, 1.0),
+            &Default::default(),
+        );
+
+        let (counts, _) = tensor.histogram(7, None);
+
+        assert_eq!(counts.sum().into_scalar(), 120.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_histogram_has_no_bins() {
+        let tensor = TestTensor::from_floats([0.0, 1.0], &Default::default());
+
+        let _ = tensor.histogram(0, None);
+    }
+}
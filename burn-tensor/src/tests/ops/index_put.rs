@@ -0,0 +1,78 @@
+#[burn_tensor_testgen::testgen(index_put)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_index_put_last_value_of_duplicates() {
+        let device = Default::default();
+        let tensor = TestTensor::zeros([2, 3], &device);
+        let rows = TestTensorInt::from_ints([0, 1, 0], &device);
+        let cols = TestTensorInt::from_ints([2, 0, 2], &device);
+        let values = TestTensor::from_floats([[1.0], [2.0], [3.0]], &device);
+
+        let output = tensor.index_put(&[Some(rows), Some(cols)], values, false);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0, 3.0], [2.0, 0.0, 0.0]]), 4);
+    }
+
+    #[test]
+    fn should_index_put_accumulate_duplicates() {
+        let device = Default::default();
+        let tensor = TestTensor::ones([2, 3], &device);
+        let rows = TestTensorInt::from_ints([0, 1, 0], &device);
+        let cols = TestTensorInt::from_ints([2, 0, 2], &device);
+        let values = TestTensor::from_floats([[1.0], [2.0], [3.0]], &device);
+
+        let output = tensor.index_put(&[Some(rows), Some(cols)], values, true);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0, 5.0], [3.0, 1.0, 1.0]]), 4);
+    }
+
+    #[test]
+    fn should_index_put_whole_dimensions() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]], &device);
+        let cols = TestTensorInt::from_ints([1], &device);
+        let values = TestTensor::from_floats([[7.0], [8.0], [9.0]], &device);
+
+        let output = tensor.index_put(&[None, Some(cols)], values, false);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 7.0], [3.0, 8.0], [5.0, 9.0]]), 4);
+    }
+
+    #[test]
+    fn should_index_put_non_adjacent_dimensions() {
+        let device = Default::default();
+        let tensor: TestTensor<3> = TestTensorInt::arange(0..8, &device)
+            .reshape([2, 2, 2])
+            .float();
+        let first = TestTensorInt::from_ints([1, 0], &device);
+        let last = TestTensorInt::from_ints([0, 1], &device);
+        let values = TestTensor::from_floats([[[10.0], [11.0]], [[20.0], [21.0]]], &device);
+
+        let output = tensor.index_put(&[Some(first), None, Some(last)], values, false);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[0.0, 20.0], [2.0, 21.0]], [[10.0, 5.0], [11.0, 7.0]]]),
+            4,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_values_have_wrong_shape() {
+        let device = Default::default();
+        let tensor = TestTensor::zeros([2, 3], &device);
+        let rows = TestTensorInt::from_ints([0, 1], &device);
+        let values = TestTensor::zeros([2, 2], &device);
+
+        let _ = tensor.index_put(&[Some(rows)], values, false);
+    }
+}
@@ -0,0 +1,77 @@
+#[burn_tensor_testgen::testgen(kron)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    fn identity(size: usize) -> TestTensor<2> {
+        let values = (0..size * size)
+            .map(|index| if index % (size + 1) == 0 { 1.0 } else { 0.0 })
+            .collect();
+
+        TestTensor::from_data(
+            Data::new(values, [size, size].into()).convert(),
+            &Default::default(),
+        )
+    }
+
+    #[test]
+    fn test_outer() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0, -1.0], &device);
+        let rhs = Tensor::<TestBackend, 1>::from_floats([3.0, 0.5], &device);
+
+        let data_actual = lhs.outer(rhs).into_data();
+
+        let data_expected = Data::from([[3.0, 0.5], [6.0, 1.0], [-3.0, -0.5]]);
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn test_kron_identities() {
+        let data_actual = identity(2).kron(identity(3)).into_data();
+
+        let data_expected = identity(6).into_data();
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn test_kron_d2() {
+        let lhs = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::from([[0.0, 5.0], [6.0, 7.0]]);
+
+        let data_actual = lhs.kron(rhs).into_data();
+
+        let data_expected = Data::from([
+            [0.0, 5.0, 0.0, 10.0],
+            [6.0, 7.0, 12.0, 14.0],
+            [0.0, 15.0, 0.0, 20.0],
+            [18.0, 21.0, 24.0, 28.0],
+        ]);
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn test_kron_d1() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 1>::from_floats([1.0, 10.0], &device);
+        let rhs = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0, 3.0], &device);
+
+        let data_actual = lhs.kron(rhs).into_data();
+
+        let data_expected = Data::from([1.0, 2.0, 3.0, 10.0, 20.0, 30.0]);
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn test_kron_d3() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 3>::from_floats([[[1.0], [2.0]]], &device);
+        let rhs = Tensor::<TestBackend, 3>::from_floats([[[1.0, -1.0]], [[3.0, 0.0]]], &device);
+
+        let tensor = lhs.kron(rhs);
+
+        assert_eq!(tensor.dims(), [2, 2, 2]);
+        let data_expected = Data::from([[[1.0, -1.0], [2.0, -2.0]], [[3.0, 0.0], [6.0, 0.0]]]);
+        tensor.into_data().assert_approx_eq(&data_expected, 5);
+    }
+}
@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(logcumsumexp)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    /// Accumulates `log(sum(exp(x)))` along the last dimension of a matrix, one element at a time.
+    fn logcumsumexp_naive(rows: &[[f32; 4]]) -> Vec<[f32; 4]> {
+        rows.iter()
+            .map(|row| {
+                let mut sum = 0.0f64;
+                row.map(|value| {
+                    sum += (value as f64).exp();
+                    sum.ln() as f32
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_match_naive_logcumsumexp() {
+        let rows = [[0.5, -1.0, 2.0, 0.0], [3.0, 1.5, -2.0, 4.0]];
+        let tensor = TestTensor::from(rows);
+
+        let data_actual = tensor.logcumsumexp(1).into_data();
+
+        let expected = logcumsumexp_naive(&rows);
+        let data_expected = Data::from([expected[0], expected[1]]);
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+
+    #[test]
+    fn should_support_logcumsumexp_along_first_dim() {
+        let rows = [[0.5, -1.0, 2.0, 0.0], [3.0, 1.5, -2.0, 4.0]];
+        let tensor = TestTensor::from(rows);
+
+        let data_actual = tensor.transpose().logcumsumexp(0).transpose().into_data();
+
+        let expected = logcumsumexp_naive(&rows);
+        let data_expected = Data::from([expected[0], expected[1]]);
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+
+    #[test]
+    fn should_not_overflow_with_large_values() {
+        let tensor =
+            Tensor::<TestBackend, 1>::from_floats([1000.0, 1000.0, -1000.0], &Default::default());
+
+        let data_actual = tensor.logcumsumexp(0).into_data();
+
+        let data_expected = Data::from([1000.0, 1000.0 + 2.0f32.ln(), 1000.0 + 2.0f32.ln()]);
+        data_actual.assert_approx_eq(&data_expected, 3);
+    }
+
+    #[test]
+    fn should_support_negative_infinity() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats(
+            [f32::NEG_INFINITY, f32::NEG_INFINITY, 0.0],
+            &Default::default(),
+        );
+
+        let values = tensor.logcumsumexp(0).into_data().convert::<f32>().value;
+
+        assert_eq!(values[..2], [f32::NEG_INFINITY, f32::NEG_INFINITY]);
+        assert!(values[2].abs() < 1e-6);
+    }
+}
@@ -0,0 +1,109 @@
+#[burn_tensor_testgen::testgen(logical)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Distribution};
+
+    fn operands() -> (TestTensorBool<2>, TestTensorBool<2>) {
+        let device = Default::default();
+        let lhs = TestTensorBool::from_bool(Data::from([[true, true], [false, false]]), &device);
+        let rhs = TestTensorBool::from_bool(Data::from([[true, false], [true, false]]), &device);
+
+        (lhs, rhs)
+    }
+
+    fn random_bool(shape: [usize; 3]) -> TestTensorBool<3> {
+        TestTensor::random(shape, Distribution::Default, &Default::default()).greater_elem(0.5)
+    }
+
+    #[test]
+    fn should_support_logical_and() {
+        let (lhs, rhs) = operands();
+
+        let output = lhs.logical_and(rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[true, false], [false, false]])
+        );
+    }
+
+    #[test]
+    fn should_support_logical_or() {
+        let (lhs, rhs) = operands();
+
+        let output = lhs.logical_or(rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[true, true], [true, false]])
+        );
+    }
+
+    #[test]
+    fn should_support_logical_xor() {
+        let (lhs, rhs) = operands();
+
+        let output = lhs.logical_xor(rhs);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[false, true], [true, false]])
+        );
+    }
+
+    #[test]
+    fn should_support_logical_not() {
+        let (lhs, _) = operands();
+
+        let output = lhs.logical_not();
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[false, false], [true, true]])
+        );
+    }
+
+    #[test]
+    fn should_support_operators() {
+        let (lhs, rhs) = operands();
+
+        assert_eq!(
+            (lhs.clone() & rhs.clone()).into_data(),
+            lhs.clone().logical_and(rhs.clone()).into_data()
+        );
+        assert_eq!(
+            (lhs.clone() | rhs.clone()).into_data(),
+            lhs.clone().logical_or(rhs.clone()).into_data()
+        );
+        assert_eq!(
+            (lhs.clone() ^ rhs.clone()).into_data(),
+            lhs.clone().logical_xor(rhs).into_data()
+        );
+        assert_eq!((!lhs.clone()).into_data(), lhs.logical_not().into_data());
+    }
+
+    #[test]
+    fn should_satisfy_de_morgan_laws() {
+        let a = random_bool([2, 3, 16]);
+        let b = random_bool([2, 3, 16]);
+
+        assert_eq!(
+            (!(a.clone() & b.clone())).into_data(),
+            (!a.clone() | !b.clone()).into_data()
+        );
+        assert_eq!(
+            (!(a.clone() | b.clone())).into_data(),
+            (!a & !b).into_data()
+        );
+    }
+
+    #[test]
+    fn xor_should_match_and_or_composition() {
+        let a = random_bool([2, 3, 16]);
+        let b = random_bool([2, 3, 16]);
+
+        let expected = (a.clone() | b.clone()) & !(a.clone() & b.clone());
+
+        assert_eq!((a ^ b).into_data(), expected.into_data());
+    }
+}
@@ -0,0 +1,47 @@
+#[burn_tensor_testgen::testgen(logit)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn should_support_logit_ops() {
+        let tensor = TestTensor::from([[0.5, 0.25], [0.9, 1e-6]]);
+
+        let output = tensor.logit(None);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, -1.0986], [2.1972, -13.8155]]), 3);
+    }
+
+    #[test]
+    fn should_clamp_logit_with_eps() {
+        let tensor = TestTensor::from([0.0, 1.0, 0.5]);
+
+        let output = tensor.logit(Some(1e-3));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([-6.9068, 6.9068, 0.0]), 3);
+    }
+
+    #[test]
+    fn should_map_bounds_to_infinities_without_eps() {
+        let tensor = TestTensor::from([0.0, 1.0]);
+
+        let output = tensor.logit(None).into_data().convert::<f32>();
+
+        assert_eq!(output.value, vec![f32::NEG_INFINITY, f32::INFINITY]);
+    }
+
+    #[test]
+    fn should_invert_sigmoid() {
+        let tensor = TestTensor::from([-100.0, -3.0, 0.0, 2.5, 10.0]);
+
+        let output = activation::sigmoid(tensor.clone()).logit(None);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([f32::NEG_INFINITY, -3.0, 0.0, 2.5, 10.0]), 2);
+    }
+}
@@ -35,6 +35,82 @@ mod tests {
         assert_eq!(data_expected, data_actual);
     }
 
+    #[test]
+    fn should_support_masked_fill_ops() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            Data::from([[false, true], [true, false]]),
+            &device,
+        );
+
+        let data_actual = tensor.masked_fill(mask, -1.0).into_data();
+
+        let data_expected = Data::from([[1.0, -1.0], [-1.0, 3.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_masked_fill_broadcast_attention_mask() {
+        let device = Default::default();
+        let scores = TestTensor::<3>::from_data(
+            [
+                [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+                [[9.0, 8.0, 7.0], [6.0, 5.0, 4.0], [3.0, 2.0, 1.0]],
+            ],
+            &device,
+        );
+        // Causal mask, each position can't attend to the following ones.
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            Data::from([
+                [false, true, true],
+                [false, false, true],
+                [false, false, false],
+            ]),
+            &device,
+        );
+
+        let data_actual = scores.masked_fill_broadcast(mask, 0.0).into_data();
+
+        let data_expected = Data::from([
+            [[1.0, 0.0, 0.0], [4.0, 5.0, 0.0], [7.0, 8.0, 9.0]],
+            [[9.0, 0.0, 0.0], [6.0, 5.0, 0.0], [3.0, 2.0, 1.0]],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_masked_fill_broadcast_unit_dims() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::from_data(
+            [[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]],
+            &device,
+        );
+        // Padding mask of shape [batch_size, 1, seq_length].
+        let mask = Tensor::<TestBackend, 3, Bool>::from_bool(
+            Data::from([[[false, true]], [[true, false]]]),
+            &device,
+        );
+
+        let data_actual = tensor.masked_fill_broadcast(mask, 0.0).into_data();
+
+        let data_expected = Data::from([[[1.0, 0.0], [3.0, 0.0]], [[0.0, 6.0], [0.0, 8.0]]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_mask_cant_be_broadcasted() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::zeros([2, 3, 3], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            Data::from([[false, true], [true, false]]),
+            &device,
+        );
+
+        let _ = tensor.masked_fill_broadcast(mask, 0.0);
+    }
+
     #[test]
     fn should_support_int_mask_where_ops() {
         let device = Default::default();
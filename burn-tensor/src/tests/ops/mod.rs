@@ -4,29 +4,52 @@ mod aggregation;
 mod arange;
 mod arange_step;
 mod arg;
+mod as_strided;
+mod bucketize;
 mod cast;
 mod cat;
+mod cdist;
+mod cholesky;
 mod chunk;
 mod clamp;
 mod cos;
+mod cosine_similarity;
 mod create_like;
+mod cumulative;
 mod div;
+mod eigh;
+mod einsum;
 mod erf;
+mod erfinv;
 mod exp;
+mod expand;
+mod fft;
 mod flatten;
 mod full;
 mod gather_scatter;
+mod histogram;
+mod index_put;
 mod init;
 mod iter_dim;
+mod kron;
 mod log;
 mod log1p;
+mod logcumsumexp;
+mod logical;
+mod logit;
 mod map_comparison;
 mod mask;
 mod matmul;
 mod maxmin;
 mod mul;
+mod multinomial;
+mod nan_aggregation;
+mod nan_to_num;
 mod narrow;
 mod neg;
+mod nextafter;
+mod nonzero;
+mod normalize;
 mod one_hot;
 mod powf;
 mod powf_scalar;
@@ -34,13 +57,26 @@ mod random;
 mod recip;
 mod repeat;
 mod reshape;
+mod roll;
+mod segment_reduce;
 mod select;
 mod sin;
 mod slice;
+mod solve;
+mod sort;
 mod sqrt;
 mod squeeze;
 mod stack;
 mod sub;
+mod svd;
 mod tanh;
+mod tensordot;
+mod to_device_async;
+mod topk;
 mod transpose;
 mod tri;
+mod unbind;
+mod unfold;
+mod unique;
+mod vmap;
+mod where_cond;
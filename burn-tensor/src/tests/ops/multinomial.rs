@@ -0,0 +1,86 @@
+#[burn_tensor_testgen::testgen(multinomial)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_match_weights_frequencies_with_replacement() {
+        let num_samples = 10000;
+        let weights = [1.0f32, 2.0, 3.0, 4.0];
+        let tensor = Tensor::<TestBackend, 1>::from_floats(weights, &Default::default());
+
+        let samples = tensor.multinomial(num_samples, true, 42).into_data();
+
+        let mut counts = [0usize; 4];
+        for sample in samples.convert::<i64>().value {
+            counts[sample as usize] += 1;
+        }
+        let total: f32 = weights.iter().sum();
+        let chi_squared: f64 = weights
+            .iter()
+            .zip(counts)
+            .map(|(weight, count)| {
+                let expected = (weight / total) as f64 * num_samples as f64;
+                (count as f64 - expected).powi(2) / expected
+            })
+            .sum();
+
+        // Critical value of the chi-squared distribution with 3 degrees of freedom at p = 0.001.
+        assert!(chi_squared < 16.27, "chi-squared statistic {chi_squared}");
+    }
+
+    #[test]
+    fn should_never_sample_zero_weights() {
+        let tensor =
+            Tensor::<TestBackend, 1>::from_floats([0.0, 1.0, 0.0, 1.0], &Default::default());
+
+        let samples = tensor.multinomial(100, true, 0).into_data();
+
+        for sample in samples.convert::<i64>().value {
+            assert!(sample == 1 || sample == 3);
+        }
+    }
+
+    #[test]
+    fn should_sample_distinct_categories_without_replacement() {
+        let tensor =
+            Tensor::<TestBackend, 1>::from_floats([0.1, 0.5, 0.2, 0.3, 0.9], &Default::default());
+
+        let mut samples = tensor
+            .multinomial(5, false, 7)
+            .into_data()
+            .convert::<i64>()
+            .value;
+        samples.sort();
+
+        assert_eq!(samples, Data::from([0, 1, 2, 3, 4]).value);
+    }
+
+    #[test]
+    fn should_prefer_large_weights_without_replacement() {
+        let num_draws = 2000;
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 3.0], &Default::default());
+
+        let mut first = 0;
+        for seed in 0..num_draws {
+            let sample = tensor.clone().multinomial(1, false, seed).into_data();
+            if sample.convert::<i64>().value[0] == 1 {
+                first += 1;
+            }
+        }
+
+        // The expected frequency is 0.75, chi-squared with 1 degree of freedom at p = 0.001.
+        let expected = 0.75 * num_draws as f64;
+        let chi_squared = (first as f64 - expected).powi(2) / expected
+            + (first as f64 - expected).powi(2) / (num_draws as f64 - expected);
+        assert!(chi_squared < 10.83, "chi-squared statistic {chi_squared}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_too_many_samples_without_replacement() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0], &Default::default());
+
+        let _samples = tensor.multinomial(3, false, 0);
+    }
+}
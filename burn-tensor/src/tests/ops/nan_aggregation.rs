@@ -0,0 +1,39 @@
+#[burn_tensor_testgen::testgen(nan_aggregation)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_ignore_nan_values() {
+        let tensor = TestTensor::from([[1.0, f32::NAN, 3.0], [f32::NAN, 4.0, f32::NAN]]);
+
+        let sum = tensor.clone().nan_sum(1);
+        let mean = tensor.nan_mean(1);
+
+        assert_eq!(sum.into_data(), Data::from([[4.0], [4.0]]));
+        assert_eq!(mean.into_data(), Data::from([[2.0], [4.0]]));
+    }
+
+    #[test]
+    fn should_match_sum_and_mean_without_nan() {
+        let tensor = TestTensor::from([[1.0, 2.0, 6.0], [-4.0, 0.5, 2.0]]);
+
+        let nan_sum = tensor.clone().nan_sum(0);
+        let nan_mean = tensor.clone().nan_mean(0);
+
+        assert_eq!(nan_sum.into_data(), tensor.clone().sum_dim(0).into_data());
+        assert_eq!(nan_mean.into_data(), tensor.mean_dim(0).into_data());
+    }
+
+    #[test]
+    fn should_handle_all_nan_values() {
+        let tensor = TestTensor::from([[f32::NAN, f32::NAN], [1.0, f32::NAN]]);
+
+        let sum = tensor.clone().nan_sum(1).into_data().convert::<f32>();
+        let mean = tensor.nan_mean(1).into_data().convert::<f32>();
+
+        assert_eq!(sum.value, vec![0.0, 1.0]);
+        assert!(mean.value[0].is_nan());
+        assert_eq!(mean.value[1], 1.0);
+    }
+}
@@ -0,0 +1,54 @@
+#[burn_tensor_testgen::testgen(nan_to_num)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    fn tensor() -> TestTensor<2> {
+        TestTensor::from_floats(
+            [
+                [f32::NAN, 1.0, f32::INFINITY],
+                [-2.5, f32::NEG_INFINITY, 0.0],
+            ],
+            &Default::default(),
+        )
+    }
+
+    #[test]
+    fn should_replace_nan() {
+        let output = tensor().nan_to_num(-1.0, Some(10.0), Some(-10.0));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[-1.0, 1.0, 10.0], [-2.5, -10.0, 0.0]]), 4);
+    }
+
+    #[test]
+    fn should_replace_posinf_with_largest_finite_value_by_default() {
+        let output = tensor().nan_to_num(0.0, None, Some(-10.0));
+
+        let data = output.into_data().convert::<f32>();
+        assert_eq!(data.value[2], f32::MAX);
+        assert_eq!(data.value[4], -10.0);
+    }
+
+    #[test]
+    fn should_replace_neginf_with_smallest_finite_value_by_default() {
+        let output = tensor().nan_to_num(0.0, Some(10.0), None);
+
+        let data = output.into_data().convert::<f32>();
+        assert_eq!(data.value[2], 10.0);
+        assert_eq!(data.value[4], f32::MIN);
+    }
+
+    #[test]
+    fn should_keep_finite_values() {
+        let tensor = TestTensor::from_floats(
+            [[-1e30, -2.5, 0.0], [1e-30, 3.0, 1e30]],
+            &Default::default(),
+        );
+
+        let output = tensor.clone().nan_to_num(0.0, None, None);
+
+        output.into_data().assert_approx_eq(&tensor.into_data(), 4);
+    }
+}
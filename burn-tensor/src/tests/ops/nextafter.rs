@@ -0,0 +1,85 @@
+#[burn_tensor_testgen::testgen(nextafter)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, ElementNextAfter, Tensor};
+
+    #[test]
+    fn should_return_next_value_up_and_down() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([1.0, 1.0, -1.0, -1.0], &device);
+        let other = TestTensor::from_floats([2.0, 0.0, 0.0, -2.0], &device);
+
+        let output = tensor.nextafter(other).into_data().convert::<f32>();
+
+        assert_eq!(
+            output,
+            Data::from([
+                1.0 + f32::EPSILON,
+                1.0 - f32::EPSILON / 2.0,
+                -1.0 + f32::EPSILON / 2.0,
+                -1.0 - f32::EPSILON,
+            ])
+        );
+    }
+
+    #[test]
+    fn should_return_smallest_subnormal_after_zero() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([0.0, 0.0], &device);
+        let other = TestTensor::from_floats([1.0, -1.0], &device);
+
+        let output = tensor.nextafter(other).into_data().convert::<f32>();
+
+        assert_eq!(output, Data::from([f32::from_bits(1), -f32::from_bits(1)]));
+    }
+
+    #[test]
+    fn should_return_other_when_equal() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([3.0, 0.0], &device);
+        let other = TestTensor::from_floats([3.0, -0.0], &device);
+
+        let output = tensor.nextafter(other).into_data().convert::<f32>();
+
+        assert_eq!(output.value[0], 3.0);
+        assert!(output.value[1] == 0.0 && output.value[1].is_sign_negative());
+    }
+
+    #[test]
+    fn should_handle_extremes() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([f32::MAX, f32::INFINITY, f32::NAN, 1.0], &device);
+        let other = TestTensor::from_floats([f32::INFINITY, 0.0, 1.0, f32::NAN], &device);
+
+        let output = tensor.nextafter(other).into_data().convert::<f32>();
+
+        assert_eq!(output.value[0], f32::INFINITY);
+        assert_eq!(output.value[1], f32::MAX);
+        assert!(output.value[2].is_nan());
+        assert!(output.value[3].is_nan());
+    }
+
+    #[test]
+    fn should_broadcast_nextafter() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0], [2.0]], &device);
+        let other = TestTensor::from_floats([[0.0, 3.0]], &device);
+
+        let output = tensor.nextafter(other).into_data().convert::<f32>();
+
+        assert_eq!(
+            output,
+            Data::from([
+                [1.0 - f32::EPSILON / 2.0, 1.0 + f32::EPSILON],
+                [2.0 - f32::EPSILON, 2.0 + 2.0 * f32::EPSILON],
+            ])
+        );
+    }
+
+    #[test]
+    fn should_move_integer_elements_by_one() {
+        assert_eq!(3i64.next_after(10), 4);
+        assert_eq!(3i32.next_after(-10), 2);
+        assert_eq!(0u8.next_after(0), 0);
+    }
+}
@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(nonzero)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_return_the_diagonal_indices_of_eye() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::diagonal(3, &device);
+
+        let indices = tensor.nonzero();
+
+        assert_eq!(indices.into_data(), Data::from([[0, 0], [1, 1], [2, 2]]));
+    }
+
+    #[test]
+    fn should_return_indices_in_row_major_order_for_int_tensor() {
+        let device = Default::default();
+        let tensor = TestTensorInt::from_ints([[[0, 3], [0, 0]], [[-1, 0], [0, 7]]], &device);
+
+        let indices = tensor.nonzero();
+
+        assert_eq!(
+            indices.into_data(),
+            Data::from([[0, 0, 1], [1, 0, 0], [1, 1, 1]])
+        );
+    }
+
+    #[test]
+    fn should_return_empty_indices_when_all_zeros() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::zeros([2, 3, 4], &device);
+
+        let indices = tensor.nonzero();
+
+        assert_eq!(indices.dims(), [0, 3]);
+    }
+
+    #[test]
+    fn should_return_indices_of_true_values_for_bool_tensor() {
+        let device = Default::default();
+        let tensor = TestTensorBool::from_bool(Data::from([false, true, true, false]), &device);
+
+        let indices = tensor.int().nonzero();
+
+        assert_eq!(indices.into_data(), Data::from([[1], [2]]));
+    }
+
+    #[test]
+    fn should_count_nonzero_over_the_whole_tensor() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 0.0, 2.0], [0.0, 0.0, -3.0]], &device);
+
+        let count = tensor.count_nonzero(None);
+
+        assert_eq!(count.into_data(), Data::from([[3]]));
+    }
+
+    #[test]
+    fn should_count_nonzero_along_dim() {
+        let device = Default::default();
+        let tensor = TestTensorInt::from_ints([[1, 0, 2], [0, 0, -3]], &device);
+
+        let count_rows = tensor.clone().count_nonzero(Some(1));
+        let count_cols = tensor.count_nonzero(Some(0));
+
+        assert_eq!(count_rows.into_data(), Data::from([[2], [1]]));
+        assert_eq!(count_cols.into_data(), Data::from([[1, 0, 2]]));
+    }
+
+    #[test]
+    fn should_match_count_nonzero_with_nonzero() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::diagonal(4, &device) * 2.0
+            + TestTensor::from_floats([[0.0, 0.0, 0.0, 1.0]; 4], &device);
+
+        let count = tensor.clone().count_nonzero(None).into_scalar();
+        let indices: Tensor<TestBackend, 2, Int> = tensor.nonzero();
+
+        assert_eq!(indices.dims()[0] as i64, count as i64);
+        assert_eq!(indices.dims()[1], 2);
+    }
+}
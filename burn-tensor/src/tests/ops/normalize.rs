@@ -0,0 +1,60 @@
+#[burn_tensor_testgen::testgen(normalize)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_normalize_l2() {
+        let tensor = TestTensor::from_floats([[3.0, 4.0], [-1.0, 0.0]], &Default::default());
+
+        let output = tensor.normalize(2.0, 1, 1e-12);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.6, 0.8], [-1.0, 0.0]]), 4);
+    }
+
+    #[test]
+    fn should_normalize_l1() {
+        let tensor = TestTensor::from_floats([[3.0, -1.0], [1.0, 3.0]], &Default::default());
+
+        let output = tensor.normalize(1.0, 0, 1e-12);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.75, -0.25], [0.25, 0.75]]), 4);
+    }
+
+    #[test]
+    fn should_normalize_lp() {
+        let tensor = TestTensor::from_floats([[3.0, 4.0]], &Default::default());
+
+        let output = tensor.normalize(3.0, 1, 1e-12);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.66698, 0.88931]]), 4);
+    }
+
+    #[test]
+    fn should_normalize_infinity() {
+        let tensor = TestTensor::from_floats([[3.0, -4.0], [2.0, 1.0]], &Default::default());
+
+        let output = tensor.normalize(f64::INFINITY, 1, 1e-12);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.75, -1.0], [1.0, 0.5]]), 4);
+    }
+
+    #[test]
+    fn should_clamp_small_norms_to_eps() {
+        let tensor = TestTensor::from_floats([[0.0, 0.0], [0.1, 0.2]], &Default::default());
+
+        let output = tensor.normalize(2.0, 1, 0.5);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [0.2, 0.4]]), 4);
+    }
+}
@@ -70,6 +70,18 @@ mod tests {
         assert_eq!(reshaped.shape(), [4, 3].into());
     }
 
+    #[test]
+    fn should_support_view() {
+        let tensor = TestTensorInt::arange(0..12, &Default::default());
+
+        let viewed = tensor.view([3, -1]);
+
+        assert_eq!(
+            viewed.into_data(),
+            Data::from([[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]])
+        );
+    }
+
     #[test]
     #[should_panic]
     fn multiple_neg_ones() {
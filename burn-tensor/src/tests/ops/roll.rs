@@ -0,0 +1,94 @@
+#[burn_tensor_testgen::testgen(roll)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Int, Tensor};
+
+    #[test]
+    fn should_roll_positive_shift() {
+        let tensor = TestTensor::from([0.0, 1.0, 2.0, 3.0, 4.0]);
+
+        let output = tensor.roll(&[2], &[0]);
+
+        assert_eq!(output.into_data(), Data::from([3.0, 4.0, 0.0, 1.0, 2.0]));
+    }
+
+    #[test]
+    fn should_roll_negative_shift() {
+        let tensor = TestTensor::from([0.0, 1.0, 2.0, 3.0, 4.0]);
+
+        let output = tensor.roll(&[-1], &[0]);
+
+        assert_eq!(output.into_data(), Data::from([1.0, 2.0, 3.0, 4.0, 0.0]));
+    }
+
+    #[test]
+    fn should_roll_shift_larger_than_size() {
+        let tensor = TestTensor::from([0.0, 1.0, 2.0, 3.0, 4.0]);
+
+        let output = tensor.roll(&[7], &[0]);
+
+        assert_eq!(output.into_data(), Data::from([3.0, 4.0, 0.0, 1.0, 2.0]));
+    }
+
+    #[test]
+    fn should_roll_multiple_dims() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.roll(&[1, -1], &[0, 1]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[5.0, 6.0, 4.0], [2.0, 3.0, 1.0]])
+        );
+    }
+
+    #[test]
+    fn should_roll_same_dim_twice() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.roll(&[1, 1], &[1, 1]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[2.0, 3.0, 1.0], [5.0, 6.0, 4.0]])
+        );
+    }
+
+    #[test]
+    fn should_roll_int_tensor() {
+        let tensor =
+            Tensor::<TestBackend, 2, Int>::from_ints([[1, 2], [3, 4], [5, 6]], &Default::default());
+
+        let output = tensor.roll(&[-1], &[0]);
+
+        assert_eq!(output.into_data(), Data::from([[3, 4], [5, 6], [1, 2]]));
+    }
+
+    #[test]
+    fn should_roll_bool_tensor() {
+        let tensor = Tensor::<TestBackend, 1, Bool>::from_bool(
+            Data::from([true, false, false]),
+            &Default::default(),
+        );
+
+        let output = tensor.roll(&[1], &[0]);
+
+        assert_eq!(output.into_data(), Data::from([false, true, false]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_shifts_and_dims_have_different_lengths() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.roll(&[1, 1], &[0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_dim_is_out_of_bounds() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.roll(&[1], &[2]);
+    }
+}
@@ -0,0 +1,67 @@
+#[burn_tensor_testgen::testgen(segment_reduce)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, SegmentReduce, Tensor};
+
+    fn data() -> (TestTensor<2>, TestTensorInt<1>) {
+        let device = Default::default();
+        let data =
+            TestTensor::from_floats([[1.0, 2.0], [3.0, -4.0], [5.0, 6.0], [-7.0, 8.0]], &device);
+        let segment_ids = TestTensorInt::from_ints([0, 2, 0, 2], &device);
+
+        (data, segment_ids)
+    }
+
+    #[test]
+    fn should_segment_reduce_sum() {
+        let (data, segment_ids) = data();
+
+        let output = data.segment_reduce(segment_ids, 3, SegmentReduce::Sum);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[6.0, 8.0], [0.0, 0.0], [-4.0, 4.0]]), 4);
+    }
+
+    #[test]
+    fn should_segment_reduce_mean() {
+        let (data, segment_ids) = data();
+
+        let output = data.segment_reduce(segment_ids, 3, SegmentReduce::Mean);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0, 4.0], [0.0, 0.0], [-2.0, 2.0]]), 4);
+    }
+
+    #[test]
+    fn should_segment_reduce_max() {
+        let (data, segment_ids) = data();
+
+        let output = data.segment_reduce(segment_ids, 3, SegmentReduce::Max);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[5.0, 6.0], [0.0, 0.0], [3.0, 8.0]]), 4);
+    }
+
+    #[test]
+    fn should_segment_reduce_min() {
+        let (data, segment_ids) = data();
+
+        let output = data.segment_reduce(segment_ids, 3, SegmentReduce::Min);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 2.0], [0.0, 0.0], [-7.0, -4.0]]), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_segment_ids_and_rows_differ() {
+        let (data, _) = data();
+        let segment_ids = TestTensorInt::from_ints([0, 1], &Default::default());
+
+        data.segment_reduce(segment_ids, 2, SegmentReduce::Sum);
+    }
+}
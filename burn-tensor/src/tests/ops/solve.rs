@@ -0,0 +1,96 @@
+#[burn_tensor_testgen::testgen(solve)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_solve() {
+        let a = TestTensor::from([[3.0, 1.0], [1.0, 2.0]]);
+        let b = TestTensor::from([[9.0], [8.0]]);
+
+        let x = a.solve(b);
+
+        x.into_data()
+            .assert_approx_eq(&Data::from([[2.0], [3.0]]), 4);
+    }
+
+    #[test]
+    fn test_solve_needs_pivoting() {
+        let a = TestTensor::from([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [2.0, 0.0, 3.0]]);
+        let b = TestTensor::from([[1.0, 0.0], [2.0, 1.0], [3.0, -1.0]]);
+
+        let x = a.clone().solve(b.clone());
+
+        a.matmul(x).into_data().assert_approx_eq(&b.into_data(), 4);
+    }
+
+    #[test]
+    fn test_solve_batched() {
+        let a = Tensor::<TestBackend, 3>::from_floats(
+            [[[2.0, 0.0], [0.0, 4.0]], [[1.0, 2.0], [3.0, 4.0]]],
+            &Default::default(),
+        );
+        let b = Tensor::<TestBackend, 3>::from_floats(
+            [[[2.0], [2.0]], [[5.0], [11.0]]],
+            &Default::default(),
+        );
+
+        let x = a.solve(b);
+
+        x.into_data()
+            .assert_approx_eq(&Data::from([[[1.0], [0.5]], [[1.0], [2.0]]]), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_solve_non_square() {
+        let a = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = TestTensor::from([[1.0], [2.0]]);
+
+        let _ = a.solve(b);
+    }
+
+    #[test]
+    fn test_lstsq_overdetermined() {
+        // Fits a line through points that aren't aligned.
+        let a = TestTensor::from([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+        let b = TestTensor::from([[1.0], [2.0], [2.0], [4.0]]);
+
+        let x = a.lstsq(b);
+
+        x.into_data()
+            .assert_approx_eq(&Data::from([[0.9], [0.9]]), 4);
+    }
+
+    #[test]
+    fn test_lstsq_square_matches_solve() {
+        let a = TestTensor::from([[3.0, 1.0], [1.0, 2.0]]);
+        let b = TestTensor::from([[9.0, 1.0], [8.0, 0.0]]);
+
+        let x = a.clone().lstsq(b.clone());
+
+        x.into_data().assert_approx_eq(&a.solve(b).into_data(), 4);
+    }
+
+    #[test]
+    fn test_lstsq_underdetermined_minimal_norm() {
+        let a = TestTensor::from([[1.0, 1.0]]);
+        let b = TestTensor::from([[2.0]]);
+
+        let x = a.lstsq(b);
+
+        x.into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0]]), 4);
+    }
+
+    #[test]
+    fn test_lstsq_rank_deficient() {
+        let a = TestTensor::from([[1.0, 2.0], [2.0, 4.0], [0.0, 0.0]]);
+        let b = TestTensor::from([[5.0], [10.0], [1.0]]);
+
+        let x = a.lstsq(b);
+
+        x.into_data()
+            .assert_approx_eq(&Data::from([[1.0], [2.0]]), 4);
+    }
+}
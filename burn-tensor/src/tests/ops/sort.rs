@@ -0,0 +1,102 @@
+#[burn_tensor_testgen::testgen(sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_sort_ascending_last_dim() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 5., 3., 2.], [4., 2., 6., 0.]]),
+            &Default::default(),
+        );
+
+        let values = tensor.sort(1, false);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[1., 2., 3., 5.], [0., 2., 4., 6.]]), 3);
+    }
+
+    #[test]
+    fn test_sort_descending_first_dim() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 5., 3.], [4., 2., 6.], [0., 7., -1.]]),
+            &Default::default(),
+        );
+
+        let values = tensor.sort(0, true);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[4., 7., 6.], [1., 5., 3.], [0., 2., -1.]]), 3);
+    }
+
+    #[test]
+    fn test_sort_with_indices_3d() {
+        let tensor = TestTensor::from_data(
+            Data::from([
+                [[1., 9.], [4., 2.], [3., 5.]],
+                [[8., 0.], [6., 7.], [2., 1.]],
+            ]),
+            &Default::default(),
+        );
+
+        let (values, indices) = tensor.sort_with_indices(1, false);
+
+        values.into_data().assert_approx_eq(
+            &Data::from([
+                [[1., 2.], [3., 5.], [4., 9.]],
+                [[2., 0.], [6., 1.], [8., 7.]],
+            ]),
+            3,
+        );
+        assert_eq!(
+            indices.into_data(),
+            Data::from([[[0, 1], [2, 2], [1, 0]], [[2, 0], [1, 2], [0, 1]]])
+        );
+    }
+
+    #[test]
+    fn test_sort_is_stable() {
+        let tensor = TestTensor::from_data(Data::from([[2., 1., 2., 1.]]), &Default::default());
+
+        let (_values, indices_asc) = tensor.clone().sort_with_indices(1, false);
+        let (_values, indices_desc) = tensor.sort_with_indices(1, true);
+
+        assert_eq!(indices_asc.into_data(), Data::from([[1, 3, 0, 2]]));
+        assert_eq!(indices_desc.into_data(), Data::from([[0, 2, 1, 3]]));
+    }
+
+    #[test]
+    fn test_argsort() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 5., 3.], [4., 2., 6.]]),
+            &Default::default(),
+        );
+
+        let indices_asc = tensor.clone().argsort(1, false);
+        let indices_desc = tensor.argsort(1, true);
+
+        assert_eq!(indices_asc.into_data(), Data::from([[0, 2, 1], [1, 0, 2]]));
+        assert_eq!(indices_desc.into_data(), Data::from([[1, 2, 0], [2, 0, 1]]));
+    }
+
+    #[test]
+    fn test_sort_nan_last() {
+        let tensor = TestTensor::from_data(Data::from([[3., f32::NAN, 1.]]), &Default::default());
+
+        let indices_asc = tensor.clone().argsort(1, false);
+        let indices_desc = tensor.argsort(1, true);
+
+        assert_eq!(indices_asc.into_data(), Data::from([[2, 0, 1]]));
+        assert_eq!(indices_desc.into_data(), Data::from([[0, 2, 1]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sort_invalid_dim() {
+        let tensor = TestTensor::from_data(Data::from([[3., 1., 2.]]), &Default::default());
+
+        let values = tensor.sort(2, false);
+    }
+}
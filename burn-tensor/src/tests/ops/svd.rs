@@ -0,0 +1,126 @@
+#[burn_tensor_testgen::testgen(svd)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    fn reconstruct<const D: usize, const D2: usize>(
+        u: Tensor<TestBackend, D>,
+        s: Tensor<TestBackend, D2>,
+        vh: Tensor<TestBackend, D>,
+    ) -> Tensor<TestBackend, D> {
+        let k = s.dims()[D2 - 1];
+        let u = u.narrow(D - 1, 0, k);
+        let vh = vh.narrow(D - 2, 0, k);
+
+        u.mul(s.unsqueeze_dim(D2 - 1)).matmul(vh)
+    }
+
+    fn assert_orthonormal_columns<const D: usize>(tensor: Tensor<TestBackend, D>) {
+        let size = tensor.dims()[D - 1];
+        let gram = tensor.clone().transpose().matmul(tensor);
+        let identity = TestTensor::from_data(
+            Data::new(
+                (0..size * size)
+                    .map(|index| if index % (size + 1) == 0 { 1.0 } else { 0.0 })
+                    .collect(),
+                [size, size].into(),
+            )
+            .convert(),
+            &Default::default(),
+        );
+
+        gram.into_data()
+            .assert_approx_eq(&identity.unsqueeze::<D>().into_data(), 4);
+    }
+
+    #[test]
+    fn test_svd_square() {
+        let tensor = TestTensor::from([[4.0, 1.0, -2.0], [1.0, 3.0, 0.5], [0.0, -1.0, 2.0]]);
+
+        let (u, s, vh) = tensor.clone().svd::<1>(false);
+
+        reconstruct(u.clone(), s.clone(), vh.clone())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+        assert_orthonormal_columns(u);
+        assert_orthonormal_columns(vh.transpose());
+        let values = s.into_data().convert::<f32>().value;
+        assert!(values
+            .windows(2)
+            .all(|pair| pair[0] >= pair[1] && pair[1] >= 0.0));
+    }
+
+    #[test]
+    fn test_svd_diagonal() {
+        let tensor = TestTensor::from([[3.0, 0.0], [0.0, -4.0]]);
+
+        let (_u, s, _vh) = tensor.svd::<1>(false);
+
+        s.into_data().assert_approx_eq(&Data::from([4.0, 3.0]), 5);
+    }
+
+    #[test]
+    fn test_svd_thin_shapes() {
+        let tall = TestTensor::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let wide = tall.clone().transpose();
+
+        let (u, s, vh) = tall.clone().svd::<1>(false);
+        assert_eq!((u.dims(), s.dims(), vh.dims()), ([3, 2], [2], [2, 2]));
+        reconstruct(u, s, vh)
+            .into_data()
+            .assert_approx_eq(&tall.into_data(), 4);
+
+        let (u, s, vh) = wide.clone().svd::<1>(false);
+        assert_eq!((u.dims(), s.dims(), vh.dims()), ([2, 2], [2], [2, 3]));
+        reconstruct(u, s, vh)
+            .into_data()
+            .assert_approx_eq(&wide.into_data(), 4);
+    }
+
+    #[test]
+    fn test_svd_full_matrices() {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let (u, s, vh) = tensor.clone().svd::<1>(true);
+
+        assert_eq!((u.dims(), s.dims(), vh.dims()), ([3, 3], [2], [2, 2]));
+        assert_orthonormal_columns(u.clone());
+        reconstruct(u, s, vh)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+    }
+
+    #[test]
+    fn test_svd_rank_deficient() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 1.0, 1.0]]);
+
+        let (u, s, vh) = tensor.clone().svd::<1>(false);
+
+        assert!(s.clone().into_data().convert::<f32>().value[2].abs() < 1e-4);
+        assert_orthonormal_columns(u.clone());
+        reconstruct(u, s, vh)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+    }
+
+    #[test]
+    fn test_svd_batched() {
+        let tensor = Tensor::<TestBackend, 3>::from_floats(
+            [
+                [[1.0, 2.0, 0.0], [0.0, 1.0, -1.0]],
+                [[2.0, 0.0, 1.0], [1.0, 3.0, 1.0]],
+            ],
+            &Default::default(),
+        );
+
+        let (u, s, vh) = tensor.clone().svd::<2>(false);
+
+        assert_eq!(
+            (u.dims(), s.dims(), vh.dims()),
+            ([2, 2, 2], [2, 2], [2, 2, 3])
+        );
+        reconstruct(u, s, vh)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+    }
+}
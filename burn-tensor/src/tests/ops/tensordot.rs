@@ -0,0 +1,87 @@
+#[burn_tensor_testgen::testgen(tensordot)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_tensordot_matmul() {
+        let lhs = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::from([[1.0, -1.0], [0.5, 2.0], [3.0, 0.0]]);
+
+        let data_actual = lhs
+            .clone()
+            .tensordot::<2, 2, 1>(rhs.clone(), [[1], [0]])
+            .into_data();
+
+        let data_expected = lhs.matmul(rhs).into_data();
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn test_tensordot_frobenius_inner_product() {
+        let lhs = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::from([[5.0, 6.0], [7.0, 8.0]]);
+
+        let data_actual = lhs
+            .clone()
+            .tensordot::<2, 1, 2>(rhs.clone(), [[0, 1], [0, 1]])
+            .into_data();
+
+        let data_expected = lhs.mul(rhs).sum().into_data();
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn test_tensordot_transposed_dims() {
+        let lhs = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::from([[1.0, -1.0], [0.5, 2.0]]);
+
+        let data_actual = lhs
+            .clone()
+            .tensordot::<2, 2, 1>(rhs.clone(), [[0], [0]])
+            .into_data();
+
+        let data_expected = lhs.transpose().matmul(rhs).into_data();
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn test_tensordot_d3() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 3>::from_floats(
+            [[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]],
+            &device,
+        );
+        let rhs = TestTensor::from([[1.0, 0.0], [-1.0, 1.0]]);
+
+        // out[j] = sum_{i, l} lhs[i, j, l] * rhs[l, i]
+        let data_actual = lhs.tensordot::<2, 1, 2>(rhs, [[0, 2], [1, 0]]).into_data();
+
+        let data_expected = Data::from([5.0, 7.0]);
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn test_tensordot_outer_product() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0], &device);
+        let rhs = Tensor::<TestBackend, 1>::from_floats([3.0, 4.0, 5.0], &device);
+
+        let data_actual = lhs
+            .clone()
+            .tensordot::<1, 2, 0>(rhs.clone(), [[], []])
+            .into_data();
+
+        let data_expected = lhs.outer(rhs).into_data();
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_tensordot_mismatched_sizes() {
+        let lhs = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let _output = lhs.tensordot::<2, 2, 1>(rhs, [[1], [0]]);
+    }
+}
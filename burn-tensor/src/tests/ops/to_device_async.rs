@@ -0,0 +1,69 @@
+#[burn_tensor_testgen::testgen(to_device_async)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Int, Tensor};
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+    /// Polls the future until it completes.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        const VTABLE: RawWakerVTable = RawWakerVTable::new(
+            |_| RawWaker::new(core::ptr::null(), &VTABLE),
+            |_| {},
+            |_| {},
+            |_| {},
+        );
+        // The waker does nothing, so its data pointer is never used.
+        let waker = unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) };
+        let mut context = Context::from_waker(&waker);
+        let mut future = pin!(future);
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn should_move_float_tensor() {
+        let device = Default::default();
+        let tensor = TestTensor::from([[1.0, -2.0], [3.0, 4.5]]);
+
+        let output = block_on(tensor.to_device_async(&device));
+
+        assert_eq!(output.device(), device);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, -2.0], [3.0, 4.5]]), 5);
+    }
+
+    #[test]
+    fn should_move_int_and_bool_tensors() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, -2, 3], &device);
+        let mask = Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([true, false]), &device);
+
+        let tensor = block_on(tensor.to_device_async(&device));
+        let mask = block_on(mask.to_device_async(&device));
+
+        assert_eq!(tensor.into_data(), Data::from([1, -2, 3]));
+        assert_eq!(mask.into_data(), Data::from([true, false]));
+    }
+
+    #[test]
+    fn should_prepare_next_tensor_while_moving() {
+        let device = Default::default();
+        let batch = TestTensor::from([1.0, 2.0, 3.0]);
+
+        let transfer = batch.to_device_async(&device);
+        let next = TestTensor::from([4.0, 5.0, 6.0]).mul_scalar(2.0);
+        let batch = block_on(transfer);
+
+        batch
+            .add(next)
+            .into_data()
+            .assert_approx_eq(&Data::from([9.0, 12.0, 15.0]), 5);
+    }
+}
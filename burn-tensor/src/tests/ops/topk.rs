@@ -0,0 +1,82 @@
+#[burn_tensor_testgen::testgen(topk)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_top_k_last_dim() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 5., 3., 2.], [4., 2., 6., 0.]]),
+            &Default::default(),
+        );
+
+        let (values, indices) = tensor.top_k(2, 1);
+
+        assert_eq!(values.shape(), Shape::new([2, 2]));
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[5., 3.], [6., 4.]]), 3);
+        assert_eq!(indices.into_data(), Data::from([[1, 2], [2, 0]]));
+    }
+
+    #[test]
+    fn test_top_k_first_dim() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 5., 3.], [4., 2., 6.], [0., 7., -1.]]),
+            &Default::default(),
+        );
+
+        let (values, indices) = tensor.top_k(2, 0);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[4., 7., 6.], [1., 5., 3.]]), 3);
+        assert_eq!(indices.into_data(), Data::from([[1, 2, 1], [0, 0, 0]]));
+    }
+
+    #[test]
+    fn test_top_k_3d() {
+        let tensor = TestTensor::from_data(
+            Data::from([
+                [[1., 9.], [4., 2.], [3., 5.]],
+                [[8., 0.], [6., 7.], [2., 1.]],
+            ]),
+            &Default::default(),
+        );
+
+        let (values, indices) = tensor.top_k(1, 1);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[[4., 9.]], [[8., 7.]]]), 3);
+        assert_eq!(indices.into_data(), Data::from([[[1, 0]], [[0, 1]]]));
+    }
+
+    #[test]
+    fn test_top_k_equal_to_dim_size_sorts_descending() {
+        let tensor = TestTensor::from_data(Data::from([[3., 1., 2.]]), &Default::default());
+
+        let (values, indices) = tensor.top_k(3, 1);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[3., 2., 1.]]), 3);
+        assert_eq!(indices.into_data(), Data::from([[0, 2, 1]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_top_k_k_greater_than_dim_size() {
+        let tensor = TestTensor::from_data(Data::from([[3., 1., 2.]]), &Default::default());
+
+        let _ = tensor.top_k(4, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_top_k_invalid_dim() {
+        let tensor = TestTensor::from_data(Data::from([[3., 1., 2.]]), &Default::default());
+
+        let _ = tensor.top_k(1, 2);
+    }
+}
@@ -0,0 +1,50 @@
+#[burn_tensor_testgen::testgen(unbind)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_unbind_first_dim() {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let tensors: Vec<TestTensor<1>> = tensor.unbind(0);
+
+        assert_eq!(tensors.len(), 3);
+        assert_eq!(tensors[0].to_data(), Data::from([1.0, 2.0]));
+        assert_eq!(tensors[1].to_data(), Data::from([3.0, 4.0]));
+        assert_eq!(tensors[2].to_data(), Data::from([5.0, 6.0]));
+    }
+
+    #[test]
+    fn should_unbind_inner_dim() {
+        let tensor = TestTensorInt::arange(0..12, &Default::default()).reshape([2, 3, 2]);
+
+        let tensors: Vec<Tensor<TestBackend, 2, Int>> = tensor.unbind(1);
+
+        assert_eq!(tensors.len(), 3);
+        assert_eq!(tensors[0].to_data(), Data::from([[0, 1], [6, 7]]));
+        assert_eq!(tensors[1].to_data(), Data::from([[2, 3], [8, 9]]));
+        assert_eq!(tensors[2].to_data(), Data::from([[4, 5], [10, 11]]));
+    }
+
+    #[test]
+    fn should_stack_unbind_round_trip() {
+        let tensor = TestTensorInt::arange(0..24, &Default::default()).reshape([2, 3, 4]);
+
+        for dim in 0..3 {
+            let tensors: Vec<Tensor<TestBackend, 2, Int>> = tensor.clone().unbind(dim);
+            let output: Tensor<TestBackend, 3, Int> = Tensor::stack(tensors, dim);
+
+            assert_eq!(output.into_data(), tensor.to_data());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_dim_out_of_bounds() {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _tensors: Vec<TestTensor<1>> = tensor.unbind(2);
+    }
+}
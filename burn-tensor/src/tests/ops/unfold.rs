@@ -0,0 +1,103 @@
+#[burn_tensor_testgen::testgen(unfold)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Shape, Tensor};
+
+    #[test]
+    fn test_unfold_overlapping_windows() {
+        let tensor = TestTensor::from_data(Data::from([1., 2., 3., 4., 5.]), &Default::default());
+
+        let output = tensor.unfold::<2>(0, 3, 1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1., 2., 3.], [2., 3., 4.], [3., 4., 5.]]), 3);
+    }
+
+    #[test]
+    fn test_unfold_drops_incomplete_window() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 2., 3., 4., 5.], [6., 7., 8., 9., 10.]]),
+            &Default::default(),
+        );
+
+        let output = tensor.unfold::<3>(1, 2, 2);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[1., 2.], [3., 4.]], [[6., 7.], [8., 9.]]]), 3);
+    }
+
+    #[test]
+    fn test_unfold_inner_dim() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..12, &device)
+            .reshape(Shape::new([2, 3, 2]))
+            .float();
+
+        let output = tensor.unfold::<4>(1, 2, 1);
+
+        assert_eq!(output.dims(), [2, 2, 2, 2]);
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [[[0., 2.], [1., 3.]], [[2., 4.], [3., 5.]]],
+                [[[6., 8.], [7., 9.]], [[8., 10.], [9., 11.]]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_unfold_int() {
+        let tensor = TestTensorInt::from_data(Data::from([1, 2, 3, 4]), &Default::default());
+
+        let output = tensor.unfold::<2>(0, 2, 2);
+
+        assert_eq!(output.into_data(), Data::from([[1, 2], [3, 4]]));
+    }
+
+    #[test]
+    fn test_fold_sums_overlapping_windows() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 2., 3.], [3., 4., 5.]]),
+            &Default::default(),
+        );
+
+        let output = tensor.fold::<1>(0, 6, 2);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([1., 2., 6., 4., 5., 0.]), 3);
+    }
+
+    #[test]
+    fn test_fold_unfold_round_trip() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..24, &device)
+            .reshape(Shape::new([2, 6, 2]))
+            .float();
+
+        let output = tensor.clone().unfold::<4>(1, 3, 3).fold::<3>(1, 6, 3);
+
+        output.into_data().assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unfold_window_larger_than_dim() {
+        let tensor = TestTensor::from_data(Data::from([1., 2., 3.]), &Default::default());
+
+        let _output = tensor.unfold::<2>(0, 4, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fold_windows_exceed_length() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 2., 3.], [3., 4., 5.]]),
+            &Default::default(),
+        );
+
+        let _output = tensor.fold::<1>(0, 4, 2);
+    }
+}
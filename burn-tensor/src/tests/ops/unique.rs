@@ -0,0 +1,97 @@
+#[burn_tensor_testgen::testgen(unique)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn test_unique_float() {
+        let tensor = TestTensor::from_data(
+            Data::from([[3., 1., 2.], [1., 3., -1.]]),
+            &Default::default(),
+        );
+
+        let (values, inverse) = tensor.unique();
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([-1., 1., 2., 3.]), 3);
+        assert_eq!(inverse.into_data(), Data::from([3, 1, 2, 1, 3, 0]));
+    }
+
+    #[test]
+    fn test_unique_with_counts_int() {
+        let tensor = Tensor::<TestBackend, 1, Int>::from_data(
+            Data::from([4, 2, 4, 4, 0, 2]),
+            &Default::default(),
+        );
+
+        let (values, inverse, counts) = tensor.unique_with_counts();
+
+        assert_eq!(values.into_data(), Data::from([0, 2, 4]));
+        assert_eq!(inverse.into_data(), Data::from([2, 1, 2, 2, 0, 1]));
+        assert_eq!(counts.into_data(), Data::from([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_unique_inverse_reconstructs_tensor() {
+        let tensor = TestTensor::from_data(
+            Data::from([[5., 0.5, 5.], [0.5, 0.5, 7.]]),
+            &Default::default(),
+        );
+
+        let (values, inverse) = tensor.clone().unique();
+
+        let reconstructed = values.select(0, inverse).reshape([2, 3]);
+        reconstructed
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn test_unique_consecutive_1d() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 1., 2., 2., 3., 1., 1., 2.]]),
+            &Default::default(),
+        );
+
+        let values = tensor.unique_consecutive(1);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[1., 2., 3., 1., 2.]]), 3);
+    }
+
+    #[test]
+    fn test_unique_consecutive_dim_0() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data(
+            Data::from([[1, 2], [1, 2], [1, 3], [1, 2], [1, 2]]),
+            &Default::default(),
+        );
+
+        let values = tensor.unique_consecutive(0);
+
+        assert_eq!(values.into_data(), Data::from([[1, 2], [1, 3], [1, 2]]));
+    }
+
+    #[test]
+    fn test_unique_consecutive_dim_1_compares_whole_columns() {
+        let tensor = TestTensor::from_data(
+            Data::from([[1., 1., 2.], [3., 4., 4.]]),
+            &Default::default(),
+        );
+
+        let values = tensor.unique_consecutive(1);
+
+        values
+            .into_data()
+            .assert_approx_eq(&Data::from([[1., 1., 2.], [3., 4., 4.]]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unique_consecutive_should_panic_on_invalid_dim() {
+        let tensor = TestTensor::from_data(Data::from([[1., 2.]]), &Default::default());
+
+        tensor.unique_consecutive(2);
+    }
+}
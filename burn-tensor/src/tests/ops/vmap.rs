@@ -0,0 +1,63 @@
+#[burn_tensor_testgen::testgen(vmap)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_map_each_sample() {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let data_actual = tensor
+            .vmap(|sample: TestTensor<1>| sample.clone().mul(sample).cumsum(0))
+            .into_data();
+
+        let data_expected = Data::from([[1.0, 5.0], [9.0, 25.0], [25.0, 61.0]]);
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn should_match_batched_computation() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 3>::from_floats(
+            [
+                [[1.0, 2.0], [3.0, 4.0]],
+                [[-1.0, 0.5], [2.0, 0.0]],
+                [[0.0, 1.0], [1.0, 0.0]],
+            ],
+            &device,
+        );
+        let weights = Tensor::<TestBackend, 2>::from_floats([[0.5, -1.0], [2.0, 1.5]], &device);
+
+        let data_actual = tensor
+            .clone()
+            .vmap(|sample: TestTensor<2>| sample.matmul(weights.clone()))
+            .into_data();
+
+        let data_expected = tensor.matmul(weights.unsqueeze()).into_data();
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn should_map_samples_to_a_different_shape() {
+        let tensor =
+            Tensor::<TestBackend, 2, Int>::from_ints([[1, 2, 3], [4, 5, 6]], &Default::default());
+
+        let data_actual = tensor
+            .vmap(|sample: Tensor<TestBackend, 1, Int>| sample.narrow(0, 1, 2))
+            .into_data();
+
+        assert_eq!(data_actual, Data::from([[2, 3], [5, 6]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_samples_have_different_shapes() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mut length = 0;
+
+        let _tensor = tensor.vmap(|sample: TestTensor<1>| {
+            length += 1;
+            sample.narrow(0, 0, length)
+        });
+    }
+}
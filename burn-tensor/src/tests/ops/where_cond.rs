@@ -0,0 +1,76 @@
+#[burn_tensor_testgen::testgen(where_cond)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_select_from_x_where_true_and_y_where_false() {
+        let device = Default::default();
+        let condition =
+            TestTensorBool::from_bool(Data::from([[true, false], [false, true]]), &device);
+        let x = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let y = TestTensor::from_floats([[-1.0, -2.0], [-3.0, -4.0]], &device);
+
+        let output = Tensor::where_cond(condition, x, y);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, -2.0], [-3.0, 4.0]]), 3);
+    }
+
+    #[test]
+    fn should_broadcast_a_scalar_condition() {
+        let device = Default::default();
+        let x = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let y = TestTensor::from_floats([[-1.0, -2.0], [-3.0, -4.0]], &device);
+
+        let condition = TestTensorBool::<2>::from_bool(Data::from([[true]]), &device);
+        let output = Tensor::where_cond(condition, x.clone(), y.clone());
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 2.0], [3.0, 4.0]]), 3);
+
+        let condition = TestTensorBool::<2>::from_bool(Data::from([[false]]), &device);
+        let output = Tensor::where_cond(condition, x, y);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[-1.0, -2.0], [-3.0, -4.0]]), 3);
+    }
+
+    #[test]
+    fn should_broadcast_all_the_operands() {
+        let device = Default::default();
+        let condition = TestTensorBool::from_bool(Data::from([[true], [false]]), &device);
+        let x = TestTensor::from_floats([[1.0, 2.0, 3.0]], &device);
+        let y = TestTensor::from_floats([[0.0]], &device);
+
+        let output = Tensor::where_cond(condition, x, y);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 2.0, 3.0], [0.0, 0.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_support_int_tensors() {
+        let device = Default::default();
+        let condition = TestTensorBool::from_bool(Data::from([false, true, true]), &device);
+        let x = TestTensorInt::from_ints([1, 2, 3], &device);
+        let y = TestTensorInt::from_ints([0], &device);
+
+        let output = Tensor::where_cond(condition, x, y);
+
+        assert_eq!(output.into_data(), Data::from([0, 2, 3]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_shapes_are_incompatible() {
+        let device = Default::default();
+        let condition = TestTensorBool::from_bool(Data::from([true, false]), &device);
+        let x = TestTensor::from_floats([1.0, 2.0, 3.0], &device);
+        let y = TestTensor::from_floats([0.0], &device);
+
+        let _output = Tensor::where_cond(condition, x, y);
+    }
+}
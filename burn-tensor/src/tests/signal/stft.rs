@@ -0,0 +1,147 @@
+#[burn_tensor_testgen::testgen(signal_stft)]
+mod tests {
+    use super::*;
+    use burn_tensor::signal::{istft, stft, PadMode};
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_stft_center_reflect() {
+        let device = Default::default();
+        let waveform = TestTensor::from_floats([[1., 2., 3., 4., 5., 6., 7., 8.]], &device);
+
+        let spectrogram = stft(waveform, 4, 2, 4, hann_window(4), true, PadMode::Reflect);
+
+        spectrogram.into_data().assert_approx_eq(
+            &Data::from([[
+                [[3., 0.], [6., 0.], [10., 0.], [14., 0.], [14., 0.]],
+                [[-1., 0.], [-3., 1.], [-5., 1.], [-7., 1.], [-7., -1.]],
+                [[-1., 0.], [0., 0.], [0., 0.], [0., 0.], [0., 0.]],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_stft_center_replicate() {
+        let device = Default::default();
+        let waveform = TestTensor::from_floats([[1., 2., 3., 4., 5.]], &device);
+        let window = TestTensor::ones([4], &device);
+
+        let spectrogram = stft(waveform, 4, 2, 4, window, true, PadMode::Replicate);
+
+        spectrogram.into_data().assert_approx_eq(
+            &Data::from([[
+                [[5., 0.], [10., 0.], [17., 0.]],
+                [[0., 1.], [-2., 2.], [-2., 1.]],
+                [[-1., 0.], [-2., 0.], [-1., 0.]],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_stft_center_constant() {
+        let device = Default::default();
+        let waveform = TestTensor::from_floats([[1., 2., 3., 4., 5.]], &device);
+        let window = TestTensor::ones([4], &device);
+
+        let spectrogram = stft(waveform, 4, 2, 4, window, true, PadMode::Constant);
+
+        spectrogram.into_data().assert_approx_eq(
+            &Data::from([[
+                [[3., 0.], [10., 0.], [12., 0.]],
+                [[-1., 2.], [-2., 2.], [-2., -4.]],
+                [[-1., 0.], [-2., 0.], [4., 0.]],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_stft_window_shorter_than_n_fft() {
+        let device = Default::default();
+        let waveform = TestTensor::from_floats([[1., -2., 3., 0.5, 2.]], &device);
+        let window = TestTensor::ones([3], &device);
+
+        let spectrogram = stft(waveform, 4, 1, 3, window, false, PadMode::Constant);
+
+        spectrogram.into_data().assert_approx_eq(
+            &Data::from([[
+                [[2., 0.], [1.5, 0.]],
+                [[-2., 2.], [-2.5, -3.]],
+                [[6., 0.], [-4.5, 0.]],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_istft_inverts_stft() {
+        let device = Default::default();
+        let waveform = TestTensor::from_floats(
+            [
+                [
+                    0.5, -1., 2., 3., 0., 1., -0.5, 4., 2., 1., 0., -2., 1., 0.5, 3., -1.,
+                ],
+                [
+                    1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+                ],
+            ],
+            &device,
+        );
+
+        let spectrogram = stft(
+            waveform.clone(),
+            8,
+            2,
+            8,
+            hann_window(8),
+            true,
+            PadMode::Reflect,
+        );
+        let reconstructed = istft(spectrogram, 8, 2, 8, hann_window(8), true, Some(16));
+
+        reconstructed
+            .into_data()
+            .assert_approx_eq(&waveform.into_data(), 3);
+    }
+
+    #[test]
+    fn test_istft_pads_to_length() {
+        let device = Default::default();
+        let waveform = TestTensor::from_floats([[1., 2., 3., 4., 5., 6.]], &device);
+        let window = TestTensor::ones([4], &device);
+
+        let spectrogram = stft(waveform, 4, 2, 4, window.clone(), false, PadMode::Constant);
+        let reconstructed = istft(spectrogram, 4, 2, 4, window, false, Some(8));
+
+        reconstructed
+            .into_data()
+            .assert_approx_eq(&Data::from([[1., 2., 3., 4., 5., 6., 0., 0.]]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_stft_should_panic_when_window_is_longer_than_n_fft() {
+        let device = Default::default();
+        let waveform = TestTensor::from_floats([[1., 2., 3., 4., 5.]], &device);
+
+        stft(
+            waveform,
+            2,
+            1,
+            4,
+            TestTensor::ones([4], &device),
+            false,
+            PadMode::Constant,
+        );
+    }
+
+    fn hann_window(size: usize) -> TestTensor<1> {
+        let values: Vec<f32> = (0..size)
+            .map(|n| 0.5 - 0.5 * (2.0 * core::f32::consts::PI * n as f32 / size as f32).cos())
+            .collect();
+
+        Tensor::from_floats(Data::new(values, [size].into()), &Default::default())
+    }
+}
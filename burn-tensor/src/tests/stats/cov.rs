@@ -59,4 +59,49 @@ mod tests {
         let data_expected = Tensor::<TestBackend, 3>::zeros([4, 4, 4], &device).to_data();
         data_expected.assert_approx_eq(&data_actual, 3);
     }
+
+    #[test]
+    fn test_covariance_rowvar() {
+        let data = Data::from([[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data, &Default::default());
+
+        let rows = tensor.clone().covariance(true).into_data();
+        let columns = tensor.transpose().covariance(false).into_data();
+
+        let data_expected = Data::from([[2.4892, -1.7333], [-1.7333, 15.3333]]);
+        data_expected.assert_approx_eq(&rows, 3);
+        data_expected.assert_approx_eq(&columns, 3);
+    }
+
+    #[test]
+    fn test_corrcoef() {
+        let data = Data::from([
+            [0.5, 1.8, 0.2, -2.0],
+            [3.0, -4.0, 5.0, 0.0],
+            [1.0, 2.0, 3.0, 4.0],
+            [-2.0, -4.0, -6.0, -8.0],
+        ]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data, &Default::default());
+
+        let data_actual = tensor.corrcoef().into_data();
+
+        let data_expected = Data::from([
+            [1.0, -0.2806, -0.7446, 0.7446],
+            [-0.2806, 1.0, 0.0, 0.0],
+            [-0.7446, 0.0, 1.0, -1.0],
+            [0.7446, 0.0, -1.0, 1.0],
+        ]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+
+        let values = data_actual.convert::<f32>().value;
+        for i in 0..4 {
+            for j in 0..4 {
+                let value = values[i * 4 + j];
+                match i == j {
+                    true => assert_eq!(value, 1.0),
+                    false => assert!((-1.0..=1.0).contains(&value)),
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,46 @@
+use super::CheckpointingStrategy;
+use crate::{checkpoint::CheckpointingAction, metric::store::EventStoreClient};
+
+/// Keep the checkpoint of every N epochs.
+///
+/// The checkpoints are never deleted, which is useful to compare the model at different stages
+/// of the training.
+#[derive(new)]
+pub struct KeepEveryNEpochs {
+    num_epochs: usize,
+}
+
+impl CheckpointingStrategy for KeepEveryNEpochs {
+    fn checkpointing(
+        &mut self,
+        epoch: usize,
+        _store: &EventStoreClient,
+    ) -> Vec<CheckpointingAction> {
+        if epoch.is_multiple_of(self.num_epochs) {
+            vec![CheckpointingAction::Save]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::store::LogEventStore;
+
+    #[test]
+    fn should_save_every_n_epochs_without_deleting() {
+        let mut strategy = KeepEveryNEpochs::new(3);
+        let store = EventStoreClient::new(LogEventStore::default());
+
+        for epoch in 1..=7 {
+            let actions = strategy.checkpointing(epoch, &store);
+
+            match epoch {
+                3 | 6 => assert_eq!(vec![CheckpointingAction::Save], actions),
+                _ => assert!(actions.is_empty()),
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use super::CheckpointingStrategy;
 use crate::{
     checkpoint::CheckpointingAction,
@@ -7,9 +9,16 @@ use crate::{
     },
 };
 
-/// Keep the best checkpoint based on a metric.
+/// Keep the best checkpoints based on a metric.
+///
+/// By default, only the checkpoint of the best epoch is kept, use
+/// [with_top_k](MetricCheckpointingStrategy::with_top_k) to keep more of them.
 pub struct MetricCheckpointingStrategy {
-    current: Option<usize>,
+    kept: Vec<usize>,
+    history: Vec<(usize, f64)>,
+    history_path: Option<PathBuf>,
+    restored: bool,
+    num_keep: usize,
     aggregate: Aggregate,
     direction: Direction,
     split: Split,
@@ -22,14 +31,96 @@ impl MetricCheckpointingStrategy {
     where
         M: Metric,
     {
+        Self::named(M::NAME, aggregate, direction, split)
+    }
+
+    /// Create a new metric strategy using the name of the metric.
+    pub fn named(name: &str, aggregate: Aggregate, direction: Direction, split: Split) -> Self {
         Self {
-            current: None,
-            name: M::NAME.to_string(),
+            kept: Vec::new(),
+            history: Vec::new(),
+            history_path: None,
+            restored: false,
+            num_keep: 1,
+            name: name.to_string(),
             aggregate,
             direction,
             split,
         }
     }
+
+    /// Keep the checkpoints of the `num_keep` best epochs instead of only the best one.
+    ///
+    /// # Panics
+    ///
+    /// If `num_keep` is zero.
+    pub fn with_top_k(mut self, num_keep: usize) -> Self {
+        assert!(num_keep > 0, "At least one checkpoint must be kept.");
+        self.num_keep = num_keep;
+        self
+    }
+
+    /// Track the metric value of each epoch in the given file.
+    ///
+    /// The history of the previous epochs is restored from the file when the training is
+    /// resumed, so that the best checkpoints saved before are still considered.
+    pub fn with_history<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.history_path = Some(path.into());
+        self
+    }
+
+    fn restore_history(&mut self, epoch: usize) {
+        let path = match &self.history_path {
+            Some(path) if path.exists() => path,
+            _ => return,
+        };
+        let content = std::fs::read_to_string(path).expect("Can read the metric history.");
+
+        // Only the epochs before the current one are kept, the others are from a previous run.
+        self.history = content
+            .lines()
+            .filter_map(|line| {
+                let (epoch, value) = line.split_once(',')?;
+                Some((epoch.parse().ok()?, value.parse().ok()?))
+            })
+            .filter(|(epoch_history, _)| *epoch_history < epoch)
+            .collect();
+        self.kept = self.best_epochs();
+    }
+
+    fn save_history(&self) {
+        let path = match &self.history_path {
+            Some(path) => path,
+            None => return,
+        };
+
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory).ok();
+        }
+
+        let content: String = self
+            .history
+            .iter()
+            .map(|(epoch, value)| format!("{epoch},{value}\n"))
+            .collect();
+        std::fs::write(path, content).expect("Can write the metric history.");
+    }
+
+    fn best_epochs(&self) -> Vec<usize> {
+        let mut history = self.history.clone();
+
+        // The sort is stable, so the earliest epoch wins when values are equal.
+        match self.direction {
+            Direction::Lowest => history.sort_by(|a, b| a.1.total_cmp(&b.1)),
+            Direction::Highest => history.sort_by(|a, b| b.1.total_cmp(&a.1)),
+        }
+
+        history
+            .into_iter()
+            .take(self.num_keep)
+            .map(|(epoch, _)| epoch)
+            .collect()
+    }
 }
 
 impl CheckpointingStrategy for MetricCheckpointingStrategy {
@@ -38,25 +129,35 @@ impl CheckpointingStrategy for MetricCheckpointingStrategy {
         epoch: usize,
         store: &EventStoreClient,
     ) -> Vec<CheckpointingAction> {
-        let best_epoch =
-            match store.find_epoch(&self.name, self.aggregate, self.direction, self.split) {
-                Some(epoch_best) => epoch_best,
-                None => epoch,
-            };
+        if !self.restored {
+            self.restore_history(epoch);
+            self.restored = true;
+        }
+
+        let value = match store.find_metric(&self.name, epoch, self.aggregate, self.split) {
+            Some(value) => value,
+            None => return Vec::new(),
+        };
+
+        self.history
+            .retain(|(epoch_history, _)| *epoch_history != epoch);
+        self.history.push((epoch, value));
+        self.save_history();
 
+        let best_epochs = self.best_epochs();
         let mut actions = Vec::new();
 
-        if let Some(current) = self.current {
-            if current != best_epoch {
-                actions.push(CheckpointingAction::Delete(current));
+        for current in self.kept.iter() {
+            if !best_epochs.contains(current) {
+                actions.push(CheckpointingAction::Delete(*current));
             }
         }
 
-        if best_epoch == epoch {
+        if best_epochs.contains(&epoch) {
             actions.push(CheckpointingAction::Save);
         }
 
-        self.current = Some(best_epoch);
+        self.kept = best_epochs;
 
         actions
     }
@@ -130,4 +231,84 @@ mod tests {
         // new one.
         assert!(strategy.checkpointing(epoch, &store).is_empty());
     }
+
+    #[test]
+    fn keep_the_top_k_epochs_when_the_metric_fluctuates() {
+        let mut store = LogEventStore::default();
+        let mut strategy = MetricCheckpointingStrategy::new::<LossMetric<TestBackend>>(
+            Aggregate::Mean,
+            Direction::Lowest,
+            Split::Train,
+        )
+        .with_top_k(2);
+        let mut metrics = Metrics::<f64, f64>::default();
+        store.register_logger_train(InMemoryMetricLogger::default());
+        metrics.register_train_metric_numeric(LossMetric::<TestBackend>::new());
+        let store = Arc::new(EventStoreClient::new(store));
+        let mut processor = MinimalEventProcessor::new(metrics, store.clone());
+
+        let expected = [
+            (0.9, vec![CheckpointingAction::Save]),
+            (0.7, vec![CheckpointingAction::Save]),
+            (
+                0.8,
+                vec![CheckpointingAction::Delete(1), CheckpointingAction::Save],
+            ),
+            (0.95, vec![]),
+            (
+                0.5,
+                vec![CheckpointingAction::Delete(3), CheckpointingAction::Save],
+            ),
+            // Ties are resolved in favor of the earliest epoch, so epoch 6 isn't kept.
+            (0.7, vec![]),
+        ];
+
+        for (i, (loss, actions)) in expected.into_iter().enumerate() {
+            let epoch = i + 1;
+            process_train(&mut processor, loss, epoch);
+            end_epoch(&mut processor, epoch);
+            assert_eq!(actions, strategy.checkpointing(epoch, &store));
+        }
+    }
+
+    #[test]
+    fn restore_the_metric_history_when_resuming() {
+        let path = std::env::temp_dir().join(format!(
+            "burn-train-metric-history-{}.log",
+            std::process::id()
+        ));
+        std::fs::write(&path, "1,0.75\n2,0.25\n3,0.9\n").unwrap();
+
+        let mut store = LogEventStore::default();
+        let mut strategy = MetricCheckpointingStrategy::new::<LossMetric<TestBackend>>(
+            Aggregate::Mean,
+            Direction::Lowest,
+            Split::Train,
+        )
+        .with_top_k(2)
+        .with_history(&path);
+        let mut metrics = Metrics::<f64, f64>::default();
+        store.register_logger_train(InMemoryMetricLogger::default());
+        metrics.register_train_metric_numeric(LossMetric::<TestBackend>::new());
+        let store = Arc::new(EventStoreClient::new(store));
+        let mut processor = MinimalEventProcessor::new(metrics, store.clone());
+
+        // Resume at the third epoch, its history is from the previous run and is overwritten.
+        process_train(&mut processor, 1.0, 1);
+        end_epoch(&mut processor, 1);
+        process_train(&mut processor, 0.4, 2);
+        end_epoch(&mut processor, 2);
+        process_train(&mut processor, 0.5, 3);
+        end_epoch(&mut processor, 3);
+
+        // The checkpoints of the epochs 1 and 2 were kept, epoch 1 is replaced.
+        assert_eq!(
+            vec![CheckpointingAction::Delete(1), CheckpointingAction::Save],
+            strategy.checkpointing(3, &store)
+        );
+
+        let history = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(history, "1,0.75\n2,0.25\n3,0.5\n");
+    }
 }
@@ -1,9 +1,13 @@
 mod base;
 mod composed;
+mod epoch;
 mod lastn;
 mod metric;
+mod preset;
 
 pub use base::*;
 pub use composed::*;
+pub use epoch::*;
 pub use lastn::*;
 pub use metric::*;
+pub use preset::*;
@@ -0,0 +1,163 @@
+use super::{ComposedCheckpointingStrategy, KeepEveryNEpochs, MetricCheckpointingStrategy};
+use crate::metric::store::{Aggregate, Direction, Split};
+
+/// A simple checkpointing strategy, multiple ones can be used simultaneously with
+/// [compose](CheckpointStrategy::compose).
+#[derive(Clone)]
+pub enum CheckpointStrategy {
+    /// Keep the checkpoint every N epochs.
+    ByEpoch(usize),
+    /// Keep the checkpoints of the epochs with the best metric values.
+    BestMetric {
+        /// The name of the metric.
+        metric: String,
+        /// Whether lower or higher values are better.
+        direction: Direction,
+        /// The split on which the metric is computed.
+        split: Split,
+        /// The number of checkpoints to keep, only the best one is kept when set to 1.
+        keep_top_k: usize,
+    },
+}
+
+impl CheckpointStrategy {
+    /// Compose the strategies, a checkpoint is only deleted when no strategy wants to keep it.
+    ///
+    /// The metric history of each [best metric](CheckpointStrategy::BestMetric) strategy is
+    /// tracked in the `checkpoint-history` directory alongside the checkpoint directory.
+    pub fn compose<I>(strategies: I, directory: &str) -> ComposedCheckpointingStrategy
+    where
+        I: IntoIterator<Item = CheckpointStrategy>,
+    {
+        strategies
+            .into_iter()
+            .fold(
+                ComposedCheckpointingStrategy::builder(),
+                |builder, strategy| match strategy {
+                    CheckpointStrategy::ByEpoch(num_epochs) => {
+                        builder.add(KeepEveryNEpochs::new(num_epochs))
+                    }
+                    CheckpointStrategy::BestMetric {
+                        metric,
+                        direction,
+                        split,
+                        keep_top_k,
+                    } => {
+                        let split_name = match split {
+                            Split::Train => "train",
+                            Split::Valid => "valid",
+                        };
+                        let history =
+                            format!("{directory}/checkpoint-history/{split_name}/{metric}.log");
+
+                        builder.add(
+                            MetricCheckpointingStrategy::named(
+                                &metric,
+                                Aggregate::Mean,
+                                direction,
+                                split,
+                            )
+                            .with_top_k(keep_top_k)
+                            .with_history(history),
+                        )
+                    }
+                },
+            )
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        checkpoint::{CheckpointingAction, CheckpointingStrategy},
+        logger::InMemoryMetricLogger,
+        metric::{
+            processor::{
+                test_utils::{end_epoch, process_train},
+                Metrics, MinimalEventProcessor,
+            },
+            store::{EventStoreClient, LogEventStore},
+            LossMetric, Metric,
+        },
+        TestBackend,
+    };
+    use std::{collections::BTreeSet, sync::Arc};
+
+    /// Simulate a training run with one loss value per epoch and return the retained checkpoints.
+    fn retained_checkpoints(
+        name: &str,
+        strategies: Vec<CheckpointStrategy>,
+        losses: &[f64],
+    ) -> Vec<usize> {
+        let directory =
+            std::env::temp_dir().join(format!("burn-train-{name}-{}", std::process::id()));
+        std::fs::remove_dir_all(&directory).ok();
+
+        let mut store = LogEventStore::default();
+        let mut metrics = Metrics::<f64, f64>::default();
+        store.register_logger_train(InMemoryMetricLogger::default());
+        metrics.register_train_metric_numeric(LossMetric::<TestBackend>::new());
+        let store = Arc::new(EventStoreClient::new(store));
+        let mut processor = MinimalEventProcessor::new(metrics, store.clone());
+
+        let mut strategy = CheckpointStrategy::compose(strategies, directory.to_str().unwrap());
+        let mut retained = BTreeSet::new();
+
+        for (i, loss) in losses.iter().enumerate() {
+            let epoch = i + 1;
+            process_train(&mut processor, *loss, epoch);
+            end_epoch(&mut processor, epoch);
+
+            for action in strategy.checkpointing(epoch, &store) {
+                match action {
+                    CheckpointingAction::Delete(epoch) => assert!(retained.remove(&epoch)),
+                    CheckpointingAction::Save => assert!(retained.insert(epoch)),
+                }
+            }
+        }
+
+        let history = directory
+            .join("checkpoint-history")
+            .join("train")
+            .join(format!("{}.log", LossMetric::<TestBackend>::NAME));
+        if history.exists() {
+            let content = std::fs::read_to_string(history).unwrap();
+            assert_eq!(content.lines().count(), losses.len());
+        }
+        std::fs::remove_dir_all(&directory).ok();
+
+        retained.into_iter().collect()
+    }
+
+    fn best_loss(keep_top_k: usize) -> CheckpointStrategy {
+        CheckpointStrategy::BestMetric {
+            metric: LossMetric::<TestBackend>::NAME.to_string(),
+            direction: Direction::Lowest,
+            split: Split::Train,
+            keep_top_k,
+        }
+    }
+
+    const LOSSES: [f64; 7] = [0.9, 0.7, 0.8, 0.5, 0.6, 0.75, 0.4];
+
+    #[test]
+    fn should_keep_every_n_epochs_and_the_best_ones() {
+        let retained = retained_checkpoints(
+            "by-epoch-and-best",
+            vec![CheckpointStrategy::ByEpoch(2), best_loss(2)],
+            &LOSSES,
+        );
+
+        // Epochs 2, 4 and 6 are kept by epoch, 7 and 4 have the lowest losses.
+        assert_eq!(retained, vec![2, 4, 6, 7]);
+    }
+
+    #[test]
+    fn should_only_keep_the_best_checkpoint_when_top_1() {
+        let retained = retained_checkpoints("best-only", vec![best_loss(1)], &LOSSES);
+
+        assert_eq!(retained, vec![7]);
+    }
+}
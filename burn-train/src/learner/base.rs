@@ -1,7 +1,8 @@
 use crate::checkpoint::{Checkpointer, CheckpointingAction, CheckpointingStrategy};
 use crate::components::LearnerComponents;
-use crate::learner::EarlyStoppingStrategy;
+use crate::learner::{Communicator, EarlyStoppingStrategy, GradScaler, Swa};
 use crate::metric::store::EventStoreClient;
+use burn_core::grad_clipping::GradientClippingConfig;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::Module;
 use burn_core::optim::Optimizer;
@@ -20,6 +21,10 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) num_epochs: usize,
     pub(crate) checkpoint: Option<usize>,
     pub(crate) grad_accumulation: Option<usize>,
+    pub(crate) grad_scaler: Option<GradScaler>,
+    pub(crate) grad_clipping: Option<GradientClippingConfig>,
+    pub(crate) swa: Option<Swa>,
+    pub(crate) communicator: Option<Arc<dyn Communicator>>,
     pub(crate) checkpointer: Option<LearnerCheckpointer<LC>>,
     pub(crate) devices: Vec<<LC::Backend as Backend>::Device>,
     pub(crate) interrupter: TrainingInterrupter,
@@ -103,6 +108,30 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
 
         (model, optim, scheduler)
     }
+
+    /// Load the model checkpoint of the given epoch, the model being returned unchanged when no
+    /// checkpoint is available for that epoch.
+    pub(crate) fn load_model(
+        &self,
+        model: LC::Model,
+        device: &Device<LC::Backend>,
+        epoch: usize,
+    ) -> LC::Model {
+        match self.model.restore(epoch, device) {
+            Ok(record) => {
+                log::info!("Restoring the model from the checkpoint of epoch {}", epoch);
+                model.load_record(record)
+            }
+            Err(err) => {
+                log::warn!(
+                    "Can't restore the model from the checkpoint of epoch {}: {:?}",
+                    epoch,
+                    err
+                );
+                model
+            }
+        }
+    }
 }
 
 #[derive(Clone, Default)]
@@ -3,23 +3,28 @@ use std::sync::Arc;
 use super::log::install_file_logger;
 use super::Learner;
 use crate::checkpoint::{
-    AsyncCheckpointer, CheckpointingStrategy, ComposedCheckpointingStrategy, FileCheckpointer,
-    KeepLastNCheckpoints, MetricCheckpointingStrategy,
+    AsyncCheckpointer, CheckpointStrategy, CheckpointingStrategy, ComposedCheckpointingStrategy,
+    FileCheckpointer, KeepLastNCheckpoints, MetricCheckpointingStrategy,
 };
 use crate::components::LearnerComponentsMarker;
 use crate::learner::base::TrainingInterrupter;
-use crate::learner::EarlyStoppingStrategy;
+use crate::learner::{
+    Communicator, EarlyStoppingConfig, EarlyStoppingStrategy, GradScaler, GradScalerConfig,
+    HalfPrecision, Swa, SwaConfig,
+};
 use crate::logger::{FileMetricLogger, MetricLogger};
 use crate::metric::processor::{FullEventProcessor, Metrics};
 use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore, Split};
 use crate::metric::{Adaptor, LossMetric, Metric};
 use crate::renderer::{default_renderer, MetricsRenderer};
 use crate::LearnerCheckpointer;
+use burn_core::grad_clipping::GradientClippingConfig;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::AutodiffModule;
 use burn_core::optim::Optimizer;
 use burn_core::record::FileRecorder;
 use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::{ElementPrecision, Precision};
 
 /// Struct to configure and create a [learner](Learner).
 pub struct LearnerBuilder<B, T, V, M, O, S>
@@ -44,6 +49,12 @@ where
     checkpoint: Option<usize>,
     directory: String,
     grad_accumulation: Option<usize>,
+    grad_scaler: GradScaler,
+    grad_clipping: Option<GradientClippingConfig>,
+    swa: Option<Swa>,
+    communicator: Option<Arc<dyn Communicator>>,
+    loss_scaling: bool,
+    half_precision: Option<HalfPrecision>,
     devices: Vec<B::Device>,
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
     metrics: Metrics<T, V>,
@@ -76,6 +87,12 @@ where
             checkpointers: None,
             directory: directory.to_string(),
             grad_accumulation: None,
+            grad_scaler: GradScalerConfig::new().init(),
+            grad_clipping: None,
+            swa: None,
+            communicator: None,
+            loss_scaling: false,
+            half_precision: None,
             devices: vec![B::Device::default()],
             metrics: Metrics::default(),
             event_store: LogEventStore::default(),
@@ -122,6 +139,19 @@ where
         self.checkpointer_strategy = Box::new(strategy);
     }
 
+    /// Use multiple [checkpoint strategies](CheckpointStrategy) simultaneously, for instance to
+    /// keep the checkpoint every N epochs along with the ones of the best epochs.
+    ///
+    /// The metric history used to select the best epochs is saved in the learner directory.
+    pub fn with_checkpoint_strategies<I>(mut self, strategies: I) -> Self
+    where
+        I: IntoIterator<Item = CheckpointStrategy>,
+    {
+        self.checkpointer_strategy =
+            Box::new(CheckpointStrategy::compose(strategies, &self.directory));
+        self
+    }
+
     /// Replace the default CLI renderer with a custom one.
     ///
     /// # Arguments
@@ -153,21 +183,146 @@ where
         self
     }
 
-    /// Enable gradients accumulation.
+    /// Enable gradients accumulation over the given number of steps.
+    ///
+    /// # Notes
+    ///
+    /// When you enable gradients accumulation, the optimizer is only called once every `steps`
+    /// iterations, with the mean of all gradients generated by each backward pass. With a loss
+    /// averaged over the batch, the effect is the same as increasing the `batch size` by the
+    /// `steps` amount.
+    ///
+    /// The [learning rate scheduler](LrScheduler) also only advances once every `steps`
+    /// iterations. The training metrics are still updated for each iteration, their aggregation
+    /// over the epoch being the mean over all the iterations.
+    ///
+    /// When training on multiple devices, the gradients of the devices are summed, and only the
+    /// accumulation steps are averaged.
+    ///
+    /// # Breaking change
+    ///
+    /// The accumulated gradients used to be summed, and the scheduler stepped for each
+    /// iteration. With optimizers whose updates are proportional to the gradients, such as SGD,
+    /// the updates are now `steps` times smaller, so the learning rate must be multiplied by
+    /// `steps` to train the same way, while adaptive optimizers such as Adam are mostly
+    /// unaffected. The schedules expressed in iterations, such as warmup steps, must now be
+    /// expressed in optimizer steps.
+    pub fn with_gradient_accumulation(mut self, steps: usize) -> Self {
+        assert!(
+            steps > 0,
+            "The number of accumulation steps should be positive."
+        );
+        self.grad_accumulation = Some(steps);
+        self
+    }
+
+    /// Enable gradients accumulation, see
+    /// [with_gradient_accumulation](Self::with_gradient_accumulation).
+    #[deprecated(note = "Use `with_gradient_accumulation` instead.")]
+    pub fn grads_accumulation(self, accumulation: usize) -> Self {
+        self.with_gradient_accumulation(accumulation)
+    }
+
+    /// Enable gradient clipping.
+    ///
+    /// The gradients are clipped after the backward pass and before each optimizer step, once
+    /// they are accumulated and unscaled by the [gradient scaler](Self::grad_scaler). With
+    /// [global norm](GradientClippingConfig::GlobalNorm) clipping, the norm is computed over all
+    /// the gradients of the model.
+    pub fn with_gradient_clipping(mut self, config: GradientClippingConfig) -> Self {
+        self.grad_clipping = Some(config);
+        self
+    }
+
+    /// Enable [stochastic weight averaging](Swa).
+    ///
+    /// From the start epoch, the parameters are averaged every `update_freq` optimizer steps.
+    /// At the end of the training, the average replaces the parameters of the model, and the
+    /// running statistics of its layers, e.g. batch normalization, are updated with one pass over
+    /// the training set.
+    ///
+    /// The checkpoints are still saved with the parameters being trained, not their average, so
+    /// the averaging restarts from scratch when the training is resumed from a checkpoint.
+    pub fn with_swa(mut self, config: SwaConfig) -> Self {
+        self.swa = Some(config.init());
+        self
+    }
+
+    /// Enable distributed data parallel training, where each process trains a replica of the
+    /// model on its own shard of the training dataset.
+    ///
+    /// The parameters of the process of rank 0 are broadcasted to the others before the
+    /// training, and the gradients are averaged across all the processes with the
+    /// [communicator](Communicator) before each optimizer step. The batches of the training data
+    /// loader are distributed in turn to each rank, so it must return them in the same order in
+    /// every process.
+    ///
+    /// Every process should use its own directory, since each one saves its own checkpoints and
+    /// logs.
+    pub fn with_distributed<C>(mut self, communicator: C) -> Self
+    where
+        C: Communicator + 'static,
+    {
+        self.communicator = Some(Arc::new(communicator));
+        self
+    }
+
+    /// Enable dynamic loss scaling.
+    ///
+    /// The gradients are unscaled by the [gradient scaler](Self::grad_scaler) before each
+    /// optimizer step, which is skipped when infinite or NaN gradients are found. The
+    /// [train step](crate::TrainStep) must therefore call [scale](GradScaler::scale) on its loss
+    /// with the same scaler before the backward pass.
     ///
     /// # Notes
     ///
-    /// When you enable gradients accumulation, the gradients object used by the optimizer will be
-    /// the sum of all gradients generated by each backward pass. It might be a good idea to
-    /// reduce the learning to compensate.
+    /// Loss scaling is only useful when the backend itself computes with half precision floats,
+    /// e.g. `LibTorch<f16>` or `LibTorch<bf16>`. A warning is logged when it doesn't. Use
+    /// [with_half_precision](Self::with_half_precision) to also cast the model parameters.
+    pub fn with_loss_scaling(mut self) -> Self {
+        self.loss_scaling = true;
+        self
+    }
+
+    /// Train with half precision ([f16](burn_core::tensor::f16)) parameters.
+    ///
+    /// The float parameters of the model are cast to half precision when the learner is built,
+    /// and [loss scaling](Self::with_loss_scaling) is enabled.
+    ///
+    /// # Notes
     ///
-    /// The effect is similar to increasing the `batch size` and the `learning rate` by the `accumulation`
-    /// amount.
-    pub fn grads_accumulation(mut self, accumulation: usize) -> Self {
-        self.grad_accumulation = Some(accumulation);
+    /// The parameters keep the float element type of the backend, so the computations are only
+    /// done in half precision when the backend uses a half precision float type, e.g.
+    /// `LibTorch<f16>` or `Wgpu<AutoGraphicsApi, f16>`. Otherwise the parameters are only rounded
+    /// and a warning is logged.
+    pub fn with_half_precision(mut self) -> Self {
+        self.half_precision = Some(HalfPrecision::F16);
+        self.loss_scaling = true;
+        self
+    }
+
+    /// Train with [bf16](burn_core::tensor::bf16) parameters, see
+    /// [with_half_precision](Self::with_half_precision).
+    pub fn with_bf16_precision(mut self) -> Self {
+        self.half_precision = Some(HalfPrecision::Bf16);
+        self.loss_scaling = true;
+        self
+    }
+
+    /// Enable dynamic loss scaling with the given [gradient scaler](GradScaler), see
+    /// [with_loss_scaling](Self::with_loss_scaling).
+    pub fn with_grad_scaler(mut self, scaler: GradScaler) -> Self {
+        self.grad_scaler = scaler;
+        self.loss_scaling = true;
         self
     }
 
+    /// Provides the handle to the [gradient scaler](GradScaler) used when loss scaling is
+    /// enabled, to scale the loss in the [train step](crate::TrainStep).
+    pub fn grad_scaler(&self) -> GradScaler {
+        self.grad_scaler.clone()
+    }
+
     /// Register a [numeric](crate::metric::Numeric) training [metric](Metric).
     pub fn metric_train_numeric<Me>(mut self, metric: Me) -> Self
     where
@@ -197,6 +352,8 @@ where
     }
 
     /// Run the training loop on multiple devices.
+    ///
+    /// The gradients computed on each device are averaged before each optimizer step.
     pub fn devices(mut self, devices: Vec<B::Device>) -> Self {
         self.devices = devices;
         self
@@ -223,6 +380,17 @@ where
         self
     }
 
+    /// Stop the training when the validation metric of the [config](EarlyStoppingConfig) hasn't
+    /// improved by at least `min_delta` for `patience` epochs.
+    ///
+    /// When a [file checkpointer](Self::with_file_checkpointer) is registered, the model is
+    /// restored from the checkpoint of the best epoch when the training is stopped. The
+    /// [checkpointing strategy](Self::with_checkpointing_strategy) should therefore keep the
+    /// checkpoint of the best epoch for the same metric, otherwise the last model is kept.
+    pub fn with_early_stopping(self, config: EarlyStoppingConfig) -> Self {
+        self.early_stopping(config.init())
+    }
+
     /// By default, Rust logs are captured and written into
     /// `experiment.log`. If disabled, standard Rust log handling
     /// will apply.
@@ -297,6 +465,17 @@ where
         if self.log_to_file {
             self.init_logger();
         }
+        if self.loss_scaling && <B::FloatElem as ElementPrecision>::precision() != Precision::Half {
+            log::warn!(
+                "Loss scaling is enabled, but the backend doesn't use half precision floats: the \
+                 computations are done in {:?} precision.",
+                <B::FloatElem as ElementPrecision>::precision()
+            );
+        }
+        let model = match self.half_precision {
+            Some(precision) => precision.cast(model),
+            None => model,
+        };
         let renderer = self.renderer.unwrap_or_else(|| {
             Box::new(default_renderer(self.interrupter.clone(), self.checkpoint))
         });
@@ -330,6 +509,10 @@ where
             event_store,
             checkpoint: self.checkpoint,
             grad_accumulation: self.grad_accumulation,
+            grad_scaler: self.loss_scaling.then_some(self.grad_scaler),
+            grad_clipping: self.grad_clipping,
+            swa: self.swa,
+            communicator: self.communicator,
             devices: self.devices,
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
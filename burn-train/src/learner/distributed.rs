@@ -0,0 +1,474 @@
+use burn_core::data::dataloader::{DataLoader, DataLoaderIterator, Progress};
+use burn_core::module::{AutodiffModule, ModuleMapper, ModuleVisitor, ParamId};
+use burn_core::optim::GradientsParams;
+use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::{Data, Tensor};
+use std::marker::PhantomData;
+use std::sync::{Arc, Condvar, Mutex};
+
+/// Collective communication between the processes of a distributed training.
+///
+/// Each process has a distinct rank between `0` and `world_size - 1`. The collective operations
+/// must be called by all the processes in the same order, since each call blocks until all of
+/// them have reached it.
+///
+/// The [in process communicator](InProcessCommunicator) can be used to train with multiple
+/// threads; other transports, such as MPI or NCCL, can be supported by implementing this trait.
+pub trait Communicator: Send + Sync {
+    /// The rank of the current process.
+    fn rank(&self) -> usize;
+
+    /// The number of processes.
+    fn world_size(&self) -> usize;
+
+    /// Sum the values element-wise across all the processes, each process receiving the result.
+    fn all_reduce(&self, values: Vec<f32>) -> Vec<f32>;
+}
+
+/// Synchronize the gradients of a module between the processes of a distributed training.
+///
+/// After each backward pass, the gradients of every parameter are averaged across all the
+/// processes with an all-reduce operation, so that the optimizer applies the same update to each
+/// replica of the module.
+pub struct DistributedDataParallel<B: AutodiffBackend> {
+    communicator: Arc<dyn Communicator>,
+    phantom: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> DistributedDataParallel<B> {
+    /// Create a new distributed data parallel wrapper using the given communicator.
+    pub fn new(communicator: Arc<dyn Communicator>) -> Self {
+        Self {
+            communicator,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The rank of the current process.
+    pub fn rank(&self) -> usize {
+        self.communicator.rank()
+    }
+
+    /// The number of processes.
+    pub fn world_size(&self) -> usize {
+        self.communicator.world_size()
+    }
+
+    /// Average the gradients of the module across all the processes.
+    ///
+    /// Parameters without gradients on the current process contribute zeros to the average.
+    pub fn sync_gradients<M: AutodiffModule<B>>(
+        &self,
+        module: &M,
+        mut grads: GradientsParams,
+    ) -> GradientsParams {
+        let mut visitor = GradientsSynchronizer::<M, B>::new(
+            &mut grads,
+            self.communicator.as_ref(),
+            1.0 / self.world_size() as f32,
+        );
+        module.visit(&mut visitor);
+
+        grads
+    }
+
+    /// Replace the parameters of the module with the ones of the process of rank 0, so that
+    /// every replica starts the training from the same parameters.
+    pub fn broadcast_parameters<M: AutodiffModule<B>>(&self, module: M) -> M {
+        let mut mapper = ParamsBroadcaster::new(self.communicator.as_ref());
+        module.map(&mut mapper)
+    }
+}
+
+#[derive(new)]
+struct GradientsSynchronizer<'a, M, B> {
+    grads: &'a mut GradientsParams,
+    communicator: &'a dyn Communicator,
+    factor: f32,
+    #[new(default)]
+    phantom: PhantomData<(M, B)>,
+}
+
+impl<'a, B: AutodiffBackend, M: AutodiffModule<B>> ModuleVisitor<B>
+    for GradientsSynchronizer<'a, M, B>
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let device = tensor.device();
+        let grad = match self.grads.remove::<B::InnerBackend, D>(id) {
+            Some(grad) => grad,
+            None => Tensor::zeros(tensor.shape(), &device),
+        };
+
+        let data = grad.into_data().convert::<f32>();
+        let values = self
+            .communicator
+            .all_reduce(data.value)
+            .into_iter()
+            .map(|value| value * self.factor)
+            .collect();
+        let grad = Tensor::from_data(Data::new(values, data.shape).convert(), &device);
+
+        self.grads.register::<B::InnerBackend, D>(id.clone(), grad);
+    }
+}
+
+#[derive(new)]
+struct ParamsBroadcaster<'a> {
+    communicator: &'a dyn Communicator,
+}
+
+impl<'a, B: AutodiffBackend> ModuleMapper<B> for ParamsBroadcaster<'a> {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let is_require_grad = tensor.is_require_grad();
+        let device = tensor.device();
+        let data = tensor.into_data().convert::<f32>();
+
+        // Only the process of rank 0 contributes to the sum.
+        let values = match self.communicator.rank() {
+            0 => data.value,
+            _ => vec![0.0; data.value.len()],
+        };
+        let values = self.communicator.all_reduce(values);
+        let tensor = Tensor::from_data(Data::new(values, data.shape).convert(), &device);
+
+        match is_require_grad {
+            true => tensor.require_grad(),
+            false => tensor,
+        }
+    }
+}
+
+/// A communicator between threads of the same process.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_train::{Communicator, InProcessCommunicator};
+///
+/// let handles: Vec<_> = InProcessCommunicator::group(2)
+///     .into_iter()
+///     .map(|communicator| {
+///         std::thread::spawn(move || {
+///             let value = communicator.rank() as f32 + 1.0;
+///             communicator.all_reduce(vec![value])
+///         })
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), vec![3.0]);
+/// }
+/// ```
+pub struct InProcessCommunicator {
+    rank: usize,
+    world_size: usize,
+    state: Arc<(Mutex<AllReduceState>, Condvar)>,
+}
+
+#[derive(Default)]
+struct AllReduceState {
+    sum: Option<Vec<f32>>,
+    arrived: usize,
+    generation: usize,
+    result: Vec<f32>,
+}
+
+impl InProcessCommunicator {
+    /// Create the communicators of a group of `world_size` threads, ordered by rank.
+    pub fn group(world_size: usize) -> Vec<Self> {
+        assert!(world_size > 0, "A group needs at least one member.");
+        let state = Arc::new((Mutex::new(AllReduceState::default()), Condvar::new()));
+
+        (0..world_size)
+            .map(|rank| Self {
+                rank,
+                world_size,
+                state: state.clone(),
+            })
+            .collect()
+    }
+}
+
+impl Communicator for InProcessCommunicator {
+    fn rank(&self) -> usize {
+        self.rank
+    }
+
+    fn world_size(&self) -> usize {
+        self.world_size
+    }
+
+    fn all_reduce(&self, values: Vec<f32>) -> Vec<f32> {
+        let (lock, condvar) = self.state.as_ref();
+        let mut state = lock.lock().unwrap();
+
+        state.sum = Some(match state.sum.take() {
+            Some(sum) => {
+                assert_eq!(
+                    sum.len(),
+                    values.len(),
+                    "All the members must reduce the same number of values."
+                );
+                sum.into_iter().zip(values).map(|(a, b)| a + b).collect()
+            }
+            None => values,
+        });
+        state.arrived += 1;
+
+        // The result of a round is only replaced by the next round, which can't complete before
+        // every member has read it.
+        if state.arrived == self.world_size {
+            state.result = state.sum.take().unwrap();
+            state.arrived = 0;
+            state.generation += 1;
+            condvar.notify_all();
+        } else {
+            let generation = state.generation;
+            state = condvar
+                .wait_while(state, |state| state.generation == generation)
+                .unwrap();
+        }
+
+        state.result.clone()
+    }
+}
+
+/// A data loader only returning the batches of the given rank.
+///
+/// The batches are distributed in turn to each rank, and the last batches are dropped when they
+/// can't be given to every rank, so that all the processes run the same number of iterations.
+/// The wrapped data loader must return the batches in the same order on every process.
+pub struct ShardedDataLoader<O> {
+    dataloader: Arc<dyn DataLoader<O>>,
+    rank: usize,
+    world_size: usize,
+}
+
+impl<O> ShardedDataLoader<O> {
+    /// Create a new sharded data loader for the given rank.
+    pub fn new(dataloader: Arc<dyn DataLoader<O>>, rank: usize, world_size: usize) -> Self {
+        assert!(
+            rank < world_size,
+            "The rank {rank} must be lower than the world size {world_size}."
+        );
+
+        Self {
+            dataloader,
+            rank,
+            world_size,
+        }
+    }
+}
+
+impl<O> DataLoader<O> for ShardedDataLoader<O> {
+    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        Box::new(ShardedDataLoaderIterator {
+            iterator: self.dataloader.iter(),
+            rank: self.rank,
+            world_size: self.world_size,
+        })
+    }
+
+    fn num_items(&self) -> usize {
+        self.dataloader.num_items() / self.world_size
+    }
+}
+
+struct ShardedDataLoaderIterator<'a, O> {
+    iterator: Box<dyn DataLoaderIterator<O> + 'a>,
+    rank: usize,
+    world_size: usize,
+}
+
+impl<'a, O> Iterator for ShardedDataLoaderIterator<'a, O> {
+    type Item = O;
+
+    fn next(&mut self) -> Option<O> {
+        let mut item = None;
+
+        // The whole group of batches is consumed to make sure every rank has one.
+        for i in 0..self.world_size {
+            let batch = self.iterator.next()?;
+
+            if i == self.rank {
+                item = Some(batch);
+            }
+        }
+
+        item
+    }
+}
+
+impl<'a, O> DataLoaderIterator<O> for ShardedDataLoaderIterator<'a, O> {
+    fn progress(&self) -> Progress {
+        let progress = self.iterator.progress();
+
+        Progress::new(
+            progress.items_processed / self.world_size,
+            progress.items_total / self.world_size,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core::module::Module;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::tensor::Distribution;
+
+    #[test]
+    fn all_reduce_should_sum_the_values_of_every_rank_for_each_round() {
+        let handles: Vec<_> = InProcessCommunicator::group(3)
+            .into_iter()
+            .map(|communicator| {
+                std::thread::spawn(move || {
+                    (0..10)
+                        .map(|round| {
+                            let value = (communicator.rank() * round) as f32;
+                            communicator.all_reduce(vec![value, 1.0])
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let results = handle.join().unwrap();
+
+            for (round, result) in results.into_iter().enumerate() {
+                assert_eq!(result, vec![3.0 * round as f32, 3.0]);
+            }
+        }
+    }
+
+    #[test]
+    fn should_average_the_gradients_of_two_threads() {
+        let device = Default::default();
+        let model: Linear<TestAutodiffBackend> = LinearConfig::new(4, 2).init(&device);
+        let inputs = [
+            Tensor::<TestAutodiffBackend, 2>::random([3, 4], Distribution::Default, &device),
+            Tensor::<TestAutodiffBackend, 2>::random([3, 4], Distribution::Default, &device),
+        ];
+
+        let grads = |model: &Linear<TestAutodiffBackend>, input: Tensor<TestAutodiffBackend, 2>| {
+            let grads = model.forward(input).sum().backward();
+            GradientsParams::from_grads(grads, model)
+        };
+
+        // The expected gradients are the average of the gradients of both inputs.
+        let mut expected = grads(&model, inputs[0].clone());
+        let mut expected_other = grads(&model, inputs[1].clone());
+        let expected_weight = expected
+            .remove::<TestBackend, 2>(model.weight.id())
+            .unwrap()
+            .add(
+                expected_other
+                    .remove::<TestBackend, 2>(model.weight.id())
+                    .unwrap(),
+            )
+            .div_scalar(2.0)
+            .into_data();
+
+        let handles: Vec<_> = InProcessCommunicator::group(2)
+            .into_iter()
+            .zip(inputs)
+            .map(|(communicator, input)| {
+                // Each replica has its own autodiff graph, like in distinct processes.
+                let model = model.clone().fork(&device);
+                std::thread::spawn(move || {
+                    let ddp =
+                        DistributedDataParallel::<TestAutodiffBackend>::new(Arc::new(communicator));
+                    let grads = grads(&model, input);
+                    let mut grads = ddp.sync_gradients(&model, grads);
+
+                    grads
+                        .remove::<TestBackend, 2>(model.weight.id())
+                        .unwrap()
+                        .into_data()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap().assert_approx_eq(&expected_weight, 5);
+        }
+    }
+
+    #[test]
+    fn should_broadcast_the_parameters_of_rank_0() {
+        let device = Default::default();
+        let models: Vec<Linear<TestAutodiffBackend>> = (0..2)
+            .map(|_| LinearConfig::new(4, 2).init(&device))
+            .collect();
+        let expected = models[0].weight.val().into_data();
+
+        let handles: Vec<_> = InProcessCommunicator::group(2)
+            .into_iter()
+            .zip(models)
+            .map(|(communicator, model)| {
+                std::thread::spawn(move || {
+                    let ddp =
+                        DistributedDataParallel::<TestAutodiffBackend>::new(Arc::new(communicator));
+                    ddp.broadcast_parameters(model).weight.val().into_data()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap().assert_approx_eq(&expected, 5);
+        }
+    }
+
+    struct FakeDataLoader {
+        num_batches: usize,
+    }
+
+    struct FakeDataLoaderIterator {
+        current: usize,
+        num_batches: usize,
+    }
+
+    impl Iterator for FakeDataLoaderIterator {
+        type Item = usize;
+
+        fn next(&mut self) -> Option<usize> {
+            let item = (self.current < self.num_batches).then_some(self.current);
+            self.current += 1;
+            item
+        }
+    }
+
+    impl DataLoaderIterator<usize> for FakeDataLoaderIterator {
+        fn progress(&self) -> Progress {
+            Progress::new(self.current, self.num_batches)
+        }
+    }
+
+    impl DataLoader<usize> for FakeDataLoader {
+        fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<usize> + 'a> {
+            Box::new(FakeDataLoaderIterator {
+                current: 0,
+                num_batches: self.num_batches,
+            })
+        }
+
+        fn num_items(&self) -> usize {
+            self.num_batches
+        }
+    }
+
+    #[test]
+    fn sharded_dataloader_should_give_the_same_number_of_batches_to_every_rank() {
+        let dataloader: Arc<dyn DataLoader<usize>> = Arc::new(FakeDataLoader { num_batches: 5 });
+
+        let rank_0 = ShardedDataLoader::new(dataloader.clone(), 0, 2);
+        let rank_1 = ShardedDataLoader::new(dataloader, 1, 2);
+
+        // The last batch is dropped since only one rank could get it.
+        assert_eq!(rank_0.iter().collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(rank_1.iter().collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(rank_0.num_items(), 2);
+    }
+}
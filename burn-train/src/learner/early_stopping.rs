@@ -16,6 +16,43 @@ pub enum StoppingCondition {
 pub trait EarlyStoppingStrategy {
     /// Update its current state and returns if the training should be stopped.
     fn should_stop(&mut self, epoch: usize, store: &EventStoreClient) -> bool;
+
+    /// The best epoch so far, from which the model is restored when the training is stopped
+    /// and a checkpoint of that epoch is available.
+    fn best_epoch(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Configuration to create a [metric early stopping strategy](MetricEarlyStoppingStrategy)
+/// watching a validation metric.
+#[derive(Clone)]
+pub struct EarlyStoppingConfig {
+    /// The name of the watched metric, e.g. `Loss`.
+    pub metric: String,
+    /// The number of validation epochs without improvement before the training is stopped.
+    pub patience: usize,
+    /// The minimum change of the metric to be considered as an improvement.
+    pub min_delta: f64,
+    /// Whether the metric improves when it gets lower or higher.
+    pub direction: Direction,
+}
+
+impl EarlyStoppingConfig {
+    /// Initialize a new [metric early stopping strategy](MetricEarlyStoppingStrategy), based on
+    /// the mean of the metric over each validation epoch.
+    pub fn init(&self) -> MetricEarlyStoppingStrategy {
+        MetricEarlyStoppingStrategy::from_name(
+            self.metric.clone(),
+            Aggregate::Mean,
+            self.direction,
+            Split::Valid,
+            StoppingCondition::NoImprovementSince {
+                n_epochs: self.patience,
+            },
+        )
+        .with_min_delta(self.min_delta)
+    }
 }
 
 /// An [early stopping strategy](EarlyStoppingStrategy) based on a metrics collected
@@ -26,6 +63,7 @@ pub struct MetricEarlyStoppingStrategy {
     aggregate: Aggregate,
     direction: Direction,
     split: Split,
+    min_delta: f64,
     best_epoch: usize,
     best_value: f64,
 }
@@ -42,8 +80,8 @@ impl EarlyStoppingStrategy for MetricEarlyStoppingStrategy {
             };
 
         let is_best = match self.direction {
-            Direction::Lowest => current_value < self.best_value,
-            Direction::Highest => current_value > self.best_value,
+            Direction::Lowest => current_value < self.best_value - self.min_delta,
+            Direction::Highest => current_value > self.best_value + self.min_delta,
         };
 
         if is_best {
@@ -79,6 +117,10 @@ impl EarlyStoppingStrategy for MetricEarlyStoppingStrategy {
             }
         }
     }
+
+    fn best_epoch(&self) -> Option<usize> {
+        Some(self.best_epoch)
+    }
 }
 
 impl MetricEarlyStoppingStrategy {
@@ -93,6 +135,18 @@ impl MetricEarlyStoppingStrategy {
         direction: Direction,
         split: Split,
         condition: StoppingCondition,
+    ) -> Self {
+        Self::from_name(Me::NAME.to_string(), aggregate, direction, split, condition)
+    }
+
+    /// Create a new [early stopping strategy](EarlyStoppingStrategy) based on the metric with
+    /// the given name, see [new](Self::new).
+    pub fn from_name(
+        metric_name: String,
+        aggregate: Aggregate,
+        direction: Direction,
+        split: Split,
+        condition: StoppingCondition,
     ) -> Self {
         let init_value = match direction {
             Direction::Lowest => f64::MAX,
@@ -100,15 +154,22 @@ impl MetricEarlyStoppingStrategy {
         };
 
         Self {
-            metric_name: Me::NAME.to_string(),
+            metric_name,
             condition,
             aggregate,
             direction,
             split,
+            min_delta: 0.0,
             best_epoch: 1,
             best_value: init_value,
         }
     }
+
+    /// Only consider changes of the metric greater than `min_delta` as improvements.
+    pub fn with_min_delta(mut self, min_delta: f64) -> Self {
+        self.min_delta = min_delta;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -119,7 +180,7 @@ mod tests {
         logger::InMemoryMetricLogger,
         metric::{
             processor::{
-                test_utils::{end_epoch, process_train},
+                test_utils::{end_epoch, process_train, process_valid},
                 Metrics, MinimalEventProcessor,
             },
             store::LogEventStore,
@@ -184,6 +245,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn early_stop_when_improvement_is_smaller_than_min_delta() {
+        let config = EarlyStoppingConfig {
+            metric: LossMetric::<TestBackend>::NAME.to_string(),
+            patience: 2,
+            min_delta: 0.1,
+            direction: Direction::Lowest,
+        };
+        let mut early_stopping = config.init();
+        let (mut processor, store) = processor();
+
+        let data = [1.0, 0.5, 0.45, 0.42, 0.41];
+        let expected = [false, false, false, true, true];
+
+        for (i, (point, should_stop)) in data.iter().zip(expected).enumerate() {
+            let epoch = i + 1;
+            process_valid(&mut processor, *point, epoch);
+            end_epoch(&mut processor, epoch);
+
+            assert_eq!(
+                should_stop,
+                early_stopping.should_stop(epoch, &store),
+                "Epoch {epoch}"
+            );
+        }
+        assert_eq!(early_stopping.best_epoch(), Some(2));
+    }
+
+    #[test]
+    fn early_stop_on_highest_validation_metric() {
+        let config = EarlyStoppingConfig {
+            metric: LossMetric::<TestBackend>::NAME.to_string(),
+            patience: 1,
+            min_delta: 0.0,
+            direction: Direction::Highest,
+        };
+        let mut early_stopping = config.init();
+        let (mut processor, store) = processor();
+
+        for (epoch, point) in [(1, 0.2), (2, 0.5), (3, 0.6)] {
+            process_valid(&mut processor, point, epoch);
+            end_epoch(&mut processor, epoch);
+            assert!(!early_stopping.should_stop(epoch, &store));
+        }
+
+        process_valid(&mut processor, 0.4, 4);
+        end_epoch(&mut processor, 4);
+        assert!(early_stopping.should_stop(4, &store));
+        assert_eq!(early_stopping.best_epoch(), Some(3));
+    }
+
+    fn processor() -> (MinimalEventProcessor<f64, f64>, Arc<EventStoreClient>) {
+        let mut store = LogEventStore::default();
+        let mut metrics = Metrics::<f64, f64>::default();
+
+        store.register_logger_valid(InMemoryMetricLogger::default());
+        metrics.register_valid_metric_numeric(LossMetric::<TestBackend>::new());
+
+        let store = Arc::new(EventStoreClient::new(store));
+        let processor = MinimalEventProcessor::new(metrics, store.clone());
+
+        (processor, store)
+    }
+
     fn test_early_stopping(n_epochs: usize, data: &[(&[f64], bool, &str)]) {
         let mut early_stopping = MetricEarlyStoppingStrategy::new::<LossMetric<TestBackend>>(
             Aggregate::Mean,
@@ -1,12 +1,16 @@
 use burn_core::{
-    data::dataloader::DataLoader, lr_scheduler::LrScheduler, module::AutodiffModule,
-    optim::GradientsAccumulator, tensor::backend::Backend,
+    data::dataloader::{DataLoader, Progress},
+    grad_clipping::GradientClipping,
+    lr_scheduler::LrScheduler,
+    module::AutodiffModule,
+    optim::{GradientsAccumulator, GradientsParams},
+    tensor::backend::Backend,
 };
 use std::sync::Arc;
 
 use crate::metric::processor::{Event, EventProcessor, LearnerItem};
-use crate::{components::LearnerComponents, learner::base::TrainingInterrupter};
-use crate::{MultiDevicesTrainStep, TrainStep, ValidStep};
+use crate::{components::LearnerComponents, learner::base::TrainingInterrupter, GradScaler, Swa};
+use crate::{Communicator, DistributedDataParallel, MultiDevicesTrainStep, TrainStep, ValidStep};
 
 /// A validation epoch.
 #[derive(new)]
@@ -18,11 +22,16 @@ pub struct ValidEpoch<VI> {
 
 /// A training epoch.
 #[derive(new)]
+#[allow(clippy::too_many_arguments)]
 pub struct TrainEpoch<TI> {
     dataloader: Arc<dyn DataLoader<TI>>,
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    grad_scaler: Option<GradScaler>,
+    grad_clipping: Option<GradientClipping>,
+    swa: Option<Swa>,
+    communicator: Option<Arc<dyn Communicator>>,
 }
 
 impl<VI> ValidEpoch<VI> {
@@ -103,13 +112,18 @@ impl<TI> TrainEpoch<TI> {
         let mut iteration = 0;
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
+        let mut items_accumulated = Vec::new();
+        let mut lr = 0.0;
 
         while let Some(item) = iterator.next() {
             iteration += 1;
-            let lr = scheduler.step();
+            let progress = iterator.progress();
+            // The learning rate only changes between optimizer steps.
+            if accumulation_current == 0 {
+                lr = self.learning_rate(scheduler.step(), &progress);
+            }
             log::info!("Iteration {}", iteration);
 
-            let progress = iterator.progress();
             let item = model.step(item);
 
             match self.grad_accumulation {
@@ -118,12 +132,14 @@ impl<TI> TrainEpoch<TI> {
                     accumulation_current += 1;
 
                     if accumulation <= accumulation_current {
-                        let grads = accumulator.grads();
-                        model = model.optimize(&mut optim, lr, grads);
+                        let grads = accumulator
+                            .grads()
+                            .mul_scalar(1.0 / accumulation as f64, &model);
+                        model = self.optimize::<LC, TO>(model, &mut optim, lr, grads);
                         accumulation_current = 0;
                     }
                 }
-                None => model = model.optimize(&mut optim, lr, item.grads),
+                None => model = self.optimize::<LC, TO>(model, &mut optim, lr, item.grads),
             }
 
             let item = LearnerItem::new(
@@ -135,13 +151,21 @@ impl<TI> TrainEpoch<TI> {
                 Some(lr),
             );
 
-            processor.process_train(Event::ProcessedItem(item));
+            self.process_item(
+                processor,
+                item,
+                &mut items_accumulated,
+                accumulation_current,
+            );
 
             if interrupter.should_stop() {
                 log::info!("Training interrupted.");
                 break;
             }
         }
+        if !items_accumulated.is_empty() {
+            processor.process_train(Event::ProcessedItems(items_accumulated));
+        }
         processor.process_train(Event::EndEpoch(self.epoch));
 
         (model, optim)
@@ -187,6 +211,7 @@ impl<TI> TrainEpoch<TI> {
         let mut iteration = 0;
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
+        let mut items_accumulated = Vec::new();
 
         let accumulation = self.grad_accumulation.unwrap_or(1) * devices.len();
         let step = MultiDevicesTrainStep::new(&devices);
@@ -194,6 +219,7 @@ impl<TI> TrainEpoch<TI> {
         // The main device is always the first in the list.
         let device_main = devices.first().expect("A minimum of one device.").clone();
         let mut interrupted = false;
+        let mut lr = 0.0;
 
         loop {
             let items = step.step(&mut iterator, &model);
@@ -203,8 +229,12 @@ impl<TI> TrainEpoch<TI> {
 
             for item in items {
                 iteration += 1;
-                let lr = lr_scheduler.step();
                 let progress = iterator.progress();
+                // Without gradient accumulation, the scheduler is stepped for every item, even
+                // though the gradients of the devices are summed into a single optimizer step.
+                if self.grad_accumulation.is_none() || accumulation_current == 0 {
+                    lr = self.learning_rate(lr_scheduler.step(), &progress);
+                }
 
                 let grads = item.grads.to_device(&device_main, &model);
 
@@ -212,8 +242,15 @@ impl<TI> TrainEpoch<TI> {
                 accumulation_current += 1;
 
                 if accumulation <= accumulation_current {
-                    let grads = accumulator.grads();
-                    model = model.optimize(&mut optim, lr, grads);
+                    // The gradients are summed across the devices and only averaged over the
+                    // accumulation steps.
+                    let grads = match self.grad_accumulation {
+                        Some(grad_accumulation) => accumulator
+                            .grads()
+                            .mul_scalar(1.0 / grad_accumulation as f64, &model),
+                        None => accumulator.grads(),
+                    };
+                    model = self.optimize::<LC, TO>(model, &mut optim, lr, grads);
                     accumulation_current = 0;
                 }
 
@@ -226,7 +263,12 @@ impl<TI> TrainEpoch<TI> {
                     Some(lr),
                 );
 
-                processor.process_train(Event::ProcessedItem(item));
+                self.process_item(
+                    processor,
+                    item,
+                    &mut items_accumulated,
+                    accumulation_current,
+                );
 
                 if interrupter.should_stop() {
                     log::info!("Training interrupted.");
@@ -240,8 +282,264 @@ impl<TI> TrainEpoch<TI> {
             }
         }
 
+        if !items_accumulated.is_empty() {
+            processor.process_train(Event::ProcessedItems(items_accumulated));
+        }
         processor.process_train(Event::EndEpoch(self.epoch));
 
         (model, optim)
     }
 }
+
+impl<TI> TrainEpoch<TI> {
+    /// Updates the model with the optimizer, going through the gradient scaler when mixed
+    /// precision is enabled and clipping the gradients when gradient clipping is enabled. The
+    /// gradients are first averaged across the processes when training is distributed, and the
+    /// parameters are then averaged when stochastic weight averaging is enabled.
+    fn optimize<LC: LearnerComponents, TO>(
+        &self,
+        model: LC::Model,
+        optim: &mut LC::Optimizer,
+        lr: f64,
+        grads: GradientsParams,
+    ) -> LC::Model
+    where
+        LC::Model: TrainStep<TI, TO>,
+    {
+        let grads = match &self.communicator {
+            Some(communicator) => DistributedDataParallel::<LC::Backend>::new(communicator.clone())
+                .sync_gradients(&model, grads),
+            None => grads,
+        };
+
+        let model = match &self.grad_scaler {
+            Some(scaler) => {
+                let grads = scaler.unscale(&model, grads);
+                let grads = self.clip_gradients::<LC>(&model, grads);
+                let model = match scaler.found_inf() {
+                    true => {
+                        log::info!(
+                            "Skipping optimizer step, infinite or NaN gradients found with scale {}",
+                            scaler.scale_factor()
+                        );
+                        model
+                    }
+                    false => model.optimize(optim, lr, grads),
+                };
+                scaler.update();
+                model
+            }
+            None => {
+                let grads = self.clip_gradients::<LC>(&model, grads);
+                model.optimize(optim, lr, grads)
+            }
+        };
+
+        if let Some(swa) = &self.swa {
+            swa.step(&model, self.epoch);
+        }
+
+        model
+    }
+
+    /// Sends the item to the processor. With gradient accumulation, the items are kept until the
+    /// optimizer step, so that their metrics are averaged over the accumulation window and
+    /// logged once per optimizer step.
+    fn process_item<P: EventProcessor>(
+        &self,
+        processor: &mut P,
+        item: LearnerItem<P::ItemTrain>,
+        items_accumulated: &mut Vec<LearnerItem<P::ItemTrain>>,
+        accumulation_current: usize,
+    ) {
+        if self.grad_accumulation.is_none() {
+            processor.process_train(Event::ProcessedItem(item));
+            return;
+        }
+
+        items_accumulated.push(item);
+
+        if accumulation_current == 0 {
+            processor.process_train(Event::ProcessedItems(core::mem::take(items_accumulated)));
+        }
+    }
+
+    fn learning_rate(&self, lr: f64, progress: &Progress) -> f64 {
+        match &self.swa {
+            Some(swa) => {
+                let progress = progress.items_processed as f64 / progress.items_total as f64;
+                swa.learning_rate(lr, self.epoch, progress)
+            }
+            None => lr,
+        }
+    }
+
+    fn clip_gradients<LC: LearnerComponents>(
+        &self,
+        model: &LC::Model,
+        grads: GradientsParams,
+    ) -> GradientsParams {
+        match &self.grad_clipping {
+            Some(clipping) => clipping.clip_gradients(model, grads),
+            None => grads,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::logger::InMemoryMetricLogger;
+    use crate::renderer::{MetricState, MetricsRenderer, TrainingProgress};
+    use crate::{
+        LearnerBuilder, TestAutodiffBackend, TestBackend, TrainOutput, TrainStep, ValidStep,
+    };
+    use burn_core::data::dataloader::batcher::Batcher;
+    use burn_core::data::dataloader::DataLoaderBuilder;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::lr_scheduler::LrScheduler;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::optim::SgdConfig;
+    use burn_core::tensor::backend::Backend;
+    use burn_core::tensor::{Data, Shape, Tensor};
+    use burn_core::LearningRate;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    const NUM_ITEMS: usize = 16;
+
+    #[test]
+    fn accumulation_steps_on_half_batches_should_match_full_batches() {
+        let layer = LinearConfig::new(4, 4).init(&Default::default());
+
+        let (layer_full, scheduler_steps_full) =
+            train("grad-accumulation-full", layer.clone(), 8, None);
+        let (layer_accumulated, scheduler_steps_accumulated) =
+            train("grad-accumulation-half", layer, 4, Some(2));
+
+        // The scheduler is stepped once per optimizer step.
+        assert_eq!(scheduler_steps_full, 2);
+        assert_eq!(scheduler_steps_accumulated, 2);
+        layer_accumulated
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&layer_full.weight.val().into_data(), 5);
+        layer_accumulated
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_approx_eq(&layer_full.bias.unwrap().val().into_data(), 5);
+    }
+
+    type Item = ([f32; 4], [f32; 4]);
+    type Batch<B> = (Tensor<B, 2>, Tensor<B, 2>);
+
+    fn train(
+        name: &str,
+        layer: Linear<TestAutodiffBackend>,
+        batch_size: usize,
+        accumulation: Option<usize>,
+    ) -> (Linear<TestAutodiffBackend>, usize) {
+        let items = (0..NUM_ITEMS)
+            .map(|i| {
+                let x = i as f32 / NUM_ITEMS as f32;
+                ([x, 1.0 - x, x * x, 0.5], [2.0 * x, -x, 1.0, x - 0.5])
+            })
+            .collect::<Vec<Item>>();
+        let dataloader_train = DataLoaderBuilder::new(RegressionBatcher::<TestAutodiffBackend> {
+            device: Default::default(),
+        })
+        .batch_size(batch_size)
+        .build(InMemDataset::new(items.clone()));
+        let dataloader_valid = DataLoaderBuilder::new(RegressionBatcher::<TestBackend> {
+            device: Default::default(),
+        })
+        .batch_size(batch_size)
+        .build(InMemDataset::new(items));
+
+        let scheduler_steps = Arc::new(AtomicUsize::new(0));
+        let scheduler = CountingLr {
+            steps: scheduler_steps.clone(),
+        };
+
+        let directory =
+            std::env::temp_dir().join(format!("burn-train-{name}-{}", std::process::id()));
+        std::fs::remove_dir_all(&directory).ok();
+        let builder = LearnerBuilder::new(directory.to_str().unwrap())
+            .metric_loggers(InMemoryMetricLogger::new(), InMemoryMetricLogger::new())
+            .renderer(NoRenderer)
+            .log_to_file(false)
+            .devices(vec![Default::default()])
+            .num_epochs(1);
+        let builder = match accumulation {
+            Some(accumulation) => builder.with_gradient_accumulation(accumulation),
+            None => builder,
+        };
+        let learner = builder.build(layer, SgdConfig::new().init(), scheduler);
+
+        let layer = learner.fit(dataloader_train, dataloader_valid);
+        std::fs::remove_dir_all(&directory).ok();
+
+        (layer, scheduler_steps.load(Ordering::Relaxed))
+    }
+
+    struct RegressionBatcher<B: Backend> {
+        device: B::Device,
+    }
+
+    impl<B: Backend> Batcher<Item, Batch<B>> for RegressionBatcher<B> {
+        fn batch(&self, items: Vec<Item>) -> Batch<B> {
+            let tensor = |values: Vec<f32>| {
+                let shape = Shape::new([items.len(), 4]);
+                Tensor::from_data(Data::new(values, shape).convert(), &self.device)
+            };
+
+            (
+                tensor(items.iter().flat_map(|(input, _)| *input).collect()),
+                tensor(items.iter().flat_map(|(_, target)| *target).collect()),
+            )
+        }
+    }
+
+    impl TrainStep<Batch<TestAutodiffBackend>, ()> for Linear<TestAutodiffBackend> {
+        fn step(&self, (input, target): Batch<TestAutodiffBackend>) -> TrainOutput<()> {
+            // The loss is averaged over the batch.
+            let loss = (self.forward(input) - target).powf_scalar(2.0).mean();
+
+            TrainOutput::new(self, loss.backward(), ())
+        }
+    }
+
+    impl ValidStep<Batch<TestBackend>, ()> for Linear<TestBackend> {
+        fn step(&self, _item: Batch<TestBackend>) {}
+    }
+
+    struct CountingLr {
+        steps: Arc<AtomicUsize>,
+    }
+
+    impl<B: Backend> LrScheduler<B> for CountingLr {
+        type Record = ();
+
+        fn step(&mut self) -> LearningRate {
+            self.steps.fetch_add(1, Ordering::Relaxed);
+            0.5
+        }
+
+        fn to_record(&self) -> Self::Record {}
+
+        fn load_record(self, _record: Self::Record) -> Self {
+            self
+        }
+    }
+
+    struct NoRenderer;
+
+    impl MetricsRenderer for NoRenderer {
+        fn update_train(&mut self, _state: MetricState) {}
+        fn update_valid(&mut self, _state: MetricState) {}
+        fn render_train(&mut self, _item: TrainingProgress) {}
+        fn render_valid(&mut self, _item: TrainingProgress) {}
+    }
+}
@@ -0,0 +1,298 @@
+use burn_core as burn;
+
+use burn_core::config::Config;
+use burn_core::module::{AutodiffModule, ModuleVisitor, ParamId};
+use burn_core::optim::{GradientsParams, Optimizer};
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::{ElementConversion, Tensor};
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+/// Configuration to create a [gradient scaler](GradScaler).
+#[derive(Config, Debug)]
+pub struct GradScalerConfig {
+    /// The initial scale factor.
+    #[config(default = 65536.0)]
+    pub init_scale: f64,
+    /// The factor by which the scale is multiplied after `growth_interval` steps without
+    /// infinite or NaN gradients.
+    #[config(default = 2.0)]
+    pub growth_factor: f64,
+    /// The factor by which the scale is multiplied when infinite or NaN gradients are found.
+    #[config(default = 0.5)]
+    pub backoff_factor: f64,
+    /// The number of consecutive steps without infinite or NaN gradients before the scale grows.
+    #[config(default = 2000)]
+    pub growth_interval: usize,
+}
+
+impl GradScalerConfig {
+    /// Initialize a new [gradient scaler](GradScaler).
+    pub fn init(&self) -> GradScaler {
+        assert!(
+            self.init_scale > 0.0,
+            "The initial scale must be positive, got {}",
+            self.init_scale
+        );
+        assert!(
+            self.growth_factor > 1.0,
+            "The growth factor must be greater than 1, got {}",
+            self.growth_factor
+        );
+        assert!(
+            self.backoff_factor > 0.0 && self.backoff_factor < 1.0,
+            "The backoff factor must be between 0 and 1, got {}",
+            self.backoff_factor
+        );
+
+        GradScaler {
+            config: self.clone(),
+            state: Arc::new(Mutex::new(GradScalerState {
+                scale: self.init_scale,
+                growth_tracker: 0,
+                found_inf: false,
+            })),
+        }
+    }
+}
+
+/// Dynamic loss scaling to train with half precision floats without the small gradients being
+/// flushed to zero.
+///
+/// The loss is multiplied by a scale factor before the backward pass with [scale](Self::scale),
+/// and the gradients are divided by the same factor with [unscale](Self::unscale) before the
+/// optimizer step. When infinite or NaN gradients are found, the optimizer step is skipped and
+/// the scale is reduced by [update](Self::update); after a number of successful steps, the scale
+/// is increased again.
+///
+/// The scaler is a handle: its clones share the same state, so the handle used to scale the loss
+/// in the [train step](crate::TrainStep) can be the one registered on the
+/// [learner builder](crate::LearnerBuilder::grad_scaler).
+///
+/// # Example
+///
+/// ```rust
+/// use burn_core::module::AutodiffModule;
+/// use burn_core::optim::{GradientsParams, Optimizer};
+/// use burn_core::tensor::{backend::AutodiffBackend, Tensor};
+/// use burn_train::GradScaler;
+///
+/// fn optimize<B, M, O>(scaler: &GradScaler, model: M, optim: &mut O, loss: Tensor<B, 1>) -> M
+/// where
+///     B: AutodiffBackend,
+///     M: AutodiffModule<B>,
+///     O: Optimizer<M, B>,
+/// {
+///     let grads = scaler.scale(loss).backward();
+///     let grads = GradientsParams::from_grads(grads, &model);
+///
+///     let model = scaler.step(model, optim, 1.0e-3, grads);
+///     scaler.update();
+///     model
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GradScaler {
+    config: GradScalerConfig,
+    state: Arc<Mutex<GradScalerState>>,
+}
+
+#[derive(Debug)]
+struct GradScalerState {
+    scale: f64,
+    growth_tracker: usize,
+    found_inf: bool,
+}
+
+impl GradScaler {
+    /// Multiply the given loss by the current scale factor.
+    pub fn scale<B: Backend, const D: usize>(&self, loss: Tensor<B, D>) -> Tensor<B, D> {
+        loss.mul_scalar(self.scale_factor())
+    }
+
+    /// Divide the gradients of the module by the current scale factor, and record if any of
+    /// them is infinite or NaN.
+    pub fn unscale<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        module: &M,
+        mut grads: GradientsParams,
+    ) -> GradientsParams {
+        let mut state = self.state.lock().unwrap();
+        let mut visitor =
+            GradientsUnscaler::<M, B::InnerBackend>::new(&mut grads, 1.0 / state.scale, false);
+
+        module.visit(&mut visitor);
+        state.found_inf = visitor.found_inf;
+
+        grads
+    }
+
+    /// Unscale the gradients and update the module with the optimizer, unless infinite or NaN
+    /// gradients are found, in which case the module is returned unchanged.
+    pub fn step<B, M, O>(&self, module: M, optim: &mut O, lr: f64, grads: GradientsParams) -> M
+    where
+        B: AutodiffBackend,
+        M: AutodiffModule<B>,
+        O: Optimizer<M, B>,
+    {
+        let grads = self.unscale(&module, grads);
+
+        match self.found_inf() {
+            true => module,
+            false => optim.step(lr, module, grads),
+        }
+    }
+
+    /// Update the scale factor based on the gradients of the last [unscale](Self::unscale).
+    ///
+    /// The scale is multiplied by the backoff factor when infinite or NaN gradients were found,
+    /// and by the growth factor after `growth_interval` consecutive steps without them.
+    pub fn update(&self) {
+        let mut state = self.state.lock().unwrap();
+
+        if state.found_inf {
+            state.scale *= self.config.backoff_factor;
+            state.growth_tracker = 0;
+        } else {
+            state.growth_tracker += 1;
+
+            if state.growth_tracker >= self.config.growth_interval {
+                state.scale *= self.config.growth_factor;
+                state.growth_tracker = 0;
+            }
+        }
+
+        state.found_inf = false;
+    }
+
+    /// The current scale factor.
+    pub fn scale_factor(&self) -> f64 {
+        self.state.lock().unwrap().scale
+    }
+
+    /// If infinite or NaN gradients were found by the last [unscale](Self::unscale).
+    pub fn found_inf(&self) -> bool {
+        self.state.lock().unwrap().found_inf
+    }
+}
+
+#[derive(new)]
+struct GradientsUnscaler<'a, M, B: Backend> {
+    grads: &'a mut GradientsParams,
+    inv_scale: f64,
+    found_inf: bool,
+    #[new(default)]
+    phantom: PhantomData<(M, B)>,
+}
+
+impl<'a, B, M> ModuleVisitor<B> for GradientsUnscaler<'a, M, B::InnerBackend>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) else {
+            return;
+        };
+        let grad = grad.mul_scalar(self.inv_scale);
+
+        if !self.found_inf {
+            // NaN values aren't lower than infinity either.
+            let num_finite: i64 = grad
+                .clone()
+                .abs()
+                .lower_elem(f64::INFINITY)
+                .int()
+                .sum()
+                .into_scalar()
+                .elem();
+
+            self.found_inf = num_finite as usize != grad.shape().num_elements();
+        }
+
+        self.grads.register::<B::InnerBackend, D>(id.clone(), grad);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core::module::list_param_ids;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::optim::SgdConfig;
+    use burn_core::tensor::Data;
+
+    #[test]
+    fn scale_multiplies_the_loss() {
+        let scaler = GradScalerConfig::new().with_init_scale(8.0).init();
+        let loss = Tensor::<TestAutodiffBackend, 1>::from_floats([0.5], &Default::default());
+
+        let loss = scaler.scale(loss);
+
+        assert_eq!(loss.into_data(), Data::from([4.0]));
+    }
+
+    #[test]
+    fn unscale_divides_the_gradients() {
+        let device = Default::default();
+        let scaler = GradScalerConfig::new().with_init_scale(1024.0).init();
+        let layer = layer(&device);
+        let input = Tensor::ones([2, 4], &device);
+
+        let grads = layer.forward(input.clone()).sum().backward();
+        let expected = GradientsParams::from_grads(grads, &layer);
+        let grads = scaler.scale(layer.forward(input).sum()).backward();
+        let grads = scaler.unscale(&layer, GradientsParams::from_grads(grads, &layer));
+
+        // The weight is the first parameter of the layer.
+        let id = &list_param_ids(&layer)[0];
+        let grad: Tensor<TestBackend, 2> = grads.get(id).unwrap();
+        let expected: Tensor<TestBackend, 2> = expected.get(id).unwrap();
+        grad.into_data().assert_approx_eq(&expected.into_data(), 3);
+        assert!(!scaler.found_inf());
+    }
+
+    #[test]
+    fn step_is_skipped_and_scale_reduced_on_infinite_gradients() {
+        let device = Default::default();
+        let scaler = GradScalerConfig::new().with_init_scale(f64::MAX).init();
+        let mut optim = SgdConfig::new().init();
+        let layer = layer(&device);
+        let weight = layer.weight.val().into_data();
+
+        let grads = scaler
+            .scale(layer.forward(Tensor::ones([2, 4], &device)).sum())
+            .backward();
+        let grads = GradientsParams::from_grads(grads, &layer);
+        let layer = scaler.step(layer, &mut optim, 0.1, grads);
+
+        assert!(scaler.found_inf());
+        assert_eq!(layer.weight.val().into_data(), weight);
+
+        scaler.update();
+
+        assert_eq!(scaler.scale_factor(), f64::MAX * 0.5);
+        assert!(!scaler.found_inf());
+    }
+
+    #[test]
+    fn scale_grows_after_growth_interval() {
+        let scaler = GradScalerConfig::new()
+            .with_init_scale(4.0)
+            .with_growth_interval(3)
+            .init();
+
+        for _ in 0..2 {
+            scaler.update();
+        }
+        assert_eq!(scaler.scale_factor(), 4.0);
+
+        scaler.clone().update();
+        assert_eq!(scaler.scale_factor(), 8.0);
+    }
+
+    fn layer(device: &<TestAutodiffBackend as Backend>::Device) -> Linear<TestAutodiffBackend> {
+        LinearConfig::new(4, 3).init(device)
+    }
+}
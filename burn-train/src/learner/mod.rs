@@ -1,10 +1,15 @@
 mod base;
 mod builder;
 mod classification;
+mod distributed;
 mod early_stopping;
 mod epoch;
+mod grad_scaler;
+mod precision;
 mod regression;
+mod sam;
 mod step;
+mod swa;
 mod train_val;
 
 pub(crate) mod log;
@@ -12,9 +17,14 @@ pub(crate) mod log;
 pub use base::*;
 pub use builder::*;
 pub use classification::*;
+pub use distributed::*;
 pub use early_stopping::*;
 pub use epoch::*;
+pub use grad_scaler::*;
+pub use precision::*;
 pub use regression::*;
+pub use sam::*;
 pub use step::*;
+pub use swa::*;
 pub use train::*;
 pub use train_val::*;
@@ -0,0 +1,76 @@
+use burn_core::module::{Module, ModuleMapper, ParamId};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{bf16, f16, Tensor};
+
+/// The half precision float type the model parameters are cast to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HalfPrecision {
+    /// IEEE 754 half precision floats.
+    F16,
+    /// Brain floats, with the exponent range of `f32`.
+    Bf16,
+}
+
+impl HalfPrecision {
+    /// Cast the float parameters of the module to half precision.
+    ///
+    /// The parameters keep the float element type of the backend: when it isn't a half precision
+    /// type, their values are only rounded to the nearest representable half precision value.
+    pub fn cast<B: Backend, M: Module<B>>(self, module: M) -> M {
+        module.map(&mut HalfPrecisionMapper { precision: self })
+    }
+}
+
+struct HalfPrecisionMapper {
+    precision: HalfPrecision,
+}
+
+impl<B: Backend> ModuleMapper<B> for HalfPrecisionMapper {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let device = tensor.device();
+        let require_grad = tensor.is_require_grad();
+        let data = match self.precision {
+            HalfPrecision::F16 => tensor.into_data().convert::<f16>().convert(),
+            HalfPrecision::Bf16 => tensor.into_data().convert::<bf16>().convert(),
+        };
+
+        Tensor::from_data(data, &device).set_require_grad(require_grad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use burn_core::module::Param;
+    use burn_core::tensor::Data;
+
+    #[test]
+    fn cast_should_round_the_parameters() {
+        let param = param([1.0 + 1.0 / 4096.0, 0.1]);
+
+        let param = HalfPrecision::F16.cast(param);
+
+        assert!(param.is_require_grad());
+        assert_eq!(
+            param.val().into_data(),
+            Data::from([1.0, f16::from_f32(0.1).to_f32()])
+        );
+    }
+
+    #[test]
+    fn cast_to_bf16_should_keep_the_range_of_f32() {
+        let param = param([1.0e10, 1.0 + 1.0 / 512.0]);
+
+        let param = HalfPrecision::Bf16.cast(param);
+
+        assert_eq!(
+            param.val().into_data(),
+            Data::from([bf16::from_f32(1.0e10).to_f32(), 1.0])
+        );
+    }
+
+    fn param(values: [f32; 2]) -> Param<Tensor<TestAutodiffBackend, 1>> {
+        Param::from(Tensor::from_floats(values, &Default::default()).require_grad())
+    }
+}
@@ -0,0 +1,109 @@
+use crate::TrainOutput;
+use burn_core::module::AutodiffModule;
+use burn_core::optim::{perturb_module, GradientsParams};
+use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::Tensor;
+
+/// Sharpness-Aware Minimization for the [train step](crate::TrainStep) of the
+/// [learner](crate::Learner).
+///
+/// The learner applies the optimizer once per batch with the gradients returned by the train
+/// step. Those gradients are computed at the point of highest loss in the neighborhood of the
+/// parameters, `w + rho * g / ||g||`, on a perturbed copy of the model. The parameters of the copy
+/// having the same ids, the optimizer then updates the original parameters, which is the same
+/// update as [Sam](burn_core::optim::Sam) without having to restore the parameters.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_core::module::AutodiffModule;
+/// use burn_core::tensor::{backend::AutodiffBackend, Tensor};
+/// use burn_train::{SamLearnerStep, TrainOutput};
+///
+/// fn step<B, M, F>(model: &M, item: Tensor<B, 2>, forward: F) -> TrainOutput<Tensor<B, 1>>
+/// where
+///     B: AutodiffBackend,
+///     M: AutodiffModule<B>,
+///     F: Fn(&M, Tensor<B, 2>) -> Tensor<B, 1>,
+/// {
+///     SamLearnerStep::new(0.05).step(model, item, |model, item| {
+///         let loss = forward(model, item);
+///         (loss.clone(), loss)
+///     })
+/// }
+/// ```
+#[derive(new, Clone, Debug)]
+pub struct SamLearnerStep {
+    rho: f64,
+}
+
+impl SamLearnerStep {
+    /// Run the two forward and backward passes of Sharpness-Aware Minimization on the item.
+    ///
+    /// The `forward` function returns the loss along with the output of the train step. The
+    /// output of the first pass, computed with the original parameters, is the one returned.
+    pub fn step<B, M, TI, TO, F>(&self, model: &M, item: TI, forward: F) -> TrainOutput<TO>
+    where
+        B: AutodiffBackend,
+        M: AutodiffModule<B>,
+        TI: Clone,
+        F: Fn(&M, TI) -> (Tensor<B, 1>, TO),
+    {
+        let (loss, output) = forward(model, item.clone());
+        let grads = GradientsParams::from_grads(loss.backward(), model);
+
+        let (model_perturbed, _) = perturb_module(model.clone(), &grads, self.rho);
+        let (loss, _) = forward(&model_perturbed, item);
+
+        TrainOutput::new(&model_perturbed, loss.backward(), output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core::module::list_param_ids;
+    use burn_core::nn::{Linear, LinearConfig};
+
+    const RHO: f64 = 0.5;
+
+    #[test]
+    fn step_returns_gradients_at_perturbed_params() {
+        let device = Default::default();
+        let model = LinearConfig::new(2, 2).init::<TestAutodiffBackend>(&device);
+        let input = Tensor::from_floats([[0.5, -1.0], [2.0, 1.0]], &device);
+        let id = &list_param_ids(&model)[0];
+
+        let output = SamLearnerStep::new(RHO).step(&model, input.clone(), |model, input| {
+            let loss = forward(model, input);
+            (loss.clone(), loss)
+        });
+
+        let loss = forward(&model, input.clone());
+        let grads = GradientsParams::from_grads(loss.clone().backward(), &model);
+        let (perturbed, _) = perturb_module(model.clone(), &grads, RHO);
+        let expected =
+            GradientsParams::from_grads(forward(&perturbed, input).backward(), &perturbed);
+
+        let grad: Tensor<TestBackend, 2> = output.grads.get(id).unwrap();
+        let grad_original: Tensor<TestBackend, 2> = grads.get(id).unwrap();
+        let grad_expected: Tensor<TestBackend, 2> = expected.get(id).unwrap();
+        grad.clone()
+            .into_data()
+            .assert_approx_eq(&grad_expected.into_data(), 5);
+        assert_ne!(grad.into_data(), grad_original.into_data());
+        // The output is the one computed with the original parameters.
+        output
+            .item
+            .into_data()
+            .assert_approx_eq(&loss.into_data(), 5);
+    }
+
+    fn forward(
+        model: &Linear<TestAutodiffBackend>,
+        input: Tensor<TestAutodiffBackend, 2>,
+    ) -> Tensor<TestAutodiffBackend, 1> {
+        model.forward(input).powf_scalar(2.0).sum()
+    }
+}
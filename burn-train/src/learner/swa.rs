@@ -0,0 +1,282 @@
+use burn_core as burn;
+
+use burn_core::config::Config;
+use burn_core::module::{AutodiffModule, ModuleMapper, ModuleVisitor, ParamId};
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::container::TensorContainer;
+use burn_core::tensor::Tensor;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+/// Configuration to create a [stochastic weight averaging](Swa) handle.
+#[derive(Config, Debug)]
+pub struct SwaConfig {
+    /// The epoch from which the parameters are averaged.
+    pub start_epoch: usize,
+    /// The number of optimizer steps between two updates of the average.
+    pub update_freq: usize,
+    /// The number of epochs over which the learning rate is annealed to the
+    /// [SWA learning rate](SwaConfig::swa_lr).
+    pub anneal_epochs: usize,
+    /// The learning rate used once the averaging started. When not set, the learning rate of the
+    /// scheduler is kept.
+    pub swa_lr: Option<f64>,
+}
+
+impl SwaConfig {
+    /// Initialize a new [stochastic weight averaging](Swa) handle.
+    pub fn init(&self) -> Swa {
+        assert!(
+            self.start_epoch > 0,
+            "The start epoch must be positive, epochs starting at 1"
+        );
+        assert!(
+            self.update_freq > 0,
+            "The update frequency must be positive, got {}",
+            self.update_freq
+        );
+
+        Swa {
+            config: self.clone(),
+            state: Arc::new(Mutex::new(SwaState {
+                averages: TensorContainer::new(),
+                num_averaged: 0,
+                num_steps: 0,
+            })),
+        }
+    }
+}
+
+/// Stochastic weight averaging, as described in the paper
+/// [Averaging Weights Leads to Wider Optima and Better Generalization](https://arxiv.org/abs/1803.05407).
+///
+/// From the start epoch, the parameters of the model are averaged every `update_freq` optimizer
+/// steps with [step](Self::step), and the learning rate can be annealed to a constant value with
+/// [learning_rate](Self::learning_rate). The average replaces the parameters of the model with
+/// [apply](Self::apply) at the end of the training.
+///
+/// The handle can be registered on the [learner builder](crate::LearnerBuilder::with_swa), or
+/// used in a custom training loop: its clones share the same state.
+#[derive(Clone)]
+pub struct Swa {
+    config: SwaConfig,
+    state: Arc<Mutex<SwaState>>,
+}
+
+struct SwaState {
+    averages: TensorContainer<ParamId>,
+    num_averaged: usize,
+    num_steps: usize,
+}
+
+impl Swa {
+    /// Record an optimizer step of the given epoch, updating the average with the parameters of
+    /// the model every `update_freq` steps from the start epoch.
+    pub fn step<B: AutodiffBackend, M: AutodiffModule<B>>(&self, model: &M, epoch: usize) {
+        if epoch < self.config.start_epoch {
+            return;
+        }
+
+        let should_update = {
+            let mut state = self.state.lock().unwrap();
+            state.num_steps += 1;
+
+            if state.num_steps >= self.config.update_freq {
+                state.num_steps = 0;
+                true
+            } else {
+                false
+            }
+        };
+
+        if should_update {
+            self.update(model);
+        }
+    }
+
+    /// Update the average with the parameters of the model.
+    pub fn update<B: AutodiffBackend, M: AutodiffModule<B>>(&self, model: &M) {
+        let mut state = self.state.lock().unwrap();
+        let num_averaged = state.num_averaged;
+        let mut visitor = ParamsAverager::<B::InnerBackend>::new(&mut state.averages, num_averaged);
+
+        model.visit(&mut visitor);
+        state.num_averaged += 1;
+    }
+
+    /// The number of times the parameters were averaged.
+    pub fn num_averaged(&self) -> usize {
+        self.state.lock().unwrap().num_averaged
+    }
+
+    /// The learning rate to use at the given epoch, `progress` being the fraction of the epoch
+    /// already done.
+    ///
+    /// Before the start epoch, or when no [SWA learning rate](SwaConfig::swa_lr) is set, the
+    /// learning rate of the scheduler is returned, so that the averaging can be combined with
+    /// any [scheduler](burn_core::lr_scheduler::LrScheduler). Otherwise, the learning rate is
+    /// annealed from the one of the scheduler to the SWA learning rate with a cosine over
+    /// `anneal_epochs` epochs.
+    pub fn learning_rate(&self, lr: f64, epoch: usize, progress: f64) -> f64 {
+        let swa_lr = match self.config.swa_lr {
+            Some(swa_lr) if epoch >= self.config.start_epoch => swa_lr,
+            _ => return lr,
+        };
+
+        if self.config.anneal_epochs == 0 {
+            return swa_lr;
+        }
+
+        let elapsed = (epoch - self.config.start_epoch) as f64 + progress;
+        let t = f64::min(elapsed / self.config.anneal_epochs as f64, 1.0);
+        let alpha = (1.0 - f64::cos(std::f64::consts::PI * t)) / 2.0;
+
+        (1.0 - alpha) * lr + alpha * swa_lr
+    }
+
+    /// Replace the parameters of the model with their average, the model being returned
+    /// unchanged when nothing was averaged.
+    ///
+    /// # Notes
+    ///
+    /// The running statistics of the model, e.g. of the batch normalization layers, are averaged
+    /// too. They should be updated with a forward pass over the training set afterward.
+    pub fn apply<B: AutodiffBackend, M: AutodiffModule<B>>(&self, model: M) -> M {
+        let state = self.state.lock().unwrap();
+        let mut mapper = ParamsLoader::<B::InnerBackend>::new(&state.averages);
+
+        model.map(&mut mapper)
+    }
+}
+
+#[derive(new)]
+struct ParamsAverager<'a, B: Backend> {
+    averages: &'a mut TensorContainer<ParamId>,
+    num_averaged: usize,
+    #[new(default)]
+    phantom: PhantomData<B>,
+}
+
+#[derive(new)]
+struct ParamsLoader<'a, B: Backend> {
+    averages: &'a TensorContainer<ParamId>,
+    #[new(default)]
+    phantom: PhantomData<B>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for ParamsAverager<'a, B::InnerBackend> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let tensor = tensor.clone().inner();
+        let average = match self.averages.remove::<B::InnerBackend, D>(id) {
+            Some(average) => {
+                let delta = (tensor - average.clone()).div_scalar(self.num_averaged as f64 + 1.0);
+                average + delta
+            }
+            None => tensor,
+        };
+
+        self.averages.register(id.clone(), average);
+    }
+}
+
+impl<'a, B: AutodiffBackend> ModuleMapper<B> for ParamsLoader<'a, B::InnerBackend> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let Some(average) = self.averages.get::<B::InnerBackend, D>(id) else {
+            return tensor;
+        };
+        let is_require_grad = tensor.is_require_grad();
+        let average = Tensor::from_inner(average.to_device(&tensor.device()));
+
+        match is_require_grad {
+            true => average.require_grad(),
+            false => average,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::tensor::Data;
+
+    #[test]
+    fn apply_loads_the_mean_of_the_parameters() {
+        let device = Default::default();
+        let swa = SwaConfig::new(1, 1, 0).init();
+        let layer = layer(&device);
+
+        for value in [1.0, 2.0, 6.0] {
+            swa.update(&with_weight(layer.clone(), value));
+        }
+        let layer = swa.apply(layer);
+
+        assert_eq!(swa.num_averaged(), 3);
+        layer
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0; 4]; 3]), 5);
+        assert!(layer.weight.val().is_require_grad());
+    }
+
+    #[test]
+    fn step_averages_every_update_freq_steps_from_start_epoch() {
+        let device = Default::default();
+        let swa = SwaConfig::new(2, 3, 0).init();
+        let layer = layer(&device);
+
+        for _ in 0..4 {
+            swa.step(&layer, 1);
+        }
+        assert_eq!(swa.num_averaged(), 0);
+
+        for _ in 0..7 {
+            swa.step(&layer, 2);
+        }
+        assert_eq!(swa.num_averaged(), 2);
+    }
+
+    #[test]
+    fn apply_keeps_the_model_when_nothing_is_averaged() {
+        let device = Default::default();
+        let swa = SwaConfig::new(1, 1, 0).init();
+        let layer = layer(&device);
+        let weight = layer.weight.val().into_data();
+
+        let layer = swa.apply(layer);
+
+        assert_eq!(layer.weight.val().into_data(), weight);
+    }
+
+    #[test]
+    fn learning_rate_is_annealed_to_swa_lr() {
+        let swa = SwaConfig::new(3, 1, 2).with_swa_lr(Some(0.1)).init();
+
+        assert_eq!(swa.learning_rate(1.0, 2, 0.5), 1.0);
+        assert_eq!(swa.learning_rate(1.0, 3, 0.0), 1.0);
+        assert!((swa.learning_rate(1.0, 4, 0.0) - 0.55).abs() < 1e-9);
+        assert!((swa.learning_rate(1.0, 5, 0.0) - 0.1).abs() < 1e-9);
+        assert!((swa.learning_rate(1.0, 8, 0.5) - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn learning_rate_of_the_scheduler_is_kept_without_swa_lr() {
+        let swa = SwaConfig::new(1, 1, 2).init();
+
+        assert_eq!(swa.learning_rate(0.3, 4, 0.5), 0.3);
+    }
+
+    fn layer(device: &<TestAutodiffBackend as Backend>::Device) -> Linear<TestAutodiffBackend> {
+        LinearConfig::new(3, 4).init(device)
+    }
+
+    fn with_weight(layer: Linear<TestAutodiffBackend>, value: f64) -> Linear<TestAutodiffBackend> {
+        let mut layer = layer;
+        layer.weight = layer
+            .weight
+            .map(|weight| weight.zeros_like().add_scalar(value));
+        layer
+    }
+}
@@ -1,6 +1,6 @@
 use crate::components::LearnerComponents;
 use crate::metric::processor::EventProcessor;
-use crate::{Learner, TrainEpoch, ValidEpoch};
+use crate::{DistributedDataParallel, Learner, ShardedDataLoader, TrainEpoch, ValidEpoch};
 use burn_core::data::dataloader::DataLoader;
 use burn_core::module::{AutodiffModule, Module};
 use burn_core::optim::{GradientsParams, Optimizer};
@@ -144,12 +144,30 @@ impl<LC: LearnerComponents> Learner<LC> {
             None => 1,
         };
 
+        let dataloader_train: Arc<dyn DataLoader<InputTrain>> = match &self.communicator {
+            Some(communicator) => {
+                let ddp = DistributedDataParallel::<LC::Backend>::new(communicator.clone());
+                self.model = ddp.broadcast_parameters(self.model);
+
+                Arc::new(ShardedDataLoader::new(
+                    dataloader_train,
+                    ddp.rank(),
+                    ddp.world_size(),
+                ))
+            }
+            None => dataloader_train,
+        };
+
         for epoch in starting_epoch..self.num_epochs + 1 {
             let epoch_train = TrainEpoch::new(
                 dataloader_train.clone(),
                 epoch,
                 self.num_epochs,
                 self.grad_accumulation,
+                self.grad_scaler.clone(),
+                self.grad_clipping.as_ref().map(|config| config.init()),
+                self.swa.clone(),
+                self.communicator.clone(),
             );
 
             if self.devices.len() > 1 {
@@ -194,11 +212,28 @@ impl<LC: LearnerComponents> Learner<LC> {
 
             if let Some(early_stopping) = &mut self.early_stopping {
                 if early_stopping.should_stop(epoch, &self.event_store) {
+                    if let (Some(best_epoch), Some(checkpointer)) =
+                        (early_stopping.best_epoch(), &self.checkpointer)
+                    {
+                        let device = self.devices.first().cloned().unwrap_or_default();
+                        self.model = checkpointer.load_model(self.model, &device, best_epoch);
+                    }
                     break;
                 }
             }
         }
 
+        if let Some(swa) = &self.swa {
+            if swa.num_averaged() > 0 {
+                log::info!("Updating the running statistics of the averaged model");
+                self.model = swa.apply(self.model);
+
+                for item in dataloader_train.iter() {
+                    let _ = self.model.step(item);
+                }
+            }
+        }
+
         self.model
     }
 }
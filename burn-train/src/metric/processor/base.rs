@@ -5,6 +5,9 @@ use burn_core::LearningRate;
 pub enum Event<T> {
     /// Signal that an item have been processed.
     ProcessedItem(LearnerItem<T>),
+    /// Signal that the items of a gradient accumulation window have been processed, their
+    /// numeric metrics being averaged into a single update.
+    ProcessedItems(Vec<LearnerItem<T>>),
     /// Signal the end of an epoch.
     EndEpoch(usize),
 }
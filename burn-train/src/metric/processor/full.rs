@@ -1,6 +1,6 @@
 use super::{Event, EventProcessor, Metrics};
-use crate::metric::store::EventStoreClient;
-use crate::renderer::{MetricState, MetricsRenderer};
+use crate::metric::store::{EventStoreClient, MetricsUpdate};
+use crate::renderer::{MetricState, MetricsRenderer, TrainingProgress};
 use std::sync::Arc;
 
 /// An [event processor](EventProcessor) that handles:
@@ -24,6 +24,46 @@ impl<T, V> FullEventProcessor<T, V> {
             store,
         }
     }
+
+    fn log_train(&mut self, update: MetricsUpdate, progress: TrainingProgress) {
+        self.store
+            .add_event_train(crate::metric::store::Event::MetricsUpdate(update.clone()));
+
+        update
+            .entries
+            .into_iter()
+            .for_each(|entry| self.renderer.update_train(MetricState::Generic(entry)));
+
+        update
+            .entries_numeric
+            .into_iter()
+            .for_each(|(entry, value)| {
+                self.renderer
+                    .update_train(MetricState::Numeric(entry, value))
+            });
+
+        self.renderer.render_train(progress);
+    }
+
+    fn log_valid(&mut self, update: MetricsUpdate, progress: TrainingProgress) {
+        self.store
+            .add_event_valid(crate::metric::store::Event::MetricsUpdate(update.clone()));
+
+        update
+            .entries
+            .into_iter()
+            .for_each(|entry| self.renderer.update_valid(MetricState::Generic(entry)));
+
+        update
+            .entries_numeric
+            .into_iter()
+            .for_each(|(entry, value)| {
+                self.renderer
+                    .update_valid(MetricState::Numeric(entry, value))
+            });
+
+        self.renderer.render_valid(progress);
+    }
 }
 
 impl<T, V> EventProcessor for FullEventProcessor<T, V> {
@@ -38,23 +78,16 @@ impl<T, V> EventProcessor for FullEventProcessor<T, V> {
 
                 let update = self.metrics.update_train(&item, &metadata);
 
-                self.store
-                    .add_event_train(crate::metric::store::Event::MetricsUpdate(update.clone()));
-
-                update
-                    .entries
-                    .into_iter()
-                    .for_each(|entry| self.renderer.update_train(MetricState::Generic(entry)));
-
-                update
-                    .entries_numeric
-                    .into_iter()
-                    .for_each(|(entry, value)| {
-                        self.renderer
-                            .update_train(MetricState::Numeric(entry, value))
-                    });
-
-                self.renderer.render_train(progress);
+                self.log_train(update, progress);
+            }
+            Event::ProcessedItems(items) => {
+                if let Some(item) = items.last() {
+                    let progress = item.into();
+
+                    let update = self.metrics.update_train_window(&items);
+
+                    self.log_train(update, progress);
+                }
             }
             Event::EndEpoch(epoch) => {
                 self.metrics.end_epoch_train();
@@ -72,23 +105,16 @@ impl<T, V> EventProcessor for FullEventProcessor<T, V> {
 
                 let update = self.metrics.update_valid(&item, &metadata);
 
-                self.store
-                    .add_event_valid(crate::metric::store::Event::MetricsUpdate(update.clone()));
-
-                update
-                    .entries
-                    .into_iter()
-                    .for_each(|entry| self.renderer.update_valid(MetricState::Generic(entry)));
-
-                update
-                    .entries_numeric
-                    .into_iter()
-                    .for_each(|(entry, value)| {
-                        self.renderer
-                            .update_valid(MetricState::Numeric(entry, value))
-                    });
-
-                self.renderer.render_valid(progress);
+                self.log_valid(update, progress);
+            }
+            Event::ProcessedItems(items) => {
+                if let Some(item) = items.last() {
+                    let progress = item.into();
+
+                    let update = self.metrics.update_valid_window(&items);
+
+                    self.log_valid(update, progress);
+                }
             }
             Event::EndEpoch(epoch) => {
                 self.metrics.end_epoch_valid();
@@ -107,6 +107,16 @@ impl<T, V> Metrics<T, V> {
         MetricsUpdate::new(entries, entries_numeric)
     }
 
+    /// Update the training information from the items of a gradient accumulation window.
+    pub(crate) fn update_train_window(&mut self, items: &[LearnerItem<T>]) -> MetricsUpdate {
+        update_window(&mut self.train, &mut self.train_numeric, items)
+    }
+
+    /// Update the validation information from the items of a gradient accumulation window.
+    pub(crate) fn update_valid_window(&mut self, items: &[LearnerItem<V>]) -> MetricsUpdate {
+        update_window(&mut self.valid, &mut self.valid_numeric, items)
+    }
+
     /// Signal the end of a training epoch.
     pub(crate) fn end_epoch_train(&mut self) {
         for metric in self.train.iter_mut() {
@@ -151,6 +161,44 @@ impl<T> From<&LearnerItem<T>> for MetricMetadata {
     }
 }
 
+/// Update the metrics with all the items of the window, averaging the numeric values so that a
+/// single update is logged for the window. The other metrics keep the entry of the last item.
+fn update_window<T>(
+    metrics: &mut [Box<dyn MetricUpdater<T>>],
+    metrics_numeric: &mut [Box<dyn NumericMetricUpdater<T>>],
+    items: &[LearnerItem<T>],
+) -> MetricsUpdate {
+    let mut entries = Vec::with_capacity(metrics.len());
+    let mut entries_numeric = Vec::with_capacity(metrics_numeric.len());
+
+    for metric in metrics.iter_mut() {
+        let entry = items
+            .iter()
+            .map(|item| metric.update(item, &item.into()))
+            .last();
+        entries.extend(entry);
+    }
+
+    for metric in metrics_numeric.iter_mut() {
+        let mut sum = 0.0;
+        let mut entry = None;
+
+        for item in items {
+            let (entry_item, value) = metric.update(item, &item.into());
+            sum += value;
+            entry = Some(entry_item);
+        }
+
+        if let Some(mut entry) = entry {
+            let value = sum / items.len() as f64;
+            entry.serialize = value.to_string();
+            entries_numeric.push((entry, value));
+        }
+    }
+
+    MetricsUpdate::new(entries, entries_numeric)
+}
+
 trait NumericMetricUpdater<T>: Send + Sync {
     fn update(&mut self, item: &LearnerItem<T>, metadata: &MetricMetadata) -> (MetricEntry, f64);
     fn clear(&mut self);
@@ -198,3 +246,34 @@ where
         self.metric.clear()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::InMemoryMetricLogger;
+    use crate::metric::processor::test_utils::{end_epoch, process_train_window};
+    use crate::metric::processor::MinimalEventProcessor;
+    use crate::metric::store::{Aggregate, EventStoreClient, LogEventStore, Split};
+    use crate::metric::LossMetric;
+    use crate::TestBackend;
+    use std::sync::Arc;
+
+    #[test]
+    fn numeric_metrics_should_be_averaged_over_the_window() {
+        let mut store = LogEventStore::default();
+        let mut metrics = Metrics::<f64, f64>::default();
+        store.register_logger_train(InMemoryMetricLogger::default());
+        metrics.register_train_metric_numeric(LossMetric::<TestBackend>::new());
+        let store = Arc::new(EventStoreClient::new(store));
+        let mut processor = MinimalEventProcessor::new(metrics, store.clone());
+
+        // A single value is logged per window, so the mean is (2.0 + 8.0) / 2 and not
+        // (1.0 + 3.0 + 8.0) / 3.
+        process_train_window(&mut processor, &[1.0, 3.0], 1);
+        process_train_window(&mut processor, &[8.0], 1);
+        end_epoch(&mut processor, 1);
+
+        let loss = store.find_metric("Loss", 1, Aggregate::Mean, Split::Train);
+        assert_eq!(loss, Some(5.0));
+    }
+}
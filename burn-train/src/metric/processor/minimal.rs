@@ -24,6 +24,12 @@ impl<T, V> EventProcessor for MinimalEventProcessor<T, V> {
                 self.store
                     .add_event_train(crate::metric::store::Event::MetricsUpdate(update));
             }
+            Event::ProcessedItems(items) => {
+                let update = self.metrics.update_train_window(&items);
+
+                self.store
+                    .add_event_train(crate::metric::store::Event::MetricsUpdate(update));
+            }
             Event::EndEpoch(epoch) => {
                 self.metrics.end_epoch_train();
                 self.store
@@ -42,6 +48,12 @@ impl<T, V> EventProcessor for MinimalEventProcessor<T, V> {
                 self.store
                     .add_event_valid(crate::metric::store::Event::MetricsUpdate(update));
             }
+            Event::ProcessedItems(items) => {
+                let update = self.metrics.update_valid_window(&items);
+
+                self.store
+                    .add_event_valid(crate::metric::store::Event::MetricsUpdate(update));
+            }
             Event::EndEpoch(epoch) => {
                 self.metrics.end_epoch_valid();
                 self.store
@@ -413,7 +413,7 @@ pub fn execute_static<R: Runtime, K, E: JitElement>(
     // Finally we finish with the named bindings.
     let mut scalars = None;
     if let Some(values) = &scalar_elems {
-        scalars = Some(client.create(bytemuck::cast_slice(values)));
+        scalars = Some(client.create(&E::as_bytes(values)));
     }
 
     if let Some(scalars) = scalars.as_ref() {
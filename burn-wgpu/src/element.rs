@@ -1,15 +1,19 @@
 use crate::codegen::dialect::{gpu, wgsl};
-use burn_tensor::Element;
+use alloc::borrow::Cow;
+use burn_tensor::{bf16, f16, Element};
 
 /// The base element trait for the wgou backend.
 pub trait JitElement:
-    burn_tensor::Element + core::fmt::Debug + Send + Sync + 'static + Clone + bytemuck::Pod
+    burn_tensor::Element + core::fmt::Debug + Send + Sync + 'static + Clone
 where
     Self: Sized,
 {
+    /// The name of the type used to store the element on the device.
     fn type_name() -> &'static str;
-    fn as_bytes(slice: &[Self]) -> &[u8];
-    fn from_bytes(bytes: &[u8]) -> &[Self];
+    /// Encode the elements with their device representation.
+    fn as_bytes(slice: &[Self]) -> Cow<'_, [u8]>;
+    /// Decode the elements from their device representation.
+    fn from_bytes(bytes: &[u8]) -> Cow<'_, [Self]>;
     fn gpu_elem() -> gpu::Elem;
     fn wgsl_elem() -> wgsl::Elem;
 }
@@ -24,11 +28,11 @@ impl JitElement for u32 {
     fn type_name() -> &'static str {
         "u32"
     }
-    fn as_bytes(slice: &[Self]) -> &[u8] {
-        bytemuck::cast_slice(slice)
+    fn as_bytes(slice: &[Self]) -> Cow<'_, [u8]> {
+        Cow::Borrowed(bytemuck::cast_slice(slice))
     }
-    fn from_bytes(bytes: &[u8]) -> &[Self] {
-        bytemuck::cast_slice(bytes)
+    fn from_bytes(bytes: &[u8]) -> Cow<'_, [Self]> {
+        Cow::Borrowed(bytemuck::cast_slice(bytes))
     }
     fn gpu_elem() -> gpu::Elem {
         gpu::Elem::UInt
@@ -42,11 +46,11 @@ impl JitElement for i32 {
     fn type_name() -> &'static str {
         "i32"
     }
-    fn as_bytes(slice: &[Self]) -> &[u8] {
-        bytemuck::cast_slice(slice)
+    fn as_bytes(slice: &[Self]) -> Cow<'_, [u8]> {
+        Cow::Borrowed(bytemuck::cast_slice(slice))
     }
-    fn from_bytes(bytes: &[u8]) -> &[Self] {
-        bytemuck::cast_slice(bytes)
+    fn from_bytes(bytes: &[u8]) -> Cow<'_, [Self]> {
+        Cow::Borrowed(bytemuck::cast_slice(bytes))
     }
     fn gpu_elem() -> gpu::Elem {
         gpu::Elem::Int
@@ -60,11 +64,11 @@ impl JitElement for f32 {
     fn type_name() -> &'static str {
         "f32"
     }
-    fn as_bytes(slice: &[Self]) -> &[u8] {
-        bytemuck::cast_slice(slice)
+    fn as_bytes(slice: &[Self]) -> Cow<'_, [u8]> {
+        Cow::Borrowed(bytemuck::cast_slice(slice))
     }
-    fn from_bytes(bytes: &[u8]) -> &[Self] {
-        bytemuck::cast_slice(bytes)
+    fn from_bytes(bytes: &[u8]) -> Cow<'_, [Self]> {
+        Cow::Borrowed(bytemuck::cast_slice(bytes))
     }
 
     fn gpu_elem() -> gpu::Elem {
@@ -75,5 +79,62 @@ impl JitElement for f32 {
     }
 }
 
+/// Half precision elements are stored and computed as `f32` on the device, since the WGSL
+/// frontend doesn't support `f16` yet. They are only converted when the data is transferred,
+/// so they don't reduce the memory usage of the device.
+macro_rules! half_jit_element {
+    ($ty:ty) => {
+        impl JitElement for $ty {
+            fn type_name() -> &'static str {
+                "f32"
+            }
+            fn as_bytes(slice: &[Self]) -> Cow<'_, [u8]> {
+                let values: Vec<f32> = slice.iter().map(|value| value.to_f32()).collect();
+                Cow::Owned(bytemuck::cast_slice(&values).to_vec())
+            }
+            fn from_bytes(bytes: &[u8]) -> Cow<'_, [Self]> {
+                let values: &[f32] = bytemuck::cast_slice(bytes);
+                Cow::Owned(values.iter().map(|value| <$ty>::from_f32(*value)).collect())
+            }
+            fn gpu_elem() -> gpu::Elem {
+                gpu::Elem::Float
+            }
+            fn wgsl_elem() -> wgsl::Elem {
+                wgsl::Elem::F32
+            }
+        }
+
+        impl FloatElement for $ty {}
+    };
+}
+
+half_jit_element!(f16);
+half_jit_element!(bf16);
+
 impl FloatElement for f32 {}
 impl IntElement for i32 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn half_elements_should_be_stored_as_f32() {
+        let values = [f16::from_f32(1.5), f16::from_f32(-2.0)];
+
+        let bytes = f16::as_bytes(&values);
+
+        assert_eq!(bytes.len(), values.len() * wgsl::Elem::F32.size());
+        assert_eq!(bytemuck::cast_slice::<u8, f32>(&bytes), &[1.5, -2.0]);
+        assert_eq!(f16::from_bytes(&bytes).as_ref(), &values);
+    }
+
+    #[test]
+    fn bf16_elements_should_round_trip() {
+        let values = [bf16::from_f32(0.25), bf16::from_f32(3.0)];
+
+        let bytes = bf16::as_bytes(&values);
+
+        assert_eq!(bf16::from_bytes(&bytes).as_ref(), &values);
+    }
+}
@@ -0,0 +1,152 @@
+use std::marker::PhantomData;
+
+use crate::{
+    compute::{DynamicKernel, WorkGroup},
+    element::JitElement,
+    kernel::{self, DynamicKernelSource, SourceTemplate, StaticKernelSource},
+    kernel_wgsl,
+    ops::numeric::{empty_device, zeros_device},
+    tensor::JitTensor,
+    Runtime,
+};
+use burn_tensor::Shape;
+
+use super::WORKGROUP_DEFAULT;
+
+kernel_wgsl!(FlashAttentionRaw, "../template/flash_attention.wgsl");
+
+/// The number of elements of a tile of keys or values kept in shared memory, so that both tiles
+/// fit in the 16KB guaranteed by WebGPU.
+const TILE_SIZE_MAX: usize = 2048;
+
+#[derive(new, Debug)]
+struct FlashAttention<E: JitElement> {
+    block_q: usize,
+    block_k: usize,
+    head_dim: usize,
+    head_dim_value: usize,
+    _elem: PhantomData<E>,
+}
+
+impl<E: JitElement> DynamicKernelSource for FlashAttention<E> {
+    fn source(&self) -> SourceTemplate {
+        FlashAttentionRaw::source()
+            .register("workgroup_size_x", self.block_q.to_string())
+            .register("block_k", self.block_k.to_string())
+            .register("head_dim", self.head_dim.to_string())
+            .register("head_dim_value", self.head_dim_value.to_string())
+            .register("key_tile_size", (self.block_k * self.head_dim).to_string())
+            .register(
+                "value_tile_size",
+                (self.block_k * self.head_dim_value).to_string(),
+            )
+            .register("elem", E::type_name())
+    }
+
+    fn id(&self) -> String {
+        std::format!("{:?}", self)
+    }
+}
+
+/// Execute the flash attention forward kernel.
+///
+/// Each workgroup computes a block of queries of one head, loading the keys and values in tiles
+/// in shared memory and accumulating the output with an online softmax, so the attention weights
+/// are never written to global memory.
+pub fn flash_attention_forward<R: Runtime, E: JitElement>(
+    query: JitTensor<R, E, 4>,
+    key: JitTensor<R, E, 4>,
+    value: JitTensor<R, E, 4>,
+    causal: bool,
+) -> JitTensor<R, E, 4> {
+    let query = kernel::into_contiguous(query);
+    let key = kernel::into_contiguous(key);
+    let value = kernel::into_contiguous(value);
+    let [batch_size, num_heads, seq_length_1, head_dim] = query.shape.dims;
+    let [_, _, seq_length_2, head_dim_value] = value.shape.dims;
+    let shape_out = Shape::new([batch_size, num_heads, seq_length_1, head_dim_value]);
+
+    if seq_length_2 == 0 {
+        return zeros_device(query.client.clone(), query.device.clone(), shape_out);
+    }
+
+    let output = empty_device(query.client.clone(), query.device.clone(), shape_out);
+
+    if output.shape.num_elements() == 0 {
+        return output;
+    }
+
+    let block_q = WORKGROUP_DEFAULT;
+    let block_k = usize::clamp(
+        TILE_SIZE_MAX / usize::max(head_dim, head_dim_value),
+        1,
+        WORKGROUP_DEFAULT,
+    );
+
+    let info = [seq_length_1 as u32, seq_length_2 as u32, causal as u32];
+    let info_handle = query.client.create(bytemuck::cast_slice(&info));
+
+    let workgroup = WorkGroup::new(
+        seq_length_1.div_ceil(block_q) as u32,
+        (batch_size * num_heads) as u32,
+        1,
+    );
+    let kernel = DynamicKernel::new(
+        FlashAttention::<E>::new(block_q, block_k, head_dim, head_dim_value),
+        workgroup,
+    );
+
+    query.client.execute(
+        Box::new(kernel),
+        &[
+            &query.handle,
+            &key.handle,
+            &value.handle,
+            &output.handle,
+            &info_handle,
+        ],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{module, Distribution, Tensor};
+
+    #[test]
+    fn flash_attention_should_match_reference() {
+        test_with_params([2, 3, 45, 16], [2, 3, 70, 16], [2, 3, 70, 8]);
+    }
+
+    #[test]
+    fn flash_attention_should_match_reference_with_large_head_dim() {
+        test_with_params([1, 2, 33, 128], [1, 2, 33, 128], [1, 2, 33, 128]);
+    }
+
+    fn test_with_params(shape_query: [usize; 4], shape_key: [usize; 4], shape_value: [usize; 4]) {
+        let device = Default::default();
+        let query = Tensor::<TestBackend, 4>::random(shape_query, Distribution::Default, &device);
+        let key = Tensor::<TestBackend, 4>::random(shape_key, Distribution::Default, &device);
+        let value = Tensor::<TestBackend, 4>::random(shape_value, Distribution::Default, &device);
+
+        let to_reference = |tensor: Tensor<TestBackend, 4>| {
+            Tensor::<ReferenceBackend, 4>::from_data(tensor.into_data(), &Default::default())
+        };
+
+        for causal in [false, true] {
+            let expected = module::flash_attention(
+                to_reference(query.clone()),
+                to_reference(key.clone()),
+                to_reference(value.clone()),
+                causal,
+            );
+            let actual = module::flash_attention(query.clone(), key.clone(), value.clone(), causal);
+
+            expected
+                .into_data()
+                .assert_approx_eq(&actual.into_data(), 3);
+        }
+    }
+}
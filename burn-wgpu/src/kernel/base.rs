@@ -59,7 +59,7 @@ pub fn into_contiguous<R: Runtime, E: JitElement, const D: usize>(
     }
 
     let num_elems = tensor.shape.num_elements();
-    let handle = tensor.client.empty(num_elems * core::mem::size_of::<E>());
+    let handle = tensor.client.empty(num_elems * E::wgsl_elem().size());
     let output = JitTensor::new(
         tensor.client.clone(),
         tensor.device.clone(),
@@ -90,7 +90,7 @@ pub fn into_contiguous_dyn<R: Runtime, E: JitElement>(
     output_strides: &[usize],
     num_elems: usize,
 ) -> Handle<R::Server> {
-    let handle = client.empty(num_elems * core::mem::size_of::<E>());
+    let handle = client.empty(num_elems * E::wgsl_elem().size());
     let info = kernel::build_info_dyn::<E>(
         &[input_shape, output_shape],
         &[input_strides, output_strides],
@@ -205,7 +205,7 @@ where
 
         let shape_out = Shape::new(shape_out);
         let num_elems = shape_out.num_elements();
-        let buffer = lhs.client.empty(num_elems * core::mem::size_of::<E>());
+        let buffer = lhs.client.empty(num_elems * E::wgsl_elem().size());
         let out = JitTensor::new(lhs.client.clone(), lhs.device, shape_out, buffer);
 
         execute_static::<R, Kernel, E>(
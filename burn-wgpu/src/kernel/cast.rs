@@ -44,7 +44,7 @@ pub fn cast<R: Runtime, InputElem: JitElement, OutputElem: JitElement, const D:
 
     let handle = tensor
         .client
-        .empty(num_elems * core::mem::size_of::<OutputElem>());
+        .empty(num_elems * OutputElem::wgsl_elem().size());
     let output = JitTensor::new(
         tensor.client.clone(),
         tensor.device,
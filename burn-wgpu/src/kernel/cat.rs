@@ -22,7 +22,7 @@ pub fn cat<R: Runtime, E: JitElement, const D: usize>(
 
     let buffer = first_input
         .client
-        .empty(shape_output.num_elements() * std::mem::size_of::<E>());
+        .empty(shape_output.num_elements() * E::wgsl_elem().size());
 
     let output = JitTensor::new(
         client.clone(),
@@ -216,7 +216,7 @@ where
     KernelInplaceRhs: StaticKernelSource,
     E: JitElement,
 {
-    let can_be_used_as_bool = mem::size_of::<E>() == mem::size_of::<u32>();
+    let can_be_used_as_bool = E::wgsl_elem().size() == mem::size_of::<u32>();
 
     let output = binary::<Kernel, KernelInplaceLhs, KernelInplaceRhs, R, E, D>(
         lhs,
@@ -237,7 +237,7 @@ where
     KernelInplace: StaticKernelSource,
     E: JitElement,
 {
-    let can_be_used_as_bool = mem::size_of::<E>() == mem::size_of::<u32>();
+    let can_be_used_as_bool = E::wgsl_elem().size() == mem::size_of::<u32>();
 
     let output =
         unary::<Kernel, KernelInplace, R, E, D>(tensor, Some(&[scalars]), can_be_used_as_bool);
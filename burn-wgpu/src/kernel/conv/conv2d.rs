@@ -59,7 +59,7 @@ pub(crate) fn conv2d<R: Runtime, E: JitElement + Element>(
 
     let bias_handle = bias
         .map(|bias| bias.handle)
-        .unwrap_or_else(|| input.client.create(E::as_bytes(&[0.elem()])));
+        .unwrap_or_else(|| input.client.create(&E::as_bytes(&[0.elem()])));
 
     let info_handle = input.client.create(bytemuck::cast_slice(&info));
 
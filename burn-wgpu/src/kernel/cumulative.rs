@@ -0,0 +1,135 @@
+use crate::{
+    compute::StaticKernel,
+    element::JitElement,
+    kernel::{self, elemwise_workgroup, KernelSettings, SourceTemplate, StaticKernelSource},
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    Runtime,
+};
+
+use super::WORKGROUP_DEFAULT;
+
+kernel_wgsl!(CumulativeRaw, "../template/cumulative.wgsl");
+
+pub(crate) struct CumSum;
+pub(crate) struct CumProd;
+
+impl StaticKernelSource for CumSum {
+    fn source() -> SourceTemplate {
+        CumulativeRaw::source()
+            .register("ops", "+")
+            .register("initial", "0")
+    }
+}
+
+impl StaticKernelSource for CumProd {
+    fn source() -> SourceTemplate {
+        CumulativeRaw::source()
+            .register("ops", "*")
+            .register("initial", "1")
+    }
+}
+
+/// Execute the cumulative sum kernel.
+pub fn cumsum<R: Runtime, E: JitElement, const D: usize>(
+    input: JitTensor<R, E, D>,
+    dim: usize,
+) -> JitTensor<R, E, D> {
+    cumulative::<CumSum, R, E, D>(input, dim)
+}
+
+/// Execute the cumulative product kernel.
+pub fn cumprod<R: Runtime, E: JitElement, const D: usize>(
+    input: JitTensor<R, E, D>,
+    dim: usize,
+) -> JitTensor<R, E, D> {
+    cumulative::<CumProd, R, E, D>(input, dim)
+}
+
+fn cumulative<K: StaticKernelSource, R: Runtime, E: JitElement, const D: usize>(
+    input: JitTensor<R, E, D>,
+    dim: usize,
+) -> JitTensor<R, E, D> {
+    let input = kernel::into_contiguous(input);
+    let output = empty_device(
+        input.client.clone(),
+        input.device.clone(),
+        input.shape.clone(),
+    );
+
+    let shape_dim = input.shape.dims[dim];
+    let num_lanes = match shape_dim {
+        0 => 0,
+        _ => input.shape.num_elements() / shape_dim,
+    };
+
+    if num_lanes == 0 {
+        return output;
+    }
+
+    let info = [
+        input.strides[dim] as u32,
+        shape_dim as u32,
+        num_lanes as u32,
+    ];
+    let info_handle = input.client.create(bytemuck::cast_slice(&info));
+
+    let kernel =
+        StaticKernel::<KernelSettings<K, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>>::new(
+            elemwise_workgroup(num_lanes, WORKGROUP_DEFAULT),
+        );
+
+    input.client.execute(
+        Box::new(kernel),
+        &[&input.handle, &output.handle, &info_handle],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn cumsum_should_work_with_multiple_workgroups() {
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [4, 32, 48],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &Default::default());
+
+        for dim in 0..3 {
+            let actual = tensor.clone().cumsum(dim);
+            let expected = tensor_ref.clone().cumsum(dim);
+
+            expected
+                .into_data()
+                .assert_approx_eq(&actual.into_data(), 2);
+        }
+    }
+
+    #[test]
+    fn cumprod_should_match_reference() {
+        let tensor = Tensor::<TestBackend, 2>::random(
+            [6, 256],
+            Distribution::Uniform(0.9, 1.1),
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data(), &Default::default());
+
+        for dim in 0..2 {
+            let actual = tensor.clone().cumprod(dim);
+            let expected = tensor_ref.clone().cumprod(dim);
+
+            expected
+                .into_data()
+                .assert_approx_eq(&actual.into_data(), 2);
+        }
+    }
+}
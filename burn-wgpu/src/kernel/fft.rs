@@ -0,0 +1,137 @@
+use crate::{
+    compute::StaticKernel,
+    element::JitElement,
+    kernel::{self, elemwise_workgroup, KernelSettings},
+    kernel_wgsl,
+    ops::numeric::{div_scalar, empty_device},
+    tensor::JitTensor,
+    Runtime,
+};
+use burn_tensor::ElementConversion;
+
+use super::WORKGROUP_DEFAULT;
+
+kernel_wgsl!(FftBitReverse, "../template/fft_bit_reverse.wgsl");
+kernel_wgsl!(FftButterfly, "../template/fft_butterfly.wgsl");
+
+/// Execute the radix-2 fast Fourier transform along the dimension, whose size must be a power
+/// of two.
+pub fn fft<R: Runtime, E: JitElement, const D: usize>(
+    real: JitTensor<R, E, D>,
+    imag: JitTensor<R, E, D>,
+    dim: usize,
+    inverse: bool,
+) -> (JitTensor<R, E, D>, JitTensor<R, E, D>) {
+    let real = kernel::into_contiguous(real);
+    let imag = kernel::into_contiguous(imag);
+    let shape_dim = real.shape.dims[dim];
+    let num_elems = real.shape.num_elements();
+
+    let output_real = empty_device(real.client.clone(), real.device.clone(), real.shape.clone());
+    let output_imag = empty_device(real.client.clone(), real.device.clone(), real.shape.clone());
+
+    if num_elems == 0 {
+        return (output_real, output_imag);
+    }
+
+    let stride_dim = real.strides[dim] as u32;
+
+    // The elements are first reordered by the bit-reversal of their position.
+    let info = [
+        stride_dim,
+        shape_dim as u32,
+        num_elems as u32,
+        shape_dim.trailing_zeros(),
+    ];
+    let info_handle = real.client.create(bytemuck::cast_slice(&info));
+    let kernel = StaticKernel::<
+        KernelSettings<FftBitReverse, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+    >::new(elemwise_workgroup(num_elems, WORKGROUP_DEFAULT));
+
+    real.client.execute(
+        Box::new(kernel),
+        &[
+            &real.handle,
+            &imag.handle,
+            &output_real.handle,
+            &output_imag.handle,
+            &info_handle,
+        ],
+    );
+
+    // Then each stage merges pairs of transforms, doubling their size.
+    let num_butterflies = num_elems / 2;
+    let mut half_size = 1;
+
+    while half_size < shape_dim {
+        let info = [
+            stride_dim,
+            shape_dim as u32,
+            num_butterflies as u32,
+            half_size as u32,
+            inverse as u32,
+        ];
+        let info_handle = real.client.create(bytemuck::cast_slice(&info));
+        let kernel = StaticKernel::<
+            KernelSettings<FftButterfly, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+        >::new(elemwise_workgroup(num_butterflies, WORKGROUP_DEFAULT));
+
+        real.client.execute(
+            Box::new(kernel),
+            &[&output_real.handle, &output_imag.handle, &info_handle],
+        );
+
+        half_size *= 2;
+    }
+
+    match inverse {
+        true => (
+            div_scalar(output_real, (shape_dim as f32).elem()),
+            div_scalar(output_imag, (shape_dim as f32).elem()),
+        ),
+        false => (output_real, output_imag),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn fft_should_match_reference() {
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [4, 16, 64],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &Default::default());
+
+        for dim in 0..3 {
+            let (actual_real, actual_imag) = tensor.clone().fft(None, dim);
+            let (expected_real, expected_imag) = tensor_ref.clone().fft(None, dim);
+
+            expected_real
+                .into_data()
+                .assert_approx_eq(&actual_real.into_data(), 2);
+            expected_imag
+                .into_data()
+                .assert_approx_eq(&actual_imag.into_data(), 2);
+        }
+    }
+
+    #[test]
+    fn ifft_should_invert_fft() {
+        let tensor =
+            Tensor::<TestBackend, 2>::random([8, 128], Distribution::Default, &Default::default());
+
+        let (real, imag) = tensor.clone().fft(None, 1);
+        let (real, imag) = real.ifft(imag, None, 1);
+
+        tensor.into_data().assert_approx_eq(&real.into_data(), 3);
+        imag.zeros_like()
+            .into_data()
+            .assert_approx_eq(&imag.into_data(), 3);
+    }
+}
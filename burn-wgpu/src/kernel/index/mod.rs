@@ -1,11 +1,13 @@
 mod gather;
 mod repeat;
+mod roll;
 mod scatter;
 mod select;
 mod slice;
 
 pub use gather::*;
 pub use repeat::*;
+pub use roll::*;
 pub use scatter::*;
 pub use select::*;
 pub use slice::*;
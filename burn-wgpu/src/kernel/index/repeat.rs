@@ -22,9 +22,7 @@ pub(crate) fn repeat<R: Runtime, E: JitElement, const D1: usize>(
     // Create output handle
     shape.dims[dim] = times;
     let num_elems_output = shape.num_elements();
-    let handle = input
-        .client
-        .empty(num_elems_output * core::mem::size_of::<E>());
+    let handle = input.client.empty(num_elems_output * E::wgsl_elem().size());
     let output = JitTensor::new(
         input.client.clone(),
         input.device.clone(),
@@ -0,0 +1,83 @@
+use crate::{
+    compute::StaticKernel,
+    element::JitElement,
+    kernel::{build_info, elemwise_workgroup, KernelSettings, WORKGROUP_DEFAULT},
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    Runtime,
+};
+
+kernel_wgsl!(RollRaw, "../../template/index/roll.wgsl");
+
+pub(crate) fn roll<R: Runtime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    shifts: &[i64],
+    dims: &[usize],
+) -> JitTensor<R, E, D> {
+    // The shifts of the same dimension are accumulated, so that every dimension is rolled at
+    // once.
+    let mut shifts_dims = [0; D];
+    for (shift, dim) in shifts.iter().zip(dims.iter()) {
+        shifts_dims[*dim] += shift;
+    }
+
+    let output = empty_device(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+    let mut info = build_info(&[&tensor, &output]);
+
+    for (shift, size) in shifts_dims.iter().zip(tensor.shape.dims.iter()) {
+        let shift = match *size {
+            0 => 0,
+            size => shift.rem_euclid(size as i64),
+        };
+        info.push(shift as u32);
+    }
+
+    let info_handle = output.client.create(bytemuck::cast_slice(&info));
+
+    let kernel = StaticKernel::<
+        KernelSettings<RollRaw, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+    >::new(elemwise_workgroup(
+        output.shape.num_elements(),
+        WORKGROUP_DEFAULT,
+    ));
+
+    tensor.client.execute(
+        Box::new(kernel),
+        &[&tensor.handle, &output.handle, &info_handle],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn roll_should_work_with_multiple_workgroups() {
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [6, 256, 3],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let shifts = [2, -45, 7, 1];
+        let dims = [0, 1, 2, 0];
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &Default::default());
+
+        let actual = roll(tensor.into_primitive(), &shifts, &dims);
+        let expected = tensor_ref.roll(&shifts, &dims);
+
+        expected.into_data().assert_approx_eq(
+            &Tensor::<TestBackend, 3>::from_primitive(actual).into_data(),
+            3,
+        );
+    }
+}
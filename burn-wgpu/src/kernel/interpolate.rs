@@ -0,0 +1,154 @@
+use burn_tensor::{ops::InterpolateMode, Shape};
+
+use crate::{
+    compute::{Kernel, StaticKernel},
+    element::JitElement,
+    kernel::{self, elemwise_workgroup, KernelSettings, StaticKernelSource, WORKGROUP_DEFAULT},
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    Runtime,
+};
+
+kernel_wgsl!(InterpolateNearest, "../template/interpolate/nearest.wgsl");
+kernel_wgsl!(
+    InterpolateBilinearRaw,
+    "../template/interpolate/bilinear.wgsl"
+);
+kernel_wgsl!(InterpolateBicubic, "../template/interpolate/bicubic.wgsl");
+
+struct InterpolateBilinear<const ALIGN_CORNERS: bool>;
+
+impl<const ALIGN_CORNERS: bool> StaticKernelSource for InterpolateBilinear<ALIGN_CORNERS> {
+    fn source() -> kernel::SourceTemplate {
+        InterpolateBilinearRaw::source().register("align_corners", format!("{ALIGN_CORNERS}"))
+    }
+}
+
+/// Execute the interpolate kernel, one invocation per output element.
+pub(crate) fn interpolate<R: Runtime, E: JitElement>(
+    input: JitTensor<R, E, 4>,
+    output_size: [usize; 2],
+    mode: InterpolateMode,
+) -> JitTensor<R, E, 4> {
+    let [batch_size, channels, _, _] = input.shape.dims;
+    let output_shape = Shape::new([batch_size, channels, output_size[0], output_size[1]]);
+    let output = empty_device(input.client.clone(), input.device.clone(), output_shape);
+
+    let num_elems = output.shape.num_elements();
+
+    if num_elems == 0 {
+        return output;
+    }
+
+    let workgroup = elemwise_workgroup(num_elems, WORKGROUP_DEFAULT);
+    let kernel: Box<dyn Kernel> = match mode {
+        InterpolateMode::Nearest => Box::new(StaticKernel::<
+            KernelSettings<InterpolateNearest, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+        >::new(workgroup)),
+        InterpolateMode::Bilinear {
+            align_corners: true,
+        } => Box::new(StaticKernel::<
+            KernelSettings<
+                InterpolateBilinear<true>,
+                E,
+                i32,
+                WORKGROUP_DEFAULT,
+                WORKGROUP_DEFAULT,
+                1,
+            >,
+        >::new(workgroup)),
+        InterpolateMode::Bilinear {
+            align_corners: false,
+        } => Box::new(StaticKernel::<
+            KernelSettings<
+                InterpolateBilinear<false>,
+                E,
+                i32,
+                WORKGROUP_DEFAULT,
+                WORKGROUP_DEFAULT,
+                1,
+            >,
+        >::new(workgroup)),
+        InterpolateMode::Bicubic => Box::new(StaticKernel::<
+            KernelSettings<InterpolateBicubic, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+        >::new(workgroup)),
+    };
+
+    let info: Vec<u32> = [
+        input.strides,
+        input.shape.dims,
+        output.strides,
+        output.shape.dims,
+    ]
+    .iter()
+    .flatten()
+    .map(|value| *value as u32)
+    .collect();
+    let info_handle = input.client.create(bytemuck::cast_slice(&info));
+
+    input
+        .client
+        .execute(kernel, &[&input.handle, &output.handle, &info_handle]);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{module::interpolate, ops::InterpolateMode, Distribution, Tensor};
+
+    #[test]
+    fn interpolate_should_match_reference_backend() {
+        let tensor = Tensor::<TestBackend, 4>::random(
+            [2, 3, 7, 5],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 4>::from_data(tensor.to_data(), &Default::default());
+        let modes = [
+            InterpolateMode::Nearest,
+            InterpolateMode::Bilinear {
+                align_corners: false,
+            },
+            InterpolateMode::Bilinear {
+                align_corners: true,
+            },
+            InterpolateMode::Bicubic,
+        ];
+
+        for mode in modes {
+            for output_size in [[3, 4], [12, 9]] {
+                let actual = interpolate(tensor.clone(), output_size, mode);
+                let expected = interpolate(tensor_ref.clone(), output_size, mode);
+
+                expected
+                    .into_data()
+                    .assert_approx_eq(&actual.into_data(), 3);
+            }
+        }
+    }
+
+    #[test]
+    fn interpolate_should_work_with_transposed_input() {
+        let tensor = Tensor::<TestBackend, 4>::random(
+            [1, 2, 6, 4],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 4>::from_data(tensor.to_data(), &Default::default());
+        let mode = InterpolateMode::Bilinear {
+            align_corners: false,
+        };
+
+        let actual = interpolate(tensor.swap_dims(2, 3), [5, 8], mode);
+        let expected = interpolate(tensor_ref.swap_dims(2, 3), [5, 8], mode);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+}
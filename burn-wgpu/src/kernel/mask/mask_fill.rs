@@ -23,7 +23,7 @@ pub fn mask_fill<R: Runtime, E: JitElement, const D: usize>(
         input.shape.clone(),
     );
 
-    let value_handle = output.client.create(E::as_bytes(&[value]));
+    let value_handle = output.client.create(&E::as_bytes(&[value]));
     let kernel = StaticKernel::<
         KernelSettings<MaskFill, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
     >::new(elemwise_workgroup(num_elems, WORKGROUP_DEFAULT));
@@ -51,7 +51,7 @@ pub fn mask_fill_inplace<R: Runtime, E: JitElement, const D: usize>(
     value: E,
 ) -> JitTensor<R, E, D> {
     let num_elems = input.shape.num_elements();
-    let value_handle = input.client.create(E::as_bytes(&[value]));
+    let value_handle = input.client.create(&E::as_bytes(&[value]));
     let kernel = StaticKernel::<
         KernelSettings<MaskFillInplace, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
     >::new(elemwise_workgroup(num_elems, WORKGROUP_DEFAULT));
@@ -1,11 +1,18 @@
+mod attention;
 mod base;
 mod binary;
 mod cast;
 mod cat;
 mod clamp;
 mod comparison;
+mod cumulative;
+mod fft;
 mod index;
+mod interpolate;
 mod mask;
+mod nonzero;
+mod rope;
+mod sort;
 mod source;
 mod unary;
 
@@ -26,8 +33,15 @@ pub mod prng;
 /// Reduction algorithms
 pub mod reduce;
 
+pub(crate) use attention::*;
 pub(crate) use cat::*;
 pub(crate) use clamp::*;
 pub(crate) use comparison::*;
+pub(crate) use cumulative::*;
+pub(crate) use fft::*;
 pub(crate) use index::*;
+pub(crate) use interpolate::*;
 pub(crate) use mask::*;
+pub(crate) use nonzero::*;
+pub(crate) use rope::*;
+pub(crate) use sort::*;
@@ -0,0 +1,124 @@
+use crate::{
+    compute::StaticKernel,
+    element::JitElement,
+    kernel::{self, elemwise_workgroup, KernelSettings},
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    Runtime,
+};
+use burn_tensor::Shape;
+
+use super::WORKGROUP_DEFAULT;
+
+kernel_wgsl!(NonzeroFlagsRaw, "../template/nonzero_flags.wgsl");
+kernel_wgsl!(NonzeroScatterRaw, "../template/nonzero_scatter.wgsl");
+
+/// The number of elements scanned together before their totals are combined.
+const BLOCK_SIZE: usize = 256;
+
+/// Execute the nonzero kernel, returning the indices of the non-zero elements.
+///
+/// This is a stream compaction: every element is flagged, the flags are prefix summed per block
+/// and then across the block totals, which gives the output position of each non-zero element
+/// before its coordinates are scattered. The number of non-zero elements is read back to allocate
+/// the output, so the operation is synchronous.
+pub fn nonzero<R: Runtime, E: JitElement, I: JitElement, const D: usize>(
+    input: JitTensor<R, E, D>,
+) -> JitTensor<R, I, 2> {
+    let input = kernel::into_contiguous(input);
+    let client = input.client.clone();
+    let device = input.device.clone();
+    let num_elems = input.shape.num_elements();
+
+    if num_elems == 0 {
+        return empty_device(client, device, Shape::new([0, D]));
+    }
+
+    let num_blocks = num_elems.div_ceil(BLOCK_SIZE);
+    let flags: JitTensor<R, I, 2> = empty_device(
+        client.clone(),
+        device.clone(),
+        Shape::new([num_blocks, BLOCK_SIZE]),
+    );
+
+    let info = [num_elems as u32, (num_blocks * BLOCK_SIZE) as u32];
+    let info_handle = client.create(bytemuck::cast_slice(&info));
+    let kernel = StaticKernel::<
+        KernelSettings<NonzeroFlagsRaw, E, I, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+    >::new(elemwise_workgroup(
+        num_blocks * BLOCK_SIZE,
+        WORKGROUP_DEFAULT,
+    ));
+    client.execute(
+        Box::new(kernel),
+        &[&input.handle, &flags.handle, &info_handle],
+    );
+
+    let scan = kernel::cumsum(flags, 1);
+    let totals = kernel::slice(scan.clone(), [0..num_blocks, BLOCK_SIZE - 1..BLOCK_SIZE]);
+    let offsets = kernel::cumsum(totals, 0);
+
+    let count = kernel::slice(offsets.clone(), [num_blocks - 1..num_blocks, 0..1]);
+    let count = client
+        .read(&count.handle)
+        .read_sync()
+        .expect("Can't compute the non-zero indices of a tensor with async data.");
+    let count = I::from_bytes(&count)[0].elem::<i64>() as usize;
+
+    let output = empty_device(client.clone(), device, Shape::new([count, D]));
+
+    if count == 0 {
+        return output;
+    }
+
+    let mut info = vec![num_elems as u32, BLOCK_SIZE as u32, D as u32];
+    info.extend(input.shape.dims.iter().map(|dim| *dim as u32));
+    let info_handle = client.create(bytemuck::cast_slice(&info));
+    let kernel = StaticKernel::<
+        KernelSettings<NonzeroScatterRaw, E, I, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+    >::new(elemwise_workgroup(num_elems, WORKGROUP_DEFAULT));
+    client.execute(
+        Box::new(kernel),
+        &[
+            &input.handle,
+            &scan.handle,
+            &offsets.handle,
+            &output.handle,
+            &info_handle,
+        ],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn nonzero_should_match_reference_with_multiple_blocks() {
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [3, 17, 29],
+            Distribution::Bernoulli(0.3),
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &Default::default());
+
+        let actual = tensor.nonzero();
+        let expected = tensor_ref.nonzero();
+
+        assert_eq!(actual.into_data().convert(), expected.into_data());
+    }
+
+    #[test]
+    fn nonzero_should_return_empty_indices_for_zeros() {
+        let tensor = Tensor::<TestBackend, 2>::zeros([20, 30], &Default::default());
+
+        let actual = tensor.nonzero();
+
+        assert_eq!(actual.dims(), [0, 2]);
+    }
+}
@@ -48,7 +48,7 @@ pub(crate) fn adaptive_avg_pool2d_backward<R: Runtime, E: JitElement>(
 ) -> JitTensor<R, E, 4> {
     let output_shape = x.shape.clone();
     let num_elems = output_shape.num_elements();
-    let output_buffer = x.client.empty(num_elems * core::mem::size_of::<E>());
+    let output_buffer = x.client.empty(num_elems * E::wgsl_elem().size());
     let output = JitTensor::new(
         x.client.clone(),
         x.device.clone(),
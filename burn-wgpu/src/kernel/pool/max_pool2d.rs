@@ -83,7 +83,7 @@ pub(crate) fn max_pool2d_with_indices_backward<R: Runtime, E: JitElement, I: Jit
     let indices = kernel::into_contiguous(indices);
 
     let num_elems = x.shape.num_elements();
-    let buffer = x.client.empty(num_elems * core::mem::size_of::<E>());
+    let buffer = x.client.empty(num_elems * E::wgsl_elem().size());
     let output = JitTensor::new(x.client.clone(), x.device.clone(), x.shape.clone(), buffer);
 
     let info_handle = build_pool2d_info(&x, &grad, kernel_size, stride, padding, dilation);
@@ -32,7 +32,7 @@ pub(crate) fn make_args_buffer<R: Runtime, E: JitElement>(
     client: ComputeClient<R::Server, R::Channel>,
     args: &[E],
 ) -> Handle<R::Server> {
-    client.create(E::as_bytes(args))
+    client.create(&E::as_bytes(args))
 }
 
 #[cfg(test)]
@@ -10,9 +10,7 @@ pub fn init_reduce_output<R: Runtime, E: JitElement, const D: usize>(
 
     // Create output handle
     let num_elems_output = shape_out.num_elements();
-    let handle = input
-        .client
-        .empty(num_elems_output * core::mem::size_of::<E>());
+    let handle = input.client.empty(num_elems_output * E::wgsl_elem().size());
     JitTensor::new(
         input.client.clone(),
         input.device.clone(),
@@ -66,9 +66,7 @@ pub fn sum<R: Runtime, E: JitElement, const D: usize>(
 
     loop {
         let num_invocations = workgroup.num_invocations();
-        let handle = input
-            .client
-            .empty(core::mem::size_of::<E>() * num_invocations);
+        let handle = input.client.empty(E::wgsl_elem().size() * num_invocations);
 
         let kernel = StaticKernel::<
             KernelSettings<RecursiveSumRaw, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
@@ -156,7 +154,7 @@ fn reduction_args_dim<
     let mut shape_out = input.shape.clone();
     shape_out.dims[dim] = 1;
     let num_elems = shape_out.num_elements();
-    let buffer = input.client.empty(num_elems * core::mem::size_of::<I>());
+    let buffer = input.client.empty(num_elems * I::wgsl_elem().size());
     let output = JitTensor::new(
         input.client.clone(),
         input.device.clone(),
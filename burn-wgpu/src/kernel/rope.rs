@@ -0,0 +1,89 @@
+use crate::{
+    compute::StaticKernel,
+    element::JitElement,
+    kernel::{self, elemwise_workgroup, KernelSettings},
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    Runtime,
+};
+
+use super::WORKGROUP_DEFAULT;
+
+kernel_wgsl!(RopeEmbed, "../template/rope.wgsl");
+
+/// Execute the rotary position embedding kernel.
+pub fn rope_embed<R: Runtime, E: JitElement>(
+    x: JitTensor<R, E, 4>,
+    cos: JitTensor<R, E, 2>,
+    sin: JitTensor<R, E, 2>,
+) -> JitTensor<R, E, 4> {
+    let x = kernel::into_contiguous(x);
+    let cos = kernel::into_contiguous(cos);
+    let sin = kernel::into_contiguous(sin);
+    let [_, _, seq_length, head_dim] = x.shape.dims;
+    let num_elems = x.shape.num_elements();
+
+    let output = empty_device(x.client.clone(), x.device.clone(), x.shape.clone());
+
+    if num_elems == 0 {
+        return output;
+    }
+
+    let info = [seq_length as u32, head_dim as u32, num_elems as u32];
+    let info_handle = x.client.create(bytemuck::cast_slice(&info));
+
+    let kernel = StaticKernel::<
+        KernelSettings<RopeEmbed, E, i32, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+    >::new(elemwise_workgroup(num_elems, WORKGROUP_DEFAULT));
+
+    x.client.execute(
+        Box::new(kernel),
+        &[
+            &x.handle,
+            &cos.handle,
+            &sin.handle,
+            &output.handle,
+            &info_handle,
+        ],
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{module, Distribution, Tensor};
+
+    #[test]
+    fn rope_embed_should_match_reference() {
+        let device = Default::default();
+        let query = Tensor::<TestBackend, 4>::random([2, 4, 9, 16], Distribution::Default, &device);
+        let key = Tensor::<TestBackend, 4>::random([2, 2, 9, 16], Distribution::Default, &device);
+        let angles = Tensor::<TestBackend, 2>::random([9, 8], Distribution::Default, &device);
+        let (cos, sin) = (angles.clone().cos(), angles.sin());
+
+        let to_reference = |tensor: Tensor<TestBackend, 4>| {
+            Tensor::<ReferenceBackend, 4>::from_data(tensor.into_data(), &Default::default())
+        };
+        let to_reference_2d = |tensor: Tensor<TestBackend, 2>| {
+            Tensor::<ReferenceBackend, 2>::from_data(tensor.into_data(), &Default::default())
+        };
+
+        let (expected_query, expected_key) = module::rope_embed(
+            to_reference(query.clone()),
+            to_reference(key.clone()),
+            to_reference_2d(cos.clone()),
+            to_reference_2d(sin.clone()),
+        );
+        let (actual_query, actual_key) = module::rope_embed(query, key, cos, sin);
+
+        expected_query
+            .into_data()
+            .assert_approx_eq(&actual_query.into_data(), 3);
+        expected_key
+            .into_data()
+            .assert_approx_eq(&actual_key.into_data(), 3);
+    }
+}
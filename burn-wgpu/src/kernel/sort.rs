@@ -0,0 +1,180 @@
+use crate::{
+    compute::StaticKernel,
+    element::JitElement,
+    kernel::{self, elemwise_workgroup, KernelSettings},
+    kernel_wgsl,
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    Runtime,
+};
+
+use super::WORKGROUP_DEFAULT;
+
+kernel_wgsl!(SortLanes, "../template/sort_lanes.wgsl");
+kernel_wgsl!(SortBitonic, "../template/sort_bitonic.wgsl");
+kernel_wgsl!(SortOutput, "../template/sort_output.wgsl");
+
+/// Execute the sort kernel, returning the sorted values and their indices along the dimension.
+///
+/// NaN values are placed last regardless of the order, and equal values keep their order.
+pub fn sort_with_indices<R: Runtime, E: JitElement, I: JitElement, const D: usize>(
+    input: JitTensor<R, E, D>,
+    dim: usize,
+    descending: bool,
+) -> (JitTensor<R, E, D>, JitTensor<R, I, D>) {
+    let k = input.shape.dims[dim];
+
+    sort_first_k(input, k, dim, descending)
+}
+
+/// Execute the top k kernel, returning the `k` largest values and their indices along the
+/// dimension, in descending order.
+pub fn topk<R: Runtime, E: JitElement, I: JitElement, const D: usize>(
+    input: JitTensor<R, E, D>,
+    k: usize,
+    dim: usize,
+) -> (JitTensor<R, E, D>, JitTensor<R, I, D>) {
+    sort_first_k(input, k, dim, true)
+}
+
+/// Each lane is copied to a buffer padded to a power of two, sorted there with a bitonic sort,
+/// and its first `k` elements are written to the output.
+fn sort_first_k<R: Runtime, E: JitElement, I: JitElement, const D: usize>(
+    input: JitTensor<R, E, D>,
+    k: usize,
+    dim: usize,
+    descending: bool,
+) -> (JitTensor<R, E, D>, JitTensor<R, I, D>) {
+    let input = kernel::into_contiguous(input);
+    let mut shape = input.shape.clone();
+    shape.dims[dim] = k;
+
+    let values = empty_device(input.client.clone(), input.device.clone(), shape.clone());
+    let indices = empty_device(input.client.clone(), input.device.clone(), shape);
+    let num_elems = input.shape.num_elements();
+
+    if num_elems == 0 || k == 0 {
+        return (values, indices);
+    }
+
+    let stride_dim = input.strides[dim] as u32;
+    let shape_dim = input.shape.dims[dim];
+    let padded_dim = shape_dim.next_power_of_two();
+    let num_lanes = num_elems / shape_dim;
+    let num_padded = num_lanes * padded_dim;
+
+    let keys = input.client.empty(num_padded * E::wgsl_elem().size());
+    let positions = input.client.empty(num_padded * core::mem::size_of::<u32>());
+
+    let info = [
+        stride_dim,
+        shape_dim as u32,
+        padded_dim as u32,
+        num_lanes as u32,
+    ];
+    let info_handle = input.client.create(bytemuck::cast_slice(&info));
+    let kernel = StaticKernel::<
+        KernelSettings<SortLanes, E, I, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+    >::new(elemwise_workgroup(num_padded, WORKGROUP_DEFAULT));
+
+    input.client.execute(
+        Box::new(kernel),
+        &[&input.handle, &keys, &positions, &info_handle],
+    );
+
+    // Each step compares the elements at a given distance in blocks of a given size, the blocks
+    // being merged into sorted sequences of twice their size.
+    let mut block_size = 2;
+
+    while block_size <= padded_dim {
+        let mut distance = block_size / 2;
+
+        while distance > 0 {
+            let info = [
+                shape_dim as u32,
+                padded_dim as u32,
+                descending as u32,
+                block_size as u32,
+                distance as u32,
+                num_lanes as u32,
+            ];
+            let info_handle = input.client.create(bytemuck::cast_slice(&info));
+            let kernel = StaticKernel::<
+                KernelSettings<SortBitonic, E, I, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+            >::new(elemwise_workgroup(num_padded / 2, WORKGROUP_DEFAULT));
+
+            input
+                .client
+                .execute(Box::new(kernel), &[&keys, &positions, &info_handle]);
+
+            distance /= 2;
+        }
+
+        block_size *= 2;
+    }
+
+    let info = [stride_dim, padded_dim as u32, k as u32, num_lanes as u32];
+    let info_handle = input.client.create(bytemuck::cast_slice(&info));
+    let kernel = StaticKernel::<
+        KernelSettings<SortOutput, E, I, WORKGROUP_DEFAULT, WORKGROUP_DEFAULT, 1>,
+    >::new(elemwise_workgroup(num_lanes * k, WORKGROUP_DEFAULT));
+
+    input.client.execute(
+        Box::new(kernel),
+        &[
+            &keys,
+            &positions,
+            &values.handle,
+            &indices.handle,
+            &info_handle,
+        ],
+    );
+
+    (values, indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{ReferenceBackend, TestBackend};
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn sort_should_match_reference_with_multiple_workgroups() {
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [4, 32, 48],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &Default::default());
+
+        for dim in 0..3 {
+            for descending in [false, true] {
+                let (values, indices) = tensor.clone().sort_with_indices(dim, descending);
+                let (values_ref, indices_ref) =
+                    tensor_ref.clone().sort_with_indices(dim, descending);
+
+                values_ref
+                    .into_data()
+                    .assert_approx_eq(&values.into_data(), 3);
+                assert_eq!(indices_ref.into_data(), indices.into_data().convert());
+            }
+        }
+    }
+
+    #[test]
+    fn topk_should_match_reference() {
+        let tensor =
+            Tensor::<TestBackend, 2>::random([6, 300], Distribution::Default, &Default::default());
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data(), &Default::default());
+
+        let (values, indices) = tensor.top_k(7, 1);
+        let (values_ref, indices_ref) = tensor_ref.top_k(7, 1);
+
+        values_ref
+            .into_data()
+            .assert_approx_eq(&values.into_data(), 3);
+        assert_eq!(indices_ref.into_data(), indices.into_data().convert());
+    }
+}
@@ -212,7 +212,7 @@ where
         tensor
     } else {
         let num_elems = tensor.shape.num_elements();
-        let buffer = tensor.client.empty(num_elems * core::mem::size_of::<E>());
+        let buffer = tensor.client.empty(num_elems * E::wgsl_elem().size());
         let output = JitTensor::new(
             tensor.client.clone(),
             tensor.device,
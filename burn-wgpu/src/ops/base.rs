@@ -6,7 +6,7 @@ pub fn from_data<R: Runtime, E: JitElement, const D: usize>(
     device: &R::Device,
 ) -> JitTensor<R, E, D> {
     let client = R::client(device);
-    let buffer = client.create(E::as_bytes(&data.value));
+    let buffer = client.create(&E::as_bytes(&data.value));
 
     JitTensor::new(client, device.clone(), data.shape, buffer)
 }
@@ -47,12 +47,24 @@ pub fn to_device<R: Runtime, E: JitElement, const D: usize>(
     tensor.to_client(client, device.clone())
 }
 
+pub fn to_device_async<R: Runtime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    device: &R::Device,
+) -> Reader<JitTensor<R, E, D>> {
+    if &tensor.device == device {
+        return Reader::Concrete(tensor);
+    }
+
+    let client = R::client(device);
+    tensor.to_client_async(client, device.clone())
+}
+
 pub fn empty<R: Runtime, E: JitElement, const D: usize>(
     shape: Shape<D>,
     device: &R::Device,
 ) -> JitTensor<R, E, D> {
     let client = R::client(device);
-    let buffer = client.empty(shape.num_elements() * core::mem::size_of::<E>());
+    let buffer = client.empty(shape.num_elements() * E::wgsl_elem().size());
 
     JitTensor::new(client, device.clone(), shape, buffer)
 }
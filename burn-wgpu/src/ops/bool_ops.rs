@@ -59,6 +59,13 @@ impl<R: Runtime> BoolTensorOps<Self> for JitBackend<R> {
         super::to_device(tensor, device)
     }
 
+    fn bool_to_device_async<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> Reader<BoolTensor<Self, D>> {
+        super::to_device_async(tensor, device)
+    }
+
     fn bool_reshape<const D1: usize, const D2: usize>(
         tensor: BoolTensor<Self, D1>,
         shape: Shape<D2>,
@@ -121,4 +128,12 @@ impl<R: Runtime> BoolTensorOps<Self> for JitBackend<R> {
     ) -> BoolTensor<Self, D> {
         kernel::repeat(tensor, dim, times)
     }
+
+    fn bool_roll<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> BoolTensor<Self, D> {
+        kernel::roll(tensor, shifts, dims)
+    }
 }
@@ -68,6 +68,13 @@ impl<R: Runtime> FloatTensorOps<Self> for JitBackend<R> {
         super::to_device(tensor, device)
     }
 
+    fn float_to_device_async<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> Reader<FloatTensor<Self, D>> {
+        super::to_device_async(tensor, device)
+    }
+
     fn float_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
         super::empty(shape, device)
     }
@@ -493,6 +500,48 @@ impl<R: Runtime> FloatTensorOps<Self> for JitBackend<R> {
         kernel::cat(tensors, dim)
     }
 
+    fn float_cumsum<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        kernel::cumsum(tensor, dim)
+    }
+
+    fn float_cumprod<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        kernel::cumprod(tensor, dim)
+    }
+
+    fn float_fft<const D: usize>(
+        real: FloatTensor<Self, D>,
+        imag: FloatTensor<Self, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        match real.shape.dims[dim].is_power_of_two() {
+            true => kernel::fft(real, imag, dim, inverse),
+            false => burn_tensor::fft::<Self, D>(real, imag, dim, inverse),
+        }
+    }
+
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::sort_with_indices(tensor, dim, descending)
+    }
+
+    fn float_topk<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        k: usize,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::topk(tensor, k, dim)
+    }
+
     fn float_argmax<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -500,6 +549,10 @@ impl<R: Runtime> FloatTensorOps<Self> for JitBackend<R> {
         reduce::argmax(tensor, dim)
     }
 
+    fn float_nonzero<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, 2> {
+        kernel::nonzero(tensor)
+    }
+
     fn float_argmin<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -539,6 +592,14 @@ impl<R: Runtime> FloatTensorOps<Self> for JitBackend<R> {
         kernel::repeat(tensor, dim, times)
     }
 
+    fn float_roll<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> FloatTensor<Self, D> {
+        kernel::roll(tensor, shifts, dims)
+    }
+
     fn float_powf<const D: usize>(
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
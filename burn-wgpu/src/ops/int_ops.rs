@@ -39,6 +39,13 @@ impl<R: Runtime> IntTensorOps<Self> for JitBackend<R> {
         super::to_device(tensor, device)
     }
 
+    fn int_to_device_async<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> Reader<IntTensor<Self, D>> {
+        super::to_device_async(tensor, device)
+    }
+
     fn int_reshape<const D1: usize, const D2: usize>(
         tensor: IntTensor<Self, D1>,
         shape: Shape<D2>,
@@ -271,6 +278,10 @@ impl<R: Runtime> IntTensorOps<Self> for JitBackend<R> {
         kernel::reduce::argmin(tensor, dim)
     }
 
+    fn int_nonzero<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 2> {
+        kernel::nonzero(tensor)
+    }
+
     fn int_clamp<const D: usize>(
         tensor: IntTensor<Self, D>,
         min: IntElem<Self>,
@@ -313,4 +324,12 @@ impl<R: Runtime> IntTensorOps<Self> for JitBackend<R> {
     ) -> IntTensor<Self, D> {
         kernel::repeat(tensor, dim, times)
     }
+
+    fn int_roll<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        shifts: &[i64],
+        dims: &[usize],
+    ) -> IntTensor<Self, D> {
+        kernel::roll(tensor, shifts, dims)
+    }
 }
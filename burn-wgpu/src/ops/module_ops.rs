@@ -1,6 +1,7 @@
 use crate::{kernel, JitBackend, Runtime};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+    ConvOptions, ConvTransposeOptions, InterpolateMode, MaxPool2dBackward, MaxPool2dWithIndices,
+    ModuleOps,
 };
 use burn_tensor::ops::{FloatTensor, IntTensor};
 
@@ -100,4 +101,29 @@ impl<R: Runtime> ModuleOps<Self> for JitBackend<R> {
     ) -> FloatTensor<Self, 4> {
         kernel::pool::adaptive_avg_pool2d_backward(x, grad)
     }
+
+    fn interpolate(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        mode: InterpolateMode,
+    ) -> FloatTensor<Self, 4> {
+        kernel::interpolate(x, output_size, mode)
+    }
+
+    fn rope_embed(
+        x: FloatTensor<Self, 4>,
+        cos: FloatTensor<Self, 2>,
+        sin: FloatTensor<Self, 2>,
+    ) -> FloatTensor<Self, 4> {
+        kernel::rope_embed(x, cos, sin)
+    }
+
+    fn flash_attention(
+        query: FloatTensor<Self, 4>,
+        key: FloatTensor<Self, 4>,
+        value: FloatTensor<Self, 4>,
+        causal: bool,
+    ) -> FloatTensor<Self, 4> {
+        kernel::flash_attention_forward(query, key, value, causal)
+    }
 }
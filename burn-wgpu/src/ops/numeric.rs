@@ -74,7 +74,7 @@ pub fn empty_device<R: Runtime, E: JitElement, const D: usize>(
     device: R::Device,
     shape: Shape<D>,
 ) -> JitTensor<R, E, D> {
-    let buffer = client.empty(shape.num_elements() * core::mem::size_of::<E>());
+    let buffer = client.empty(shape.num_elements() * E::wgsl_elem().size());
 
     JitTensor::new(client, device, shape, buffer)
 }
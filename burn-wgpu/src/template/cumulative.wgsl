@@ -0,0 +1,39 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let stride_dim = info[0];
+    let shape_dim = info[1];
+    let num_lanes = info[2];
+
+    if id >= num_lanes {
+        return;
+    }
+
+    // Each invocation accumulates one lane along the dimension.
+    let index_offset = id / stride_dim * stride_dim * shape_dim + id % stride_dim;
+    var accumulator = {{ elem }}({{ initial }});
+
+    for (var i = 0u; i < shape_dim; i++) {
+        let index = index_offset + i * stride_dim;
+        accumulator = accumulator {{ ops }} input[index];
+        output[index] = accumulator;
+    }
+}
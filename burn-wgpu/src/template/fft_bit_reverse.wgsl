@@ -0,0 +1,51 @@
+@group(0)
+@binding(0)
+var<storage, read> input_real: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> input_imag: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> output_real: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> output_imag: array<{{ elem }}>;
+
+@group(0)
+@binding(4)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let stride_dim = info[0];
+    let shape_dim = info[1];
+    let num_elems = info[2];
+    let num_bits = info[3];
+
+    if id >= num_elems {
+        return;
+    }
+
+    // Each invocation moves one element to the bit-reversed position of its lane.
+    let position = id / stride_dim % shape_dim;
+    let index_offset = id - position * stride_dim;
+    var reversed = 0u;
+
+    if num_bits > 0u {
+        reversed = reverseBits(position) >> (32u - num_bits);
+    }
+
+    let index = index_offset + reversed * stride_dim;
+    output_real[index] = input_real[id];
+    output_imag[index] = input_imag[id];
+}
@@ -0,0 +1,65 @@
+@group(0)
+@binding(0)
+var<storage, read_write> real: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> imag: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+const PI = 3.141592653589793;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let stride_dim = info[0];
+    let shape_dim = info[1];
+    let num_butterflies = info[2];
+    let half_size = info[3];
+    let inverse = info[4];
+
+    if id >= num_butterflies {
+        return;
+    }
+
+    // Each invocation combines two elements of a lane, half_size apart, in the stage merging
+    // the transforms of size half_size into transforms of size 2 * half_size.
+    let butterflies_per_lane = shape_dim / 2u;
+    let lane = id / butterflies_per_lane;
+    let butterfly = id % butterflies_per_lane;
+    let offset = butterfly % half_size;
+    let position = butterfly / half_size * 2u * half_size + offset;
+
+    let index_offset = lane / stride_dim * stride_dim * shape_dim + lane % stride_dim;
+    let index_even = index_offset + position * stride_dim;
+    let index_odd = index_even + half_size * stride_dim;
+
+    var sign = -1.0;
+    if inverse == 1u {
+        sign = 1.0;
+    }
+
+    let angle = {{ elem }}(sign * PI * f32(offset) / f32(half_size));
+    let cos_angle = cos(angle);
+    let sin_angle = sin(angle);
+
+    let even_real = real[index_even];
+    let even_imag = imag[index_even];
+    let odd_real = real[index_odd];
+    let odd_imag = imag[index_odd];
+    let twiddled_real = odd_real * cos_angle - odd_imag * sin_angle;
+    let twiddled_imag = odd_real * sin_angle + odd_imag * cos_angle;
+
+    real[index_even] = even_real + twiddled_real;
+    imag[index_even] = even_imag + twiddled_imag;
+    real[index_odd] = even_real - twiddled_real;
+    imag[index_odd] = even_imag - twiddled_imag;
+}
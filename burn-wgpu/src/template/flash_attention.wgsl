@@ -0,0 +1,128 @@
+@group(0)
+@binding(0)
+var<storage, read> query: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> key: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> value: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(4)
+var<storage, read> info: array<u32>;
+
+const BLOCK_Q = {{ workgroup_size_x }}u;
+const BLOCK_K = {{ block_k }}u;
+const HEAD_DIM = {{ head_dim }}u;
+const HEAD_DIM_VALUE = {{ head_dim_value }}u;
+
+var<workgroup> key_tile: array<{{ elem }}, {{ key_tile_size }}>;
+var<workgroup> value_tile: array<{{ elem }}, {{ value_tile_size }}>;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, 1, 1)
+fn main(
+    @builtin(local_invocation_index) local_idx: u32,
+    @builtin(workgroup_id) workgroup_id: vec3<u32>,
+) {
+    let seq_length_1 = info[0];
+    let seq_length_2 = info[1];
+    let causal = info[2];
+
+    // Each workgroup computes a block of queries of one head, one query per invocation.
+    let batch_head = workgroup_id.y;
+    let row = workgroup_id.x * BLOCK_Q + local_idx;
+    let row_valid = row < seq_length_1;
+    let query_offset = batch_head * seq_length_1 * HEAD_DIM + row * HEAD_DIM;
+    let key_offset = batch_head * seq_length_2 * HEAD_DIM;
+    let value_offset = batch_head * seq_length_2 * HEAD_DIM_VALUE;
+    let output_offset = batch_head * seq_length_1 * HEAD_DIM_VALUE + row * HEAD_DIM_VALUE;
+
+    let scale = 1.0 / sqrt({{ elem }}(HEAD_DIM));
+    var query_row: array<{{ elem }}, HEAD_DIM>;
+    var acc: array<{{ elem }}, HEAD_DIM_VALUE>;
+    var scores: array<{{ elem }}, BLOCK_K>;
+    var row_max = {{ elem }}(-3.4e38);
+    var row_sum = {{ elem }}(0.0);
+
+    if row_valid {
+        for (var d = 0u; d < HEAD_DIM; d++) {
+            query_row[d] = query[query_offset + d] * scale;
+        }
+    }
+    for (var d = 0u; d < HEAD_DIM_VALUE; d++) {
+        acc[d] = {{ elem }}(0.0);
+    }
+
+    // With a causal mask, the keys after the last query of the block are never used. The last
+    // query is aligned with the last key.
+    var key_end = seq_length_2;
+    if causal == 1u {
+        let last_row = min(seq_length_1, (workgroup_id.x + 1u) * BLOCK_Q) - 1u;
+        key_end = min(seq_length_2, last_row + seq_length_2 - seq_length_1 + 1u);
+    }
+
+    for (var tile_start = 0u; tile_start < key_end; tile_start += BLOCK_K) {
+        let tile_length = min(BLOCK_K, key_end - tile_start);
+
+        // Load the tiles of keys and values in shared memory.
+        for (var i = local_idx; i < tile_length * HEAD_DIM; i += BLOCK_Q) {
+            key_tile[i] = key[key_offset + tile_start * HEAD_DIM + i];
+        }
+        for (var i = local_idx; i < tile_length * HEAD_DIM_VALUE; i += BLOCK_Q) {
+            value_tile[i] = value[value_offset + tile_start * HEAD_DIM_VALUE + i];
+        }
+
+        workgroupBarrier();
+
+        if row_valid {
+            var num_keys = tile_length;
+            if causal == 1u {
+                let row_key_end = row + seq_length_2 - seq_length_1 + 1u;
+                num_keys = select(0u, min(tile_length, row_key_end - tile_start), row_key_end > tile_start);
+            }
+
+            // Online softmax: the accumulator is rescaled once per tile with the new maximum.
+            var tile_max = row_max;
+            for (var j = 0u; j < num_keys; j++) {
+                var score = {{ elem }}(0.0);
+                for (var d = 0u; d < HEAD_DIM; d++) {
+                    score += query_row[d] * key_tile[j * HEAD_DIM + d];
+                }
+                scores[j] = score;
+                tile_max = max(tile_max, score);
+            }
+
+            let correction = exp(row_max - tile_max);
+            row_sum *= correction;
+            for (var d = 0u; d < HEAD_DIM_VALUE; d++) {
+                acc[d] *= correction;
+            }
+
+            for (var j = 0u; j < num_keys; j++) {
+                let weight = exp(scores[j] - tile_max);
+                row_sum += weight;
+                for (var d = 0u; d < HEAD_DIM_VALUE; d++) {
+                    acc[d] += weight * value_tile[j * HEAD_DIM_VALUE + d];
+                }
+            }
+
+            row_max = tile_max;
+        }
+
+        workgroupBarrier();
+    }
+
+    if row_valid {
+        for (var d = 0u; d < HEAD_DIM_VALUE; d++) {
+            output[output_offset + d] = acc[d] / row_sum;
+        }
+    }
+}
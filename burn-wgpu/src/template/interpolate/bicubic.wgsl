@@ -0,0 +1,102 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32, 16>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+
+    let input_stride_0 = info[0];
+    let input_stride_1 = info[1];
+    let input_stride_2 = info[2];
+    let input_stride_3 = info[3];
+    let input_shape_0 = info[4];
+    let input_shape_1 = info[5];
+    let input_shape_2 = info[6];
+    let input_shape_3 = info[7];
+
+    let output_stride_0 = info[8];
+    let output_stride_1 = info[9];
+    let output_stride_2 = info[10];
+    let output_stride_3 = info[11];
+    let output_shape_0 = info[12];
+    let output_shape_1 = info[13];
+    let output_shape_2 = info[14];
+    let output_shape_3 = info[15];
+
+    if id >= output_shape_0 * output_stride_0 {
+        return;
+    }
+
+    let b = id / output_stride_0 % output_shape_0;
+    let c = id / output_stride_1 % output_shape_1;
+    let oh = id / output_stride_2 % output_shape_2;
+    let ow = id / output_stride_3 % output_shape_3;
+
+    let index_base = b * input_stride_0 + c * input_stride_1;
+
+    let y = (f32(oh) + 0.5) * f32(input_shape_2) / f32(output_shape_2) - 0.5;
+    let x = (f32(ow) + 0.5) * f32(input_shape_3) / f32(output_shape_3) - 0.5;
+
+    let y0 = i32(floor(y));
+    let x0 = i32(floor(x));
+    var weights_y = cubic_weights(y - floor(y));
+    var weights_x = cubic_weights(x - floor(x));
+
+    var sum = 0.0;
+
+    for (var i = 0; i < 4; i++) {
+        let ih = clamp_index(y0 - 1 + i, input_shape_2);
+        var row = 0.0;
+
+        for (var j = 0; j < 4; j++) {
+            let iw = clamp_index(x0 - 1 + j, input_shape_3);
+            row += weights_x[j] * f32(input[index_base + ih * input_stride_2 + iw * input_stride_3]);
+        }
+
+        sum += weights_y[i] * row;
+    }
+
+    output[id] = {{ elem }}(sum);
+}
+
+fn clamp_index(index: i32, size: u32) -> u32 {
+    return u32(clamp(index, 0, i32(size) - 1));
+}
+
+// Weights of the four pixels surrounding a position at distance `t` of the second pixel, using
+// the same coefficient as PyTorch.
+fn cubic_weights(t: f32) -> array<f32, 4> {
+    let a = -0.75;
+
+    return array<f32, 4>(
+        cubic_far(t + 1.0, a),
+        cubic_near(t, a),
+        cubic_near(1.0 - t, a),
+        cubic_far(2.0 - t, a),
+    );
+}
+
+// Used for distances in [0, 1].
+fn cubic_near(x: f32, a: f32) -> f32 {
+    return ((a + 2.0) * x - (a + 3.0)) * x * x + 1.0;
+}
+
+// Used for distances in [1, 2].
+fn cubic_far(x: f32, a: f32) -> f32 {
+    return ((a * x - 5.0 * a) * x + 8.0 * a) * x - 4.0 * a;
+}
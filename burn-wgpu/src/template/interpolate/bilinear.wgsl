@@ -0,0 +1,86 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32, 16>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+
+    let input_stride_0 = info[0];
+    let input_stride_1 = info[1];
+    let input_stride_2 = info[2];
+    let input_stride_3 = info[3];
+    let input_shape_0 = info[4];
+    let input_shape_1 = info[5];
+    let input_shape_2 = info[6];
+    let input_shape_3 = info[7];
+
+    let output_stride_0 = info[8];
+    let output_stride_1 = info[9];
+    let output_stride_2 = info[10];
+    let output_stride_3 = info[11];
+    let output_shape_0 = info[12];
+    let output_shape_1 = info[13];
+    let output_shape_2 = info[14];
+    let output_shape_3 = info[15];
+
+    if id >= output_shape_0 * output_stride_0 {
+        return;
+    }
+
+    let b = id / output_stride_0 % output_shape_0;
+    let c = id / output_stride_1 % output_shape_1;
+    let oh = id / output_stride_2 % output_shape_2;
+    let ow = id / output_stride_3 % output_shape_3;
+
+    let index_base = b * input_stride_0 + c * input_stride_1;
+
+    let y = source_position(oh, output_shape_2, input_shape_2);
+    let x = source_position(ow, output_shape_3, input_shape_3);
+
+    let y0 = u32(floor(y));
+    let x0 = u32(floor(x));
+    let y1 = min(y0 + 1u, input_shape_2 - 1u);
+    let x1 = min(x0 + 1u, input_shape_3 - 1u);
+    let ly = y - floor(y);
+    let lx = x - floor(x);
+
+    let p00 = f32(input[index_base + y0 * input_stride_2 + x0 * input_stride_3]);
+    let p01 = f32(input[index_base + y0 * input_stride_2 + x1 * input_stride_3]);
+    let p10 = f32(input[index_base + y1 * input_stride_2 + x0 * input_stride_3]);
+    let p11 = f32(input[index_base + y1 * input_stride_2 + x1 * input_stride_3]);
+
+    let top = p00 * (1.0 - lx) + p01 * lx;
+    let bottom = p10 * (1.0 - lx) + p11 * lx;
+
+    output[id] = {{ elem }}(top * (1.0 - ly) + bottom * ly);
+}
+
+fn source_position(output_index: u32, output_size: u32, input_size: u32) -> f32 {
+    if {{ align_corners }} {
+        if output_size <= 1u {
+            return 0.0;
+        }
+
+        return f32(output_index) * f32(input_size - 1u) / f32(output_size - 1u);
+    }
+
+    let scale = f32(input_size) / f32(output_size);
+    let position = (f32(output_index) + 0.5) * scale - 0.5;
+
+    return min(max(position, 0.0), f32(input_size - 1u));
+}
@@ -0,0 +1,37 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> flags: array<{{ int }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let num_elems = info[0];
+    let num_flags = info[1];
+
+    if id >= num_flags {
+        return;
+    }
+
+    // The flags are padded up to a whole number of blocks, the padding being never selected.
+    var flag = {{ int }}(0);
+
+    if id < num_elems && input[id] != {{ elem }}(0) {
+        flag = {{ int }}(1);
+    }
+
+    flags[id] = flag;
+}
@@ -0,0 +1,54 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> scan: array<{{ int }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> offsets: array<{{ int }}>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> output: array<{{ int }}>;
+
+@group(0)
+@binding(4)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let num_elems = info[0];
+    let block_size = info[1];
+    let rank = info[2];
+
+    if id >= num_elems || input[id] == {{ elem }}(0) {
+        return;
+    }
+
+    // The scan is inclusive within each block, and the offsets are the inclusive scan of the
+    // block totals, so the previous block offset is where this block starts writing.
+    let block = id / block_size;
+    var position = u32(scan[id]) - 1u;
+
+    if block > 0u {
+        position += u32(offsets[block - 1u]);
+    }
+
+    var remainder = id;
+
+    for (var i = rank; i > 0u; i--) {
+        let shape = info[2u + i];
+        output[position * rank + i - 1u] = {{ int }}(remainder % shape);
+        remainder = remainder / shape;
+    }
+}
@@ -0,0 +1,50 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> cos_table: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read> sin_table: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> output: array<{{ elem }}>;
+
+@group(0)
+@binding(4)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let seq_length = info[0];
+    let head_dim = info[1];
+    let num_elems = info[2];
+    let half_dim = head_dim / 2u;
+
+    if id >= num_elems {
+        return;
+    }
+
+    // Each invocation computes one element, rotated with the other element of its pair.
+    let index_dim = id % head_dim;
+    let position = id / head_dim % seq_length;
+
+    if index_dim < half_dim {
+        let index_table = position * half_dim + index_dim;
+        output[id] = input[id] * cos_table[index_table] - input[id + half_dim] * sin_table[index_table];
+    } else {
+        let index_table = position * half_dim + index_dim - half_dim;
+        output[id] = input[id] * cos_table[index_table] + input[id - half_dim] * sin_table[index_table];
+    }
+}
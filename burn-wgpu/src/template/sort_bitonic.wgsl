@@ -0,0 +1,100 @@
+@group(0)
+@binding(0)
+var<storage, read_write> keys: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> positions: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let shape_dim = info[0];
+    let padded_dim = info[1];
+    let descending = info[2] == 1u;
+    let block_size = info[3];
+    let distance = info[4];
+    let num_lanes = info[5];
+
+    let half_dim = padded_dim / 2u;
+
+    if id >= num_lanes * half_dim {
+        return;
+    }
+
+    // Each invocation compares two elements of a lane, distance apart, and swaps them when they
+    // aren't in the order of their block, which alternates so that the blocks merged in the next
+    // step form bitonic sequences.
+    let lane = id / half_dim;
+    let pair = id % half_dim;
+    let position = pair / distance * 2u * distance + pair % distance;
+    let lhs = lane * padded_dim + position;
+    let rhs = lhs + distance;
+
+    let lhs_key = keys[lhs];
+    let rhs_key = keys[rhs];
+    let lhs_position = positions[lhs];
+    let rhs_position = positions[rhs];
+
+    var swap = is_before(rhs_key, rhs_position, lhs_key, lhs_position, shape_dim, descending);
+
+    if (position & block_size) != 0u {
+        swap = is_before(lhs_key, lhs_position, rhs_key, rhs_position, shape_dim, descending);
+    }
+
+    if swap {
+        keys[lhs] = rhs_key;
+        keys[rhs] = lhs_key;
+        positions[lhs] = rhs_position;
+        positions[rhs] = lhs_position;
+    }
+}
+
+// Orders the elements by value with NaN values last regardless of the order, and by position
+// when they are equal, so the sort is stable. The padding is placed after every element.
+fn is_before(
+    lhs: {{ elem }},
+    lhs_position: u32,
+    rhs: {{ elem }},
+    rhs_position: u32,
+    shape_dim: u32,
+    descending: bool,
+) -> bool {
+    let lhs_padding = lhs_position >= shape_dim;
+    let rhs_padding = rhs_position >= shape_dim;
+
+    if lhs_padding || rhs_padding {
+        return lhs_position < rhs_position;
+    }
+
+    let lhs_nan = lhs != lhs;
+    let rhs_nan = rhs != rhs;
+
+    if lhs_nan || rhs_nan {
+        if lhs_nan == rhs_nan {
+            return lhs_position < rhs_position;
+        }
+
+        return rhs_nan;
+    }
+
+    if lhs == rhs {
+        return lhs_position < rhs_position;
+    }
+
+    if descending {
+        return lhs > rhs;
+    }
+
+    return lhs < rhs;
+}
@@ -0,0 +1,46 @@
+@group(0)
+@binding(0)
+var<storage, read> input: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> keys: array<{{ elem }}>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> positions: array<u32>;
+
+@group(0)
+@binding(3)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let stride_dim = info[0];
+    let shape_dim = info[1];
+    let padded_dim = info[2];
+    let num_lanes = info[3];
+
+    if id >= num_lanes * padded_dim {
+        return;
+    }
+
+    // Each lane is copied next to each other and padded to a power of two. The padding keeps a
+    // position past the end of the lane, which orders it after every element.
+    let lane = id / padded_dim;
+    let position = id % padded_dim;
+    positions[id] = position;
+
+    if position < shape_dim {
+        let outer = lane / stride_dim;
+        let inner = lane % stride_dim;
+        keys[id] = input[outer * shape_dim * stride_dim + position * stride_dim + inner];
+    }
+}
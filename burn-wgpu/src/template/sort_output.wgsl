@@ -0,0 +1,49 @@
+@group(0)
+@binding(0)
+var<storage, read> keys: array<{{ elem }}>;
+
+@group(0)
+@binding(1)
+var<storage, read> positions: array<u32>;
+
+@group(0)
+@binding(2)
+var<storage, read_write> values: array<{{ elem }}>;
+
+@group(0)
+@binding(3)
+var<storage, read_write> indices: array<{{ int }}>;
+
+@group(0)
+@binding(4)
+var<storage, read> info: array<u32>;
+
+const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
+
+@compute
+@workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let id = global_id.y * (num_workgroups.x * WORKGROUP_SIZE_X) + global_id.x;
+    let stride_dim = info[0];
+    let padded_dim = info[1];
+    let k = info[2];
+    let num_lanes = info[3];
+
+    if id >= num_lanes * k {
+        return;
+    }
+
+    // The first k elements of each sorted lane are written back along the dimension.
+    let lane = id / k;
+    let rank = id % k;
+    let outer = lane / stride_dim;
+    let inner = lane % stride_dim;
+    let index = outer * k * stride_dim + rank * stride_dim + inner;
+    let sorted = lane * padded_dim + rank;
+
+    values[index] = keys[sorted];
+    indices[index] = {{ int }}(positions[sorted]);
+}
@@ -3,7 +3,7 @@ use crate::element::JitElement;
 use crate::{unary, Runtime};
 use burn_compute::client::ComputeClient;
 use burn_compute::server::Handle;
-use burn_tensor::Shape;
+use burn_tensor::{Reader, Shape};
 use std::marker::PhantomData;
 
 /// The basic tensor primitive struct.
@@ -117,6 +117,30 @@ where
         }
     }
 
+    /// Change the context of the current tensor without waiting for its data to be read back,
+    /// returning the reader of the newly transferred tensor.
+    pub fn to_client_async(
+        &self,
+        client: ComputeClient<R::Server, R::Channel>,
+        device: R::Device,
+    ) -> Reader<Self> {
+        let shape = self.shape.clone();
+        let strides = self.strides;
+
+        self.client.read(&self.handle).map(move |bytes| {
+            let handle = client.create(&bytes);
+
+            Self {
+                client,
+                handle,
+                shape,
+                strides,
+                device,
+                elem: PhantomData,
+            }
+        })
+    }
+
     pub(crate) fn can_mut_broadcast(&self, rhs: &Self) -> bool {
         if !self.handle.can_mut() {
             return false;
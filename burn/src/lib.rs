@@ -78,9 +78,13 @@
 //!   - `fusion`: Enable operation fusion in backends that support it.
 //! - Backend decorators
 //!   - `autodiff`: Makes available the Autodiff backend
+//!   - `quantization`: Makes available the quantized backend for quantized inference
 //! - Others:
 //!   - `std`: Activates the standard library (deactivate for no_std)
 //!   - `network`: Enables network utilities (currently, only a file downloader with progress bar)
+//!   - `sparse`: Makes available the sparse tensors
+//!   - `graph`: Makes available the graph neural network layers
+//!   - `prune`: Makes available the magnitude and structured pruning of models
 //!   - `experimental-named-tensor`: Enables named tensors (experimental)
 
 pub use burn_core::*;
@@ -90,3 +94,27 @@ pub use burn_core::*;
 pub mod train {
     pub use burn_train::*;
 }
+
+/// Sparse module
+#[cfg(feature = "sparse")]
+pub mod sparse {
+    pub use burn_sparse::*;
+}
+
+/// Graph module
+#[cfg(feature = "graph")]
+pub mod graph {
+    pub use burn_graph::*;
+}
+
+/// Quantization module
+#[cfg(feature = "quantization")]
+pub mod quantization {
+    pub use burn_quantization::*;
+}
+
+/// Prune module
+#[cfg(feature = "prune")]
+pub mod prune {
+    pub use burn_prune::*;
+}
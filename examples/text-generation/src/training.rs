@@ -62,8 +62,10 @@ pub fn train<B: AutodiffBackend, D: Dataset<TextGenerationItem> + 'static>(
 
     let accum = 6; // Effective batch size = 6 * 6 = 32.
     let optim = config.optimizer.init();
-    let lr_scheduler = NoamLrSchedulerConfig::new(0.01 / accum as f64)
-        .with_warmup_steps(6000)
+    // The scheduler advances once per optimizer step, every `accum` iterations, so the warmup
+    // and the learning rate are scaled to follow the same schedule over the iterations.
+    let lr_scheduler = NoamLrSchedulerConfig::new(0.01 / (accum as f64).powf(1.5))
+        .with_warmup_steps(6000 / accum)
         .with_model_size(config.transformer.d_model)
         .init();
 
@@ -77,7 +79,7 @@ pub fn train<B: AutodiffBackend, D: Dataset<TextGenerationItem> + 'static>(
         .metric_train_numeric(LearningRateMetric::new())
         .with_file_checkpointer(CompactRecorder::new())
         .devices(vec![device])
-        .grads_accumulation(accum)
+        .with_gradient_accumulation(accum)
         .num_epochs(config.num_epochs)
         .build(model, optim, lr_scheduler);
 
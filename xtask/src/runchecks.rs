@@ -239,6 +239,7 @@ fn no_std_checks() {
     build_and_test_no_std("burn-common", []);
     build_and_test_no_std("burn-tensor", []);
     build_and_test_no_std("burn-ndarray", []);
+    build_and_test_no_std("burn-sparse", []);
     build_and_test_no_std("burn-no-std-tests", []);
 }
 